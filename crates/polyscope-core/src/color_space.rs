@@ -0,0 +1,68 @@
+//! Encoding of user-supplied colors.
+
+use glam::Vec4;
+use serde::{Deserialize, Serialize};
+
+/// How the RGB channels of user-supplied colors are encoded.
+///
+/// Scenes are shaded in linear space and gamma encoded for display by the
+/// tone mapping pass, so sRGB colors are converted to linear on upload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ColorSpace {
+    /// sRGB encoded, like colors from color pickers and image files (default).
+    #[default]
+    Srgb,
+    /// Linear, uploaded as is.
+    Linear,
+}
+
+impl ColorSpace {
+    /// Converts `color` from this color space to linear. Alpha is kept.
+    #[must_use]
+    pub fn to_linear(self, color: Vec4) -> Vec4 {
+        match self {
+            Self::Srgb => Vec4::new(
+                srgb_to_linear(color.x),
+                srgb_to_linear(color.y),
+                srgb_to_linear(color.z),
+                color.w,
+            ),
+            Self::Linear => color,
+        }
+    }
+
+    /// Converts `colors` from this color space to linear.
+    #[must_use]
+    pub fn colors_to_linear(self, colors: &[Vec4]) -> Vec<Vec4> {
+        colors.iter().map(|&c| self.to_linear(c)).collect()
+    }
+}
+
+/// Converts an sRGB encoded channel value to linear with the sRGB transfer
+/// function (IEC 61966-2-1).
+#[must_use]
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srgb_to_linear() {
+        assert!(srgb_to_linear(0.0).abs() < 1e-6);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert!((srgb_to_linear(0.5) - 0.214).abs() < 1e-3);
+
+        let gray = Vec4::new(0.5, 0.5, 0.5, 0.5);
+        assert_eq!(ColorSpace::Linear.to_linear(gray), gray);
+        let linear = ColorSpace::Srgb.to_linear(gray);
+        assert!((linear.x - 0.214).abs() < 1e-3);
+        assert!((linear.w - 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,159 @@
+//! Crop box for clipping the scene to a box.
+//!
+//! Unlike slice planes, which each keep one half-space, the crop box keeps
+//! the inside of a single (optionally oriented) box and discards everything
+//! else.
+
+use glam::{Mat4, Vec3};
+
+/// A box that clips all structures to its inside.
+///
+/// The box spans `min..max` in its own frame, which is placed in the world
+/// by `transform` (a rigid transform; identity for an axis-aligned box).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropBox {
+    min: Vec3,
+    max: Vec3,
+    transform: Mat4,
+    draw_widget: bool,
+}
+
+impl CropBox {
+    /// Creates an axis-aligned crop box between two corners.
+    #[must_use]
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+            transform: Mat4::IDENTITY,
+            draw_widget: false,
+        }
+    }
+
+    /// Returns the corners of the box, in its own frame.
+    #[must_use]
+    pub fn bounds(&self) -> (Vec3, Vec3) {
+        (self.min, self.max)
+    }
+
+    /// Sets the corners of the box, in its own frame.
+    pub fn set_bounds(&mut self, min: Vec3, max: Vec3) {
+        self.min = min.min(max);
+        self.max = min.max(max);
+    }
+
+    /// Returns the transform placing the box frame in the world.
+    #[must_use]
+    pub fn transform(&self) -> Mat4 {
+        self.transform
+    }
+
+    /// Sets the transform placing the box frame in the world.
+    pub fn set_transform(&mut self, transform: Mat4) {
+        self.transform = transform;
+    }
+
+    /// Returns whether the transform gizmo is shown for the box.
+    #[must_use]
+    pub fn draw_widget(&self) -> bool {
+        self.draw_widget
+    }
+
+    /// Sets whether the transform gizmo is shown for the box.
+    pub fn set_draw_widget(&mut self, draw: bool) {
+        self.draw_widget = draw;
+    }
+
+    /// Returns the world transform of the box center, with the box frame's
+    /// orientation; this is where the widget is drawn.
+    #[must_use]
+    pub fn center_transform(&self) -> Mat4 {
+        self.transform * Mat4::from_translation((self.min + self.max) * 0.5)
+    }
+
+    /// Moves the box so that its center has the given world transform.
+    pub fn set_center_transform(&mut self, center_transform: Mat4) {
+        self.transform = center_transform * Mat4::from_translation(-(self.min + self.max) * 0.5);
+    }
+
+    /// Returns whether a world-space point is inside the box.
+    #[must_use]
+    pub fn contains(&self, point: Vec3) -> bool {
+        let local = self.transform.inverse().transform_point3(point);
+        local.cmpge(self.min).all() && local.cmple(self.max).all()
+    }
+}
+
+/// GPU-compatible crop box uniforms.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct CropBoxUniforms {
+    /// Transform from world space to the box frame.
+    pub world_to_box: [[f32; 4]; 4],
+    /// Minimum corner in the box frame.
+    pub min: [f32; 3],
+    /// Whether the box is enabled (1.0) or disabled (0.0).
+    pub enabled: f32,
+    /// Maximum corner in the box frame.
+    pub max: [f32; 3],
+    /// Padding to a 16-byte multiple.
+    _padding: f32,
+}
+
+impl From<&CropBox> for CropBoxUniforms {
+    fn from(crop_box: &CropBox) -> Self {
+        Self {
+            world_to_box: crop_box.transform.inverse().to_cols_array_2d(),
+            min: crop_box.min.to_array(),
+            enabled: 1.0,
+            max: crop_box.max.to_array(),
+            _padding: 0.0,
+        }
+    }
+}
+
+impl Default for CropBoxUniforms {
+    fn default() -> Self {
+        Self {
+            world_to_box: Mat4::IDENTITY.to_cols_array_2d(),
+            min: [0.0; 3],
+            enabled: 0.0,
+            max: [0.0; 3],
+            _padding: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crop_box_contains() {
+        let mut crop_box = CropBox::new(Vec3::ONE, -Vec3::ONE);
+        assert_eq!(crop_box.bounds(), (-Vec3::ONE, Vec3::ONE));
+        assert!(crop_box.contains(Vec3::new(0.5, -0.5, 0.9)));
+        assert!(!crop_box.contains(Vec3::new(1.5, 0.0, 0.0)));
+
+        // Rotated 45 degrees about Z, the corner direction along X is cut
+        crop_box.set_transform(Mat4::from_rotation_z(std::f32::consts::FRAC_PI_4));
+        assert!(crop_box.contains(Vec3::new(1.3, 0.0, 0.0)));
+        assert!(!crop_box.contains(Vec3::new(1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_crop_box_center_transform() {
+        let mut crop_box = CropBox::new(Vec3::ZERO, Vec3::splat(2.0));
+        assert!(
+            crop_box
+                .center_transform()
+                .transform_point3(Vec3::ZERO)
+                .abs_diff_eq(Vec3::ONE, 1e-6)
+        );
+
+        crop_box.set_center_transform(Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0)));
+        assert!(crop_box.contains(Vec3::new(5.9, 0.0, 0.0)));
+        assert!(!crop_box.contains(Vec3::ONE));
+        assert_eq!(crop_box.bounds(), (Vec3::ZERO, Vec3::splat(2.0)));
+    }
+}
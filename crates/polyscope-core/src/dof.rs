@@ -0,0 +1,26 @@
+//! Depth of field configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Depth of field configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DofConfig {
+    /// Whether depth of field is enabled.
+    pub enabled: bool,
+    /// Distance from the camera to the plane in focus, in scene units.
+    pub focal_distance: f32,
+    /// Blur radius in pixels for points infinitely far from the camera
+    /// (larger = shallower depth of field).
+    pub aperture: f32,
+}
+
+impl Default for DofConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            focal_distance: 1.0,
+            aperture: 8.0,
+        }
+    }
+}
@@ -37,6 +37,18 @@ pub enum PolyscopeError {
     #[error("material load error: {0}")]
     MaterialLoadError(String),
 
+    /// Failed to parse a geometry file.
+    #[error("file load error: {0}")]
+    FileLoadError(String),
+
+    /// Invalid geometry (e.g. degenerate faces or out-of-range indices).
+    #[error("invalid geometry: {0}")]
+    InvalidGeometry(String),
+
+    /// Invalid color map definition.
+    #[error("invalid color map: {0}")]
+    InvalidColorMap(String),
+
     /// Data size mismatch.
     #[error("data size mismatch: expected {expected}, got {actual}")]
     SizeMismatch { expected: usize, actual: usize },
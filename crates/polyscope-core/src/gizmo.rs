@@ -14,6 +14,8 @@ pub enum GizmoMode {
     Rotate,
     /// Scale gizmo (boxes along axes).
     Scale,
+    /// Scale gizmo with the three axes linked, so scaling is always uniform.
+    UniformScale,
 }
 
 /// The coordinate space for gizmo operations.
@@ -107,6 +109,31 @@ impl GizmoConfig {
         self.snap_scale = snap;
         self
     }
+
+    /// Applies the scale constraints of this configuration to a scale edited
+    /// from `previous` to `scale`.
+    ///
+    /// In [`GizmoMode::UniformScale`] mode, the axis that changed the most
+    /// drives all three. The result is then snapped to `snap_scale`.
+    #[must_use]
+    pub fn constrain_scale(&self, previous: Vec3, scale: Vec3) -> Vec3 {
+        let mut scale = scale;
+        if self.mode == GizmoMode::UniformScale {
+            let change = (scale - previous).abs();
+            let driving = if change.x >= change.y && change.x >= change.z {
+                scale.x
+            } else if change.y >= change.z {
+                scale.y
+            } else {
+                scale.z
+            };
+            scale = Vec3::splat(driving);
+        }
+        if self.snap_scale > 0.0 {
+            scale = (scale / self.snap_scale).round() * self.snap_scale;
+        }
+        scale
+    }
 }
 
 /// A transformation represented as separate components.
@@ -345,4 +372,18 @@ mod tests {
         t.snap_translation(0.5);
         assert_eq!(t.translation, Vec3::new(1.0, 2.5, 3.0));
     }
+
+    #[test]
+    fn test_constrain_uniform_scale() {
+        let config = GizmoConfig::new()
+            .with_mode(GizmoMode::UniformScale)
+            .with_snap_scale(0.5);
+        let scale = config.constrain_scale(Vec3::ONE, Vec3::new(1.0, 1.6, 1.1));
+        assert_eq!(scale, Vec3::splat(1.5));
+
+        // Other modes keep per-axis scale
+        let config = GizmoConfig::new().with_mode(GizmoMode::Scale);
+        let scale = Vec3::new(2.0, 1.0, 1.0);
+        assert_eq!(config.constrain_scale(Vec3::ONE, scale), scale);
+    }
 }
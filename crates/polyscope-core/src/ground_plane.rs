@@ -3,6 +3,9 @@
 use serde::{Deserialize, Serialize};
 
 /// Ground plane rendering mode.
+///
+/// A preset for the `draw_*` flags of [`GroundPlaneConfig`]; see
+/// [`GroundPlaneConfig::set_mode`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum GroundPlaneMode {
     /// No ground plane.
@@ -17,14 +20,22 @@ pub enum GroundPlaneMode {
 }
 
 /// Ground plane configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GroundPlaneConfig {
-    /// Rendering mode.
-    pub mode: GroundPlaneMode,
+    /// Draw the tiled ground plane.
+    pub draw_tile: bool,
+    /// Draw contact shadows of surface meshes, on the tile or alone.
+    pub draw_shadow: bool,
+    /// Mirror the scene in the tile. Has no effect without `draw_tile`.
+    pub draw_reflection: bool,
     /// Height of the ground plane (Y coordinate), used when `height_is_relative` is false.
     pub height: f32,
     /// Whether height is relative to scene bounds (auto-placed below scene).
     pub height_is_relative: bool,
+    /// Offset above the bottom of the scene bounding box, in multiples of the
+    /// scene length scale. Used when `height_is_relative` is true.
+    pub height_factor: f32,
     /// Shadow blur iterations (0-5).
     pub shadow_blur_iters: u32,
     /// Shadow darkness (0.0 = no shadow, 1.0 = full black).
@@ -36,12 +47,125 @@ pub struct GroundPlaneConfig {
 impl Default for GroundPlaneConfig {
     fn default() -> Self {
         Self {
-            mode: GroundPlaneMode::Tile,
+            draw_tile: true,
+            draw_shadow: true,
+            draw_reflection: false,
             height: 0.0,
             height_is_relative: true,
+            height_factor: 0.0,
             shadow_blur_iters: 2,
             shadow_darkness: 0.4,
             reflection_intensity: 0.25,
         }
     }
 }
+
+impl GroundPlaneConfig {
+    /// Returns the mode closest to the `draw_*` flags.
+    ///
+    /// A tile without a shadow has no mode of its own and reports
+    /// [`GroundPlaneMode::Tile`].
+    #[must_use]
+    pub fn mode(&self) -> GroundPlaneMode {
+        match (self.draw_tile, self.draw_shadow) {
+            (true, _) if self.draw_reflection => GroundPlaneMode::TileReflection,
+            (true, _) => GroundPlaneMode::Tile,
+            (false, true) => GroundPlaneMode::ShadowOnly,
+            (false, false) => GroundPlaneMode::None,
+        }
+    }
+
+    /// Sets the `draw_*` flags from a mode. Every mode except
+    /// [`GroundPlaneMode::None`] draws the shadow.
+    pub fn set_mode(&mut self, mode: GroundPlaneMode) {
+        self.draw_tile = matches!(
+            mode,
+            GroundPlaneMode::Tile | GroundPlaneMode::TileReflection
+        );
+        self.draw_shadow = mode != GroundPlaneMode::None;
+        self.draw_reflection = mode == GroundPlaneMode::TileReflection;
+    }
+
+    /// Returns whether anything is drawn on the ground plane.
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.draw_tile || self.draw_shadow
+    }
+
+    /// Returns whether the shadow map pass runs.
+    #[must_use]
+    pub fn shadow_pass_enabled(&self) -> bool {
+        self.draw_shadow
+    }
+
+    /// Returns whether the mirrored geometry pass runs.
+    #[must_use]
+    pub fn reflection_pass_enabled(&self) -> bool {
+        self.draw_tile && self.draw_reflection
+    }
+
+    /// Returns the ground shader's shadow mode: 0 = no shadow (tile only, or
+    /// nothing), 1 = shadow only, 2 = tile with shadow.
+    #[must_use]
+    pub fn shader_shadow_mode(&self) -> u32 {
+        match (self.draw_tile, self.draw_shadow) {
+            (_, false) => 0,
+            (false, true) => 1,
+            (true, true) => 2,
+        }
+    }
+
+    /// Returns the world-space Y coordinate of the ground plane.
+    ///
+    /// A relative ground plane follows the scene bounding box, so it moves
+    /// whenever the scene extents are updated. It is lowered by a tiny
+    /// fraction of the length scale to avoid z-fighting with geometry resting
+    /// on it.
+    #[must_use]
+    pub fn world_height(&self, scene_min_y: f32, length_scale: f32) -> f32 {
+        if self.height_is_relative {
+            scene_min_y + (self.height_factor - 0.001) * length_scale
+        } else {
+            self.height
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_round_trips_through_flags() {
+        let mut config = GroundPlaneConfig::default();
+        assert_eq!(config.mode(), GroundPlaneMode::Tile);
+        for mode in [
+            GroundPlaneMode::None,
+            GroundPlaneMode::Tile,
+            GroundPlaneMode::ShadowOnly,
+            GroundPlaneMode::TileReflection,
+        ] {
+            config.set_mode(mode);
+            assert_eq!(config.mode(), mode);
+        }
+
+        config.set_mode(GroundPlaneMode::ShadowOnly);
+        assert!(config.is_visible());
+        assert_eq!(config.shader_shadow_mode(), 1);
+        config.set_mode(GroundPlaneMode::None);
+        assert!(!config.is_visible());
+        assert!(!config.shadow_pass_enabled());
+    }
+
+    #[test]
+    fn test_reflection_needs_tile() {
+        let config = GroundPlaneConfig {
+            draw_tile: false,
+            draw_shadow: false,
+            draw_reflection: true,
+            ..GroundPlaneConfig::default()
+        };
+        assert!(!config.reflection_pass_enabled());
+        assert_eq!(config.mode(), GroundPlaneMode::None);
+    }
+}
@@ -0,0 +1,25 @@
+//! Text labels anchored at world positions.
+
+use glam::Vec3;
+
+/// A text label drawn on screen at the projection of a world position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    /// The text to display.
+    pub text: String,
+    /// The anchor point in world space.
+    pub position: Vec3,
+    /// The text color (RGB, 0-1).
+    pub color: Vec3,
+}
+
+impl Label {
+    /// Creates a white label.
+    pub fn new(text: impl Into<String>, position: Vec3) -> Self {
+        Self {
+            text: text.into(),
+            position,
+            color: Vec3::ONE,
+        }
+    }
+}
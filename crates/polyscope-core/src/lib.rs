@@ -14,10 +14,14 @@
 #![allow(clippy::struct_excessive_bools)]
 #![allow(clippy::struct_field_names)]
 
+pub mod color_space;
+pub mod crop_box;
+pub mod dof;
 pub mod error;
 pub mod gizmo;
 pub mod ground_plane;
 pub mod group;
+pub mod label;
 pub mod marching_cubes;
 pub mod options;
 pub mod pick;
@@ -29,12 +33,16 @@ pub mod state;
 pub mod structure;
 pub mod tone_mapping;
 
+pub use color_space::ColorSpace;
+pub use crop_box::{CropBox, CropBoxUniforms};
+pub use dof::DofConfig;
 pub use error::{PolyscopeError, Result};
 pub use gizmo::{GizmoAxis, GizmoConfig, GizmoMode, GizmoSpace, GizmoUniforms, Transform};
 pub use ground_plane::{GroundPlaneConfig, GroundPlaneMode};
 pub use group::Group;
-pub use marching_cubes::{McmMesh, marching_cubes};
-pub use options::Options;
+pub use label::Label;
+pub use marching_cubes::{McmMesh, marching_cubes, marching_cubes_fn};
+pub use options::{Key, KeyAction, KeyBindings, Options};
 pub use pick::{PickResult, Pickable};
 pub use quantity::{Quantity, QuantityKind};
 pub use registry::Registry;
@@ -42,7 +50,7 @@ pub use slice_plane::{MAX_SLICE_PLANES, SlicePlane, SlicePlaneUniforms};
 pub use ssao::SsaoConfig;
 pub use state::{Context, MaterialLoadRequest, with_context, with_context_mut};
 pub use structure::{HasQuantities, Structure};
-pub use tone_mapping::ToneMappingConfig;
+pub use tone_mapping::{ToneMapOperator, ToneMappingConfig};
 
 // Re-export glam types for convenience
-pub use glam::{Mat4, Vec2, Vec3, Vec4};
+pub use glam::{DVec3, Mat4, Vec2, Vec3, Vec4};
@@ -12,7 +12,7 @@
     clippy::cast_precision_loss
 )]
 
-use glam::Vec3;
+use glam::{UVec3, Vec3};
 
 /// Output mesh from the marching cubes algorithm.
 #[derive(Debug, Clone, Default)]
@@ -37,6 +37,22 @@ impl McmMesh {
     pub fn is_empty(&self) -> bool {
         self.indices.is_empty()
     }
+
+    /// Maps vertices and normals from grid-index space to the axis-aligned
+    /// box `[bound_min, bound_max]` sampled by a grid with `dims` nodes.
+    pub fn map_to_bounds(&mut self, bound_min: Vec3, bound_max: Vec3, dims: UVec3) {
+        let cell_dim = (dims.max(UVec3::splat(2)) - 1).as_vec3();
+        let spacing = (bound_max - bound_min) / cell_dim;
+
+        for v in &mut self.vertices {
+            *v = *v * spacing + bound_min;
+        }
+
+        // Scale normals by inverse spacing to account for non-uniform grids
+        for n in &mut self.normals {
+            *n = (*n / spacing).normalize_or_zero();
+        }
+    }
 }
 
 /// Extracts the isosurface from a 3D scalar field using marching cubes.
@@ -287,6 +303,56 @@ pub fn marching_cubes(field: &[f32], isoval: f32, nx: u32, ny: u32, nz: u32) ->
     mesh
 }
 
+/// Extracts the isosurface of an implicit function using marching cubes.
+///
+/// `f` is sampled at the nodes of a regular grid with `dims` nodes spanning
+/// `[bound_min, bound_max]`; the surface is where `f(p) == isoval`. With the
+/// `rayon` feature enabled the sampling runs in parallel.
+///
+/// Unlike [`marching_cubes`], the returned vertices and normals are already
+/// in world space.
+///
+/// # Panics
+/// Panics if any dimension is less than 2.
+#[must_use]
+pub fn marching_cubes_fn(
+    bound_min: Vec3,
+    bound_max: Vec3,
+    dims: UVec3,
+    isoval: f32,
+    f: impl Fn(Vec3) -> f32 + Sync,
+) -> McmMesh {
+    assert!(
+        dims.x >= 2 && dims.y >= 2 && dims.z >= 2,
+        "All dimensions must be >= 2"
+    );
+
+    // Same layout as `to_index_1d`: (i * ny + j) * nz + k
+    let spacing = (bound_max - bound_min) / (dims - 1).as_vec3();
+    let (ny, nz) = (dims.y as usize, dims.z as usize);
+    let sample = |idx: usize| {
+        let node = UVec3::new(
+            (idx / (ny * nz)) as u32,
+            ((idx / nz) % ny) as u32,
+            (idx % nz) as u32,
+        );
+        f(bound_min + node.as_vec3() * spacing)
+    };
+
+    let len = (dims.x as usize) * ny * nz;
+    #[cfg(feature = "rayon")]
+    let field: Vec<f32> = {
+        use rayon::prelude::*;
+        (0..len).into_par_iter().map(sample).collect()
+    };
+    #[cfg(not(feature = "rayon"))]
+    let field: Vec<f32> = (0..len).map(sample).collect();
+
+    let mut mesh = marching_cubes(&field, isoval, dims.x, dims.y, dims.z);
+    mesh.map_to_bounds(bound_min, bound_max, dims);
+    mesh
+}
+
 /// Converts 3D grid coordinates to a 1D array index.
 /// Layout: `(i * ny + j) * nz + k`
 #[inline]
@@ -492,6 +558,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sphere_closure() {
+        let radius = 0.6;
+        let mesh = marching_cubes_fn(
+            Vec3::splat(-1.0),
+            Vec3::splat(1.0),
+            UVec3::splat(24),
+            0.0,
+            |p| p.length() - radius,
+        );
+
+        assert!(!mesh.vertices.is_empty());
+        assert!(mesh.num_triangles() > 100);
+        let spacing = 2.0 / 23.0;
+        for v in &mesh.vertices {
+            assert!(
+                (v.length() - radius).abs() < spacing,
+                "Vertex {v:?} is {} from origin (radius {radius})",
+                v.length()
+            );
+        }
+        // Normals point outward on a sphere
+        for (v, n) in mesh.vertices.iter().zip(&mesh.normals) {
+            assert!(v.normalize().dot(*n) > 0.9);
+        }
+    }
+
     #[test]
     fn test_single_crossing() {
         // 2x2x2 grid with one corner inside, rest outside
@@ -1,12 +1,19 @@
 //! Configuration options for polyscope.
 
+use std::path::{Path, PathBuf};
+
 use glam::Vec4;
 use serde::{Deserialize, Serialize};
 
-use crate::SsaoConfig;
+use crate::{DofConfig, GroundPlaneConfig, Result, SsaoConfig, ToneMappingConfig};
 
 /// Global configuration options for polyscope.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Options can be persisted across sessions with [`Options::save`] and
+/// [`Options::load`]. Missing fields keep their default values and unknown
+/// fields are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Options {
     /// Whether to automatically compute scene extents.
     pub auto_compute_scene_extents: bool,
@@ -17,25 +24,26 @@ pub struct Options {
     /// Whether to give focus to the polyscope window.
     pub give_focus_on_show: bool,
 
-    /// Whether the ground plane is enabled.
-    pub ground_plane_enabled: bool,
-
-    /// Ground plane mode (shadow, tile, etc.).
-    pub ground_plane_mode: GroundPlaneMode,
-
-    /// Ground plane height (world coordinates).
-    pub ground_plane_height: f32,
+    /// Ground plane mode, placement and shading.
+    pub ground_plane: GroundPlaneConfig,
 
-    /// Background color.
+    /// Background (clear) color. The alpha component is ignored; see
+    /// `transparent_background`.
     pub background_color: Vec4,
 
+    /// Whether screenshots have a transparent background (zero alpha).
+    /// The on-screen background is always opaque.
+    pub transparent_background: bool,
+
     /// Whether to enable transparency.
     pub transparency_enabled: bool,
 
     /// Transparency mode.
     pub transparency_mode: TransparencyMode,
 
-    /// Number of render passes for depth-peeling transparency (Pretty mode).
+    /// Number of peeling passes for depth-peeled transparency (Pretty mode).
+    /// Each pass resolves one more layer of stacked transparent surfaces
+    /// (default 4).
     pub transparency_render_passes: u32,
 
     /// SSAA (supersampling) factor.
@@ -44,8 +52,51 @@ pub struct Options {
     /// Maximum frames per second (0 = unlimited).
     pub max_fps: u32,
 
+    /// Whether the viewer renders on demand instead of every frame.
+    ///
+    /// When set, the window redraws only on input, on `request_redraw()` and
+    /// after scene changes made through the API (transforms, enabling).
+    pub redraw_on_change: bool,
+
     /// SSAO configuration.
     pub ssao: SsaoConfig,
+
+    /// Tone mapping operator, exposure, white level and gamma.
+    pub tone_mapping: ToneMappingConfig,
+
+    /// Depth of field, applied after tone mapping.
+    pub dof: DofConfig,
+
+    /// Global back-face culling override for surface meshes.
+    ///
+    /// `Some(true)` culls back faces of every mesh, `Some(false)` never culls
+    /// (meshes set to cull render back faces like front faces), and `None`
+    /// uses each mesh's own backface policy. Point clouds and curve networks
+    /// are ray-cast impostors without back faces and ignore this.
+    pub backface_cull: Option<bool>,
+
+    /// Color shown where a masked scalar quantity has no data.
+    pub no_data_color: Vec4,
+
+    /// Directory for auto-named screenshots (empty means the current directory).
+    pub screenshot_dir: PathBuf,
+
+    /// Filename prefix for auto-named screenshots (`<prefix>_NNNN.<ext>`).
+    pub screenshot_prefix: String,
+
+    /// File extension for auto-named screenshots, without the dot.
+    pub screenshot_extension: String,
+
+    /// Keyboard shortcuts of the viewer.
+    pub key_bindings: KeyBindings,
+
+    /// Duration in seconds of the camera flight for `look_at_*` requests
+    /// (0 = jump immediately).
+    pub camera_smoothing: f32,
+
+    /// Whether the UI panels are drawn. Hiding them leaves only the scene,
+    /// e.g. for clean screenshots.
+    pub ui_visible: bool,
 }
 
 impl Default for Options {
@@ -54,32 +105,49 @@ impl Default for Options {
             auto_compute_scene_extents: true,
             invoke_user_callback_for_nested_show: false,
             give_focus_on_show: true,
-            ground_plane_enabled: true,
-            ground_plane_mode: GroundPlaneMode::ShadowOnly,
-            ground_plane_height: 0.0,
-            background_color: Vec4::new(1.0, 1.0, 1.0, 1.0),
+            ground_plane: GroundPlaneConfig::default(),
+            background_color: Vec4::new(0.1, 0.1, 0.1, 1.0),
+            transparent_background: false,
             transparency_enabled: true,
             transparency_mode: TransparencyMode::Simple,
-            transparency_render_passes: 8,
+            transparency_render_passes: 4,
             ssaa_factor: 1,
             max_fps: 60,
+            redraw_on_change: false,
             ssao: SsaoConfig::default(),
+            tone_mapping: ToneMappingConfig::default(),
+            dof: DofConfig::default(),
+            backface_cull: None,
+            no_data_color: Vec4::new(0.5, 0.5, 0.5, 1.0),
+            screenshot_dir: PathBuf::new(),
+            screenshot_prefix: "screenshot".to_string(),
+            screenshot_extension: "png".to_string(),
+            key_bindings: KeyBindings::default(),
+            camera_smoothing: 0.0,
+            ui_visible: true,
         }
     }
 }
 
-/// Mode for the ground plane rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub enum GroundPlaneMode {
-    /// No ground plane.
-    None,
-    /// Ground plane with shadow only.
-    #[default]
-    ShadowOnly,
-    /// Ground plane with tile pattern.
-    Tile,
-    /// Ground plane with solid color.
-    SolidColor,
+impl Options {
+    /// Writes all options to a JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Reads options from a JSON file written by [`Self::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not valid JSON.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
 }
 
 /// Mode for transparency rendering.
@@ -89,7 +157,211 @@ pub enum TransparencyMode {
     #[default]
     Simple,
     /// Depth-peeled transparency - correct front-to-back ordering via multiple passes.
+    ///
+    /// Slower than `Simple` (one extra geometry pass per peel, see
+    /// `Options::transparency_render_passes`), but stacked transparent
+    /// layers composite correctly.
     Pretty,
-    /// No transparency.
-    None,
+}
+
+impl From<u32> for TransparencyMode {
+    fn from(v: u32) -> Self {
+        match v {
+            2 => Self::Pretty,
+            _ => Self::Simple,
+        }
+    }
+}
+
+impl From<TransparencyMode> for u32 {
+    fn from(v: TransparencyMode) -> Self {
+        match v {
+            TransparencyMode::Simple => 1,
+            TransparencyMode::Pretty => 2,
+        }
+    }
+}
+
+/// A keyboard key that can be bound to a viewer action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+    /// A letter or digit key. Letters match regardless of case.
+    Char(char),
+    /// A function key, `Function(1)` to `Function(12)`.
+    Function(u8),
+    /// The Home key.
+    Home,
+}
+
+impl Key {
+    /// Returns the key with letters normalized to upper case.
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        match self {
+            Self::Char(c) => Self::Char(c.to_ascii_uppercase()),
+            key => key,
+        }
+    }
+}
+
+/// A viewer action that can be triggered from the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyAction {
+    /// Switch the gizmo to translation mode.
+    GizmoTranslate,
+    /// Switch the gizmo to rotation mode.
+    GizmoRotate,
+    /// Switch the gizmo to scale mode.
+    GizmoScale,
+    /// Take an auto-named screenshot.
+    Screenshot,
+    /// Fit the camera to the scene.
+    ResetView,
+    /// Show or hide the UI panels.
+    ToggleUi,
+}
+
+impl KeyAction {
+    /// All bindable actions.
+    pub const ALL: [Self; 6] = [
+        Self::GizmoTranslate,
+        Self::GizmoRotate,
+        Self::GizmoScale,
+        Self::Screenshot,
+        Self::ResetView,
+        Self::ToggleUi,
+    ];
+}
+
+/// Keyboard shortcuts of the viewer. `None` leaves an action unbound.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    /// Switches the gizmo to translation mode (default `G`).
+    pub gizmo_translate: Option<Key>,
+    /// Switches the gizmo to rotation mode (default `R`).
+    pub gizmo_rotate: Option<Key>,
+    /// Switches the gizmo to scale mode (default `S`).
+    pub gizmo_scale: Option<Key>,
+    /// Takes an auto-named screenshot (default `F12`).
+    pub screenshot: Option<Key>,
+    /// Resets the camera to the home view or, if none is set, fits it to the
+    /// scene (default `Home`).
+    pub reset_view: Option<Key>,
+    /// Shows or hides the UI panels (default `U`).
+    pub toggle_ui: Option<Key>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            gizmo_translate: Some(Key::Char('G')),
+            gizmo_rotate: Some(Key::Char('R')),
+            gizmo_scale: Some(Key::Char('S')),
+            screenshot: Some(Key::Function(12)),
+            reset_view: Some(Key::Home),
+            toggle_ui: Some(Key::Char('U')),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Returns the key bound to `action`.
+    #[must_use]
+    pub fn get(&self, action: KeyAction) -> Option<Key> {
+        match action {
+            KeyAction::GizmoTranslate => self.gizmo_translate,
+            KeyAction::GizmoRotate => self.gizmo_rotate,
+            KeyAction::GizmoScale => self.gizmo_scale,
+            KeyAction::Screenshot => self.screenshot,
+            KeyAction::ResetView => self.reset_view,
+            KeyAction::ToggleUi => self.toggle_ui,
+        }
+    }
+
+    /// Binds `action` to `key`, or unbinds it with `None`.
+    pub fn set(&mut self, action: KeyAction, key: Option<Key>) {
+        let key = key.map(Key::normalized);
+        match action {
+            KeyAction::GizmoTranslate => self.gizmo_translate = key,
+            KeyAction::GizmoRotate => self.gizmo_rotate = key,
+            KeyAction::GizmoScale => self.gizmo_scale = key,
+            KeyAction::Screenshot => self.screenshot = key,
+            KeyAction::ResetView => self.reset_view = key,
+            KeyAction::ToggleUi => self.toggle_ui = key,
+        }
+    }
+
+    /// Returns the action bound to `key`, if any.
+    #[must_use]
+    pub fn action_for(&self, key: Key) -> Option<KeyAction> {
+        let key = key.normalized();
+        KeyAction::ALL
+            .into_iter()
+            .find(|&action| self.get(action).map(Key::normalized) == Some(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_bindings_lookup() {
+        let mut bindings = KeyBindings::default();
+        assert_eq!(
+            bindings.action_for(Key::Char('g')),
+            Some(KeyAction::GizmoTranslate)
+        );
+        assert_eq!(
+            bindings.action_for(Key::Function(12)),
+            Some(KeyAction::Screenshot)
+        );
+        assert_eq!(bindings.action_for(Key::Char('X')), None);
+
+        bindings.set(KeyAction::GizmoRotate, Some(Key::Char('e')));
+        assert_eq!(bindings.get(KeyAction::GizmoRotate), Some(Key::Char('E')));
+        assert_eq!(
+            bindings.action_for(Key::Char('E')),
+            Some(KeyAction::GizmoRotate)
+        );
+        assert_eq!(bindings.action_for(Key::Char('R')), None);
+
+        bindings.set(KeyAction::GizmoScale, None);
+        assert_eq!(bindings.action_for(Key::Char('S')), None);
+    }
+
+    #[test]
+    fn test_options_save_load_round_trip() {
+        let mut options = Options {
+            ssaa_factor: 4,
+            max_fps: 30,
+            background_color: Vec4::new(0.2, 0.3, 0.4, 1.0),
+            transparency_mode: TransparencyMode::Pretty,
+            screenshot_prefix: "frame".to_string(),
+            backface_cull: Some(true),
+            ..Options::default()
+        };
+        options.ssao.enabled = true;
+        options.key_bindings.set(KeyAction::Screenshot, None);
+
+        let path = std::env::temp_dir().join("polyscope_options_round_trip.json");
+        options.save(&path).unwrap();
+        let loaded = Options::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, options);
+    }
+
+    #[test]
+    fn test_options_tolerate_missing_and_unknown_fields() {
+        let json = r#"{ "max_fps": 24, "ssao": { "enabled": true }, "no_such_option": 1 }"#;
+        let options: Options = serde_json::from_str(json).unwrap();
+        assert_eq!(options.max_fps, 24);
+        assert!(options.ssao.enabled);
+        assert_eq!(
+            options.ssao.sample_count,
+            SsaoConfig::default().sample_count
+        );
+        assert_eq!(options.screenshot_prefix, "screenshot");
+    }
 }
@@ -1,6 +1,6 @@
 //! Picking and selection system.
 
-use glam::Vec3;
+use glam::{DVec3, Vec3};
 
 /// Result of a pick/selection operation.
 #[derive(Debug, Clone)]
@@ -19,6 +19,14 @@ pub struct PickResult {
 
     /// The depth of the pick point.
     pub depth: f32,
+
+    /// Barycentric weights of the pick point within the hit triangle
+    /// (surface meshes only).
+    pub barycentric: Option<Vec3>,
+
+    /// The world position in double precision, including the structure's
+    /// origin offset (surface meshes only).
+    pub world_position_f64: Option<DVec3>,
 }
 
 impl PickResult {
@@ -36,6 +44,8 @@ impl PickResult {
             element_index,
             world_position,
             depth,
+            barycentric: None,
+            world_position_f64: None,
         }
     }
 }
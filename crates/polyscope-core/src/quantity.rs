@@ -62,6 +62,11 @@ pub trait Quantity: Any + Send + Sync {
     fn clear_gpu_resources(&mut self) {
         // Default no-op; quantity types with GPU resources override this
     }
+
+    /// Returns the size of the GPU buffers held by this quantity, in bytes.
+    fn gpu_buffer_bytes(&self) -> u64 {
+        0
+    }
 }
 
 /// Marker trait for quantities defined on vertices.
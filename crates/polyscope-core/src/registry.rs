@@ -12,6 +12,8 @@ use crate::structure::Structure;
 pub struct Registry {
     /// Map from type name -> (instance name -> structure)
     structures: HashMap<String, HashMap<String, Box<dyn Structure>>>,
+    /// (draw order, type name, name) of every structure, kept sorted
+    draw_sequence: Vec<(i32, String, String)>,
 }
 
 impl Registry {
@@ -35,6 +37,7 @@ impl Registry {
         }
 
         type_map.insert(name, structure);
+        self.sort_draw_sequence();
         Ok(())
     }
 
@@ -62,19 +65,56 @@ impl Registry {
 
     /// Removes a structure by type and name.
     pub fn remove(&mut self, type_name: &str, name: &str) -> Option<Box<dyn Structure>> {
-        self.structures
+        let removed = self
+            .structures
             .get_mut(type_name)
-            .and_then(|m| m.remove(name))
+            .and_then(|m| m.remove(name));
+        if removed.is_some() {
+            self.sort_draw_sequence();
+        }
+        removed
     }
 
     /// Removes all structures of a given type.
     pub fn remove_all_of_type(&mut self, type_name: &str) {
         self.structures.remove(type_name);
+        self.sort_draw_sequence();
     }
 
     /// Removes all structures from the registry.
     pub fn clear(&mut self) {
         self.structures.clear();
+        self.draw_sequence.clear();
+    }
+
+    /// Sets the draw order of a structure and re-sorts the draw sequence.
+    ///
+    /// Returns `false` if the structure does not exist. Use this rather than
+    /// [`Structure::set_draw_order`] on a registered structure, which leaves
+    /// [`Registry::iter_in_draw_order`] unsorted.
+    pub fn set_draw_order(&mut self, type_name: &str, name: &str, order: i32) -> bool {
+        let Some(structure) = self.get_mut(type_name, name) else {
+            return false;
+        };
+        structure.set_draw_order(order);
+        self.sort_draw_sequence();
+        true
+    }
+
+    /// Rebuilds the draw sequence after structures or their orders change.
+    fn sort_draw_sequence(&mut self) {
+        let mut sequence: Vec<(i32, String, String)> = self
+            .iter()
+            .map(|s| {
+                (
+                    s.draw_order(),
+                    s.type_name().to_string(),
+                    s.name().to_string(),
+                )
+            })
+            .collect();
+        sequence.sort_unstable();
+        self.draw_sequence = sequence;
     }
 
     /// Returns an iterator over all structures.
@@ -85,6 +125,18 @@ impl Registry {
             .map(std::convert::AsRef::as_ref)
     }
 
+    /// Returns all structures sorted by [`Structure::draw_order`].
+    ///
+    /// Structures with equal orders are sorted by type name and then by name,
+    /// so the sequence is the same every frame. The sequence is sorted when
+    /// structures are added or removed or [`Registry::set_draw_order`] is
+    /// called, not on every call.
+    pub fn iter_in_draw_order(&self) -> impl Iterator<Item = &dyn Structure> {
+        self.draw_sequence
+            .iter()
+            .filter_map(|(_, type_name, name)| self.get(type_name, name))
+    }
+
     /// Returns a mutable iterator over all structures.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Box<dyn Structure>> + '_ {
         self.structures.values_mut().flat_map(|m| m.values_mut())
@@ -131,6 +183,7 @@ mod tests {
         type_name: &'static str,
         enabled: bool,
         transform: Mat4,
+        draw_order: i32,
     }
 
     impl MockStructure {
@@ -140,6 +193,7 @@ mod tests {
                 type_name,
                 enabled: true,
                 transform: Mat4::IDENTITY,
+                draw_order: 0,
             }
         }
     }
@@ -175,6 +229,12 @@ mod tests {
         fn set_enabled(&mut self, enabled: bool) {
             self.enabled = enabled;
         }
+        fn draw_order(&self) -> i32 {
+            self.draw_order
+        }
+        fn set_draw_order(&mut self, order: i32) {
+            self.draw_order = order;
+        }
         fn draw(&self, _ctx: &mut dyn crate::structure::RenderContext) {}
         fn draw_pick(&self, _ctx: &mut dyn crate::structure::RenderContext) {}
         fn build_ui(&mut self, _ui: &dyn Any) {}
@@ -304,6 +364,31 @@ mod tests {
         assert!(names.contains(&"c"));
     }
 
+    #[test]
+    fn test_iter_in_draw_order() {
+        let mut reg = Registry::new();
+        reg.register(mock("annotation", "CurveNetwork")).unwrap();
+        reg.register(mock("mesh", "SurfaceMesh")).unwrap();
+        reg.register(mock("points", "PointCloud")).unwrap();
+        assert!(reg.set_draw_order("CurveNetwork", "annotation", 10));
+        assert!(reg.set_draw_order("SurfaceMesh", "mesh", -5));
+        assert!(!reg.set_draw_order("SurfaceMesh", "missing", 1));
+
+        let names: Vec<&str> = reg.iter_in_draw_order().map(Structure::name).collect();
+        assert_eq!(names, ["mesh", "points", "annotation"]);
+
+        // Equal orders fall back to type name, then name
+        reg.set_draw_order("CurveNetwork", "annotation", 0);
+        reg.set_draw_order("SurfaceMesh", "mesh", 0);
+        let names: Vec<&str> = reg.iter_in_draw_order().map(Structure::name).collect();
+        assert_eq!(names, ["annotation", "points", "mesh"]);
+
+        // Removed structures leave the sequence
+        reg.remove("PointCloud", "points");
+        let names: Vec<&str> = reg.iter_in_draw_order().map(Structure::name).collect();
+        assert_eq!(names, ["annotation", "mesh"]);
+    }
+
     #[test]
     fn test_get_all_of_type() {
         let mut reg = Registry::new();
@@ -3,12 +3,13 @@
 //! Slice planes allow visualizing the interior of 3D geometry by
 //! discarding fragments on one side of the plane.
 
-use glam::{Mat4, Vec3, Vec4};
+use glam::{DMat3, DVec3, Mat4, Vec3, Vec4};
 
 /// A slice plane that can cut through geometry.
 ///
 /// The plane is defined by a point (origin) and a normal direction.
-/// Geometry on the negative side of the plane (opposite to normal) is discarded.
+/// Geometry on the negative side of the plane (opposite to normal) is discarded,
+/// unless the plane is inverted.
 #[derive(Debug, Clone)]
 pub struct SlicePlane {
     /// Unique name of the slice plane.
@@ -29,6 +30,10 @@ pub struct SlicePlane {
     transparency: f32,
     /// Size of the plane visualization (half-extent in each direction).
     plane_size: f32,
+    /// Whether the kept side is flipped (geometry on the normal side is discarded).
+    inverted: bool,
+    /// Structures (`type_name`, name) this plane cuts; `None` means all.
+    active_structures: Option<Vec<(String, String)>>,
 }
 
 impl SlicePlane {
@@ -46,6 +51,8 @@ impl SlicePlane {
             color: Vec4::new(0.5, 0.5, 0.5, 1.0),
             transparency: 0.5,
             plane_size: 0.05,
+            inverted: false,
+            active_structures: None,
         }
     }
 
@@ -61,6 +68,8 @@ impl SlicePlane {
             color: Vec4::new(0.5, 0.5, 0.5, 1.0),
             transparency: 0.5,
             plane_size: 0.05,
+            inverted: false,
+            active_structures: None,
         }
     }
 
@@ -164,9 +173,55 @@ impl SlicePlane {
         self.plane_size = size.max(0.001);
     }
 
+    /// Returns whether the kept side is flipped.
+    #[must_use]
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Sets whether to flip the kept side.
+    ///
+    /// Unlike negating the normal, this leaves the plane orientation (and
+    /// the widget) unchanged.
+    pub fn set_inverted(&mut self, inverted: bool) {
+        self.inverted = inverted;
+    }
+
+    /// Returns the normal pointing toward kept geometry, taking inversion
+    /// into account.
+    #[must_use]
+    pub fn kept_normal(&self) -> Vec3 {
+        if self.inverted {
+            -self.normal
+        } else {
+            self.normal
+        }
+    }
+
+    /// Returns the structures this plane cuts, or `None` if it cuts all.
+    #[must_use]
+    pub fn active_structures(&self) -> Option<&[(String, String)]> {
+        self.active_structures.as_deref()
+    }
+
+    /// Restricts the plane to the given (`type_name`, name) structures.
+    ///
+    /// `None` makes the plane cut all structures (the default).
+    pub fn set_active_structures(&mut self, structures: Option<Vec<(String, String)>>) {
+        self.active_structures = structures;
+    }
+
+    /// Returns whether this plane cuts the given structure.
+    #[must_use]
+    pub fn applies_to(&self, type_name: &str, name: &str) -> bool {
+        self.active_structures
+            .as_ref()
+            .is_none_or(|s| s.iter().any(|(t, n)| t == type_name && n == name))
+    }
+
     /// Returns the signed distance from a point to the plane.
     ///
-    /// Positive values are on the normal side (kept), negative on the opposite (discarded).
+    /// Positive values are on the normal side, negative on the opposite side.
     #[must_use]
     pub fn signed_distance(&self, point: Vec3) -> f32 {
         (point - self.origin).dot(self.normal)
@@ -175,7 +230,7 @@ impl SlicePlane {
     /// Returns whether a point is on the kept side of the plane.
     #[must_use]
     pub fn is_kept(&self, point: Vec3) -> bool {
-        !self.enabled || self.signed_distance(point) >= 0.0
+        !self.enabled || (point - self.origin).dot(self.kept_normal()) >= 0.0
     }
 
     /// Projects a point onto the plane.
@@ -233,10 +288,74 @@ impl Default for SlicePlane {
     }
 }
 
+/// Fits a plane to points by principal component analysis.
+///
+/// Returns the centroid and the unit normal, the direction of least
+/// variance. The normal's sign is arbitrary. Returns `None` for fewer than
+/// three points or for points that are all on one line.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fit_plane(points: &[Vec3]) -> Option<(Vec3, Vec3)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let centroid = points.iter().map(Vec3::as_dvec3).sum::<DVec3>() / points.len() as f64;
+    let covariance = points
+        .iter()
+        .map(|p| {
+            let d = p.as_dvec3() - centroid;
+            DMat3::from_cols(d * d.x, d * d.y, d * d.z)
+        })
+        .fold(DMat3::ZERO, |sum, outer| sum + outer);
+
+    let (eigenvalues, eigenvectors) = symmetric_eigen(covariance);
+    let mut order = [0, 1, 2];
+    order.sort_by(|&i, &j| eigenvalues[i].total_cmp(&eigenvalues[j]));
+    // Collinear points leave two directions of zero variance
+    if eigenvalues[order[1]] <= 1e-12 * eigenvalues[order[2]] {
+        return None;
+    }
+    let normal = eigenvectors.col(order[0]).normalize();
+    Some((centroid.as_vec3(), normal.as_vec3()))
+}
+
+/// Eigen-decomposes a symmetric 3x3 matrix with cyclic Jacobi rotations.
+///
+/// Returns the eigenvalues and a matrix whose columns are the matching unit
+/// eigenvectors.
+fn symmetric_eigen(mut matrix: DMat3) -> (DVec3, DMat3) {
+    let diagonal = |m: &DMat3| DVec3::new(m.x_axis.x, m.y_axis.y, m.z_axis.z);
+    let mut eigenvectors = DMat3::IDENTITY;
+    for _ in 0..32 {
+        let off_diagonal = matrix.y_axis.x.abs() + matrix.z_axis.x.abs() + matrix.z_axis.y.abs();
+        if off_diagonal <= 1e-15 * diagonal(&matrix).abs().element_sum() {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            let apq = matrix.col(q)[p];
+            if apq == 0.0 {
+                continue;
+            }
+            // Rotation in the (p, q) plane that zeroes the (p, q) entry
+            let theta = (matrix.col(q)[q] - matrix.col(p)[p]) / (2.0 * apq);
+            let tan = theta.signum() / (theta.abs() + theta.hypot(1.0));
+            let cos = 1.0 / tan.hypot(1.0);
+            let sin = tan * cos;
+            let mut rotation = DMat3::IDENTITY;
+            rotation.col_mut(p)[p] = cos;
+            rotation.col_mut(q)[q] = cos;
+            rotation.col_mut(q)[p] = sin;
+            rotation.col_mut(p)[q] = -sin;
+            matrix = rotation.transpose() * matrix * rotation;
+            eigenvectors *= rotation;
+        }
+    }
+    (diagonal(&matrix), eigenvectors)
+}
+
 /// GPU-compatible slice plane uniforms.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
-#[allow(clippy::pub_underscore_fields)]
 pub struct SlicePlaneUniforms {
     /// Origin point of the plane.
     pub origin: [f32; 3],
@@ -244,8 +363,8 @@ pub struct SlicePlaneUniforms {
     pub enabled: f32,
     /// Normal direction of the plane.
     pub normal: [f32; 3],
-    /// Padding for alignment.
-    pub _padding: f32,
+    /// Which side is kept: 1.0 for the normal side, -1.0 when inverted.
+    pub side: f32,
 }
 
 impl From<&SlicePlane> for SlicePlaneUniforms {
@@ -254,7 +373,7 @@ impl From<&SlicePlane> for SlicePlaneUniforms {
             origin: plane.origin.to_array(),
             enabled: if plane.enabled { 1.0 } else { 0.0 },
             normal: plane.normal.to_array(),
-            _padding: 0.0,
+            side: if plane.inverted { -1.0 } else { 1.0 },
         }
     }
 }
@@ -265,7 +384,7 @@ impl Default for SlicePlaneUniforms {
             origin: [0.0; 3],
             enabled: 0.0,
             normal: [0.0, 1.0, 0.0],
-            _padding: 0.0,
+            side: 1.0,
         }
     }
 }
@@ -326,6 +445,37 @@ mod tests {
         assert_eq!(uniforms.enabled, 1.0);
     }
 
+    #[test]
+    fn test_inverted() {
+        let mut plane = SlicePlane::with_pose("test", Vec3::ZERO, Vec3::Y);
+        assert!(!plane.is_inverted());
+        assert!(SlicePlaneUniforms::from(&plane).side > 0.0);
+
+        plane.set_inverted(true);
+        assert!(plane.is_inverted());
+        assert!(SlicePlaneUniforms::from(&plane).side < 0.0);
+        // The normal itself is unchanged
+        assert_eq!(SlicePlaneUniforms::from(&plane).normal, [0.0, 1.0, 0.0]);
+        assert_eq!(plane.kept_normal(), -Vec3::Y);
+        assert!(!plane.is_kept(Vec3::new(0.0, 1.0, 0.0)));
+        assert!(plane.is_kept(Vec3::new(0.0, -1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_active_structures() {
+        let mut plane = SlicePlane::new("test");
+        assert!(plane.active_structures().is_none());
+        assert!(plane.applies_to("SurfaceMesh", "a"));
+
+        plane.set_active_structures(Some(vec![("SurfaceMesh".into(), "a".into())]));
+        assert!(plane.applies_to("SurfaceMesh", "a"));
+        assert!(!plane.applies_to("SurfaceMesh", "b"));
+        assert!(!plane.applies_to("PointCloud", "a"));
+
+        plane.set_active_structures(None);
+        assert!(plane.applies_to("SurfaceMesh", "b"));
+    }
+
     #[test]
     fn test_to_transform() {
         let plane = SlicePlane::with_pose("test", Vec3::new(1.0, 2.0, 3.0), Vec3::X);
@@ -355,4 +505,30 @@ mod tests {
         // Normal should match (normalized)
         assert!((restored.normal() - original.normal().normalize()).length() < 1e-6);
     }
+
+    #[test]
+    fn test_fit_plane() {
+        // Points on a tilted plane through (1, 2, 3)
+        let normal = Vec3::new(1.0, -2.0, 0.5).normalize();
+        let u = normal.any_orthonormal_vector();
+        let v = normal.cross(u);
+        let origin = Vec3::new(1.0, 2.0, 3.0);
+        let points: Vec<Vec3> = [
+            (-1.0, -1.0),
+            (2.0, 0.5),
+            (0.3, 1.5),
+            (-0.7, 2.0),
+            (1.0, -2.0),
+        ]
+        .into_iter()
+        .map(|(a, b)| origin + u * a + v * b)
+        .collect();
+
+        let (centroid, fitted) = fit_plane(&points).unwrap();
+        assert!(fitted.dot(normal).abs() > 1.0 - 1e-5, "normal {fitted}");
+        assert!((centroid - origin).dot(normal).abs() < 1e-5);
+
+        assert!(fit_plane(&points[..2]).is_none());
+        assert!(fit_plane(&[Vec3::ZERO, Vec3::X, Vec3::X * 2.0]).is_none());
+    }
 }
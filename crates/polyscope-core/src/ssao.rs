@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 
 /// SSAO configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SsaoConfig {
     /// Whether SSAO is enabled.
     pub enabled: bool,
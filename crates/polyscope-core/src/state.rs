@@ -1,14 +1,18 @@
 //! Global state management for polyscope.
 
-use std::collections::HashMap;
-use std::sync::{OnceLock, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::Instant;
 
-use glam::Vec3;
+use glam::{DVec3, Vec3};
 
+use crate::crop_box::CropBox;
 use crate::error::{PolyscopeError, Result};
 use crate::gizmo::GizmoConfig;
 use crate::group::Group;
+use crate::label::Label;
 use crate::options::Options;
+use crate::pick::PickResult;
 use crate::quantity::Quantity;
 use crate::registry::Registry;
 use crate::slice_plane::SlicePlane;
@@ -16,7 +20,23 @@ use crate::slice_plane::SlicePlane;
 /// Callback type for file drop events.
 pub type FileDropCallback = Box<dyn FnMut(&[std::path::PathBuf]) + Send + Sync>;
 
-/// A deferred request to load a material from disk.
+/// Callback type for the per-frame user callback.
+///
+/// Stored behind its own mutex so the viewer can run it without holding the
+/// context lock.
+pub type UserCallback = Arc<Mutex<Box<dyn FnMut() + Send>>>;
+
+/// Callback type for resolved left-click picks.
+///
+/// Receives `None` when the click hit the background.
+pub type PickCallback = Arc<Mutex<Box<dyn FnMut(Option<PickResult>) + Send>>>;
+
+/// Callback type for structure selection changes.
+///
+/// Receives the new `(type_name, name)` selection, or `None` after deselection.
+pub type SelectionCallback = Arc<Mutex<Box<dyn FnMut(Option<(String, String)>) + Send>>>;
+
+/// A deferred request to load a material, from disk or from memory.
 #[derive(Debug, Clone)]
 pub enum MaterialLoadRequest {
     /// Load a static material from a single file.
@@ -26,6 +46,13 @@ pub enum MaterialLoadRequest {
         name: String,
         filenames: [String; 4],
     },
+    /// Load a static material from 8-bit RGBA pixels, row by row from the top.
+    Image {
+        name: String,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    },
 }
 
 /// Global context singleton.
@@ -45,6 +72,9 @@ pub struct Context {
     /// Slice planes for cutting through geometry.
     pub slice_planes: HashMap<String, SlicePlane>,
 
+    /// Box that all structures are clipped to, if set.
+    pub crop_box: Option<CropBox>,
+
     /// Gizmo configuration for transformation controls.
     pub gizmo_config: GizmoConfig,
 
@@ -57,20 +87,58 @@ pub struct Context {
     /// Global options.
     pub options: Options,
 
+    /// Structures (`type_name`, name) whose bounding box wireframe is drawn.
+    pub bbox_visible: HashSet<(String, String)>,
+
     /// Representative length scale for all registered structures.
     pub length_scale: f32,
 
+    /// Whether `length_scale` was set by the user; extent updates then keep
+    /// it instead of recomputing it from the structures.
+    pub length_scale_is_manual: bool,
+
     /// Axis-aligned bounding box for all registered structures.
     pub bounding_box: (Vec3, Vec3),
 
+    /// Double-precision world position that scene coordinates are relative
+    /// to, set by the first structure registered from `f64` positions.
+    pub scene_origin: Option<DVec3>,
+
+    /// Text labels drawn at world positions, by name.
+    pub labels: HashMap<String, Label>,
+
     /// Floating quantities (not attached to any structure).
     pub floating_quantities: Vec<Box<dyn Quantity>>,
 
     /// Callback invoked when files are dropped onto the window.
     pub file_drop_callback: Option<FileDropCallback>,
 
+    /// Callback invoked once per frame, before rendering.
+    pub user_callback: Option<UserCallback>,
+
+    /// Callback invoked when a left-click pick is resolved.
+    pub pick_callback: Option<PickCallback>,
+
+    /// Callback invoked when the selected structure changes.
+    pub selection_callback: Option<SelectionCallback>,
+
     /// Deferred material load requests (processed by App each frame).
     pub material_load_queue: Vec<MaterialLoadRequest>,
+
+    /// Whether planar (2D) data has been registered.
+    ///
+    /// When set, the initial camera fit switches from the default turntable
+    /// navigation to planar navigation.
+    pub planar_data_hint: bool,
+
+    /// Number of frames started since initialization.
+    pub frame_count: u64,
+
+    /// Seconds from `start_time` to the start of the current frame.
+    pub elapsed_seconds: f32,
+
+    /// Reference time for `elapsed_seconds` (context creation).
+    pub start_time: Instant,
 }
 
 impl Default for Context {
@@ -80,20 +148,39 @@ impl Default for Context {
             registry: Registry::new(),
             groups: HashMap::new(),
             slice_planes: HashMap::new(),
+            crop_box: None,
             gizmo_config: GizmoConfig::default(),
             selected_structure: None,
             selected_slice_plane: None,
             options: Options::default(),
             length_scale: 1.0,
+            length_scale_is_manual: false,
             bounding_box: (Vec3::ZERO, Vec3::ONE),
+            scene_origin: None,
+            labels: HashMap::new(),
             floating_quantities: Vec::new(),
             file_drop_callback: None,
+            user_callback: None,
+            pick_callback: None,
+            selection_callback: None,
             material_load_queue: Vec::new(),
+            bbox_visible: HashSet::new(),
+            planar_data_hint: false,
+            frame_count: 0,
+            elapsed_seconds: 0.0,
+            start_time: Instant::now(),
         }
     }
 }
 
 impl Context {
+    /// Starts a new frame: increments `frame_count` and samples
+    /// `elapsed_seconds`, which then stays fixed for the whole frame.
+    pub fn advance_frame(&mut self) {
+        self.frame_count += 1;
+        self.elapsed_seconds = self.start_time.elapsed().as_secs_f32();
+    }
+
     /// Computes the center of the bounding box.
     #[must_use]
     pub fn center(&self) -> Vec3 {
@@ -115,7 +202,8 @@ impl Context {
     /// Unconditionally recomputes extents from all registered structures.
     ///
     /// Called by `update_extents()` when auto-compute is enabled, and also
-    /// called directly when the user re-enables auto-compute.
+    /// called directly when the user re-enables auto-compute. A manual length
+    /// scale (see [`Self::set_length_scale`]) is kept.
     pub fn recompute_extents(&mut self) {
         let mut min = Vec3::splat(f32::MAX);
         let mut max = Vec3::splat(f32::MIN);
@@ -129,27 +217,46 @@ impl Context {
             }
         }
 
-        if has_extent {
+        let length_scale = if has_extent {
             self.bounding_box = (min, max);
-            self.length_scale = (max - min).length();
+            let length_scale = (max - min).length();
 
             // Handle degenerate bounding box (all points coincide).
             // Matches C++ Polyscope commit 3198ab5 — tolerance 1e-3.
             if min == max {
-                let offset_scale = if self.length_scale == 0.0 {
+                let offset_scale = if length_scale == 0.0 {
                     1e-3
                 } else {
-                    self.length_scale * 1e-3
+                    length_scale * 1e-3
                 };
                 let offset = Vec3::splat(offset_scale / 2.0);
                 self.bounding_box = (min - offset, max + offset);
             }
+            length_scale
         } else {
             self.bounding_box = (Vec3::ZERO, Vec3::ONE);
-            self.length_scale = 1.0;
+            1.0
+        };
+
+        if !self.length_scale_is_manual {
+            self.length_scale = length_scale;
         }
     }
 
+    /// Fixes the length scale at `scale`; extent updates keep it until
+    /// [`Self::reset_length_scale`] is called.
+    pub fn set_length_scale(&mut self, scale: f32) {
+        self.length_scale = scale;
+        self.length_scale_is_manual = true;
+    }
+
+    /// Returns to the automatic length scale, recomputed from all structures
+    /// (subject to `auto_compute_scene_extents`).
+    pub fn reset_length_scale(&mut self) {
+        self.length_scale_is_manual = false;
+        self.update_extents();
+    }
+
     /// Creates a new group.
     pub fn create_group(&mut self, name: &str) -> &mut Group {
         self.groups
@@ -200,6 +307,21 @@ impl Context {
             && self.is_structure_visible_in_groups(structure.type_name(), structure.name())
     }
 
+    /// Returns the world-space bounding boxes of the visible structures whose
+    /// bounding box wireframe is enabled.
+    #[must_use]
+    pub fn bounding_box_wireframes(&self) -> Vec<(Vec3, Vec3)> {
+        self.registry
+            .iter()
+            .filter(|s| {
+                self.bbox_visible
+                    .contains(&(s.type_name().to_string(), s.name().to_string()))
+                    && self.is_structure_visible(*s)
+            })
+            .filter_map(crate::Structure::bounding_box)
+            .collect()
+    }
+
     /// Checks if a structure should be visible based on its group membership.
     ///
     /// A structure is visible if:
@@ -293,6 +415,29 @@ impl Context {
         self.slice_planes.values().filter(|sp| sp.is_enabled())
     }
 
+    /// Returns a bitmask of the slice plane slots that do not cut the given
+    /// structure.
+    ///
+    /// Bit `i` refers to the `i`-th plane of [`Self::slice_planes`], which is
+    /// the order in which planes are uploaded to the GPU.
+    ///
+    /// Structures that ignore slice planes get every bit set.
+    #[must_use]
+    pub fn slice_plane_mask(&self, type_name: &str, name: &str) -> u32 {
+        if self
+            .registry
+            .get(type_name, name)
+            .is_some_and(crate::Structure::ignores_slice_planes)
+        {
+            return u32::MAX;
+        }
+        self.slice_planes()
+            .take(crate::slice_plane::MAX_SLICE_PLANES)
+            .enumerate()
+            .filter(|(_, plane)| !plane.applies_to(type_name, name))
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+
     // ========================================================================
     // Gizmo and Selection Management
     // ========================================================================
@@ -447,10 +592,49 @@ pub fn shutdown_context() {
             ctx.registry.clear();
             ctx.groups.clear();
             ctx.slice_planes.clear();
+            ctx.crop_box = None;
             ctx.selected_structure = None;
             ctx.selected_slice_plane = None;
             ctx.floating_quantities.clear();
             ctx.material_load_queue.clear();
+            ctx.planar_data_hint = false;
+            ctx.user_callback = None;
+            ctx.pick_callback = None;
+            ctx.selection_callback = None;
+            ctx.frame_count = 0;
+            ctx.elapsed_seconds = 0.0;
+            ctx.start_time = Instant::now();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_frame() {
+        let mut ctx = Context::default();
+        assert_eq!(ctx.frame_count, 0);
+        assert!(ctx.elapsed_seconds.abs() < f32::EPSILON);
+
+        ctx.start_time -= std::time::Duration::from_secs(2);
+        ctx.advance_frame();
+        ctx.advance_frame();
+        assert_eq!(ctx.frame_count, 2);
+        assert!(ctx.elapsed_seconds >= 2.0);
+    }
+
+    #[test]
+    fn test_manual_length_scale_survives_extent_updates() {
+        let mut ctx = Context::default();
+        ctx.set_length_scale(4.0);
+        ctx.update_extents();
+        assert!((ctx.length_scale - 4.0).abs() < f32::EPSILON);
+
+        // With no structures the automatic scale is 1
+        ctx.reset_length_scale();
+        assert!(!ctx.length_scale_is_manual);
+        assert!((ctx.length_scale - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -49,6 +49,36 @@ pub trait Structure: Any + Send + Sync {
     /// Sets the visibility of this structure.
     fn set_enabled(&mut self, enabled: bool);
 
+    /// Returns whether this structure is drawn uncut by all slice planes.
+    fn ignores_slice_planes(&self) -> bool {
+        false
+    }
+
+    /// Sets whether this structure ignores all slice planes.
+    ///
+    /// Unlike per-plane structure targeting, this is a blanket opt-out: when
+    /// set, no slice plane cuts the structure.
+    fn set_ignore_slice_planes(&mut self, _ignore: bool) {
+        // Default no-op; structures that can be sliced override this
+    }
+
+    /// Returns the draw order of this structure (default 0).
+    ///
+    /// Within each render pass, structures with a lower order are drawn first.
+    /// Passes are per structure type, so this does not reorder types.
+    fn draw_order(&self) -> i32 {
+        0
+    }
+
+    /// Sets the draw order of this structure.
+    ///
+    /// For a registered structure call
+    /// [`Registry::set_draw_order`](crate::Registry::set_draw_order) instead,
+    /// which keeps the registry's draw sequence sorted.
+    fn set_draw_order(&mut self, _order: i32) {
+        // Default no-op; drawable structures override this
+    }
+
     /// Draws this structure to the scene.
     ///
     /// Called during the main render pass.
@@ -87,6 +117,14 @@ pub trait Structure: Any + Send + Sync {
         // Default no-op; each structure type overrides this
     }
 
+    /// Returns the size of the GPU buffers held by this structure and its
+    /// quantities, in bytes.
+    ///
+    /// Zero until the structure is first rendered.
+    fn gpu_buffer_bytes(&self) -> u64 {
+        0
+    }
+
     /// Returns the material name for this structure (e.g., "clay", "wax").
     #[allow(clippy::unnecessary_literal_bound)]
     fn material(&self) -> &str {
@@ -120,6 +158,19 @@ pub trait HasQuantities: Structure {
     fn num_quantities(&self) -> usize {
         self.quantities().len()
     }
+
+    /// Returns the names of all attached quantities, in insertion order.
+    fn quantity_names(&self) -> Vec<String> {
+        self.quantities()
+            .iter()
+            .map(|q| q.name().to_string())
+            .collect()
+    }
+
+    /// Returns whether a quantity with the given name is attached.
+    fn has_quantity(&self, name: &str) -> bool {
+        self.quantities().iter().any(|q| q.name() == name)
+    }
 }
 
 /// Trait for render context - will be implemented in polyscope-render.
@@ -2,10 +2,49 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Operator that maps HDR scene colors to displayable colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ToneMapOperator {
+    /// No compression: colors are clamped and gamma encoded.
+    None,
+    /// Extended Reinhard with a white point (default).
+    #[default]
+    Reinhard,
+    /// ACES filmic curve (Narkowicz fit), with more contrast and saturation.
+    Aces,
+    /// Hable's "Uncharted 2" filmic curve.
+    Filmic,
+}
+
+impl From<u32> for ToneMapOperator {
+    fn from(v: u32) -> Self {
+        match v {
+            0 => Self::None,
+            2 => Self::Aces,
+            3 => Self::Filmic,
+            _ => Self::Reinhard,
+        }
+    }
+}
+
+impl From<ToneMapOperator> for u32 {
+    fn from(v: ToneMapOperator) -> Self {
+        match v {
+            ToneMapOperator::None => 0,
+            ToneMapOperator::Reinhard => 1,
+            ToneMapOperator::Aces => 2,
+            ToneMapOperator::Filmic => 3,
+        }
+    }
+}
+
 /// Tone mapping configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ToneMappingConfig {
-    /// Exposure multiplier (default 1.0).
+    /// Tone mapping operator (default Reinhard).
+    pub operator: ToneMapOperator,
+    /// Exposure multiplier (default 1.1).
     pub exposure: f32,
     /// White level for highlight compression (default 1.0).
     pub white_level: f32,
@@ -16,7 +55,8 @@ pub struct ToneMappingConfig {
 impl Default for ToneMappingConfig {
     fn default() -> Self {
         Self {
-            exposure: 1.0,
+            operator: ToneMapOperator::Reinhard,
+            exposure: 1.1,
             white_level: 1.0,
             gamma: 2.2,
         }
@@ -30,6 +70,13 @@ impl ToneMappingConfig {
         Self::default()
     }
 
+    /// Sets the tone mapping operator.
+    #[must_use]
+    pub fn with_operator(mut self, operator: ToneMapOperator) -> Self {
+        self.operator = operator;
+        self
+    }
+
     /// Sets the exposure value.
     #[must_use]
     pub fn with_exposure(mut self, exposure: f32) -> Self {
@@ -1,10 +1,11 @@
 //! Camera and view management.
 
 use glam::{Mat3, Mat4, Quat, Vec3};
+use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 /// Camera navigation/interaction style.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum NavigationStyle {
     /// Turntable - orbits around target, constrained to up direction.
     #[default]
@@ -48,7 +49,7 @@ impl From<NavigationStyle> for u32 {
 }
 
 /// Camera projection mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ProjectionMode {
     /// Perspective projection.
     #[default]
@@ -76,7 +77,7 @@ impl From<ProjectionMode> for u32 {
 }
 
 /// Axis direction for up/front vectors.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum AxisDirection {
     /// Positive X axis.
     PosX,
@@ -223,6 +224,51 @@ pub struct CameraFlight {
     target_dist: f32,
 }
 
+/// Persistent camera view, as written by [`Camera::to_json`].
+///
+/// Missing fields take the default camera's values and unknown fields are
+/// ignored, so files stay loadable across versions.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct CameraViewJson {
+    position: Vec3,
+    target: Vec3,
+    up: Vec3,
+    up_direction: AxisDirection,
+    front_direction: AxisDirection,
+    /// Vertical field of view in radians.
+    fov: f32,
+    near: f32,
+    far: f32,
+    projection_mode: ProjectionMode,
+    navigation_style: NavigationStyle,
+    ortho_scale: f32,
+}
+
+impl Default for CameraViewJson {
+    fn default() -> Self {
+        Self::from(&Camera::default())
+    }
+}
+
+impl From<&Camera> for CameraViewJson {
+    fn from(camera: &Camera) -> Self {
+        Self {
+            position: camera.position,
+            target: camera.target,
+            up: camera.up,
+            up_direction: camera.up_direction,
+            front_direction: camera.front_direction,
+            fov: camera.fov,
+            near: camera.near,
+            far: camera.far,
+            projection_mode: camera.projection_mode,
+            navigation_style: camera.navigation_style,
+            ortho_scale: camera.ortho_scale,
+        }
+    }
+}
+
 /// A 3D camera for viewing the scene.
 #[derive(Debug, Clone)]
 pub struct Camera {
@@ -278,6 +324,39 @@ impl Camera {
         }
     }
 
+    /// Serializes the camera view (position, target, orientation, projection,
+    /// and navigation settings) to a JSON string.
+    ///
+    /// The aspect ratio and any in-progress flight are not saved.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&CameraViewJson::from(self))
+            .expect("camera view serialization cannot fail")
+    }
+
+    /// Restores a camera from a JSON string written by [`Self::to_json`].
+    ///
+    /// Missing fields keep their default values and unknown fields are
+    /// ignored. The aspect ratio is left at its default; callers should set
+    /// it to match their viewport.
+    pub fn from_json(json: &str) -> polyscope_core::Result<Self> {
+        let view: CameraViewJson = serde_json::from_str(json)?;
+        Ok(Self {
+            position: view.position,
+            target: view.target,
+            up: view.up,
+            fov: view.fov,
+            near: view.near,
+            far: view.far,
+            navigation_style: view.navigation_style,
+            projection_mode: view.projection_mode,
+            up_direction: view.up_direction,
+            front_direction: view.front_direction,
+            ortho_scale: view.ortho_scale,
+            ..Self::default()
+        })
+    }
+
     /// Sets the aspect ratio.
     pub fn set_aspect_ratio(&mut self, aspect_ratio: f32) {
         self.aspect_ratio = aspect_ratio;
@@ -325,6 +404,27 @@ impl Camera {
         self.projection_matrix() * self.view_matrix()
     }
 
+    /// Returns the world-space ray `(origin, direction)` through a screen
+    /// position.
+    ///
+    /// `(x, y)` is in pixels with the origin at the top-left corner of the
+    /// viewport and y pointing down; `viewport` is the `(width, height)` in
+    /// pixels. Use `x + 0.5, y + 0.5` to go through the center of a pixel.
+    /// The origin lies on the near clip plane and the direction is normalized.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn screen_ray(&self, x: f32, y: f32, viewport: (u32, u32)) -> (Vec3, Vec3) {
+        let width = viewport.0.max(1) as f32;
+        let height = viewport.1.max(1) as f32;
+        let ndc_x = 2.0 * x / width - 1.0;
+        let ndc_y = 1.0 - 2.0 * y / height;
+
+        let inverse = self.view_projection_matrix().inverse();
+        let near = inverse.project_point3(Vec3::new(ndc_x, ndc_y, 0.0));
+        let far = inverse.project_point3(Vec3::new(ndc_x, ndc_y, 1.0));
+        (near, (far - near).normalize())
+    }
+
     /// Returns the camera's forward direction.
     #[must_use]
     pub fn forward(&self) -> Vec3 {
@@ -647,6 +747,18 @@ impl Camera {
         self.ortho_scale = half_height.max(0.1);
     }
 
+    /// Frames a bounding box like [`Self::look_at_box`], but views it along the
+    /// current front direction with the current up direction.
+    ///
+    /// Distance, clip planes, and orthographic scale match `look_at_box`, so
+    /// framing a structure behaves like the initial scene fit.
+    pub fn look_at_box_from_front(&mut self, min: Vec3, max: Vec3) {
+        self.look_at_box(min, max);
+        let distance = (self.position - self.target).length();
+        self.up = self.up_direction.to_vec3();
+        self.position = self.target - self.front_direction.to_vec3() * distance;
+    }
+
     /// Sets the navigation style.
     pub fn set_navigation_style(&mut self, style: NavigationStyle) {
         self.navigation_style = style;
@@ -747,6 +859,31 @@ impl Camera {
         });
     }
 
+    /// Frames a bounding box like [`Self::look_at_box_from_front`], flying
+    /// there over `duration_secs` instead of jumping.
+    ///
+    /// Clip planes and orthographic scale switch immediately; position,
+    /// orientation and orbit distance are interpolated by [`Self::update_flight`].
+    pub fn fly_to_box_from_front(&mut self, min: Vec3, max: Vec3, duration_secs: f32) {
+        let mut target = self.clone();
+        target.look_at_box_from_front(min, max);
+        self.near = target.near;
+        self.far = target.far;
+        self.ortho_scale = target.ortho_scale;
+        self.start_flight_to(target.view_matrix(), target.fov, duration_secs);
+        if let Some(flight) = &mut self.flight {
+            flight.target_dist = (target.position - target.target).length().max(0.01);
+        }
+    }
+
+    /// Returns the `(position, target, up)` pose the active flight ends at.
+    #[must_use]
+    pub fn flight_target(&self) -> Option<(Vec3, Vec3, Vec3)> {
+        self.flight.as_ref().map(|flight| {
+            Self::camera_from_inverse_view(&flight.target_rot, &flight.target_t, flight.target_dist)
+        })
+    }
+
     /// Updates the camera flight animation. Call once per frame.
     ///
     /// When the flight completes, the camera is set exactly to the target
@@ -858,6 +995,92 @@ mod tests {
         assert!(camera.fov < std::f32::consts::PI);
     }
 
+    #[test]
+    fn test_look_at_box_from_front() {
+        let mut camera = Camera::new(1.0);
+        let (min, max) = (Vec3::splat(-1.0), Vec3::splat(1.0));
+
+        // Default orientation matches look_at_box
+        let mut reference = camera.clone();
+        reference.look_at_box(min, max);
+        camera.look_at_box_from_front(min, max);
+        assert!((camera.position - reference.position).length() < 1e-5);
+        assert_eq!(camera.target, reference.target);
+
+        // Z-up looks along +X, keeping the same distance
+        camera.set_up_direction(AxisDirection::PosZ);
+        camera.look_at_box_from_front(min, max);
+        let offset = camera.position - camera.target;
+        assert!(offset.normalize().abs_diff_eq(Vec3::NEG_X, 1e-5));
+        assert!((offset.length() - (reference.position - reference.target).length()).abs() < 1e-5);
+        assert_eq!(camera.up, Vec3::Z);
+    }
+
+    #[test]
+    fn test_fly_to_box_from_front() {
+        let mut camera = Camera::new(1.0);
+        let (min, max) = (Vec3::new(4.0, 0.0, 0.0), Vec3::new(6.0, 2.0, 2.0));
+        let mut jumped = camera.clone();
+        jumped.look_at_box_from_front(min, max);
+
+        // The flight stores the framed pose as its target without moving yet
+        let start = camera.position;
+        camera.fly_to_box_from_front(min, max, 10.0);
+        assert_eq!(camera.position, start);
+        let (position, target, up) = camera.flight_target().unwrap();
+        assert!((position - jumped.position).length() < 1e-4);
+        assert!((target - jumped.target).length() < 1e-4);
+        assert!((up - jumped.up).length() < 1e-4);
+
+        // A finished flight lands exactly on the framed pose
+        camera.fly_to_box_from_front(min, max, 1e-6);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        camera.update_flight();
+        assert!(!camera.is_in_flight());
+        assert!((camera.position - jumped.position).length() < 1e-4);
+        assert!((camera.target - jumped.target).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_camera_json_round_trip() {
+        let mut camera = Camera::new(1.0);
+        camera.position = Vec3::new(1.5, -2.0, 7.25);
+        camera.target = Vec3::new(0.5, 0.25, -1.0);
+        camera.set_up_direction(AxisDirection::NegZ);
+        camera.up = Vec3::new(0.0, 0.6, -0.8);
+        camera.front_direction = AxisDirection::PosY;
+        camera.fov = 1.1;
+        camera.near = 0.05;
+        camera.far = 250.0;
+        camera.projection_mode = ProjectionMode::Orthographic;
+        camera.navigation_style = NavigationStyle::Arcball;
+        camera.ortho_scale = 3.5;
+
+        let restored = Camera::from_json(&camera.to_json()).unwrap();
+        assert!(restored.position.abs_diff_eq(camera.position, 1e-6));
+        assert!(restored.target.abs_diff_eq(camera.target, 1e-6));
+        assert!(restored.up.abs_diff_eq(camera.up, 1e-6));
+        assert_eq!(restored.up_direction, camera.up_direction);
+        assert_eq!(restored.front_direction, camera.front_direction);
+        assert!((restored.fov - camera.fov).abs() < 1e-6);
+        assert!((restored.near - camera.near).abs() < 1e-6);
+        assert!((restored.far - camera.far).abs() < 1e-4);
+        assert_eq!(restored.projection_mode, camera.projection_mode);
+        assert_eq!(restored.navigation_style, camera.navigation_style);
+        assert!((restored.ortho_scale - camera.ortho_scale).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_camera_json_missing_and_unknown_fields() {
+        let camera = Camera::from_json(r#"{ "fov": 0.5, "future_field": [1, 2, 3] }"#).unwrap();
+        let default = Camera::default();
+        assert!((camera.fov - 0.5).abs() < 1e-6);
+        assert_eq!(camera.position, default.position);
+        assert_eq!(camera.navigation_style, default.navigation_style);
+
+        assert!(Camera::from_json("not json").is_err());
+    }
+
     #[test]
     fn test_zoom_perspective() {
         let mut camera = Camera::new(1.0);
@@ -890,4 +1113,24 @@ mod tests {
             "Orthographic zoom in should decrease scale"
         );
     }
+
+    #[test]
+    fn test_screen_ray() {
+        let mut camera = Camera::new(2.0);
+        camera.position = Vec3::new(1.0, 2.0, 5.0);
+        camera.target = Vec3::new(1.0, 2.0, 0.0);
+
+        // The center of the viewport looks along the view direction
+        let (origin, dir) = camera.screen_ray(400.0, 200.0, (800, 400));
+        assert!((dir - camera.forward()).length() < 1e-4);
+        assert!((origin - (camera.position + camera.forward() * camera.near)).length() < 1e-3);
+
+        // The top-left corner points up and to the left
+        let (_, corner) = camera.screen_ray(0.0, 0.0, (800, 400));
+        assert!(corner.x < 0.0 && corner.y > 0.0 && corner.z < 0.0);
+
+        camera.projection_mode = ProjectionMode::Orthographic;
+        let (_, dir) = camera.screen_ray(0.0, 400.0, (800, 400));
+        assert!((dir - camera.forward()).length() < 1e-4);
+    }
 }
@@ -3,6 +3,10 @@
 use std::collections::HashMap;
 
 use glam::Vec3;
+use polyscope_core::PolyscopeError;
+
+/// Number of evenly spaced samples used for color maps built from control points.
+const CONTROL_POINT_SAMPLES: usize = 256;
 
 /// A color map for mapping scalar values to colors.
 #[derive(Debug, Clone)]
@@ -22,6 +26,67 @@ impl ColorMap {
         }
     }
 
+    /// Builds a color map by piecewise-linear interpolation between control points.
+    ///
+    /// Each control point is a position in `[0, 1]` and an RGB color. Points may
+    /// be given in any order; they are sorted by position. Values before the
+    /// first or after the last point take that point's color.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::InvalidColorMap`] if fewer than two points are
+    /// given or a position is outside `[0, 1]`.
+    pub fn from_control_points(
+        name: impl Into<String>,
+        control_points: &[(f32, [f32; 3])],
+    ) -> polyscope_core::Result<Self> {
+        let name = name.into();
+        if control_points.len() < 2 {
+            return Err(PolyscopeError::InvalidColorMap(format!(
+                "'{name}' needs at least 2 control points, got {}",
+                control_points.len()
+            )));
+        }
+        if let Some((t, _)) = control_points
+            .iter()
+            .find(|(t, _)| !(0.0..=1.0).contains(t))
+        {
+            return Err(PolyscopeError::InvalidColorMap(format!(
+                "'{name}' has control point position {t} outside [0, 1]"
+            )));
+        }
+
+        let mut points: Vec<(f32, Vec3)> = control_points
+            .iter()
+            .map(|&(t, rgb)| (t, Vec3::from(rgb)))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let last = CONTROL_POINT_SAMPLES - 1;
+        let colors = (0..CONTROL_POINT_SAMPLES)
+            .map(|i| {
+                let t = i as f32 / last as f32;
+                let upper = points.partition_point(|(pt, _)| *pt < t);
+                if upper == 0 {
+                    return points[0].1;
+                }
+                if upper == points.len() {
+                    return points[points.len() - 1].1;
+                }
+                let (t0, c0) = points[upper - 1];
+                let (t1, c1) = points[upper];
+                let span = t1 - t0;
+                if span <= f32::EPSILON {
+                    c1
+                } else {
+                    c0.lerp(c1, (t - t0) / span)
+                }
+            })
+            .collect();
+
+        Ok(Self::new(name, colors))
+    }
+
     /// Samples the color map at a given value (0 to 1).
     #[must_use]
     pub fn sample(&self, t: f32) -> Vec3 {
@@ -142,6 +207,23 @@ impl ColorMapRegistry {
         self.color_maps.insert(color_map.name.clone(), color_map);
     }
 
+    /// Registers a custom color map built from control points.
+    ///
+    /// See [`ColorMap::from_control_points`]. A map with the same name
+    /// (including a built-in one) is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the control points are invalid.
+    pub fn register_custom(
+        &mut self,
+        name: &str,
+        control_points: &[(f32, [f32; 3])],
+    ) -> polyscope_core::Result<()> {
+        self.register(ColorMap::from_control_points(name, control_points)?);
+        Ok(())
+    }
+
     /// Gets a color map by name.
     #[must_use]
     pub fn get(&self, name: &str) -> Option<&ColorMap> {
@@ -153,3 +235,42 @@ impl ColorMapRegistry {
         self.color_maps.keys().map(std::string::String::as_str)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_custom_color_map() {
+        let mut registry = ColorMapRegistry::new();
+        registry
+            .register_custom("gray", &[(1.0, [1.0, 1.0, 1.0]), (0.0, [0.0, 0.0, 0.0])])
+            .unwrap();
+        let gray = registry.get("gray").unwrap();
+        assert!((gray.sample(0.5) - Vec3::splat(0.5)).length() < 1e-5);
+        assert_eq!(gray.sample(0.0), Vec3::ZERO);
+        assert_eq!(gray.sample(1.0), Vec3::ONE);
+
+        // Three-point diverging map hits the middle color exactly
+        registry
+            .register_custom(
+                "diverging",
+                &[
+                    (0.0, [0.0, 0.0, 1.0]),
+                    (0.5, [1.0, 1.0, 1.0]),
+                    (1.0, [1.0, 0.0, 0.0]),
+                ],
+            )
+            .unwrap();
+        let diverging = registry.get("diverging").unwrap();
+        assert!((diverging.sample(0.5) - Vec3::ONE).length() < 1e-2);
+
+        assert!(registry.register_custom("one", &[(0.0, [0.0; 3])]).is_err());
+        assert!(
+            registry
+                .register_custom("out", &[(0.0, [0.0; 3]), (1.5, [1.0; 3])])
+                .is_err()
+        );
+        assert!(registry.get("one").is_none());
+    }
+}
@@ -9,7 +9,6 @@ use crate::point_cloud_render::PointUniforms;
 /// Layout must match WGSL `CurveNetworkUniforms` exactly (32 bytes).
 #[repr(C)]
 #[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
-#[allow(clippy::pub_underscore_fields)]
 pub struct CurveNetworkUniforms {
     /// Base color (RGBA)
     pub color: [f32; 4],
@@ -19,8 +18,8 @@ pub struct CurveNetworkUniforms {
     pub radius_is_relative: u32,
     /// Render mode: 0 = line, 1 = tube (cylinder)
     pub render_mode: u32,
-    /// Padding to 16-byte alignment
-    pub _padding: f32,
+    /// Transparency (0.0 = opaque, 1.0 = fully transparent)
+    pub transparency: f32,
 }
 
 impl Default for CurveNetworkUniforms {
@@ -30,7 +29,7 @@ impl Default for CurveNetworkUniforms {
             radius: 0.005,
             radius_is_relative: 1,
             render_mode: 0, // lines by default
-            _padding: 0.0,
+            transparency: 0.0,
         }
     }
 }
@@ -45,7 +44,8 @@ pub struct CurveNetworkRenderData {
     /// Edge vertex buffer - contains tail and tip positions per edge.
     /// Layout: [tail0, tip0, tail1, tip1, ...] (vec4 each for alignment)
     pub edge_vertex_buffer: wgpu::Buffer,
-    /// Edge color buffer (per-edge colors, vec4).
+    /// Edge color buffer - contains tail and tip colors per edge.
+    /// Layout matches `edge_vertex_buffer` (vec4 each).
     pub edge_color_buffer: wgpu::Buffer,
 
     /// Uniform buffer for curve network settings.
@@ -71,6 +71,8 @@ pub struct CurveNetworkRenderData {
     // Node sphere rendering resources (for tube mode joint filling)
     /// Uniform buffer for node sphere rendering (matches `PointUniforms`).
     pub node_uniform_buffer: Option<wgpu::Buffer>,
+    /// Per-node sphere radius buffer (storage buffer).
+    pub node_radius_buffer: Option<wgpu::Buffer>,
     /// Bind group for node sphere rendering (uses point pipeline).
     pub node_render_bind_group: Option<wgpu::BindGroup>,
 }
@@ -131,7 +133,7 @@ impl CurveNetworkRenderData {
         });
 
         // Create edge color buffer (default zero - shader uses base color when zero)
-        let edge_color_data: Vec<f32> = vec![0.0; edge_tail_inds.len() * 4];
+        let edge_color_data: Vec<f32> = vec![0.0; edge_tail_inds.len() * 8];
         let edge_color_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("curve network edge colors"),
             contents: bytemuck::cast_slice(&edge_color_data),
@@ -199,6 +201,7 @@ impl CurveNetworkRenderData {
             compute_bind_group: None,
             tube_render_bind_group: None,
             node_uniform_buffer: None,
+            node_radius_buffer: None,
             node_render_bind_group: None,
         }
     }
@@ -303,6 +306,13 @@ impl CurveNetworkRenderData {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
+        // Used when a node radius quantity is active
+        let node_radius_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Curve Network Node Radii"),
+            contents: bytemuck::cast_slice(&vec![0.0f32; self.num_nodes.max(1) as usize]),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        });
+
         // Create bind group matching point pipeline layout
         let node_render_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Curve Network Node Render Bind Group"),
@@ -324,10 +334,15 @@ impl CurveNetworkRenderData {
                     binding: 3,
                     resource: self.node_color_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: node_radius_buffer.as_entire_binding(),
+                },
             ],
         });
 
         self.node_uniform_buffer = Some(node_uniform_buffer);
+        self.node_radius_buffer = Some(node_radius_buffer);
         self.node_render_bind_group = Some(node_render_bind_group);
     }
 
@@ -344,6 +359,13 @@ impl CurveNetworkRenderData {
         }
     }
 
+    /// Updates the per-node sphere radii.
+    pub fn update_node_radii(&self, queue: &wgpu::Queue, radii: &[f32]) {
+        if let Some(buffer) = &self.node_radius_buffer {
+            queue.write_buffer(buffer, 0, bytemuck::cast_slice(radii));
+        }
+    }
+
     /// Updates the uniform buffer.
     pub fn update_uniforms(&self, queue: &wgpu::Queue, uniforms: &CurveNetworkUniforms) {
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[*uniforms]));
@@ -359,8 +381,26 @@ impl CurveNetworkRenderData {
         );
     }
 
-    /// Updates edge colors.
+    /// Updates edge colors (one color per edge).
     pub fn update_edge_colors(&self, queue: &wgpu::Queue, colors: &[Vec4]) {
+        let color_data: Vec<f32> = colors
+            .iter()
+            .flat_map(|c| {
+                let c = c.to_array();
+                [c, c]
+            })
+            .flatten()
+            .collect();
+        queue.write_buffer(
+            &self.edge_color_buffer,
+            0,
+            bytemuck::cast_slice(&color_data),
+        );
+    }
+
+    /// Updates edge endpoint colors (tail and tip color per edge), which the
+    /// shaders interpolate along each edge.
+    pub fn update_edge_endpoint_colors(&self, queue: &wgpu::Queue, colors: &[Vec4]) {
         let color_data: Vec<f32> = colors.iter().flat_map(glam::Vec4::to_array).collect();
         queue.write_buffer(
             &self.edge_color_buffer,
@@ -399,6 +439,29 @@ impl CurveNetworkRenderData {
             bytemuck::cast_slice(&edge_vertex_data),
         );
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.node_buffer.size()
+            + self.node_color_buffer.size()
+            + self.edge_vertex_buffer.size()
+            + self.edge_color_buffer.size()
+            + self.uniform_buffer.size()
+            + self
+                .generated_vertex_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self.num_edges_buffer.as_ref().map_or(0, wgpu::Buffer::size)
+            + self
+                .node_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .node_radius_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
 }
 
 #[cfg(test)]
@@ -414,7 +477,7 @@ mod tests {
         // radius: 4 bytes (f32)
         // radius_is_relative: 4 bytes (u32)
         // render_mode: 4 bytes (u32)
-        // _padding: 4 bytes (f32)
+        // transparency: 4 bytes (f32)
         // Total: 32 bytes
         assert_eq!(size, 32, "CurveNetworkUniforms should be 32 bytes");
 
@@ -0,0 +1,83 @@
+use glam::Vec3;
+
+use super::RenderEngine;
+use crate::curve_network_render::{CurveNetworkRenderData, CurveNetworkUniforms};
+
+/// Color of structure bounding box wireframes (orange, to stand out from the
+/// default structure colors).
+const BBOX_COLOR: [f32; 4] = [1.0, 0.55, 0.0, 1.0];
+
+/// Number of edges in one box wireframe.
+const EDGES_PER_BOX: usize = 12;
+
+impl RenderEngine {
+    /// Updates the bounding box wireframe geometry.
+    ///
+    /// Each `(min, max)` pair is drawn as a 12-edge box. The GPU buffers are
+    /// rewritten in place while the number of boxes stays the same, and
+    /// recreated when it changes. An empty slice releases the geometry.
+    pub fn update_bounding_box_wireframes(&mut self, boxes: &[(Vec3, Vec3)]) {
+        if boxes.is_empty() {
+            self.bbox_render_data = None;
+            return;
+        }
+
+        let mut nodes = Vec::with_capacity(boxes.len() * 8);
+        let mut tails = Vec::with_capacity(boxes.len() * EDGES_PER_BOX);
+        let mut tips = Vec::with_capacity(boxes.len() * EDGES_PER_BOX);
+        for &(min, max) in boxes {
+            let base = nodes.len() as u32;
+            // Corner `i` takes max along the axes whose bit is set in `i`
+            for i in 0..8u32 {
+                nodes.push(Vec3::new(
+                    if i & 1 == 0 { min.x } else { max.x },
+                    if i & 2 == 0 { min.y } else { max.y },
+                    if i & 4 == 0 { min.z } else { max.z },
+                ));
+            }
+            // Edges connect corners that differ along exactly one axis
+            for i in 0..8u32 {
+                for bit in [1, 2, 4] {
+                    if i & bit == 0 {
+                        tails.push(base + i);
+                        tips.push(base + (i | bit));
+                    }
+                }
+            }
+        }
+
+        if let Some(render_data) = &self.bbox_render_data {
+            if render_data.num_edges as usize == tails.len() {
+                render_data.update_edge_vertices(&self.queue, &nodes, &tails, &tips);
+                return;
+            }
+        }
+
+        let Some(layout) = &self.curve_network_edge_bind_group_layout else {
+            return;
+        };
+        let render_data = CurveNetworkRenderData::new(
+            &self.device,
+            layout,
+            &self.camera_buffer,
+            &nodes,
+            &tails,
+            &tips,
+        );
+        render_data.update_uniforms(
+            &self.queue,
+            &CurveNetworkUniforms {
+                color: BBOX_COLOR,
+                radius_is_relative: 0,
+                ..CurveNetworkUniforms::default()
+            },
+        );
+        self.bbox_render_data = Some(render_data);
+    }
+
+    /// Returns the number of bounding box wireframe edges currently uploaded.
+    #[must_use]
+    pub fn bounding_box_wireframe_edge_count(&self) -> u32 {
+        self.bbox_render_data.as_ref().map_or(0, |rd| rd.num_edges)
+    }
+}
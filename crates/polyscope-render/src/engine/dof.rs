@@ -0,0 +1,83 @@
+use super::RenderEngine;
+use crate::dof_pass::DofPass;
+
+impl RenderEngine {
+    /// Updates depth of field for the following tone mapping passes.
+    ///
+    /// When enabled, creates the pass and the intermediate texture that tone
+    /// mapping renders into (recreated when the screen size changes) and
+    /// uploads the focal parameters. When disabled, releases the intermediate
+    /// texture so tone mapping writes straight to its output again.
+    pub fn update_dof(&mut self, config: &polyscope_core::DofConfig) {
+        if !config.enabled {
+            self.dof_intermediate_texture = None;
+            self.dof_intermediate_view = None;
+            return;
+        }
+
+        let format = self.surface_config.format;
+        let pass = self
+            .dof_pass
+            .get_or_insert_with(|| DofPass::new(&self.device, format));
+        pass.update_uniforms(&self.queue, config, self.ssaa_factor);
+
+        let size_matches = self
+            .dof_intermediate_texture
+            .as_ref()
+            .is_some_and(|t| t.width() == self.width && t.height() == self.height);
+        if size_matches {
+            return;
+        }
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("DOF Intermediate Texture"),
+            size: wgpu::Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        self.dof_intermediate_view =
+            Some(texture.create_view(&wgpu::TextureViewDescriptor::default()));
+        self.dof_intermediate_texture = Some(texture);
+    }
+
+    /// Returns whether depth of field is applied after tone mapping.
+    #[must_use]
+    pub fn dof_enabled(&self) -> bool {
+        self.dof_intermediate_view.is_some()
+    }
+
+    /// Returns the view tone mapping renders into: the depth of field input
+    /// while depth of field is enabled, otherwise `output_view` itself.
+    pub(crate) fn tone_map_target<'a>(
+        &'a self,
+        output_view: &'a wgpu::TextureView,
+    ) -> &'a wgpu::TextureView {
+        self.dof_intermediate_view.as_ref().unwrap_or(output_view)
+    }
+
+    /// Blurs the tone-mapped image into `output_view`. Does nothing while
+    /// depth of field is disabled.
+    pub(crate) fn render_dof(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        output_view: &wgpu::TextureView,
+    ) {
+        if let (Some(pass), Some(input_view)) = (&self.dof_pass, &self.dof_intermediate_view) {
+            pass.render_to_target(
+                &self.device,
+                encoder,
+                input_view,
+                &self.depth_only_view,
+                &self.camera_buffer,
+                output_view,
+            );
+        }
+    }
+}
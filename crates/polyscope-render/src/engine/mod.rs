@@ -1,5 +1,7 @@
 //! The main rendering engine.
 
+mod bbox;
+mod dof;
 mod pick;
 mod pipelines;
 mod postprocessing;
@@ -12,10 +14,12 @@ use std::sync::Arc;
 
 use wgpu::util::DeviceExt;
 
+use polyscope_core::crop_box::{CropBox, CropBoxUniforms};
 use polyscope_core::slice_plane::{MAX_SLICE_PLANES, SlicePlaneUniforms};
 
 use crate::camera::Camera;
 use crate::color_maps::ColorMapRegistry;
+use crate::curve_network_render::CurveNetworkRenderData;
 use crate::error::{RenderError, RenderResult};
 use crate::ground_plane::GroundPlaneRenderData;
 use crate::materials::{self, MatcapTextureSet, Material, MaterialRegistry};
@@ -91,10 +95,15 @@ pub struct RenderEngine {
     pub camera_buffer: wgpu::Buffer,
     /// Slice plane uniform buffer.
     pub slice_plane_buffer: wgpu::Buffer,
+    /// Crop box uniform buffer, bound next to the slice planes.
+    pub crop_box_buffer: wgpu::Buffer,
     /// Slice plane bind group layout (shared by all structure shaders).
     pub slice_plane_bind_group_layout: wgpu::BindGroupLayout,
     /// Slice plane bind group (updated each frame).
     pub slice_plane_bind_group: wgpu::BindGroup,
+    /// Copies of the slice plane uniforms with some slots disabled, for
+    /// structures that only some planes cut. Entry `i` masks slots `i + 1`.
+    pub(crate) masked_slice_planes: Vec<(wgpu::Buffer, wgpu::BindGroup)>,
     /// Vector arrow render pipeline.
     pub vector_pipeline: Option<wgpu::RenderPipeline>,
     /// Vector bind group layout.
@@ -109,6 +118,9 @@ pub struct RenderEngine {
     pub curve_network_edge_pipeline: Option<wgpu::RenderPipeline>,
     /// Curve network edge bind group layout.
     pub(crate) curve_network_edge_bind_group_layout: Option<wgpu::BindGroupLayout>,
+    /// Line geometry for structure bounding box wireframes (drawn with the
+    /// curve network edge pipeline), or `None` when no box is shown.
+    pub bbox_render_data: Option<CurveNetworkRenderData>,
     /// Curve network tube render pipeline (cylinder impostor rendering).
     pub curve_network_tube_pipeline: Option<wgpu::RenderPipeline>,
     /// Curve network tube compute pipeline (generates bounding box geometry).
@@ -129,6 +141,10 @@ pub struct RenderEngine {
     pub(crate) slice_plane_vis_bind_group_layout: wgpu::BindGroupLayout,
     /// Slice plane render data (per-plane, lazily initialized).
     pub(crate) slice_plane_render_data: Vec<SlicePlaneRenderData>,
+    /// Camera view image pipeline.
+    pub(crate) camera_image_pipeline: wgpu::RenderPipeline,
+    /// Camera view image bind group layout.
+    pub(crate) camera_image_bind_group_layout: wgpu::BindGroupLayout,
     /// Screenshot capture texture (lazily initialized).
     pub(crate) screenshot_texture: Option<wgpu::Texture>,
     /// Screenshot capture buffer (lazily initialized).
@@ -167,6 +183,13 @@ pub struct RenderEngine {
     pub(crate) ssaa_intermediate_texture: Option<wgpu::Texture>,
     /// Intermediate HDR texture view.
     pub(crate) ssaa_intermediate_view: Option<wgpu::TextureView>,
+    /// Depth of field pass, created when depth of field is first enabled.
+    pub(crate) dof_pass: Option<crate::dof_pass::DofPass>,
+    /// Tone-mapped (LDR) texture that depth of field reads from (screen
+    /// resolution). Present only while depth of field is enabled.
+    pub(crate) dof_intermediate_texture: Option<wgpu::Texture>,
+    /// Depth of field intermediate texture view.
+    pub(crate) dof_intermediate_view: Option<wgpu::TextureView>,
     /// Shadow map pass for ground plane shadows.
     pub(crate) shadow_map_pass: Option<ShadowMapPass>,
     /// Shadow render pipeline (depth-only, renders objects from light's perspective).
@@ -195,6 +218,8 @@ pub struct RenderEngine {
     pub(crate) simple_mesh_bind_group_layout: Option<wgpu::BindGroupLayout>,
     /// Gridcube pipeline (for volume grid scalar visualization).
     pub gridcube_pipeline: Option<wgpu::RenderPipeline>,
+    /// Gridcube pipeline without depth writes (for see-through voxel blocks).
+    pub gridcube_transparent_pipeline: Option<wgpu::RenderPipeline>,
     /// Gridcube bind group layout.
     pub(crate) gridcube_bind_group_layout: Option<wgpu::BindGroupLayout>,
 
@@ -225,8 +250,10 @@ pub struct RenderEngine {
     pub(crate) curve_network_tube_pick_pipeline: Option<wgpu::RenderPipeline>,
     /// Tube pick bind group layout.
     pub(crate) curve_network_tube_pick_bind_group_layout: Option<wgpu::BindGroupLayout>,
-    /// Pick bind group layout (shared across point cloud and curve network pick pipelines).
+    /// Pick bind group layout (used by the curve network pick pipeline).
     pub(crate) pick_bind_group_layout: Option<wgpu::BindGroupLayout>,
+    /// Point cloud pick bind group layout (has extra `point_radii` binding).
+    pub(crate) point_pick_bind_group_layout: Option<wgpu::BindGroupLayout>,
     /// Pick pipeline for surface meshes (face picking).
     pub(crate) mesh_pick_pipeline: Option<wgpu::RenderPipeline>,
     /// Mesh pick bind group layout (has extra `face_indices` binding).
@@ -302,37 +329,62 @@ impl RenderEngine {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        // Create slice plane buffer and bind group
+        // Create slice plane and crop box buffers and bind group
         let slice_planes_data = [SlicePlaneUniforms::default(); MAX_SLICE_PLANES];
         let slice_plane_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Slice Plane Buffer"),
             contents: bytemuck::cast_slice(&slice_planes_data),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
+        let crop_box_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Crop Box Buffer"),
+            contents: bytemuck::cast_slice(&[CropBoxUniforms::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
 
         let slice_plane_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Slice Plane Bind Group Layout"),
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: NonZeroU64::new(128),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(128),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(96),
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                ],
             });
 
         let slice_plane_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Slice Plane Bind Group"),
             layout: &slice_plane_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: slice_plane_buffer.as_entire_binding(),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: slice_plane_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: crop_box_buffer.as_entire_binding(),
+                },
+            ],
         });
+        let masked_slice_planes =
+            create_masked_slice_planes(&device, &slice_plane_bind_group_layout, &crop_box_buffer);
 
         // Create shadow map pass first (needed for bind group)
         let shadow_map_pass = ShadowMapPass::new(&device);
@@ -457,6 +509,16 @@ impl RenderEngine {
             wgpu::TextureFormat::Depth24PlusStencil8,
         );
 
+        // Camera view image pipeline
+        let camera_image_bind_group_layout =
+            crate::camera_image_render::create_camera_image_bind_group_layout(&device);
+        let camera_image_pipeline = crate::camera_image_render::create_camera_image_pipeline(
+            &device,
+            &camera_image_bind_group_layout,
+            wgpu::TextureFormat::Rgba16Float,
+            wgpu::TextureFormat::Depth24PlusStencil8,
+        );
+
         // Create matcap bind group layout and load all matcap textures
         let matcap_bind_group_layout = materials::create_matcap_bind_group_layout(&device);
         let matcap_textures =
@@ -483,8 +545,10 @@ impl RenderEngine {
             point_bind_group_layout: None,
             camera_buffer,
             slice_plane_buffer,
+            crop_box_buffer,
             slice_plane_bind_group_layout,
             slice_plane_bind_group,
+            masked_slice_planes,
             vector_pipeline: None,
             vector_bind_group_layout: None,
             mesh_pipeline: None,
@@ -493,6 +557,7 @@ impl RenderEngine {
             mesh_bind_group_layout: None,
             curve_network_edge_pipeline: None,
             curve_network_edge_bind_group_layout: None,
+            bbox_render_data: None,
             curve_network_tube_pipeline: None,
             curve_network_tube_compute_pipeline: None,
             curve_network_tube_bind_group_layout: None,
@@ -503,6 +568,8 @@ impl RenderEngine {
             slice_plane_vis_pipeline,
             slice_plane_vis_bind_group_layout,
             slice_plane_render_data: Vec::new(),
+            camera_image_pipeline,
+            camera_image_bind_group_layout,
             screenshot_texture: None,
             screenshot_buffer: None,
             screenshot_hdr_texture: None,
@@ -522,6 +589,9 @@ impl RenderEngine {
             ssaa_factor: 1,
             ssaa_intermediate_texture: None,
             ssaa_intermediate_view: None,
+            dof_pass: None,
+            dof_intermediate_texture: None,
+            dof_intermediate_view: None,
             shadow_map_pass: Some(shadow_map_pass),
             shadow_pipeline: None,
             shadow_bind_group_layout: None,
@@ -536,6 +606,7 @@ impl RenderEngine {
             simple_mesh_pipeline: None,
             simple_mesh_bind_group_layout: None,
             gridcube_pipeline: None,
+            gridcube_transparent_pipeline: None,
             gridcube_bind_group_layout: None,
             pick_ranges: HashMap::new(),
             next_global_index: 1, // 0 is reserved for background
@@ -550,6 +621,7 @@ impl RenderEngine {
             curve_network_tube_pick_pipeline: None,
             curve_network_tube_pick_bind_group_layout: None,
             pick_bind_group_layout: None,
+            point_pick_bind_group_layout: None,
             mesh_pick_pipeline: None,
             mesh_pick_bind_group_layout: None,
             gridcube_pick_pipeline: None,
@@ -626,37 +698,62 @@ impl RenderEngine {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        // Create slice plane buffer and bind group
+        // Create slice plane and crop box buffers and bind group
         let slice_planes_data = [SlicePlaneUniforms::default(); MAX_SLICE_PLANES];
         let slice_plane_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Slice Plane Buffer"),
             contents: bytemuck::cast_slice(&slice_planes_data),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
+        let crop_box_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Crop Box Buffer"),
+            contents: bytemuck::cast_slice(&[CropBoxUniforms::default()]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
 
         let slice_plane_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Slice Plane Bind Group Layout"),
-                entries: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: NonZeroU64::new(128),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(128),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: NonZeroU64::new(96),
+                        },
+                        count: None,
                     },
-                    count: None,
-                }],
+                ],
             });
 
         let slice_plane_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Slice Plane Bind Group"),
             layout: &slice_plane_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: slice_plane_buffer.as_entire_binding(),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: slice_plane_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: crop_box_buffer.as_entire_binding(),
+                },
+            ],
         });
+        let masked_slice_planes =
+            create_masked_slice_planes(&device, &slice_plane_bind_group_layout, &crop_box_buffer);
 
         // Create shadow map pass first (needed for bind group)
         let shadow_map_pass = ShadowMapPass::new(&device);
@@ -781,6 +878,16 @@ impl RenderEngine {
             wgpu::TextureFormat::Depth24PlusStencil8,
         );
 
+        // Camera view image pipeline
+        let camera_image_bind_group_layout =
+            crate::camera_image_render::create_camera_image_bind_group_layout(&device);
+        let camera_image_pipeline = crate::camera_image_render::create_camera_image_pipeline(
+            &device,
+            &camera_image_bind_group_layout,
+            wgpu::TextureFormat::Rgba16Float,
+            wgpu::TextureFormat::Depth24PlusStencil8,
+        );
+
         // Create matcap bind group layout and load all matcap textures
         let matcap_bind_group_layout = materials::create_matcap_bind_group_layout(&device);
         let matcap_textures =
@@ -807,8 +914,10 @@ impl RenderEngine {
             point_bind_group_layout: None,
             camera_buffer,
             slice_plane_buffer,
+            crop_box_buffer,
             slice_plane_bind_group_layout,
             slice_plane_bind_group,
+            masked_slice_planes,
             vector_pipeline: None,
             vector_bind_group_layout: None,
             mesh_pipeline: None,
@@ -817,6 +926,7 @@ impl RenderEngine {
             mesh_bind_group_layout: None,
             curve_network_edge_pipeline: None,
             curve_network_edge_bind_group_layout: None,
+            bbox_render_data: None,
             curve_network_tube_pipeline: None,
             curve_network_tube_compute_pipeline: None,
             curve_network_tube_bind_group_layout: None,
@@ -827,6 +937,8 @@ impl RenderEngine {
             slice_plane_vis_pipeline,
             slice_plane_vis_bind_group_layout,
             slice_plane_render_data: Vec::new(),
+            camera_image_pipeline,
+            camera_image_bind_group_layout,
             screenshot_texture: None,
             screenshot_buffer: None,
             screenshot_hdr_texture: None,
@@ -846,6 +958,9 @@ impl RenderEngine {
             ssaa_factor: 1,
             ssaa_intermediate_texture: None,
             ssaa_intermediate_view: None,
+            dof_pass: None,
+            dof_intermediate_texture: None,
+            dof_intermediate_view: None,
             shadow_map_pass: Some(shadow_map_pass),
             shadow_pipeline: None,
             shadow_bind_group_layout: None,
@@ -860,6 +975,7 @@ impl RenderEngine {
             simple_mesh_pipeline: None,
             simple_mesh_bind_group_layout: None,
             gridcube_pipeline: None,
+            gridcube_transparent_pipeline: None,
             gridcube_bind_group_layout: None,
             pick_ranges: HashMap::new(),
             next_global_index: 1, // 0 is reserved for background
@@ -874,6 +990,7 @@ impl RenderEngine {
             curve_network_tube_pick_pipeline: None,
             curve_network_tube_pick_bind_group_layout: None,
             pick_bind_group_layout: None,
+            point_pick_bind_group_layout: None,
             mesh_pick_pipeline: None,
             mesh_pick_bind_group_layout: None,
             gridcube_pick_pipeline: None,
@@ -1010,6 +1127,25 @@ impl RenderEngine {
 
         self.queue
             .write_buffer(&self.slice_plane_buffer, 0, bytemuck::cast_slice(&uniforms));
+
+        for (i, (buffer, _)) in self.masked_slice_planes.iter().enumerate() {
+            let mask = i + 1;
+            let mut masked = uniforms;
+            for (slot, plane) in masked.iter_mut().enumerate() {
+                if mask & (1 << slot) != 0 {
+                    plane.enabled = 0.0;
+                }
+            }
+            self.queue
+                .write_buffer(buffer, 0, bytemuck::cast_slice(&masked));
+        }
+    }
+
+    /// Updates the crop box uniforms; `None` disables the crop box.
+    pub fn update_crop_box_uniforms(&self, crop_box: Option<&CropBox>) {
+        let uniforms = crop_box.map(CropBoxUniforms::from).unwrap_or_default();
+        self.queue
+            .write_buffer(&self.crop_box_buffer, 0, bytemuck::cast_slice(&[uniforms]));
     }
 
     /// Gets the camera buffer.
@@ -1017,6 +1153,16 @@ impl RenderEngine {
         &self.camera_buffer
     }
 
+    /// Gets the camera view image pipeline.
+    pub fn camera_image_pipeline(&self) -> &wgpu::RenderPipeline {
+        &self.camera_image_pipeline
+    }
+
+    /// Gets the camera view image bind group layout.
+    pub fn camera_image_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.camera_image_bind_group_layout
+    }
+
     /// Gets the shadow map pass (if initialized).
     pub fn shadow_map_pass(&self) -> Option<&ShadowMapPass> {
         self.shadow_map_pass.as_ref()
@@ -1152,13 +1298,43 @@ impl RenderEngine {
         let path = std::path::Path::new(filename);
         let (w, h, rgba) = materials::decode_matcap_image_from_file(path)
             .map_err(PolyscopeError::MaterialLoadError)?;
+        self.register_static_matcap(name, w, h, &rgba);
+        Ok(())
+    }
+
+    /// Loads a static material from 8-bit RGBA pixels, row by row from the
+    /// top. Alpha is ignored.
+    ///
+    /// The image is used for all 4 matcap channels, like
+    /// [`Self::load_static_material`].
+    pub fn load_matcap(
+        &mut self,
+        name: &str,
+        rgba: &[u8],
+        width: u32,
+        height: u32,
+    ) -> std::result::Result<(), polyscope_core::PolyscopeError> {
+        use polyscope_core::PolyscopeError;
+
+        if self.matcap_textures.contains_key(name) {
+            return Err(PolyscopeError::MaterialExists(name.to_string()));
+        }
+
+        let rgba = materials::decode_matcap_image_from_rgba8(rgba, width, height)
+            .map_err(PolyscopeError::MaterialLoadError)?;
+        self.register_static_matcap(name, width, height, &rgba);
+        Ok(())
+    }
+
+    /// Uploads a decoded image as a static matcap and registers the material.
+    fn register_static_matcap(&mut self, name: &str, w: u32, h: u32, rgba: &[f32]) {
         let tex = materials::upload_matcap_texture(
             &self.device,
             &self.queue,
             &format!("matcap_{name}"),
             w,
             h,
-            &rgba,
+            rgba,
         );
 
         let view_r = tex.create_view(&wgpu::TextureViewDescriptor::default());
@@ -1209,7 +1385,39 @@ impl RenderEngine {
 
         self.materials
             .register(Material::static_mat(name, 0.2, 0.7, 0.3, 32.0));
-
-        Ok(())
     }
 }
+
+/// Creates one slice plane buffer and bind group per non-empty mask of
+/// disabled slots (see [`RenderEngine::slice_plane_bind_group_for`]).
+fn create_masked_slice_planes(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    crop_box_buffer: &wgpu::Buffer,
+) -> Vec<(wgpu::Buffer, wgpu::BindGroup)> {
+    let slice_planes_data = [SlicePlaneUniforms::default(); MAX_SLICE_PLANES];
+    (1..1usize << MAX_SLICE_PLANES)
+        .map(|_| {
+            let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Masked Slice Plane Buffer"),
+                contents: bytemuck::cast_slice(&slice_planes_data),
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Masked Slice Plane Bind Group"),
+                layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: crop_box_buffer.as_entire_binding(),
+                    },
+                ],
+            });
+            (buffer, bind_group)
+        })
+        .collect()
+}
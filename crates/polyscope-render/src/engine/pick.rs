@@ -151,52 +151,72 @@ impl RenderEngine {
             });
 
         // Pick bind group layout: camera, pick uniforms, positions
+        let mut entries = vec![
+            // Camera uniforms
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: NonZeroU64::new(272),
+                },
+                count: None,
+            },
+            // Pick uniforms
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: NonZeroU64::new(16),
+                },
+                count: None,
+            },
+            // Position storage buffer
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            },
+        ];
         let bind_group_layout =
             self.device
                 .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                     label: Some("Pick Bind Group Layout"),
-                    entries: &[
-                        // Camera uniforms
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 0,
-                            visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Buffer {
-                                ty: wgpu::BufferBindingType::Uniform,
-                                has_dynamic_offset: false,
-                                min_binding_size: NonZeroU64::new(272),
-                            },
-                            count: None,
-                        },
-                        // Pick uniforms
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 1,
-                            visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Buffer {
-                                ty: wgpu::BufferBindingType::Uniform,
-                                has_dynamic_offset: false,
-                                min_binding_size: NonZeroU64::new(16),
-                            },
-                            count: None,
-                        },
-                        // Position storage buffer
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 2,
-                            visibility: wgpu::ShaderStages::VERTEX,
-                            ty: wgpu::BindingType::Buffer {
-                                ty: wgpu::BufferBindingType::Storage { read_only: true },
-                                has_dynamic_offset: false,
-                                min_binding_size: None,
-                            },
-                            count: None,
-                        },
-                    ],
+                    entries: &entries,
+                });
+
+        // Point clouds also read per-point radii, so picking matches the
+        // drawn sphere sizes
+        entries.push(wgpu::BindGroupLayoutEntry {
+            binding: 3,
+            visibility: wgpu::ShaderStages::VERTEX,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        });
+        let point_bind_group_layout =
+            self.device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("Point Pick Bind Group Layout"),
+                    entries: &entries,
                 });
 
         let pipeline_layout = self
             .device
             .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
                 label: Some("Pick Pipeline Layout"),
-                bind_group_layouts: &[&bind_group_layout],
+                bind_group_layouts: &[&point_bind_group_layout],
                 push_constant_ranges: &[],
             });
 
@@ -239,6 +259,7 @@ impl RenderEngine {
 
         self.point_pick_pipeline = Some(pipeline);
         self.pick_bind_group_layout = Some(bind_group_layout);
+        self.point_pick_bind_group_layout = Some(point_bind_group_layout);
     }
 
     /// Gets the pick bind group layout.
@@ -248,6 +269,13 @@ impl RenderEngine {
             .expect("pick pipeline not initialized")
     }
 
+    /// Gets the point cloud pick bind group layout.
+    pub fn point_pick_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        self.point_pick_bind_group_layout
+            .as_ref()
+            .expect("pick pipeline not initialized")
+    }
+
     /// Gets the point cloud pick pipeline.
     pub fn point_pick_pipeline(&self) -> &wgpu::RenderPipeline {
         self.point_pick_pipeline
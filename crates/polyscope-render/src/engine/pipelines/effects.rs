@@ -242,7 +242,7 @@ impl RenderEngine {
                             ty: wgpu::BindingType::Buffer {
                                 ty: wgpu::BufferBindingType::Uniform,
                                 has_dynamic_offset: false,
-                                min_binding_size: NonZeroU64::new(160),
+                                min_binding_size: NonZeroU64::new(176),
                             },
                             count: None,
                         },
@@ -422,7 +422,7 @@ impl RenderEngine {
                             ty: wgpu::BindingType::Buffer {
                                 ty: wgpu::BufferBindingType::Uniform,
                                 has_dynamic_offset: false,
-                                min_binding_size: NonZeroU64::new(96),
+                                min_binding_size: NonZeroU64::new(112),
                             },
                             count: None,
                         },
@@ -448,6 +448,17 @@ impl RenderEngine {
                             },
                             count: None,
                         },
+                        // Point radii
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 4,
+                            visibility: wgpu::ShaderStages::VERTEX,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Storage { read_only: true },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
                     ],
                 });
 
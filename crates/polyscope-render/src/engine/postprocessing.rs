@@ -7,6 +7,8 @@ impl RenderEngine {
     /// Returns a texture view (HDR format) that can be used as a render target.
     /// The pipelines render to HDR format, so we need an HDR texture for rendering,
     /// then tone map to the final screenshot texture.
+    /// The HDR texture is allocated at SSAA resolution so it matches the depth
+    /// buffer; it is downsampled to screen resolution during tone mapping.
     /// After rendering to this view, call `apply_screenshot_tone_mapping()` then
     /// `capture_screenshot()` to get the pixel data.
     pub fn create_screenshot_target(&mut self) -> wgpu::TextureView {
@@ -18,8 +20,8 @@ impl RenderEngine {
         let hdr_texture = self.device.create_texture(&wgpu::TextureDescriptor {
             label: Some("screenshot HDR texture"),
             size: wgpu::Extent3d {
-                width: self.width,
-                height: self.height,
+                width: self.width * self.ssaa_factor,
+                height: self.height * self.ssaa_factor,
                 depth_or_array_layers: 1,
             },
             mip_level_count: 1,
@@ -73,6 +75,8 @@ impl RenderEngine {
     }
 
     /// Applies tone mapping from the screenshot HDR texture to the final screenshot texture.
+    ///
+    /// With SSAA enabled, the HDR texture is first downsampled to screen resolution.
     pub fn apply_screenshot_tone_mapping(&mut self, encoder: &mut wgpu::CommandEncoder) {
         let Some(hdr_view) = &self.screenshot_hdr_view else {
             log::error!("Screenshot HDR view not initialized");
@@ -86,21 +90,41 @@ impl RenderEngine {
 
         let screenshot_view =
             screenshot_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        // With depth of field, tone map into its input and blur into the screenshot
+        let target = self.tone_map_target(&screenshot_view);
 
-        // Use the existing tone mapping pass
-        // For screenshots, we use the main SSAO output view if available
-        // (Note: SSAO effect depends on the main render resolution, not screenshot resolution)
-        if let Some(tone_map_pass) = &self.tone_map_pass {
-            // Use SSAO output or fall back to HDR view (which is ignored when ssao_enabled=false)
-            let ssao_view = self.ssao_output_view.as_ref().unwrap_or(hdr_view);
+        let ssaa = match (
+            &self.tone_map_pass,
+            &self.ssaa_intermediate_view,
+            &self.ssaa_pass,
+        ) {
+            (Some(tone_map_pass), Some(intermediate_view), Some(ssaa_pass))
+                if self.ssaa_factor > 1 =>
+            {
+                Some((tone_map_pass, intermediate_view, ssaa_pass))
+            }
+            _ => None,
+        };
+        if let Some((tone_map_pass, intermediate_view, ssaa_pass)) = ssaa {
+            // Downsample, then tone map without SSAO (same as `render_tone_mapping`)
+            ssaa_pass.render_to_target(&self.device, encoder, hdr_view, intermediate_view);
             tone_map_pass.render_to_target(
                 &self.device,
                 encoder,
-                hdr_view,
-                ssao_view,
-                &screenshot_view,
+                intermediate_view,
+                intermediate_view,
+                target,
             );
+        } else if let Some(tone_map_pass) = &self.tone_map_pass {
+            // Use the existing tone mapping pass
+            // For screenshots, we use the main SSAO output view if available
+            // (Note: SSAO effect depends on the main render resolution, not screenshot resolution)
+            // Use SSAO output or fall back to HDR view (which is ignored when ssao_enabled=false)
+            let ssao_view = self.ssao_output_view.as_ref().unwrap_or(hdr_view);
+            tone_map_pass.render_to_target(&self.device, encoder, hdr_view, ssao_view, target);
         }
+
+        self.render_dof(encoder, &screenshot_view);
     }
 
     /// Returns the screenshot depth view for rendering.
@@ -199,6 +223,238 @@ impl RenderEngine {
         Ok(result)
     }
 
+    /// Reads back the depth buffer as linear eye-space depth.
+    ///
+    /// Call this after rendering to the screenshot target. Each value is the
+    /// distance from the camera plane along the view direction, in scene
+    /// units, for the current camera; pixels where nothing was drawn are
+    /// `f32::INFINITY`. Values are row-by-row from the top-left.
+    pub fn capture_linear_depth(&self) -> Result<Vec<f32>, crate::screenshot::ScreenshotError> {
+        let num_pixels = u64::from(self.width) * u64::from(self.height);
+        let buffer_size = num_pixels * 4;
+
+        let bind_group_layout =
+            self.device
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("depth linearize bind group layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStages::COMPUTE,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStages::COMPUTE,
+                            ty: wgpu::BindingType::Texture {
+                                sample_type: wgpu::TextureSampleType::Depth,
+                                view_dimension: wgpu::TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 2,
+                            visibility: wgpu::ShaderStages::COMPUTE,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Storage { read_only: false },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                });
+        let shader = self
+            .device
+            .create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("depth linearize shader"),
+                source: wgpu::ShaderSource::Wgsl(
+                    include_str!("../shaders/depth_linearize.wgsl").into(),
+                ),
+            });
+        let pipeline_layout = self
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("depth linearize pipeline layout"),
+                bind_group_layouts: &[&bind_group_layout],
+                push_constant_ranges: &[],
+            });
+        let pipeline = self
+            .device
+            .create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("depth linearize pipeline"),
+                layout: Some(&pipeline_layout),
+                module: &shader,
+                entry_point: Some("main"),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+
+        let storage_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("linear depth buffer"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("linear depth readback buffer"),
+            size: buffer_size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("depth linearize bind group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: self.camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&self.depth_only_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: storage_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("depth readback encoder"),
+            });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("depth linearize pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(self.width.div_ceil(8), self.height.div_ceil(8), 1);
+        }
+        encoder.copy_buffer_to_buffer(&storage_buffer, 0, &readback_buffer, 0, buffer_size);
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let buffer_slice = readback_buffer.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
+            tx.send(result).unwrap();
+        });
+        let _ = self.device.poll(wgpu::PollType::wait_indefinitely());
+        rx.recv()
+            .map_err(|_| crate::screenshot::ScreenshotError::BufferMapFailed)?
+            .map_err(|_| crate::screenshot::ScreenshotError::BufferMapFailed)?;
+
+        let data = buffer_slice.get_mapped_range();
+        let depths = bytemuck::cast_slice::<u8, f32>(&data)
+            .iter()
+            .map(|&d| if d < 0.0 { f32::INFINITY } else { d })
+            .collect();
+        drop(data);
+        readback_buffer.unmap();
+
+        Ok(depths)
+    }
+
+    /// Reads back the normal G-buffer written by the mesh pass.
+    ///
+    /// Call this after rendering a frame. Returns one `[x, y, z, w]` per
+    /// screen pixel, row-by-row from the top-left. `xyz` is the unit surface
+    /// normal in view space (right-handed: +X right, +Y up, +Z toward the
+    /// camera), so surfaces facing the camera have `z > 0`. `w` is the
+    /// coverage: the surface opacity where a mesh was drawn, and zero for
+    /// the background and for structures that do not write normals (point
+    /// clouds, curve networks, vectors). With SSAA, the first sample of each
+    /// pixel is returned.
+    pub fn read_normal_buffer(&self) -> Result<Vec<[f32; 4]>, crate::screenshot::ScreenshotError> {
+        let texture = self
+            .normal_texture
+            .as_ref()
+            .ok_or(crate::screenshot::ScreenshotError::InvalidImageData)?;
+        let (texture_width, texture_height) = (texture.width(), texture.height());
+        let scale = (texture_width / self.width.max(1)).max(1);
+
+        // Rgba16Float: 8 bytes per texel
+        let texel_bytes = 8u32;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let bytes_per_row = (texture_width * texel_bytes).div_ceil(align) * align;
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("normal readback buffer"),
+            size: u64::from(bytes_per_row) * u64::from(texture_height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("normal readback encoder"),
+            });
+        encoder.copy_texture_to_buffer(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(texture_height),
+                },
+            },
+            wgpu::Extent3d {
+                width: texture_width,
+                height: texture_height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.queue.submit(std::iter::once(encoder.finish()));
+
+        let buffer_slice = buffer.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
+            tx.send(result).unwrap();
+        });
+        let _ = self.device.poll(wgpu::PollType::wait_indefinitely());
+        rx.recv()
+            .map_err(|_| crate::screenshot::ScreenshotError::BufferMapFailed)?
+            .map_err(|_| crate::screenshot::ScreenshotError::BufferMapFailed)?;
+
+        let data = buffer_slice.get_mapped_range();
+        let half_at =
+            |offset: usize| half::f16::from_le_bytes([data[offset], data[offset + 1]]).to_f32();
+        let mut normals = Vec::with_capacity((self.width * self.height) as usize);
+        for y in 0..self.height {
+            let row = ((y * scale).min(texture_height - 1) * bytes_per_row) as usize;
+            for x in 0..self.width {
+                let texel = row + ((x * scale).min(texture_width - 1) * texel_bytes) as usize;
+                // Shaders encode normals into [0, 1]
+                normals.push([
+                    half_at(texel) * 2.0 - 1.0,
+                    half_at(texel + 2) * 2.0 - 1.0,
+                    half_at(texel + 4) * 2.0 - 1.0,
+                    half_at(texel + 6),
+                ]);
+            }
+        }
+        drop(data);
+        buffer.unmap();
+
+        Ok(normals)
+    }
+
     /// Initializes tone mapping resources.
     pub(crate) fn init_tone_mapping(&mut self) {
         self.tone_map_pass = Some(ToneMapPass::new(&self.device, self.surface_config.format));
@@ -433,13 +689,11 @@ impl RenderEngine {
     /// Updates tone mapping uniforms.
     pub fn update_tone_mapping(
         &self,
-        exposure: f32,
-        white_level: f32,
-        gamma: f32,
+        config: &polyscope_core::ToneMappingConfig,
         ssao_enabled: bool,
     ) {
         if let Some(tone_map) = &self.tone_map_pass {
-            tone_map.update_uniforms(&self.queue, exposure, white_level, gamma, ssao_enabled);
+            tone_map.update_uniforms(&self.queue, config, ssao_enabled);
         }
     }
 
@@ -449,10 +703,23 @@ impl RenderEngine {
     /// When SSAA is enabled (factor > 1):
     /// 1. Downsamples HDR (SSAA res) → intermediate HDR (screen res)
     /// 2. Tone maps intermediate HDR → output LDR (SSAO disabled — resolution mismatch)
+    ///
+    /// When depth of field is enabled (see `update_dof`), tone mapping writes
+    /// to an intermediate LDR texture that is then blurred into the output.
     pub fn render_tone_mapping(
         &self,
         encoder: &mut wgpu::CommandEncoder,
         output_view: &wgpu::TextureView,
+    ) {
+        self.render_tone_mapping_to(encoder, self.tone_map_target(output_view));
+        self.render_dof(encoder, output_view);
+    }
+
+    /// Tone maps the HDR scene into `output_view` (see `render_tone_mapping`).
+    fn render_tone_mapping_to(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        output_view: &wgpu::TextureView,
     ) {
         if let (Some(tone_map), Some(hdr_view)) = (&self.tone_map_pass, &self.hdr_view) {
             // If SSAA is enabled, first downsample HDR, then tone map
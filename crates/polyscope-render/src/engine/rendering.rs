@@ -1,6 +1,7 @@
 use super::RenderEngine;
 use crate::ground_plane::GroundPlaneRenderData;
 use crate::slice_plane_render::SlicePlaneRenderData;
+use polyscope_core::slice_plane::MAX_SLICE_PLANES;
 
 const DEFAULT_MATERIAL: &str = "clay";
 
@@ -19,6 +20,20 @@ impl RenderEngine {
             .unwrap_or(&self.matcap_textures[DEFAULT_MATERIAL])
             .bind_group
     }
+
+    /// Returns the slice plane bind group with the slots in `disabled_mask`
+    /// turned off (bit `i` disables slot `i`).
+    ///
+    /// A mask of 0 returns the shared [`Self::slice_plane_bind_group`].
+    pub fn slice_plane_bind_group_for(&self, disabled_mask: u32) -> &wgpu::BindGroup {
+        let mask = disabled_mask as usize & ((1 << MAX_SLICE_PLANES) - 1);
+        if mask == 0 {
+            &self.slice_plane_bind_group
+        } else {
+            &self.masked_slice_planes[mask - 1].1
+        }
+    }
+
     /// Renders the ground plane.
     ///
     /// # Arguments
@@ -28,7 +43,7 @@ impl RenderEngine {
     /// * `scene_center` - Center of the scene bounding box
     /// * `scene_min_y` - Minimum Y coordinate of scene bounding box
     /// * `length_scale` - Scene length scale
-    /// * `height_override` - Optional manual height (None = auto below scene)
+    /// * `height_override` - Optional world-space Y of the plane (None = auto below scene)
     /// * `shadow_darkness` - Shadow darkness (0.0 = no shadow, 1.0 = full black)
     /// * `shadow_mode` - Shadow mode: 0=none, `1=shadow_only`, `2=tile_with_shadow`
     /// * `reflection_intensity` - Reflection intensity (0.0 = opaque, affects transparency)
@@ -436,12 +451,16 @@ impl RenderEngine {
     /// Renders a single reflected mesh.
     ///
     /// Call this for each visible surface mesh after `render_stencil_pass`.
+    ///
+    /// `slice_plane_mask` selects the slice planes to ignore (see
+    /// [`Self::slice_plane_bind_group_for`]).
     pub fn render_reflected_mesh(
         &self,
         render_pass: &mut wgpu::RenderPass,
         mesh_bind_group: &wgpu::BindGroup,
         vertex_count: u32,
         material_name: &str,
+        slice_plane_mask: u32,
     ) {
         let Some(pipeline) = &self.reflected_mesh_pipeline else {
             return;
@@ -454,7 +473,7 @@ impl RenderEngine {
         render_pass.set_bind_group(0, mesh_bind_group, &[]);
         render_pass.set_bind_group(1, reflection.bind_group(), &[]);
         render_pass.set_bind_group(2, self.matcap_bind_group_for(material_name), &[]);
-        render_pass.set_bind_group(3, &self.slice_plane_bind_group, &[]);
+        render_pass.set_bind_group(3, self.slice_plane_bind_group_for(slice_plane_mask), &[]);
         render_pass.set_stencil_reference(1); // Test against stencil value 1
         render_pass.draw(0..vertex_count, 0..1);
     }
@@ -486,17 +505,25 @@ impl RenderEngine {
                     binding: 3,
                     resource: point_render_data.color_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: point_render_data.radius_buffer.as_entire_binding(),
+                },
             ],
         }))
     }
 
     /// Renders a single reflected point cloud.
+    ///
+    /// `slice_plane_mask` selects the slice planes to ignore (see
+    /// [`Self::slice_plane_bind_group_for`]).
     pub fn render_reflected_point_cloud(
         &self,
         render_pass: &mut wgpu::RenderPass,
         point_bind_group: &wgpu::BindGroup,
         point_count: u32,
         material_name: &str,
+        slice_plane_mask: u32,
     ) {
         let Some(pipeline) = &self.reflected_point_cloud_pipeline else {
             return;
@@ -509,7 +536,7 @@ impl RenderEngine {
         render_pass.set_bind_group(0, point_bind_group, &[]);
         render_pass.set_bind_group(1, reflection.bind_group(), &[]);
         render_pass.set_bind_group(2, self.matcap_bind_group_for(material_name), &[]);
-        render_pass.set_bind_group(3, &self.slice_plane_bind_group, &[]);
+        render_pass.set_bind_group(3, self.slice_plane_bind_group_for(slice_plane_mask), &[]);
         render_pass.set_stencil_reference(1);
         // 6 vertices per point (billboard quad as 2 triangles)
         render_pass.draw(0..6, 0..point_count);
@@ -547,12 +574,16 @@ impl RenderEngine {
     }
 
     /// Renders a single reflected curve network (tube mode).
+    ///
+    /// `slice_plane_mask` selects the slice planes to ignore (see
+    /// [`Self::slice_plane_bind_group_for`]).
     pub fn render_reflected_curve_network(
         &self,
         render_pass: &mut wgpu::RenderPass,
         curve_bind_group: &wgpu::BindGroup,
         curve_render_data: &crate::curve_network_render::CurveNetworkRenderData,
         material_name: &str,
+        slice_plane_mask: u32,
     ) {
         let Some(pipeline) = &self.reflected_curve_network_pipeline else {
             return;
@@ -571,7 +602,7 @@ impl RenderEngine {
         render_pass.set_bind_group(0, curve_bind_group, &[]);
         render_pass.set_bind_group(1, reflection.bind_group(), &[]);
         render_pass.set_bind_group(2, self.matcap_bind_group_for(material_name), &[]);
-        render_pass.set_bind_group(3, &self.slice_plane_bind_group, &[]);
+        render_pass.set_bind_group(3, self.slice_plane_bind_group_for(slice_plane_mask), &[]);
         render_pass.set_vertex_buffer(0, tube_vertex_buffer.slice(..));
         render_pass.set_stencil_reference(1);
         render_pass.draw(0..tube_vertex_count, 0..1);
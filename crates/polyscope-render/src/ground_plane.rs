@@ -126,7 +126,7 @@ impl GroundPlaneRenderData {
     /// * `scene_min_y` - Minimum Y coordinate of scene bounding box
     /// * `length_scale` - Scene length scale
     /// * `camera_height` - Current camera Y position
-    /// * `height_override` - Optional manual height override
+    /// * `height_override` - Optional world-space Y coordinate of the plane
     /// * `shadow_darkness` - Shadow darkness (0.0 = no shadow, 1.0 = full black)
     /// * `shadow_mode` - Shadow mode: 0=none, `1=shadow_only`, `2=tile_with_shadow`
     /// * `is_orthographic` - Whether camera is in orthographic mode
@@ -149,13 +149,10 @@ impl GroundPlaneRenderData {
         // The shader computes: center + up_direction * height
         // So height should be relative to center, not absolute Y coordinate
         let center_y = scene_center[1];
-        let height = height_override.unwrap_or_else(|| {
-            // Place at the scene's minimum Y coordinate
-            // Use a tiny offset (0.1% of length_scale) to avoid z-fighting
-            // height = (target_y - center_y), where target_y = scene_min_y - offset
-            let target_y = scene_min_y - length_scale * 0.001;
-            target_y - center_y
-        });
+        // Default: place at the scene's minimum Y coordinate, with a tiny
+        // offset (0.1% of length_scale) to avoid z-fighting
+        let target_y = height_override.unwrap_or(scene_min_y - length_scale * 0.001);
+        let height = target_y - center_y;
 
         let uniforms = GroundPlaneUniforms {
             center: [scene_center[0], scene_center[1], scene_center[2], 0.0],
@@ -40,9 +40,11 @@
 
 pub mod buffer;
 pub mod camera;
+pub mod camera_image_render;
 pub mod color_maps;
 pub mod curve_network_render;
 pub mod depth_peel_pass;
+pub mod dof_pass;
 pub mod dual_quat;
 pub mod engine;
 pub mod error;
@@ -65,21 +67,23 @@ pub mod vector_render;
 pub mod volume_grid_render;
 
 pub use camera::{AxisDirection, Camera, NavigationStyle, ProjectionMode};
+pub use camera_image_render::{CameraImageRenderData, CameraImageUniforms};
 pub use color_maps::{ColorMap, ColorMapRegistry};
 pub use curve_network_render::{CurveNetworkRenderData, CurveNetworkUniforms};
 pub use depth_peel_pass::DepthPeelPass;
+pub use dof_pass::{DofPass, DofUniforms};
 pub use engine::RenderEngine;
 pub use error::{RenderError, RenderResult};
 pub use ground_plane::{GroundPlaneRenderData, GroundPlaneUniforms};
 pub use materials::{Material, MaterialRegistry, MaterialUniforms};
 pub use pick::{
     MeshPickUniforms, PickElementType, PickResult, PickUniforms, TubePickUniforms, color_to_index,
-    index_to_color,
+    index_to_color, ray_triangle_intersection,
 };
 pub use point_cloud_render::{PointCloudRenderData, PointUniforms};
 pub use reflection::{ground_reflection_matrix, reflection_matrix};
 pub use reflection_pass::{ReflectionPass, ReflectionUniforms};
-pub use screenshot::{ScreenshotError, ScreenshotOptions, save_image, save_to_buffer};
+pub use screenshot::{ScreenshotError, ScreenshotOptions, encode_png, save_image, save_to_buffer};
 pub use shader::{ShaderBuilder, ShaderProgram};
 pub use shadow_map::{LightUniforms, SHADOW_MAP_SIZE, ShadowMapPass};
 pub use slice_mesh_render::SliceMeshRenderData;
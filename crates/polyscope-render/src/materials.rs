@@ -401,6 +401,38 @@ pub fn decode_matcap_image_from_file(
     Ok((width, height, rgba))
 }
 
+/// Convert 8-bit RGBA pixels into float RGBA pixel data for a matcap.
+///
+/// Channels are scaled to `[0, 1]`, like 8-bit images loaded from disk.
+/// Alpha is ignored and set to 1.0.
+pub fn decode_matcap_image_from_rgba8(
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+) -> std::result::Result<Vec<f32>, String> {
+    if width == 0 || height == 0 {
+        return Err("matcap image has zero dimensions".to_string());
+    }
+    let expected = width as usize * height as usize * 4;
+    if rgba.len() != expected {
+        return Err(format!(
+            "expected {expected} bytes for a {width}x{height} RGBA image, got {}",
+            rgba.len()
+        ));
+    }
+    Ok(rgba
+        .chunks_exact(4)
+        .flat_map(|p| {
+            [
+                f32::from(p[0]) / 255.0,
+                f32::from(p[1]) / 255.0,
+                f32::from(p[2]) / 255.0,
+                1.0,
+            ]
+        })
+        .collect())
+}
+
 /// Upload a decoded matcap image as a GPU texture.
 #[must_use]
 pub fn upload_matcap_texture(
@@ -761,6 +793,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_matcap_image_from_rgba8() {
+        let rgba = decode_matcap_image_from_rgba8(&[255, 0, 51, 0], 1, 1).unwrap();
+        let expected = [1.0, 0.0, 0.2, 1.0];
+        assert!(rgba.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-6));
+        assert!(decode_matcap_image_from_rgba8(&[0; 8], 2, 2).is_err());
+        assert!(decode_matcap_image_from_rgba8(&[], 0, 0).is_err());
+    }
+
     #[test]
     fn test_material_registry_custom() {
         let mut registry = MaterialRegistry::new();
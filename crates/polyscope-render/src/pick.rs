@@ -4,7 +4,7 @@
 //! with a unique color encoding its ID. When the user clicks, we read the pixel
 //! at that position and decode the color to find what was clicked.
 
-use glam::Vec2;
+use glam::{Vec2, Vec3};
 
 /// Element type for pick results.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -41,6 +41,13 @@ pub struct PickResult {
     pub screen_pos: Vec2,
     /// The depth value at the pick location.
     pub depth: f32,
+    /// The world-space position of the hit, for surface mesh faces.
+    pub world_position: Option<Vec3>,
+    /// Barycentric weights of the hit within the picked triangle, for surface mesh faces.
+    ///
+    /// For triangle faces the weights follow the face's vertex order; polygon faces
+    /// report weights for the fan triangle `(v0, v(i), v(i+1))` that was hit.
+    pub barycentric: Option<Vec3>,
 }
 
 /// Decodes a pick color back to an index.
@@ -54,6 +61,41 @@ pub fn color_to_index(r: u8, g: u8, b: u8) -> u32 {
     (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b)
 }
 
+/// Intersects a ray with a triangle (Möller–Trumbore).
+///
+/// Returns the ray parameter `t` and the barycentric weights of the hit point
+/// with respect to `(v0, v1, v2)`, or `None` if the ray misses.
+#[must_use]
+pub fn ray_triangle_intersection(
+    ray_origin: Vec3,
+    ray_dir: Vec3,
+    v0: Vec3,
+    v1: Vec3,
+    v2: Vec3,
+) -> Option<(f32, Vec3)> {
+    let eps = 1e-6;
+    let edge1 = v1 - v0;
+    let edge2 = v2 - v0;
+    let pvec = ray_dir.cross(edge2);
+    let det = edge1.dot(pvec);
+    if det.abs() < eps {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let tvec = ray_origin - v0;
+    let u = inv_det * tvec.dot(pvec);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let qvec = tvec.cross(edge1);
+    let v = inv_det * ray_dir.dot(qvec);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = inv_det * edge2.dot(qvec);
+    (t > eps).then(|| (t, Vec3::new(1.0 - u - v, u, v)))
+}
+
 /// Encodes a structure ID and element ID into RGB pick color.
 /// GPU uniforms for pick rendering (flat 24-bit global index encoding).
 ///
@@ -67,8 +109,10 @@ pub struct PickUniforms {
     pub global_start: u32,
     /// Point radius for sphere impostor rendering.
     pub point_radius: f32,
-    /// Padding to align to 16 bytes.
-    pub _padding: [f32; 2],
+    /// Instance `i` draws point `i * point_stride` (1 = every point).
+    pub point_stride: u32,
+    /// 0 = uniform `point_radius`, 1 = per-point radii.
+    pub use_per_point_radius: u32,
 }
 
 impl Default for PickUniforms {
@@ -76,7 +120,8 @@ impl Default for PickUniforms {
         Self {
             global_start: 0,
             point_radius: 0.01,
-            _padding: [0.0; 2],
+            point_stride: 1,
+            use_per_point_radius: 0,
         }
     }
 }
@@ -179,6 +224,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ray_triangle_intersection() {
+        let v0 = Vec3::new(0.0, 0.0, 0.0);
+        let v1 = Vec3::new(1.0, 0.0, 0.0);
+        let v2 = Vec3::new(0.0, 1.0, 0.0);
+
+        let (t, bary) = ray_triangle_intersection(
+            Vec3::new(0.25, 0.5, 2.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            v0,
+            v1,
+            v2,
+        )
+        .unwrap();
+        assert!((t - 2.0).abs() < 1e-5);
+        assert!((bary - Vec3::new(0.25, 0.25, 0.5)).length() < 1e-5);
+        let hit = v0 * bary.x + v1 * bary.y + v2 * bary.z;
+        assert!((hit - Vec3::new(0.25, 0.5, 0.0)).length() < 1e-5);
+
+        // Outside the triangle
+        assert!(
+            ray_triangle_intersection(
+                Vec3::new(1.0, 1.0, 2.0),
+                Vec3::new(0.0, 0.0, -1.0),
+                v0,
+                v1,
+                v2
+            )
+            .is_none()
+        );
+    }
+
     #[test]
     fn test_specific_colors() {
         // Test that specific values encode correctly
@@ -9,6 +9,8 @@ pub struct PointCloudRenderData {
     pub position_buffer: wgpu::Buffer,
     /// Color buffer (storage buffer).
     pub color_buffer: wgpu::Buffer,
+    /// Per-point radius buffer (storage buffer).
+    pub radius_buffer: wgpu::Buffer,
     /// Uniform buffer for point-specific settings.
     pub uniform_buffer: wgpu::Buffer,
     /// Bind group for this point cloud.
@@ -25,8 +27,15 @@ pub struct PointUniforms {
     pub model_matrix: [[f32; 4]; 4],
     pub point_radius: f32,
     pub use_per_point_color: u32,
-    pub _padding: [f32; 2],
+    pub use_per_point_radius: u32,
+    /// Transparency (0.0 = opaque, 1.0 = fully transparent).
+    pub transparency: f32,
     pub base_color: [f32; 4],
+    /// 0 = sphere impostor, 1 = flat square, 2 = flat disc.
+    pub render_mode: u32,
+    /// Instance `i` draws point `i * point_stride` (1 = every point).
+    pub point_stride: u32,
+    pub _padding: [u32; 2],
 }
 
 impl Default for PointUniforms {
@@ -40,8 +49,12 @@ impl Default for PointUniforms {
             ],
             point_radius: 0.01,
             use_per_point_color: 0,
-            _padding: [0.0; 2],
+            use_per_point_radius: 0,
+            transparency: 0.0,
             base_color: [0.2, 0.5, 0.8, 1.0], // Default blue
+            render_mode: 0,
+            point_stride: 1,
+            _padding: [0; 2],
         }
     }
 }
@@ -81,6 +94,15 @@ impl PointCloudRenderData {
             usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
         });
 
+        // Create radius buffer (only read when per-point radii are enabled;
+        // at least one element so the binding is never empty)
+        let radius_data = vec![0.0f32; positions.len().max(1)];
+        let radius_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("point radii"),
+            contents: bytemuck::cast_slice(&radius_data),
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+        });
+
         // Create uniform buffer
         let uniforms = PointUniforms::default();
         let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -110,12 +132,17 @@ impl PointCloudRenderData {
                     binding: 3,
                     resource: color_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: radius_buffer.as_entire_binding(),
+                },
             ],
         });
 
         Self {
             position_buffer,
             color_buffer,
+            radius_buffer,
             uniform_buffer,
             bind_group,
             num_points,
@@ -128,8 +155,22 @@ impl PointCloudRenderData {
         queue.write_buffer(&self.color_buffer, 0, bytemuck::cast_slice(&color_data));
     }
 
+    /// Updates the per-point radius buffer.
+    pub fn update_radii(&self, queue: &wgpu::Queue, radii: &[f32]) {
+        queue.write_buffer(&self.radius_buffer, 0, bytemuck::cast_slice(radii));
+    }
+
     /// Updates uniforms.
     pub fn update_uniforms(&self, queue: &wgpu::Queue, uniforms: &PointUniforms) {
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[*uniforms]));
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.position_buffer.size()
+            + self.color_buffer.size()
+            + self.radius_buffer.size()
+            + self.uniform_buffer.size()
+    }
 }
@@ -8,6 +8,14 @@ use std::path::Path;
 pub struct ScreenshotOptions {
     /// Whether to use transparent background (PNG only).
     pub transparent_background: bool,
+    /// Size `(width, height)` of headless captures in pixels; `None` uses the
+    /// current frame size.
+    pub size: Option<(u32, u32)>,
+    /// Forces headless captures to an orthographic projection spanning
+    /// exactly this many world units from the bottom to the top edge, so each
+    /// pixel covers `ortho_world_height / height` units. The view direction
+    /// is kept.
+    pub ortho_world_height: Option<f32>,
 }
 
 /// Saves raw BGRA pixel data to an image file.
@@ -77,6 +85,17 @@ pub fn save_to_buffer(data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, S
         chunk.swap(0, 2); // Swap B and R
     }
 
+    encode_png(rgba_data, width, height)
+}
+
+/// Encodes raw RGBA pixel data as a PNG buffer in memory.
+///
+/// # Arguments
+/// * `rgba_data` - Raw RGBA pixel data (4 bytes per pixel, as from the headless
+///   `Rgba8UnormSrgb` target)
+/// * `width` - Image width in pixels
+/// * `height` - Image height in pixels
+pub fn encode_png(rgba_data: Vec<u8>, width: u32, height: u32) -> Result<Vec<u8>, ScreenshotError> {
     // Note: wgpu uses top-left origin, so no vertical flip needed
     let img: ImageBuffer<Rgba<u8>, Vec<u8>> =
         ImageBuffer::from_raw(width, height, rgba_data).ok_or(ScreenshotError::InvalidImageData)?;
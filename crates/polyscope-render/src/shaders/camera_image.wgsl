@@ -0,0 +1,53 @@
+// Camera view image shader
+// Renders an RGBA texture on a quad at a camera view's image plane (double-sided)
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+    proj: mat4x4<f32>,
+    view_proj: mat4x4<f32>,
+    inv_proj: mat4x4<f32>,
+    camera_pos: vec3<f32>,
+    _padding: f32,
+}
+
+struct ImageUniforms {
+    model: mat4x4<f32>,
+    // Quad corners: upper-left, upper-right, lower-left, lower-right
+    corners: array<vec4<f32>, 4>,
+}
+
+@group(0) @binding(0) var<uniform> camera: CameraUniforms;
+@group(0) @binding(1) var<uniform> image: ImageUniforms;
+@group(0) @binding(2) var image_texture: texture_2d<f32>;
+@group(0) @binding(3) var image_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    // Two triangles; corner indices into image.corners
+    var corner_ids = array<u32, 6>(0u, 2u, 3u, 0u, 3u, 1u);
+    // Row 0 of the image is its top row, at the upper edge of the frame
+    var uvs = array<vec2<f32>, 4>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 1.0),
+    );
+
+    let corner = corner_ids[vertex_index];
+    let world_pos = image.model * vec4<f32>(image.corners[corner].xyz, 1.0);
+
+    var out: VertexOutput;
+    out.clip_position = camera.view_proj * world_pos;
+    out.uv = uvs[corner];
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(image_texture, image_sampler, in.uv);
+}
@@ -15,19 +15,27 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct CurveNetworkUniforms {
     color: vec4<f32>,         // Base color (RGBA)
     radius: f32,              // Line thickness (for future tube rendering)
     radius_is_relative: u32,  // Whether radius is relative to scene scale
     render_mode: u32,         // 0 = line, 1 = tube
-    _padding: f32,
+    transparency: f32,        // 0 = opaque, 1 = fully transparent
 }
 
 @group(0) @binding(0) var<uniform> camera: CameraUniforms;
@@ -35,9 +43,11 @@ struct CurveNetworkUniforms {
 @group(0) @binding(2) var<storage, read> node_positions: array<vec4<f32>>;
 @group(0) @binding(3) var<storage, read> node_colors: array<vec4<f32>>;
 @group(0) @binding(4) var<storage, read> edge_vertices: array<vec4<f32>>;
+// Per edge endpoint colors, laid out like edge_vertices: [tail0, tip0, tail1, tip1, ...]
 @group(0) @binding(5) var<storage, read> edge_colors: array<vec4<f32>>;
 
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Matcap textures (Group 2) - declared for pipeline layout compatibility
 // Line rendering does not use matcap (no normals available)
@@ -63,15 +73,12 @@ fn vs_main(
     // Edge vertices are stored as [tail0, tip0, tail1, tip1, ...]
     let position = edge_vertices[vertex_index].xyz;
 
-    // Get edge index (each edge has 2 vertices)
-    let edge_index = vertex_index / 2u;
-
     // Transform position
     out.clip_position = camera.view_proj * vec4<f32>(position, 1.0);
     out.world_position = position;
 
-    // Get color - use edge color if non-zero, otherwise base color
-    let ec = edge_colors[edge_index];
+    // Get color - use endpoint color if non-zero, otherwise base color
+    let ec = edge_colors[vertex_index];
     let color_sum = ec.r + ec.g + ec.b;
     if (color_sum > 0.001) {
         out.edge_color = ec;
@@ -82,24 +89,38 @@ fn vs_main(
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Slice plane culling
     for (var i = 0u; i < 4u; i = i + 1u) {
         let plane = slice_planes.planes[i];
         if (plane.enabled > 0.5) {
-            let dist = dot(in.world_position - plane.origin, plane.normal);
+            let dist = dot(in.world_position - plane.origin, plane.normal) * plane.side;
             if (dist < 0.0) {
                 discard;
             }
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.world_position)) {
+        discard;
+    }
+
     // Simple unlit color for lines
     // Lines don't have normals, so we can't do proper lighting
     // Just return the edge color with slight ambient darkening
     let ambient_factor = 0.8;
     let color = in.edge_color.rgb * ambient_factor;
 
-    return vec4<f32>(color, in.edge_color.a);
+    return vec4<f32>(color, in.edge_color.a * (1.0 - cn_uniforms.transparency));
 }
@@ -15,27 +15,37 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct CurveNetworkUniforms {
     color: vec4<f32>,
     radius: f32,
     radius_is_relative: u32,
     render_mode: u32,
-    _padding: f32,
+    transparency: f32,  // 0 = opaque, 1 = fully transparent
 }
 
 @group(0) @binding(0) var<uniform> camera: CameraUniforms;
 @group(0) @binding(1) var<uniform> uniforms: CurveNetworkUniforms;
 @group(0) @binding(2) var<storage, read> edge_vertices: array<vec4<f32>>;
+// Per edge endpoint colors, laid out like edge_vertices: [tail0, tip0, tail1, tip1, ...]
 @group(0) @binding(3) var<storage, read> edge_colors: array<vec4<f32>>;
 
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Matcap textures (Group 2)
 @group(2) @binding(0) var matcap_r: texture_2d<f32>;
@@ -150,6 +160,15 @@ struct FragmentOutput {
     @builtin(frag_depth) depth: f32,
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
@@ -177,19 +196,26 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     for (var i = 0u; i < 4u; i = i + 1u) {
         let plane = slice_planes.planes[i];
         if (plane.enabled > 0.5) {
-            let dist = dot(hit_point - plane.origin, plane.normal);
+            let dist = dot(hit_point - plane.origin, plane.normal) * plane.side;
             if (dist < 0.0) {
                 discard;
             }
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(hit_point)) {
+        discard;
+    }
+
     // Compute depth
     let clip_pos = camera.view_proj * vec4<f32>(hit_point, 1.0);
     out.depth = clip_pos.z / clip_pos.w;
 
-    // Get color
-    let ec = edge_colors[in.edge_id];
+    // Get color, interpolated from tail to tip (constant for per-edge colors)
+    let axis = tip - tail;
+    let s = clamp(dot(hit_point - tail, axis) / dot(axis, axis), 0.0, 1.0);
+    let ec = mix(edge_colors[in.edge_id * 2u], edge_colors[in.edge_id * 2u + 1u], s);
     var base_color: vec3<f32>;
     if (ec.r + ec.g + ec.b > 0.001) {
         base_color = ec.rgb;
@@ -201,7 +227,7 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     let view_normal = normalize((camera.view * vec4<f32>(hit_normal, 0.0)).xyz);
     let lit_color = light_surface_matcap(view_normal, base_color);
 
-    out.color = vec4<f32>(lit_color, 1.0);
+    out.color = vec4<f32>(lit_color, 1.0 - uniforms.transparency);
 
     return out;
 }
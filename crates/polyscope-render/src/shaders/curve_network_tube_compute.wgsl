@@ -6,7 +6,7 @@ struct CurveNetworkUniforms {
     radius: f32,
     radius_is_relative: u32,
     render_mode: u32,
-    _padding: f32,
+    transparency: f32,  // 0 = opaque, 1 = fully transparent
 }
 
 struct GeneratedVertex {
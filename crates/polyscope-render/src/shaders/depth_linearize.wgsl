@@ -0,0 +1,33 @@
+// Depth linearization shader
+// Converts the depth buffer to linear eye-space depth for readback
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+    proj: mat4x4<f32>,
+    view_proj: mat4x4<f32>,
+    inv_proj: mat4x4<f32>,
+    camera_pos: vec3<f32>,
+    _padding: f32,
+}
+
+@group(0) @binding(0) var<uniform> camera: CameraUniforms;
+@group(0) @binding(1) var depth_texture: texture_depth_2d;
+@group(0) @binding(2) var<storage, read_write> linear_depth: array<f32>;
+
+@compute @workgroup_size(8, 8)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let dims = textureDimensions(depth_texture);
+    if (id.x >= dims.x || id.y >= dims.y) {
+        return;
+    }
+
+    let depth = textureLoad(depth_texture, vec2<i32>(id.xy), 0);
+    // Negative marks background (nothing drawn)
+    var eye_depth = -1.0;
+    if (depth < 1.0) {
+        // Eye-space z only depends on NDC depth, so x and y can be zero
+        let eye = camera.inv_proj * vec4<f32>(0.0, 0.0, depth, 1.0);
+        eye_depth = -eye.z / eye.w;
+    }
+    linear_depth[id.y * dims.x + id.x] = eye_depth;
+}
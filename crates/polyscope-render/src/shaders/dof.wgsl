@@ -0,0 +1,100 @@
+// Depth of field post-processing shader
+// Blurs the tone-mapped image with a circle of confusion that grows with the
+// distance of each pixel from the focal plane
+
+struct CameraUniforms {
+    view: mat4x4<f32>,
+    proj: mat4x4<f32>,
+    view_proj: mat4x4<f32>,
+    inv_proj: mat4x4<f32>,
+    camera_pos: vec3<f32>,
+    _padding: f32,
+}
+
+struct DofUniforms {
+    focal_distance: f32,
+    aperture: f32,    // blur radius in pixels at infinite distance
+    depth_scale: u32, // depth texels per output pixel (the SSAA factor)
+    _padding: f32,
+}
+
+@group(0) @binding(0) var input_texture: texture_2d<f32>;
+@group(0) @binding(1) var input_sampler: sampler;
+@group(0) @binding(2) var<uniform> uniforms: DofUniforms;
+@group(0) @binding(3) var depth_texture: texture_depth_2d;
+@group(0) @binding(4) var<uniform> camera: CameraUniforms;
+
+const SAMPLE_COUNT: u32 = 48u;
+const GOLDEN_ANGLE: f32 = 2.39996323;
+const MAX_RADIUS: f32 = 32.0;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+// Fullscreen triangle
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+
+    let x = f32((vertex_index & 1u) << 2u) - 1.0;
+    let y = f32((vertex_index & 2u) << 1u) - 1.0;
+
+    out.position = vec4<f32>(x, y, 0.0, 1.0);
+    out.uv = vec2<f32>((x + 1.0) * 0.5, (1.0 - y) * 0.5);
+
+    return out;
+}
+
+// Linear eye-space depth at an output pixel; the background is infinitely far
+fn eye_depth(pixel: vec2<i32>) -> f32 {
+    let dims = vec2<i32>(textureDimensions(depth_texture));
+    let texel = clamp(pixel * i32(uniforms.depth_scale), vec2<i32>(0), dims - 1);
+    let depth = textureLoad(depth_texture, texel, 0);
+    if (depth >= 1.0) {
+        return 1e30;
+    }
+    // Eye-space z only depends on NDC depth, so x and y can be zero
+    let eye = camera.inv_proj * vec4<f32>(0.0, 0.0, depth, 1.0);
+    return -eye.z / eye.w;
+}
+
+// Circle of confusion radius in pixels
+fn coc_radius(depth: f32) -> f32 {
+    let focus = max(uniforms.focal_distance, 1e-6);
+    let radius = uniforms.aperture * abs(depth - focus) / max(depth, 1e-6);
+    return min(radius, MAX_RADIUS);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let pixel = vec2<i32>(in.position.xy);
+    let center = textureLoad(input_texture, pixel, 0);
+    let radius = coc_radius(eye_depth(pixel));
+
+    // In-focus pixels pass through untouched
+    if (radius < 0.5) {
+        return center;
+    }
+
+    let texel_size = 1.0 / vec2<f32>(textureDimensions(input_texture));
+    var sum = center;
+    var total = 1.0;
+    for (var i = 0u; i < SAMPLE_COUNT; i = i + 1u) {
+        // Golden-angle spiral covers the disc evenly
+        let r = radius * sqrt((f32(i) + 0.5) / f32(SAMPLE_COUNT));
+        let theta = f32(i) * GOLDEN_ANGLE;
+        let offset = vec2<f32>(cos(theta), sin(theta)) * r;
+        let sample_pixel = pixel + vec2<i32>(round(offset));
+
+        // Sharper samples do not spread into their blurred surroundings
+        let sample_radius = coc_radius(eye_depth(sample_pixel));
+        let weight = clamp(sample_radius - r + 1.0, 0.0, 1.0);
+
+        let uv = in.uv + offset * texel_size;
+        sum = sum + textureSampleLevel(input_texture, input_sampler, uv, 0.0) * weight;
+        total = total + weight;
+    }
+    return sum / total;
+}
@@ -15,13 +15,21 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct GridcubeUniforms {
     model: mat4x4<f32>,
     cube_size_factor: f32,  // 0..1, shrink factor for cubes
@@ -45,6 +53,7 @@ struct GridcubeUniforms {
 
 // Group 1: slice planes
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Group 2: matcap textures
 @group(2) @binding(0) var matcap_r: texture_2d<f32>;
@@ -125,6 +134,15 @@ fn vs_main(
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(
     in: VertexOutput,
@@ -135,7 +153,7 @@ fn fs_main(
         for (var i = 0u; i < 4u; i = i + 1u) {
             let plane = slice_planes.planes[i];
             if (plane.enabled > 0.5) {
-                let dist = dot(in.world_position - plane.origin, plane.normal);
+                let dist = dot(in.world_position - plane.origin, plane.normal) * plane.side;
                 if (dist < 0.0) {
                     discard;
                 }
@@ -143,6 +161,11 @@ fn fs_main(
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.world_position)) {
+        discard;
+    }
+
     // Use the flat face normal from the cube (already correct per-face)
     var normal = normalize(in.world_normal);
     if (!front_facing) {
@@ -17,12 +17,14 @@ struct CameraUniforms {
 struct PickUniforms {
     global_start: u32,
     point_radius: f32,
-    _padding: vec2<f32>,
+    point_stride: u32,  // instance i draws point i * point_stride
+    use_per_point_radius: u32,  // 0 = uniform radius, 1 = per-point radius
 }
 
 @group(0) @binding(0) var<uniform> camera: CameraUniforms;
 @group(0) @binding(1) var<uniform> pick_uniforms: PickUniforms;
 @group(0) @binding(2) var<storage, read> point_positions: array<vec4<f32>>;
+@group(0) @binding(3) var<storage, read> point_radii: array<f32>;
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
@@ -56,24 +58,28 @@ fn vs_main(
     @builtin(instance_index) instance_index: u32,
 ) -> VertexOutput {
     var out: VertexOutput;
+    let point_index = instance_index * pick_uniforms.point_stride;
 
     // Get point position (stored as vec4, using xyz)
-    let world_pos = point_positions[instance_index].xyz;
+    let world_pos = point_positions[point_index].xyz;
     let view_pos = (camera.view * vec4<f32>(world_pos, 1.0)).xyz;
 
     // Get quad vertex
     let quad_pos = QUAD_VERTICES[vertex_index];
 
     // Compute billboard offset in view space (always facing camera)
-    let radius = pick_uniforms.point_radius;
+    var radius = pick_uniforms.point_radius;
+    if (pick_uniforms.use_per_point_radius == 1u) {
+        radius = point_radii[point_index];
+    }
     let offset = vec3<f32>(quad_pos * radius, 0.0);
     let billboard_pos_view = view_pos + offset;
 
     // Project to clip space
     out.clip_position = camera.proj * vec4<f32>(billboard_pos_view, 1.0);
 
-    // Encode the pick color from global_start + instance_index
-    out.pick_color = index_to_color(pick_uniforms.global_start + instance_index);
+    // Encode the pick color from global_start + point_index
+    out.pick_color = index_to_color(pick_uniforms.global_start + point_index);
 
     // Pass through for ray-sphere intersection
     out.sphere_center_view = view_pos;
@@ -14,7 +14,7 @@ struct CurveNetworkUniforms {
     radius: f32,
     radius_is_relative: u32,
     render_mode: u32,
-    _padding: f32,
+    transparency: f32,  // 0 = opaque, 1 = fully transparent
 }
 
 struct ReflectionUniforms {
@@ -27,19 +27,28 @@ struct ReflectionUniforms {
 @group(0) @binding(0) var<uniform> camera: CameraUniforms;
 @group(0) @binding(1) var<uniform> uniforms: CurveNetworkUniforms;
 @group(0) @binding(2) var<storage, read> edge_vertices: array<vec4<f32>>;
+// Per edge endpoint colors, laid out like edge_vertices: [tail0, tip0, tail1, tip1, ...]
 @group(0) @binding(3) var<storage, read> edge_colors: array<vec4<f32>>;
 // Slice plane uniforms for fragment-level slicing
 struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 @group(1) @binding(0) var<uniform> reflection: ReflectionUniforms;
 
 // Matcap textures (Group 2)
@@ -51,6 +60,7 @@ struct SlicePlanesArray {
 
 // Slice planes (Group 3)
 @group(3) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(3) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 fn light_surface_matcap(normal: vec3<f32>, color: vec3<f32>) -> vec3<f32> {
     var n = normalize(normal);
@@ -152,6 +162,15 @@ struct FragmentOutput {
     @builtin(frag_depth) depth: f32,
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
@@ -193,19 +212,26 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     for (var i = 0u; i < 4u; i = i + 1u) {
         let plane = slice_planes.planes[i];
         if (plane.enabled > 0.5) {
-            let dist = dot(original_hit - plane.origin, plane.normal);
+            let dist = dot(original_hit - plane.origin, plane.normal) * plane.side;
             if (dist < 0.0) {
                 discard;
             }
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(original_hit)) {
+        discard;
+    }
+
     // Compute depth
     let clip_pos = camera.view_proj * vec4<f32>(hit_point, 1.0);
     out.depth = clip_pos.z / clip_pos.w;
 
-    // Get color
-    let ec = edge_colors[in.edge_id];
+    // Get color, interpolated from tail to tip (constant for per-edge colors)
+    let axis = tip_orig - tail_orig;
+    let s = clamp(dot(original_hit - tail_orig, axis) / dot(axis, axis), 0.0, 1.0);
+    let ec = mix(edge_colors[in.edge_id * 2u], edge_colors[in.edge_id * 2u + 1u], s);
     var base_color: vec3<f32>;
     if (ec.r + ec.g + ec.b > 0.001) {
         base_color = ec.rgb;
@@ -22,12 +22,13 @@ struct MeshUniforms {
     backface_policy: u32,
     slice_planes_enabled: u32,
     use_vertex_color: u32,
-    _pad1: f32,
-    _pad2_0: f32,
-    _pad2_1: f32,
-    _pad2_2: f32,
+    isolines_enabled: u32, // 0 = off, 1 = on
+    isoline_count: f32,
+    isoline_width: f32,
+    _pad2: f32,
     _pad3: f32,
     backface_color: vec4<f32>,
+    no_data_color: vec4<f32>,
 }
 
 struct ReflectionUniforms {
@@ -49,13 +50,21 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 @group(1) @binding(0) var<uniform> reflection: ReflectionUniforms;
 
 // Matcap textures (Group 2)
@@ -67,6 +76,7 @@ struct SlicePlanesArray {
 
 // Slice planes (Group 3)
 @group(3) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(3) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 fn light_surface_matcap(normal: vec3<f32>, color: vec3<f32>) -> vec3<f32> {
     var n = normalize(normal);
@@ -122,6 +132,15 @@ fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput, @builtin(front_facing) front_facing: bool) -> @location(0) vec4<f32> {
     // Clip pixels above ground plane (reflected geometry should not poke through)
@@ -134,7 +153,7 @@ fn fs_main(in: VertexOutput, @builtin(front_facing) front_facing: bool) -> @loca
         for (var i = 0u; i < 4u; i = i + 1u) {
             let plane = slice_planes.planes[i];
             if (plane.enabled > 0.5) {
-                let dist = dot(in.original_world_position - plane.origin, plane.normal);
+                let dist = dot(in.original_world_position - plane.origin, plane.normal) * plane.side;
                 if (dist < 0.0) {
                     discard;
                 }
@@ -142,10 +161,22 @@ fn fs_main(in: VertexOutput, @builtin(front_facing) front_facing: bool) -> @loca
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.original_world_position)) {
+        discard;
+    }
+
     // Get base color
     var base_color = mesh_uniforms.surface_color.rgb;
     if (mesh_uniforms.use_vertex_color == 1u) {
         base_color = in.vertex_color.rgb;
+    } else if (mesh_uniforms.use_vertex_color == 2u) {
+        let validity = in.vertex_color.w;
+        if (validity < 0.5) {
+            base_color = mesh_uniforms.no_data_color.rgb;
+        } else {
+            base_color = in.vertex_color.rgb / validity;
+        }
     }
 
     // Use flipped normal for front-facing test on reflected geometry
@@ -14,8 +14,13 @@ struct PointUniforms {
     model: mat4x4<f32>,
     point_radius: f32,
     use_per_point_color: u32,
-    _padding: vec2<f32>,
+    use_per_point_radius: u32,  // 0 = uniform radius, 1 = per-point radius
+    transparency: f32,  // 0 = opaque, 1 = fully transparent
     base_color: vec4<f32>,
+    render_mode: u32,  // 0 = sphere impostor, 1 = flat square, 2 = flat disc
+    point_stride: u32,  // instance i draws point i * point_stride
+    _pad1: u32,
+    _pad2: u32,
 }
 
 struct ReflectionUniforms {
@@ -29,18 +34,27 @@ struct ReflectionUniforms {
 @group(0) @binding(1) var<uniform> point_uniforms: PointUniforms;
 @group(0) @binding(2) var<storage, read> point_positions: array<vec3<f32>>;
 @group(0) @binding(3) var<storage, read> point_colors: array<vec4<f32>>;
+@group(0) @binding(4) var<storage, read> point_radii: array<f32>;
 // Slice plane uniforms for fragment-level slicing
 struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 @group(1) @binding(0) var<uniform> reflection: ReflectionUniforms;
 
 // Matcap textures (Group 2)
@@ -52,6 +66,7 @@ struct SlicePlanesArray {
 
 // Slice planes (Group 3)
 @group(3) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(3) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 fn light_surface_matcap(normal: vec3<f32>, color: vec3<f32>) -> vec3<f32> {
     var n = normalize(normal);
@@ -70,7 +85,7 @@ struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) sphere_center_view: vec3<f32>,
     @location(1) quad_pos: vec2<f32>,
-    @location(2) point_color: vec3<f32>,
+    @location(2) point_color: vec4<f32>,
     @location(3) point_radius: f32,
     @location(4) sphere_center_world: vec3<f32>,
     @location(5) original_world_position: vec3<f32>,
@@ -92,9 +107,10 @@ fn vs_main(
     @builtin(instance_index) instance_index: u32,
 ) -> VertexOutput {
     var out: VertexOutput;
+    let point_index = instance_index * point_uniforms.point_stride;
 
     // Get point position and apply model transform
-    let local_pos = point_positions[instance_index];
+    let local_pos = point_positions[point_index];
     let world_pos = (point_uniforms.model * vec4<f32>(local_pos, 1.0)).xyz;
 
     // Apply reflection matrix
@@ -106,7 +122,10 @@ fn vs_main(
     let quad_pos = QUAD_VERTICES[vertex_index];
 
     // Compute billboard offset in view space (always facing camera)
-    let radius = point_uniforms.point_radius;
+    var radius = point_uniforms.point_radius;
+    if (point_uniforms.use_per_point_radius == 1u) {
+        radius = point_radii[point_index];
+    }
     let offset = vec3<f32>(quad_pos * radius, 0.0);
     let billboard_pos_view = view_pos + offset;
 
@@ -119,9 +138,9 @@ fn vs_main(
 
     // Get color
     if (point_uniforms.use_per_point_color == 1u) {
-        out.point_color = point_colors[instance_index].xyz;
+        out.point_color = point_colors[point_index];
     } else {
-        out.point_color = point_uniforms.base_color.rgb;
+        out.point_color = vec4<f32>(point_uniforms.base_color.rgb, 1.0);
     }
 
     out.original_world_position = world_pos;
@@ -129,6 +148,15 @@ fn vs_main(
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Clip pixels above ground plane
@@ -140,13 +168,27 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     for (var i = 0u; i < 4u; i = i + 1u) {
         let plane = slice_planes.planes[i];
         if (plane.enabled > 0.5) {
-            let dist = dot(in.original_world_position - plane.origin, plane.normal);
+            let dist = dot(in.original_world_position - plane.origin, plane.normal) * plane.side;
             if (dist < 0.0) {
                 discard;
             }
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.original_world_position)) {
+        discard;
+    }
+
+    // Flat modes skip the ray-sphere intersection and per-pixel lighting
+    if (point_uniforms.render_mode != 0u) {
+        if (point_uniforms.render_mode == 2u && dot(in.quad_pos, in.quad_pos) > 1.0) {
+            discard;
+        }
+        let flat_color = light_surface_matcap(vec3<f32>(0.0, 0.0, 1.0), in.point_color.rgb);
+        return vec4<f32>(flat_color, reflection.intensity * in.point_color.a);
+    }
+
     // Ray-sphere intersection in view space
     let ray_origin = vec3<f32>(
         in.sphere_center_view.xy + in.quad_pos * in.point_radius,
@@ -170,8 +212,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let normal = normalize(hit_point - in.sphere_center_view);
 
     // Matcap lighting: normal is already in view space from ray-sphere intersection
-    let color = light_surface_matcap(normal, in.point_color);
+    let color = light_surface_matcap(normal, in.point_color.rgb);
 
-    // Output with reflection intensity as alpha
-    return vec4<f32>(color, reflection.intensity);
+    // Output with reflection intensity (and per-point alpha) as alpha
+    return vec4<f32>(color, reflection.intensity * in.point_color.a);
 }
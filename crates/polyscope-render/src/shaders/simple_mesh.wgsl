@@ -15,13 +15,21 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct SimpleMeshUniforms {
     model: mat4x4<f32>,
     base_color: vec4<f32>,
@@ -39,6 +47,7 @@ struct SimpleMeshUniforms {
 
 // Group 1: slice planes
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Group 2: matcap textures
 @group(2) @binding(0) var matcap_r: texture_2d<f32>;
@@ -90,6 +99,15 @@ fn vs_main(
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(
     in: VertexOutput,
@@ -105,7 +123,7 @@ fn fs_main(
         for (var i = 0u; i < 4u; i = i + 1u) {
             let plane = slice_planes.planes[i];
             if (plane.enabled > 0.5) {
-                let dist = dot(in.world_position - plane.origin, plane.normal);
+                let dist = dot(in.world_position - plane.origin, plane.normal) * plane.side;
                 if (dist < 0.0) {
                     discard;
                 }
@@ -113,6 +131,11 @@ fn fs_main(
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.world_position)) {
+        discard;
+    }
+
     // Use flat shading (screen-space derivatives) for isosurface
     let dpdx_pos = dpdx(in.world_position);
     let dpdy_pos = dpdy(in.world_position);
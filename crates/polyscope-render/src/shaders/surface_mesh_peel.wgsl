@@ -15,13 +15,21 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct MeshUniforms {
     model: mat4x4<f32>,
     shade_style: u32,      // 0 = smooth, 1 = flat, 2 = tri-flat
@@ -32,13 +40,14 @@ struct MeshUniforms {
     edge_color: vec4<f32>,
     backface_policy: u32,  // 0 = identical, 1 = different, 2 = custom, 3 = cull
     slice_planes_enabled: u32, // 0 = off, 1 = on
-    use_vertex_color: u32, // 0 = surface_color, 1 = per-vertex color
-    _pad1: f32,
-    _pad2_0: f32,
-    _pad2_1: f32,
-    _pad2_2: f32,
+    use_vertex_color: u32, // 0 = surface_color, 1 = per-vertex color, 2 = with validity
+    isolines_enabled: u32, // 0 = off, 1 = on
+    isoline_count: f32,
+    isoline_width: f32,
+    _pad2: f32,
     _pad3: f32,
     backface_color: vec4<f32>,
+    no_data_color: vec4<f32>,
 }
 
 @group(0) @binding(0) var<uniform> camera: CameraUniforms;
@@ -50,6 +59,7 @@ struct MeshUniforms {
 @group(0) @binding(6) var<storage, read> edge_is_real: array<vec4<f32>>;
 
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Matcap textures (Group 2)
 @group(2) @binding(0) var matcap_r: texture_2d<f32>;
@@ -83,6 +93,7 @@ struct VertexOutput {
     @location(2) barycentric: vec3<f32>,
     @location(3) vertex_color: vec4<f32>,
     @location(4) edge_real: vec3<f32>,
+    @location(5) isoline_value: f32,
 }
 
 @vertex
@@ -105,6 +116,7 @@ fn vs_main(
     out.barycentric = bary;
     out.vertex_color = color;
     out.edge_real = edge_is_real[vertex_index].xyz;
+    out.isoline_value = barycentrics[vertex_index].w;
 
     return out;
 }
@@ -114,6 +126,15 @@ struct PeelOutput {
     @location(1) depth_out: vec4<f32>,
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(
     in: VertexOutput,
@@ -142,7 +163,7 @@ fn fs_main(
         for (var i = 0u; i < 4u; i = i + 1u) {
             let plane = slice_planes.planes[i];
             if (plane.enabled > 0.5) {
-                let dist = dot(in.world_position - plane.origin, plane.normal);
+                let dist = dot(in.world_position - plane.origin, plane.normal) * plane.side;
                 if (dist < 0.0) {
                     discard;
                 }
@@ -150,6 +171,11 @@ fn fs_main(
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.world_position)) {
+        discard;
+    }
+
     // Determine base color based on backface policy
     var base_color = mesh_uniforms.surface_color.rgb;
     if (!front_facing) {
@@ -170,9 +196,29 @@ fn fs_main(
     }
 
     var per_element_alpha = 1.0;
+    var has_data = true;
     if (mesh_uniforms.use_vertex_color == 1u) {
         base_color = in.vertex_color.rgb;
         per_element_alpha = in.vertex_color.w;
+    } else if (mesh_uniforms.use_vertex_color == 2u) {
+        // Colors are premultiplied by the interpolated data validity in alpha;
+        // dividing it out averages only the vertices that have data
+        let validity = in.vertex_color.w;
+        if (validity < 0.5) {
+            base_color = mesh_uniforms.no_data_color.rgb;
+            has_data = false;
+        } else {
+            base_color = in.vertex_color.rgb / validity;
+        }
+    }
+
+    // Isolines: darken thin bands where the normalized scalar crosses a multiple
+    // of 1 / isoline_count; fwidth keeps the band width constant in pixels
+    if (mesh_uniforms.isolines_enabled == 1u && has_data) {
+        let v = in.isoline_value * mesh_uniforms.isoline_count;
+        let dist = min(fract(v), 1.0 - fract(v));
+        let line_factor = smoothstep(0.0, mesh_uniforms.isoline_width * fwidth(v), dist);
+        base_color = mix(base_color * 0.3, base_color, line_factor);
     }
 
     // Calculate normal for lighting based on shade_style
@@ -1,12 +1,16 @@
 // Tone mapping post-processing shader
-// Applies exposure, Reinhard tone mapping, gamma correction, and optional SSAO
+// Applies exposure, optional SSAO, a tone mapping operator, and gamma correction
 
 struct ToneMapUniforms {
     exposure: f32,
     white_level: f32,
     gamma: f32,
     ssao_enabled: u32, // 0 = disabled, 1 = enabled
-    _padding: vec4<f32>, // Pad to 32 bytes (workaround for wgpu late binding size validation)
+    tone_operator: u32, // 0 = none, 1 = Reinhard, 2 = ACES, 3 = filmic
+    // Pad to 32 bytes (workaround for wgpu late binding size validation)
+    _padding0: f32,
+    _padding1: f32,
+    _padding2: f32,
 }
 
 @group(0) @binding(0) var input_texture: texture_2d<f32>;
@@ -43,6 +47,33 @@ fn reinhard_extended(color: vec3<f32>, white: f32) -> vec3<f32> {
     return numerator / (1.0 + color);
 }
 
+// ACES filmic curve (Krzysztof Narkowicz's fit)
+fn aces(color: vec3<f32>) -> vec3<f32> {
+    let a = 2.51;
+    let b = 0.03;
+    let c = 2.43;
+    let d = 0.59;
+    let e = 0.14;
+    return (color * (a * color + b)) / (color * (c * color + d) + e);
+}
+
+// John Hable's Uncharted 2 curve, normalized so `white` maps to 1
+fn hable_partial(x: vec3<f32>) -> vec3<f32> {
+    let a = 0.15;
+    let b = 0.50;
+    let c = 0.10;
+    let d = 0.20;
+    let e = 0.02;
+    let f = 0.30;
+    return ((x * (a * x + c * b) + d * e) / (x * (a * x + b) + d * f)) - e / f;
+}
+
+fn filmic(color: vec3<f32>, white: f32) -> vec3<f32> {
+    let exposure_bias = 2.0;
+    let white_scale = 1.0 / hable_partial(vec3<f32>(11.2 * white));
+    return hable_partial(color * exposure_bias) * white_scale;
+}
+
 // Gamma correction
 fn gamma_correct(color: vec3<f32>, gamma: f32) -> vec3<f32> {
     return pow(color, vec3<f32>(1.0 / gamma));
@@ -62,14 +93,25 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         color = color * ssao;
     }
 
-    // Apply Reinhard tone mapping with white point
-    color = reinhard_extended(color, uniforms.white_level);
+    // Compress HDR range with the selected operator (none passes through)
+    switch uniforms.tone_operator {
+        case 0u: {}
+        case 2u: {
+            color = aces(color);
+        }
+        case 3u: {
+            color = filmic(color, uniforms.white_level);
+        }
+        default: {
+            color = reinhard_extended(color, uniforms.white_level);
+        }
+    }
+
+    // Clamp before gamma so out-of-range values encode as white
+    color = clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
 
     // Apply gamma correction
     color = gamma_correct(color, uniforms.gamma);
 
-    // Clamp to valid range
-    color = clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
-
     return vec4<f32>(color, hdr_color.a);
 }
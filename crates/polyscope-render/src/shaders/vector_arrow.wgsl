@@ -15,18 +15,27 @@ struct SlicePlaneUniforms {
     origin: vec3<f32>,
     enabled: f32,
     normal: vec3<f32>,
-    _padding: f32,
+    side: f32,  // 1 = keep normal side, -1 = inverted
 }
 
 struct SlicePlanesArray {
     planes: array<SlicePlaneUniforms, 4>,
 }
 
+struct CropBoxUniforms {
+    world_to_box: mat4x4<f32>,
+    box_min: vec3<f32>,
+    enabled: f32,  // 1 = clip to the box, 0 = off
+    box_max: vec3<f32>,
+    _pad: f32,
+}
+
 struct VectorUniforms {
     model: mat4x4<f32>,
     length_scale: f32,
     radius: f32,
-    _padding: vec2<f32>,
+    normalize: u32,  // 1 = all arrows have length length_scale
+    _padding: f32,
     color: vec4<f32>,
 }
 
@@ -36,6 +45,7 @@ struct VectorUniforms {
 @group(0) @binding(3) var<storage, read> vectors: array<vec3<f32>>;
 
 @group(1) @binding(0) var<uniform> slice_planes: SlicePlanesArray;
+@group(1) @binding(1) var<uniform> crop_box: CropBoxUniforms;
 
 // Matcap textures (Group 2)
 @group(2) @binding(0) var matcap_r: texture_2d<f32>;
@@ -102,7 +112,8 @@ fn vs_main(
     }
 
     let vec_dir = vec / vec_length;
-    let scaled_length = vec_length * vector_uniforms.length_scale;
+    let scaled_length = select(vec_length, 1.0, vector_uniforms.normalize != 0u)
+        * vector_uniforms.length_scale;
     let shaft_radius = vector_uniforms.radius;
     let cone_base_radius = shaft_radius * CONE_RADIUS_MULT;
     let shaft_height = scaled_length * (1.0 - CONE_HEIGHT_FRAC);
@@ -237,19 +248,33 @@ fn vs_main(
     return out;
 }
 
+// Returns true if a world-space point lies outside the enabled crop box
+fn outside_crop_box(world_position: vec3<f32>) -> bool {
+    if (crop_box.enabled < 0.5) {
+        return false;
+    }
+    let p = (crop_box.world_to_box * vec4<f32>(world_position, 1.0)).xyz;
+    return any(p < crop_box.box_min) || any(p > crop_box.box_max);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // Slice plane culling
     for (var i = 0u; i < 4u; i = i + 1u) {
         let plane = slice_planes.planes[i];
         if (plane.enabled > 0.5) {
-            let dist = dot(in.world_position - plane.origin, plane.normal);
+            let dist = dot(in.world_position - plane.origin, plane.normal) * plane.side;
             if (dist < 0.0) {
                 discard;
             }
         }
     }
 
+    // Crop box culling
+    if (outside_crop_box(in.world_position)) {
+        discard;
+    }
+
     // Matcap lighting: transform world-space normal to view space
     let view_normal = normalize((camera.view * vec4<f32>(normalize(in.normal), 0.0)).xyz);
     let lit_color = light_surface_matcap(view_normal, in.color);
@@ -2,7 +2,7 @@
 //!
 //! Renders the triangulated slice geometry using the surface mesh shader.
 
-use glam::{Vec3, Vec4};
+use glam::{Mat4, Vec3, Vec4};
 use wgpu::util::DeviceExt;
 
 use crate::surface_mesh_render::MeshUniforms;
@@ -18,11 +18,11 @@ pub struct SliceMeshRenderData {
     /// Normal buffer (vertex normals, vec4 for alignment).
     normal_buffer: wgpu::Buffer,
     /// Barycentric coordinate buffer for wireframe rendering (kept alive for `bind_group`).
-    _barycentric_buffer: wgpu::Buffer,
+    barycentric_buffer: wgpu::Buffer,
     /// Color buffer (per-vertex colors).
     color_buffer: wgpu::Buffer,
     /// Edge is real buffer (kept alive for `bind_group`).
-    _edge_is_real_buffer: wgpu::Buffer,
+    edge_is_real_buffer: wgpu::Buffer,
     /// Uniform buffer for mesh-specific settings.
     uniform_buffer: wgpu::Buffer,
     /// Bind group for this slice mesh.
@@ -120,10 +120,7 @@ impl SliceMeshRenderData {
         });
 
         // Create uniform buffer with interior color styling
-        let mut uniforms = MeshUniforms::default();
-        uniforms.shade_style = 1; // Flat shading for slice cap
-        uniforms.show_edges = 0; // No edges by default
-        uniforms.backface_policy = 0; // Identical front/back
+        let uniforms = Self::cap_uniforms(Vec3::splat(0.5), Mat4::IDENTITY);
 
         let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("slice mesh uniforms"),
@@ -171,9 +168,9 @@ impl SliceMeshRenderData {
             vertex_buffer,
             index_buffer,
             normal_buffer,
-            _barycentric_buffer: barycentric_buffer,
+            barycentric_buffer,
             color_buffer,
-            _edge_is_real_buffer: edge_is_real_buffer,
+            edge_is_real_buffer,
             uniform_buffer,
             bind_group,
             num_indices,
@@ -228,16 +225,29 @@ impl SliceMeshRenderData {
     }
 
     /// Updates the uniform buffer with new settings.
-    pub fn update_uniforms(&self, queue: &wgpu::Queue, color: Vec3) {
-        let mut uniforms = MeshUniforms::default();
-        uniforms.shade_style = 1; // Flat shading
-        uniforms.show_edges = 0;
-        uniforms.surface_color = [color.x, color.y, color.z, 1.0];
-        uniforms.backface_policy = 0;
-
+    ///
+    /// Cap colors always come from the per-vertex color buffer (which holds the
+    /// interior color when no quantity is active); `color` is the fallback
+    /// surface color.
+    pub fn update_uniforms(&self, queue: &wgpu::Queue, color: Vec3, model_matrix: Mat4) {
+        let uniforms = Self::cap_uniforms(color, model_matrix);
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
     }
 
+    fn cap_uniforms(color: Vec3, model_matrix: Mat4) -> MeshUniforms {
+        MeshUniforms {
+            model_matrix: model_matrix.to_cols_array_2d(),
+            shade_style: 1,     // Flat shading for slice cap
+            show_edges: 0,      // No edges
+            backface_policy: 0, // Identical front/back
+            surface_color: [color.x, color.y, color.z, 1.0],
+            // The cap lies exactly on the plane, so it must not be sliced itself
+            slice_planes_enabled: 0,
+            use_vertex_color: 1,
+            ..MeshUniforms::default()
+        }
+    }
+
     /// Returns the bind group for rendering.
     #[must_use]
     pub fn bind_group(&self) -> &wgpu::BindGroup {
@@ -261,4 +271,16 @@ impl SliceMeshRenderData {
     pub fn is_empty(&self) -> bool {
         self.num_indices == 0
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.vertex_buffer.size()
+            + self.index_buffer.size()
+            + self.normal_buffer.size()
+            + self.barycentric_buffer.size()
+            + self.color_buffer.size()
+            + self.edge_is_real_buffer.size()
+            + self.uniform_buffer.size()
+    }
 }
@@ -28,16 +28,23 @@ pub struct MeshUniforms {
     pub backface_policy: u32,
     /// Slice plane clipping enable: 0 = off, 1 = on
     pub slice_planes_enabled: u32,
-    /// Use per-vertex colors (1) or surface color (0)
+    /// Use surface color (0), per-vertex colors (1), or per-vertex colors
+    /// premultiplied by a data validity weight stored in alpha (2)
     pub use_vertex_color: u32,
-    /// Padding to align to 16 bytes
-    pub _pad1: f32,
-    /// Padding matching WGSL layout (12 bytes)
-    pub _pad2: [f32; 3],
+    /// Draw scalar isolines: 0 = off, 1 = on
+    pub isolines_enabled: u32,
+    /// Number of isolines over the normalized scalar range
+    pub isoline_count: f32,
+    /// Isoline width in pixels
+    pub isoline_width: f32,
+    /// Padding matching WGSL layout
+    pub _pad2: f32,
     /// Padding to align vec4 to 16 bytes
     pub _pad3: f32,
     /// Backface color (RGBA), used when `backface_policy` is custom
     pub backface_color: [f32; 4],
+    /// Color of regions without data (RGBA), used when `use_vertex_color` is 2
+    pub no_data_color: [f32; 4],
 }
 
 /// Model uniforms for shadow rendering.
@@ -80,10 +87,13 @@ impl Default for MeshUniforms {
             backface_policy: 0,                  // identical to front
             slice_planes_enabled: 1,
             use_vertex_color: 0,
-            _pad1: 0.0,
-            _pad2: [0.0; 3],
+            isolines_enabled: 0,
+            isoline_count: 10.0,
+            isoline_width: 1.0,
+            _pad2: 0.0,
             _pad3: 0.0,
             backface_color: [0.3, 0.3, 0.3, 1.0], // darker gray
+            no_data_color: [0.5, 0.5, 0.5, 1.0],  // gray
         }
     }
 }
@@ -296,6 +306,38 @@ impl SurfaceMeshRenderData {
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[*uniforms]));
     }
 
+    /// Updates the position and normal buffers in place.
+    ///
+    /// The triangulation must match the one used to create this render data.
+    pub fn update_positions(
+        &self,
+        queue: &wgpu::Queue,
+        vertices: &[Vec3],
+        triangles: &[[u32; 3]],
+        vertex_normals: &[Vec3],
+    ) {
+        let mut expanded_positions: Vec<f32> = Vec::with_capacity(triangles.len() * 3 * 4);
+        let mut expanded_normals: Vec<f32> = Vec::with_capacity(triangles.len() * 3 * 4);
+        for tri in triangles {
+            for &vi in tri {
+                let v = vertices[vi as usize];
+                expanded_positions.extend_from_slice(&[v.x, v.y, v.z, 1.0]);
+                let n = vertex_normals[vi as usize];
+                expanded_normals.extend_from_slice(&[n.x, n.y, n.z, 0.0]);
+            }
+        }
+        queue.write_buffer(
+            &self.vertex_buffer,
+            0,
+            bytemuck::cast_slice(&expanded_positions),
+        );
+        queue.write_buffer(
+            &self.normal_buffer,
+            0,
+            bytemuck::cast_slice(&expanded_normals),
+        );
+    }
+
     /// Updates the per-vertex color buffer with per-original-vertex colors.
     /// This expands the colors to match the per-triangle-vertex buffer layout.
     pub fn update_colors(&self, queue: &wgpu::Queue, colors: &[Vec4], triangles: &[[u32; 3]]) {
@@ -314,6 +356,32 @@ impl SurfaceMeshRenderData {
         );
     }
 
+    /// Updates the color buffer with one color per triangle corner
+    /// (three per triangle, in triangulation order).
+    pub fn update_corner_colors(&self, queue: &wgpu::Queue, colors: &[Vec4]) {
+        let data: Vec<[f32; 4]> = colors.iter().map(Vec4::to_array).collect();
+        queue.write_buffer(&self.color_buffer, 0, bytemuck::cast_slice(&data));
+    }
+
+    /// Writes per-vertex isoline scalar values into the unused `w` channel of
+    /// the barycentric buffer, expanded to the per-triangle-vertex layout.
+    pub fn update_isoline_values(
+        &self,
+        queue: &wgpu::Queue,
+        values: &[f32],
+        triangles: &[[u32; 3]],
+    ) {
+        let bary_coords = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let mut data: Vec<f32> = Vec::with_capacity(triangles.len() * 3 * 4);
+        for tri in triangles {
+            for (i, &vi) in tri.iter().enumerate() {
+                let [x, y, z] = bary_coords[i];
+                data.extend_from_slice(&[x, y, z, values[vi as usize]]);
+            }
+        }
+        queue.write_buffer(&self.barycentric_buffer, 0, bytemuck::cast_slice(&data));
+    }
+
     /// Clears the color buffer (sets all colors to zero, which means use `surface_color`).
     pub fn clear_colors(&self, queue: &wgpu::Queue) {
         let zero_colors: Vec<f32> = vec![0.0; self.num_indices as usize * 4];
@@ -436,6 +504,22 @@ impl SurfaceMeshRenderData {
     pub fn vertex_count(&self) -> u32 {
         self.num_indices
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.vertex_buffer.size()
+            + self.index_buffer.size()
+            + self.normal_buffer.size()
+            + self.barycentric_buffer.size()
+            + self.color_buffer.size()
+            + self.edge_is_real_buffer.size()
+            + self.uniform_buffer.size()
+            + self
+                .shadow_model_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
 }
 
 #[cfg(test)]
@@ -465,14 +549,17 @@ mod tests {
         // backface_policy: 4 bytes (u32)
         // slice_planes_enabled: 4 bytes (u32)
         // use_vertex_color: 4 bytes (u32)
-        // _pad1: 4 bytes (f32)
-        // _pad2: 12 bytes ([f32; 3])
+        // isolines_enabled: 4 bytes (u32)
+        // isoline_count: 4 bytes (f32)
+        // isoline_width: 4 bytes (f32)
+        // _pad2: 4 bytes (f32)
         // _pad3: 4 bytes (f32)
         // backface_color: 16 bytes ([f32; 4])
-        // Total: 160 bytes (matches WGSL layout with vec3 alignment)
+        // no_data_color: 16 bytes ([f32; 4])
+        // Total: 176 bytes (matches WGSL layout with vec3 alignment)
         assert_eq!(
-            size, 160,
-            "MeshUniforms should be 160 bytes, got {} bytes",
+            size, 176,
+            "MeshUniforms should be 176 bytes, got {} bytes",
             size
         );
     }
@@ -10,10 +10,11 @@ pub struct ToneMapUniforms {
     pub exposure: f32,
     pub white_level: f32,
     pub gamma: f32,
-    pub ssao_enabled: u32, // 0 = disabled, 1 = enabled
+    pub ssao_enabled: u32,  // 0 = disabled, 1 = enabled
+    pub tone_operator: u32, // 0 = none, 1 = Reinhard, 2 = ACES, 3 = filmic
     /// Padding to 32 bytes (workaround for wgpu late binding size validation).
     #[allow(clippy::pub_underscore_fields)]
-    pub _padding: [f32; 4],
+    pub _padding: [f32; 3],
 }
 
 impl Default for ToneMapUniforms {
@@ -23,7 +24,8 @@ impl Default for ToneMapUniforms {
             white_level: 1.0,
             gamma: 2.2,
             ssao_enabled: 0,
-            _padding: [0.0; 4],
+            tone_operator: 1,
+            _padding: [0.0; 3],
         }
     }
 }
@@ -158,17 +160,16 @@ impl ToneMapPass {
     pub fn update_uniforms(
         &self,
         queue: &wgpu::Queue,
-        exposure: f32,
-        white_level: f32,
-        gamma: f32,
+        config: &polyscope_core::ToneMappingConfig,
         ssao_enabled: bool,
     ) {
         let uniforms = ToneMapUniforms {
-            exposure,
-            white_level,
-            gamma,
+            exposure: config.exposure,
+            white_level: config.white_level,
+            gamma: config.gamma,
             ssao_enabled: u32::from(ssao_enabled),
-            _padding: [0.0; 4],
+            tone_operator: config.operator.into(),
+            _padding: [0.0; 3],
         };
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
     }
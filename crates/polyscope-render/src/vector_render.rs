@@ -19,7 +19,9 @@ pub struct VectorUniforms {
     pub model: [f32; 16],
     pub length_scale: f32,
     pub radius: f32,
-    pub _padding: [f32; 2],
+    /// Nonzero to draw every arrow with length `length_scale`.
+    pub normalize: u32,
+    pub _padding: f32,
     pub color: [f32; 4],
 }
 
@@ -36,7 +38,8 @@ impl Default for VectorUniforms {
             model: identity,
             length_scale: 1.0,
             radius: 0.005,
-            _padding: [0.0; 2],
+            normalize: 0,
+            _padding: 0.0,
             color: [0.8, 0.2, 0.2, 1.0], // Red
         }
     }
@@ -64,7 +67,7 @@ impl VectorRenderData {
         let vector_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("vectors"),
             contents: bytemuck::cast_slice(&vector_data),
-            usage: wgpu::BufferUsages::STORAGE,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
         });
 
         let uniforms = VectorUniforms::default();
@@ -106,8 +109,25 @@ impl VectorRenderData {
         }
     }
 
+    /// Rewrites the vectors in place. Extra vectors beyond `num_vectors` are
+    /// ignored.
+    pub fn update_vectors(&self, queue: &wgpu::Queue, vectors: &[Vec3]) {
+        let vector_data: Vec<f32> = vectors
+            .iter()
+            .take(self.num_vectors as usize)
+            .flat_map(|v| [v.x, v.y, v.z, 0.0])
+            .collect();
+        queue.write_buffer(&self.vector_buffer, 0, bytemuck::cast_slice(&vector_data));
+    }
+
     /// Updates vector uniforms.
     pub fn update_uniforms(&self, queue: &wgpu::Queue, uniforms: &VectorUniforms) {
         queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[*uniforms]));
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.base_buffer.size() + self.vector_buffer.size() + self.uniform_buffer.size()
+    }
 }
@@ -284,6 +284,18 @@ impl IsosurfaceRenderData {
             queue.write_buffer(buffer, 0, bytemuck::cast_slice(&[uniforms]));
         }
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.vertex_buffer.size()
+            + self.normal_buffer.size()
+            + self.uniform_buffer.size()
+            + self
+                .shadow_model_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
 }
 
 /// GPU resources for gridcube visualization.
@@ -694,6 +706,19 @@ impl GridcubeRenderData {
             queue.write_buffer(buffer, 0, bytemuck::cast_slice(&[uniforms]));
         }
     }
+
+    /// Returns the total size of the GPU buffers, in bytes.
+    #[must_use]
+    pub fn buffer_bytes(&self) -> u64 {
+        self.position_buffer.size()
+            + self.normal_buffer.size()
+            + self.scalar_buffer.size()
+            + self.uniform_buffer.size()
+            + self
+                .shadow_model_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
 }
 
 #[cfg(test)]
@@ -5,10 +5,11 @@ mod camera_parameters;
 pub use camera_parameters::*;
 
 use glam::{Mat4, Vec3, Vec4};
+use polyscope_core::error::{PolyscopeError, Result};
 use polyscope_core::pick::PickResult;
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::{HasQuantities, RenderContext, Structure};
-use polyscope_render::CurveNetworkRenderData;
+use polyscope_render::{CameraImageRenderData, CameraImageUniforms, CurveNetworkRenderData};
 
 /// A camera view structure for visualizing camera poses.
 pub struct CameraView {
@@ -19,6 +20,8 @@ pub struct CameraView {
 
     // Common structure fields
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
 
@@ -33,6 +36,10 @@ pub struct CameraView {
     /// Length scale used when geometry was last generated (to regenerate if it changes).
     prepared_length_scale: f32,
 
+    // Image shown at the image plane: (width, height, RGBA pixels)
+    image: Option<(u32, u32, Vec<u8>)>,
+    image_render_data: Option<CameraImageRenderData>,
+
     // UI state: set to true when user clicks "fly to" button
     fly_to_requested: bool,
 }
@@ -44,6 +51,8 @@ impl CameraView {
             name: name.into(),
             params,
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
             color: Vec4::new(0.0, 0.0, 0.0, 1.0),
@@ -52,6 +61,8 @@ impl CameraView {
             widget_thickness: 0.02,
             render_data: None,
             prepared_length_scale: 0.0,
+            image: None,
+            image_render_data: None,
             fly_to_requested: false,
         }
     }
@@ -145,28 +156,39 @@ impl CameraView {
         focal * self.widget_thickness
     }
 
-    /// Generates the camera frustum wireframe geometry.
-    fn generate_wireframe(&self, length_scale: f32) -> (Vec<Vec3>, Vec<[u32; 2]>) {
+    /// Computes the image frame: its center and half-extent vectors along
+    /// the camera's up and right directions.
+    fn compute_frame(&self, length_scale: f32) -> (Vec3, Vec3, Vec3) {
         let focal = self.compute_focal_length(length_scale);
-
-        let root = self.params.position();
         let (look_dir, up_dir, right_dir) = self.params.camera_frame();
 
         // Frame center is at focal distance from camera
-        let frame_center = root + look_dir * focal;
+        let frame_center = self.params.position() + look_dir * focal;
 
         // Compute frame half-dimensions based on FoV and aspect ratio
         let half_height = focal * (self.params.fov_vertical_degrees().to_radians() / 2.0).tan();
         let half_width = self.params.aspect_ratio() * half_height;
 
-        let frame_up = up_dir * half_height;
-        let frame_right = right_dir * half_width;
+        (frame_center, up_dir * half_height, right_dir * half_width)
+    }
 
-        // Frame corners
-        let upper_left = frame_center + frame_up - frame_right;
-        let upper_right = frame_center + frame_up + frame_right;
-        let lower_left = frame_center - frame_up - frame_right;
-        let lower_right = frame_center - frame_up + frame_right;
+    /// Computes the frame corners: upper-left, upper-right, lower-left, lower-right.
+    fn compute_frame_corners(&self, length_scale: f32) -> [Vec3; 4] {
+        let (center, up, right) = self.compute_frame(length_scale);
+        [
+            center + up - right,
+            center + up + right,
+            center - up - right,
+            center - up + right,
+        ]
+    }
+
+    /// Generates the camera frustum wireframe geometry.
+    fn generate_wireframe(&self, length_scale: f32) -> (Vec<Vec3>, Vec<[u32; 2]>) {
+        let root = self.params.position();
+        let (frame_center, frame_up, frame_right) = self.compute_frame(length_scale);
+        let [upper_left, upper_right, lower_left, lower_right] =
+            self.compute_frame_corners(length_scale);
 
         // Orientation triangle (above frame)
         let tri_left = frame_center + frame_up * 1.2 - frame_right * 0.7;
@@ -244,7 +266,7 @@ impl CameraView {
             radius: self.compute_radius(length_scale),
             radius_is_relative: 0, // Absolute radius since we already computed it
             render_mode: 0,        // Lines
-            _padding: 0.0,
+            transparency: 0.0,
         };
         render_data.update_uniforms(queue, &uniforms);
 
@@ -258,6 +280,94 @@ impl CameraView {
         self.render_data.as_ref()
     }
 
+    /// Sets an image to display in the camera's image frame.
+    ///
+    /// `rgba` holds 8-bit sRGB RGBA pixels, row by row starting from the top
+    /// row. The image is stretched over the frame, whose shape follows the
+    /// field of view and aspect ratio of the camera parameters. Replaces any
+    /// previous image.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `rgba` does not have
+    /// `width * height * 4` entries.
+    pub fn add_image(&mut self, width: u32, height: u32, rgba: Vec<u8>) -> Result<&mut Self> {
+        let expected = width as usize * height as usize * 4;
+        if rgba.len() != expected {
+            return Err(PolyscopeError::SizeMismatch {
+                expected,
+                actual: rgba.len(),
+            });
+        }
+        self.image = Some((width, height, rgba));
+        self.image_render_data = None;
+        Ok(self)
+    }
+
+    /// Removes the image, if any.
+    pub fn remove_image(&mut self) -> &mut Self {
+        self.image = None;
+        self.image_render_data = None;
+        self
+    }
+
+    /// Returns true if an image is set.
+    #[must_use]
+    pub fn has_image(&self) -> bool {
+        self.image.is_some()
+    }
+
+    /// Returns the image size in pixels, if an image is set.
+    #[must_use]
+    pub fn image_size(&self) -> Option<(u32, u32)> {
+        self.image.as_ref().map(|(w, h, _)| (*w, *h))
+    }
+
+    /// Returns the image quad uniforms for the current frame placement.
+    #[must_use]
+    pub fn image_uniforms(&self, length_scale: f32) -> CameraImageUniforms {
+        CameraImageUniforms::new(self.transform, self.compute_frame_corners(length_scale))
+    }
+
+    /// Uploads the image if needed and places its quad at the image frame.
+    ///
+    /// Does nothing if no (non-empty) image is set.
+    pub fn update_image_render_data(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        camera_buffer: &wgpu::Buffer,
+        length_scale: f32,
+    ) {
+        let Some((width, height, rgba)) = &self.image else {
+            return;
+        };
+        if *width == 0 || *height == 0 {
+            return;
+        }
+        if self.image_render_data.is_none() {
+            self.image_render_data = Some(CameraImageRenderData::new(
+                device,
+                queue,
+                bind_group_layout,
+                camera_buffer,
+                *width,
+                *height,
+                rgba,
+            ));
+        }
+        if let Some(render_data) = &self.image_render_data {
+            render_data.update_uniforms(queue, &self.image_uniforms(length_scale));
+        }
+    }
+
+    /// Returns the image render data if available.
+    #[must_use]
+    pub fn image_render_data(&self) -> Option<&CameraImageRenderData> {
+        self.image_render_data.as_ref()
+    }
+
     /// Returns true if the user has requested to fly to this camera view.
     /// The flag is automatically cleared after reading.
     #[must_use]
@@ -362,6 +472,22 @@ impl Structure for CameraView {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn draw(&self, _ctx: &mut dyn RenderContext) {
         // Drawing is handled externally using render_data()
     }
@@ -380,11 +506,27 @@ impl Structure for CameraView {
 
     fn clear_gpu_resources(&mut self) {
         self.render_data = None;
+        self.image_render_data = None;
         for quantity in &mut self.quantities {
             quantity.clear_gpu_resources();
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, CurveNetworkRenderData::buffer_bytes)
+            + self
+                .image_render_data
+                .as_ref()
+                .map_or(0, CameraImageRenderData::buffer_bytes)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         // Invalidate render data so it will be regenerated
         self.render_data = None;
@@ -419,3 +561,38 @@ impl HasQuantities for CameraView {
         &self.quantities
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_image_validates_size() {
+        let params = CameraParameters::look_at(Vec3::Z, Vec3::ZERO, Vec3::Y, 60.0, 1.0);
+        let mut cv = CameraView::new("cam", params);
+        assert!(cv.add_image(2, 2, vec![0; 15]).is_err());
+        assert!(!cv.has_image());
+
+        cv.add_image(2, 2, vec![0; 16]).unwrap();
+        assert_eq!(cv.image_size(), Some((2, 2)));
+        cv.remove_image();
+        assert!(!cv.has_image());
+    }
+
+    #[test]
+    fn test_image_quad_matches_frame() {
+        let params =
+            CameraParameters::look_at(Vec3::new(0.0, 0.0, 2.0), Vec3::ZERO, Vec3::Y, 90.0, 2.0);
+        let mut cv = CameraView::new("cam", params);
+        cv.set_widget_focal_length(2.0, false);
+
+        // 90 degree vertical FoV at distance 2: half height 2, half width 4
+        let corners = cv.image_uniforms(1.0).corners;
+        let expected = [[-4.0, 2.0], [4.0, 2.0], [-4.0, -2.0], [4.0, -2.0]];
+        for (corner, [x, y]) in corners.iter().zip(expected) {
+            assert!((corner[0] - x).abs() < 1e-5);
+            assert!((corner[1] - y).abs() < 1e-5);
+            assert!(corner[2].abs() < 1e-5);
+        }
+    }
+}
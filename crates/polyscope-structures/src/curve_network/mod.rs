@@ -3,6 +3,7 @@
 mod quantities;
 
 use glam::{Mat4, Vec3, Vec4};
+use polyscope_core::error::{PolyscopeError, Result};
 use polyscope_core::pick::PickResult;
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::{HasQuantities, RenderContext, Structure};
@@ -14,6 +15,17 @@ use wgpu::util::DeviceExt;
 
 pub use quantities::*;
 
+/// How curve network edges are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurveRenderMode {
+    /// Edges are rasterized as lines; the radius is ignored. Cheapest for
+    /// very large networks.
+    Line = 0,
+    /// Edges are drawn as shaded cylinders with spheres at the nodes.
+    #[default]
+    Tube = 1,
+}
+
 /// A curve network structure (nodes connected by edges).
 pub struct CurveNetwork {
     name: String,
@@ -29,6 +41,8 @@ pub struct CurveNetwork {
 
     // Common structure fields
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
 
@@ -36,9 +50,12 @@ pub struct CurveNetwork {
     color: Vec4,
     radius: f32,
     radius_is_relative: bool,
+    /// Node sphere radius in tube mode; `None` follows the edge radius.
+    node_radius: Option<f32>,
+    node_radius_is_relative: bool,
+    transparency: f32,
     material: String,
-    /// Render mode: 0 = line, 1 = tube (cylinder)
-    render_mode: u32,
+    render_mode: CurveRenderMode,
 
     // Variable radius (reserved for future use)
     #[allow(dead_code)]
@@ -77,13 +94,18 @@ impl CurveNetwork {
             edge_centers: Vec::new(),
             node_degrees: Vec::new(),
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
             color: Vec4::new(0.2, 0.5, 0.8, 1.0),
             radius: 0.005,
             radius_is_relative: true,
+            node_radius: None,
+            node_radius_is_relative: true,
+            transparency: 0.0, // 0.0 = fully opaque, 1.0 = fully transparent
             material: "default".to_string(),
-            render_mode: 0, // Default to line rendering
+            render_mode: CurveRenderMode::default(),
             node_radius_quantity_name: None,
             edge_radius_quantity_name: None,
             node_radius_autoscale: true,
@@ -203,6 +225,56 @@ impl CurveNetwork {
         self
     }
 
+    /// Gets the transparency (0.0 = opaque, 1.0 = fully transparent).
+    #[must_use]
+    pub fn transparency(&self) -> f32 {
+        self.transparency
+    }
+
+    /// Sets the transparency of edges and nodes.
+    pub fn set_transparency(&mut self, transparency: f32) -> &mut Self {
+        self.transparency = transparency.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the render uniforms for edges and tubes.
+    #[must_use]
+    pub fn uniforms(&self) -> CurveNetworkUniforms {
+        CurveNetworkUniforms {
+            color: self.color.to_array(),
+            radius: self.radius,
+            radius_is_relative: u32::from(self.radius_is_relative),
+            render_mode: self.render_mode as u32,
+            transparency: self.transparency,
+        }
+    }
+
+    /// Gets the node sphere radius used in tube mode.
+    ///
+    /// Equals the edge radius unless set with [`Self::set_node_radius`].
+    #[must_use]
+    pub fn node_radius(&self) -> f32 {
+        self.node_radius.unwrap_or(self.radius)
+    }
+
+    /// Returns whether the node radius is relative to scene scale.
+    #[must_use]
+    pub fn node_radius_is_relative(&self) -> bool {
+        if self.node_radius.is_some() {
+            self.node_radius_is_relative
+        } else {
+            self.radius_is_relative
+        }
+    }
+
+    /// Sets the node sphere radius used in tube mode, independently of the
+    /// edge radius (e.g. fat nodes on thin edges).
+    pub fn set_node_radius(&mut self, radius: f32, is_relative: bool) -> &mut Self {
+        self.node_radius = Some(radius);
+        self.node_radius_is_relative = is_relative;
+        self
+    }
+
     /// Gets the material name.
     #[must_use]
     pub fn material(&self) -> &str {
@@ -215,15 +287,16 @@ impl CurveNetwork {
         self
     }
 
-    /// Gets the render mode (0 = line, 1 = tube).
+    /// Gets the render mode.
     #[must_use]
-    pub fn render_mode(&self) -> u32 {
+    pub fn render_mode(&self) -> CurveRenderMode {
         self.render_mode
     }
 
-    /// Sets the render mode (0 = line, 1 = tube).
-    pub fn set_render_mode(&mut self, mode: u32) -> &mut Self {
-        self.render_mode = mode.min(1); // Clamp to valid values
+    /// Sets the render mode. In [`CurveRenderMode::Line`] mode the radius is
+    /// ignored.
+    pub fn set_render_mode(&mut self, mode: CurveRenderMode) -> &mut Self {
+        self.render_mode = mode;
         self
     }
 
@@ -235,25 +308,69 @@ impl CurveNetwork {
     }
 
     /// Adds a node scalar quantity to this curve network.
+    ///
+    /// Node colors are interpolated along the edges. Returns
+    /// [`PolyscopeError::SizeMismatch`] if `values` does not have one entry per node.
     pub fn add_node_scalar_quantity(
         &mut self,
         name: impl Into<String>,
         values: Vec<f32>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        if values.len() != self.num_nodes() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.num_nodes(),
+                actual: values.len(),
+            });
+        }
         let quantity = CurveNodeScalarQuantity::new(name, self.name.clone(), values);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
+    }
+
+    /// Adds a radius quantity that sizes each node sphere by a scalar value.
+    ///
+    /// Node spheres are drawn in [`CurveRenderMode::Tube`] mode; the radius
+    /// is independent of node colors. Negative values are clamped to zero.
+    /// See [`CurveNodeRadiusQuantity`] for how values are scaled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have one
+    /// entry per node.
+    pub fn add_node_scalar_radius_quantity(
+        &mut self,
+        name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Result<&mut Self> {
+        if values.len() != self.num_nodes() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.num_nodes(),
+                actual: values.len(),
+            });
+        }
+        let quantity = CurveNodeRadiusQuantity::new(name, self.name.clone(), values);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
     }
 
     /// Adds an edge scalar quantity to this curve network.
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have one
+    /// entry per edge.
     pub fn add_edge_scalar_quantity(
         &mut self,
         name: impl Into<String>,
         values: Vec<f32>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        if values.len() != self.num_edges() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.num_edges(),
+                actual: values.len(),
+            });
+        }
         let quantity = CurveEdgeScalarQuantity::new(name, self.name.clone(), values);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a node color quantity to this curve network.
@@ -315,6 +432,15 @@ impl CurveNetwork {
         None
     }
 
+    /// Returns the currently active node radius quantity, if any.
+    #[must_use]
+    pub fn active_node_radius_quantity(&self) -> Option<&CurveNodeRadiusQuantity> {
+        self.quantities
+            .iter()
+            .filter(|q| q.is_enabled())
+            .find_map(|q| q.as_any().downcast_ref::<CurveNodeRadiusQuantity>())
+    }
+
     /// Returns the currently active edge scalar quantity, if any.
     #[must_use]
     pub fn active_edge_scalar_quantity(&self) -> Option<&CurveEdgeScalarQuantity> {
@@ -365,7 +491,7 @@ impl CurveNetwork {
         let mut color = [self.color.x, self.color.y, self.color.z];
         let mut radius = self.radius;
         let mut radius_is_relative = self.radius_is_relative;
-        let mut render_mode = self.render_mode;
+        let mut render_mode = self.render_mode as u32;
 
         if polyscope_ui::build_curve_network_ui(
             ui,
@@ -381,7 +507,11 @@ impl CurveNetwork {
             self.color = Vec4::new(color[0], color[1], color[2], self.color.w);
             self.radius = radius;
             self.radius_is_relative = radius_is_relative;
-            self.render_mode = render_mode;
+            self.render_mode = if render_mode == 0 {
+                CurveRenderMode::Line
+            } else {
+                CurveRenderMode::Tube
+            };
         }
 
         // Show quantities
@@ -420,6 +550,11 @@ impl CurveNetwork {
                     .downcast_mut::<CurveEdgeVectorQuantity>()
                 {
                     vq.build_egui_ui(ui);
+                } else if let Some(rq) = quantity
+                    .as_any_mut()
+                    .downcast_mut::<CurveNodeRadiusQuantity>()
+                {
+                    rq.build_egui_ui(ui);
                 }
             }
         }
@@ -492,7 +627,8 @@ impl CurveNetwork {
         let pick_uniforms = PickUniforms {
             global_start,
             point_radius: self.radius, // Used as line_width in shader
-            _padding: [0.0; 2],
+            point_stride: 1,
+            use_per_point_radius: 0,
         };
         let pick_uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("curve network pick uniforms"),
@@ -538,7 +674,8 @@ impl CurveNetwork {
             let pick_uniforms = PickUniforms {
                 global_start: self.global_start,
                 point_radius: self.radius,
-                _padding: [0.0; 2],
+                point_stride: 1,
+                use_per_point_radius: 0,
             };
             queue.write_buffer(buffer, 0, bytemuck::cast_slice(&[pick_uniforms]));
         }
@@ -635,48 +772,66 @@ impl CurveNetwork {
             &self.edge_tip_inds,
         );
 
-        let uniforms = CurveNetworkUniforms {
-            color: self.color.to_array(),
-            radius: self.radius,
-            radius_is_relative: u32::from(self.radius_is_relative),
-            render_mode: self.render_mode,
-            _padding: 0.0,
-        };
-
-        render_data.update_uniforms(queue, &uniforms);
+        render_data.update_uniforms(queue, &self.uniforms());
+
+        // Active node colors (from a color or scalar quantity), if any
+        let node_colors: Option<Vec<Vec4>> =
+            if let Some(color_q) = self.active_node_color_quantity() {
+                Some(color_q.linear_colors())
+            } else if let Some(scalar_q) = self.active_node_scalar_quantity() {
+                color_maps
+                    .get(scalar_q.colormap_name())
+                    .map(|colormap| scalar_q.compute_colors(colormap))
+            } else {
+                None
+            };
 
-        // Update node sphere uniforms for tube mode (slightly larger than tube radius to fill gaps)
-        if self.render_mode == 1 && render_data.has_node_render_resources() {
+        // Update node sphere uniforms for tube mode
+        if self.render_mode == CurveRenderMode::Tube && render_data.has_node_render_resources() {
             let model_matrix = self.transform.to_cols_array_2d();
+            // Unless set explicitly, make spheres slightly larger than tubes
+            // to ensure they fill gaps at joints
+            let node_radius = self.node_radius.unwrap_or(self.radius * 1.02);
+            let radius_q = self.active_node_radius_quantity();
+            if let Some(radius_q) = radius_q {
+                render_data.update_node_radii(queue, &radius_q.compute_radii(node_radius));
+            }
             let node_uniforms = PointUniforms {
                 model_matrix,
-                // Make spheres slightly larger than tubes to ensure they fill gaps at joints
-                point_radius: self.radius * 1.02,
-                use_per_point_color: 0, // Use base color
-                _padding: [0.0; 2],
+                point_radius: node_radius,
+                use_per_point_color: u32::from(node_colors.is_some()),
+                use_per_point_radius: u32::from(radius_q.is_some()),
+                transparency: self.transparency,
                 base_color: self.color.to_array(),
+                ..PointUniforms::default()
             };
             render_data.update_node_uniforms(queue, &node_uniforms);
         }
 
-        // Apply node color quantities
-        if let Some(color_q) = self.active_node_color_quantity() {
-            color_q.apply_to_render_data(queue, render_data);
-        } else if let Some(scalar_q) = self.active_node_scalar_quantity() {
-            if let Some(colormap) = color_maps.get(scalar_q.colormap_name()) {
-                let colors = scalar_q.compute_colors(colormap);
-                render_data.update_node_colors(queue, &colors);
-            }
+        if let Some(colors) = &node_colors {
+            render_data.update_node_colors(queue, colors);
         }
 
-        // Apply edge color quantities
+        // Edge quantities take precedence; otherwise node colors are interpolated
+        // along each edge, and zero falls back to the base color.
         if let Some(color_q) = self.active_edge_color_quantity() {
             color_q.apply_to_render_data(queue, render_data);
-        } else if let Some(scalar_q) = self.active_edge_scalar_quantity() {
-            if let Some(colormap) = color_maps.get(scalar_q.colormap_name()) {
-                let colors = scalar_q.compute_colors(colormap);
-                render_data.update_edge_colors(queue, &colors);
-            }
+        } else if let Some(colors) = self.active_edge_scalar_quantity().and_then(|q| {
+            color_maps
+                .get(q.colormap_name())
+                .map(|cm| q.compute_colors(cm))
+        }) {
+            render_data.update_edge_colors(queue, &colors);
+        } else if let Some(colors) = &node_colors {
+            let endpoint_colors: Vec<Vec4> = self
+                .edge_tail_inds
+                .iter()
+                .zip(&self.edge_tip_inds)
+                .flat_map(|(&tail, &tip)| [colors[tail as usize], colors[tip as usize]])
+                .collect();
+            render_data.update_edge_endpoint_colors(queue, &endpoint_colors);
+        } else {
+            render_data.update_edge_colors(queue, &vec![Vec4::ZERO; self.num_edges()]);
         }
     }
 
@@ -775,6 +930,22 @@ impl Structure for CurveNetwork {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn material(&self) -> &str {
         &self.material
     }
@@ -810,6 +981,25 @@ impl Structure for CurveNetwork {
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, CurveNetworkRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .tube_pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         self.recompute_geometry();
         for quantity in &mut self.quantities {
@@ -865,6 +1055,17 @@ mod tests {
         assert_eq!(cn.nodes(), &nodes);
     }
 
+    #[test]
+    fn test_curve_network_render_mode() {
+        let mut cn = CurveNetwork::new_line("line", vec![Vec3::ZERO, Vec3::X]);
+        assert_eq!(cn.render_mode(), CurveRenderMode::Tube);
+        assert_eq!(cn.uniforms().render_mode, 1);
+
+        cn.set_render_mode(CurveRenderMode::Line);
+        assert_eq!(cn.render_mode(), CurveRenderMode::Line);
+        assert_eq!(cn.uniforms().render_mode, 0);
+    }
+
     #[test]
     fn test_curve_network_line_connectivity() {
         let nodes = vec![
@@ -937,6 +1138,37 @@ mod tests {
         assert_eq!(cn.material(), "clay");
     }
 
+    #[test]
+    fn test_curve_network_node_radius() {
+        let mut cn = CurveNetwork::new("test", vec![Vec3::ZERO, Vec3::X], vec![[0, 1]]);
+
+        // Node radius follows the edge radius until set
+        cn.set_radius(0.02, false);
+        assert_eq!(cn.node_radius(), 0.02);
+        assert!(!cn.node_radius_is_relative());
+
+        cn.set_node_radius(0.1, true);
+        cn.set_radius(0.01, false);
+        assert_eq!(cn.node_radius(), 0.1);
+        assert!(cn.node_radius_is_relative());
+        assert_eq!(cn.radius(), 0.01);
+        assert!(!cn.radius_is_relative());
+    }
+
+    #[test]
+    fn test_curve_network_transparency() {
+        let mut cn = CurveNetwork::new("test", vec![Vec3::ZERO, Vec3::X], vec![[0, 1]]);
+        assert_eq!(cn.transparency(), 0.0);
+        assert_eq!(cn.uniforms().transparency, 0.0);
+
+        cn.set_transparency(0.4);
+        assert_eq!(cn.transparency(), 0.4);
+        assert_eq!(cn.uniforms().transparency, 0.4);
+
+        cn.set_transparency(2.0);
+        assert_eq!(cn.transparency(), 1.0);
+    }
+
     #[test]
     fn test_curve_network_vector_quantities() {
         use polyscope_core::quantity::QuantityKind;
@@ -956,4 +1188,62 @@ mod tests {
         assert_eq!(eq.data_size(), 2);
         assert_eq!(eq.kind(), QuantityKind::Vector);
     }
+
+    #[test]
+    fn test_curve_network_scalar_quantity_sizes() {
+        let nodes = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        let edges = vec![[0, 1], [1, 2]];
+        let mut cn = CurveNetwork::new("test", nodes, edges);
+
+        assert!(
+            cn.add_node_scalar_quantity("nodes", vec![0.0, 0.5, 1.0])
+                .is_ok()
+        );
+        assert!(cn.add_edge_scalar_quantity("edges", vec![0.0, 1.0]).is_ok());
+        assert_eq!(cn.get_quantity("edges").unwrap().data_size(), 2);
+
+        assert!(matches!(
+            cn.add_node_scalar_quantity("bad_nodes", vec![0.0, 1.0]),
+            Err(PolyscopeError::SizeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            cn.add_edge_scalar_quantity("bad_edges", vec![0.0, 0.5, 1.0]),
+            Err(PolyscopeError::SizeMismatch {
+                expected: 2,
+                actual: 3
+            })
+        ));
+        assert!(cn.get_quantity("bad_edges").is_none());
+    }
+
+    #[test]
+    fn test_curve_network_node_radius_quantity() {
+        let nodes = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        let mut cn = CurveNetwork::new("test", nodes, vec![[0, 1], [1, 2]]);
+
+        assert!(matches!(
+            cn.add_node_scalar_radius_quantity("bad", vec![1.0]),
+            Err(PolyscopeError::SizeMismatch {
+                expected: 3,
+                actual: 1
+            })
+        ));
+        cn.add_node_scalar_radius_quantity("degree", vec![1.0, 2.0, -1.0])
+            .unwrap();
+        cn.add_node_color_quantity("colors", vec![Vec3::X; 3]);
+        assert!(cn.active_node_radius_quantity().is_none());
+
+        cn.get_quantity_mut("degree").unwrap().set_enabled(true);
+        cn.get_quantity_mut("colors").unwrap().set_enabled(true);
+        assert!(cn.active_node_color_quantity().is_some());
+        let radius_q = cn.active_node_radius_quantity().unwrap();
+        assert_eq!(radius_q.values(), &[1.0, 2.0, 0.0]);
+        let radii = radius_q.compute_radii(0.1);
+        assert!((radii[0] - 0.05).abs() < 1e-6);
+        assert!((radii[1] - 0.1).abs() < 1e-6);
+        assert!(radii[2].abs() < 1e-6);
+    }
 }
@@ -1,9 +1,13 @@
 //! Curve network quantity implementations.
 
 use glam::{Vec3, Vec4};
+use polyscope_core::color_space::ColorSpace;
+use polyscope_core::error::Result;
 use polyscope_core::quantity::{EdgeQuantity, Quantity, QuantityKind, VertexQuantity};
 use polyscope_render::{ColorMap, CurveNetworkRenderData};
 
+use crate::quantity_update::{data_range, replace_data};
+
 /// A scalar quantity on curve network nodes.
 pub struct CurveNodeScalarQuantity {
     name: String,
@@ -13,6 +17,7 @@ pub struct CurveNodeScalarQuantity {
     colormap_name: String,
     range_min: f32,
     range_max: f32,
+    range_user_set: bool, // false = range follows the data
 }
 
 impl CurveNodeScalarQuantity {
@@ -22,8 +27,7 @@ impl CurveNodeScalarQuantity {
         structure_name: impl Into<String>,
         values: Vec<f32>,
     ) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
@@ -33,6 +37,7 @@ impl CurveNodeScalarQuantity {
             colormap_name: "viridis".to_string(),
             range_min: min,
             range_max: max,
+            range_user_set: false,
         }
     }
 
@@ -42,6 +47,23 @@ impl CurveNodeScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
     /// Maps scalar values to colors using the colormap.
     #[must_use]
     pub fn compute_colors(&self, colormap: &ColorMap) -> Vec<Vec4> {
@@ -84,12 +106,21 @@ impl CurveNodeScalarQuantity {
     pub fn set_range(&mut self, min: f32, max: f32) {
         self.range_min = min;
         self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
     }
 
     /// Builds the egui UI for this scalar quantity.
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
         let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
-        polyscope_ui::build_scalar_quantity_ui(
+        let range = (self.range_min, self.range_max);
+        let changed = polyscope_ui::build_scalar_quantity_ui(
             ui,
             &self.name,
             &mut self.enabled,
@@ -97,7 +128,11 @@ impl CurveNodeScalarQuantity {
             &mut self.range_min,
             &mut self.range_max,
             &colormaps,
-        )
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+        changed
     }
 }
 
@@ -145,6 +180,122 @@ impl Quantity for CurveNodeScalarQuantity {
 
 impl VertexQuantity for CurveNodeScalarQuantity {}
 
+/// A scalar quantity that sets the radius of each node sphere.
+///
+/// In relative mode (the default) values are rescaled so that the largest
+/// one maps to the curve network's node radius. In absolute mode values are
+/// used directly as world-space radii.
+pub struct CurveNodeRadiusQuantity {
+    name: String,
+    structure_name: String,
+    values: Vec<f32>,
+    enabled: bool,
+    radius_is_relative: bool,
+}
+
+impl CurveNodeRadiusQuantity {
+    /// Creates a new node radius quantity. Negative values are clamped to zero.
+    pub fn new(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            structure_name: structure_name.into(),
+            values: values.into_iter().map(|v| v.max(0.0)).collect(),
+            enabled: false,
+            radius_is_relative: true,
+        }
+    }
+
+    /// Returns the (clamped) radius values.
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Returns whether values are scaled relative to the node radius.
+    #[must_use]
+    pub fn radius_is_relative(&self) -> bool {
+        self.radius_is_relative
+    }
+
+    /// Sets whether values are scaled relative to the node radius.
+    pub fn set_radius_is_relative(&mut self, is_relative: bool) {
+        self.radius_is_relative = is_relative;
+    }
+
+    /// Computes the final per-node radii given the structure's node radius.
+    #[must_use]
+    pub fn compute_radii(&self, node_radius: f32) -> Vec<f32> {
+        if !self.radius_is_relative {
+            return self.values.clone();
+        }
+
+        let max = self.values.iter().copied().fold(0.0, f32::max);
+        if max <= 0.0 {
+            return vec![0.0; self.values.len()];
+        }
+        let scale = node_radius / max;
+        self.values.iter().map(|&v| v * scale).collect()
+    }
+
+    /// Builds the egui UI for this radius quantity.
+    pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        polyscope_ui::build_radius_quantity_ui(
+            ui,
+            &self.name,
+            &mut self.enabled,
+            &mut self.radius_is_relative,
+        )
+    }
+}
+
+impl Quantity for CurveNodeRadiusQuantity {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn structure_name(&self) -> &str {
+        &self.structure_name
+    }
+
+    fn kind(&self) -> QuantityKind {
+        QuantityKind::Scalar
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn build_ui(&mut self, _ui: &dyn std::any::Any) {
+        // UI is handled by polyscope-ui/src/structure_ui.rs
+    }
+
+    fn refresh(&mut self) {
+        // GPU refresh is handled by polyscope/src/app/render.rs
+    }
+
+    fn data_size(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl VertexQuantity for CurveNodeRadiusQuantity {}
+
 /// A scalar quantity on curve network edges.
 pub struct CurveEdgeScalarQuantity {
     name: String,
@@ -154,6 +305,7 @@ pub struct CurveEdgeScalarQuantity {
     colormap_name: String,
     range_min: f32,
     range_max: f32,
+    range_user_set: bool, // false = range follows the data
 }
 
 impl CurveEdgeScalarQuantity {
@@ -163,8 +315,7 @@ impl CurveEdgeScalarQuantity {
         structure_name: impl Into<String>,
         values: Vec<f32>,
     ) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
@@ -174,6 +325,7 @@ impl CurveEdgeScalarQuantity {
             colormap_name: "viridis".to_string(),
             range_min: min,
             range_max: max,
+            range_user_set: false,
         }
     }
 
@@ -183,6 +335,23 @@ impl CurveEdgeScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
     /// Maps scalar values to colors using the colormap.
     #[must_use]
     pub fn compute_colors(&self, colormap: &ColorMap) -> Vec<Vec4> {
@@ -225,12 +394,21 @@ impl CurveEdgeScalarQuantity {
     pub fn set_range(&mut self, min: f32, max: f32) {
         self.range_min = min;
         self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
     }
 
     /// Builds the egui UI for this scalar quantity.
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
         let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
-        polyscope_ui::build_scalar_quantity_ui(
+        let range = (self.range_min, self.range_max);
+        let changed = polyscope_ui::build_scalar_quantity_ui(
             ui,
             &self.name,
             &mut self.enabled,
@@ -238,7 +416,11 @@ impl CurveEdgeScalarQuantity {
             &mut self.range_min,
             &mut self.range_max,
             &colormaps,
-        )
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+        changed
     }
 }
 
@@ -292,6 +474,7 @@ pub struct CurveNodeColorQuantity {
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
 }
 
 impl CurveNodeColorQuantity {
@@ -306,6 +489,7 @@ impl CurveNodeColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
         }
     }
 
@@ -315,9 +499,37 @@ impl CurveNodeColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     /// Applies this color quantity to the curve network render data.
     pub fn apply_to_render_data(&self, queue: &wgpu::Queue, render_data: &CurveNetworkRenderData) {
-        render_data.update_node_colors(queue, &self.colors);
+        render_data.update_node_colors(queue, &self.linear_colors());
     }
 
     /// Builds the egui UI for this color quantity.
@@ -376,6 +588,7 @@ pub struct CurveEdgeColorQuantity {
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
 }
 
 impl CurveEdgeColorQuantity {
@@ -390,6 +603,7 @@ impl CurveEdgeColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
         }
     }
 
@@ -399,9 +613,37 @@ impl CurveEdgeColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     /// Applies this color quantity to the curve network render data.
     pub fn apply_to_render_data(&self, queue: &wgpu::Queue, render_data: &CurveNetworkRenderData) {
-        render_data.update_edge_colors(queue, &self.colors);
+        render_data.update_edge_colors(queue, &self.linear_colors());
     }
 
     /// Builds the egui UI for this color quantity.
@@ -3,7 +3,7 @@
 use glam::{Vec3, Vec4};
 use polyscope_core::quantity::{Quantity, QuantityKind};
 
-use super::ImageOrigin;
+use super::{ImageOrigin, load_egui_texture};
 
 /// A floating color image quantity (not attached to any structure).
 ///
@@ -15,12 +15,18 @@ pub struct FloatingColorImage {
     colors: Vec<Vec4>, // RGBA per pixel
     origin: ImageOrigin,
     enabled: bool,
+    texture: Option<egui::TextureHandle>,
 }
 
 impl FloatingColorImage {
     /// Creates a new floating color image.
     pub fn new(name: impl Into<String>, width: u32, height: u32, colors: Vec<Vec3>) -> Self {
         let colors = colors.into_iter().map(|c| c.extend(1.0)).collect();
+        Self::from_rgba(name, width, height, colors)
+    }
+
+    /// Creates a new floating color image with per-pixel alpha.
+    pub fn from_rgba(name: impl Into<String>, width: u32, height: u32, colors: Vec<Vec4>) -> Self {
         Self {
             name: name.into(),
             width,
@@ -28,6 +34,7 @@ impl FloatingColorImage {
             colors,
             origin: ImageOrigin::default(),
             enabled: true,
+            texture: None,
         }
     }
 
@@ -58,6 +65,7 @@ impl FloatingColorImage {
     /// Sets the image origin.
     pub fn set_origin(&mut self, origin: ImageOrigin) -> &mut Self {
         self.origin = origin;
+        self.texture = None;
         self
     }
 
@@ -70,6 +78,23 @@ impl FloatingColorImage {
         };
         self.colors[(row * self.width + x) as usize]
     }
+
+    /// Returns the texture used to display the image, creating it on first use.
+    ///
+    /// Returns `None` if the image is empty or has fewer colors than pixels.
+    pub fn egui_texture(&mut self, ctx: &egui::Context) -> Option<&egui::TextureHandle> {
+        let num_pixels = self.width as usize * self.height as usize;
+        if num_pixels == 0 || self.colors.len() < num_pixels {
+            return None;
+        }
+        if self.texture.is_none() {
+            let texture = load_egui_texture(ctx, &self.name, self.width, self.height, |x, y| {
+                self.pixel(x, y)
+            });
+            self.texture = Some(texture);
+        }
+        self.texture.as_ref()
+    }
 }
 
 impl Quantity for FloatingColorImage {
@@ -96,7 +121,12 @@ impl Quantity for FloatingColorImage {
         self.enabled = enabled;
     }
     fn build_ui(&mut self, _ui: &dyn std::any::Any) {}
-    fn refresh(&mut self) {}
+    fn refresh(&mut self) {
+        self.texture = None;
+    }
+    fn clear_gpu_resources(&mut self) {
+        self.texture = None;
+    }
     fn data_size(&self) -> usize {
         self.colors.len()
     }
@@ -156,4 +186,19 @@ mod tests {
         assert_eq!(img.pixel(0, 1), Vec4::new(1.0, 0.0, 0.0, 1.0));
         assert_eq!(img.pixel(1, 1), Vec4::new(0.0, 1.0, 0.0, 1.0));
     }
+
+    #[test]
+    fn test_color_image_texture() {
+        let ctx = egui::Context::default();
+        let mut img = FloatingColorImage::from_rgba("test", 2, 1, vec![Vec4::ONE; 2]);
+        let texture = img.egui_texture(&ctx).unwrap().clone();
+        assert_eq!(texture.size(), [2, 1]);
+        // Cached until the image changes
+        assert_eq!(img.egui_texture(&ctx).unwrap().id(), texture.id());
+        img.set_origin(ImageOrigin::LowerLeft);
+        assert_ne!(img.egui_texture(&ctx).unwrap().id(), texture.id());
+
+        let mut short = FloatingColorImage::from_rgba("short", 2, 2, vec![Vec4::ONE; 3]);
+        assert!(short.egui_texture(&ctx).is_none());
+    }
 }
@@ -11,6 +11,8 @@ pub use color_image::*;
 pub use render_image::*;
 pub use scalar_image::*;
 
+use glam::Vec4;
+
 /// Image origin convention.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ImageOrigin {
@@ -20,3 +22,28 @@ pub enum ImageOrigin {
     /// Image row 0 is the bottom row (OpenGL convention).
     LowerLeft,
 }
+
+/// Uploads an image as an egui texture for display in an overlay window.
+///
+/// `pixel(x, y)` returns the color at column `x` and row `y`, counted from
+/// the top of the displayed image. Components are clamped to `[0, 1]`.
+fn load_egui_texture(
+    ctx: &egui::Context,
+    name: &str,
+    width: u32,
+    height: u32,
+    pixel: impl Fn(u32, u32) -> Vec4,
+) -> egui::TextureHandle {
+    let pixels = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let [r, g, b, a] = (pixel(x, y).clamp(Vec4::ZERO, Vec4::ONE) * 255.0)
+                .round()
+                .to_array()
+                .map(|c| c as u8);
+            egui::Color32::from_rgba_unmultiplied(r, g, b, a)
+        })
+        .collect();
+    let image = egui::ColorImage::new([width as usize, height as usize], pixels);
+    ctx.load_texture(name, image, egui::TextureOptions::NEAREST)
+}
@@ -1,8 +1,10 @@
 //! Floating scalar image quantity.
 
 use polyscope_core::quantity::{Quantity, QuantityKind};
+use polyscope_render::ColorMap;
 
-use super::ImageOrigin;
+use super::{ImageOrigin, load_egui_texture};
+use crate::quantity_update::data_range;
 
 /// A floating scalar image quantity (not attached to any structure).
 ///
@@ -17,13 +19,13 @@ pub struct FloatingScalarImage {
     colormap_name: String,
     data_min: f32,
     data_max: f32,
+    texture: Option<egui::TextureHandle>,
 }
 
 impl FloatingScalarImage {
     /// Creates a new floating scalar image.
     pub fn new(name: impl Into<String>, width: u32, height: u32, values: Vec<f32>) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
@@ -35,6 +37,7 @@ impl FloatingScalarImage {
             colormap_name: "viridis".to_string(),
             data_min: min,
             data_max: max,
+            texture: None,
         }
     }
 
@@ -65,6 +68,7 @@ impl FloatingScalarImage {
     /// Sets the image origin.
     pub fn set_origin(&mut self, origin: ImageOrigin) -> &mut Self {
         self.origin = origin;
+        self.texture = None;
         self
     }
 
@@ -77,6 +81,7 @@ impl FloatingScalarImage {
     /// Sets the colormap name.
     pub fn set_colormap(&mut self, name: impl Into<String>) -> &mut Self {
         self.colormap_name = name.into();
+        self.texture = None;
         self
     }
 
@@ -96,6 +101,7 @@ impl FloatingScalarImage {
     pub fn set_data_range(&mut self, min: f32, max: f32) -> &mut Self {
         self.data_min = min;
         self.data_max = max;
+        self.texture = None;
         self
     }
 
@@ -108,6 +114,34 @@ impl FloatingScalarImage {
         };
         self.values[(row * self.width + x) as usize]
     }
+
+    /// Returns the texture used to display the image, creating it on first
+    /// use by mapping the values over the data range through `colormap`.
+    ///
+    /// Returns `None` if the image is empty or has fewer values than pixels.
+    pub fn egui_texture(
+        &mut self,
+        ctx: &egui::Context,
+        colormap: &ColorMap,
+    ) -> Option<&egui::TextureHandle> {
+        let num_pixels = self.width as usize * self.height as usize;
+        if num_pixels == 0 || self.values.len() < num_pixels {
+            return None;
+        }
+        if self.texture.is_none() {
+            let range = self.data_max - self.data_min;
+            let texture = load_egui_texture(ctx, &self.name, self.width, self.height, |x, y| {
+                let t = if range > 0.0 {
+                    (self.pixel(x, y) - self.data_min) / range
+                } else {
+                    0.0
+                };
+                colormap.sample(t).extend(1.0)
+            });
+            self.texture = Some(texture);
+        }
+        self.texture.as_ref()
+    }
 }
 
 impl Quantity for FloatingScalarImage {
@@ -134,7 +168,12 @@ impl Quantity for FloatingScalarImage {
         self.enabled = enabled;
     }
     fn build_ui(&mut self, _ui: &dyn std::any::Any) {}
-    fn refresh(&mut self) {}
+    fn refresh(&mut self) {
+        self.texture = None;
+    }
+    fn clear_gpu_resources(&mut self) {
+        self.texture = None;
+    }
     fn data_size(&self) -> usize {
         self.values.len()
     }
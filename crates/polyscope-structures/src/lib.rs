@@ -39,17 +39,18 @@ pub mod camera_view;
 pub mod curve_network;
 pub mod floating;
 pub mod point_cloud;
+pub mod quantity_update;
 pub mod surface_mesh;
 pub mod volume_grid;
 pub mod volume_mesh;
 
 pub use camera_view::{CameraExtrinsics, CameraIntrinsics, CameraParameters, CameraView};
-pub use curve_network::CurveNetwork;
+pub use curve_network::{CurveNetwork, CurveRenderMode};
 pub use floating::{
     FloatingColorImage, FloatingColorRenderImage, FloatingDepthRenderImage, FloatingRawColorImage,
     FloatingScalarImage, ImageOrigin,
 };
-pub use point_cloud::PointCloud;
+pub use point_cloud::{PointCloud, PointRenderMode};
 pub use surface_mesh::SurfaceMesh;
 pub use volume_grid::VolumeGrid;
 pub use volume_mesh::{VolumeCellType, VolumeMesh};
@@ -3,6 +3,7 @@
 mod quantities;
 
 use glam::{Mat4, Vec3, Vec4};
+use polyscope_core::error::{PolyscopeError, Result};
 use polyscope_core::pick::PickResult;
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::{HasQuantities, RenderContext, Structure};
@@ -11,17 +12,35 @@ use wgpu::util::DeviceExt;
 
 pub use quantities::*;
 
+/// How point cloud points are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointRenderMode {
+    /// Ray-cast sphere impostors with per-pixel normals and lighting.
+    #[default]
+    Sphere = 0,
+    /// Flat camera-facing squares with uniform shading. Cheapest for very
+    /// large clouds.
+    Quad = 1,
+    /// Flat camera-facing discs with uniform shading.
+    Circle = 2,
+}
+
 /// A point cloud structure.
 pub struct PointCloud {
     name: String,
     points: Vec<Vec3>,
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
     render_data: Option<PointCloudRenderData>,
     material: String,
     point_radius: f32,
+    point_render_mode: PointRenderMode,
     base_color: Vec4,
+    transparency: f32,
+    display_fraction: f32,
     // GPU picking resources
     pick_uniform_buffer: Option<wgpu::Buffer>,
     pick_bind_group: Option<wgpu::BindGroup>,
@@ -35,12 +54,17 @@ impl PointCloud {
             name: name.into(),
             points,
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
             render_data: None,
             material: "clay".to_string(),
             point_radius: 0.01,
+            point_render_mode: PointRenderMode::default(),
             base_color: Vec4::new(0.2, 0.5, 0.8, 1.0),
+            transparency: 0.0, // 0.0 = fully opaque, 1.0 = fully transparent
+            display_fraction: 1.0,
             pick_uniform_buffer: None,
             pick_bind_group: None,
             global_start: 0,
@@ -72,6 +96,20 @@ impl PointCloud {
         self
     }
 
+    /// Adds a scalar quantity defined on a subset of the points.
+    ///
+    /// Points with `None` have no data and are drawn in
+    /// `Options::no_data_color` instead of the colormap.
+    pub fn add_scalar_quantity_masked(
+        &mut self,
+        name: impl Into<String>,
+        values: Vec<Option<f32>>,
+    ) -> &mut Self {
+        let quantity = PointCloudScalarQuantity::new_masked(name, self.name.clone(), values);
+        self.add_quantity(Box::new(quantity));
+        self
+    }
+
     /// Adds a vector quantity to this point cloud.
     pub fn add_vector_quantity(
         &mut self,
@@ -90,6 +128,56 @@ impl PointCloud {
         self
     }
 
+    /// Adds a color quantity with explicit per-point RGBA alpha values.
+    ///
+    /// Points with alpha below 1 are blended even if the point cloud itself
+    /// is opaque; the alpha multiplies the point cloud's transparency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `colors` does not have one
+    /// entry per point.
+    pub fn add_color_quantity_with_alpha(
+        &mut self,
+        name: impl Into<String>,
+        colors: Vec<Vec4>,
+    ) -> Result<&mut Self> {
+        if colors.len() != self.points.len() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.points.len(),
+                actual: colors.len(),
+            });
+        }
+        let quantity = PointCloudColorQuantity::new_with_alpha(name, self.name.clone(), colors);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
+    }
+
+    /// Adds a radius quantity that sizes each point by a scalar value.
+    ///
+    /// Negative values are clamped to zero. See [`PointCloudRadiusQuantity`]
+    /// for how values are scaled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have one
+    /// entry per point.
+    pub fn add_scalar_radius_quantity(
+        &mut self,
+        name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Result<&mut Self> {
+        if values.len() != self.points.len() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.points.len(),
+                actual: values.len(),
+            });
+        }
+        let quantity = PointCloudRadiusQuantity::new(name, self.name.clone(), values);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
+    }
+
     /// Initializes GPU resources for this point cloud.
     pub fn init_gpu_resources(
         &mut self,
@@ -126,7 +214,8 @@ impl PointCloud {
         let pick_uniforms = PickUniforms {
             global_start,
             point_radius: self.point_radius,
-            _padding: [0.0; 2],
+            point_stride: self.display_stride(),
+            use_per_point_radius: u32::from(self.active_radius_quantity().is_some()),
         };
         let pick_uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("point cloud pick uniforms"),
@@ -134,7 +223,7 @@ impl PointCloud {
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        // Create pick bind group (reuses position buffer from render_data)
+        // Create pick bind group (reuses position and radius buffers from render_data)
         if let Some(render_data) = &self.render_data {
             let pick_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("point cloud pick bind group"),
@@ -152,6 +241,10 @@ impl PointCloud {
                         binding: 2,
                         resource: render_data.position_buffer.as_entire_binding(),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: render_data.radius_buffer.as_entire_binding(),
+                    },
                 ],
             });
             self.pick_bind_group = Some(pick_bind_group);
@@ -172,7 +265,8 @@ impl PointCloud {
             let pick_uniforms = PickUniforms {
                 global_start: self.global_start,
                 point_radius: self.point_radius,
-                _padding: [0.0; 2],
+                point_stride: self.display_stride(),
+                use_per_point_radius: u32::from(self.active_radius_quantity().is_some()),
             };
             queue.write_buffer(buffer, 0, bytemuck::cast_slice(&[pick_uniforms]));
         }
@@ -189,6 +283,17 @@ impl PointCloud {
         self.point_radius
     }
 
+    /// Sets how points are drawn.
+    pub fn set_point_render_mode(&mut self, mode: PointRenderMode) {
+        self.point_render_mode = mode;
+    }
+
+    /// Gets how points are drawn.
+    #[must_use]
+    pub fn point_render_mode(&self) -> PointRenderMode {
+        self.point_render_mode
+    }
+
     /// Sets the base color.
     pub fn set_base_color(&mut self, color: Vec3) {
         self.base_color = color.extend(1.0);
@@ -200,6 +305,67 @@ impl PointCloud {
         self.base_color
     }
 
+    /// Gets the transparency (0.0 = opaque, 1.0 = fully transparent).
+    #[must_use]
+    pub fn transparency(&self) -> f32 {
+        self.transparency
+    }
+
+    /// Sets the transparency.
+    pub fn set_transparency(&mut self, transparency: f32) {
+        self.transparency = transparency.clamp(0.0, 1.0);
+    }
+
+    /// Gets the fraction of points that are drawn.
+    #[must_use]
+    pub fn display_fraction(&self) -> f32 {
+        self.display_fraction
+    }
+
+    /// Sets the fraction of points that are drawn (clamped to 0..=1).
+    ///
+    /// Points are subsampled with a fixed stride, so the same points are
+    /// drawn and pickable every frame. Quantities and [`Self::num_points`]
+    /// are unaffected.
+    pub fn set_display_fraction(&mut self, fraction: f32) {
+        self.display_fraction = fraction.clamp(0.0, 1.0);
+    }
+
+    /// Returns the index stride between drawn points.
+    #[must_use]
+    pub fn display_stride(&self) -> u32 {
+        if self.display_fraction <= 0.0 {
+            return 1;
+        }
+        ((1.0 / self.display_fraction).round() as u32).max(1)
+    }
+
+    /// Returns the number of points drawn at the current display fraction.
+    #[must_use]
+    pub fn num_displayed_points(&self) -> u32 {
+        if self.display_fraction <= 0.0 {
+            return 0;
+        }
+        (self.points.len() as u32).div_ceil(self.display_stride())
+    }
+
+    /// Returns the render uniforms for the structure itself, before any
+    /// quantity switches on per-point colors or radii.
+    #[must_use]
+    pub fn uniforms(&self) -> PointUniforms {
+        PointUniforms {
+            model_matrix: self.transform.to_cols_array_2d(),
+            point_radius: self.point_radius,
+            use_per_point_color: 0,
+            use_per_point_radius: 0,
+            transparency: self.transparency,
+            base_color: self.base_color.to_array(),
+            render_mode: self.point_render_mode as u32,
+            point_stride: self.display_stride(),
+            _padding: [0; 2],
+        }
+    }
+
     /// Returns the currently active color quantity, if any.
     #[must_use]
     pub fn active_color_quantity(&self) -> Option<&PointCloudColorQuantity> {
@@ -230,6 +396,15 @@ impl PointCloud {
         None
     }
 
+    /// Returns the currently active radius quantity, if any.
+    #[must_use]
+    pub fn active_radius_quantity(&self) -> Option<&PointCloudRadiusQuantity> {
+        self.quantities
+            .iter()
+            .filter(|q| q.is_enabled())
+            .find_map(|q| q.as_any().downcast_ref::<PointCloudRadiusQuantity>())
+    }
+
     /// Returns the currently active vector quantity, if any.
     #[must_use]
     pub fn active_vector_quantity(&self) -> Option<&PointCloudVectorQuantity> {
@@ -297,27 +472,36 @@ impl PointCloud {
                     .downcast_mut::<PointCloudVectorQuantity>()
                 {
                     vq.build_egui_ui(ui);
+                } else if let Some(rq) = quantity
+                    .as_any_mut()
+                    .downcast_mut::<PointCloudRadiusQuantity>()
+                {
+                    rq.build_egui_ui(ui);
                 }
             }
         }
     }
 
     /// Updates GPU buffers based on current state.
-    pub fn update_gpu_buffers(&self, queue: &wgpu::Queue, color_maps: &ColorMapRegistry) {
+    ///
+    /// `no_data_color` is the global `Options::no_data_color`, used for points
+    /// without data in a masked scalar quantity.
+    pub fn update_gpu_buffers(
+        &self,
+        queue: &wgpu::Queue,
+        color_maps: &ColorMapRegistry,
+        no_data_color: Vec4,
+    ) {
         let Some(render_data) = &self.render_data else {
             return;
         };
 
-        // Convert glam Mat4 to [[f32; 4]; 4] for GPU
-        let model_matrix = self.transform.to_cols_array_2d();
+        let mut uniforms = self.uniforms();
 
-        let mut uniforms = PointUniforms {
-            model_matrix,
-            point_radius: self.point_radius,
-            use_per_point_color: 0,
-            _padding: [0.0; 2],
-            base_color: self.base_color.to_array(),
-        };
+        if let Some(radius_q) = self.active_radius_quantity() {
+            uniforms.use_per_point_radius = 1;
+            render_data.update_radii(queue, &radius_q.compute_radii(self.point_radius));
+        }
 
         // Priority: color quantity > scalar quantity > base color
         if let Some(color_q) = self.active_color_quantity() {
@@ -326,7 +510,12 @@ impl PointCloud {
         } else if let Some(scalar_q) = self.active_scalar_quantity() {
             if let Some(colormap) = color_maps.get(scalar_q.colormap_name()) {
                 uniforms.use_per_point_color = 1;
-                let colors = scalar_q.compute_colors(colormap);
+                let mut colors = scalar_q.compute_colors(colormap);
+                for (color, value) in colors.iter_mut().zip(scalar_q.values()) {
+                    if value.is_nan() {
+                        *color = no_data_color;
+                    }
+                }
                 render_data.update_colors(queue, &colors);
             }
         }
@@ -409,6 +598,22 @@ impl Structure for PointCloud {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn material(&self) -> &str {
         &self.material
     }
@@ -442,6 +647,21 @@ impl Structure for PointCloud {
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, PointCloudRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         for quantity in &mut self.quantities {
             quantity.refresh();
@@ -474,3 +694,108 @@ impl HasQuantities for PointCloud {
         &self.quantities
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_cloud_transparency() {
+        let mut pc = PointCloud::new("test", vec![Vec3::ZERO, Vec3::X]);
+        assert!(pc.transparency().abs() < 1e-6);
+        assert!(pc.uniforms().transparency.abs() < 1e-6);
+
+        pc.set_transparency(0.25);
+        assert!((pc.transparency() - 0.25).abs() < 1e-6);
+        assert!((pc.uniforms().transparency - 0.25).abs() < 1e-6);
+
+        pc.set_transparency(-1.0);
+        assert!(pc.transparency().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_color_quantity_with_alpha() {
+        let mut pc = PointCloud::new("test", vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z]);
+        let colors = vec![
+            Vec4::new(1.0, 0.0, 0.0, 0.5),
+            Vec4::new(0.0, 1.0, 0.0, 0.5),
+            Vec4::new(0.0, 0.0, 1.0, 1.0),
+            Vec4::new(1.0, 1.0, 1.0, 1.0),
+        ];
+        pc.add_color_quantity_with_alpha("rgba", colors.clone())
+            .unwrap();
+        pc.get_quantity_mut("rgba").unwrap().set_enabled(true);
+
+        // The colors uploaded to the GPU keep their alpha
+        let quantity = pc.active_color_quantity().unwrap();
+        assert!(quantity.has_transparency());
+        assert_eq!(quantity.colors(), colors.as_slice());
+
+        // RGB colors are opaque
+        pc.add_color_quantity("rgb", vec![Vec3::ONE; 4]);
+        let rgb = pc.get_quantity("rgb").unwrap().as_any();
+        let rgb = rgb.downcast_ref::<PointCloudColorQuantity>().unwrap();
+        assert!(!rgb.has_transparency());
+
+        assert!(matches!(
+            pc.add_color_quantity_with_alpha("short", vec![Vec4::ONE]),
+            Err(PolyscopeError::SizeMismatch {
+                expected: 4,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_point_render_mode_uniforms() {
+        let mut pc = PointCloud::new("test", vec![Vec3::ZERO, Vec3::X]);
+        assert_eq!(pc.point_render_mode(), PointRenderMode::Sphere);
+        assert_eq!(pc.uniforms().render_mode, 0);
+
+        pc.set_point_render_mode(PointRenderMode::Quad);
+        assert_eq!(pc.uniforms().render_mode, 1);
+        pc.set_point_render_mode(PointRenderMode::Circle);
+        assert_eq!(pc.uniforms().render_mode, 2);
+    }
+
+    #[test]
+    fn test_display_fraction() {
+        let points = (0..1000u16).map(|i| Vec3::splat(f32::from(i))).collect();
+        let mut pc = PointCloud::new("test", points);
+        assert_eq!(pc.num_displayed_points(), 1000);
+        assert_eq!(pc.uniforms().point_stride, 1);
+
+        pc.set_display_fraction(0.1);
+        assert_eq!(pc.num_displayed_points(), 100);
+        assert_eq!(pc.uniforms().point_stride, 10);
+        assert_eq!(pc.num_points(), 1000);
+
+        pc.set_display_fraction(0.0);
+        assert_eq!(pc.num_displayed_points(), 0);
+        pc.set_display_fraction(2.0);
+        assert_eq!(pc.num_displayed_points(), 1000);
+    }
+
+    #[test]
+    fn test_vector_quantity_uniforms() {
+        let mut vq = PointCloudVectorQuantity::new("v", "pc", vec![Vec3::X, Vec3::Y * 3.0]);
+        let model = Mat4::from_translation(Vec3::ONE);
+
+        let uniforms = vq.uniforms(&model, 10.0);
+        assert!((uniforms.length_scale - 1.0).abs() < 1e-6);
+        assert_eq!(uniforms.normalize, 0);
+        assert!(Mat4::from_cols_array(&uniforms.model).abs_diff_eq(model, 1e-6));
+
+        vq.set_length_scale(0.05, true);
+        vq.set_radius(0.02);
+        vq.set_normalize(true);
+        let uniforms = vq.uniforms(&model, 10.0);
+        assert!((uniforms.length_scale - 0.5).abs() < 1e-6);
+        assert!((uniforms.radius - 0.02).abs() < 1e-6);
+        assert_eq!(uniforms.normalize, 1);
+
+        vq.set_length_scale(0.05, false);
+        assert!(!vq.length_is_relative());
+        assert!((vq.uniforms(&model, 10.0).length_scale - 0.05).abs() < 1e-6);
+    }
+}
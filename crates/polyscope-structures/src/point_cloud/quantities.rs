@@ -1,8 +1,17 @@
 //! Point cloud quantity implementations.
 
 use glam::{Vec3, Vec4};
+use polyscope_core::color_space::ColorSpace;
+use polyscope_core::error::Result;
 use polyscope_core::quantity::{Quantity, QuantityKind, VertexQuantity};
-use polyscope_render::{ColorMap, PointCloudRenderData, VectorRenderData, VectorUniforms};
+use polyscope_render::{
+    ColorMap, ColorMapRegistry, PointCloudRenderData, VectorRenderData, VectorUniforms,
+};
+
+use crate::quantity_update::{data_range, replace_data};
+
+/// Colormap used by scalar quantities unless another one is selected.
+pub const DEFAULT_COLORMAP: &str = "viridis";
 
 /// A scalar quantity on a point cloud.
 pub struct PointCloudScalarQuantity {
@@ -13,6 +22,7 @@ pub struct PointCloudScalarQuantity {
     colormap_name: String,
     range_min: f32,
     range_max: f32,
+    range_user_set: bool, // false = range follows the data
 }
 
 impl PointCloudScalarQuantity {
@@ -22,27 +32,62 @@ impl PointCloudScalarQuantity {
         structure_name: impl Into<String>,
         values: Vec<f32>,
     ) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
             structure_name: structure_name.into(),
             values,
             enabled: false,
-            colormap_name: "viridis".to_string(),
+            colormap_name: DEFAULT_COLORMAP.to_string(),
             range_min: min,
             range_max: max,
+            range_user_set: false,
         }
     }
 
-    /// Returns the scalar values.
+    /// Creates a scalar quantity defined on a subset of the points; `None`
+    /// entries have no data and are stored as NaN.
+    pub fn new_masked(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        values: Vec<Option<f32>>,
+    ) -> Self {
+        let values = values.into_iter().map(|v| v.unwrap_or(f32::NAN)).collect();
+        Self::new(name, structure_name, values)
+    }
+
+    /// Returns the scalar values. Points without data hold NaN.
     #[must_use]
     pub fn values(&self) -> &[f32] {
         &self.values
     }
 
-    /// Maps scalar values to colors using the colormap.
+    /// Returns whether some points have no data (NaN values).
+    #[must_use]
+    pub fn has_missing_values(&self) -> bool {
+        self.values.iter().any(|v| v.is_nan())
+    }
+
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
+    /// Maps scalar values to colors using the colormap. Points without data
+    /// map to transparent black.
     #[must_use]
     pub fn compute_colors(&self, colormap: &ColorMap) -> Vec<Vec4> {
         let range = self.range_max - self.range_min;
@@ -51,7 +96,10 @@ impl PointCloudScalarQuantity {
         self.values
             .iter()
             .map(|&v| {
-                let t = (v - self.range_min) / range;
+                if v.is_nan() {
+                    return Vec4::ZERO;
+                }
+                let t = ((v - self.range_min) / range).clamp(0.0, 1.0);
                 colormap.sample(t).extend(1.0)
             })
             .collect()
@@ -68,6 +116,24 @@ impl PointCloudScalarQuantity {
         self.colormap_name = name.into();
     }
 
+    /// Sets the colormap by name, validated against a colormap registry.
+    ///
+    /// Unknown names fall back to [`DEFAULT_COLORMAP`] and log a warning.
+    /// Returns whether the requested colormap was found.
+    pub fn set_colormap_checked(&mut self, name: &str, color_maps: &ColorMapRegistry) -> bool {
+        if color_maps.get(name).is_some() {
+            self.colormap_name = name.to_string();
+            true
+        } else {
+            log::warn!(
+                "unknown colormap '{name}' for quantity '{}', using '{DEFAULT_COLORMAP}'",
+                self.name
+            );
+            self.colormap_name = DEFAULT_COLORMAP.to_string();
+            false
+        }
+    }
+
     /// Gets the range minimum.
     #[must_use]
     pub fn range_min(&self) -> f32 {
@@ -84,12 +150,29 @@ impl PointCloudScalarQuantity {
     pub fn set_range(&mut self, min: f32, max: f32) {
         self.range_min = min;
         self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Sets a fixed colormap range.
+    ///
+    /// Values outside `[min, max]` are clamped to the ends of the colormap.
+    /// Use this to keep colors comparable across frames or datasets.
+    pub fn set_map_range(&mut self, min: f32, max: f32) {
+        self.set_range(min, max);
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
     }
 
     /// Builds the egui UI for this scalar quantity.
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
         let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
-        polyscope_ui::build_scalar_quantity_ui(
+        let range = (self.range_min, self.range_max);
+        let changed = polyscope_ui::build_scalar_quantity_ui(
             ui,
             &self.name,
             &mut self.enabled,
@@ -97,7 +180,11 @@ impl PointCloudScalarQuantity {
             &mut self.range_min,
             &mut self.range_max,
             &colormaps,
-        )
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+        changed
     }
 }
 
@@ -145,6 +232,122 @@ impl Quantity for PointCloudScalarQuantity {
 
 impl VertexQuantity for PointCloudScalarQuantity {}
 
+/// A scalar quantity that sets the radius of each point.
+///
+/// In relative mode (the default) values are rescaled so that the largest
+/// one maps to the point cloud's point radius. In absolute mode values are
+/// used directly as world-space radii.
+pub struct PointCloudRadiusQuantity {
+    name: String,
+    structure_name: String,
+    values: Vec<f32>,
+    enabled: bool,
+    radius_is_relative: bool,
+}
+
+impl PointCloudRadiusQuantity {
+    /// Creates a new radius quantity. Negative values are clamped to zero.
+    pub fn new(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            structure_name: structure_name.into(),
+            values: values.into_iter().map(|v| v.max(0.0)).collect(),
+            enabled: false,
+            radius_is_relative: true,
+        }
+    }
+
+    /// Returns the (clamped) radius values.
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Returns whether values are scaled relative to the point radius.
+    #[must_use]
+    pub fn radius_is_relative(&self) -> bool {
+        self.radius_is_relative
+    }
+
+    /// Sets whether values are scaled relative to the point radius.
+    pub fn set_radius_is_relative(&mut self, is_relative: bool) {
+        self.radius_is_relative = is_relative;
+    }
+
+    /// Computes the final per-point radii given the structure's point radius.
+    #[must_use]
+    pub fn compute_radii(&self, point_radius: f32) -> Vec<f32> {
+        if !self.radius_is_relative {
+            return self.values.clone();
+        }
+
+        let max = self.values.iter().copied().fold(0.0, f32::max);
+        if max <= 0.0 {
+            return vec![0.0; self.values.len()];
+        }
+        let scale = point_radius / max;
+        self.values.iter().map(|&v| v * scale).collect()
+    }
+
+    /// Builds the egui UI for this radius quantity.
+    pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        polyscope_ui::build_radius_quantity_ui(
+            ui,
+            &self.name,
+            &mut self.enabled,
+            &mut self.radius_is_relative,
+        )
+    }
+}
+
+impl Quantity for PointCloudRadiusQuantity {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn structure_name(&self) -> &str {
+        &self.structure_name
+    }
+
+    fn kind(&self) -> QuantityKind {
+        QuantityKind::Scalar
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn build_ui(&mut self, _ui: &dyn std::any::Any) {
+        // UI is handled by polyscope-ui/src/structure_ui.rs
+    }
+
+    fn refresh(&mut self) {
+        // GPU refresh is handled by polyscope/src/app/render.rs
+    }
+
+    fn data_size(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl VertexQuantity for PointCloudRadiusQuantity {}
+
 /// A vector quantity on a point cloud.
 pub struct PointCloudVectorQuantity {
     name: String,
@@ -152,9 +355,13 @@ pub struct PointCloudVectorQuantity {
     vectors: Vec<Vec3>,
     enabled: bool,
     length_scale: f32,
+    length_is_relative: bool,
+    normalize: bool,
     radius: f32,
     color: Vec4,
     render_data: Option<VectorRenderData>,
+    /// Set when the vectors changed after the GPU buffer was created.
+    vectors_dirty: bool,
 }
 
 impl PointCloudVectorQuantity {
@@ -170,9 +377,12 @@ impl PointCloudVectorQuantity {
             vectors,
             enabled: false,
             length_scale: 1.0,
+            length_is_relative: false,
+            normalize: false,
             radius: 0.005,
             color: Vec4::new(0.8, 0.2, 0.2, 1.0), // Red
             render_data: None,
+            vectors_dirty: false,
         }
     }
 
@@ -182,6 +392,28 @@ impl PointCloudVectorQuantity {
         &self.vectors
     }
 
+    /// Replaces the vectors. The existing GPU buffer is rewritten in place on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one vector per
+    /// existing vector.
+    pub fn update_vectors(&mut self, vectors: Vec<Vec3>) -> Result<()> {
+        replace_data(&mut self.vectors, vectors)?;
+        self.vectors_dirty = true;
+        Ok(())
+    }
+
+    /// Writes vectors changed by [`Self::update_vectors`] to the existing GPU
+    /// buffer.
+    pub fn upload_vectors(&mut self, queue: &wgpu::Queue) {
+        if let (true, Some(render_data)) = (self.vectors_dirty, &self.render_data) {
+            render_data.update_vectors(queue, &self.vectors);
+            self.vectors_dirty = false;
+        }
+    }
+
     /// Initializes GPU resources for this vector quantity.
     pub fn init_gpu_resources(
         &mut self,
@@ -197,6 +429,7 @@ impl PointCloudVectorQuantity {
             base_positions,
             &self.vectors,
         ));
+        self.vectors_dirty = false;
     }
 
     /// Returns the render data if initialized.
@@ -205,23 +438,64 @@ impl PointCloudVectorQuantity {
         self.render_data.as_ref()
     }
 
-    /// Updates GPU uniforms with the given model transform.
-    pub fn update_uniforms(&self, queue: &wgpu::Queue, model: &glam::Mat4) {
+    /// Returns the GPU uniforms for the given model transform and
+    /// structure length scale.
+    #[must_use]
+    pub fn uniforms(&self, model: &glam::Mat4, structure_length_scale: f32) -> VectorUniforms {
+        let length_scale = if self.length_is_relative {
+            self.length_scale * structure_length_scale
+        } else {
+            self.length_scale
+        };
+        VectorUniforms {
+            model: model.to_cols_array(),
+            length_scale,
+            radius: self.radius,
+            normalize: u32::from(self.normalize),
+            _padding: 0.0,
+            color: self.color.to_array(),
+        }
+    }
+
+    /// Updates GPU uniforms with the given model transform and structure
+    /// length scale.
+    pub fn update_uniforms(
+        &self,
+        queue: &wgpu::Queue,
+        model: &glam::Mat4,
+        structure_length_scale: f32,
+    ) {
         if let Some(render_data) = &self.render_data {
-            let uniforms = VectorUniforms {
-                model: model.to_cols_array(),
-                length_scale: self.length_scale,
-                radius: self.radius,
-                _padding: [0.0; 2],
-                color: self.color.to_array(),
-            };
-            render_data.update_uniforms(queue, &uniforms);
+            render_data.update_uniforms(queue, &self.uniforms(model, structure_length_scale));
         }
     }
 
     /// Sets the length scale.
-    pub fn set_length_scale(&mut self, scale: f32) {
+    ///
+    /// Arrow lengths are the vector magnitudes times `scale`, or just `scale`
+    /// when normalized. If `relative`, `scale` is further multiplied by the
+    /// structure's length scale.
+    pub fn set_length_scale(&mut self, scale: f32, relative: bool) {
         self.length_scale = scale;
+        self.length_is_relative = relative;
+    }
+
+    /// Returns whether the length scale is relative to the structure size.
+    #[must_use]
+    pub fn length_is_relative(&self) -> bool {
+        self.length_is_relative
+    }
+
+    /// Sets whether all arrows are drawn with the same length, keeping
+    /// only their direction.
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    /// Returns whether arrows are normalized to a common length.
+    #[must_use]
+    pub fn is_normalized(&self) -> bool {
+        self.normalize
     }
 
     /// Sets the radius.
@@ -311,6 +585,12 @@ impl Quantity for PointCloudVectorQuantity {
         self.render_data = None;
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
+
     fn data_size(&self) -> usize {
         self.vectors.len()
     }
@@ -324,6 +604,7 @@ pub struct PointCloudColorQuantity {
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
 }
 
 impl PointCloudColorQuantity {
@@ -338,6 +619,22 @@ impl PointCloudColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
+        }
+    }
+
+    /// Creates a new color quantity with explicit RGBA alpha values.
+    pub fn new_with_alpha(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        colors: Vec<Vec4>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            structure_name: structure_name.into(),
+            colors,
+            enabled: false,
+            color_space: ColorSpace::default(),
         }
     }
 
@@ -347,9 +644,43 @@ impl PointCloudColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
+    /// Returns true if any color has alpha < 1.0.
+    #[must_use]
+    pub fn has_transparency(&self) -> bool {
+        self.colors.iter().any(|c| c.w < 0.999)
+    }
+
     /// Applies this color quantity to the point cloud render data.
     pub fn apply_to_render_data(&self, queue: &wgpu::Queue, render_data: &PointCloudRenderData) {
-        render_data.update_colors(queue, &self.colors);
+        render_data.update_colors(queue, &self.linear_colors());
     }
 
     /// Builds the egui UI for this color quantity.
@@ -0,0 +1,178 @@
+//! In-place updates of quantity data.
+
+use polyscope_core::color_space::ColorSpace;
+use polyscope_core::error::{PolyscopeError, Result};
+use polyscope_core::quantity::Quantity;
+
+use crate::curve_network::{
+    CurveEdgeColorQuantity, CurveEdgeScalarQuantity, CurveNodeColorQuantity,
+    CurveNodeScalarQuantity,
+};
+use crate::point_cloud::{PointCloudColorQuantity, PointCloudScalarQuantity};
+use crate::surface_mesh::{
+    MeshCornerColorQuantity, MeshEdgeScalarQuantity, MeshFaceColorQuantity, MeshFaceScalarQuantity,
+    MeshVertexColorQuantity, MeshVertexScalarQuantity,
+};
+use crate::volume_grid::{VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity};
+use crate::volume_mesh::{
+    VolumeMeshCellColorQuantity, VolumeMeshCellScalarQuantity, VolumeMeshVertexColorQuantity,
+    VolumeMeshVertexScalarQuantity,
+};
+
+/// Replaces `data` with `new`, which must have the same length.
+pub(crate) fn replace_data<T>(data: &mut Vec<T>, new: Vec<T>) -> Result<()> {
+    if new.len() != data.len() {
+        return Err(PolyscopeError::SizeMismatch {
+            expected: data.len(),
+            actual: new.len(),
+        });
+    }
+    *data = new;
+    Ok(())
+}
+
+/// Returns the min and max of `values`, ignoring NaN (missing) entries.
+pub(crate) fn data_range(values: &[f32]) -> (f32, f32) {
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    (min, max)
+}
+
+/// Replaces the values of a scalar quantity of any structure, keeping its
+/// colormap. The colormap range follows the new values unless it was set
+/// explicitly.
+///
+/// Returns `None` if `quantity` is not a scalar quantity.
+pub fn update_scalar_values(quantity: &mut dyn Quantity, values: Vec<f32>) -> Option<Result<()>> {
+    let any = quantity.as_any_mut();
+    macro_rules! update_as {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if let Some(q) = any.downcast_mut::<$ty>() {
+                    return Some(q.update_values(values));
+                }
+            )*
+        };
+    }
+    update_as!(
+        PointCloudScalarQuantity,
+        MeshVertexScalarQuantity,
+        MeshFaceScalarQuantity,
+        MeshEdgeScalarQuantity,
+        CurveNodeScalarQuantity,
+        CurveEdgeScalarQuantity,
+        VolumeMeshVertexScalarQuantity,
+        VolumeMeshCellScalarQuantity,
+        VolumeGridNodeScalarQuantity,
+        VolumeGridCellScalarQuantity,
+    );
+    None
+}
+
+/// Sets how the colors of a color quantity of any structure are encoded.
+///
+/// Returns `false` if `quantity` is not a color quantity.
+pub fn set_color_space(quantity: &mut dyn Quantity, color_space: ColorSpace) -> bool {
+    let any = quantity.as_any_mut();
+    macro_rules! set_as {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if let Some(q) = any.downcast_mut::<$ty>() {
+                    q.set_color_space(color_space);
+                    return true;
+                }
+            )*
+        };
+    }
+    set_as!(
+        PointCloudColorQuantity,
+        MeshVertexColorQuantity,
+        MeshFaceColorQuantity,
+        MeshCornerColorQuantity,
+        CurveNodeColorQuantity,
+        CurveEdgeColorQuantity,
+        VolumeMeshVertexColorQuantity,
+        VolumeMeshCellColorQuantity,
+    );
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PointCloud;
+    use glam::Vec3;
+    use polyscope_core::structure::HasQuantities;
+
+    #[test]
+    fn test_update_scalar_values_in_place() {
+        let mut pc = PointCloud::new("pc", vec![Vec3::ZERO, Vec3::X]);
+        pc.add_scalar_quantity("s", vec![0.0, 1.0]);
+        pc.add_vector_quantity("v", vec![Vec3::X, Vec3::Y]);
+
+        let q = pc.get_quantity_mut("s").unwrap();
+        let sq = q.as_any_mut().downcast_mut::<PointCloudScalarQuantity>();
+        sq.unwrap().set_colormap("coolwarm");
+        assert!(
+            update_scalar_values(q.as_mut(), vec![1.0, 0.0])
+                .unwrap()
+                .is_ok()
+        );
+        assert!(matches!(
+            update_scalar_values(q.as_mut(), vec![1.0]),
+            Some(Err(PolyscopeError::SizeMismatch {
+                expected: 2,
+                actual: 1
+            }))
+        ));
+
+        let q = pc.get_quantity("s").unwrap().as_any();
+        let sq = q.downcast_ref::<PointCloudScalarQuantity>().unwrap();
+        assert_eq!(sq.values(), &[1.0, 0.0]);
+        assert_eq!(sq.colormap_name(), "coolwarm");
+        // The range follows the data until it is set explicitly
+        assert!(sq.range_min().abs() < 1e-6 && (sq.range_max() - 1.0).abs() < 1e-6);
+
+        let q = pc.get_quantity_mut("s").unwrap();
+        assert!(update_scalar_values(q.as_mut(), vec![4.0, 2.0]).is_some());
+        let sq = q.as_any_mut().downcast_mut::<PointCloudScalarQuantity>();
+        let sq = sq.unwrap();
+        assert_eq!((sq.range_min(), sq.range_max()), (2.0, 4.0));
+        sq.set_map_range(0.0, 10.0);
+        sq.update_values(vec![5.0, 6.0]).unwrap();
+        assert_eq!((sq.range_min(), sq.range_max()), (0.0, 10.0));
+        sq.reset_map_range();
+        assert_eq!((sq.range_min(), sq.range_max()), (5.0, 6.0));
+        sq.update_values(vec![1.0, 3.0]).unwrap();
+        assert_eq!((sq.range_min(), sq.range_max()), (1.0, 3.0));
+
+        let vq = pc.get_quantity_mut("v").unwrap();
+        assert!(update_scalar_values(vq.as_mut(), vec![0.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn test_color_space_of_uploaded_colors() {
+        let mut pc = PointCloud::new("pc", vec![Vec3::ZERO]);
+        pc.add_color_quantity("c", vec![Vec3::splat(0.5)]);
+        let q = pc.get_quantity_mut("c").unwrap();
+
+        let linear_gray = |q: &dyn Quantity| {
+            let cq = q
+                .as_any()
+                .downcast_ref::<PointCloudColorQuantity>()
+                .unwrap();
+            cq.linear_colors()[0]
+        };
+        // sRGB by default: mid gray is darker in linear space
+        let srgb = linear_gray(q.as_ref());
+        assert!((srgb.x - 0.214).abs() < 1e-3 && (srgb.w - 1.0).abs() < 1e-6);
+
+        assert!(set_color_space(q.as_mut(), ColorSpace::Linear));
+        let linear = linear_gray(q.as_ref());
+        assert!((linear.x - 0.5).abs() < 1e-6);
+
+        pc.add_scalar_quantity("s", vec![0.0]);
+        let sq = pc.get_quantity_mut("s").unwrap();
+        assert!(!set_color_space(sq.as_mut(), ColorSpace::Linear));
+    }
+}
@@ -0,0 +1,193 @@
+//! Vertex and face adjacency queries on surface meshes.
+//!
+//! Adjacency follows the polygon edges of the faces as given, not the
+//! internal triangulation. It is built on the first query and kept until the
+//! faces change.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::SurfaceMesh;
+
+/// Lazily built adjacency of a mesh. Replaced with an empty cache when the
+/// faces change.
+#[derive(Default)]
+pub(super) struct AdjacencyCache(OnceLock<MeshAdjacency>);
+
+/// Connectivity derived from the faces. Every list is sorted and free of
+/// duplicates.
+struct MeshAdjacency {
+    vertex_faces: Vec<Vec<u32>>,
+    face_faces: Vec<Vec<u32>>,
+    one_ring: Vec<Vec<u32>>,
+}
+
+impl MeshAdjacency {
+    fn new(num_vertices: usize, faces: &[Vec<u32>]) -> Self {
+        let mut vertex_faces = vec![Vec::new(); num_vertices];
+        let mut one_ring = vec![Vec::new(); num_vertices];
+        let mut edge_faces: HashMap<(u32, u32), Vec<u32>> = HashMap::new();
+
+        for (f, face) in faces.iter().enumerate() {
+            let f = f as u32;
+            for (i, &v) in face.iter().enumerate() {
+                let next = face[(i + 1) % face.len()];
+                if let Some(list) = vertex_faces.get_mut(v as usize) {
+                    list.push(f);
+                }
+                if v == next {
+                    continue;
+                }
+                if let Some(list) = one_ring.get_mut(v as usize) {
+                    list.push(next);
+                }
+                if let Some(list) = one_ring.get_mut(next as usize) {
+                    list.push(v);
+                }
+                edge_faces
+                    .entry((v.min(next), v.max(next)))
+                    .or_default()
+                    .push(f);
+            }
+        }
+
+        let mut face_faces = vec![Vec::new(); faces.len()];
+        for sharing in edge_faces.values() {
+            for &a in sharing {
+                for &b in sharing {
+                    if a != b {
+                        face_faces[a as usize].push(b);
+                    }
+                }
+            }
+        }
+
+        for list in vertex_faces
+            .iter_mut()
+            .chain(&mut face_faces)
+            .chain(&mut one_ring)
+        {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Self {
+            vertex_faces,
+            face_faces,
+            one_ring,
+        }
+    }
+}
+
+impl SurfaceMesh {
+    /// Returns the indices of the faces that contain vertex `v`, in ascending
+    /// order.
+    ///
+    /// Returns an empty list if `v` is not a vertex index.
+    #[must_use]
+    pub fn vertex_adjacent_faces(&self, v: u32) -> Vec<u32> {
+        self.adjacency()
+            .vertex_faces
+            .get(v as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the indices of the faces that share an edge with face `f`, in
+    /// ascending order.
+    ///
+    /// Faces that only touch `f` at a vertex are not included. Returns an
+    /// empty list if `f` is not a face index.
+    #[must_use]
+    pub fn face_adjacent_faces(&self, f: u32) -> Vec<u32> {
+        self.adjacency()
+            .face_faces
+            .get(f as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns the vertices connected to vertex `v` by a face edge, in
+    /// ascending order.
+    ///
+    /// Returns an empty list if `v` is not a vertex index.
+    #[must_use]
+    pub fn vertex_one_ring(&self, v: u32) -> Vec<u32> {
+        self.adjacency()
+            .one_ring
+            .get(v as usize)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn adjacency(&self) -> &MeshAdjacency {
+        self.adjacency
+            .0
+            .get_or_init(|| MeshAdjacency::new(self.vertices.len(), &self.faces))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    /// Flat 2 x 2 grid of unit quads in the XY plane, vertices numbered row
+    /// by row.
+    fn quad_grid() -> SurfaceMesh {
+        let vertices = (0..9u32)
+            .map(|i| Vec3::new((i % 3) as f32, (i / 3) as f32, 0.0))
+            .collect();
+        let faces = vec![
+            vec![0, 1, 4, 3],
+            vec![1, 2, 5, 4],
+            vec![3, 4, 7, 6],
+            vec![4, 5, 8, 7],
+        ];
+        SurfaceMesh::new("grid", vertices, faces)
+    }
+
+    #[test]
+    fn test_vertex_one_ring_interior_and_boundary() {
+        let mesh = quad_grid();
+        assert_eq!(mesh.vertex_one_ring(4), [1, 3, 5, 7]);
+        assert_eq!(mesh.vertex_one_ring(1), [0, 2, 4]);
+        assert_eq!(mesh.vertex_one_ring(0), [1, 3]);
+        assert!(mesh.vertex_one_ring(9).is_empty());
+
+        assert_eq!(mesh.vertex_adjacent_faces(4), [0, 1, 2, 3]);
+        assert_eq!(mesh.vertex_adjacent_faces(1), [0, 1]);
+        assert_eq!(mesh.vertex_adjacent_faces(8), [3]);
+    }
+
+    #[test]
+    fn test_face_adjacent_faces_share_an_edge() {
+        let mesh = quad_grid();
+        // Face 3 touches face 0 only at vertex 4
+        assert_eq!(mesh.face_adjacent_faces(0), [1, 2]);
+        assert_eq!(mesh.face_adjacent_faces(3), [1, 2]);
+        assert!(mesh.face_adjacent_faces(4).is_empty());
+    }
+
+    #[test]
+    fn test_adjacency_updates_with_faces() {
+        let mut mesh = quad_grid();
+        assert_eq!(mesh.vertex_one_ring(4).len(), 4);
+
+        // Split every quad along a diagonal through the center vertex
+        mesh.update_faces(vec![
+            vec![0, 1, 4],
+            vec![0, 4, 3],
+            vec![1, 2, 4],
+            vec![2, 5, 4],
+            vec![3, 4, 6],
+            vec![4, 7, 6],
+            vec![4, 5, 8],
+            vec![4, 8, 7],
+        ]);
+        assert_eq!(mesh.vertex_one_ring(4), [0, 1, 2, 3, 5, 6, 7, 8]);
+        assert_eq!(mesh.vertex_one_ring(1), [0, 2, 4]);
+        assert_eq!(mesh.vertex_adjacent_faces(4).len(), 8);
+        assert_eq!(mesh.face_adjacent_faces(0), [1, 2]);
+    }
+}
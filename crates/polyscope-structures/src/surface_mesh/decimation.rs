@@ -0,0 +1,540 @@
+//! Quadric error metric simplification of surface meshes.
+//!
+//! Follows Garland and Heckbert, "Surface Simplification Using Quadric Error
+//! Metrics" (1997): every vertex accumulates the planes of its triangles, and
+//! edges are collapsed cheapest first into the point minimizing the summed
+//! squared distance to those planes.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap, HashSet};
+use std::ops::{Add, AddAssign};
+
+use glam::{DMat3, DVec3, Vec3};
+
+use super::SurfaceMesh;
+
+/// Weight of the planes that hold boundary edges in place, relative to the
+/// triangle planes.
+const BOUNDARY_WEIGHT: f64 = 100.0;
+
+/// Smallest cosine allowed between a triangle's normal before and after a
+/// collapse; rejects flipped and nearly edge-on triangles.
+const MIN_NORMAL_COS: f64 = 0.2;
+
+/// Smallest ratio of twice a triangle's area to its longest squared edge
+/// that a collapse may create; rejects slivers that round to degenerate
+/// triangles in single precision.
+const MIN_TRIANGLE_QUALITY: f64 = 1e-3;
+
+impl SurfaceMesh {
+    /// Returns a simplified copy of this mesh with at most
+    /// `target_triangle_count` triangles, if reachable.
+    ///
+    /// Edges are collapsed in order of quadric error. Collapses that would
+    /// change the topology, pinch the mesh, or flip a triangle are skipped,
+    /// so simplification can stop above the target. Boundary edges are
+    /// weighted to stay in place. The copy has the same name, is made of the
+    /// triangles of [`Self::triangulation`], and has no quantities and the
+    /// default transform and appearance; the original is not changed.
+    #[must_use]
+    pub fn decimate(&self, target_triangle_count: usize) -> SurfaceMesh {
+        let mut decimator = Decimator::new(&self.vertices, &self.triangulation);
+        decimator.run(target_triangle_count);
+        let (vertices, faces) = decimator.into_mesh();
+        SurfaceMesh::new(self.name.clone(), vertices, faces)
+    }
+}
+
+/// Quadric `p^T a p + 2 b^T p + c`: a sum of squared distances to planes.
+#[derive(Clone, Copy)]
+struct Quadric {
+    a: DMat3,
+    b: DVec3,
+    c: f64,
+}
+
+impl Quadric {
+    const ZERO: Self = Self {
+        a: DMat3::ZERO,
+        b: DVec3::ZERO,
+        c: 0.0,
+    };
+
+    /// Squared distance to the plane through `point` with unit `normal`,
+    /// times `weight`.
+    fn plane(normal: DVec3, point: DVec3, weight: f64) -> Self {
+        let d = -normal.dot(point);
+        Self {
+            a: DMat3::from_cols(normal * normal.x, normal * normal.y, normal * normal.z) * weight,
+            b: normal * (d * weight),
+            c: d * d * weight,
+        }
+    }
+
+    fn error(&self, p: DVec3) -> f64 {
+        p.dot(self.a * p) + 2.0 * self.b.dot(p) + self.c
+    }
+
+    /// Point of least error, if the quadric is well conditioned.
+    fn minimizer(&self) -> Option<DVec3> {
+        let trace = self.a.x_axis.x + self.a.y_axis.y + self.a.z_axis.z;
+        let det = self.a.determinant();
+        (det.abs() > 1e-9 * trace.powi(3)).then(|| self.a.inverse() * -self.b)
+    }
+}
+
+impl Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            a: self.a + other.a,
+            b: self.b + other.b,
+            c: self.c + other.c,
+        }
+    }
+}
+
+impl AddAssign for Quadric {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// A candidate edge collapse, valid while both vertices keep their version.
+struct Collapse {
+    cost: f64,
+    vertices: [usize; 2],
+    versions: [u32; 2],
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    // Reversed so the binary heap pops the cheapest collapse first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+/// Edge-collapse state over a triangle soup with shared vertices.
+struct Decimator {
+    positions: Vec<DVec3>,
+    quadrics: Vec<Quadric>,
+    /// Bumped whenever a vertex moves or is removed, invalidating its queued
+    /// collapses.
+    versions: Vec<u32>,
+    triangles: Vec<[usize; 3]>,
+    triangle_alive: Vec<bool>,
+    live_triangles: usize,
+    /// Live triangles around each vertex.
+    vertex_triangles: Vec<Vec<usize>>,
+    queue: BinaryHeap<Collapse>,
+}
+
+impl Decimator {
+    fn new(vertices: &[Vec3], triangles: &[[u32; 3]]) -> Self {
+        let positions: Vec<DVec3> = vertices.iter().map(glam::Vec3::as_dvec3).collect();
+        let triangles: Vec<[usize; 3]> = triangles
+            .iter()
+            .map(|t| t.map(|v| v as usize))
+            .filter(|t| t[0] != t[1] && t[1] != t[2] && t[2] != t[0])
+            .collect();
+        let mut vertex_triangles = vec![Vec::new(); positions.len()];
+        for (t, tri) in triangles.iter().enumerate() {
+            for &v in tri {
+                vertex_triangles[v].push(t);
+            }
+        }
+
+        let mut decimator = Self {
+            quadrics: vec![Quadric::ZERO; positions.len()],
+            versions: vec![0; positions.len()],
+            triangle_alive: vec![true; triangles.len()],
+            live_triangles: triangles.len(),
+            positions,
+            triangles,
+            vertex_triangles,
+            queue: BinaryHeap::new(),
+        };
+        decimator.init_quadrics();
+
+        let mut edges = HashSet::new();
+        for tri in &decimator.triangles {
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                edges.insert((a.min(b), a.max(b)));
+            }
+        }
+        let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+        edges.sort_unstable();
+        for (a, b) in edges {
+            decimator.push_collapse(a, b);
+        }
+        decimator
+    }
+
+    /// Accumulates the area-weighted triangle planes at each vertex, plus
+    /// planes perpendicular to the boundary edges.
+    fn init_quadrics(&mut self) {
+        for t in 0..self.triangles.len() {
+            let tri = self.triangles[t];
+            let p = tri.map(|v| self.positions[v]);
+            let cross = (p[1] - p[0]).cross(p[2] - p[0]);
+            let double_area = cross.length();
+            if double_area <= 0.0 {
+                continue;
+            }
+            let normal = cross / double_area;
+            let quadric = Quadric::plane(normal, p[0], double_area * 0.5);
+            for &v in &tri {
+                self.quadrics[v] += quadric;
+            }
+
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                if self.edge_triangles(a, b).count() != 1 {
+                    continue;
+                }
+                let edge = self.positions[b] - self.positions[a];
+                let Some(side) = edge.cross(normal).try_normalize() else {
+                    continue;
+                };
+                let quadric = Quadric::plane(
+                    side,
+                    self.positions[a],
+                    BOUNDARY_WEIGHT * edge.length_squared(),
+                );
+                self.quadrics[a] += quadric;
+                self.quadrics[b] += quadric;
+            }
+        }
+    }
+
+    /// Live triangles containing both `a` and `b`.
+    fn edge_triangles(&self, a: usize, b: usize) -> impl Iterator<Item = usize> + '_ {
+        self.vertex_triangles[a]
+            .iter()
+            .copied()
+            .filter(move |&t| self.triangles[t].contains(&b))
+    }
+
+    /// Vertices sharing a live triangle with `v`, in order so that
+    /// simplification is deterministic.
+    fn neighbors(&self, v: usize) -> BTreeSet<usize> {
+        self.vertex_triangles[v]
+            .iter()
+            .flat_map(|&t| self.triangles[t])
+            .filter(|&w| w != v)
+            .collect()
+    }
+
+    fn is_boundary_vertex(&self, v: usize) -> bool {
+        self.neighbors(v)
+            .into_iter()
+            .any(|w| self.edge_triangles(v, w).count() == 1)
+    }
+
+    /// Cheapest position for the vertex replacing edge `(a, b)`, and its
+    /// error.
+    fn collapse_target(&self, a: usize, b: usize) -> (DVec3, f64) {
+        let quadric = self.quadrics[a] + self.quadrics[b];
+        let (pa, pb) = (self.positions[a], self.positions[b]);
+        let midpoint = (pa + pb) * 0.5;
+        // An optimum far off the edge comes from a nearly singular quadric
+        let optimum = quadric
+            .minimizer()
+            .filter(|p| p.distance(midpoint) <= pa.distance(pb));
+        [pa, pb, midpoint]
+            .into_iter()
+            .chain(optimum)
+            .map(|p| (p, quadric.error(p)))
+            .min_by(|x, y| x.1.total_cmp(&y.1))
+            .unwrap_or((midpoint, 0.0))
+    }
+
+    fn push_collapse(&mut self, a: usize, b: usize) {
+        let (_, cost) = self.collapse_target(a, b);
+        self.queue.push(Collapse {
+            cost,
+            vertices: [a, b],
+            versions: [self.versions[a], self.versions[b]],
+        });
+    }
+
+    /// Returns whether collapsing `(a, b)` into `target` keeps the mesh
+    /// manifold with the same topology and flips no triangle.
+    fn can_collapse(&self, a: usize, b: usize, target: DVec3) -> bool {
+        let shared: Vec<usize> = self.edge_triangles(a, b).collect();
+        if shared.is_empty() || shared.len() > 2 {
+            return false;
+        }
+        // An interior edge between two boundary vertices would pinch the mesh
+        if shared.len() == 2 && self.is_boundary_vertex(a) && self.is_boundary_vertex(b) {
+            return false;
+        }
+        // Link condition: the only common neighbors are the opposite corners
+        // of the triangles on the edge
+        let opposite: HashSet<usize> = shared
+            .iter()
+            .flat_map(|&t| self.triangles[t])
+            .filter(|&v| v != a && v != b)
+            .collect();
+        let common: HashSet<usize> = self
+            .neighbors(a)
+            .intersection(&self.neighbors(b))
+            .copied()
+            .collect();
+        if common != opposite {
+            return false;
+        }
+
+        [a, b].into_iter().all(|v| {
+            self.vertex_triangles[v]
+                .iter()
+                .filter(|t| !shared.contains(t))
+                .all(|&t| {
+                    let tri = self.triangles[t];
+                    let before = tri.map(|w| self.positions[w]);
+                    let after = tri.map(|w| if w == v { target } else { self.positions[w] });
+                    let normal = |p: [DVec3; 3]| (p[1] - p[0]).cross(p[2] - p[0]);
+                    let (n0, n1) = (normal(before), normal(after));
+                    let longest_squared = (0..3)
+                        .map(|k| after[k].distance_squared(after[(k + 1) % 3]))
+                        .fold(0.0, f64::max);
+                    n0.dot(n1) > MIN_NORMAL_COS * n0.length() * n1.length()
+                        && n1.length() > MIN_TRIANGLE_QUALITY * longest_squared
+                })
+        })
+    }
+
+    /// Collapses edges until at most `target` triangles are left or no
+    /// collapse is allowed.
+    fn run(&mut self, target: usize) {
+        while self.live_triangles > target {
+            let Some(collapse) = self.queue.pop() else {
+                break;
+            };
+            let [a, b] = collapse.vertices;
+            if collapse.versions != [self.versions[a], self.versions[b]] {
+                continue;
+            }
+            let (target_position, _) = self.collapse_target(a, b);
+            if self.can_collapse(a, b, target_position) {
+                self.collapse(a, b, target_position);
+            }
+        }
+    }
+
+    /// Merges `b` into `a`, moving `a` to `position`.
+    fn collapse(&mut self, a: usize, b: usize, position: DVec3) {
+        let removed: Vec<usize> = self.edge_triangles(a, b).collect();
+        for &t in &removed {
+            self.triangle_alive[t] = false;
+            self.live_triangles -= 1;
+            for v in self.triangles[t] {
+                let alive = &self.triangle_alive;
+                self.vertex_triangles[v].retain(|&t| alive[t]);
+            }
+        }
+        for t in std::mem::take(&mut self.vertex_triangles[b]) {
+            if !self.triangle_alive[t] {
+                continue;
+            }
+            for v in &mut self.triangles[t] {
+                if *v == b {
+                    *v = a;
+                }
+            }
+            self.vertex_triangles[a].push(t);
+        }
+
+        self.positions[a] = position;
+        let merged = self.quadrics[a] + self.quadrics[b];
+        self.quadrics[a] = merged;
+        self.versions[a] += 1;
+        self.versions[b] += 1;
+        for v in self.neighbors(a) {
+            self.push_collapse(a, v);
+        }
+    }
+
+    /// Returns the remaining vertices and triangles, with unused vertices
+    /// dropped.
+    fn into_mesh(self) -> (Vec<Vec3>, Vec<Vec<u32>>) {
+        let mut remap = vec![u32::MAX; self.positions.len()];
+        let mut vertices = Vec::new();
+        let mut faces = Vec::with_capacity(self.live_triangles);
+        for (t, tri) in self.triangles.iter().enumerate() {
+            if !self.triangle_alive[t] {
+                continue;
+            }
+            let face = tri
+                .iter()
+                .map(|&v| {
+                    if remap[v] == u32::MAX {
+                        remap[v] = vertices.len() as u32;
+                        vertices.push(self.positions[v].as_vec3());
+                    }
+                    remap[v]
+                })
+                .collect();
+            faces.push(face);
+        }
+        (vertices, faces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Unit icosphere with `subdivisions` levels of 4-to-1 splits.
+    fn icosphere(subdivisions: u32) -> SurfaceMesh {
+        let t = f32::midpoint(1.0, 5.0_f32.sqrt());
+        let mut vertices: Vec<Vec3> = [
+            [-1.0, t, 0.0],
+            [1.0, t, 0.0],
+            [-1.0, -t, 0.0],
+            [1.0, -t, 0.0],
+            [0.0, -1.0, t],
+            [0.0, 1.0, t],
+            [0.0, -1.0, -t],
+            [0.0, 1.0, -t],
+            [t, 0.0, -1.0],
+            [t, 0.0, 1.0],
+            [-t, 0.0, -1.0],
+            [-t, 0.0, 1.0],
+        ]
+        .into_iter()
+        .map(|p| Vec3::from(p).normalize())
+        .collect();
+        let mut faces: Vec<[u32; 3]> = vec![
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ];
+        for _ in 0..subdivisions {
+            let mut midpoints = HashMap::new();
+            let mut midpoint = |a: u32, b: u32, vertices: &mut Vec<Vec3>| {
+                *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                    let p = (vertices[a as usize] + vertices[b as usize]).normalize();
+                    vertices.push(p);
+                    vertices.len() as u32 - 1
+                })
+            };
+            faces = faces
+                .iter()
+                .flat_map(|&[a, b, c]| {
+                    let ab = midpoint(a, b, &mut vertices);
+                    let bc = midpoint(b, c, &mut vertices);
+                    let ca = midpoint(c, a, &mut vertices);
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+        let faces = faces.into_iter().map(Vec::from).collect();
+        SurfaceMesh::new("sphere", vertices, faces)
+    }
+
+    /// Number of faces on each undirected edge.
+    fn edge_face_counts(mesh: &SurfaceMesh) -> HashMap<(u32, u32), usize> {
+        let mut counts = HashMap::new();
+        for tri in mesh.triangulation() {
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                *counts.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn test_decimate_sphere_preserves_topology() {
+        let sphere = icosphere(4);
+        assert_eq!(sphere.num_faces(), 5120);
+
+        let decimated = sphere.decimate(500);
+        let faces = decimated.num_faces();
+        assert!((400..=500).contains(&faces), "got {faces} triangles");
+
+        let edges = edge_face_counts(&decimated);
+        assert!(edges.values().all(|&count| count == 2));
+        let euler = decimated.num_vertices() as i64 - edges.len() as i64 + faces as i64;
+        assert_eq!(euler, 2);
+
+        for v in decimated.vertices() {
+            assert!((v.length() - 1.0).abs() < 0.05, "vertex {v} off the sphere");
+        }
+
+        // The source mesh is untouched
+        assert_eq!(sphere.num_faces(), 5120);
+        assert_eq!(sphere.num_vertices(), 2562);
+    }
+
+    #[test]
+    fn test_decimate_keeps_boundary() {
+        let size = 10;
+        let row = size + 1;
+        let vertices = (0..row * row)
+            .map(|i| Vec3::new((i % row) as f32, (i / row) as f32, 0.0))
+            .collect();
+        let mut faces = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let corner = y * row + x;
+                faces.push(vec![corner, corner + 1, corner + row + 1]);
+                faces.push(vec![corner, corner + row + 1, corner + row]);
+            }
+        }
+        let grid = SurfaceMesh::new("grid", vertices, faces);
+
+        let decimated = grid.decimate(20);
+        assert!(decimated.num_faces() < 200);
+        let (min, max) = decimated
+            .vertices()
+            .iter()
+            .fold((Vec3::MAX, Vec3::MIN), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        assert!(min.abs_diff_eq(Vec3::ZERO, 1e-4), "min {min}");
+        assert!(
+            max.abs_diff_eq(Vec3::new(10.0, 10.0, 0.0), 1e-4),
+            "max {max}"
+        );
+        for tri in decimated.triangulation() {
+            let p = tri.map(|v| decimated.vertices()[v as usize]);
+            assert!((p[1] - p[0]).cross(p[2] - p[0]).z > 0.0);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Double-precision vertex input for surface meshes.
+//!
+//! Meshes with large coordinates (e.g. geospatial data) lose precision when
+//! stored as `f32`. These methods keep an `f64` origin on the mesh and store
+//! vertices relative to it, so the positions sent to the GPU stay small.
+//! Meshes of one scene share the origin, so the scene is rendered in the
+//! frame of that origin and the mesh transform acts in it; the `*_f64`
+//! accessors convert back to true world coordinates.
+
+use glam::{DVec3, Vec3};
+
+use super::SurfaceMesh;
+
+impl SurfaceMesh {
+    /// Creates a new surface mesh from double-precision vertex positions,
+    /// stored relative to `origin`.
+    ///
+    /// Meshes placed in the same scene must share the origin to keep their
+    /// relative positions; [`bounding_box_center_f64`] gives a suitable one
+    /// for the first mesh.
+    pub fn new_f64(
+        name: impl Into<String>,
+        vertices: &[[f64; 3]],
+        faces: Vec<Vec<u32>>,
+        origin: DVec3,
+    ) -> Self {
+        let local = vertices
+            .iter()
+            .map(|&v| (DVec3::from_array(v) - origin).as_vec3())
+            .collect();
+        let mut mesh = Self::new(name, local, faces);
+        mesh.origin = origin;
+        mesh
+    }
+
+    /// Returns the double-precision world origin that the vertices are
+    /// relative to (zero for meshes created from `f32` vertices).
+    #[must_use]
+    pub fn origin(&self) -> DVec3 {
+        self.origin
+    }
+
+    /// Converts a scene-space position (e.g. a pick position) to true world
+    /// coordinates by adding the mesh origin.
+    #[must_use]
+    pub fn to_world_f64(&self, position: Vec3) -> DVec3 {
+        self.origin + position.as_dvec3()
+    }
+
+    /// Returns the world-space bounding box in double precision, including
+    /// the mesh transform and origin.
+    ///
+    /// The transform is applied to each origin-relative vertex in `f64`
+    /// before the origin is added back, so the box is tight for rotated
+    /// meshes and exact at large coordinates.
+    #[must_use]
+    pub fn bounding_box_f64(&self) -> Option<(DVec3, DVec3)> {
+        if self.vertices.is_empty() {
+            return None;
+        }
+        let transform = self.transform.as_dmat4();
+        let (min, max) = self.vertices.iter().fold(
+            (DVec3::splat(f64::MAX), DVec3::splat(f64::MIN)),
+            |(min, max), &v| {
+                let p = transform.transform_point3(v.as_dvec3());
+                (min.min(p), max.max(p))
+            },
+        );
+        Some((self.origin + min, self.origin + max))
+    }
+
+    /// Returns the world-space centroid (vertex average) in double precision,
+    /// including the mesh transform and origin.
+    #[must_use]
+    pub fn centroid_f64(&self) -> Option<DVec3> {
+        if self.vertices.is_empty() {
+            return None;
+        }
+        let transform = self.transform.as_dmat4();
+        let sum: DVec3 = self
+            .vertices
+            .iter()
+            .map(|&v| transform.transform_point3(v.as_dvec3()))
+            .sum();
+        Some(self.origin + sum / self.vertices.len() as f64)
+    }
+}
+
+/// Returns the center of the bounding box of `points` (zero if empty).
+#[must_use]
+pub fn bounding_box_center_f64(points: &[[f64; 3]]) -> DVec3 {
+    if points.is_empty() {
+        return DVec3::ZERO;
+    }
+    let (min, max) = points.iter().fold(
+        (DVec3::splat(f64::MAX), DVec3::splat(f64::MIN)),
+        |(min, max), &p| {
+            let p = DVec3::from_array(p);
+            (min.min(p), max.max(p))
+        },
+    );
+    (min + max) * 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_coordinates_keep_precision() {
+        let base = [1.0e7, -2.0e7, 3.0e7];
+        let vertices: Vec<[f64; 3]> = [[0.0, 0.0, 0.0], [0.3, 0.0, 0.0], [0.0, 0.3, 0.1]]
+            .iter()
+            .map(|d| [base[0] + d[0], base[1] + d[1], base[2] + d[2]])
+            .collect();
+        let origin = bounding_box_center_f64(&vertices);
+        let mesh = SurfaceMesh::new_f64("geo", &vertices, vec![vec![0, 1, 2]], origin);
+
+        // GPU-side positions stay small
+        assert!(mesh.vertices().iter().all(|v| v.length() < 1.0));
+
+        // Sub-millimeter offsets survive, where f32 would round to whole meters
+        let expected = DVec3::new(base[0] + 0.1, base[1] + 0.1, base[2] + 0.1 / 3.0);
+        let centroid = mesh.centroid_f64().unwrap();
+        assert!((centroid - expected).length() < 1e-6);
+
+        let (min, max) = mesh.bounding_box_f64().unwrap();
+        assert!((min - DVec3::from_array(base)).length() < 1e-6);
+        assert!((max - (DVec3::from_array(base) + DVec3::new(0.3, 0.3, 0.1))).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_shared_origin_and_transformed_bounds() {
+        let origin = DVec3::new(1.0e7, 1.0e7, 0.0);
+        let near = SurfaceMesh::new_f64(
+            "near",
+            &[
+                [1.0e7, 1.0e7, 0.0],
+                [1.0e7 + 1.0, 1.0e7, 0.0],
+                [1.0e7, 1.0e7 + 1.0, 0.0],
+            ],
+            vec![vec![0, 1, 2]],
+            origin,
+        );
+        let far = SurfaceMesh::new_f64(
+            "far",
+            &[
+                [1.0e7 + 500.0, 1.0e7, 0.0],
+                [1.0e7 + 501.0, 1.0e7, 0.0],
+                [1.0e7 + 500.0, 1.0e7 + 1.0, 0.0],
+            ],
+            vec![vec![0, 1, 2]],
+            origin,
+        );
+
+        // Both meshes live in the frame of the shared origin, 500 apart
+        assert_eq!(near.vertices()[0], Vec3::ZERO);
+        assert_eq!(far.vertices()[0], Vec3::new(500.0, 0.0, 0.0));
+
+        // The transform acts on the origin-relative vertices: a quarter turn
+        // about the origin maps the far mesh onto the +y axis
+        let mut far = far;
+        far.transform = glam::Mat4::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let (min, max) = far.bounding_box_f64().unwrap();
+        assert!((min - (origin + DVec3::new(-1.0, 500.0, 0.0))).length() < 1e-3);
+        assert!((max - (origin + DVec3::new(0.0, 501.0, 0.0))).length() < 1e-3);
+    }
+}
@@ -0,0 +1,99 @@
+//! Wavefront OBJ export for surface meshes.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use polyscope_core::error::Result;
+
+use super::SurfaceMesh;
+
+impl SurfaceMesh {
+    /// Writes the mesh to a Wavefront OBJ file.
+    ///
+    /// Vertices are written in the mesh's local frame (the structure
+    /// transform is not applied) and faces keep their original polygons, not
+    /// the render triangulation. Vertex normals are written as `vn` lines. If
+    /// a vertex parameterization quantity is enabled, its coordinates are
+    /// written as `vt` lines and referenced by every face corner.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn export_obj(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_obj_string())?;
+        Ok(())
+    }
+
+    /// Returns the mesh as OBJ text. See [`Self::export_obj`].
+    #[must_use]
+    pub fn to_obj_string(&self) -> String {
+        let normals = Some(self.vertex_normals()).filter(|n| n.len() == self.vertices.len());
+        let uvs = self
+            .active_vertex_parameterization_quantity()
+            .map(super::MeshVertexParameterizationQuantity::coords)
+            .filter(|uv| uv.len() == self.vertices.len());
+
+        let mut out = String::new();
+        let _ = writeln!(out, "# {}", self.name);
+        for v in &self.vertices {
+            let _ = writeln!(out, "v {} {} {}", v.x, v.y, v.z);
+        }
+        for uv in uvs.unwrap_or_default() {
+            let _ = writeln!(out, "vt {} {}", uv.x, uv.y);
+        }
+        for n in normals.unwrap_or_default() {
+            let _ = writeln!(out, "vn {} {} {}", n.x, n.y, n.z);
+        }
+        for face in &self.faces {
+            out.push('f');
+            for &v in face {
+                // OBJ indices are 1-based; uvs and normals share vertex indices
+                let i = v + 1;
+                let _ = match (uvs.is_some(), normals.is_some()) {
+                    (true, true) => write!(out, " {i}/{i}/{i}"),
+                    (true, false) => write!(out, " {i}/{i}"),
+                    (false, true) => write!(out, " {i}//{i}"),
+                    (false, false) => write!(out, " {i}"),
+                };
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{Vec2, Vec3};
+    use polyscope_core::structure::HasQuantities;
+
+    fn quad() -> SurfaceMesh {
+        SurfaceMesh::new(
+            "quad",
+            vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0), Vec3::Y],
+            vec![vec![0, 1, 2, 3]],
+        )
+    }
+
+    #[test]
+    fn test_obj_keeps_polygons_and_writes_normals() {
+        let obj = quad().to_obj_string();
+        assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 4);
+        assert_eq!(obj.lines().filter(|l| l.starts_with("vn ")).count(), 4);
+        assert!(!obj.contains("vt "));
+        assert!(obj.contains("f 1//1 2//2 3//3 4//4\n"));
+    }
+
+    #[test]
+    fn test_obj_writes_active_vertex_parameterization() {
+        let mut mesh = quad();
+        let coords = vec![Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+        mesh.add_vertex_parameterization_quantity("uv", coords)
+            .unwrap();
+        mesh.get_quantity_mut("uv").unwrap().set_enabled(true);
+        let obj = mesh.to_obj_string();
+        assert_eq!(obj.lines().filter(|l| l.starts_with("vt ")).count(), 4);
+        assert!(obj.contains("f 1/1/1 2/2/2 3/3/3 4/4/4\n"));
+    }
+}
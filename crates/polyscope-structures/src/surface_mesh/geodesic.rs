@@ -0,0 +1,387 @@
+//! Geodesic distance on surface meshes with the heat method.
+//!
+//! Follows Crane et al., "Geodesics in Heat" (2013): heat is diffused from the
+//! source for a short time, the negated heat gradient is normalized on every
+//! triangle, and a Poisson solve recovers the distance whose gradient best
+//! matches it. Both linear systems use the cotan Laplacian of the
+//! triangulation and are solved with Jacobi-preconditioned conjugate
+//! gradients.
+
+use std::collections::{HashMap, VecDeque};
+
+use glam::DVec3;
+use polyscope_core::error::{PolyscopeError, Result};
+use polyscope_core::structure::HasQuantities;
+
+use super::{MeshVertexScalarQuantity, SurfaceMesh};
+
+impl SurfaceMesh {
+    /// Computes the approximate geodesic distance from vertex `source` to
+    /// every vertex with the heat method.
+    ///
+    /// Distances are measured along the triangulated surface, in object
+    /// space. Vertices that share no chain of faces with the source are
+    /// unreachable and get `f32::INFINITY`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::InvalidGeometry`] if `source` is not a vertex
+    /// index.
+    pub fn compute_geodesic_distance(&self, source: u32) -> Result<Vec<f32>> {
+        let n = self.vertices.len();
+        let source = source as usize;
+        if source >= n {
+            return Err(PolyscopeError::InvalidGeometry(format!(
+                "geodesic source vertex {source} out of range for {n} vertices"
+            )));
+        }
+
+        let positions: Vec<DVec3> = self.vertices.iter().map(glam::Vec3::as_dvec3).collect();
+        let triangles = self.triangulation();
+        let operators = CotanOperators::new(&positions, triangles);
+        let reachable = connected_vertices(n, triangles, source);
+
+        // Heat diffusion for a time of one squared mean edge length
+        let time = operators.mean_edge_length.powi(2);
+        let mut impulse = vec![0.0; n];
+        impulse[source] = 1.0;
+        let heat_diagonal: Vec<f64> = (0..n)
+            .map(|i| operators.mass[i] + time * operators.diagonal[i])
+            .collect();
+        let heat = conjugate_gradient(
+            |x, y| {
+                operators.apply_laplacian(x, y);
+                for i in 0..n {
+                    y[i] = operators.mass[i] * x[i] + time * y[i];
+                }
+            },
+            &heat_diagonal,
+            &impulse,
+            &reachable,
+        );
+
+        // Integrated divergence of the normalized field pointing away from
+        // the source, negated to match the positive semi-definite Laplacian
+        let mut divergence = vec![0.0; n];
+        for tri in triangles {
+            let idx = tri.map(|v| v as usize);
+            let p = idx.map(|i| positions[i]);
+            let normal = (p[1] - p[0]).cross(p[2] - p[0]);
+            let double_area = normal.length();
+            if double_area <= 0.0 {
+                continue;
+            }
+            let normal = normal / double_area;
+            let gradient = (0..3)
+                .map(|k| heat[idx[k]] * normal.cross(p[(k + 2) % 3] - p[(k + 1) % 3]))
+                .sum::<DVec3>()
+                / double_area;
+            let field = -gradient.normalize_or_zero();
+            let cot = corner_cotangents(&p, double_area);
+            for k in 0..3 {
+                let (next, prev) = ((k + 1) % 3, (k + 2) % 3);
+                divergence[idx[k]] -= 0.5
+                    * (cot[prev] * (p[next] - p[k]).dot(field)
+                        + cot[next] * (p[prev] - p[k]).dot(field));
+            }
+        }
+
+        // Poisson solve with the source pinned at zero
+        let mut free = reachable.clone();
+        free[source] = false;
+        let distance = conjugate_gradient(
+            |x, y| operators.apply_laplacian(x, y),
+            &operators.diagonal,
+            &divergence,
+            &free,
+        );
+
+        Ok(distance
+            .iter()
+            .zip(&reachable)
+            .map(|(&d, &reached)| {
+                if reached {
+                    d.max(0.0) as f32
+                } else {
+                    f32::INFINITY
+                }
+            })
+            .collect())
+    }
+
+    /// Adds a vertex scalar quantity holding the approximate geodesic
+    /// distance from `source_vertex` (see [`Self::compute_geodesic_distance`]).
+    ///
+    /// Unreachable vertices are clamped to the largest reachable distance so
+    /// the colormap range stays finite. The distances are not recomputed when
+    /// the mesh is updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolyscopeError::InvalidGeometry`] if `source_vertex` is not a
+    /// vertex index.
+    pub fn add_geodesic_distance_quantity(
+        &mut self,
+        name: impl Into<String>,
+        source_vertex: u32,
+    ) -> Result<&mut Self> {
+        let mut distances = self.compute_geodesic_distance(source_vertex)?;
+        let max_reachable = distances
+            .iter()
+            .copied()
+            .filter(|d| d.is_finite())
+            .fold(0.0, f32::max);
+        for d in &mut distances {
+            if !d.is_finite() {
+                *d = max_reachable;
+            }
+        }
+        let quantity = MeshVertexScalarQuantity::new(name, self.name.clone(), distances);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
+    }
+}
+
+/// Cotan Laplacian and lumped mass matrix of a triangle mesh.
+struct CotanOperators {
+    /// `(i, j, w)` per edge, with `i < j` and the cotan weight `w` summed over
+    /// the edge's triangles.
+    edges: Vec<(usize, usize, f64)>,
+    /// Sum of the edge weights around each vertex.
+    diagonal: Vec<f64>,
+    /// One third of the area of the triangles around each vertex.
+    mass: Vec<f64>,
+    mean_edge_length: f64,
+}
+
+impl CotanOperators {
+    fn new(positions: &[DVec3], triangles: &[[u32; 3]]) -> Self {
+        let n = positions.len();
+        let mut weights: HashMap<(usize, usize), f64> = HashMap::new();
+        let mut mass = vec![0.0; n];
+        for tri in triangles {
+            let idx = tri.map(|v| v as usize);
+            let p = idx.map(|i| positions[i]);
+            let double_area = (p[1] - p[0]).cross(p[2] - p[0]).length();
+            if double_area <= 0.0 {
+                continue;
+            }
+            let cot = corner_cotangents(&p, double_area);
+            for k in 0..3 {
+                mass[idx[k]] += double_area / 6.0;
+                // The angle at corner k is opposite the edge between the others
+                let (i, j) = (idx[(k + 1) % 3], idx[(k + 2) % 3]);
+                *weights.entry((i.min(j), i.max(j))).or_default() += 0.5 * cot[k];
+            }
+        }
+
+        // Sorted so the solves are deterministic
+        let mut edges: Vec<(usize, usize, f64)> =
+            weights.into_iter().map(|((i, j), w)| (i, j, w)).collect();
+        edges.sort_unstable_by_key(|&(i, j, _)| (i, j));
+        let mut diagonal = vec![0.0; n];
+        for &(i, j, w) in &edges {
+            diagonal[i] += w;
+            diagonal[j] += w;
+        }
+        let mean_edge_length = if edges.is_empty() {
+            0.0
+        } else {
+            edges
+                .iter()
+                .map(|&(i, j, _)| positions[i].distance(positions[j]))
+                .sum::<f64>()
+                / edges.len() as f64
+        };
+
+        Self {
+            edges,
+            diagonal,
+            mass,
+            mean_edge_length,
+        }
+    }
+
+    /// Computes `y = L x`.
+    fn apply_laplacian(&self, x: &[f64], y: &mut [f64]) {
+        for (i, yi) in y.iter_mut().enumerate() {
+            *yi = self.diagonal[i] * x[i];
+        }
+        for &(i, j, w) in &self.edges {
+            y[i] -= w * x[j];
+            y[j] -= w * x[i];
+        }
+    }
+}
+
+/// Cotangents of the three corner angles of triangle `p`.
+fn corner_cotangents(p: &[DVec3; 3], double_area: f64) -> [f64; 3] {
+    std::array::from_fn(|k| (p[(k + 1) % 3] - p[k]).dot(p[(k + 2) % 3] - p[k]) / double_area)
+}
+
+/// Marks the vertices connected to `source` through triangles.
+fn connected_vertices(n: usize, triangles: &[[u32; 3]], source: usize) -> Vec<bool> {
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for tri in triangles {
+        for k in 0..3 {
+            neighbors[tri[k] as usize].push(tri[(k + 1) % 3] as usize);
+            neighbors[tri[(k + 1) % 3] as usize].push(tri[k] as usize);
+        }
+    }
+    let mut reached = vec![false; n];
+    reached[source] = true;
+    let mut queue = VecDeque::from([source]);
+    while let Some(v) = queue.pop_front() {
+        for &w in &neighbors[v] {
+            if !reached[w] {
+                reached[w] = true;
+                queue.push_back(w);
+            }
+        }
+    }
+    reached
+}
+
+/// Solves `A x = b` for the `free` entries of `x` with Jacobi-preconditioned
+/// conjugate gradients, where `apply` computes `A x` and `diagonal` is the
+/// diagonal of `A`. The other entries of `x` stay zero.
+fn conjugate_gradient(
+    apply: impl Fn(&[f64], &mut [f64]),
+    diagonal: &[f64],
+    b: &[f64],
+    free: &[bool],
+) -> Vec<f64> {
+    const TOLERANCE: f64 = 1e-10;
+    let n = b.len();
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v).map(|(ui, vi)| ui * vi).sum::<f64>();
+    let mask = |v: &mut [f64]| {
+        for (vi, &is_free) in v.iter_mut().zip(free) {
+            if !is_free {
+                *vi = 0.0;
+            }
+        }
+    };
+    let precondition = |residual: &[f64]| -> Vec<f64> {
+        residual
+            .iter()
+            .zip(diagonal)
+            .map(|(&ri, &di)| if di > 0.0 { ri / di } else { ri })
+            .collect()
+    };
+
+    let mut solution = vec![0.0; n];
+    let mut residual = b.to_vec();
+    mask(&mut residual);
+    let b_norm = dot(&residual, &residual).sqrt();
+    if b_norm <= 0.0 {
+        return solution;
+    }
+    let mut preconditioned = precondition(&residual);
+    let mut direction = preconditioned.clone();
+    let mut rz = dot(&residual, &preconditioned);
+    let mut a_direction = vec![0.0; n];
+    for _ in 0..2 * n + 100 {
+        apply(&direction, &mut a_direction);
+        mask(&mut a_direction);
+        let curvature = dot(&direction, &a_direction);
+        if curvature <= 0.0 {
+            break;
+        }
+        let alpha = rz / curvature;
+        for i in 0..n {
+            solution[i] += alpha * direction[i];
+            residual[i] -= alpha * a_direction[i];
+        }
+        if dot(&residual, &residual).sqrt() <= TOLERANCE * b_norm {
+            break;
+        }
+        preconditioned = precondition(&residual);
+        let rz_next = dot(&residual, &preconditioned);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for i in 0..n {
+            direction[i] = preconditioned[i] + beta * direction[i];
+        }
+    }
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    /// Flat `size` x `size` grid of unit squares in the XY plane, split into
+    /// triangles along alternating diagonals.
+    fn grid(size: u32) -> SurfaceMesh {
+        let row = size + 1;
+        let vertices = (0..row * row)
+            .map(|i| Vec3::new((i % row) as f32, (i / row) as f32, 0.0))
+            .collect();
+        let mut faces = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let corner = y * row + x;
+                let [v0, v1, v2, v3] = [corner, corner + 1, corner + row + 1, corner + row];
+                if (x + y) % 2 == 0 {
+                    faces.extend([vec![v0, v1, v2], vec![v0, v2, v3]]);
+                } else {
+                    faces.extend([vec![v0, v1, v3], vec![v1, v2, v3]]);
+                }
+            }
+        }
+        SurfaceMesh::new("grid", vertices, faces)
+    }
+
+    #[test]
+    fn test_geodesic_distance_on_flat_grid_matches_euclidean() {
+        let mesh = grid(20);
+        let source = 10 * 21 + 10;
+        let distances = mesh.compute_geodesic_distance(source).unwrap();
+        assert!(distances[source as usize].abs() < 1e-6);
+        let center = mesh.vertices()[source as usize];
+        for (v, &d) in mesh.vertices().iter().zip(&distances) {
+            let euclidean = v.distance(center);
+            assert!(
+                (d - euclidean).abs() <= 0.05 * euclidean + 0.1,
+                "vertex {v}: geodesic {d}, euclidean {euclidean}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_geodesic_distance_unreachable_component() {
+        let vertices = vec![
+            Vec3::ZERO,
+            Vec3::X,
+            Vec3::Y,
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::new(6.0, 0.0, 0.0),
+            Vec3::new(5.0, 1.0, 0.0),
+        ];
+        let mut mesh = SurfaceMesh::new(
+            "two_triangles",
+            vertices,
+            vec![vec![0, 1, 2], vec![3, 4, 5]],
+        );
+        let distances = mesh.compute_geodesic_distance(0).unwrap();
+        assert!(distances[..3].iter().all(|d| d.is_finite()));
+        assert!(distances[3..].iter().all(|d| d.is_infinite()));
+
+        mesh.add_geodesic_distance_quantity("dist", 0).unwrap();
+        let quantity = mesh
+            .get_quantity("dist")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<MeshVertexScalarQuantity>()
+            .unwrap();
+        let max_reachable = distances[1].max(distances[2]);
+        assert!(
+            quantity.values()[3..]
+                .iter()
+                .all(|&d| (d - max_reachable).abs() < 1e-6)
+        );
+
+        assert!(mesh.compute_geodesic_distance(6).is_err());
+    }
+}
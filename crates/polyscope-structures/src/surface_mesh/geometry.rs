@@ -31,6 +31,15 @@ impl SurfaceMesh {
         self.needs_recompute = false;
     }
 
+    /// Recomputes only the position-dependent data (face, vertex, and corner normals).
+    ///
+    /// Triangulation and edge data depend only on connectivity and are reused.
+    pub(super) fn recompute_normals(&mut self) {
+        self.compute_face_normals();
+        self.compute_vertex_normals();
+        self.compute_corner_normals();
+    }
+
     /// Computes triangulation using fan triangulation.
     ///
     /// For a polygon with vertices [v0, v1, v2, v3, ...], creates triangles:
@@ -55,21 +64,32 @@ impl SurfaceMesh {
         }
     }
 
-    /// Computes face normals using cross product of first two edges.
+    /// Computes face normals using Newell's method over all face vertices.
+    ///
+    /// Unlike the cross product of the first two edges, this gives a stable
+    /// average normal for non-planar polygons. User-supplied normals (see
+    /// [`SurfaceMesh::set_face_normals`]) are kept as long as the face count matches.
     fn compute_face_normals(&mut self) {
+        if self.face_normals_user_supplied && self.face_normals.len() == self.faces.len() {
+            return;
+        }
+        self.face_normals_user_supplied = false;
+
         self.face_normals.clear();
         self.face_normals.reserve(self.faces.len());
 
         for face in &self.faces {
             if face.len() >= 3 {
+                // Sum of edge cross products relative to v0 (translation-invariant,
+                // and relative coordinates keep precision far from the origin)
                 let v0 = self.vertices[face[0] as usize];
-                let v1 = self.vertices[face[1] as usize];
-                let v2 = self.vertices[face[2] as usize];
-
-                let e1 = v1 - v0;
-                let e2 = v2 - v0;
-                let normal = e1.cross(e2).normalize_or_zero();
-                self.face_normals.push(normal);
+                let mut normal = Vec3::ZERO;
+                for i in 0..face.len() {
+                    let a = self.vertices[face[i] as usize] - v0;
+                    let b = self.vertices[face[(i + 1) % face.len()] as usize] - v0;
+                    normal += a.cross(b);
+                }
+                self.face_normals.push(normal.normalize_or_zero());
             } else {
                 self.face_normals.push(Vec3::ZERO);
             }
@@ -77,7 +97,15 @@ impl SurfaceMesh {
     }
 
     /// Computes vertex normals as area-weighted average of incident face normals.
+    ///
+    /// User-supplied normals (see [`SurfaceMesh::set_vertex_normals`]) are kept
+    /// as long as the vertex count matches.
     fn compute_vertex_normals(&mut self) {
+        if self.vertex_normals_user_supplied && self.vertex_normals.len() == self.vertices.len() {
+            return;
+        }
+        self.vertex_normals_user_supplied = false;
+
         self.vertex_normals.clear();
         self.vertex_normals.resize(self.vertices.len(), Vec3::ZERO);
 
@@ -187,6 +215,37 @@ impl SurfaceMesh {
         self.edges.sort_unstable(); // Sort for deterministic ordering
     }
 
+    /// Computes the area of each face, summed over its fan triangulation.
+    #[must_use]
+    pub fn compute_face_areas(&self) -> Vec<f32> {
+        self.faces
+            .iter()
+            .map(|face| {
+                let Some(&first) = face.first() else {
+                    return 0.0;
+                };
+                let v0 = self.vertices[first as usize];
+                face.windows(2)
+                    .skip(1)
+                    .map(|pair| {
+                        let v1 = self.vertices[pair[0] as usize];
+                        let v2 = self.vertices[pair[1] as usize];
+                        (v1 - v0).cross(v2 - v0).length() * 0.5
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Computes the length of each edge, in the order of [`SurfaceMesh::edges`].
+    #[must_use]
+    pub fn compute_edge_lengths(&self) -> Vec<f32> {
+        self.edges
+            .iter()
+            .map(|&(a, b)| self.vertices[a as usize].distance(self.vertices[b as usize]))
+            .collect()
+    }
+
     /// Compute default per-face tangent basis from first edge direction.
     #[must_use]
     pub fn compute_face_tangent_basis(&self) -> (Vec<Vec3>, Vec<Vec3>) {
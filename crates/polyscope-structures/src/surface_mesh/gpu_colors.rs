@@ -0,0 +1,210 @@
+//! Quantity color selection and uniforms for the mesh shader.
+
+use glam::Vec4;
+use polyscope_render::{ColorMapRegistry, MeshUniforms};
+
+use super::{MeshVertexScalarQuantity, SurfaceMesh};
+
+impl SurfaceMesh {
+    /// Updates GPU buffers with current mesh settings.
+    ///
+    /// `backface_cull` is the global `Options::backface_cull` override (see
+    /// [`Self::effective_backface_policy`]), and `no_data_color` the global
+    /// `Options::no_data_color` for masked vertex scalar quantities.
+    pub fn update_gpu_buffers(
+        &self,
+        queue: &wgpu::Queue,
+        color_maps: &ColorMapRegistry,
+        backface_cull: Option<bool>,
+        no_data_color: Vec4,
+    ) {
+        let Some(render_data) = &self.render_data else {
+            return;
+        };
+
+        // Convert glam Mat4 to [[f32; 4]; 4] for GPU
+        let model_matrix = self.transform.to_cols_array_2d();
+
+        let mut use_vertex_color = false;
+        let mut has_missing_values = false;
+
+        // Apply quantity colors with priority:
+        // vertex param > corner param > vertex color > corner color > face color > vertex scalar
+        // > face scalar > edge scalar > surface color
+        if let Some(pq) = self.active_vertex_parameterization_quantity() {
+            use_vertex_color = true;
+            let colors = pq.compute_colors();
+            render_data.update_colors(queue, &colors, &self.triangulation);
+        } else if let Some(pq) = self.active_corner_parameterization_quantity() {
+            use_vertex_color = true;
+            // Corner parameterization: compute per-corner colors, expand to per-vertex
+            // (for now, treat as per-face by averaging corners)
+            let corner_colors = pq.compute_colors();
+            let mut vertex_colors = vec![Vec4::splat(0.5); self.vertices.len()];
+            let mut counts = vec![0u32; self.vertices.len()];
+            let mut corner_idx = 0;
+            for face in &self.faces {
+                for &vi in face {
+                    if corner_idx < corner_colors.len() {
+                        vertex_colors[vi as usize] += corner_colors[corner_idx];
+                        counts[vi as usize] += 1;
+                        corner_idx += 1;
+                    }
+                }
+            }
+            for (i, count) in counts.iter().enumerate() {
+                if *count > 0 {
+                    vertex_colors[i] /= *count as f32;
+                }
+            }
+            render_data.update_colors(queue, &vertex_colors, &self.triangulation);
+        } else if let Some(cq) = self.active_vertex_color_quantity() {
+            use_vertex_color = true;
+            // Direct vertex color quantity
+            render_data.update_colors(queue, &cq.linear_colors(), &self.triangulation);
+        } else if let Some(cq) = self.active_corner_color_quantity() {
+            use_vertex_color = true;
+            // Corner colors are written per triangle corner, so they are not
+            // shared between faces at a vertex
+            let colors = cq.compute_triangle_corner_colors(&self.faces);
+            render_data.update_corner_colors(queue, &colors);
+        } else if let Some(cq) = self.active_face_color_quantity() {
+            use_vertex_color = true;
+            // Face color expanded to vertices
+            let colors = cq.compute_vertex_colors(&self.faces, self.vertices.len());
+            render_data.update_colors(queue, &colors, &self.triangulation);
+        } else if let Some(sq) = self.active_vertex_scalar_quantity() {
+            use_vertex_color = true;
+            has_missing_values = sq.has_missing_values();
+            // Vertex scalar mapped through colormap
+            if let Some(colormap) = color_maps.get(sq.colormap_name()) {
+                let colors = sq.compute_colors(colormap);
+                render_data.update_colors(queue, &colors, &self.triangulation);
+            }
+            if sq.isolines_enabled() {
+                let values = sq.compute_isoline_values();
+                render_data.update_isoline_values(queue, &values, &self.triangulation);
+            }
+        } else if let Some(sq) = self.active_face_scalar_quantity() {
+            use_vertex_color = true;
+            // Face scalar mapped through colormap and expanded to vertices
+            if let Some(colormap) = color_maps.get(sq.colormap_name()) {
+                let colors = sq.compute_vertex_colors(&self.faces, self.vertices.len(), colormap);
+                render_data.update_colors(queue, &colors, &self.triangulation);
+            }
+        } else if let Some(sq) = self.active_edge_scalar_quantity() {
+            use_vertex_color = true;
+            // Edge scalar mapped through colormap and averaged onto vertices
+            if let Some(colormap) = color_maps.get(sq.colormap_name()) {
+                let colors = sq.compute_vertex_colors(&self.edges, self.vertices.len(), colormap);
+                render_data.update_colors(queue, &colors, &self.triangulation);
+            }
+        } else {
+            // No quantity enabled - clear colors so shader uses surface_color
+            render_data.clear_colors(queue);
+        }
+
+        let mut uniforms = self.mesh_uniforms(use_vertex_color, backface_cull);
+        if has_missing_values {
+            // Colors carry the data validity in alpha
+            uniforms.use_vertex_color = 2;
+        }
+        uniforms.no_data_color = no_data_color.to_array();
+        render_data.update_uniforms(queue, &uniforms);
+
+        // Update shadow model buffer if initialized
+        render_data.update_shadow_model(queue, model_matrix);
+    }
+
+    /// Builds the mesh shader uniforms from the current settings.
+    ///
+    /// `use_vertex_color` is whether an enabled quantity supplies the colors.
+    #[must_use]
+    pub fn mesh_uniforms(
+        &self,
+        use_vertex_color: bool,
+        backface_cull: Option<bool>,
+    ) -> MeshUniforms {
+        let isolines = self.active_isolines();
+        MeshUniforms {
+            model_matrix: self.transform.to_cols_array_2d(),
+            shade_style: self.shade_style as u32,
+            show_edges: u32::from(self.show_edges),
+            edge_width: self.edge_width,
+            transparency: self.transparency,
+            surface_color: self.surface_color.to_array(),
+            edge_color: self.edge_color.to_array(),
+            backface_policy: self.effective_backface_policy(backface_cull) as u32,
+            slice_planes_enabled: 1,
+            use_vertex_color: u32::from(use_vertex_color),
+            isolines_enabled: u32::from(isolines.is_some()),
+            isoline_count: isolines.map_or(0.0, |q| q.isoline_count() as f32),
+            isoline_width: isolines.map_or(0.0, MeshVertexScalarQuantity::isoline_width),
+            _pad2: 0.0,
+            _pad3: 0.0,
+            backface_color: self.backface_color.to_array(),
+            ..MeshUniforms::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+    use polyscope_core::structure::HasQuantities;
+
+    /// Test that masked vertices get zero validity in the color alpha channel.
+    #[test]
+    fn test_vertex_scalar_masked_validity() {
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE];
+        let mut mesh = SurfaceMesh::new("mesh", vertices, vec![vec![0, 1, 2], vec![2, 1, 3]]);
+        assert!(
+            mesh.add_vertex_scalar_quantity_masked("bad", vec![Some(1.0)])
+                .is_err()
+        );
+        mesh.add_vertex_scalar_quantity_masked("measured", vec![Some(0.0), None, Some(4.0), None])
+            .unwrap();
+
+        let q = mesh.get_quantity("measured").unwrap().as_any();
+        let sq = q.downcast_ref::<MeshVertexScalarQuantity>().unwrap();
+        assert!(sq.has_missing_values());
+        // Missing values do not affect the colormap range
+        assert_eq!((sq.range_min(), sq.range_max()), (0.0, 4.0));
+
+        let colormap = polyscope_render::ColorMap::new("test", vec![Vec3::ZERO, Vec3::ONE]);
+        let validity: Vec<f32> = sq.compute_colors(&colormap).iter().map(|c| c.w).collect();
+        assert_eq!(validity, vec![1.0, 0.0, 1.0, 0.0]);
+        assert_eq!(sq.compute_isoline_values(), vec![0.0, 0.0, 1.0, 0.0]);
+    }
+
+    /// Test that isoline settings on the active vertex scalar reach the uniforms.
+    #[test]
+    fn test_vertex_scalar_isolines_uniforms() {
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        let mut mesh = SurfaceMesh::new("mesh", vertices, vec![vec![0, 1, 2]]);
+        mesh.add_vertex_scalar_quantity("height", vec![0.0, 1.0, 2.0])
+            .unwrap();
+        mesh.get_quantity_mut("height").unwrap().set_enabled(true);
+
+        assert_eq!(mesh.mesh_uniforms(true, None).isolines_enabled, 0);
+
+        let q = mesh.get_quantity_mut("height").unwrap().as_any_mut();
+        let sq = q.downcast_mut::<MeshVertexScalarQuantity>().unwrap();
+        sq.set_isolines_enabled(true);
+        sq.set_isoline_count(5);
+        sq.set_isoline_width(2.0);
+        assert_eq!(sq.compute_isoline_values(), vec![0.0, 0.5, 1.0]);
+
+        let uniforms = mesh.mesh_uniforms(true, None);
+        assert_eq!(uniforms.isolines_enabled, 1);
+        assert_eq!(uniforms.isoline_count, 5.0);
+        assert_eq!(uniforms.isoline_width, 2.0);
+
+        // A higher-priority color quantity hides the isolines
+        mesh.add_vertex_color_quantity("colors", vec![Vec3::ONE; 3])
+            .unwrap();
+        mesh.get_quantity_mut("colors").unwrap().set_enabled(true);
+        assert_eq!(mesh.mesh_uniforms(true, None).isolines_enabled, 0);
+    }
+}
@@ -198,7 +198,8 @@ impl MeshVertexIntrinsicVectorQuantity {
                 model: model.to_cols_array(),
                 length_scale: self.length_scale,
                 radius: self.radius,
-                _padding: [0.0; 2],
+                normalize: 0,
+                _padding: 0.0,
                 color: self.color.to_array(),
             };
             render_data.update_uniforms(queue, &uniforms);
@@ -251,6 +252,12 @@ impl Quantity for MeshVertexIntrinsicVectorQuantity {
     fn clear_gpu_resources(&mut self) {
         self.render_data = None;
     }
+
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
     fn data_size(&self) -> usize {
         self.vectors.len()
     }
@@ -444,7 +451,8 @@ impl MeshFaceIntrinsicVectorQuantity {
                 model: model.to_cols_array(),
                 length_scale: self.length_scale,
                 radius: self.radius,
-                _padding: [0.0; 2],
+                normalize: 0,
+                _padding: 0.0,
                 color: self.color.to_array(),
             };
             render_data.update_uniforms(queue, &uniforms);
@@ -497,6 +505,12 @@ impl Quantity for MeshFaceIntrinsicVectorQuantity {
     fn clear_gpu_resources(&mut self) {
         self.render_data = None;
     }
+
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
     fn data_size(&self) -> usize {
         self.vectors.len()
     }
@@ -1,21 +1,30 @@
 //! Surface mesh structure.
 
+mod adjacency;
+mod decimation;
+mod double_precision;
+mod export;
+mod geodesic;
 mod geometry;
+mod gpu_colors;
 mod intrinsic_vector_quantity;
 mod one_form_quantity;
+mod orientation;
 mod parameterization_quantity;
 mod quantities;
 mod quantity_methods;
+pub use double_precision::bounding_box_center_f64;
 pub use intrinsic_vector_quantity::*;
 pub use one_form_quantity::*;
 pub use parameterization_quantity::*;
 pub use quantities::*;
 
-use glam::{Mat4, Vec3, Vec4};
+use glam::{DVec3, Mat3, Mat4, Vec3, Vec4};
+use polyscope_core::error::{PolyscopeError, Result};
 use polyscope_core::pick::PickResult;
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::{HasQuantities, RenderContext, Structure};
-use polyscope_render::{ColorMapRegistry, MeshPickUniforms, MeshUniforms, SurfaceMeshRenderData};
+use polyscope_render::{MeshPickUniforms, SurfaceMeshRenderData};
 use std::ops::Range;
 
 /// Shading style for surface mesh rendering.
@@ -51,18 +60,26 @@ pub struct SurfaceMesh {
     vertices: Vec<Vec3>,
     faces: Vec<Vec<u32>>, // Variable-length polygons
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
+    // Double-precision world origin the vertices are relative to
+    origin: DVec3,
 
     // Computed data
     triangulation: Vec<[u32; 3]>,
     face_to_tri_range: Vec<Range<usize>>,
     vertex_normals: Vec<Vec3>,
+    vertex_normals_user_supplied: bool,
     face_normals: Vec<Vec3>,
+    face_normals_user_supplied: bool,
     corner_normals: Vec<Vec3>,
     edge_is_real: Vec<Vec3>,
     edges: Vec<(u32, u32)>,
+    adjacency: adjacency::AdjacencyCache,
     needs_recompute: bool,
+    positions_dirty: bool,
 
     // Render options
     material: String,
@@ -96,18 +113,25 @@ impl SurfaceMesh {
             vertices,
             faces,
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
+            origin: DVec3::ZERO,
 
             // Computed data (will be filled by recompute)
             triangulation: Vec::new(),
             face_to_tri_range: Vec::new(),
             vertex_normals: Vec::new(),
+            vertex_normals_user_supplied: false,
             face_normals: Vec::new(),
+            face_normals_user_supplied: false,
             corner_normals: Vec::new(),
             edge_is_real: Vec::new(),
             edges: Vec::new(),
+            adjacency: adjacency::AdjacencyCache::default(),
             needs_recompute: true,
+            positions_dirty: false,
 
             // Default render options
             material: "clay".to_string(),
@@ -167,6 +191,12 @@ impl SurfaceMesh {
         self.edges.len()
     }
 
+    /// Returns the number of face corners (the sum of all face degrees).
+    #[must_use]
+    pub fn num_corners(&self) -> usize {
+        self.faces.iter().map(Vec::len).sum()
+    }
+
     /// Returns the vertices.
     #[must_use]
     pub fn vertices(&self) -> &[Vec3] {
@@ -203,6 +233,113 @@ impl SurfaceMesh {
         &self.face_normals
     }
 
+    /// Overrides the computed vertex normals with user-supplied ones, e.g.
+    /// authored normals from an OBJ file.
+    ///
+    /// Normals are normalized and used for smooth shading. They survive later
+    /// recomputation (e.g. [`Self::update_vertices`]), which still rebuilds
+    /// the triangulation, face normals and edges, until the vertex count
+    /// changes or [`Self::clear_vertex_normals`] is called. Returns
+    /// [`PolyscopeError::SizeMismatch`] if there is not one normal per vertex.
+    pub fn set_vertex_normals(&mut self, normals: Vec<Vec3>) -> Result<()> {
+        if normals.len() != self.vertices.len() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.vertices.len(),
+                actual: normals.len(),
+            });
+        }
+        self.recompute();
+        self.vertex_normals = normals.into_iter().map(Vec3::normalize_or_zero).collect();
+        self.vertex_normals_user_supplied = true;
+        self.recompute_normals();
+        self.positions_dirty = true;
+        Ok(())
+    }
+
+    /// Discards user-supplied vertex normals and recomputes them from the
+    /// face normals.
+    pub fn clear_vertex_normals(&mut self) {
+        if !self.vertex_normals_user_supplied {
+            return;
+        }
+        self.vertex_normals_user_supplied = false;
+        self.recompute_normals();
+        self.positions_dirty = true;
+    }
+
+    /// Returns whether the vertex normals were supplied via [`Self::set_vertex_normals`].
+    #[must_use]
+    pub fn has_user_vertex_normals(&self) -> bool {
+        self.vertex_normals_user_supplied
+    }
+
+    /// Overrides the computed face normals with user-supplied ones.
+    ///
+    /// Normals are normalized and used for flat shading and for the
+    /// area-weighted vertex normals. They survive later recomputation (e.g.
+    /// [`Self::update_vertices`]) until the face count changes or
+    /// [`Self::clear_face_normals`] is called. Returns
+    /// [`PolyscopeError::SizeMismatch`] if there is not one normal per face.
+    pub fn set_face_normals(&mut self, normals: Vec<Vec3>) -> Result<()> {
+        if normals.len() != self.faces.len() {
+            return Err(PolyscopeError::SizeMismatch {
+                expected: self.faces.len(),
+                actual: normals.len(),
+            });
+        }
+        self.recompute();
+        self.face_normals = normals.into_iter().map(Vec3::normalize_or_zero).collect();
+        self.face_normals_user_supplied = true;
+        self.recompute_normals();
+        self.positions_dirty = true;
+        Ok(())
+    }
+
+    /// Discards user-supplied face normals and recomputes them from the geometry.
+    pub fn clear_face_normals(&mut self) {
+        if !self.face_normals_user_supplied {
+            return;
+        }
+        self.face_normals_user_supplied = false;
+        self.recompute_normals();
+        self.positions_dirty = true;
+    }
+
+    /// Returns whether the face normals were supplied via [`Self::set_face_normals`].
+    #[must_use]
+    pub fn has_user_face_normals(&self) -> bool {
+        self.face_normals_user_supplied
+    }
+
+    /// Returns the world-space normal of a face.
+    ///
+    /// The local face normal is transformed by the structure's transform
+    /// (inverse-transpose, so non-uniform scales are handled). Returns `None`
+    /// if `face_idx` is out of range.
+    #[must_use]
+    pub fn face_normal(&self, face_idx: usize) -> Option<Vec3> {
+        let normal = *self.face_normals.get(face_idx)?;
+        let normal_matrix = Mat3::from_mat4(self.transform).inverse().transpose();
+        Some((normal_matrix * normal).normalize_or_zero())
+    }
+
+    /// Returns the world-space centroid (vertex average) of a face.
+    ///
+    /// Returns `None` if `face_idx` is out of range or the face references a
+    /// vertex that does not exist.
+    #[must_use]
+    pub fn face_centroid(&self, face_idx: usize) -> Option<Vec3> {
+        let face = self.faces.get(face_idx)?;
+        if face.is_empty() {
+            return None;
+        }
+        let sum: Vec3 = face
+            .iter()
+            .map(|&i| self.vertices.get(i as usize).copied())
+            .sum::<Option<Vec3>>()?;
+        Some(self.transform.transform_point3(sum / face.len() as f32))
+    }
+
     /// Returns the corner normals (per triangle vertex).
     #[must_use]
     pub fn corner_normals(&self) -> &[Vec3] {
@@ -226,15 +363,46 @@ impl SurfaceMesh {
     }
 
     /// Updates the vertex positions.
+    ///
+    /// Recomputes all derived data and rebuilds GPU resources. If the
+    /// connectivity is unchanged, prefer [`Self::update_vertex_positions_only`].
     pub fn update_vertices(&mut self, vertices: Vec<Vec3>) {
         self.vertices = vertices;
         self.needs_recompute = true;
         self.refresh();
+        self.clear_gpu_resources();
+    }
+
+    /// Updates the vertex positions, keeping the existing connectivity.
+    ///
+    /// Only normals are recomputed; the triangulation, face-to-triangle
+    /// mapping, and edge data are reused, and the existing GPU position and
+    /// normal buffers are overwritten in place on the next frame. Intended
+    /// for animations where vertices move but topology is fixed.
+    ///
+    /// If the vertex count differs from the current mesh, this falls back to
+    /// [`Self::update_vertices`].
+    pub fn update_vertex_positions_only(&mut self, vertices: Vec<Vec3>) {
+        if vertices.len() != self.vertices.len() {
+            log::warn!(
+                "update_vertex_positions_only on '{}': vertex count changed ({} -> {}), rebuilding mesh",
+                self.name,
+                self.vertices.len(),
+                vertices.len()
+            );
+            self.update_vertices(vertices);
+            return;
+        }
+        self.vertices = vertices;
+        self.recompute_normals();
+        self.refresh_derived_quantities();
+        self.positions_dirty = true;
     }
 
     /// Updates the faces.
     pub fn update_faces(&mut self, faces: Vec<Vec<u32>>) {
         self.faces = faces;
+        self.adjacency = adjacency::AdjacencyCache::default();
         self.needs_recompute = true;
         self.refresh();
     }
@@ -248,8 +416,17 @@ impl SurfaceMesh {
     }
 
     /// Sets the shade style.
+    ///
+    /// Corner normals depend on the shade style, so they are recomputed
+    /// immediately when the style changes.
     pub fn set_shade_style(&mut self, style: ShadeStyle) {
+        if self.shade_style == style {
+            return;
+        }
         self.shade_style = style;
+        if !self.needs_recompute {
+            self.compute_corner_normals();
+        }
     }
 
     /// Gets the edge width.
@@ -296,6 +473,21 @@ impl SurfaceMesh {
         self.backface_policy = policy;
     }
 
+    /// Returns the backface policy used for rendering, given the global
+    /// `Options::backface_cull` override.
+    ///
+    /// `Some(true)` forces [`BackfacePolicy::Cull`], `Some(false)` turns a
+    /// `Cull` policy into [`BackfacePolicy::Identical`], and `None` keeps the
+    /// mesh's own policy.
+    #[must_use]
+    pub fn effective_backface_policy(&self, global_cull: Option<bool>) -> BackfacePolicy {
+        match (global_cull, self.backface_policy) {
+            (Some(true), _) => BackfacePolicy::Cull,
+            (Some(false), BackfacePolicy::Cull) => BackfacePolicy::Identical,
+            (_, policy) => policy,
+        }
+    }
+
     /// Gets the backface color.
     #[must_use]
     pub fn backface_color(&self) -> Vec4 {
@@ -358,11 +550,11 @@ impl SurfaceMesh {
             &mut self.material,
             available_materials,
         ) {
-            self.shade_style = match shade_style {
+            self.set_shade_style(match shade_style {
                 0 => ShadeStyle::Smooth,
                 1 => ShadeStyle::Flat,
                 _ => ShadeStyle::TriFlat,
-            };
+            });
             self.surface_color = Vec4::new(color[0], color[1], color[2], self.surface_color.w);
             self.transparency = transparency;
             self.show_edges = show_edges;
@@ -397,11 +589,21 @@ impl SurfaceMesh {
                     .downcast_mut::<MeshFaceScalarQuantity>()
                 {
                     sq.build_egui_ui(ui);
+                } else if let Some(sq) = quantity
+                    .as_any_mut()
+                    .downcast_mut::<MeshEdgeScalarQuantity>()
+                {
+                    sq.build_egui_ui(ui);
                 } else if let Some(cq) = quantity
                     .as_any_mut()
                     .downcast_mut::<MeshVertexColorQuantity>()
                 {
                     cq.build_egui_ui(ui);
+                } else if let Some(cq) = quantity
+                    .as_any_mut()
+                    .downcast_mut::<MeshCornerColorQuantity>()
+                {
+                    cq.build_egui_ui(ui);
                 } else if let Some(cq) = quantity
                     .as_any_mut()
                     .downcast_mut::<MeshFaceColorQuantity>()
@@ -463,6 +665,7 @@ impl SurfaceMesh {
             &self.vertex_normals,
             &self.edge_is_real,
         ));
+        self.positions_dirty = false;
     }
 
     /// Returns the render data if initialized.
@@ -471,6 +674,28 @@ impl SurfaceMesh {
         self.render_data.as_ref()
     }
 
+    /// Returns whether vertex positions changed since the last GPU upload.
+    #[must_use]
+    pub fn positions_dirty(&self) -> bool {
+        self.positions_dirty
+    }
+
+    /// Uploads vertex positions and normals to the existing GPU buffers.
+    ///
+    /// Called after [`Self::update_vertex_positions_only`]; does nothing if
+    /// GPU resources have not been initialized yet.
+    pub fn upload_vertex_positions(&mut self, queue: &wgpu::Queue) {
+        if let Some(render_data) = &self.render_data {
+            render_data.update_positions(
+                queue,
+                &self.vertices,
+                &self.triangulation,
+                &self.vertex_normals,
+            );
+        }
+        self.positions_dirty = false;
+    }
+
     /// Initializes shadow rendering resources.
     ///
     /// Creates the bind group needed to render this mesh in the shadow pass.
@@ -596,96 +821,6 @@ impl SurfaceMesh {
     pub fn num_triangulation_vertices(&self) -> u32 {
         (self.triangulation.len() * 3) as u32
     }
-
-    /// Updates GPU buffers with current mesh settings.
-    pub fn update_gpu_buffers(&self, queue: &wgpu::Queue, color_maps: &ColorMapRegistry) {
-        let Some(render_data) = &self.render_data else {
-            return;
-        };
-
-        // Convert glam Mat4 to [[f32; 4]; 4] for GPU
-        let model_matrix = self.transform.to_cols_array_2d();
-
-        let mut use_vertex_color = false;
-
-        // Apply quantity colors with priority:
-        // vertex param > corner param > vertex color > face color > vertex scalar > face scalar > surface color
-        if let Some(pq) = self.active_vertex_parameterization_quantity() {
-            use_vertex_color = true;
-            let colors = pq.compute_colors();
-            render_data.update_colors(queue, &colors, &self.triangulation);
-        } else if let Some(pq) = self.active_corner_parameterization_quantity() {
-            use_vertex_color = true;
-            // Corner parameterization: compute per-corner colors, expand to per-vertex
-            // (for now, treat as per-face by averaging corners)
-            let corner_colors = pq.compute_colors();
-            let mut vertex_colors = vec![Vec4::splat(0.5); self.vertices.len()];
-            let mut counts = vec![0u32; self.vertices.len()];
-            let mut corner_idx = 0;
-            for face in &self.faces {
-                for &vi in face {
-                    if corner_idx < corner_colors.len() {
-                        vertex_colors[vi as usize] += corner_colors[corner_idx];
-                        counts[vi as usize] += 1;
-                        corner_idx += 1;
-                    }
-                }
-            }
-            for (i, count) in counts.iter().enumerate() {
-                if *count > 0 {
-                    vertex_colors[i] /= *count as f32;
-                }
-            }
-            render_data.update_colors(queue, &vertex_colors, &self.triangulation);
-        } else if let Some(cq) = self.active_vertex_color_quantity() {
-            use_vertex_color = true;
-            // Direct vertex color quantity
-            render_data.update_colors(queue, cq.colors(), &self.triangulation);
-        } else if let Some(cq) = self.active_face_color_quantity() {
-            use_vertex_color = true;
-            // Face color expanded to vertices
-            let colors = cq.compute_vertex_colors(&self.faces, self.vertices.len());
-            render_data.update_colors(queue, &colors, &self.triangulation);
-        } else if let Some(sq) = self.active_vertex_scalar_quantity() {
-            use_vertex_color = true;
-            // Vertex scalar mapped through colormap
-            if let Some(colormap) = color_maps.get(sq.colormap_name()) {
-                let colors = sq.compute_colors(colormap);
-                render_data.update_colors(queue, &colors, &self.triangulation);
-            }
-        } else if let Some(sq) = self.active_face_scalar_quantity() {
-            use_vertex_color = true;
-            // Face scalar mapped through colormap and expanded to vertices
-            if let Some(colormap) = color_maps.get(sq.colormap_name()) {
-                let colors = sq.compute_vertex_colors(&self.faces, self.vertices.len(), colormap);
-                render_data.update_colors(queue, &colors, &self.triangulation);
-            }
-        } else {
-            // No quantity enabled - clear colors so shader uses surface_color
-            render_data.clear_colors(queue);
-        }
-
-        let uniforms = MeshUniforms {
-            model_matrix,
-            shade_style: self.shade_style as u32,
-            show_edges: u32::from(self.show_edges),
-            edge_width: self.edge_width,
-            transparency: self.transparency,
-            surface_color: self.surface_color.to_array(),
-            edge_color: self.edge_color.to_array(),
-            backface_policy: self.backface_policy as u32,
-            slice_planes_enabled: 1,
-            use_vertex_color: u32::from(use_vertex_color),
-            _pad1: 0.0,
-            _pad2: [0.0; 3],
-            _pad3: 0.0,
-            backface_color: self.backface_color.to_array(),
-        };
-        render_data.update_uniforms(queue, &uniforms);
-
-        // Update shadow model buffer if initialized
-        render_data.update_shadow_model(queue, model_matrix);
-    }
 }
 
 impl Structure for SurfaceMesh {
@@ -762,6 +897,22 @@ impl Structure for SurfaceMesh {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn material(&self) -> &str {
         &self.material
     }
@@ -796,8 +947,28 @@ impl Structure for SurfaceMesh {
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, SurfaceMeshRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .pick_face_index_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         self.recompute();
+        self.refresh_derived_quantities();
         for quantity in &mut self.quantities {
             quantity.refresh();
         }
@@ -833,6 +1004,7 @@ impl HasQuantities for SurfaceMesh {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use glam::Vec2;
 
     /// Test triangle and quad mesh creation and triangulation.
     #[test]
@@ -865,6 +1037,135 @@ mod tests {
         assert_eq!(mesh.face_to_tri_range()[0], 0..2);
     }
 
+    /// Test world-space face normal and centroid lookup.
+    #[test]
+    fn test_face_normal_and_centroid() {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+        ];
+        let mut mesh = SurfaceMesh::new("test_face_lookup", vertices, vec![vec![0, 1, 2]]);
+
+        assert!((mesh.face_normal(0).unwrap() - Vec3::Z).length() < 1e-6);
+        assert!((mesh.face_centroid(0).unwrap() - Vec3::new(1.0, 1.0, 0.0)).length() < 1e-6);
+        assert!(mesh.face_normal(1).is_none());
+        assert!(mesh.face_centroid(1).is_none());
+        assert!(mesh.face_normal(usize::MAX).is_none());
+        assert!(mesh.face_centroid(usize::MAX).is_none());
+
+        // Rotate +Z onto +X and translate
+        mesh.set_transform(
+            Mat4::from_translation(Vec3::new(0.0, 0.0, 5.0))
+                * Mat4::from_rotation_y(std::f32::consts::FRAC_PI_2),
+        );
+        assert!((mesh.face_normal(0).unwrap() - Vec3::X).length() < 1e-5);
+        assert!((mesh.face_centroid(0).unwrap() - Vec3::new(0.0, 1.0, 4.0)).length() < 1e-5);
+    }
+
+    /// Non-planar quads get the Newell (average) normal, not the first-corner normal.
+    #[test]
+    fn test_face_normals_twisted_quad() {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let naive = (vertices[1] - vertices[0])
+            .cross(vertices[2] - vertices[0])
+            .normalize();
+        let mut mesh = SurfaceMesh::new("twisted", vertices, vec![vec![0, 1, 2, 3]]);
+
+        let newell = mesh.face_normals()[0];
+        assert!((newell - Vec3::new(-1.0, -1.0, 2.0).normalize()).length() < 1e-6);
+        assert!((newell - naive).length() > 0.1);
+
+        // User-supplied normals survive recomputation until cleared
+        mesh.set_face_normals(vec![Vec3::new(0.0, 0.0, 2.0)])
+            .unwrap();
+        assert_eq!(mesh.face_normals()[0], Vec3::Z);
+        assert!((mesh.vertex_normals()[0] - Vec3::Z).length() < 1e-6);
+        mesh.update_vertices(mesh.vertices().to_vec());
+        mesh.recompute();
+        assert_eq!(mesh.face_normals()[0], Vec3::Z);
+
+        mesh.clear_face_normals();
+        assert!((mesh.face_normals()[0] - newell).length() < 1e-6);
+        assert!(mesh.set_face_normals(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_user_vertex_normals() {
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::new(1.0, 1.0, 0.0)];
+        let mut mesh = SurfaceMesh::new("sharp", vertices, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+        let smooth = mesh.vertex_normals().to_vec();
+
+        // Sharp normals that the area-weighted average would never produce
+        let sharp = vec![Vec3::X, Vec3::Y, Vec3::NEG_X, Vec3::NEG_Y];
+        mesh.set_vertex_normals(sharp.clone()).unwrap();
+        assert!(mesh.has_user_vertex_normals());
+        assert_eq!(mesh.vertex_normals(), sharp.as_slice());
+
+        // Unrelated recomputation keeps them, but still rebuilds the rest
+        mesh.update_faces(vec![vec![0, 1, 3], vec![0, 3, 2]]);
+        mesh.recompute();
+        assert_eq!(mesh.vertex_normals(), sharp.as_slice());
+        assert_eq!(mesh.triangulation(), &[[0, 1, 3], [0, 3, 2]]);
+
+        mesh.clear_vertex_normals();
+        assert!(!mesh.has_user_vertex_normals());
+        assert!(
+            mesh.vertex_normals()
+                .iter()
+                .zip(&smooth)
+                .all(|(a, b)| (*a - *b).length() < 1e-6)
+        );
+        assert!(mesh.set_vertex_normals(vec![Vec3::Z]).is_err());
+    }
+
+    #[test]
+    fn test_face_area_and_edge_length_quantities() {
+        let square = vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0), Vec3::Y];
+        let mut mesh = SurfaceMesh::new("square", square, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+        mesh.add_face_area_quantity("area");
+        mesh.add_edge_length_quantity("length");
+
+        let areas = |mesh: &SurfaceMesh| {
+            mesh.get_quantity("area")
+                .and_then(|q| q.as_any().downcast_ref::<MeshFaceScalarQuantity>())
+                .unwrap()
+                .values()
+                .to_vec()
+        };
+        let diagonal_length = |mesh: &SurfaceMesh| {
+            let diagonal = mesh.edges().iter().position(|&e| e == (0, 2)).unwrap();
+            mesh.get_quantity("length")
+                .and_then(|q| q.as_any().downcast_ref::<MeshEdgeScalarQuantity>())
+                .unwrap()
+                .values()[diagonal]
+        };
+        assert!((areas(&mesh).iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!((diagonal_length(&mesh) - 2.0f32.sqrt()).abs() < 1e-6);
+
+        // Derived values follow the vertices
+        let doubled: Vec<Vec3> = mesh.vertices().iter().map(|v| *v * 2.0).collect();
+        mesh.update_vertices(doubled);
+        assert!((areas(&mesh).iter().sum::<f32>() - 4.0).abs() < 1e-6);
+        assert!((diagonal_length(&mesh) - 8.0f32.sqrt()).abs() < 1e-6);
+
+        // User-supplied face scalars are left alone
+        mesh.add_face_scalar_quantity("user", vec![1.0, 2.0])
+            .unwrap();
+        mesh.update_vertex_positions_only(mesh.vertices().to_vec());
+        let user = mesh
+            .get_quantity("user")
+            .and_then(|q| q.as_any().downcast_ref::<MeshFaceScalarQuantity>())
+            .unwrap();
+        assert!(!user.is_derived());
+        assert_eq!(user.values(), &[1.0, 2.0]);
+    }
+
     /// Test face normal computation.
     #[test]
     fn test_face_normals() {
@@ -967,6 +1268,42 @@ mod tests {
         assert_eq!(mesh.num_edges(), 5);
     }
 
+    /// Test position-only updates keep connectivity and recompute normals.
+    #[test]
+    fn test_update_vertex_positions_only() {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let mut mesh = SurfaceMesh::new("test_update", vertices, vec![vec![0, 1, 2, 3]]);
+        let triangulation = mesh.triangulation().to_vec();
+        let edges = mesh.edges().to_vec();
+        assert!(!mesh.positions_dirty());
+
+        // Rotate the quad into the XZ plane: normal flips from +Z to -Y
+        mesh.update_vertex_positions_only(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ]);
+
+        assert!(mesh.positions_dirty());
+        assert_eq!(mesh.triangulation(), triangulation.as_slice());
+        assert_eq!(mesh.edges(), edges.as_slice());
+        assert!((mesh.face_normals()[0] - Vec3::NEG_Y).length() < 1e-6);
+        for normal in mesh.vertex_normals() {
+            assert!((*normal - Vec3::NEG_Y).length() < 1e-6);
+        }
+
+        // A vertex count change falls back to a full rebuild
+        mesh.update_vertex_positions_only(vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE, Vec3::Z]);
+        assert_eq!(mesh.num_vertices(), 5);
+        assert_eq!(mesh.num_triangles(), 2);
+    }
+
     /// Test shared edge between two triangles.
     #[test]
     fn test_shared_edges() {
@@ -1036,6 +1373,63 @@ mod tests {
         assert_eq!(mesh.transparency(), 0.0);
     }
 
+    #[test]
+    fn test_set_shade_style_updates_corner_normals() {
+        // Two faces folded along the shared edge, so vertex and face normals differ
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ];
+        let mut mesh = SurfaceMesh::new("shade", vertices, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+
+        mesh.set_shade_style(ShadeStyle::Smooth);
+        assert_eq!(mesh.corner_normals()[1], mesh.vertex_normals()[1]);
+        assert_ne!(mesh.corner_normals()[1], mesh.face_normals()[0]);
+
+        mesh.set_shade_style(ShadeStyle::Flat);
+        for (face_idx, range) in mesh.face_to_tri_range.iter().enumerate() {
+            for tri_idx in range.clone() {
+                for corner in 0..3 {
+                    assert_eq!(
+                        mesh.corner_normals()[tri_idx * 3 + corner],
+                        mesh.face_normals()[face_idx]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_effective_backface_policy() {
+        let mut mesh = SurfaceMesh::from_triangles(
+            "test",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![[0, 1, 2]],
+        );
+        mesh.set_backface_policy(BackfacePolicy::Different);
+        assert_eq!(
+            mesh.effective_backface_policy(None),
+            BackfacePolicy::Different
+        );
+        assert_eq!(
+            mesh.effective_backface_policy(Some(true)),
+            BackfacePolicy::Cull
+        );
+        assert_eq!(
+            mesh.effective_backface_policy(Some(false)),
+            BackfacePolicy::Different
+        );
+
+        mesh.set_backface_policy(BackfacePolicy::Cull);
+        assert_eq!(mesh.effective_backface_policy(None), BackfacePolicy::Cull);
+        assert_eq!(
+            mesh.effective_backface_policy(Some(false)),
+            BackfacePolicy::Identical
+        );
+    }
+
     /// Test all quantity types on a surface mesh.
     #[test]
     fn test_surface_mesh_quantities() {
@@ -1050,14 +1444,19 @@ mod tests {
         let mut mesh = SurfaceMesh::new("test", vertices, faces);
 
         // Vertex quantities (size = 3)
-        mesh.add_vertex_scalar_quantity("height", vec![0.0, 0.5, 1.0]);
-        mesh.add_vertex_color_quantity("colors", vec![Vec3::X, Vec3::Y, Vec3::Z]);
-        mesh.add_vertex_vector_quantity("normals", vec![Vec3::Z, Vec3::Z, Vec3::Z]);
+        mesh.add_vertex_scalar_quantity("height", vec![0.0, 0.5, 1.0])
+            .unwrap();
+        mesh.add_vertex_color_quantity("colors", vec![Vec3::X, Vec3::Y, Vec3::Z])
+            .unwrap();
+        mesh.add_vertex_vector_quantity("normals", vec![Vec3::Z, Vec3::Z, Vec3::Z])
+            .unwrap();
 
         // Face quantities (size = 1)
-        mesh.add_face_scalar_quantity("area", vec![1.0]);
-        mesh.add_face_color_quantity("face_colors", vec![Vec3::new(1.0, 0.0, 0.0)]);
-        mesh.add_face_vector_quantity("face_normals", vec![Vec3::Z]);
+        mesh.add_face_scalar_quantity("area", vec![1.0]).unwrap();
+        mesh.add_face_color_quantity("face_colors", vec![Vec3::new(1.0, 0.0, 0.0)])
+            .unwrap();
+        mesh.add_face_vector_quantity("face_normals", vec![Vec3::Z])
+            .unwrap();
 
         let cases: &[(&str, usize, QuantityKind)] = &[
             ("height", 3, QuantityKind::Scalar),
@@ -1081,6 +1480,109 @@ mod tests {
         }
     }
 
+    /// Quantity adders reject data whose length does not match the mesh.
+    #[test]
+    fn test_quantity_size_mismatch_rejected() {
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE];
+        let faces = vec![vec![0, 1, 2], vec![1, 3, 2]];
+        let mut mesh = SurfaceMesh::new("test", vertices, faces);
+        let (nv, nf, nc, ne) = (4, 2, 6, mesh.num_edges());
+        assert_eq!(mesh.num_corners(), nc);
+
+        let results = [
+            (
+                nv,
+                mesh.add_vertex_scalar_quantity("a", vec![0.0; 3])
+                    .map(|_| ()),
+            ),
+            (
+                nf,
+                mesh.add_face_scalar_quantity("b", vec![0.0; 3]).map(|_| ()),
+            ),
+            (
+                nv,
+                mesh.add_vertex_color_quantity("c", vec![Vec3::ONE; 5])
+                    .map(|_| ()),
+            ),
+            (
+                nv,
+                mesh.add_vertex_color_quantity_with_alpha("d", vec![Vec4::ONE; 1])
+                    .map(|_| ()),
+            ),
+            (
+                nf,
+                mesh.add_face_color_quantity("e", vec![Vec3::ONE; 1])
+                    .map(|_| ()),
+            ),
+            (
+                nf,
+                mesh.add_face_color_quantity_with_alpha("f", vec![Vec4::ONE; 4])
+                    .map(|_| ()),
+            ),
+            (
+                nv,
+                mesh.add_vertex_vector_quantity("g", vec![Vec3::Z; 2])
+                    .map(|_| ()),
+            ),
+            (
+                nf,
+                mesh.add_face_vector_quantity("h", vec![Vec3::Z; 3])
+                    .map(|_| ()),
+            ),
+            (
+                nv,
+                mesh.add_vertex_parameterization_quantity("i", vec![Vec2::ZERO; 2])
+                    .map(|_| ()),
+            ),
+            (
+                nc,
+                mesh.add_corner_parameterization_quantity("j", vec![Vec2::ZERO; 4])
+                    .map(|_| ()),
+            ),
+            (
+                nv,
+                mesh.add_vertex_intrinsic_vector_quantity_auto("k", vec![Vec2::X; 3])
+                    .map(|_| ()),
+            ),
+            (
+                nf,
+                mesh.add_face_intrinsic_vector_quantity_auto("l", vec![Vec2::X; 1])
+                    .map(|_| ()),
+            ),
+            (
+                ne,
+                mesh.add_one_form_quantity("m", vec![1.0; ne + 1], vec![true; ne + 1])
+                    .map(|_| ()),
+            ),
+        ];
+        for (expected, result) in results {
+            assert!(
+                matches!(result, Err(PolyscopeError::SizeMismatch { expected: e, .. }) if e == expected),
+                "expected a size mismatch against {expected}, got {result:?}"
+            );
+        }
+
+        // A correctly sized basis does not excuse a short vector array.
+        assert!(
+            mesh.add_vertex_intrinsic_vector_quantity(
+                "n",
+                vec![Vec2::X; 4],
+                vec![Vec3::X; 4],
+                vec![Vec3::Y; 3],
+            )
+            .is_err()
+        );
+        assert!(
+            mesh.add_one_form_quantity("o", vec![1.0; ne], vec![true; ne - 1])
+                .is_err()
+        );
+        assert!(mesh.quantities().is_empty());
+
+        mesh.add_corner_parameterization_quantity("uv", vec![Vec2::ZERO; nc])
+            .unwrap();
+        assert_eq!(mesh.quantities().len(), 1);
+    }
+
     /// Test face scalar quantity compute_vertex_colors.
     #[test]
     fn test_face_scalar_compute_vertex_colors() {
@@ -1098,6 +1600,22 @@ mod tests {
         assert!((colors[3] - Vec4::new(1.0, 1.0, 1.0, 1.0)).length() < 1e-5);
     }
 
+    /// Test that a fixed map range clamps values outside it.
+    #[test]
+    fn test_vertex_scalar_map_range_clamps() {
+        let mut q = MeshVertexScalarQuantity::new("test", "mesh", vec![-5.0, 0.5, 5.0]);
+        let colormap = polyscope_render::ColorMap::new("test", vec![Vec3::ZERO, Vec3::ONE]);
+
+        q.set_map_range(0.0, 1.0);
+        let colors = q.compute_colors(&colormap);
+        assert_eq!(colors[0], Vec4::new(0.0, 0.0, 0.0, 1.0));
+        assert!((colors[1] - Vec4::new(0.5, 0.5, 0.5, 1.0)).length() < 1e-5);
+        assert_eq!(colors[2], Vec4::new(1.0, 1.0, 1.0, 1.0));
+
+        q.reset_map_range();
+        assert_eq!((q.range_min(), q.range_max()), (-5.0, 5.0));
+    }
+
     /// Test face color quantity compute_vertex_colors.
     #[test]
     fn test_face_color_compute_vertex_colors() {
@@ -1117,6 +1635,51 @@ mod tests {
         assert_eq!(colors[3], Vec4::new(0.0, 1.0, 0.0, 1.0));
     }
 
+    /// Test that corner colors of two triangles sharing a diagonal stay
+    /// separate at the shared vertices.
+    #[test]
+    fn test_corner_colors_discontinuous_at_shared_vertices() {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let mut mesh = SurfaceMesh::new("quad", vertices, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+        let red = Vec3::new(1.0, 0.0, 0.0);
+        let blue = Vec3::new(0.0, 0.0, 1.0);
+
+        assert!(mesh.add_corner_color_quantity("c", vec![red; 4]).is_err());
+        mesh.add_corner_color_quantity("c", vec![red, red, red, blue, blue, blue])
+            .unwrap();
+        mesh.get_quantity_mut("c").unwrap().set_enabled(true);
+
+        let cq = mesh.active_corner_color_quantity().unwrap();
+        let colors = cq.compute_triangle_corner_colors(mesh.faces());
+        assert_eq!(colors.len(), mesh.triangulation().len() * 3);
+        // Vertices 0 and 2 on the diagonal are red in the first triangle and
+        // blue in the second
+        assert_eq!(colors[0], red.extend(1.0));
+        assert_eq!(colors[2], red.extend(1.0));
+        assert_eq!(colors[3], blue.extend(1.0));
+        assert_eq!(colors[4], blue.extend(1.0));
+    }
+
+    /// Test corner colors of a polygon follow its fan triangulation.
+    #[test]
+    fn test_corner_colors_polygon_fan() {
+        let color = |i: u8| Vec3::splat(f32::from(i));
+        let mut cq = MeshCornerColorQuantity::new("c", "mesh", (0..5).map(color).collect());
+        // Linear, so the corner indices used as colors pass through unchanged
+        cq.set_color_space(polyscope_core::ColorSpace::Linear);
+        let corner_colors = cq.compute_triangle_corner_colors(&[vec![0, 1, 2, 3, 4]]);
+        let expected: Vec<Vec4> = [0, 1, 2, 0, 2, 3, 0, 3, 4]
+            .into_iter()
+            .map(|i| color(i).extend(1.0))
+            .collect();
+        assert_eq!(corner_colors, expected);
+    }
+
     /// Test edge_is_real for triangle (all edges should be real).
     #[test]
     fn test_edge_is_real_triangle() {
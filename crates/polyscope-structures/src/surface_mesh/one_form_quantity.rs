@@ -190,7 +190,8 @@ impl MeshOneFormQuantity {
                 model: model.to_cols_array(),
                 length_scale: self.length_scale,
                 radius: self.radius,
-                _padding: [0.0; 2],
+                normalize: 0,
+                _padding: 0.0,
                 color: self.color.to_array(),
             };
             render_data.update_uniforms(queue, &uniforms);
@@ -242,6 +243,12 @@ impl Quantity for MeshOneFormQuantity {
     fn clear_gpu_resources(&mut self) {
         self.render_data = None;
     }
+
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
     fn data_size(&self) -> usize {
         self.values.len()
     }
@@ -0,0 +1,210 @@
+//! Face winding repair for surface meshes.
+
+use std::collections::{HashMap, VecDeque};
+
+use polyscope_core::structure::Structure;
+
+use super::SurfaceMesh;
+
+impl SurfaceMesh {
+    /// Reverses the winding of every face, turning the mesh inside out.
+    ///
+    /// Each face keeps its first vertex, so `[a, b, c, d]` becomes
+    /// `[a, d, c, b]`. Computed normals are recomputed and user-supplied
+    /// vertex and face normals are negated. Per-corner quantities are not
+    /// reordered.
+    pub fn flip_orientation(&mut self) {
+        for face in &mut self.faces {
+            reverse_winding(face);
+        }
+        if self.vertex_normals_user_supplied {
+            for normal in &mut self.vertex_normals {
+                *normal = -*normal;
+            }
+        }
+        if self.face_normals_user_supplied {
+            for normal in &mut self.face_normals {
+                *normal = -*normal;
+            }
+        }
+        self.winding_changed();
+    }
+
+    /// Makes the face winding consistent within each connected component.
+    ///
+    /// Walks face adjacency breadth-first from the first face of every
+    /// component and reverses neighbors that traverse a shared edge in the
+    /// same direction (faces are reversed as in [`Self::flip_orientation`]).
+    /// Closed components are then turned so their normals point outward.
+    /// Across non-manifold edges, a face follows whichever neighbor reaches
+    /// it first. User-supplied face normals of reversed faces are negated.
+    ///
+    /// Returns the number of reversed faces.
+    pub fn orient_consistently(&mut self) -> usize {
+        // Faces around each undirected edge, and whether the face runs from
+        // the lower to the higher vertex index along it
+        let mut edge_faces: HashMap<(u32, u32), Vec<(usize, bool)>> = HashMap::new();
+        for (f, face) in self.faces.iter().enumerate() {
+            for (a, b) in face_edges(face) {
+                edge_faces
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push((f, a < b));
+            }
+        }
+
+        let mut flipped = vec![false; self.faces.len()];
+        let mut visited = vec![false; self.faces.len()];
+        for root in 0..self.faces.len() {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            let mut component = vec![root];
+            let mut queue = VecDeque::from([root]);
+            let mut closed = true;
+
+            while let Some(f) = queue.pop_front() {
+                for (a, b) in face_edges(&self.faces[f]) {
+                    let neighbors = &edge_faces[&(a.min(b), a.max(b))];
+                    closed &= neighbors.len() == 2;
+                    let forward = (a < b) != flipped[f];
+                    for &(g, g_forward) in neighbors {
+                        if visited[g] {
+                            continue;
+                        }
+                        visited[g] = true;
+                        // Consistent neighbors traverse the shared edge the other way
+                        flipped[g] = g_forward == forward;
+                        component.push(g);
+                        queue.push_back(g);
+                    }
+                }
+            }
+
+            if closed && self.signed_volume(&component, &flipped) < 0.0 {
+                for &f in &component {
+                    flipped[f] = !flipped[f];
+                }
+            }
+        }
+
+        let mut count = 0;
+        for (f, &flip) in flipped.iter().enumerate() {
+            if flip {
+                reverse_winding(&mut self.faces[f]);
+                if self.face_normals_user_supplied {
+                    self.face_normals[f] = -self.face_normals[f];
+                }
+                count += 1;
+            }
+        }
+        if count > 0 {
+            self.winding_changed();
+        }
+        count
+    }
+
+    /// Signed volume enclosed by `faces`, with the faces marked in `flipped`
+    /// counted as reversed. Positive when the normals point outward.
+    fn signed_volume(&self, faces: &[usize], flipped: &[bool]) -> f32 {
+        let volume: f32 = faces
+            .iter()
+            .map(|&f| {
+                let face = &self.faces[f];
+                let p = |i: usize| self.vertices[face[i] as usize];
+                let volume: f32 = (1..face.len().saturating_sub(1))
+                    .map(|i| p(0).dot(p(i).cross(p(i + 1))))
+                    .sum();
+                if flipped[f] { -volume } else { volume }
+            })
+            .sum();
+        volume / 6.0
+    }
+
+    /// Rebuilds the triangulation, normals and GPU resources after faces
+    /// were reversed.
+    fn winding_changed(&mut self) {
+        self.needs_recompute = true;
+        self.refresh();
+        self.clear_gpu_resources();
+    }
+}
+
+/// Reverses a face's vertex order, keeping its first vertex in place.
+fn reverse_winding(face: &mut [u32]) {
+    if face.len() > 2 {
+        face[1..].reverse();
+    }
+}
+
+/// Returns the directed edges of a face, in winding order.
+fn face_edges(face: &[u32]) -> impl Iterator<Item = (u32, u32)> + '_ {
+    let n = face.len();
+    (0..n).map(move |i| (face[i], face[(i + 1) % n]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    /// Unit cube with outward-facing quads.
+    fn cube() -> SurfaceMesh {
+        let vertices = (0..8u8)
+            .map(|i| Vec3::new(f32::from(i & 1), f32::from((i >> 1) & 1), f32::from(i >> 2)))
+            .collect();
+        let faces = vec![
+            vec![0, 2, 3, 1], // z = 0
+            vec![4, 5, 7, 6], // z = 1
+            vec![0, 1, 5, 4], // y = 0
+            vec![2, 6, 7, 3], // y = 1
+            vec![0, 4, 6, 2], // x = 0
+            vec![1, 3, 7, 5], // x = 1
+        ];
+        SurfaceMesh::new("cube", vertices, faces)
+    }
+
+    fn all_outward(mesh: &SurfaceMesh) -> bool {
+        (0..mesh.num_faces()).all(|f| {
+            let outward = mesh.face_centroid(f).unwrap() - Vec3::splat(0.5);
+            mesh.face_normal(f).unwrap().dot(outward) > 0.0
+        })
+    }
+
+    #[test]
+    fn test_flip_orientation_reverses_faces_and_normals() {
+        let mut mesh = cube();
+        assert!(all_outward(&mesh));
+        mesh.flip_orientation();
+        assert_eq!(mesh.faces()[0], vec![0, 1, 3, 2]);
+        assert!((0..6).all(|f| {
+            let inward = Vec3::splat(0.5) - mesh.face_centroid(f).unwrap();
+            mesh.face_normal(f).unwrap().dot(inward) > 0.0
+        }));
+    }
+
+    #[test]
+    fn test_orient_consistently_fixes_inverted_faces() {
+        // Inverting the first face also checks that the root face is not trusted
+        for inverted in [0, 3] {
+            let mut mesh = cube();
+            let mut faces = mesh.faces().to_vec();
+            reverse_winding(&mut faces[inverted]);
+            mesh.update_faces(faces);
+            assert!(!all_outward(&mesh));
+
+            assert_eq!(mesh.orient_consistently(), 1);
+            assert!(all_outward(&mesh));
+        }
+    }
+
+    #[test]
+    fn test_orient_consistently_turns_inside_out_cube() {
+        let mut mesh = cube();
+        mesh.flip_orientation();
+        assert_eq!(mesh.orient_consistently(), 6);
+        assert!(all_outward(&mesh));
+        assert_eq!(mesh.orient_consistently(), 0);
+    }
+}
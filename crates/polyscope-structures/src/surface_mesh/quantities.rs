@@ -1,9 +1,15 @@
 //! Surface mesh quantity implementations.
 
 use glam::{Vec3, Vec4};
-use polyscope_core::quantity::{FaceQuantity, Quantity, QuantityKind, VertexQuantity};
+use polyscope_core::color_space::ColorSpace;
+use polyscope_core::error::Result;
+use polyscope_core::quantity::{
+    EdgeQuantity, FaceQuantity, Quantity, QuantityKind, VertexQuantity,
+};
 use polyscope_render::{ColorMap, VectorRenderData, VectorUniforms};
 
+use crate::quantity_update::{data_range, replace_data};
+
 /// A vertex scalar quantity on a surface mesh.
 pub struct MeshVertexScalarQuantity {
     name: String,
@@ -13,6 +19,10 @@ pub struct MeshVertexScalarQuantity {
     colormap_name: String,
     range_min: f32,
     range_max: f32,
+    range_user_set: bool, // false = range follows the data
+    isolines_enabled: bool,
+    isoline_count: u32,
+    isoline_width: f32,
 }
 
 impl MeshVertexScalarQuantity {
@@ -22,8 +32,7 @@ impl MeshVertexScalarQuantity {
         structure_name: impl Into<String>,
         values: Vec<f32>,
     ) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
@@ -33,15 +42,107 @@ impl MeshVertexScalarQuantity {
             colormap_name: "viridis".to_string(),
             range_min: min,
             range_max: max,
+            range_user_set: false,
+            isolines_enabled: false,
+            isoline_count: 10,
+            isoline_width: 1.0,
         }
     }
 
-    /// Returns the scalar values.
+    /// Creates a vertex scalar quantity defined on a subset of the vertices;
+    /// `None` entries have no data and are stored as NaN.
+    pub fn new_masked(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        values: Vec<Option<f32>>,
+    ) -> Self {
+        let values = values.into_iter().map(|v| v.unwrap_or(f32::NAN)).collect();
+        Self::new(name, structure_name, values)
+    }
+
+    /// Returns the scalar values. Vertices without data hold NaN.
     #[must_use]
     pub fn values(&self) -> &[f32] {
         &self.values
     }
 
+    /// Returns whether some vertices have no data (NaN values).
+    #[must_use]
+    pub fn has_missing_values(&self) -> bool {
+        self.values.iter().any(|v| v.is_nan())
+    }
+
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
+    /// Returns whether isolines are drawn over the colormap.
+    #[must_use]
+    pub fn isolines_enabled(&self) -> bool {
+        self.isolines_enabled
+    }
+
+    /// Enables or disables isolines (contour lines) over the colormap.
+    pub fn set_isolines_enabled(&mut self, enabled: bool) {
+        self.isolines_enabled = enabled;
+    }
+
+    /// Returns the number of isolines across the colormap range.
+    #[must_use]
+    pub fn isoline_count(&self) -> u32 {
+        self.isoline_count
+    }
+
+    /// Sets the number of isolines, evenly spaced across the colormap range
+    /// (at least 1).
+    pub fn set_isoline_count(&mut self, count: u32) {
+        self.isoline_count = count.max(1);
+    }
+
+    /// Returns the isoline width in pixels.
+    #[must_use]
+    pub fn isoline_width(&self) -> f32 {
+        self.isoline_width
+    }
+
+    /// Sets the isoline width in pixels.
+    pub fn set_isoline_width(&mut self, width: f32) {
+        self.isoline_width = width.max(0.0);
+    }
+
+    /// Returns the values normalized to the colormap range, which the mesh
+    /// shader bands into isolines. Values outside the range are not clamped,
+    /// so isolines continue past it; vertices without data get 0.
+    #[must_use]
+    pub fn compute_isoline_values(&self) -> Vec<f32> {
+        let range = self.range_max - self.range_min;
+        let range = if range.abs() < 1e-10 { 1.0 } else { range };
+
+        self.values
+            .iter()
+            .map(|&v| {
+                if v.is_nan() {
+                    0.0
+                } else {
+                    (v - self.range_min) / range
+                }
+            })
+            .collect()
+    }
+
     /// Gets the colormap name.
     #[must_use]
     pub fn colormap_name(&self) -> &str {
@@ -69,9 +170,29 @@ impl MeshVertexScalarQuantity {
     pub fn set_range(&mut self, min: f32, max: f32) {
         self.range_min = min;
         self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Sets a fixed colormap range.
+    ///
+    /// Values outside `[min, max]` are clamped to the ends of the colormap.
+    /// Use this to keep colors comparable across frames or datasets.
+    pub fn set_map_range(&mut self, min: f32, max: f32) {
+        self.set_range(min, max);
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
     }
 
     /// Maps scalar values to colors using the colormap.
+    ///
+    /// Vertices without data map to transparent black, so the alpha channel
+    /// is a validity mask: the mesh shader divides interpolated colors by it
+    /// and shows `Options::no_data_color` where it falls below one half.
     #[must_use]
     pub fn compute_colors(&self, colormap: &ColorMap) -> Vec<Vec4> {
         let range = self.range_max - self.range_min;
@@ -80,7 +201,10 @@ impl MeshVertexScalarQuantity {
         self.values
             .iter()
             .map(|&v| {
-                let t = (v - self.range_min) / range;
+                if v.is_nan() {
+                    return Vec4::ZERO;
+                }
+                let t = ((v - self.range_min) / range).clamp(0.0, 1.0);
                 colormap.sample(t).extend(1.0)
             })
             .collect()
@@ -89,7 +213,8 @@ impl MeshVertexScalarQuantity {
     /// Builds the egui UI for this quantity.
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
         let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
-        polyscope_ui::build_scalar_quantity_ui(
+        let range = (self.range_min, self.range_max);
+        let mut changed = polyscope_ui::build_scalar_quantity_ui(
             ui,
             &self.name,
             &mut self.enabled,
@@ -97,7 +222,42 @@ impl MeshVertexScalarQuantity {
             &mut self.range_min,
             &mut self.range_max,
             &colormaps,
-        )
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+
+        if self.enabled {
+            ui.indent(format!("{}_isolines", self.name), |ui| {
+                egui::Grid::new(format!("{}_isoline_grid", self.name))
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Isolines:");
+                        changed |= ui.checkbox(&mut self.isolines_enabled, "").changed();
+                        ui.end_row();
+
+                        if self.isolines_enabled {
+                            ui.label("Count:");
+                            changed |= ui
+                                .add(egui::DragValue::new(&mut self.isoline_count).range(1..=100))
+                                .changed();
+                            ui.end_row();
+
+                            ui.label("Width:");
+                            changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut self.isoline_width)
+                                        .speed(0.1)
+                                        .range(0.0..=10.0),
+                                )
+                                .changed();
+                            ui.end_row();
+                        }
+                    });
+            });
+        }
+
+        changed
     }
 }
 
@@ -150,6 +310,9 @@ pub struct MeshFaceScalarQuantity {
     colormap_name: String,
     range_min: f32,
     range_max: f32,
+    range_user_set: bool, // false = range follows the data
+    // Face areas computed by the mesh, updated when its vertices move
+    derived: bool,
 }
 
 impl MeshFaceScalarQuantity {
@@ -159,8 +322,7 @@ impl MeshFaceScalarQuantity {
         structure_name: impl Into<String>,
         values: Vec<f32>,
     ) -> Self {
-        let min = values.iter().copied().fold(f32::INFINITY, f32::min);
-        let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let (min, max) = data_range(&values);
 
         Self {
             name: name.into(),
@@ -170,6 +332,8 @@ impl MeshFaceScalarQuantity {
             colormap_name: "viridis".to_string(),
             range_min: min,
             range_max: max,
+            range_user_set: false,
+            derived: false,
         }
     }
 
@@ -179,6 +343,44 @@ impl MeshFaceScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the values are face areas computed by the mesh (see
+    /// [`SurfaceMesh::add_face_area_quantity`](super::SurfaceMesh::add_face_area_quantity)).
+    #[must_use]
+    pub fn is_derived(&self) -> bool {
+        self.derived
+    }
+
+    /// Marks the values as computed by the mesh.
+    pub(super) fn set_derived(&mut self) {
+        self.derived = true;
+    }
+
+    /// Replaces the values, refitting the colormap range unless it was set
+    /// explicitly.
+    pub(super) fn set_values(&mut self, values: Vec<f32>) {
+        self.values = values;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+    }
+
     /// Gets the colormap name.
     #[must_use]
     pub fn colormap_name(&self) -> &str {
@@ -206,6 +408,22 @@ impl MeshFaceScalarQuantity {
     pub fn set_range(&mut self, min: f32, max: f32) {
         self.range_min = min;
         self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Sets a fixed colormap range.
+    ///
+    /// Values outside `[min, max]` are clamped to the ends of the colormap.
+    /// Use this to keep colors comparable across frames or datasets.
+    pub fn set_map_range(&mut self, min: f32, max: f32) {
+        self.set_range(min, max);
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
     }
 
     /// Computes vertex colors by expanding face values to all vertices of each face.
@@ -223,7 +441,7 @@ impl MeshFaceScalarQuantity {
         let mut colors = vec![Vec4::splat(0.5); num_vertices];
 
         for (face_idx, face) in faces.iter().enumerate() {
-            let t = (self.values[face_idx] - self.range_min) / range;
+            let t = ((self.values[face_idx] - self.range_min) / range).clamp(0.0, 1.0);
             let color = colormap.sample(t).extend(1.0);
             for &vi in face {
                 colors[vi as usize] = color;
@@ -236,7 +454,8 @@ impl MeshFaceScalarQuantity {
     /// Builds the egui UI for this quantity.
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
         let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
-        polyscope_ui::build_scalar_quantity_ui(
+        let range = (self.range_min, self.range_max);
+        let changed = polyscope_ui::build_scalar_quantity_ui(
             ui,
             &self.name,
             &mut self.enabled,
@@ -244,7 +463,11 @@ impl MeshFaceScalarQuantity {
             &mut self.range_min,
             &mut self.range_max,
             &colormaps,
-        )
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+        changed
     }
 }
 
@@ -288,12 +511,229 @@ impl Quantity for MeshFaceScalarQuantity {
 
 impl FaceQuantity for MeshFaceScalarQuantity {}
 
+/// An edge scalar quantity on a surface mesh, with one value per edge in
+/// the order of [`SurfaceMesh::edges`](super::SurfaceMesh::edges).
+///
+/// It is displayed on the surface by averaging the values of the edges
+/// around each vertex.
+pub struct MeshEdgeScalarQuantity {
+    name: String,
+    structure_name: String,
+    values: Vec<f32>,
+    enabled: bool,
+    colormap_name: String,
+    range_min: f32,
+    range_max: f32,
+    range_user_set: bool, // false = range follows the data
+    // Edge lengths computed by the mesh, updated when its vertices move
+    derived: bool,
+}
+
+impl MeshEdgeScalarQuantity {
+    /// Creates a new edge scalar quantity.
+    pub fn new(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Self {
+        let (min, max) = data_range(&values);
+
+        Self {
+            name: name.into(),
+            structure_name: structure_name.into(),
+            values,
+            enabled: false,
+            colormap_name: "viridis".to_string(),
+            range_min: min,
+            range_max: max,
+            range_user_set: false,
+            derived: false,
+        }
+    }
+
+    /// Returns the scalar values.
+    #[must_use]
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    /// Replaces the values in place, keeping the colormap. The colormap range
+    /// is refit to the new values unless it was set explicitly (in code or
+    /// in the UI). The colors shown are recomputed from the new values on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the values are edge lengths computed by the mesh (see
+    /// [`SurfaceMesh::add_edge_length_quantity`](super::SurfaceMesh::add_edge_length_quantity)).
+    #[must_use]
+    pub fn is_derived(&self) -> bool {
+        self.derived
+    }
+
+    /// Marks the values as computed by the mesh.
+    pub(super) fn set_derived(&mut self) {
+        self.derived = true;
+    }
+
+    /// Replaces the values, refitting the colormap range unless it was set
+    /// explicitly.
+    pub(super) fn set_values(&mut self, values: Vec<f32>) {
+        self.values = values;
+        if !self.range_user_set {
+            (self.range_min, self.range_max) = data_range(&self.values);
+        }
+    }
+
+    /// Gets the colormap name.
+    #[must_use]
+    pub fn colormap_name(&self) -> &str {
+        &self.colormap_name
+    }
+
+    /// Sets the colormap name.
+    pub fn set_colormap(&mut self, name: impl Into<String>) {
+        self.colormap_name = name.into();
+    }
+
+    /// Gets the range minimum.
+    #[must_use]
+    pub fn range_min(&self) -> f32 {
+        self.range_min
+    }
+
+    /// Gets the range maximum.
+    #[must_use]
+    pub fn range_max(&self) -> f32 {
+        self.range_max
+    }
+
+    /// Sets a fixed colormap range.
+    ///
+    /// Values outside `[min, max]` are clamped to the ends of the colormap.
+    pub fn set_map_range(&mut self, min: f32, max: f32) {
+        self.range_min = min;
+        self.range_max = max;
+        self.range_user_set = true;
+    }
+
+    /// Resets the colormap range to the min/max of the data, which it then
+    /// follows on value updates.
+    pub fn reset_map_range(&mut self) {
+        (self.range_min, self.range_max) = data_range(&self.values);
+        self.range_user_set = false;
+    }
+
+    /// Computes vertex colors from the mean value of the edges at each vertex.
+    #[must_use]
+    pub fn compute_vertex_colors(
+        &self,
+        edges: &[(u32, u32)],
+        num_vertices: usize,
+        colormap: &ColorMap,
+    ) -> Vec<Vec4> {
+        let range = self.range_max - self.range_min;
+        let range = if range.abs() < 1e-10 { 1.0 } else { range };
+
+        let mut sums = vec![0.0f32; num_vertices];
+        let mut counts = vec![0u32; num_vertices];
+        for (&(a, b), &value) in edges.iter().zip(&self.values) {
+            for vi in [a as usize, b as usize] {
+                sums[vi] += value;
+                counts[vi] += 1;
+            }
+        }
+
+        sums.iter()
+            .zip(&counts)
+            .map(|(&sum, &count)| {
+                if count == 0 {
+                    return Vec4::splat(0.5);
+                }
+                let mean = sum / count as f32;
+                let t = ((mean - self.range_min) / range).clamp(0.0, 1.0);
+                colormap.sample(t).extend(1.0)
+            })
+            .collect()
+    }
+
+    /// Builds the egui UI for this quantity.
+    pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        let colormaps = ["viridis", "blues", "reds", "coolwarm", "rainbow"];
+        let range = (self.range_min, self.range_max);
+        let changed = polyscope_ui::build_scalar_quantity_ui(
+            ui,
+            &self.name,
+            &mut self.enabled,
+            &mut self.colormap_name,
+            &mut self.range_min,
+            &mut self.range_max,
+            &colormaps,
+        );
+        if (self.range_min, self.range_max) != range {
+            self.range_user_set = true;
+        }
+        changed
+    }
+}
+
+impl Quantity for MeshEdgeScalarQuantity {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn structure_name(&self) -> &str {
+        &self.structure_name
+    }
+
+    fn kind(&self) -> QuantityKind {
+        QuantityKind::Scalar
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn build_ui(&mut self, _ui: &dyn std::any::Any) {}
+
+    fn refresh(&mut self) {}
+
+    fn data_size(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl EdgeQuantity for MeshEdgeScalarQuantity {}
+
 /// A vertex color quantity on a surface mesh.
 pub struct MeshVertexColorQuantity {
     name: String,
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
     has_transparency: bool,
 }
 
@@ -309,6 +749,7 @@ impl MeshVertexColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
             has_transparency: false,
         }
     }
@@ -325,6 +766,7 @@ impl MeshVertexColorQuantity {
             structure_name: structure_name.into(),
             colors,
             enabled: false,
+            color_space: ColorSpace::default(),
             has_transparency,
         }
     }
@@ -335,6 +777,34 @@ impl MeshVertexColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     /// Returns true if any color has alpha < 1.0.
     #[must_use]
     pub fn has_transparency(&self) -> bool {
@@ -393,6 +863,7 @@ pub struct MeshFaceColorQuantity {
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
     has_transparency: bool,
 }
 
@@ -408,6 +879,7 @@ impl MeshFaceColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
             has_transparency: false,
         }
     }
@@ -424,6 +896,7 @@ impl MeshFaceColorQuantity {
             structure_name: structure_name.into(),
             colors,
             enabled: false,
+            color_space: ColorSpace::default(),
             has_transparency,
         }
     }
@@ -434,20 +907,49 @@ impl MeshFaceColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     /// Returns true if any color has alpha < 1.0.
     #[must_use]
     pub fn has_transparency(&self) -> bool {
         self.has_transparency
     }
 
-    /// Computes vertex colors by expanding face colors to all vertices of each face.
-    /// For each vertex, uses the color of the last face it belongs to.
+    /// Computes linear vertex colors by expanding face colors to all vertices
+    /// of each face. For each vertex, uses the color of the last face it
+    /// belongs to.
     #[must_use]
     pub fn compute_vertex_colors(&self, faces: &[Vec<u32>], num_vertices: usize) -> Vec<Vec4> {
         let mut colors = vec![Vec4::splat(0.5); num_vertices];
 
         for (face_idx, face) in faces.iter().enumerate() {
-            let color = self.colors[face_idx];
+            let color = self.color_space.to_linear(self.colors[face_idx]);
             for &vi in face {
                 colors[vi as usize] = color;
             }
@@ -502,6 +1004,133 @@ impl Quantity for MeshFaceColorQuantity {
 
 impl FaceQuantity for MeshFaceColorQuantity {}
 
+/// A corner (per-face-vertex) color quantity on a surface mesh.
+///
+/// Each face corner has its own color, so faces sharing a vertex can be
+/// colored discontinuously across it.
+pub struct MeshCornerColorQuantity {
+    name: String,
+    structure_name: String,
+    colors: Vec<Vec4>, // One per face corner, faces in order
+    enabled: bool,
+    color_space: ColorSpace,
+}
+
+impl MeshCornerColorQuantity {
+    /// Creates a new corner color quantity (RGB, alpha defaults to 1.0).
+    pub fn new(
+        name: impl Into<String>,
+        structure_name: impl Into<String>,
+        colors: Vec<Vec3>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            structure_name: structure_name.into(),
+            colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
+            enabled: false,
+            color_space: ColorSpace::default(),
+        }
+    }
+
+    /// Returns the colors.
+    #[must_use]
+    pub fn colors(&self) -> &[Vec4] {
+        &self.colors
+    }
+
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
+    /// Computes one linear color per triangle corner of the fan
+    /// triangulation of `faces` (three per triangle, in triangulation order).
+    #[must_use]
+    pub fn compute_triangle_corner_colors(&self, faces: &[Vec<u32>]) -> Vec<Vec4> {
+        let corner_colors = self.linear_colors();
+        let mut colors = Vec::new();
+        let mut face_start = 0;
+        for face in faces {
+            // Triangle i of the fan uses face corners 0, i, i + 1
+            for i in 1..face.len().saturating_sub(1) {
+                colors.push(corner_colors[face_start]);
+                colors.push(corner_colors[face_start + i]);
+                colors.push(corner_colors[face_start + i + 1]);
+            }
+            face_start += face.len();
+        }
+        colors
+    }
+
+    /// Builds the egui UI for this quantity.
+    pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) -> bool {
+        polyscope_ui::build_color_quantity_ui(ui, &self.name, &mut self.enabled, self.colors.len())
+    }
+}
+
+impl Quantity for MeshCornerColorQuantity {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn structure_name(&self) -> &str {
+        &self.structure_name
+    }
+
+    fn kind(&self) -> QuantityKind {
+        QuantityKind::Color
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn build_ui(&mut self, _ui: &dyn std::any::Any) {}
+
+    fn refresh(&mut self) {}
+
+    fn data_size(&self) -> usize {
+        self.colors.len()
+    }
+}
+
+impl FaceQuantity for MeshCornerColorQuantity {}
+
 /// A vertex vector quantity on a surface mesh.
 pub struct MeshVertexVectorQuantity {
     name: String,
@@ -509,9 +1138,13 @@ pub struct MeshVertexVectorQuantity {
     vectors: Vec<Vec3>,
     enabled: bool,
     length_scale: f32,
+    length_is_relative: bool,
+    normalize: bool,
     radius: f32,
     color: Vec4,
     render_data: Option<VectorRenderData>,
+    /// Set when the vectors changed after the GPU buffer was created.
+    vectors_dirty: bool,
 }
 
 impl MeshVertexVectorQuantity {
@@ -527,9 +1160,12 @@ impl MeshVertexVectorQuantity {
             vectors,
             enabled: false,
             length_scale: 1.0,
+            length_is_relative: false,
+            normalize: false,
             radius: 0.005,
             color: Vec4::new(0.8, 0.2, 0.2, 1.0),
             render_data: None,
+            vectors_dirty: false,
         }
     }
 
@@ -539,6 +1175,28 @@ impl MeshVertexVectorQuantity {
         &self.vectors
     }
 
+    /// Replaces the vectors. The existing GPU buffer is rewritten in place on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one vector per
+    /// existing vector.
+    pub fn update_vectors(&mut self, vectors: Vec<Vec3>) -> Result<()> {
+        replace_data(&mut self.vectors, vectors)?;
+        self.vectors_dirty = true;
+        Ok(())
+    }
+
+    /// Writes vectors changed by [`Self::update_vectors`] to the existing GPU
+    /// buffer.
+    pub fn upload_vectors(&mut self, queue: &wgpu::Queue) {
+        if let (true, Some(render_data)) = (self.vectors_dirty, &self.render_data) {
+            render_data.update_vectors(queue, &self.vectors);
+            self.vectors_dirty = false;
+        }
+    }
+
     /// Gets the length scale.
     #[must_use]
     pub fn length_scale(&self) -> f32 {
@@ -546,8 +1204,31 @@ impl MeshVertexVectorQuantity {
     }
 
     /// Sets the length scale.
-    pub fn set_length_scale(&mut self, scale: f32) {
+    ///
+    /// Arrow lengths are the vector magnitudes times `scale`, or just `scale`
+    /// when normalized. If `relative`, `scale` is further multiplied by the
+    /// structure's length scale.
+    pub fn set_length_scale(&mut self, scale: f32, relative: bool) {
         self.length_scale = scale;
+        self.length_is_relative = relative;
+    }
+
+    /// Returns whether the length scale is relative to the structure size.
+    #[must_use]
+    pub fn length_is_relative(&self) -> bool {
+        self.length_is_relative
+    }
+
+    /// Sets whether all arrows are drawn with the same length, keeping
+    /// only their direction.
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    /// Returns whether arrows are normalized to a common length.
+    #[must_use]
+    pub fn is_normalized(&self) -> bool {
+        self.normalize
     }
 
     /// Gets the radius.
@@ -606,6 +1287,7 @@ impl MeshVertexVectorQuantity {
             base_positions,
             &self.vectors,
         ));
+        self.vectors_dirty = false;
     }
 
     /// Returns the render data if initialized.
@@ -614,17 +1296,35 @@ impl MeshVertexVectorQuantity {
         self.render_data.as_ref()
     }
 
-    /// Updates GPU uniforms with the given model transform.
-    pub fn update_uniforms(&self, queue: &wgpu::Queue, model: &glam::Mat4) {
+    /// Returns the GPU uniforms for the given model transform and
+    /// structure length scale.
+    #[must_use]
+    pub fn uniforms(&self, model: &glam::Mat4, structure_length_scale: f32) -> VectorUniforms {
+        let length_scale = if self.length_is_relative {
+            self.length_scale * structure_length_scale
+        } else {
+            self.length_scale
+        };
+        VectorUniforms {
+            model: model.to_cols_array(),
+            length_scale,
+            radius: self.radius,
+            normalize: u32::from(self.normalize),
+            _padding: 0.0,
+            color: self.color.to_array(),
+        }
+    }
+
+    /// Updates GPU uniforms with the given model transform and structure
+    /// length scale.
+    pub fn update_uniforms(
+        &self,
+        queue: &wgpu::Queue,
+        model: &glam::Mat4,
+        structure_length_scale: f32,
+    ) {
         if let Some(render_data) = &self.render_data {
-            let uniforms = VectorUniforms {
-                model: model.to_cols_array(),
-                length_scale: self.length_scale,
-                radius: self.radius,
-                _padding: [0.0; 2],
-                color: self.color.to_array(),
-            };
-            render_data.update_uniforms(queue, &uniforms);
+            render_data.update_uniforms(queue, &self.uniforms(model, structure_length_scale));
         }
     }
 
@@ -683,6 +1383,12 @@ impl Quantity for MeshVertexVectorQuantity {
         self.render_data = None;
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
+
     fn data_size(&self) -> usize {
         self.vectors.len()
     }
@@ -697,9 +1403,13 @@ pub struct MeshFaceVectorQuantity {
     vectors: Vec<Vec3>,
     enabled: bool,
     length_scale: f32,
+    length_is_relative: bool,
+    normalize: bool,
     radius: f32,
     color: Vec4,
     render_data: Option<VectorRenderData>,
+    /// Set when the vectors changed after the GPU buffer was created.
+    vectors_dirty: bool,
 }
 
 impl MeshFaceVectorQuantity {
@@ -715,9 +1425,12 @@ impl MeshFaceVectorQuantity {
             vectors,
             enabled: false,
             length_scale: 1.0,
+            length_is_relative: false,
+            normalize: false,
             radius: 0.005,
             color: Vec4::new(0.2, 0.2, 0.8, 1.0),
             render_data: None,
+            vectors_dirty: false,
         }
     }
 
@@ -727,6 +1440,28 @@ impl MeshFaceVectorQuantity {
         &self.vectors
     }
 
+    /// Replaces the vectors. The existing GPU buffer is rewritten in place on
+    /// the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one vector per
+    /// existing vector.
+    pub fn update_vectors(&mut self, vectors: Vec<Vec3>) -> Result<()> {
+        replace_data(&mut self.vectors, vectors)?;
+        self.vectors_dirty = true;
+        Ok(())
+    }
+
+    /// Writes vectors changed by [`Self::update_vectors`] to the existing GPU
+    /// buffer.
+    pub fn upload_vectors(&mut self, queue: &wgpu::Queue) {
+        if let (true, Some(render_data)) = (self.vectors_dirty, &self.render_data) {
+            render_data.update_vectors(queue, &self.vectors);
+            self.vectors_dirty = false;
+        }
+    }
+
     /// Gets the length scale.
     #[must_use]
     pub fn length_scale(&self) -> f32 {
@@ -734,8 +1469,31 @@ impl MeshFaceVectorQuantity {
     }
 
     /// Sets the length scale.
-    pub fn set_length_scale(&mut self, scale: f32) {
+    ///
+    /// Arrow lengths are the vector magnitudes times `scale`, or just `scale`
+    /// when normalized. If `relative`, `scale` is further multiplied by the
+    /// structure's length scale.
+    pub fn set_length_scale(&mut self, scale: f32, relative: bool) {
         self.length_scale = scale;
+        self.length_is_relative = relative;
+    }
+
+    /// Returns whether the length scale is relative to the structure size.
+    #[must_use]
+    pub fn length_is_relative(&self) -> bool {
+        self.length_is_relative
+    }
+
+    /// Sets whether all arrows are drawn with the same length, keeping
+    /// only their direction.
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    /// Returns whether arrows are normalized to a common length.
+    #[must_use]
+    pub fn is_normalized(&self) -> bool {
+        self.normalize
     }
 
     /// Gets the radius.
@@ -794,6 +1552,7 @@ impl MeshFaceVectorQuantity {
             base_positions,
             &self.vectors,
         ));
+        self.vectors_dirty = false;
     }
 
     /// Returns the render data if initialized.
@@ -802,17 +1561,35 @@ impl MeshFaceVectorQuantity {
         self.render_data.as_ref()
     }
 
-    /// Updates GPU uniforms with the given model transform.
-    pub fn update_uniforms(&self, queue: &wgpu::Queue, model: &glam::Mat4) {
+    /// Returns the GPU uniforms for the given model transform and
+    /// structure length scale.
+    #[must_use]
+    pub fn uniforms(&self, model: &glam::Mat4, structure_length_scale: f32) -> VectorUniforms {
+        let length_scale = if self.length_is_relative {
+            self.length_scale * structure_length_scale
+        } else {
+            self.length_scale
+        };
+        VectorUniforms {
+            model: model.to_cols_array(),
+            length_scale,
+            radius: self.radius,
+            normalize: u32::from(self.normalize),
+            _padding: 0.0,
+            color: self.color.to_array(),
+        }
+    }
+
+    /// Updates GPU uniforms with the given model transform and structure
+    /// length scale.
+    pub fn update_uniforms(
+        &self,
+        queue: &wgpu::Queue,
+        model: &glam::Mat4,
+        structure_length_scale: f32,
+    ) {
         if let Some(render_data) = &self.render_data {
-            let uniforms = VectorUniforms {
-                model: model.to_cols_array(),
-                length_scale: self.length_scale,
-                radius: self.radius,
-                _padding: [0.0; 2],
-                color: self.color.to_array(),
-            };
-            render_data.update_uniforms(queue, &uniforms);
+            render_data.update_uniforms(queue, &self.uniforms(model, structure_length_scale));
         }
     }
 
@@ -871,6 +1648,12 @@ impl Quantity for MeshFaceVectorQuantity {
         self.render_data = None;
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, VectorRenderData::buffer_bytes)
+    }
+
     fn data_size(&self) -> usize {
         self.vectors.len()
     }
@@ -1,20 +1,26 @@
 //! Quantity management methods for surface meshes.
 //!
 //! This module contains methods for adding and accessing quantities on surface meshes:
-//! - Scalar quantities (vertex and face)
-//! - Color quantities (vertex and face, RGB and RGBA)
+//! - Scalar quantities (vertex, face and edge; face areas and edge lengths)
+//! - Color quantities (vertex and face, RGB and RGBA; corner, RGB)
 //! - Vector quantities (vertex and face)
 //! - Parameterization quantities (vertex and corner UV)
 //! - Intrinsic vector quantities (vertex and face, with tangent basis)
 //! - One-form quantities (edge-based differential forms)
+//!
+//! Every add method checks its data against the matching element count
+//! (vertices, faces, corners or edges) and returns
+//! [`PolyscopeError::SizeMismatch`] without adding anything on a mismatch.
 
 use glam::{Vec2, Vec3, Vec4};
+use polyscope_core::error::{PolyscopeError, Result};
 use polyscope_core::quantity::QuantityKind;
 use polyscope_core::structure::{HasQuantities, Structure};
 
 use super::{
-    MeshCornerParameterizationQuantity, MeshFaceColorQuantity, MeshFaceIntrinsicVectorQuantity,
-    MeshFaceScalarQuantity, MeshFaceVectorQuantity, MeshOneFormQuantity, MeshVertexColorQuantity,
+    MeshCornerColorQuantity, MeshCornerParameterizationQuantity, MeshEdgeScalarQuantity,
+    MeshFaceColorQuantity, MeshFaceIntrinsicVectorQuantity, MeshFaceScalarQuantity,
+    MeshFaceVectorQuantity, MeshOneFormQuantity, MeshVertexColorQuantity,
     MeshVertexIntrinsicVectorQuantity, MeshVertexParameterizationQuantity,
     MeshVertexScalarQuantity, MeshVertexVectorQuantity, SurfaceMesh,
 };
@@ -27,10 +33,26 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         values: Vec<f32>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), values.len())?;
         let quantity = MeshVertexScalarQuantity::new(name, self.name.clone(), values);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
+    }
+
+    /// Adds a vertex scalar quantity defined on a subset of the vertices.
+    ///
+    /// Vertices with `None` have no data; the surface around them is drawn in
+    /// `Options::no_data_color` instead of the colormap.
+    pub fn add_vertex_scalar_quantity_masked(
+        &mut self,
+        name: impl Into<String>,
+        values: Vec<Option<f32>>,
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), values.len())?;
+        let quantity = MeshVertexScalarQuantity::new_masked(name, self.name.clone(), values);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
     }
 
     /// Adds a face scalar quantity to this mesh.
@@ -38,9 +60,45 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         values: Vec<f32>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_faces(), values.len())?;
         let quantity = MeshFaceScalarQuantity::new(name, self.name.clone(), values);
         self.add_quantity(Box::new(quantity));
+        Ok(self)
+    }
+
+    /// Adds an edge scalar quantity to this mesh, with one value per edge in
+    /// the order of [`Self::edges`].
+    pub fn add_edge_scalar_quantity(
+        &mut self,
+        name: impl Into<String>,
+        values: Vec<f32>,
+    ) -> Result<&mut Self> {
+        check_len(self.num_edges(), values.len())?;
+        let quantity = MeshEdgeScalarQuantity::new(name, self.name.clone(), values);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
+    }
+
+    /// Adds a face scalar quantity holding the area of each face.
+    ///
+    /// The areas are recomputed whenever the vertices or faces are updated.
+    pub fn add_face_area_quantity(&mut self, name: impl Into<String>) -> &mut Self {
+        let mut quantity =
+            MeshFaceScalarQuantity::new(name, self.name.clone(), self.compute_face_areas());
+        quantity.set_derived();
+        self.add_quantity(Box::new(quantity));
+        self
+    }
+
+    /// Adds an edge scalar quantity holding the length of each edge.
+    ///
+    /// The lengths are recomputed whenever the vertices or faces are updated.
+    pub fn add_edge_length_quantity(&mut self, name: impl Into<String>) -> &mut Self {
+        let mut quantity =
+            MeshEdgeScalarQuantity::new(name, self.name.clone(), self.compute_edge_lengths());
+        quantity.set_derived();
+        self.add_quantity(Box::new(quantity));
         self
     }
 
@@ -49,10 +107,11 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         colors: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), colors.len())?;
         let quantity = MeshVertexColorQuantity::new(name, self.name.clone(), colors);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a vertex color quantity with explicit per-vertex RGBA alpha values.
@@ -60,10 +119,11 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         colors: Vec<Vec4>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), colors.len())?;
         let quantity = MeshVertexColorQuantity::new_with_alpha(name, self.name.clone(), colors);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a face color quantity to this mesh (RGB, alpha defaults to 1.0).
@@ -71,10 +131,11 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         colors: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_faces(), colors.len())?;
         let quantity = MeshFaceColorQuantity::new(name, self.name.clone(), colors);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a face color quantity with explicit per-face RGBA alpha values.
@@ -82,10 +143,26 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         colors: Vec<Vec4>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_faces(), colors.len())?;
         let quantity = MeshFaceColorQuantity::new_with_alpha(name, self.name.clone(), colors);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
+    }
+
+    /// Adds a corner (per-face-vertex) color quantity to this mesh.
+    ///
+    /// `colors` has one entry per face corner, faces in order (the sum of the
+    /// face lengths). Colors are not blended across vertices shared by faces.
+    pub fn add_corner_color_quantity(
+        &mut self,
+        name: impl Into<String>,
+        colors: Vec<Vec3>,
+    ) -> Result<&mut Self> {
+        check_len(self.num_corners(), colors.len())?;
+        let quantity = MeshCornerColorQuantity::new(name, self.name.clone(), colors);
+        self.add_quantity(Box::new(quantity));
+        Ok(self)
     }
 
     /// Adds a vertex vector quantity to this mesh.
@@ -95,11 +172,12 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         vectors: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), vectors.len())?;
         let mut quantity = MeshVertexVectorQuantity::new(name, self.name.clone(), vectors);
         quantity.auto_scale(self.length_scale());
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a face vector quantity to this mesh.
@@ -109,11 +187,12 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         vectors: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_faces(), vectors.len())?;
         let mut quantity = MeshFaceVectorQuantity::new(name, self.name.clone(), vectors);
         quantity.auto_scale(self.length_scale());
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a vertex parameterization (UV) quantity to this mesh.
@@ -121,10 +200,11 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         coords: Vec<Vec2>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_vertices(), coords.len())?;
         let quantity = MeshVertexParameterizationQuantity::new(name, self.name.clone(), coords);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a corner parameterization (UV) quantity to this mesh.
@@ -132,10 +212,11 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         coords: Vec<Vec2>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_corners(), coords.len())?;
         let quantity = MeshCornerParameterizationQuantity::new(name, self.name.clone(), coords);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a vertex intrinsic vector quantity with explicit tangent basis.
@@ -147,7 +228,11 @@ impl SurfaceMesh {
         vectors: Vec<Vec2>,
         basis_x: Vec<Vec3>,
         basis_y: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        let expected = self.num_vertices();
+        check_len(expected, vectors.len())?;
+        check_len(expected, basis_x.len())?;
+        check_len(expected, basis_y.len())?;
         let mut quantity = MeshVertexIntrinsicVectorQuantity::new(
             name,
             self.name.clone(),
@@ -157,7 +242,7 @@ impl SurfaceMesh {
         );
         quantity.auto_scale(self.length_scale());
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a vertex intrinsic vector quantity with auto-computed tangent basis.
@@ -165,7 +250,7 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         vectors: Vec<Vec2>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
         let (bx, by) = self.compute_vertex_tangent_basis();
         self.add_vertex_intrinsic_vector_quantity(name, vectors, bx, by)
     }
@@ -179,7 +264,11 @@ impl SurfaceMesh {
         vectors: Vec<Vec2>,
         basis_x: Vec<Vec3>,
         basis_y: Vec<Vec3>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        let expected = self.num_faces();
+        check_len(expected, vectors.len())?;
+        check_len(expected, basis_x.len())?;
+        check_len(expected, basis_y.len())?;
         let mut quantity = MeshFaceIntrinsicVectorQuantity::new(
             name,
             self.name.clone(),
@@ -189,7 +278,7 @@ impl SurfaceMesh {
         );
         quantity.auto_scale(self.length_scale());
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     /// Adds a face intrinsic vector quantity with auto-computed tangent basis.
@@ -197,7 +286,7 @@ impl SurfaceMesh {
         &mut self,
         name: impl Into<String>,
         vectors: Vec<Vec2>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
         let (bx, by) = self.compute_face_tangent_basis();
         self.add_face_intrinsic_vector_quantity(name, vectors, bx, by)
     }
@@ -213,11 +302,13 @@ impl SurfaceMesh {
         name: impl Into<String>,
         values: Vec<f32>,
         orientations: Vec<bool>,
-    ) -> &mut Self {
+    ) -> Result<&mut Self> {
+        check_len(self.num_edges(), values.len())?;
+        check_len(self.num_edges(), orientations.len())?;
         let mut quantity = MeshOneFormQuantity::new(name, self.name.clone(), values, orientations);
         quantity.auto_scale(self.length_scale(), &self.vertices, &self.edges);
         self.add_quantity(Box::new(quantity));
-        self
+        Ok(self)
     }
 
     // === Active quantity accessors ===
@@ -235,6 +326,25 @@ impl SurfaceMesh {
         None
     }
 
+    /// Returns the active vertex scalar quantity if it colors the mesh and has
+    /// isolines enabled.
+    ///
+    /// Parameterization and color quantities take precedence over scalars, so
+    /// no isolines are drawn while one of them is enabled.
+    #[must_use]
+    pub fn active_isolines(&self) -> Option<&MeshVertexScalarQuantity> {
+        if self.active_vertex_parameterization_quantity().is_some()
+            || self.active_corner_parameterization_quantity().is_some()
+            || self.active_vertex_color_quantity().is_some()
+            || self.active_corner_color_quantity().is_some()
+            || self.active_face_color_quantity().is_some()
+        {
+            return None;
+        }
+        self.active_vertex_scalar_quantity()
+            .filter(|q| q.isolines_enabled())
+    }
+
     /// Returns the currently active face scalar quantity, if any.
     #[must_use]
     pub fn active_face_scalar_quantity(&self) -> Option<&MeshFaceScalarQuantity> {
@@ -248,6 +358,19 @@ impl SurfaceMesh {
         None
     }
 
+    /// Returns the currently active edge scalar quantity, if any.
+    #[must_use]
+    pub fn active_edge_scalar_quantity(&self) -> Option<&MeshEdgeScalarQuantity> {
+        for q in &self.quantities {
+            if q.is_enabled() && q.kind() == QuantityKind::Scalar {
+                if let Some(sq) = q.as_any().downcast_ref::<MeshEdgeScalarQuantity>() {
+                    return Some(sq);
+                }
+            }
+        }
+        None
+    }
+
     /// Returns the currently active vertex color quantity, if any.
     #[must_use]
     pub fn active_vertex_color_quantity(&self) -> Option<&MeshVertexColorQuantity> {
@@ -261,6 +384,19 @@ impl SurfaceMesh {
         None
     }
 
+    /// Returns the currently active corner color quantity, if any.
+    #[must_use]
+    pub fn active_corner_color_quantity(&self) -> Option<&MeshCornerColorQuantity> {
+        for q in &self.quantities {
+            if q.is_enabled() && q.kind() == QuantityKind::Color {
+                if let Some(cq) = q.as_any().downcast_ref::<MeshCornerColorQuantity>() {
+                    return Some(cq);
+                }
+            }
+        }
+        None
+    }
+
     /// Returns the currently active face color quantity, if any.
     #[must_use]
     pub fn active_face_color_quantity(&self) -> Option<&MeshFaceColorQuantity> {
@@ -466,4 +602,37 @@ impl SurfaceMesh {
         }
         None
     }
+
+    /// Recomputes face area and edge length quantities from the current
+    /// geometry.
+    pub(super) fn refresh_derived_quantities(&mut self) {
+        // Taken out so the geometry can be read while updating them
+        let mut quantities = std::mem::take(&mut self.quantities);
+        let mut areas = None;
+        let mut lengths = None;
+        for q in &mut quantities {
+            let q = q.as_any_mut();
+            if let Some(sq) = q.downcast_mut::<MeshFaceScalarQuantity>() {
+                if sq.is_derived() {
+                    let areas = areas.get_or_insert_with(|| self.compute_face_areas());
+                    sq.set_values(areas.clone());
+                }
+            } else if let Some(sq) = q.downcast_mut::<MeshEdgeScalarQuantity>() {
+                if sq.is_derived() {
+                    let lengths = lengths.get_or_insert_with(|| self.compute_edge_lengths());
+                    sq.set_values(lengths.clone());
+                }
+            }
+        }
+        self.quantities = quantities;
+    }
+}
+
+/// Returns [`PolyscopeError::SizeMismatch`] unless `actual == expected`.
+fn check_len(expected: usize, actual: usize) -> Result<()> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(PolyscopeError::SizeMismatch { expected, actual })
+    }
 }
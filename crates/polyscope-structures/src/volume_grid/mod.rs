@@ -27,6 +27,8 @@ pub struct VolumeGrid {
 
     // Common structure fields
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
 
@@ -35,6 +37,8 @@ pub struct VolumeGrid {
     edge_color: Vec4,
     edge_width: f32,
     cube_size_factor: f32,
+    cube_visualization: bool,
+    cube_transparency: f32,
 
     // GPU resources (bounding box wireframe)
     render_data: Option<CurveNetworkRenderData>,
@@ -55,12 +59,16 @@ impl VolumeGrid {
             bound_min,
             bound_max,
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
             color: Vec4::new(0.5, 0.5, 0.5, 1.0),
             edge_color: Vec4::new(0.0, 0.0, 0.0, 1.0),
             edge_width: 1.0,
             cube_size_factor: 0.0,
+            cube_visualization: false,
+            cube_transparency: 0.5,
             render_data: None,
         }
     }
@@ -192,6 +200,33 @@ impl VolumeGrid {
         self
     }
 
+    /// Returns whether cell scalars are shown as a semi-transparent voxel block.
+    #[must_use]
+    pub fn cube_visualization(&self) -> bool {
+        self.cube_visualization
+    }
+
+    /// Shows the enabled cell scalar quantity as a semi-transparent voxel
+    /// block: one cube per cell, colored through the quantity's color map and
+    /// blended with [`Self::cube_transparency`] so interior cells show
+    /// through. When off, cell scalar cubes are opaque.
+    pub fn set_cube_visualization(&mut self, enabled: bool) -> &mut Self {
+        self.cube_visualization = enabled;
+        self
+    }
+
+    /// Gets the cube transparency used by the cube visualization.
+    #[must_use]
+    pub fn cube_transparency(&self) -> f32 {
+        self.cube_transparency
+    }
+
+    /// Sets the cube transparency (0 = opaque, 1 = invisible).
+    pub fn set_cube_transparency(&mut self, transparency: f32) -> &mut Self {
+        self.cube_transparency = transparency.clamp(0.0, 1.0);
+        self
+    }
+
     /// Returns a mutable reference to the quantities list.
     pub fn quantities_mut(&mut self) -> &mut [Box<dyn Quantity>] {
         &mut self.quantities
@@ -264,7 +299,7 @@ impl VolumeGrid {
             radius: self.edge_width * 0.002,
             radius_is_relative: 1,
             render_mode: 0,
-            _padding: 0.0,
+            transparency: 0.0,
         };
         render_data.update_uniforms(queue, &uniforms);
 
@@ -285,7 +320,7 @@ impl VolumeGrid {
                 radius: self.edge_width * 0.002,
                 radius_is_relative: 1,
                 render_mode: 0,
-                _padding: 0.0,
+                transparency: 0.0,
             };
             render_data.update_uniforms(queue, &uniforms);
         }
@@ -309,6 +344,48 @@ impl VolumeGrid {
         self
     }
 
+    /// Extracts an isosurface from a node scalar quantity.
+    ///
+    /// Runs marching cubes over the named quantity and returns vertices (in
+    /// the grid's local space) and triangles. Returns `None` if there is no
+    /// node scalar quantity with that name, and an empty mesh if `iso` lies
+    /// outside the data range.
+    #[must_use]
+    pub fn extract_isosurface(
+        &self,
+        quantity_name: &str,
+        iso: f32,
+    ) -> Option<(Vec<Vec3>, Vec<UVec3>)> {
+        let quantity = self
+            .get_quantity(quantity_name)?
+            .as_any()
+            .downcast_ref::<VolumeGridNodeScalarQuantity>()?;
+
+        let (min, max) = quantity
+            .values()
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        if !(min..=max).contains(&iso) {
+            return Some((Vec::new(), Vec::new()));
+        }
+
+        let mesh = grid_isosurface(
+            quantity.values(),
+            self.node_dim,
+            self.bound_min,
+            self.bound_max,
+            iso,
+        );
+        let triangles = mesh
+            .indices
+            .chunks_exact(3)
+            .map(|t| UVec3::new(t[0], t[1], t[2]))
+            .collect();
+        Some((mesh.vertices, triangles))
+    }
+
     /// Adds a cell scalar quantity to the grid.
     pub fn add_cell_scalar_quantity(
         &mut self,
@@ -357,6 +434,18 @@ impl VolumeGrid {
             }
         });
 
+        // Cube visualization
+        ui.checkbox(&mut self.cube_visualization, "Cube visualization");
+        if self.cube_visualization {
+            egui::Grid::new(format!("{}_cube_grid", self.name))
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Transparency:");
+                    ui.add(egui::Slider::new(&mut self.cube_transparency, 0.0..=1.0));
+                    ui.end_row();
+                });
+        }
+
         // Show quantities
         if !self.quantities.is_empty() {
             ui.separator();
@@ -419,6 +508,22 @@ impl Structure for VolumeGrid {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn draw(&self, _ctx: &mut dyn RenderContext) {
         // Drawing is handled externally
     }
@@ -442,6 +547,17 @@ impl Structure for VolumeGrid {
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, CurveNetworkRenderData::buffer_bytes)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         self.render_data = None;
         for quantity in &mut self.quantities {
@@ -498,6 +614,45 @@ mod tests {
         assert_eq!(uvec, UVec3::new(2, 3, 4));
     }
 
+    #[test]
+    fn test_extract_isosurface() {
+        // Non-cubic grid with a field equal to the x coordinate: the
+        // isosurface at 1.5 is the plane x = 1.5
+        let dim = UVec3::new(4, 3, 2);
+        let mut grid = VolumeGrid::new("test", dim, Vec3::ZERO, Vec3::new(3.0, 2.0, 1.0));
+        let mut values = Vec::new();
+        for _k in 0..dim.z {
+            for _j in 0..dim.y {
+                for i in 0..dim.x {
+                    values.push(i as f32);
+                }
+            }
+        }
+        grid.add_node_scalar_quantity("x", values);
+
+        let (vertices, triangles) = grid.extract_isosurface("x", 1.5).unwrap();
+        assert!(!triangles.is_empty());
+        for v in &vertices {
+            assert!((v.x - 1.5).abs() < 1e-5, "vertex {v:?} not on x = 1.5");
+        }
+        for t in &triangles {
+            assert!(t.max_element() < vertices.len() as u32);
+        }
+
+        let (vertices, triangles) = grid.extract_isosurface("x", 10.0).unwrap();
+        assert!(vertices.is_empty() && triangles.is_empty());
+        assert!(grid.extract_isosurface("missing", 1.5).is_none());
+    }
+
+    #[test]
+    fn test_cube_visualization_settings() {
+        let mut grid = VolumeGrid::new("test", UVec3::splat(3), Vec3::ZERO, Vec3::ONE);
+        assert!(!grid.cube_visualization());
+        grid.set_cube_visualization(true).set_cube_transparency(1.5);
+        assert!(grid.cube_visualization());
+        assert!((grid.cube_transparency() - 1.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_node_position() {
         let grid = VolumeGrid::new(
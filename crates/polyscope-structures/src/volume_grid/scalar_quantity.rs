@@ -1,11 +1,14 @@
 //! Scalar quantities for volume grids.
 
-use glam::{UVec3, Vec3};
+use glam::{UVec3, Vec3, Vec3Swizzles};
+use polyscope_core::error::Result;
 use polyscope_core::quantity::{Quantity, QuantityKind};
 use polyscope_core::{McmMesh, marching_cubes};
 use polyscope_render::{GridcubePickUniforms, GridcubeRenderData, IsosurfaceRenderData};
 use wgpu::util::DeviceExt;
 
+use crate::quantity_update::replace_data;
+
 /// Visualization mode for volume grid scalar quantities.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VolumeGridVizMode {
@@ -15,6 +18,35 @@ pub enum VolumeGridVizMode {
     Isosurface,
 }
 
+/// Extracts an isosurface from node values in the grid's layout.
+///
+/// Node values are stored x-fastest (`i + j * nx + k * nx * ny`), which is
+/// the marching cubes layout with the axes reversed. The returned vertices
+/// and normals are in the grid's local (untransformed) space.
+pub(crate) fn grid_isosurface(
+    values: &[f32],
+    node_dim: UVec3,
+    bound_min: Vec3,
+    bound_max: Vec3,
+    isoval: f32,
+) -> McmMesh {
+    let mut mesh = marching_cubes(values, isoval, node_dim.z, node_dim.y, node_dim.x);
+
+    for v in &mut mesh.vertices {
+        *v = v.zyx();
+    }
+    for n in &mut mesh.normals {
+        *n = n.zyx();
+    }
+    // Swapping axes mirrors the mesh; flip triangles to keep the winding
+    for tri in mesh.indices.chunks_exact_mut(3) {
+        tri.swap(1, 2);
+    }
+
+    mesh.map_to_bounds(bound_min, bound_max, node_dim);
+    mesh
+}
+
 /// A scalar quantity defined at grid nodes.
 pub struct VolumeGridNodeScalarQuantity {
     name: String,
@@ -27,6 +59,7 @@ pub struct VolumeGridNodeScalarQuantity {
     color_map: String,
     data_min: f32,
     data_max: f32,
+    range_user_set: bool, // false = range follows the data
 
     // Visualization mode
     viz_mode: VolumeGridVizMode,
@@ -76,6 +109,7 @@ impl VolumeGridNodeScalarQuantity {
             color_map: "viridis".to_string(),
             data_min,
             data_max,
+            range_user_set: false,
             viz_mode: VolumeGridVizMode::Gridcube,
             gridcube_render_data: None,
             gridcube_dirty: true,
@@ -111,6 +145,25 @@ impl VolumeGridNodeScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The data range is
+    /// refit to the new values unless it was set explicitly (in code or in
+    /// the UI). The colors shown are recomputed from the new values on the
+    /// next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        }
+        self.gridcube_dirty = true;
+        self.isosurface_dirty = true;
+        Ok(())
+    }
+
     /// Returns the grid node dimensions.
     #[must_use]
     pub fn node_dim(&self) -> UVec3 {
@@ -149,6 +202,15 @@ impl VolumeGridNodeScalarQuantity {
     pub fn set_data_range(&mut self, min: f32, max: f32) -> &mut Self {
         self.data_min = min;
         self.data_max = max;
+        self.range_user_set = true;
+        self
+    }
+
+    /// Resets the data range to the min/max of the values, which it then
+    /// follows on value updates.
+    pub fn reset_data_range(&mut self) -> &mut Self {
+        (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        self.range_user_set = false;
         self
     }
 
@@ -208,46 +270,16 @@ impl VolumeGridNodeScalarQuantity {
 
     /// Extracts the isosurface mesh using marching cubes.
     ///
-    /// MC output vertices are in grid index space: vertex (i,j,k) has coords
-    /// that need swizzle(z,y,x) * `grid_spacing` + `bound_min` to transform to world space.
+    /// The result is cached until the isosurface level changes.
     pub fn extract_isosurface(&mut self) -> &McmMesh {
         if self.isosurface_mesh_cache.is_none() || self.isosurface_dirty {
-            let nx = self.node_dim.x;
-            let ny = self.node_dim.y;
-            let nz = self.node_dim.z;
-
-            let mut mesh = marching_cubes(&self.values, self.isosurface_level, nx, ny, nz);
-
-            // Transform from MC index space to world space
-            // MC uses indexing (i * ny + j) * nz + k, output coords are in (i,j,k) space
-            // Need to map: x_world = x_mc * spacing_z + bound_min.z (swizzle z,y,x)
-            let cell_dim = Vec3::new(
-                (nx - 1).max(1) as f32,
-                (ny - 1).max(1) as f32,
-                (nz - 1).max(1) as f32,
+            let mesh = grid_isosurface(
+                &self.values,
+                self.node_dim,
+                self.bound_min,
+                self.bound_max,
+                self.isosurface_level,
             );
-            let spacing = (self.bound_max - self.bound_min) / cell_dim;
-
-            for v in &mut mesh.vertices {
-                // MC output: v.x is in i-dimension, v.y in j-dimension, v.z in k-dimension
-                // Grid layout: i maps to x, j maps to y, k maps to z (no swizzle needed
-                // since our MC uses same indexing as the grid)
-                *v = Vec3::new(
-                    v.x * spacing.x + self.bound_min.x,
-                    v.y * spacing.y + self.bound_min.y,
-                    v.z * spacing.z + self.bound_min.z,
-                );
-            }
-
-            // Transform normals (only need to scale, then renormalize)
-            for n in &mut mesh.normals {
-                // Scale normals by inverse spacing to account for non-uniform grid
-                *n = Vec3::new(n.x / spacing.x, n.y / spacing.y, n.z / spacing.z);
-                let len = n.length();
-                if len > 0.0 {
-                    *n /= len;
-                }
-            }
 
             self.isosurface_mesh_cache = Some(mesh);
             self.isosurface_dirty = false;
@@ -486,6 +518,7 @@ impl VolumeGridNodeScalarQuantity {
             {
                 self.data_min = min;
                 self.data_max = max;
+                self.range_user_set = true;
             }
         });
     }
@@ -596,6 +629,20 @@ impl Quantity for VolumeGridNodeScalarQuantity {
         self.isosurface_render_data = None;
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.gridcube_render_data
+            .as_ref()
+            .map_or(0, GridcubeRenderData::buffer_bytes)
+            + self
+                .isosurface_render_data
+                .as_ref()
+                .map_or(0, IsosurfaceRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -617,6 +664,7 @@ pub struct VolumeGridCellScalarQuantity {
     color_map: String,
     data_min: f32,
     data_max: f32,
+    range_user_set: bool, // false = range follows the data
 
     // Gridcube state (cell scalars only support gridcube, not isosurface)
     gridcube_render_data: Option<GridcubeRenderData>,
@@ -654,6 +702,7 @@ impl VolumeGridCellScalarQuantity {
             color_map: "viridis".to_string(),
             data_min,
             data_max,
+            range_user_set: false,
             gridcube_render_data: None,
             gridcube_dirty: true,
             bound_min,
@@ -682,6 +731,24 @@ impl VolumeGridCellScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The data range is
+    /// refit to the new values unless it was set explicitly (in code or in
+    /// the UI). The colors shown are recomputed from the new values on the
+    /// next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        }
+        self.gridcube_dirty = true;
+        Ok(())
+    }
+
     /// Returns the grid cell dimensions.
     #[must_use]
     pub fn cell_dim(&self) -> UVec3 {
@@ -720,6 +787,15 @@ impl VolumeGridCellScalarQuantity {
     pub fn set_data_range(&mut self, min: f32, max: f32) -> &mut Self {
         self.data_min = min;
         self.data_max = max;
+        self.range_user_set = true;
+        self
+    }
+
+    /// Resets the data range to the min/max of the values, which it then
+    /// follows on value updates.
+    pub fn reset_data_range(&mut self) -> &mut Self {
+        (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        self.range_user_set = false;
         self
     }
 
@@ -883,6 +959,7 @@ impl VolumeGridCellScalarQuantity {
                     {
                         self.data_min = min;
                         self.data_max = max;
+                        self.range_user_set = true;
                     }
                 });
             });
@@ -930,6 +1007,16 @@ impl Quantity for VolumeGridCellScalarQuantity {
         self.gridcube_render_data = None;
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.gridcube_render_data
+            .as_ref()
+            .map_or(0, GridcubeRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,14 +1,19 @@
 //! Color quantities for volume meshes.
 
 use glam::{Vec3, Vec4};
+use polyscope_core::color_space::ColorSpace;
+use polyscope_core::error::Result;
 use polyscope_core::quantity::{CellQuantity, Quantity, QuantityKind, VertexQuantity};
 
+use crate::quantity_update::replace_data;
+
 /// A color quantity defined at mesh vertices.
 pub struct VolumeMeshVertexColorQuantity {
     name: String,
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
 }
 
 impl VolumeMeshVertexColorQuantity {
@@ -22,6 +27,7 @@ impl VolumeMeshVertexColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
         }
     }
 
@@ -30,6 +36,34 @@ impl VolumeMeshVertexColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             let mut enabled = self.enabled;
@@ -79,6 +113,7 @@ pub struct VolumeMeshCellColorQuantity {
     structure_name: String,
     colors: Vec<Vec4>,
     enabled: bool,
+    color_space: ColorSpace,
 }
 
 impl VolumeMeshCellColorQuantity {
@@ -92,6 +127,7 @@ impl VolumeMeshCellColorQuantity {
             structure_name: structure_name.into(),
             colors: colors.into_iter().map(|c| c.extend(1.0)).collect(),
             enabled: false,
+            color_space: ColorSpace::default(),
         }
     }
 
@@ -100,6 +136,34 @@ impl VolumeMeshCellColorQuantity {
         &self.colors
     }
 
+    /// Replaces the colors in place; they are uploaded on the next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one color per
+    /// existing color.
+    pub fn update_colors(&mut self, colors: Vec<Vec4>) -> Result<()> {
+        replace_data(&mut self.colors, colors)
+    }
+
+    /// Returns how the colors are encoded (sRGB unless changed).
+    #[must_use]
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
+
+    /// Sets how the colors are encoded; sRGB colors are converted to linear
+    /// on upload.
+    pub fn set_color_space(&mut self, color_space: ColorSpace) {
+        self.color_space = color_space;
+    }
+
+    /// Returns the colors converted to linear, as uploaded to the GPU.
+    #[must_use]
+    pub fn linear_colors(&self) -> Vec<Vec4> {
+        self.color_space.colors_to_linear(&self.colors)
+    }
+
     pub fn build_egui_ui(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             let mut enabled = self.enabled;
@@ -8,8 +8,11 @@
 //!
 //! # Interior/Exterior Faces
 //!
-//! Only exterior faces (not shared between cells) are rendered. This is determined
-//! by hashing sorted face vertex indices and counting occurrences.
+//! By default only exterior faces (not shared between cells) are rendered. This is
+//! determined by hashing sorted face vertex indices and counting occurrences, once
+//! when the mesh is created. Slice planes hide whole cells, which exposes the faces
+//! of the cells behind them. [`VolumeMesh::set_show_interior`] renders the faces of
+//! every cell instead.
 //!
 //! # Quantities
 //!
@@ -58,7 +61,8 @@ use polyscope_core::pick::PickResult;
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::{HasQuantities, RenderContext, Structure};
 use polyscope_render::{
-    MeshPickUniforms, MeshUniforms, SliceMeshRenderData, SurfaceMeshRenderData,
+    ColorMap, ColorMapRegistry, MeshPickUniforms, MeshUniforms, SliceMeshRenderData,
+    SurfaceMeshRenderData,
 };
 
 /// Cell type for volume meshes.
@@ -80,9 +84,13 @@ pub struct VolumeMesh {
     // Geometry
     vertices: Vec<Vec3>,
     cells: Vec<[u32; 8]>, // 8 indices per cell, unused slots hold u32::MAX
+    /// Number of cells sharing each face, keyed by canonical face key
+    face_counts: HashMap<[u32; 4], usize>,
 
     // Common structure fields
     enabled: bool,
+    ignore_slice_planes: bool,
+    draw_order: i32,
     transform: Mat4,
     quantities: Vec<Box<dyn Quantity>>,
 
@@ -91,6 +99,7 @@ pub struct VolumeMesh {
     interior_color: Vec4,
     edge_color: Vec4,
     edge_width: f32,
+    show_interior: bool,
 
     // GPU resources (renders exterior faces)
     render_data: Option<SurfaceMeshRenderData>,
@@ -103,8 +112,9 @@ pub struct VolumeMesh {
 
     // Slice mesh GPU resources (renders cross-section caps)
     slice_render_data: Option<SliceMeshRenderData>,
-    /// Cached slice plane parameters for invalidation (origin, normal)
-    slice_plane_cache: Option<(Vec3, Vec3)>,
+    /// Cached slice parameters for invalidation (local origin, local normal,
+    /// name of the quantity coloring the cap)
+    slice_plane_cache: Option<(Vec3, Vec3, Option<String>)>,
     /// Cached cell culling plane parameters (origin, normal) for each enabled plane.
     /// When Some, indicates `render_data` shows culled geometry.
     culling_plane_cache: Option<Vec<(Vec3, Vec3)>>,
@@ -121,18 +131,23 @@ impl VolumeMesh {
         let color = Vec4::new(0.25, 0.50, 0.75, 1.0);
         // Interior color is a desaturated version
         let interior_color = Vec4::new(0.45, 0.50, 0.55, 1.0);
+        let face_counts = compute_face_counts(&cells);
 
         Self {
             name: name.into(),
             vertices,
             cells,
+            face_counts,
             enabled: true,
+            ignore_slice_planes: false,
+            draw_order: 0,
             transform: Mat4::IDENTITY,
             quantities: Vec::new(),
             color,
             interior_color,
             edge_color: Vec4::new(0.0, 0.0, 0.0, 1.0),
             edge_width: 0.0,
+            show_interior: false,
             render_data: None,
             pick_uniform_buffer: None,
             pick_bind_group: None,
@@ -289,32 +304,48 @@ impl VolumeMesh {
         self.decompose_to_tets().len()
     }
 
-    /// Computes face counts for interior/exterior detection.
-    fn compute_face_counts(&self) -> HashMap<[u32; 4], usize> {
-        let mut face_counts: HashMap<[u32; 4], usize> = HashMap::new();
+    /// Returns the number of distinct faces, counting shared faces once.
+    #[must_use]
+    pub fn num_faces(&self) -> usize {
+        self.face_counts.len()
+    }
 
-        for cell in &self.cells {
-            if cell[4] == u32::MAX {
-                // Tetrahedron
-                for [a, b, c] in TET_FACE_STENCIL {
-                    let key = canonical_face_key(cell[a], cell[b], cell[c], None);
-                    *face_counts.entry(key).or_insert(0) += 1;
-                }
-            } else {
-                // Hexahedron - each quad face uses same 4 vertices
-                for quad in HEX_FACE_STENCIL {
-                    // Get the 4 unique vertices of this quad face
-                    let v0 = cell[quad[0][0]];
-                    let v1 = cell[quad[0][1]];
-                    let v2 = cell[quad[0][2]];
-                    let v3 = cell[quad[1][2]]; // The fourth vertex
-                    let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    *face_counts.entry(key).or_insert(0) += 1;
-                }
-            }
+    /// Returns the number of boundary faces, i.e. faces of exactly one cell.
+    #[must_use]
+    pub fn num_boundary_faces(&self) -> usize {
+        self.face_counts
+            .values()
+            .filter(|&&count| count == 1)
+            .count()
+    }
+
+    /// Returns whether the faces of all cells are rendered, not just the boundary.
+    #[must_use]
+    pub fn show_interior(&self) -> bool {
+        self.show_interior
+    }
+
+    /// Sets whether the faces of all cells are rendered (default: false).
+    ///
+    /// By default only boundary faces are rendered, which is much cheaper for
+    /// large meshes. Rendering interior faces is mostly useful with
+    /// transparency.
+    pub fn set_show_interior(&mut self, show: bool) -> &mut Self {
+        if self.show_interior != show {
+            self.show_interior = show;
+            // Render and pick geometry are rebuilt on the next frame
+            self.render_data = None;
+            self.pick_uniform_buffer = None;
+            self.pick_bind_group = None;
+            self.pick_cell_index_buffer = None;
+            self.culling_plane_cache = None;
         }
+        self
+    }
 
-        face_counts
+    /// Returns whether a face with the given cell counts is rendered.
+    fn is_face_shown(&self, face_counts: &HashMap<[u32; 4], usize>, key: &[u32; 4]) -> bool {
+        self.show_interior || face_counts.get(key) == Some(&1)
     }
 
     /// Computes the centroid of a cell.
@@ -390,7 +421,7 @@ impl VolumeMesh {
 
     /// Generates triangulated exterior faces for rendering.
     fn generate_render_geometry(&self) -> (Vec<Vec3>, Vec<[u32; 3]>) {
-        let face_counts = self.compute_face_counts();
+        let face_counts = &self.face_counts;
         let mut positions = Vec::new();
         let mut faces = Vec::new();
 
@@ -399,7 +430,7 @@ impl VolumeMesh {
                 // Tetrahedron
                 for [a, b, c] in TET_FACE_STENCIL {
                     let key = canonical_face_key(cell[a], cell[b], cell[c], None);
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         // Exterior face
                         let base_idx = positions.len() as u32;
                         positions.push(self.vertices[cell[a] as usize]);
@@ -416,7 +447,7 @@ impl VolumeMesh {
                     let v2 = cell[quad[0][2]];
                     let v3 = cell[quad[1][2]];
                     let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         // Exterior face - emit both triangles
                         for [a, b, c] in quad {
                             let base_idx = positions.len() as u32;
@@ -455,7 +486,7 @@ impl VolumeMesh {
                 for [a, b, c] in TET_FACE_STENCIL {
                     let key = canonical_face_key(cell[a], cell[b], cell[c], None);
                     // Render face if it's exterior among visible cells
-                    if face_counts.get(&key) == Some(&1) {
+                    if self.is_face_shown(&face_counts, &key) {
                         let base_idx = positions.len() as u32;
                         positions.push(self.vertices[cell[a] as usize]);
                         positions.push(self.vertices[cell[b] as usize]);
@@ -471,7 +502,7 @@ impl VolumeMesh {
                     let v2 = cell[quad[0][2]];
                     let v3 = cell[quad[1][2]];
                     let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    if face_counts.get(&key) == Some(&1) {
+                    if self.is_face_shown(&face_counts, &key) {
                         for [a, b, c] in quad {
                             let base_idx = positions.len() as u32;
                             positions.push(self.vertices[cell[a] as usize]);
@@ -490,7 +521,7 @@ impl VolumeMesh {
     /// Generates render geometry including any enabled quantity data.
     #[must_use]
     pub fn generate_render_geometry_with_quantities(&self) -> VolumeMeshRenderGeometry {
-        let face_counts = self.compute_face_counts();
+        let face_counts = &self.face_counts;
         let mut positions = Vec::new();
         let mut faces = Vec::new();
         let mut vertex_indices = Vec::new(); // Track original vertex indices
@@ -502,7 +533,7 @@ impl VolumeMesh {
                 // Tetrahedron
                 for [a, b, c] in TET_FACE_STENCIL {
                     let key = canonical_face_key(cell[a], cell[b], cell[c], None);
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         let base_idx = positions.len() as u32;
                         positions.push(self.vertices[cell[a] as usize]);
                         positions.push(self.vertices[cell[b] as usize]);
@@ -524,7 +555,7 @@ impl VolumeMesh {
                     let v2 = cell[quad[0][2]];
                     let v3 = cell[quad[1][2]];
                     let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         for [a, b, c] in quad {
                             let base_idx = positions.len() as u32;
                             positions.push(self.vertices[cell[a] as usize]);
@@ -570,16 +601,10 @@ impl VolumeMesh {
                     break;
                 }
                 if let Some(color) = q.as_any().downcast_ref::<VolumeMeshVertexColorQuantity>() {
+                    let linear = color.linear_colors();
                     let colors: Vec<Vec3> = vertex_indices
                         .iter()
-                        .map(|&idx| {
-                            color
-                                .colors()
-                                .get(idx)
-                                .copied()
-                                .unwrap_or(Vec4::new(1.0, 1.0, 1.0, 1.0))
-                                .truncate()
-                        })
+                        .map(|&idx| linear.get(idx).map_or(Vec3::ONE, |c| c.truncate()))
                         .collect();
                     vertex_colors = Some(colors);
                     break;
@@ -593,16 +618,10 @@ impl VolumeMesh {
                     break;
                 }
                 if let Some(color) = q.as_any().downcast_ref::<VolumeMeshCellColorQuantity>() {
+                    let linear = color.linear_colors();
                     let colors: Vec<Vec3> = cell_indices
                         .iter()
-                        .map(|&idx| {
-                            color
-                                .colors()
-                                .get(idx)
-                                .copied()
-                                .unwrap_or(Vec4::new(1.0, 1.0, 1.0, 1.0))
-                                .truncate()
-                        })
+                        .map(|&idx| linear.get(idx).map_or(Vec3::ONE, |c| c.truncate()))
                         .collect();
                     vertex_colors = Some(colors);
                     break;
@@ -761,7 +780,7 @@ impl VolumeMesh {
     /// Returns one `u32` per triangle indicating which cell that triangle belongs to.
     /// Matches the triangle ordering from `generate_render_geometry()`.
     fn generate_cell_index_per_triangle(&self) -> Vec<u32> {
-        let face_counts = self.compute_face_counts();
+        let face_counts = &self.face_counts;
         let mut cell_indices = Vec::new();
 
         for (cell_idx, cell) in self.cells.iter().enumerate() {
@@ -769,7 +788,7 @@ impl VolumeMesh {
                 // Tetrahedron
                 for [a, b, c] in TET_FACE_STENCIL {
                     let key = canonical_face_key(cell[a], cell[b], cell[c], None);
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         cell_indices.push(cell_idx as u32);
                     }
                 }
@@ -781,7 +800,7 @@ impl VolumeMesh {
                     let v2 = cell[quad[0][2]];
                     let v3 = cell[quad[1][2]];
                     let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    if face_counts[&key] == 1 {
+                    if self.is_face_shown(face_counts, &key) {
                         // 2 triangles per quad face
                         cell_indices.push(cell_idx as u32);
                         cell_indices.push(cell_idx as u32);
@@ -806,7 +825,7 @@ impl VolumeMesh {
             if cell[4] == u32::MAX {
                 for [a, b, c] in TET_FACE_STENCIL {
                     let key = canonical_face_key(cell[a], cell[b], cell[c], None);
-                    if face_counts.get(&key) == Some(&1) {
+                    if self.is_face_shown(&face_counts, &key) {
                         cell_indices.push(cell_idx as u32);
                     }
                 }
@@ -817,7 +836,7 @@ impl VolumeMesh {
                     let v2 = cell[quad[0][2]];
                     let v3 = cell[quad[1][2]];
                     let key = canonical_face_key(v0, v1, v2, Some(v3));
-                    if face_counts.get(&key) == Some(&1) {
+                    if self.is_face_shown(&face_counts, &key) {
                         cell_indices.push(cell_idx as u32);
                         cell_indices.push(cell_idx as u32);
                     }
@@ -948,24 +967,45 @@ impl VolumeMesh {
             };
             rd.update_uniforms(queue, &uniforms);
         }
+        if let Some(ref rd) = self.slice_render_data {
+            rd.update_uniforms(queue, self.interior_color.truncate(), self.transform);
+        }
     }
 
     /// Updates or creates slice mesh render data for a given slice plane.
     ///
+    /// The plane is given in world space. The cap is colored by the first
+    /// enabled scalar or color quantity (see [`Self::generate_slice_geometry`]).
+    ///
     /// Returns `true` if the slice intersects this volume mesh.
+    #[allow(clippy::too_many_arguments)]
     pub fn update_slice_render_data(
         &mut self,
         device: &wgpu::Device,
         queue: &wgpu::Queue,
         bind_group_layout: &wgpu::BindGroupLayout,
         camera_buffer: &wgpu::Buffer,
+        color_maps: &ColorMapRegistry,
         plane_origin: Vec3,
         plane_normal: Vec3,
     ) -> bool {
+        // Slice in the mesh's local space; the cap is drawn with the model matrix
+        let inverse = self.transform.inverse();
+        let local_origin = inverse.transform_point3(plane_origin);
+        let local_normal = self
+            .transform
+            .transpose()
+            .transform_vector3(plane_normal)
+            .normalize_or_zero();
+        let color_key = self
+            .active_slice_color_quantity()
+            .map(|q| q.name().to_string());
+
         // Check if cache is still valid
-        let cache_valid = self.slice_plane_cache.is_some_and(|(o, n)| {
-            (o - plane_origin).length_squared() < 1e-10
-                && (n - plane_normal).length_squared() < 1e-10
+        let cache_valid = self.slice_plane_cache.as_ref().is_some_and(|(o, n, key)| {
+            (*o - local_origin).length_squared() < 1e-10
+                && (*n - local_normal).length_squared() < 1e-10
+                && *key == color_key
         });
 
         if cache_valid {
@@ -975,7 +1015,9 @@ impl VolumeMesh {
         }
 
         // Generate new slice geometry
-        if let Some(slice_data) = self.generate_slice_geometry(plane_origin, plane_normal) {
+        if let Some(slice_data) =
+            self.generate_slice_geometry(local_origin, local_normal, color_maps)
+        {
             if let Some(ref mut rd) = self.slice_render_data {
                 // Update existing render data
                 rd.update(
@@ -1001,10 +1043,10 @@ impl VolumeMesh {
 
             // Update uniforms with interior color
             if let Some(ref rd) = self.slice_render_data {
-                rd.update_uniforms(queue, self.interior_color.truncate());
+                rd.update_uniforms(queue, self.interior_color.truncate(), self.transform);
             }
 
-            self.slice_plane_cache = Some((plane_origin, plane_normal));
+            self.slice_plane_cache = Some((local_origin, local_normal, color_key));
             true
         } else {
             // No intersection
@@ -1048,6 +1090,11 @@ impl VolumeMesh {
             }
         });
 
+        let mut show_interior = self.show_interior;
+        if ui.checkbox(&mut show_interior, "Show interior").changed() {
+            self.set_show_interior(show_interior);
+        }
+
         // Edge width
         ui.horizontal(|ui| {
             let mut show_edges = self.edge_width > 0.0;
@@ -1143,27 +1190,34 @@ impl VolumeMesh {
         self
     }
 
-    /// Returns the active vertex color quantity, if any.
-    fn active_vertex_color_quantity(&self) -> Option<&VolumeMeshVertexColorQuantity> {
-        for q in &self.quantities {
-            if q.is_enabled() {
-                if let Some(vcq) = q.as_any().downcast_ref::<VolumeMeshVertexColorQuantity>() {
-                    return Some(vcq);
-                }
-            }
-        }
-        None
+    /// Returns the first enabled quantity that can color a slice cap
+    /// (vertex/cell scalar or vertex/cell color), if any.
+    fn active_slice_color_quantity(&self) -> Option<&dyn Quantity> {
+        self.quantities
+            .iter()
+            .filter(|q| q.is_enabled())
+            .map(AsRef::as_ref)
+            .find(|q| {
+                let any = q.as_any();
+                any.is::<VolumeMeshVertexScalarQuantity>()
+                    || any.is::<VolumeMeshCellScalarQuantity>()
+                    || any.is::<VolumeMeshVertexColorQuantity>()
+                    || any.is::<VolumeMeshCellColorQuantity>()
+            })
     }
 
     /// Generates mesh geometry for the cross-section created by a slice plane.
     ///
     /// This computes the intersection of all cells with the plane and triangulates
-    /// the resulting polygons for rendering. If a vertex color quantity is enabled,
-    /// colors are interpolated at slice points.
+    /// the resulting polygons for rendering. The cap is colored by the first
+    /// enabled quantity: vertex scalars and colors are interpolated at slice
+    /// points, cell scalars and colors give each cut polygon a flat color.
+    /// Without such a quantity the interior color is used.
     ///
     /// # Arguments
-    /// * `plane_origin` - A point on the slice plane
+    /// * `plane_origin` - A point on the slice plane (in the mesh's local space)
     /// * `plane_normal` - The plane normal (points toward kept geometry)
+    /// * `color_maps` - Color maps used to map scalar quantities to colors
     ///
     /// # Returns
     /// `Some(SliceMeshData)` if the plane intersects the mesh, `None` otherwise.
@@ -1172,15 +1226,25 @@ impl VolumeMesh {
         &self,
         plane_origin: Vec3,
         plane_normal: Vec3,
+        color_maps: &ColorMapRegistry,
     ) -> Option<SliceMeshData> {
         let mut vertices = Vec::new();
         let mut normals = Vec::new();
         let mut colors = Vec::new();
 
-        // Get active vertex color quantity for interpolation (if any)
-        let vertex_colors = self
-            .active_vertex_color_quantity()
-            .map(color_quantity::VolumeMeshVertexColorQuantity::colors);
+        let quantity = self.active_slice_color_quantity().map(Quantity::as_any);
+        let vertex_scalar = quantity
+            .and_then(|q| q.downcast_ref::<VolumeMeshVertexScalarQuantity>())
+            .and_then(|q| Some((q, color_maps.get(q.color_map())?)));
+        let cell_scalar = quantity
+            .and_then(|q| q.downcast_ref::<VolumeMeshCellScalarQuantity>())
+            .and_then(|q| Some((q, color_maps.get(q.color_map())?)));
+        let vertex_colors = quantity
+            .and_then(|q| q.downcast_ref::<VolumeMeshVertexColorQuantity>())
+            .map(VolumeMeshVertexColorQuantity::linear_colors);
+        let cell_colors = quantity
+            .and_then(|q| q.downcast_ref::<VolumeMeshCellColorQuantity>())
+            .map(VolumeMeshCellColorQuantity::linear_colors);
 
         for (cell_idx, cell) in self.cells.iter().enumerate() {
             let cell_type = self.cell_type(cell_idx);
@@ -1202,21 +1266,41 @@ impl VolumeMesh {
             };
 
             if slice.has_intersection() {
-                // Compute interpolated colors for each slice vertex
-                let slice_colors: Vec<Vec4> = if let Some(vc) = vertex_colors {
+                // Map local cell indices to global vertex indices
+                let endpoints = |&(a, b, t): &(u32, u32, f32)| {
+                    (cell[a as usize] as usize, cell[b as usize] as usize, t)
+                };
+
+                // Compute the color of each slice vertex
+                let slice_colors: Vec<Vec4> = if let Some((q, colormap)) = vertex_scalar {
+                    let values = q.values();
                     slice
                         .interpolation
                         .iter()
-                        .map(|&(a, b, t)| {
-                            // Map local cell indices to global vertex indices
-                            let va_idx = cell[a as usize] as usize;
-                            let vb_idx = cell[b as usize] as usize;
-                            // Interpolate colors
-                            vc[va_idx].lerp(vc[vb_idx], t)
+                        .map(endpoints)
+                        .map(|(va, vb, t)| {
+                            let value = values[va] + (values[vb] - values[va]) * t;
+                            map_scalar(value, q.data_range(), colormap)
                         })
                         .collect()
+                } else if let Some(vc) = &vertex_colors {
+                    slice
+                        .interpolation
+                        .iter()
+                        .map(endpoints)
+                        .map(|(va, vb, t)| vc[va].lerp(vc[vb], t))
+                        .collect()
                 } else {
-                    vec![self.interior_color; slice.vertices.len()]
+                    let flat = if let Some((q, colormap)) = cell_scalar {
+                        q.values()
+                            .get(cell_idx)
+                            .map(|&v| map_scalar(v, q.data_range(), colormap))
+                    } else {
+                        cell_colors
+                            .as_ref()
+                            .and_then(|cc| cc.get(cell_idx).copied())
+                    };
+                    vec![flat.unwrap_or(self.interior_color); slice.vertices.len()]
                 };
 
                 // Triangulate the polygon (fan from first vertex)
@@ -1230,7 +1314,6 @@ impl VolumeMesh {
                     normals.push(plane_normal);
                     normals.push(plane_normal);
 
-                    // Interpolated colors (or interior_color if no quantity)
                     colors.push(slice_colors[0]);
                     colors.push(slice_colors[i]);
                     colors.push(slice_colors[i + 1]);
@@ -1250,6 +1333,13 @@ impl VolumeMesh {
     }
 }
 
+/// Maps a scalar value to a color given the data range.
+fn map_scalar(value: f32, (min, max): (f32, f32), colormap: &ColorMap) -> Vec4 {
+    let range = max - min;
+    let range = if range.abs() < 1e-10 { 1.0 } else { range };
+    colormap.sample((value - min) / range).extend(1.0)
+}
+
 /// Data representing a slice mesh cross-section.
 ///
 /// Contains triangulated geometry for rendering the cross-section
@@ -1336,6 +1426,22 @@ impl Structure for VolumeMesh {
         self.enabled = enabled;
     }
 
+    fn ignores_slice_planes(&self) -> bool {
+        self.ignore_slice_planes
+    }
+
+    fn set_ignore_slice_planes(&mut self, ignore: bool) {
+        self.ignore_slice_planes = ignore;
+    }
+
+    fn draw_order(&self) -> i32 {
+        self.draw_order
+    }
+
+    fn set_draw_order(&mut self, order: i32) {
+        self.draw_order = order;
+    }
+
     fn draw(&self, _ctx: &mut dyn RenderContext) {
         // Drawing is handled externally
     }
@@ -1365,6 +1471,29 @@ impl Structure for VolumeMesh {
         }
     }
 
+    fn gpu_buffer_bytes(&self) -> u64 {
+        self.render_data
+            .as_ref()
+            .map_or(0, SurfaceMeshRenderData::buffer_bytes)
+            + self
+                .pick_uniform_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .pick_cell_index_buffer
+                .as_ref()
+                .map_or(0, wgpu::Buffer::size)
+            + self
+                .slice_render_data
+                .as_ref()
+                .map_or(0, SliceMeshRenderData::buffer_bytes)
+            + self
+                .quantities
+                .iter()
+                .map(|q| q.gpu_buffer_bytes())
+                .sum::<u64>()
+    }
+
     fn refresh(&mut self) {
         self.render_data = None;
         self.pick_uniform_buffer = None;
@@ -1407,6 +1536,34 @@ impl HasQuantities for VolumeMesh {
 
 use std::collections::HashMap;
 
+/// Counts the cells sharing each face, for interior/exterior detection.
+fn compute_face_counts(cells: &[[u32; 8]]) -> HashMap<[u32; 4], usize> {
+    let mut face_counts: HashMap<[u32; 4], usize> = HashMap::new();
+
+    for cell in cells {
+        if cell[4] == u32::MAX {
+            // Tetrahedron
+            for [a, b, c] in TET_FACE_STENCIL {
+                let key = canonical_face_key(cell[a], cell[b], cell[c], None);
+                *face_counts.entry(key).or_insert(0) += 1;
+            }
+        } else {
+            // Hexahedron - each quad face uses same 4 vertices
+            for quad in HEX_FACE_STENCIL {
+                // Get the 4 unique vertices of this quad face
+                let v0 = cell[quad[0][0]];
+                let v1 = cell[quad[0][1]];
+                let v2 = cell[quad[0][2]];
+                let v3 = cell[quad[1][2]]; // The fourth vertex
+                let key = canonical_face_key(v0, v1, v2, Some(v3));
+                *face_counts.entry(key).or_insert(0) += 1;
+            }
+        }
+    }
+
+    face_counts
+}
+
 /// Generates a canonical (sorted) face key for hashing.
 /// For triangular faces, the fourth element is `u32::MAX`.
 fn canonical_face_key(v0: u32, v1: u32, v2: u32, v3: Option<u32>) -> [u32; 4] {
@@ -1472,6 +1629,48 @@ mod tests {
         assert_eq!(faces.len(), 6, "Should only render exterior faces");
     }
 
+    #[test]
+    fn test_show_interior_faces() {
+        // 2x2x2 grid of hexes, each split into 5 tets
+        let mut vertices = Vec::new();
+        for z in 0..3 {
+            for y in 0..3 {
+                for x in 0..3 {
+                    vertices.push(Vec3::new(x as f32, y as f32, z as f32));
+                }
+            }
+        }
+        let idx = |x: u32, y: u32, z: u32| x + 3 * y + 9 * z;
+        let mut hexes = Vec::new();
+        for z in 0..2 {
+            for y in 0..2 {
+                for x in 0..2 {
+                    hexes.push([
+                        idx(x, y, z),
+                        idx(x + 1, y, z),
+                        idx(x + 1, y + 1, z),
+                        idx(x, y + 1, z),
+                        idx(x, y, z + 1),
+                        idx(x + 1, y, z + 1),
+                        idx(x + 1, y + 1, z + 1),
+                        idx(x, y + 1, z + 1),
+                    ]);
+                }
+            }
+        }
+        let tets = VolumeMesh::new_hex_mesh("hexes", vertices.clone(), hexes).decompose_to_tets();
+        let mut mesh = VolumeMesh::new_tet_mesh("test", vertices, tets);
+
+        assert!(mesh.num_boundary_faces() < mesh.num_faces());
+        let (_, boundary) = mesh.generate_render_geometry();
+        assert_eq!(boundary.len(), mesh.num_boundary_faces());
+
+        mesh.set_show_interior(true);
+        let (_, all) = mesh.generate_render_geometry();
+        assert_eq!(all.len(), 4 * mesh.num_cells());
+        assert_eq!(mesh.generate_cell_index_per_triangle().len(), all.len());
+    }
+
     #[test]
     fn test_single_tet_all_exterior() {
         let vertices = vec![
@@ -1566,4 +1765,46 @@ mod tests {
             render_data.positions.len()
         );
     }
+
+    #[test]
+    fn test_slice_geometry_colored_by_scalar() {
+        let vertices = vec![
+            Vec3::new(-1.0, -1.0, -1.0),
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(0.0, 1.0, -1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+        let mut mesh = VolumeMesh::new_tet_mesh("slice", vertices, vec![[0, 1, 2, 3]]);
+        let color_maps = ColorMapRegistry::new();
+        let viridis = color_maps.get("viridis").unwrap();
+
+        // Vertex scalar equal to z: the z = 0 cut samples the middle of the range
+        mesh.add_vertex_scalar_quantity("z", vec![-1.0, -1.0, -1.0, 1.0]);
+        mesh.get_quantity_mut("z").unwrap().set_enabled(true);
+        let slice = mesh
+            .generate_slice_geometry(Vec3::ZERO, Vec3::Z, &color_maps)
+            .unwrap();
+        assert_eq!(slice.num_triangles(), 1);
+        let expected = viridis.sample(0.5).extend(1.0);
+        for color in &slice.colors {
+            assert!((*color - expected).length() < 1e-5);
+        }
+
+        // Cell scalars color each cut polygon flat
+        mesh.get_quantity_mut("z").unwrap().set_enabled(false);
+        mesh.add_cell_scalar_quantity("cell", vec![3.0]);
+        mesh.get_quantity_mut("cell").unwrap().set_enabled(true);
+        let slice = mesh
+            .generate_slice_geometry(Vec3::ZERO, Vec3::Z, &color_maps)
+            .unwrap();
+        let expected = viridis.sample(0.0).extend(1.0);
+        assert!(slice.colors.iter().all(|c| (*c - expected).length() < 1e-5));
+
+        // Without a quantity the cap uses the interior color
+        mesh.get_quantity_mut("cell").unwrap().set_enabled(false);
+        let slice = mesh
+            .generate_slice_geometry(Vec3::ZERO, Vec3::Z, &color_maps)
+            .unwrap();
+        assert!(slice.colors.iter().all(|c| *c == mesh.interior_color()));
+    }
 }
@@ -1,7 +1,10 @@
 //! Scalar quantities for volume meshes.
 
+use polyscope_core::error::Result;
 use polyscope_core::quantity::{CellQuantity, Quantity, QuantityKind, VertexQuantity};
 
+use crate::quantity_update::replace_data;
+
 /// A scalar quantity defined at mesh vertices.
 pub struct VolumeMeshVertexScalarQuantity {
     name: String,
@@ -11,6 +14,7 @@ pub struct VolumeMeshVertexScalarQuantity {
     color_map: String,
     data_min: f32,
     data_max: f32,
+    range_user_set: bool, // false = range follows the data
 }
 
 impl VolumeMeshVertexScalarQuantity {
@@ -29,6 +33,7 @@ impl VolumeMeshVertexScalarQuantity {
             color_map: "viridis".to_string(),
             data_min,
             data_max,
+            range_user_set: false,
         }
     }
 
@@ -50,6 +55,23 @@ impl VolumeMeshVertexScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The data range is
+    /// refit to the new values unless it was set explicitly (in code or in
+    /// the UI). The colors shown are recomputed from the new values on the
+    /// next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        }
+        Ok(())
+    }
+
     /// Gets the color map name.
     #[must_use]
     pub fn color_map(&self) -> &str {
@@ -72,6 +94,15 @@ impl VolumeMeshVertexScalarQuantity {
     pub fn set_data_range(&mut self, min: f32, max: f32) -> &mut Self {
         self.data_min = min;
         self.data_max = max;
+        self.range_user_set = true;
+        self
+    }
+
+    /// Resets the data range to the min/max of the values, which it then
+    /// follows on value updates.
+    pub fn reset_data_range(&mut self) -> &mut Self {
+        (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        self.range_user_set = false;
         self
     }
 
@@ -128,6 +159,7 @@ pub struct VolumeMeshCellScalarQuantity {
     color_map: String,
     data_min: f32,
     data_max: f32,
+    range_user_set: bool, // false = range follows the data
 }
 
 impl VolumeMeshCellScalarQuantity {
@@ -146,6 +178,7 @@ impl VolumeMeshCellScalarQuantity {
             color_map: "viridis".to_string(),
             data_min,
             data_max,
+            range_user_set: false,
         }
     }
 
@@ -167,6 +200,23 @@ impl VolumeMeshCellScalarQuantity {
         &self.values
     }
 
+    /// Replaces the values in place, keeping the colormap. The data range is
+    /// refit to the new values unless it was set explicitly (in code or in
+    /// the UI). The colors shown are recomputed from the new values on the
+    /// next frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PolyscopeError::SizeMismatch` unless there is one value per
+    /// existing value.
+    pub fn update_values(&mut self, values: Vec<f32>) -> Result<()> {
+        replace_data(&mut self.values, values)?;
+        if !self.range_user_set {
+            (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        }
+        Ok(())
+    }
+
     /// Gets the color map name.
     #[must_use]
     pub fn color_map(&self) -> &str {
@@ -189,6 +239,15 @@ impl VolumeMeshCellScalarQuantity {
     pub fn set_data_range(&mut self, min: f32, max: f32) -> &mut Self {
         self.data_min = min;
         self.data_max = max;
+        self.range_user_set = true;
+        self
+    }
+
+    /// Resets the data range to the min/max of the values, which it then
+    /// follows on value updates.
+    pub fn reset_data_range(&mut self) -> &mut Self {
+        (self.data_min, self.data_max) = Self::compute_range(&self.values);
+        self.range_user_set = false;
         self
     }
 
@@ -0,0 +1,27 @@
+//! Overlay windows for floating images.
+
+use egui::{Context, Id, TextureHandle, Window};
+
+/// Initial display width of a floating image window, in points.
+const DEFAULT_IMAGE_WIDTH: f32 = 256.0;
+
+/// Builds an overlay window showing a floating image, scaled to the window
+/// width with its aspect ratio preserved.
+///
+/// Returns false if the user closed the window.
+#[must_use]
+pub fn build_floating_image_window(ctx: &Context, name: &str, texture: &TextureHandle) -> bool {
+    let [width, height] = texture.size();
+    let aspect = height as f32 / width.max(1) as f32;
+    let mut open = true;
+    Window::new(name)
+        .id(Id::new(("floating_image", name)))
+        .open(&mut open)
+        .default_width(DEFAULT_IMAGE_WIDTH)
+        .resizable(true)
+        .show(ctx, |ui| {
+            let width = ui.available_width();
+            ui.image((texture.id(), egui::vec2(width, width * aspect)));
+        });
+    open
+}
@@ -0,0 +1,66 @@
+//! Screen-space overlay for world-anchored text labels.
+
+use egui::{Align2, Color32, Context, FontId, Id, LayerId, Order, Pos2, Rect};
+use glam::{Mat4, Vec3};
+use polyscope_core::Label;
+
+/// Font size of label text, in points.
+const LABEL_FONT_SIZE: f32 = 14.0;
+
+/// Projects a world position into `screen`, or returns `None` if it lies
+/// behind the camera.
+#[must_use]
+pub fn project_to_screen(view_proj: Mat4, position: Vec3, screen: Rect) -> Option<Pos2> {
+    let clip = view_proj * position.extend(1.0);
+    if clip.w <= 0.0 {
+        return None;
+    }
+    let ndc = clip.truncate() / clip.w;
+    Some(Pos2::new(
+        screen.min.x + (ndc.x + 1.0) * 0.5 * screen.width(),
+        screen.min.y + (1.0 - ndc.y) * 0.5 * screen.height(),
+    ))
+}
+
+/// Draws labels as camera-facing text centered above their projected
+/// anchors, beneath all UI windows. Labels behind the camera are skipped.
+pub fn draw_labels<'a>(
+    ctx: &Context,
+    labels: impl IntoIterator<Item = &'a Label>,
+    view_proj: Mat4,
+) {
+    let screen = ctx.viewport_rect();
+    let painter = ctx.layer_painter(LayerId::new(Order::Background, Id::new("labels")));
+    for label in labels {
+        let Some(pos) = project_to_screen(view_proj, label.position, screen) else {
+            continue;
+        };
+        let [r, g, b] = label
+            .color
+            .to_array()
+            .map(|c| (c.clamp(0.0, 1.0) * 255.0) as u8);
+        painter.text(
+            pos,
+            Align2::CENTER_BOTTOM,
+            &label.text,
+            FontId::proportional(LABEL_FONT_SIZE),
+            Color32::from_rgb(r, g, b),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_to_screen_clips_behind_camera() {
+        let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_rh(1.0, 2.0, 0.1, 100.0);
+        let screen = Rect::from_min_size(Pos2::ZERO, egui::vec2(200.0, 100.0));
+
+        let center = project_to_screen(proj * view, Vec3::ZERO, screen).unwrap();
+        assert!((center - Pos2::new(100.0, 50.0)).length() < 1e-3);
+        assert!(project_to_screen(proj * view, Vec3::new(0.0, 0.0, 10.0), screen).is_none());
+    }
+}
@@ -24,15 +24,19 @@
 // Type casts: UI element indices may need u32 to i32 conversion.
 #![allow(clippy::cast_possible_wrap)]
 
+pub mod floating_images;
 pub mod gizmo;
 pub mod integration;
+pub mod labels;
 pub mod panels;
 pub mod quantity_ui;
 pub mod selection_panel;
 pub mod structure_ui;
 
+pub use floating_images::*;
 pub use gizmo::TransformGizmo;
 pub use integration::EguiIntegration;
+pub use labels::*;
 pub use panels::*;
 pub use quantity_ui::*;
 pub use selection_panel::*;
@@ -55,7 +55,7 @@ pub struct SceneExtents {
 /// Appearance settings for UI.
 #[derive(Debug, Clone)]
 pub struct AppearanceSettings {
-    /// Transparency mode (0=None, 1=Simple, 2=Pretty/DepthPeeling)
+    /// Transparency mode (1=Simple, 2=Pretty/DepthPeeling)
     pub transparency_mode: u32,
     /// SSAA factor (1, 2, or 4)
     pub ssaa_factor: u32,
@@ -91,6 +91,8 @@ impl Default for AppearanceSettings {
 /// Tone mapping settings for UI.
 #[derive(Debug, Clone)]
 pub struct ToneMappingSettings {
+    /// Tone mapping operator (0=None, 1=Reinhard, 2=ACES, 3=Filmic).
+    pub operator: u32,
     /// Exposure value (0.1 - 4.0).
     pub exposure: f32,
     /// White level (0.5 - 4.0).
@@ -102,6 +104,7 @@ pub struct ToneMappingSettings {
 impl Default for ToneMappingSettings {
     fn default() -> Self {
         Self {
+            operator: 1, // Reinhard
             exposure: 1.1,
             white_level: 1.0,
             gamma: 2.2,
@@ -120,6 +123,8 @@ pub struct SlicePlaneSettings {
     pub origin: [f32; 3],
     /// Normal direction (x, y, z).
     pub normal: [f32; 3],
+    /// Whether the kept side is flipped.
+    pub inverted: bool,
     /// Whether to draw the plane visualization.
     pub draw_plane: bool,
     /// Whether to draw the widget.
@@ -141,6 +146,7 @@ impl Default for SlicePlaneSettings {
             enabled: true,
             origin: [0.0, 0.0, 0.0],
             normal: [0.0, 1.0, 0.0],
+            inverted: false,
             draw_plane: true,
             draw_widget: true,
             color: [0.5, 0.5, 0.5],
@@ -592,7 +598,11 @@ pub fn build_groups_section(ui: &mut Ui, groups: &mut Vec<GroupSettings>) -> Gro
 
 /// Builds the main left panel.
 /// Returns the actual panel width in logical pixels (for dynamic pointer checks).
-pub fn build_left_panel(ctx: &Context, build_contents: impl FnOnce(&mut Ui)) -> f32 {
+/// When `visible` is false nothing is drawn and the width is 0.
+pub fn build_left_panel(ctx: &Context, visible: bool, build_contents: impl FnOnce(&mut Ui)) -> f32 {
+    if !visible {
+        return 0.0;
+    }
     let resp = SidePanel::left("polyscope_main_panel")
         .default_width(305.0)
         .resizable(true)
@@ -917,17 +927,10 @@ pub fn build_appearance_section(ui: &mut Ui, settings: &mut AppearanceSettings)
             // Transparency mode
             egui::ComboBox::from_label("Transparency")
                 .selected_text(match settings.transparency_mode {
-                    0 => "None",
-                    1 => "Simple",
-                    _ => "Pretty",
+                    2 => "Pretty",
+                    _ => "Simple",
                 })
                 .show_ui(ui, |ui| {
-                    if ui
-                        .selectable_value(&mut settings.transparency_mode, 0, "None")
-                        .changed()
-                    {
-                        changed = true;
-                    }
                     if ui
                         .selectable_value(&mut settings.transparency_mode, 1, "Simple")
                         .changed()
@@ -1105,6 +1108,28 @@ pub fn build_tone_mapping_section(ui: &mut Ui, settings: &mut ToneMappingSetting
             egui::Grid::new("tone_mapping_grid")
                 .num_columns(2)
                 .show(ui, |ui| {
+                    ui.label("Operator:");
+                    egui::ComboBox::from_id_salt("tone_map_operator")
+                        .selected_text(match settings.operator {
+                            0 => "None",
+                            2 => "ACES",
+                            3 => "Filmic",
+                            _ => "Reinhard",
+                        })
+                        .show_ui(ui, |ui| {
+                            for (value, label) in
+                                [(0, "None"), (1, "Reinhard"), (2, "ACES"), (3, "Filmic")]
+                            {
+                                if ui
+                                    .selectable_value(&mut settings.operator, value, label)
+                                    .changed()
+                                {
+                                    changed = true;
+                                }
+                            }
+                        });
+                    ui.end_row();
+
                     ui.label("Exposure:");
                     if ui
                         .add(
@@ -1275,6 +1300,13 @@ fn build_slice_plane_item(ui: &mut Ui, settings: &mut SlicePlaneSettings) -> boo
         }
     });
 
+    if ui
+        .checkbox(&mut settings.inverted, "Invert (keep other side)")
+        .changed()
+    {
+        changed = true;
+    }
+
     ui.separator();
 
     // Visualization options
@@ -1389,7 +1421,9 @@ pub fn build_slice_planes_section(
 /// Builds the ground plane settings section.
 pub fn build_ground_plane_section(
     ui: &mut Ui,
-    mode: &mut u32, // 0=None, 1=Tile, 2=ShadowOnly, 3=TileReflection
+    draw_tile: &mut bool,
+    draw_shadow: &mut bool,
+    draw_reflection: &mut bool,
     height: &mut f32,
     height_is_relative: &mut bool,
     shadow_blur_iters: &mut u32,
@@ -1401,31 +1435,25 @@ pub fn build_ground_plane_section(
     CollapsingHeader::new("Ground Plane")
         .default_open(false)
         .show(ui, |ui| {
-            // Mode selector
-            egui::ComboBox::from_label("Mode")
-                .selected_text(match *mode {
-                    0 => "None",
-                    1 => "Tile",
-                    2 => "Shadow Only",
-                    3 => "Tile + Reflection",
-                    _ => "Unknown",
-                })
-                .show_ui(ui, |ui| {
-                    if ui.selectable_value(mode, 0, "None").changed() {
-                        changed = true;
-                    }
-                    if ui.selectable_value(mode, 1, "Tile").changed() {
-                        changed = true;
-                    }
-                    if ui.selectable_value(mode, 2, "Shadow Only").changed() {
+            // Independent layers
+            egui::Grid::new("ground_plane_layers_grid")
+                .num_columns(3)
+                .show(ui, |ui| {
+                    if ui.checkbox(draw_tile, "Tile").changed() {
                         changed = true;
                     }
-                    if ui.selectable_value(mode, 3, "Tile + Reflection").changed() {
+                    if ui.checkbox(draw_shadow, "Shadow").changed() {
                         changed = true;
                     }
+                    ui.add_enabled_ui(*draw_tile, |ui| {
+                        if ui.checkbox(draw_reflection, "Reflection").changed() {
+                            changed = true;
+                        }
+                    });
+                    ui.end_row();
                 });
 
-            if *mode > 0 {
+            if *draw_tile || *draw_shadow {
                 ui.separator();
 
                 // Height settings
@@ -1443,27 +1471,29 @@ pub fn build_ground_plane_section(
                 }
 
                 // Shadow settings
-                ui.separator();
-                ui.label("Shadow Settings:");
+                if *draw_shadow {
+                    ui.separator();
+                    ui.label("Shadow Settings:");
 
-                egui::Grid::new("shadow_grid")
-                    .num_columns(2)
-                    .show(ui, |ui| {
-                        ui.label("Blur iterations:");
-                        if ui.add(Slider::new(shadow_blur_iters, 0..=5)).changed() {
-                            changed = true;
-                        }
-                        ui.end_row();
+                    egui::Grid::new("shadow_grid")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label("Blur iterations:");
+                            if ui.add(Slider::new(shadow_blur_iters, 0..=5)).changed() {
+                                changed = true;
+                            }
+                            ui.end_row();
 
-                        ui.label("Darkness:");
-                        if ui.add(Slider::new(shadow_darkness, 0.0..=1.0)).changed() {
-                            changed = true;
-                        }
-                        ui.end_row();
-                    });
+                            ui.label("Darkness:");
+                            if ui.add(Slider::new(shadow_darkness, 0.0..=1.0)).changed() {
+                                changed = true;
+                            }
+                            ui.end_row();
+                        });
+                }
 
-                // Reflection settings (only for mode 3 - TileReflection)
-                if *mode == 3 {
+                // Reflection settings (reflections are drawn in the tile)
+                if *draw_tile && *draw_reflection {
                     ui.separator();
                     ui.label("Reflection Settings:");
 
@@ -1601,3 +1631,26 @@ pub fn build_structure_tree_with_ui<F, U>(
             }
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_left_panel_hidden() {
+        let ctx = Context::default();
+        let mut built = false;
+        let mut width = 0.0;
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            width = build_left_panel(ctx, false, |_| built = true);
+        });
+        assert!(!built);
+        assert!(width.abs() < f32::EPSILON);
+
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            width = build_left_panel(ctx, true, |_| built = true);
+        });
+        assert!(built);
+        assert!(width > 0.0);
+    }
+}
@@ -80,6 +80,36 @@ pub fn build_color_quantity_ui(
     changed
 }
 
+/// Builds UI for a radius quantity.
+pub fn build_radius_quantity_ui(
+    ui: &mut Ui,
+    name: &str,
+    enabled: &mut bool,
+    radius_is_relative: &mut bool,
+) -> bool {
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        if ui.checkbox(enabled, name).changed() {
+            changed = true;
+        }
+        ui.label("(radius)");
+    });
+
+    if *enabled {
+        ui.indent(name, |ui| {
+            if ui
+                .checkbox(radius_is_relative, "Relative to point radius")
+                .changed()
+            {
+                changed = true;
+            }
+        });
+    }
+
+    changed
+}
+
 /// Builds UI for a vector quantity.
 pub fn build_vector_quantity_ui(
     ui: &mut Ui,
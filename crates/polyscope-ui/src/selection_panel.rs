@@ -38,6 +38,18 @@ pub fn build_selection_panel(
                 PickElementType::Cell => "Cell",
             };
             ui.label(format!("{} #{}", element_type_str, selection.element_index));
+            if let Some(pos) = selection.world_position {
+                ui.label(format!(
+                    "Position: ({:.4}, {:.4}, {:.4})",
+                    pos.x, pos.y, pos.z
+                ));
+            }
+            if let Some(bary) = selection.barycentric {
+                ui.label(format!(
+                    "Barycentric: ({:.3}, {:.3}, {:.3})",
+                    bary.x, bary.y, bary.z
+                ));
+            }
 
             ui.separator();
             build_structure_pick_ui(ui);
@@ -20,28 +20,133 @@ impl ApplicationHandler for App {
                 .expect("failed to create window"),
         );
 
+        self.attach_window(window)
+            .expect("failed to create render engine");
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        _window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        let redraw = matches!(event, WindowEvent::RedrawRequested);
+        self.handle_window_event(event);
+        // Render continuously, or on demand: after input and while animating
+        let next_frame = if redraw {
+            self.run_frame();
+            !crate::get_redraw_on_change() || self.is_animating()
+        } else {
+            crate::get_redraw_on_change()
+        };
+        if next_frame {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+
+        if self.close_requested {
+            event_loop.exit();
+        }
+    }
+}
+
+impl App {
+    /// Creates the render engine and egui integration for `window`.
+    pub(crate) fn attach_window(&mut self, window: Arc<Window>) -> crate::Result<()> {
         // Create render engine
         let mut engine = RenderEngine::new_windowed(window.clone())
             .block_on()
-            .expect("failed to create render engine");
+            .map_err(|e| {
+                crate::PolyscopeError::RenderError(format!("Failed to create render engine: {e}"))
+            })?;
 
         // Initialize GPU picking system
         engine.init_pick_buffers(engine.width, engine.height);
+        crate::headless::record_frame_size(engine.width, engine.height);
 
         // Create egui integration
         let egui = EguiIntegration::new(&engine.device, engine.surface_config.format, &window);
 
+        crate::redraw::set_redraw_window(window.clone());
         self.window = Some(window);
         self.engine = Some(engine);
         self.egui = Some(egui);
+        Ok(())
     }
 
-    fn window_event(
-        &mut self,
-        event_loop: &ActiveEventLoop,
-        _window_id: WindowId,
-        event: WindowEvent,
-    ) {
+    /// Runs one frame: first-person movement, the user callback, then
+    /// rendering and presenting to the window.
+    pub(crate) fn run_frame(&mut self) {
+        // Per-frame first-person WASD movement
+        let now = std::time::Instant::now();
+        if let Some(last) = self.last_frame_time {
+            let dt = now.duration_since(last).as_secs_f32();
+            if let Some(engine) = &mut self.engine {
+                if engine.camera.navigation_style == polyscope_render::NavigationStyle::FirstPerson
+                    && !self.keys_down.is_empty()
+                {
+                    let mut delta = Vec3::ZERO;
+                    if self.keys_down.contains(&KeyCode::KeyA) {
+                        delta.x -= 1.0; // strafe left
+                    }
+                    if self.keys_down.contains(&KeyCode::KeyD) {
+                        delta.x += 1.0; // strafe right
+                    }
+                    if self.keys_down.contains(&KeyCode::KeyQ) {
+                        delta.y += 1.0; // rise
+                    }
+                    if self.keys_down.contains(&KeyCode::KeyE) {
+                        delta.y -= 1.0; // descend
+                    }
+                    if self.keys_down.contains(&KeyCode::KeyW) {
+                        delta.z += 1.0; // forward
+                    }
+                    if self.keys_down.contains(&KeyCode::KeyS) {
+                        delta.z -= 1.0; // backward
+                    }
+                    if delta.length_squared() > 0.0 {
+                        engine.camera.cancel_flight();
+                        let length_scale = engine
+                            .camera
+                            .position
+                            .distance(engine.camera.target)
+                            .max(1.0);
+                        let speed = length_scale * dt * engine.camera.move_speed;
+                        engine.camera.move_first_person(delta.normalize() * speed);
+                    }
+                }
+            }
+        }
+        self.last_frame_time = Some(now);
+
+        crate::with_context_mut(polyscope_core::state::Context::advance_frame);
+        crate::invoke_user_callback();
+        self.render();
+        // Resolve picks deferred by `pick_at` against this frame's pick buffer
+        for (x, y) in crate::headless::take_pending_picks() {
+            crate::invoke_pick_callback(self.resolve_pick_at(x, y));
+        }
+        // Report selection changes made directly on the context
+        crate::gizmo::notify_selection_changed();
+    }
+
+    /// Returns whether the next frame differs without new input: a camera
+    /// flight, first-person movement or an egui animation is in progress.
+    fn is_animating(&self) -> bool {
+        self.engine
+            .as_ref()
+            .is_some_and(|engine| engine.camera.flight.is_some())
+            || !self.keys_down.is_empty()
+            || self
+                .egui
+                .as_ref()
+                .is_some_and(|egui| egui.context.has_requested_repaint())
+    }
+
+    /// Handles a window event (input, resize, close). Does not render;
+    /// `RedrawRequested` is left to the caller.
+    pub(crate) fn handle_window_event(&mut self, event: WindowEvent) {
         // ALWAYS track physical mouse button state, even if egui consumes the event.
         // This prevents the mouse state from getting "stuck" when egui intercepts events.
         match &event {
@@ -98,60 +203,13 @@ impl ApplicationHandler for App {
                     // Resize pick buffers to match
                     engine.init_pick_buffers(size.width, size.height);
                 }
-            }
-            WindowEvent::RedrawRequested => {
-                // Per-frame first-person WASD movement
-                let now = std::time::Instant::now();
-                if let Some(last) = self.last_frame_time {
-                    let dt = now.duration_since(last).as_secs_f32();
-                    if let Some(engine) = &mut self.engine {
-                        if engine.camera.navigation_style
-                            == polyscope_render::NavigationStyle::FirstPerson
-                            && !self.keys_down.is_empty()
-                        {
-                            let mut delta = Vec3::ZERO;
-                            if self.keys_down.contains(&KeyCode::KeyA) {
-                                delta.x -= 1.0; // strafe left
-                            }
-                            if self.keys_down.contains(&KeyCode::KeyD) {
-                                delta.x += 1.0; // strafe right
-                            }
-                            if self.keys_down.contains(&KeyCode::KeyQ) {
-                                delta.y += 1.0; // rise
-                            }
-                            if self.keys_down.contains(&KeyCode::KeyE) {
-                                delta.y -= 1.0; // descend
-                            }
-                            if self.keys_down.contains(&KeyCode::KeyW) {
-                                delta.z += 1.0; // forward
-                            }
-                            if self.keys_down.contains(&KeyCode::KeyS) {
-                                delta.z -= 1.0; // backward
-                            }
-                            if delta.length_squared() > 0.0 {
-                                engine.camera.cancel_flight();
-                                let length_scale = engine
-                                    .camera
-                                    .position
-                                    .distance(engine.camera.target)
-                                    .max(1.0);
-                                let speed = length_scale * dt * engine.camera.move_speed;
-                                engine.camera.move_first_person(delta.normalize() * speed);
-                            }
-                        }
-                    }
-                }
-                self.last_frame_time = Some(now);
-
-                self.render();
-                if let Some(window) = &self.window {
-                    window.request_redraw();
-                }
+                crate::headless::record_frame_size(size.width, size.height);
             }
             WindowEvent::CursorMoved { position, .. } => {
                 let delta_x = position.x - self.mouse_pos.0;
                 let delta_y = position.y - self.mouse_pos.1;
                 self.mouse_pos = (position.x, position.y);
+                crate::view::record_cursor_position(Some((position.x as f32, position.y as f32)));
 
                 // Accumulate drag distance
                 if self.left_mouse_down || self.right_mouse_down {
@@ -313,7 +371,7 @@ impl ApplicationHandler for App {
                             let plane_params = crate::with_context(|ctx| {
                                 ctx.slice_planes()
                                     .filter(|p| p.is_enabled())
-                                    .map(|p| (p.origin(), p.normal()))
+                                    .map(|p| (p.origin(), p.kept_normal()))
                                     .collect::<Vec<_>>()
                             });
 
@@ -371,7 +429,7 @@ impl ApplicationHandler for App {
                                 let mut enabled_planes: Vec<(String, Vec3, Vec3)> = ctx
                                     .slice_planes()
                                     .filter(|p| p.is_enabled())
-                                    .map(|p| (p.name().to_string(), p.origin(), p.normal()))
+                                    .map(|p| (p.name().to_string(), p.origin(), p.kept_normal()))
                                     .collect();
                                 enabled_planes.sort_by(|a, b| a.0.cmp(&b.0));
                                 enabled_planes
@@ -391,10 +449,12 @@ impl ApplicationHandler for App {
                             let mut point_hit: Option<(String, u32, f32)> = None;
                             let mut curve_hit: Option<(String, u32, f32)> = None;
                             let mut mesh_hit: Option<(String, u32, f32)> = None;
-                            // Filter GPU picks by group visibility
+                            // Filter GPU picks by structure and group visibility
                             let gpu_picked = gpu_picked.filter(|(type_name, name, _)| {
                                 crate::with_context(|ctx| {
-                                    ctx.is_structure_visible_in_groups(type_name, name)
+                                    ctx.registry
+                                        .get(type_name, name)
+                                        .is_some_and(|s| ctx.is_structure_visible(s))
                                 })
                             });
                             if let Some((type_name, name, idx)) = gpu_picked {
@@ -537,6 +597,18 @@ impl ApplicationHandler for App {
                                         name.clone()
                                     };
 
+                                    // Surface mesh faces also report where on the face the click landed
+                                    let face_hit = if type_name == "SurfaceMesh" {
+                                        self.pick_surface_mesh_face_at_ray(
+                                            ray_origin,
+                                            ray_dir,
+                                            name,
+                                            *element_index,
+                                        )
+                                    } else {
+                                        None
+                                    };
+
                                     self.selection = Some(PickResult {
                                         hit: true,
                                         structure_type: type_name.clone(),
@@ -545,9 +617,29 @@ impl ApplicationHandler for App {
                                         element_type,
                                         screen_pos: click_screen,
                                         depth: 0.5,
+                                        world_position: face_hit.map(|(pos, _)| pos),
+                                        barycentric: face_hit.map(|(_, bary)| bary),
                                     });
                                     crate::select_structure(type_name, &structure_name);
                                     self.selection_info = crate::get_selection_info();
+
+                                    crate::invoke_pick_callback(Some(
+                                        crate::surface_mesh::with_world_position_f64(
+                                            crate::PickResult {
+                                                barycentric: face_hit.map(|(_, bary)| bary),
+                                                ..crate::PickResult::new(
+                                                    type_name.clone(),
+                                                    structure_name,
+                                                    *element_index as usize,
+                                                    face_hit.map_or(
+                                                        ray_origin + ray_dir * *t,
+                                                        |(pos, _)| pos,
+                                                    ),
+                                                    *t,
+                                                )
+                                            },
+                                        ),
+                                    ));
                                 }
                                 None => {
                                     log::debug!("[CLICK DEBUG] No hit - DESELECTING");
@@ -557,6 +649,7 @@ impl ApplicationHandler for App {
                                     self.selection_info = polyscope_ui::SelectionInfo::default();
                                     crate::deselect_structure();
                                     self.deselect_slice_plane_selection();
+                                    crate::invoke_pick_callback(None);
                                 }
                             }
                         }
@@ -607,16 +700,8 @@ impl ApplicationHandler for App {
                     match event.state {
                         ElementState::Pressed => {
                             self.keys_down.insert(code);
-                            // Handle special keys
-                            match code {
-                                KeyCode::Escape => {
-                                    self.close_requested = true;
-                                }
-                                KeyCode::F12 => {
-                                    self.request_auto_screenshot();
-                                    log::info!("Screenshot requested (F12)");
-                                }
-                                _ => {}
+                            if code == KeyCode::Escape {
+                                self.close_requested = true;
                             }
                         }
                         ElementState::Released => {
@@ -624,6 +709,23 @@ impl ApplicationHandler for App {
                         }
                     }
                 }
+
+                // Configurable shortcuts, unless a text field has keyboard focus
+                let egui_wants_keyboard = self
+                    .egui
+                    .as_ref()
+                    .is_some_and(|e| e.context.wants_keyboard_input());
+                if event.state == ElementState::Pressed && !event.repeat && !egui_wants_keyboard {
+                    let action = binding_key(&event.logical_key).and_then(|key| {
+                        crate::with_context(|ctx| ctx.options.key_bindings.action_for(key))
+                    });
+                    if let Some(action) = action {
+                        self.handle_key_action(action);
+                    }
+                }
+            }
+            WindowEvent::CursorLeft { .. } => {
+                crate::view::record_cursor_position(None);
             }
             WindowEvent::DroppedFile(path) => {
                 log::info!("File dropped: {}", path.display());
@@ -635,9 +737,75 @@ impl ApplicationHandler for App {
             }
             _ => {}
         }
+    }
 
-        if self.close_requested {
-            event_loop.exit();
+    /// Performs the viewer action bound to a pressed key.
+    fn handle_key_action(&mut self, action: crate::KeyAction) {
+        use crate::{GizmoMode, KeyAction};
+
+        let first_person = self.engine.as_ref().is_some_and(|engine| {
+            engine.camera.navigation_style == polyscope_render::NavigationStyle::FirstPerson
+        });
+        let gizmo_mode = match action {
+            KeyAction::GizmoTranslate => Some(GizmoMode::Translate),
+            KeyAction::GizmoRotate => Some(GizmoMode::Rotate),
+            KeyAction::GizmoScale => Some(GizmoMode::Scale),
+            KeyAction::Screenshot | KeyAction::ResetView | KeyAction::ToggleUi => None,
+        };
+        if let Some(mode) = gizmo_mode {
+            // The gizmo keys may clash with WASD movement
+            if !first_person {
+                crate::set_gizmo_mode(mode);
+            }
+            return;
+        }
+
+        match action {
+            KeyAction::Screenshot => {
+                self.request_auto_screenshot();
+                log::info!("Screenshot requested (keyboard shortcut)");
+            }
+            KeyAction::ResetView => {
+                if let Some(engine) = &mut self.engine {
+                    engine.camera.cancel_flight();
+                    super::reset_view(engine);
+                }
+            }
+            KeyAction::ToggleUi => crate::set_ui_visible(!crate::is_ui_visible()),
+            _ => {}
+        }
+    }
+}
+
+/// Maps a winit logical key to a bindable [`crate::Key`].
+fn binding_key(key: &winit::keyboard::Key) -> Option<crate::Key> {
+    use winit::keyboard::{Key as WinitKey, NamedKey};
+
+    match key {
+        WinitKey::Character(text) => {
+            let mut chars = text.chars();
+            let c = chars.next()?;
+            chars.next().is_none().then_some(crate::Key::Char(c))
+        }
+        WinitKey::Named(NamedKey::Home) => Some(crate::Key::Home),
+        WinitKey::Named(named) => {
+            let n = match named {
+                NamedKey::F1 => 1,
+                NamedKey::F2 => 2,
+                NamedKey::F3 => 3,
+                NamedKey::F4 => 4,
+                NamedKey::F5 => 5,
+                NamedKey::F6 => 6,
+                NamedKey::F7 => 7,
+                NamedKey::F8 => 8,
+                NamedKey::F9 => 9,
+                NamedKey::F10 => 10,
+                NamedKey::F11 => 11,
+                NamedKey::F12 => 12,
+                _ => return None,
+            };
+            Some(crate::Key::Function(n))
         }
+        _ => None,
     }
 }
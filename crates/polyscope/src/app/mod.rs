@@ -5,6 +5,7 @@ mod picking;
 mod render;
 mod render_capture;
 mod render_init;
+mod render_pick;
 mod render_scene;
 mod render_ui;
 
@@ -22,7 +23,7 @@ pub(super) use winit::{
     window::{Window, WindowId},
 };
 
-pub(super) use polyscope_core::{GroundPlaneConfig, GroundPlaneMode, Structure};
+pub(super) use polyscope_core::Structure;
 pub(super) use polyscope_render::{PickResult, RenderEngine, reflection};
 pub(super) use polyscope_structures::{
     CameraView, CurveNetwork, PointCloud, SurfaceMesh, VolumeGrid, VolumeMesh,
@@ -37,7 +38,6 @@ pub struct App {
     pub(super) engine: Option<RenderEngine>,
     pub(super) egui: Option<EguiIntegration>,
     pub(super) close_requested: bool,
-    pub(super) background_color: Vec3,
     // Mouse state for camera control
     // These track the PHYSICAL button state, updated on every press/release
     pub(super) mouse_pos: (f64, f64),
@@ -55,11 +55,13 @@ pub struct App {
     pub(super) last_left_click_screen_pos: Option<(f64, f64)>,
     // GPU picking - selected element index (from GPU pick)
     pub(super) selected_element_index: Option<u32>,
-    // Ground plane settings
-    pub(super) ground_plane: GroundPlaneConfig,
     // Screenshot state
     pub(super) screenshot_pending: Option<String>,
     pub(super) screenshot_counter: u32,
+    // Clear headless captures with a transparent background
+    pub(super) transparent_background: bool,
+    // Vertical world extent of an orthographic headless capture
+    pub(super) ortho_world_height: Option<f32>,
     // Camera settings UI state
     pub(super) camera_settings: polyscope_ui::CameraSettings,
     // Scene extents UI state
@@ -80,12 +82,12 @@ pub struct App {
     pub(super) slice_plane_selection: polyscope_ui::SlicePlaneSelectionInfo,
     // Visual gizmo
     pub(super) transform_gizmo: polyscope_ui::TransformGizmo,
-    // Tone mapping settings
-    pub(super) tone_mapping_settings: polyscope_ui::ToneMappingSettings,
     // Material loading UI state
     pub(super) material_load_state: polyscope_ui::MaterialLoadState,
     // Whether the camera has been auto-fitted to the scene
     pub(super) camera_fitted: bool,
+    // Last applied change of the camera modes set from user code
+    pub(super) camera_modes_generation: u64,
     // Keyboard state for first-person WASD movement
     pub(super) keys_down: HashSet<KeyCode>,
     // Frame timing for first-person movement
@@ -100,7 +102,6 @@ impl App {
             engine: None,
             egui: None,
             close_requested: false,
-            background_color: Vec3::new(0.1, 0.1, 0.1),
             mouse_pos: (0.0, 0.0),
             left_mouse_down: false,
             right_mouse_down: false,
@@ -111,9 +112,10 @@ impl App {
             last_left_click_time: None,
             last_left_click_screen_pos: None,
             selected_element_index: None,
-            ground_plane: GroundPlaneConfig::default(),
             screenshot_pending: None,
             screenshot_counter: 0,
+            transparent_background: false,
+            ortho_world_height: None,
             camera_settings: polyscope_ui::CameraSettings::default(),
             scene_extents: polyscope_ui::SceneExtents::default(),
             appearance_settings: polyscope_ui::AppearanceSettings::default(),
@@ -125,9 +127,9 @@ impl App {
             selection_info: polyscope_ui::SelectionInfo::default(),
             slice_plane_selection: polyscope_ui::SlicePlaneSelectionInfo::default(),
             transform_gizmo: polyscope_ui::TransformGizmo::new(),
-            tone_mapping_settings: polyscope_ui::ToneMappingSettings::default(),
             material_load_state: polyscope_ui::MaterialLoadState::default(),
             camera_fitted: false,
+            camera_modes_generation: 0,
             keys_down: HashSet::new(),
             last_frame_time: None,
         }
@@ -135,18 +137,41 @@ impl App {
 
     /// Requests a screenshot with an auto-generated filename.
     pub fn request_auto_screenshot(&mut self) {
-        let filename = format!("screenshot_{:04}.png", self.screenshot_counter);
-        self.screenshot_counter += 1;
+        let filename = crate::screenshot::next_auto_screenshot_path(&mut self.screenshot_counter);
         self.screenshot_pending = Some(filename);
     }
 }
 
+/// Returns the camera to the home view set with `set_home_view`, or else
+/// fits it to the scene bounding box and restores the default field of view.
+pub(super) fn reset_view(engine: &mut RenderEngine) {
+    if let Some(camera) = crate::view::home_camera() {
+        let aspect_ratio = engine.camera.aspect_ratio;
+        engine.camera = camera;
+        engine.camera.aspect_ratio = aspect_ratio;
+        return;
+    }
+    let (min, max) = crate::with_context(|ctx| ctx.bounding_box);
+    if min.x.is_finite() && max.x.is_finite() && (max - min).length() > 0.0 {
+        engine.camera.look_at_box(min, max);
+        engine.camera.fov = std::f32::consts::FRAC_PI_4; // Reset FOV to default 45°
+    }
+}
+
 impl Default for App {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl Drop for App {
+    fn drop(&mut self) {
+        if let Some(window) = &self.window {
+            crate::redraw::clear_redraw_window(window);
+        }
+    }
+}
+
 /// Runs the polyscope application.
 pub fn run_app() {
     let event_loop = EventLoop::new().expect("failed to create event loop");
@@ -21,6 +21,81 @@ impl App {
         Some((type_name.to_string(), name.to_string(), local_index))
     }
 
+    /// Resolves the structure element at a pixel from the last pick pass.
+    ///
+    /// The element comes from the GPU pick buffer; the world-space hit
+    /// position is then refined along the camera ray through the pixel.
+    pub(crate) fn resolve_pick_at(&self, x: f32, y: f32) -> Option<crate::PickResult> {
+        let engine = self.engine.as_ref()?;
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        let (type_name, name, element_index) = self.gpu_pick_at(x as u32, y as u32)?;
+
+        // For VolumeGrid, pick name is "gridname/quantityname"
+        let structure_name = if type_name == "VolumeGrid" {
+            name.split('/').next().unwrap_or(&name).to_string()
+        } else {
+            name.clone()
+        };
+        let visible = crate::with_context(|ctx| {
+            ctx.registry
+                .get(&type_name, &structure_name)
+                .is_some_and(|s| ctx.is_structure_visible(s))
+        });
+        if !visible {
+            return None;
+        }
+
+        let (ray_origin, ray_dir) = self.screen_ray(
+            glam::Vec2::new(x, y),
+            engine.width,
+            engine.height,
+            &engine.camera,
+        )?;
+
+        let mut barycentric = None;
+        let t = match type_name.as_str() {
+            "PointCloud" => self.pick_point_cloud_at_ray(ray_origin, ray_dir, &name, element_index),
+            "CurveNetwork" => {
+                self.pick_curve_network_edge_at_ray(ray_origin, ray_dir, &name, element_index)
+            }
+            "SurfaceMesh" => self
+                .pick_surface_mesh_face_at_ray(ray_origin, ray_dir, &name, element_index)
+                .map(|(pos, bary)| {
+                    barycentric = Some(bary);
+                    (pos - ray_origin).length()
+                }),
+            _ => {
+                let plane_params = crate::with_context(|ctx| {
+                    ctx.slice_planes()
+                        .filter(|p| p.is_enabled())
+                        .map(|p| (p.origin(), p.kept_normal()))
+                        .collect::<Vec<_>>()
+                });
+                self.pick_structure_at_ray(ray_origin, ray_dir, &plane_params)
+                    .filter(|(_, hit_name, _)| *hit_name == structure_name)
+                    .map(|(_, _, t)| t)
+            }
+        };
+        // Fall back to the depth of the camera target if the ray misses
+        let t = t.unwrap_or_else(|| (engine.camera.target - ray_origin).dot(ray_dir));
+
+        Some(crate::surface_mesh::with_world_position_f64(
+            crate::PickResult {
+                barycentric,
+                ..crate::PickResult::new(
+                    type_name,
+                    structure_name,
+                    element_index as usize,
+                    ray_origin + ray_dir * t,
+                    t,
+                )
+            },
+        ))
+    }
+
     pub(super) fn screen_ray(
         &self,
         click_pos: glam::Vec2,
@@ -117,27 +192,7 @@ impl App {
         v1: Vec3,
         v2: Vec3,
     ) -> Option<f32> {
-        let eps = 1e-6;
-        let edge1 = v1 - v0;
-        let edge2 = v2 - v0;
-        let h = ray_dir.cross(edge2);
-        let a = edge1.dot(h);
-        if a.abs() < eps {
-            return None;
-        }
-        let f = 1.0 / a;
-        let s = ray_origin - v0;
-        let u = f * s.dot(h);
-        if !(0.0..=1.0).contains(&u) {
-            return None;
-        }
-        let q = s.cross(edge1);
-        let v = f * ray_dir.dot(q);
-        if v < 0.0 || u + v > 1.0 {
-            return None;
-        }
-        let t = f * edge2.dot(q);
-        if t > eps { Some(t) } else { None }
+        polyscope_render::ray_triangle_intersection(ray_origin, ray_dir, v0, v1, v2).map(|(t, _)| t)
     }
 
     pub(super) fn ray_segment_closest_t(
@@ -309,6 +364,40 @@ impl App {
         })
     }
 
+    /// Intersects a ray with one face of a surface mesh.
+    ///
+    /// Returns the world-space hit position and the barycentric weights within
+    /// the hit triangle of the face's triangulation.
+    pub(super) fn pick_surface_mesh_face_at_ray(
+        &self,
+        ray_origin: Vec3,
+        ray_dir: Vec3,
+        name: &str,
+        face_index: u32,
+    ) -> Option<(Vec3, Vec3)> {
+        crate::with_context(|ctx| {
+            let structure = ctx.registry.get("SurfaceMesh", name)?;
+            let mesh = structure.as_any().downcast_ref::<SurfaceMesh>()?;
+            let range = mesh.face_to_tri_range().get(face_index as usize)?.clone();
+            let model = structure.transform();
+            let vertices = mesh.vertices();
+
+            let mut best: Option<(f32, Vec3)> = None;
+            for tri in &mesh.triangulation()[range] {
+                let [v0, v1, v2] =
+                    tri.map(|i| (model * vertices[i as usize].extend(1.0)).truncate());
+                if let Some((t, bary)) =
+                    polyscope_render::ray_triangle_intersection(ray_origin, ray_dir, v0, v1, v2)
+                {
+                    if best.is_none_or(|(best_t, _)| t < best_t) {
+                        best = Some((t, bary));
+                    }
+                }
+            }
+            best.map(|(t, bary)| (ray_origin + ray_dir * t, bary))
+        })
+    }
+
     pub(super) fn pick_curve_network_edge_at_ray(
         &self,
         ray_origin: Vec3,
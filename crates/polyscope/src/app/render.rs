@@ -1,12 +1,9 @@
 use super::{
-    App, CurveNetwork, GroundPlaneMode, PointCloud, ScreenDescriptor, Structure, SurfaceMesh, Vec3,
-    VolumeGrid, VolumeMesh, reflection, render_scene,
+    App, CurveNetwork, PointCloud, ScreenDescriptor, Structure, SurfaceMesh, Vec3, VolumeGrid,
+    VolumeMesh, reflection, render_scene,
 };
 use polyscope_core::quantity::Quantity;
 use polyscope_core::structure::HasQuantities;
-use polyscope_render::{
-    GridcubeRenderData, GridcubeUniforms, IsosurfaceRenderData, SimpleMeshUniforms,
-};
 use polyscope_structures::volume_grid::{
     VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity, VolumeGridVizMode,
 };
@@ -27,12 +24,20 @@ impl App {
 
         // Auto-fit camera to scene on first render with structures
         self.camera_fitted = super::render_init::auto_fit_camera(engine, self.camera_fitted);
+        let smoothing = crate::with_context(|ctx| ctx.options.camera_smoothing);
+        if super::render_init::apply_view_requests(engine, smoothing) {
+            self.camera_fitted = true;
+        }
+        crate::view::apply_camera_modes(&mut engine.camera, &mut self.camera_modes_generation);
 
         // Update camera flight animation (before uniforms so interpolated position is used)
         engine.camera.update_flight();
+        crate::view::record_camera(&engine.camera);
 
         // Drain deferred material load queue
         super::render_init::drain_material_queue(engine);
+        super::render_init::sync_color_maps(engine);
+        super::render_init::sync_antialiasing(engine);
 
         // Update camera and slice plane uniforms
         super::render_init::update_uniforms(engine);
@@ -40,211 +45,24 @@ impl App {
         // Initialize GPU resources for structures (shared between windowed and headless)
         super::render_init::init_structure_gpu_resources(engine);
 
-        // Initialize windowed-specific GPU resources (pick resources and VolumeGrid quantities)
-        // Collect deferred mesh registrations (from "Register as Surface Mesh" button)
+        // Initialize pick resources (shared with headless picking)
+        super::render_pick::init_pick_resources(engine);
+
+        // Windowed-only VolumeGrid extras: gridcube picking and deferred mesh
+        // registrations (from "Register as Surface Mesh" button)
         let mut meshes_to_register: Vec<(String, Vec<Vec3>, Vec<[u32; 3]>)> = Vec::new();
         crate::with_context_mut(|ctx| {
             for structure in ctx.registry.iter_mut() {
-                // PointCloud: windowed-only pick resources
-                if structure.type_name() == "PointCloud" {
-                    let structure_name = structure.name().to_string();
-                    if let Some(pc) = structure.as_any_mut().downcast_mut::<PointCloud>() {
-                        // Initialize pick resources (after render data init by shared function)
-                        if pc.pick_bind_group().is_none() && pc.render_data().is_some() {
-                            let num_points = pc.points().len() as u32;
-                            let global_start =
-                                engine.assign_pick_range("PointCloud", &structure_name, num_points);
-                            pc.init_pick_resources(
-                                &engine.device,
-                                engine.pick_bind_group_layout(),
-                                engine.camera_buffer(),
-                                global_start,
-                            );
-                        }
-                    }
-                }
-
-                // SurfaceMesh: windowed-only pick resources
-                if structure.type_name() == "SurfaceMesh" {
-                    if let Some(mesh) = structure.as_any_mut().downcast_mut::<SurfaceMesh>() {
-                        // Initialize pick resources (after render data init by shared function)
-                        if mesh.pick_bind_group().is_none() && mesh.render_data().is_some() {
-                            let num_faces = mesh.num_faces() as u32;
-                            let global_start =
-                                engine.assign_pick_range("SurfaceMesh", mesh.name(), num_faces);
-                            mesh.init_pick_resources(
-                                &engine.device,
-                                engine.mesh_pick_bind_group_layout(),
-                                engine.camera_buffer(),
-                                global_start,
-                            );
-                        }
-                    }
-                }
-
-                // CurveNetwork: windowed-only pick resources (edge and tube)
-                if structure.type_name() == "CurveNetwork" {
-                    if let Some(cn) = structure.as_any_mut().downcast_mut::<CurveNetwork>() {
-                        // Initialize pick resources (after render data init by shared function)
-                        if cn.pick_bind_group().is_none() && cn.render_data().is_some() {
-                            // Initialize curve network pick pipeline if not done
-                            if !engine.has_curve_network_pick_pipeline() {
-                                engine.init_curve_network_pick_pipeline();
-                            }
-                            let num_edges = cn.num_edges() as u32;
-                            let global_start =
-                                engine.assign_pick_range("CurveNetwork", cn.name(), num_edges);
-                            cn.init_pick_resources(
-                                &engine.device,
-                                engine.pick_bind_group_layout(),
-                                engine.camera_buffer(),
-                                global_start,
-                            );
-                        }
-
-                        // Initialize tube pick resources (for tube render mode)
-                        // This provides a larger clickable area using ray-cylinder intersection
-                        if !cn.has_tube_pick_resources() && cn.render_data().is_some() {
-                            // Initialize tube pick pipeline if not done
-                            if !engine.has_curve_network_tube_pick_pipeline() {
-                                engine.init_curve_network_tube_pick_pipeline();
-                            }
-                            cn.init_tube_pick_resources(
-                                &engine.device,
-                                engine.curve_network_tube_pick_bind_group_layout(),
-                                engine.camera_buffer(),
-                            );
-                        }
-                    }
-                }
-
-                // CameraView init moved to shared function
-
-                // VolumeGrid: windowed-only quantity initialization (gridcube/isosurface)
                 if structure.type_name() == "VolumeGrid" {
                     if let Some(vg) = structure.as_any_mut().downcast_mut::<VolumeGrid>() {
-                        // Base wireframe render data init moved to shared function
-                        // Initialize GPU resources for enabled scalar quantities (windowed-only)
-                        let grid_spacing = vg.grid_spacing();
                         let cube_size_factor = vg.cube_size_factor();
                         let transform = vg.transform();
-                        let node_dim = vg.node_dim();
-                        let bound_min = vg.bound_min();
-                        let bound_max = vg.bound_max();
 
                         for quantity in vg.quantities_mut() {
                             if !quantity.is_enabled() {
                                 continue;
                             }
 
-                            // Node scalar quantities: gridcube + isosurface
-                            if let Some(nsq) = quantity
-                                .as_any_mut()
-                                .downcast_mut::<VolumeGridNodeScalarQuantity>()
-                            {
-                                match nsq.viz_mode() {
-                                    VolumeGridVizMode::Gridcube => {
-                                        if nsq.gridcube_render_data().is_none()
-                                            || nsq.gridcube_dirty()
-                                        {
-                                            // Generate node center positions
-                                            let mut centers = Vec::new();
-                                            let cell_dim_f = Vec3::new(
-                                                (node_dim.x - 1).max(1) as f32,
-                                                (node_dim.y - 1).max(1) as f32,
-                                                (node_dim.z - 1).max(1) as f32,
-                                            );
-                                            for k in 0..node_dim.z {
-                                                for j in 0..node_dim.y {
-                                                    for i in 0..node_dim.x {
-                                                        let t =
-                                                            Vec3::new(i as f32, j as f32, k as f32)
-                                                                / cell_dim_f;
-                                                        centers.push(
-                                                            bound_min + t * (bound_max - bound_min),
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                            let half_size = grid_spacing.min_element()
-                                                * 0.5
-                                                * cube_size_factor.max(0.5);
-
-                                            // Sample colormap
-                                            let colormap_colors: Vec<Vec3> = if let Some(cm) =
-                                                engine.color_maps.get(nsq.color_map())
-                                            {
-                                                cm.colors.clone()
-                                            } else {
-                                                vec![Vec3::ZERO, Vec3::ONE]
-                                            };
-
-                                            let data = GridcubeRenderData::new(
-                                                &engine.device,
-                                                &engine.queue,
-                                                engine.gridcube_bind_group_layout(),
-                                                engine.camera_buffer(),
-                                                &centers,
-                                                half_size,
-                                                nsq.values(),
-                                                &colormap_colors,
-                                            );
-                                            nsq.set_gridcube_render_data(data);
-                                        }
-                                    }
-                                    VolumeGridVizMode::Isosurface => {
-                                        if nsq.isosurface_render_data().is_none()
-                                            || nsq.isosurface_dirty()
-                                        {
-                                            let mesh = nsq.extract_isosurface();
-                                            if mesh.vertices.is_empty() {
-                                                // Isovalue outside data range — clear old surface
-                                                nsq.clear_isosurface_render_data();
-                                            } else {
-                                                let vertices = mesh.vertices.clone();
-                                                let normals = mesh.normals.clone();
-                                                let indices = mesh.indices.clone();
-                                                let data = IsosurfaceRenderData::new(
-                                                    &engine.device,
-                                                    engine.simple_mesh_bind_group_layout(),
-                                                    engine.camera_buffer(),
-                                                    &vertices,
-                                                    &normals,
-                                                    &indices,
-                                                );
-                                                nsq.set_isosurface_render_data(data);
-                                            }
-                                        }
-                                    }
-                                }
-
-                                // Update uniforms every frame (model matrix may change)
-                                if let Some(rd) = nsq.gridcube_render_data() {
-                                    let (data_min, data_max) = nsq.data_range();
-                                    let uniforms = GridcubeUniforms {
-                                        model: transform.to_cols_array_2d(),
-                                        cube_size_factor: cube_size_factor.max(0.5),
-                                        data_min,
-                                        data_max,
-                                        transparency: 0.0,
-                                        slice_planes_enabled: 0,
-                                        ..Default::default()
-                                    };
-                                    rd.update_uniforms(&engine.queue, &uniforms);
-                                }
-                                if let Some(rd) = nsq.isosurface_render_data() {
-                                    let color = nsq.isosurface_color();
-                                    let uniforms = SimpleMeshUniforms {
-                                        model: transform.to_cols_array_2d(),
-                                        base_color: [color.x, color.y, color.z, 1.0],
-                                        transparency: 0.0,
-                                        slice_planes_enabled: 0,
-                                        ..Default::default()
-                                    };
-                                    rd.update_uniforms(&engine.queue, &uniforms);
-                                }
-                            }
-
                             // Check for "Register as Surface Mesh" request
                             if let Some(nsq) = quantity
                                 .as_any_mut()
@@ -264,73 +82,6 @@ impl App {
                                     nsq.clear_register_as_mesh_request();
                                 }
                             }
-
-                            // Cell scalar quantities: gridcube only
-                            if let Some(csq) = quantity
-                                .as_any_mut()
-                                .downcast_mut::<VolumeGridCellScalarQuantity>()
-                            {
-                                if csq.gridcube_render_data().is_none() || csq.gridcube_dirty() {
-                                    let cell_dim = node_dim.saturating_sub(glam::UVec3::ONE);
-                                    let cell_spacing = (bound_max - bound_min)
-                                        / Vec3::new(
-                                            cell_dim.x.max(1) as f32,
-                                            cell_dim.y.max(1) as f32,
-                                            cell_dim.z.max(1) as f32,
-                                        );
-                                    let half_cell_spacing = cell_spacing * 0.5;
-
-                                    // Generate cell center positions
-                                    let mut centers = Vec::new();
-                                    for k in 0..cell_dim.z {
-                                        for j in 0..cell_dim.y {
-                                            for i in 0..cell_dim.x {
-                                                let node_pos = bound_min
-                                                    + Vec3::new(i as f32, j as f32, k as f32)
-                                                        * cell_spacing;
-                                                centers.push(node_pos + half_cell_spacing);
-                                            }
-                                        }
-                                    }
-                                    let half_size = cell_spacing.min_element()
-                                        * 0.5
-                                        * cube_size_factor.max(0.5);
-
-                                    let colormap_colors: Vec<Vec3> =
-                                        if let Some(cm) = engine.color_maps.get(csq.color_map()) {
-                                            cm.colors.clone()
-                                        } else {
-                                            vec![Vec3::ZERO, Vec3::ONE]
-                                        };
-
-                                    let data = GridcubeRenderData::new(
-                                        &engine.device,
-                                        &engine.queue,
-                                        engine.gridcube_bind_group_layout(),
-                                        engine.camera_buffer(),
-                                        &centers,
-                                        half_size,
-                                        csq.values(),
-                                        &colormap_colors,
-                                    );
-                                    csq.set_gridcube_render_data(data);
-                                }
-
-                                // Update uniforms every frame
-                                if let Some(rd) = csq.gridcube_render_data() {
-                                    let (data_min, data_max) = csq.data_range();
-                                    let uniforms = GridcubeUniforms {
-                                        model: transform.to_cols_array_2d(),
-                                        cube_size_factor: cube_size_factor.max(0.5),
-                                        data_min,
-                                        data_max,
-                                        transparency: 0.0,
-                                        slice_planes_enabled: 0,
-                                        ..Default::default()
-                                    };
-                                    rd.update_uniforms(&engine.queue, &uniforms);
-                                }
-                            }
                         }
 
                         // --- VolumeGrid pick initialization ---
@@ -427,28 +178,6 @@ impl App {
                         }
                     }
                 }
-
-                // VolumeMesh: windowed-only pick resources
-                // (slice plane culling logic moved to shared function)
-                if structure.type_name() == "VolumeMesh" {
-                    if let Some(vm) = structure.as_any_mut().downcast_mut::<VolumeMesh>() {
-                        // Initialize pick resources (after render data init by shared function)
-                        if vm.pick_bind_group().is_none() && vm.render_data().is_some() {
-                            if !engine.has_mesh_pick_pipeline() {
-                                engine.init_mesh_pick_pipeline();
-                            }
-                            let num_cells = vm.num_cells() as u32;
-                            let global_start =
-                                engine.assign_pick_range("VolumeMesh", vm.name(), num_cells);
-                            vm.init_pick_resources(
-                                &engine.device,
-                                engine.mesh_pick_bind_group_layout(),
-                                engine.camera_buffer(),
-                                global_start,
-                            );
-                        }
-                    }
-                }
             }
         });
 
@@ -465,155 +194,7 @@ impl App {
         }
 
         // Render pick pass (GPU picking)
-        {
-            let mut encoder =
-                engine
-                    .device
-                    .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                        label: Some("pick pass encoder"),
-                    });
-
-            if let Some(mut pick_pass) = engine.begin_pick_pass(&mut encoder) {
-                // Draw point clouds to pick buffer
-                pick_pass.set_pipeline(engine.point_pick_pipeline());
-
-                crate::with_context(|ctx| {
-                    for structure in ctx.registry.iter() {
-                        if !ctx.is_structure_visible(structure) {
-                            continue;
-                        }
-                        if structure.type_name() == "PointCloud" {
-                            if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
-                                if let (Some(pick_bind_group), Some(render_data)) =
-                                    (pc.pick_bind_group(), pc.render_data())
-                                {
-                                    pick_pass.set_bind_group(0, pick_bind_group, &[]);
-                                    // 6 vertices per quad, num_points instances
-                                    pick_pass.draw(0..6, 0..render_data.num_points);
-                                }
-                            }
-                        }
-                    }
-                });
-
-                // Draw curve networks to pick buffer
-                // Use tube picking (ray-cylinder) for all curve networks for better hit detection
-                crate::with_context(|ctx| {
-                    for structure in ctx.registry.iter() {
-                        if !ctx.is_structure_visible(structure) {
-                            continue;
-                        }
-                        if structure.type_name() == "CurveNetwork" {
-                            if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
-                                let Some(render_data) = cn.render_data() else {
-                                    continue;
-                                };
-
-                                // Use tube picking when available - provides larger clickable area
-                                if engine.has_curve_network_tube_pick_pipeline() {
-                                    if let (Some(tube_bind_group), Some(vertex_buffer)) = (
-                                        cn.tube_pick_bind_group(),
-                                        render_data.generated_vertex_buffer.as_ref(),
-                                    ) {
-                                        // Use tube-based picking (ray-cylinder intersection)
-                                        pick_pass.set_pipeline(
-                                            engine.curve_network_tube_pick_pipeline(),
-                                        );
-                                        pick_pass.set_bind_group(0, tube_bind_group, &[]);
-                                        pick_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-                                        // 36 vertices per edge (bounding box triangles)
-                                        pick_pass.draw(0..render_data.num_edges * 36, 0..1);
-                                    }
-                                } else if engine.has_curve_network_pick_pipeline() {
-                                    // Fallback to line-based picking
-                                    if let Some(pick_bind_group) = cn.pick_bind_group() {
-                                        pick_pass
-                                            .set_pipeline(engine.curve_network_pick_pipeline());
-                                        pick_pass.set_bind_group(0, pick_bind_group, &[]);
-                                        // 2 vertices per edge (LineList topology)
-                                        pick_pass.draw(0..render_data.num_edges * 2, 0..1);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                });
-
-                // Draw surface meshes and volume meshes to pick buffer
-                // (both use the same mesh pick pipeline with face/cell index mapping)
-                if engine.has_mesh_pick_pipeline() {
-                    pick_pass.set_pipeline(engine.mesh_pick_pipeline());
-                    crate::with_context(|ctx| {
-                        for structure in ctx.registry.iter() {
-                            if !ctx.is_structure_visible(structure) {
-                                continue;
-                            }
-                            if structure.type_name() == "SurfaceMesh" {
-                                if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>()
-                                {
-                                    if let Some(pick_bind_group) = mesh.pick_bind_group() {
-                                        pick_pass.set_bind_group(0, pick_bind_group, &[]);
-                                        pick_pass.draw(0..mesh.num_triangulation_vertices(), 0..1);
-                                    }
-                                }
-                            }
-                            if structure.type_name() == "VolumeMesh" {
-                                if let Some(vm) = structure.as_any().downcast_ref::<VolumeMesh>() {
-                                    if let Some(pick_bind_group) = vm.pick_bind_group() {
-                                        pick_pass.set_bind_group(0, pick_bind_group, &[]);
-                                        pick_pass.draw(0..vm.num_render_vertices(), 0..1);
-                                    }
-                                }
-                            }
-                        }
-                    });
-                }
-
-                // --- VolumeGrid gridcube picking ---
-                if engine.has_gridcube_pick_pipeline() {
-                    pick_pass.set_pipeline(engine.gridcube_pick_pipeline());
-                    crate::with_context(|ctx| {
-                        for structure in ctx.registry.iter() {
-                            if !ctx.is_structure_visible(structure) {
-                                continue;
-                            }
-                            if structure.type_name() == "VolumeGrid" {
-                                if let Some(vg) = structure.as_any().downcast_ref::<VolumeGrid>() {
-                                    for quantity in vg.quantities() {
-                                        if !quantity.is_enabled() {
-                                            continue;
-                                        }
-                                        if let Some(nsq) = quantity
-                                            .as_any()
-                                            .downcast_ref::<VolumeGridNodeScalarQuantity>(
-                                        ) {
-                                            if nsq.viz_mode() == VolumeGridVizMode::Gridcube {
-                                                if let Some(pick_bg) = nsq.pick_bind_group() {
-                                                    pick_pass.set_bind_group(0, pick_bg, &[]);
-                                                    pick_pass
-                                                        .draw(0..nsq.pick_total_vertices(), 0..1);
-                                                }
-                                            }
-                                        }
-                                        if let Some(csq) = quantity
-                                            .as_any()
-                                            .downcast_ref::<VolumeGridCellScalarQuantity>(
-                                        ) {
-                                            if let Some(pick_bg) = csq.pick_bind_group() {
-                                                pick_pass.set_bind_group(0, pick_bg, &[]);
-                                                pick_pass.draw(0..csq.pick_total_vertices(), 0..1);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    });
-                }
-            }
-
-            engine.queue.submit(std::iter::once(encoder.finish()));
-        }
+        super::render_pick::render_pick_pass(engine);
 
         // Build UI (take engine/egui temporarily to satisfy borrow checker)
         let mut engine_temp = self.engine.take().unwrap();
@@ -664,68 +245,36 @@ impl App {
 
         // HDR texture is always available for scene rendering
         // Update tone mapping uniforms - use passthrough values if disabled
-        // Get SSAO settings from global options
-        let ssao_enabled = polyscope_core::with_context(|ctx| ctx.options.ssao.enabled);
-        engine.update_tone_mapping(
-            self.tone_mapping_settings.exposure,
-            self.tone_mapping_settings.white_level,
-            self.tone_mapping_settings.gamma,
-            ssao_enabled,
-        );
+        // Get tone mapping and SSAO settings from global options
+        let (tone_mapping, ssao_enabled, dof) = polyscope_core::with_context(|ctx| {
+            (
+                ctx.options.tone_mapping.clone(),
+                ctx.options.ssao.enabled,
+                ctx.options.dof.clone(),
+            )
+        });
+        engine.update_tone_mapping(&tone_mapping, ssao_enabled);
+        engine.update_dof(&dof);
 
-        // Store background color for use in render passes
-        let bg_r = f64::from(self.background_color.x);
-        let bg_g = f64::from(self.background_color.y);
-        let bg_b = f64::from(self.background_color.z);
+        // Background color for the clear in the first render pass
+        let background_color = super::render_init::background_color();
 
         // Store ground plane settings for later use
-        let gp_enabled = self.ground_plane.mode != GroundPlaneMode::None;
-        let gp_height_override = if self.ground_plane.height_is_relative {
-            None
-        } else {
-            Some(self.ground_plane.height)
-        };
-        // Shadow mode: 0=none (disabled), 1=shadow_only, 2=tile_with_shadow
-        let gp_shadow_mode = match self.ground_plane.mode {
-            GroundPlaneMode::None => 0u32,
-            GroundPlaneMode::ShadowOnly => 1u32,
-            // TileReflection also uses tile mode with shadows
-            GroundPlaneMode::Tile | GroundPlaneMode::TileReflection => 2u32,
-        };
+        let ground_plane = crate::with_context(|ctx| ctx.options.ground_plane.clone());
+        let gp_enabled = ground_plane.is_visible();
+        // Shadow mode: 0=none (tile only), 1=shadow_only, 2=tile_with_shadow
+        let gp_shadow_mode = ground_plane.shader_shadow_mode();
 
         // Compute pass for curve network tubes
-        {
-            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
-                label: Some("Curve Network Tube Compute Pass"),
-                timestamp_writes: None,
-            });
-
-            compute_pass.set_pipeline(engine.curve_network_tube_compute_pipeline());
-
-            crate::with_context(|ctx| {
-                for structure in ctx.registry.iter() {
-                    if !ctx.is_structure_visible(structure) {
-                        continue;
-                    }
-                    if structure.type_name() == "CurveNetwork" {
-                        if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
-                            if let Some(render_data) = cn.render_data() {
-                                if let Some(compute_bg) = &render_data.compute_bind_group {
-                                    compute_pass.set_bind_group(0, compute_bg, &[]);
-                                    let num_workgroups = render_data.num_edges.div_ceil(64);
-                                    compute_pass.dispatch_workgroups(num_workgroups, 1, 1);
-                                }
-                            }
-                        }
-                    }
-                }
-            });
-        }
-
-        // Shadow pass - render scene objects from light's perspective to shadow map
-        if let (Some(shadow_pipeline), Some(shadow_map_pass)) =
-            (engine.shadow_pipeline(), engine.shadow_map_pass())
-        {
+        render_scene::dispatch_curve_network_tubes(&mut encoder, engine);
+
+        // Shadow pass - render scene objects from light's perspective to shadow map.
+        // Shadows are only received by the ground plane, so skip it unless drawn.
+        if let (true, Some(shadow_pipeline), Some(shadow_map_pass)) = (
+            ground_plane.shadow_pass_enabled(),
+            engine.shadow_pipeline(),
+            engine.shadow_map_pass(),
+        ) {
             // Compute light matrix from scene bounds
             let (scene_center, scene_radius) =
                 crate::with_context(|ctx| (ctx.center(), ctx.length_scale * 5.0));
@@ -777,10 +326,11 @@ impl App {
             &mut encoder,
             &slice_planes,
             length_scale_for_planes,
-            [bg_r as f32, bg_g as f32, bg_b as f32],
+            background_color.to_array(),
         );
 
-        let use_depth_peel = self.appearance_settings.transparency_mode == 2;
+        let use_depth_peel = crate::with_context(|ctx| ctx.options.transparency_mode)
+            == polyscope_core::options::TransparencyMode::Pretty;
 
         // Render ground plane BEFORE surface mesh passes so transparent objects
         // composite correctly over the ground. Without this, either:
@@ -796,19 +346,14 @@ impl App {
             )
         });
 
-        if self.ground_plane.mode == GroundPlaneMode::TileReflection {
-            // Compute ground height
-            let ground_height = if self.ground_plane.height_is_relative {
-                scene_min_y - length_scale * 0.001
-            } else {
-                self.ground_plane.height
-            };
+        let ground_height = ground_plane.world_height(scene_min_y, length_scale);
 
+        if ground_plane.reflection_pass_enabled() {
             // Update reflection uniforms
             let reflection_matrix = reflection::ground_reflection_matrix(ground_height);
             engine.update_reflection(
                 reflection_matrix,
-                self.ground_plane.reflection_intensity,
+                ground_plane.reflection_intensity,
                 ground_height,
             );
 
@@ -829,8 +374,8 @@ impl App {
                 scene_center,
                 scene_min_y,
                 length_scale,
-                gp_height_override,
-                self.ground_plane.shadow_darkness,
+                Some(ground_height),
+                ground_plane.shadow_darkness,
                 gp_shadow_mode,
                 0.0, // No transparency - fully opaque ground
             );
@@ -869,6 +414,8 @@ impl App {
                         if !ctx.is_structure_visible(structure) {
                             continue;
                         }
+                        let slice_plane_mask =
+                            ctx.slice_plane_mask(structure.type_name(), structure.name());
                         // SurfaceMesh
                         if structure.type_name() == "SurfaceMesh" {
                             if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
@@ -881,6 +428,7 @@ impl App {
                                             &bind_group,
                                             mesh_data.vertex_count(),
                                             structure.material(),
+                                            slice_plane_mask,
                                         );
                                     }
                                 }
@@ -899,6 +447,7 @@ impl App {
                                             &bind_group,
                                             mesh_data.vertex_count(),
                                             structure.material(),
+                                            slice_plane_mask,
                                         );
                                     }
                                 }
@@ -914,8 +463,9 @@ impl App {
                                         engine.render_reflected_point_cloud(
                                             &mut render_pass,
                                             &bind_group,
-                                            pc_data.num_points,
+                                            pc.num_displayed_points(),
                                             structure.material(),
+                                            slice_plane_mask,
                                         );
                                     }
                                 }
@@ -933,6 +483,7 @@ impl App {
                                             &bind_group,
                                             cn_data,
                                             structure.material(),
+                                            slice_plane_mask,
                                         );
                                     }
                                 }
@@ -950,8 +501,8 @@ impl App {
                 scene_center,
                 scene_min_y,
                 length_scale,
-                gp_height_override,
-                self.ground_plane.shadow_darkness,
+                Some(ground_height),
+                ground_plane.shadow_darkness,
                 gp_shadow_mode,
                 0.0,
             );
@@ -1006,6 +557,13 @@ impl App {
                         if !ctx.is_structure_visible(structure) {
                             continue;
                         }
+                        let slice_plane_mask =
+                            ctx.slice_plane_mask(structure.type_name(), structure.name());
+                        prepass.set_bind_group(
+                            1,
+                            engine.slice_plane_bind_group_for(slice_plane_mask),
+                            &[],
+                        );
                         if structure.type_name() == "SurfaceMesh" {
                             if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
                                 if let Some(render_data) = mesh.render_data() {
@@ -1071,6 +629,9 @@ impl App {
             // Draw curve network edges (line mode), camera views, and volume grids
             render_scene::draw_curve_networks_and_lines(&mut render_pass, engine);
 
+            // Draw camera view images
+            render_scene::draw_camera_view_images(&mut render_pass, engine);
+
             // Draw curve network tubes (tube mode)
             render_scene::draw_curve_network_tubes(&mut render_pass, engine);
 
@@ -1140,25 +701,27 @@ impl App {
                 // All surface meshes go through depth peeling for color, so we only
                 // write depth+normals here for SSAO regardless of transparency.
                 crate::with_context(|ctx| {
-                    for structure in ctx.registry.iter() {
-                        if !ctx.is_structure_visible(structure) {
-                            continue;
-                        }
-                        if structure.type_name() == "SurfaceMesh" {
-                            if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
-                                if let Some(render_data) = mesh.render_data() {
-                                    render_pass.set_bind_group(
-                                        2,
-                                        engine.matcap_bind_group_for(structure.material()),
-                                        &[],
-                                    );
-                                    render_pass.set_bind_group(0, &render_data.bind_group, &[]);
-                                    render_pass.set_index_buffer(
-                                        render_data.index_buffer.slice(..),
-                                        wgpu::IndexFormat::Uint32,
-                                    );
-                                    render_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
-                                }
+                    for structure in render_scene::draw_sequence(ctx, &["SurfaceMesh"]) {
+                        let slice_plane_mask =
+                            ctx.slice_plane_mask(structure.type_name(), structure.name());
+                        render_pass.set_bind_group(
+                            1,
+                            engine.slice_plane_bind_group_for(slice_plane_mask),
+                            &[],
+                        );
+                        if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
+                            if let Some(render_data) = mesh.render_data() {
+                                render_pass.set_bind_group(
+                                    2,
+                                    engine.matcap_bind_group_for(structure.material()),
+                                    &[],
+                                );
+                                render_pass.set_bind_group(0, &render_data.bind_group, &[]);
+                                render_pass.set_index_buffer(
+                                    render_data.index_buffer.slice(..),
+                                    wgpu::IndexFormat::Uint32,
+                                );
+                                render_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
                             }
                         }
                     }
@@ -1167,29 +730,31 @@ impl App {
                 // Volume meshes: full color/normal pass (not peeled)
                 render_pass.set_pipeline(mesh_pipeline);
                 crate::with_context(|ctx| {
-                    for structure in ctx.registry.iter() {
-                        if !ctx.is_structure_visible(structure) {
-                            continue;
-                        }
-                        if structure.type_name() == "VolumeMesh" {
-                            if let Some(vm) = structure.as_any().downcast_ref::<VolumeMesh>() {
-                                // Render exterior faces (includes cell culling when slice plane is active)
-                                if let Some(render_data) = vm.render_data() {
-                                    render_pass.set_bind_group(
-                                        2,
-                                        engine.matcap_bind_group_for(structure.material()),
-                                        &[],
-                                    );
-                                    render_pass.set_bind_group(0, &render_data.bind_group, &[]);
-                                    render_pass.set_index_buffer(
-                                        render_data.index_buffer.slice(..),
-                                        wgpu::IndexFormat::Uint32,
-                                    );
-                                    render_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
-                                }
-                                // Note: No slice cap geometry needed - we use cell culling
-                                // which shows whole cells instead of cross-section caps
+                    for structure in render_scene::draw_sequence(ctx, &["VolumeMesh"]) {
+                        let slice_plane_mask =
+                            ctx.slice_plane_mask(structure.type_name(), structure.name());
+                        render_pass.set_bind_group(
+                            1,
+                            engine.slice_plane_bind_group_for(slice_plane_mask),
+                            &[],
+                        );
+                        if let Some(vm) = structure.as_any().downcast_ref::<VolumeMesh>() {
+                            // Render exterior faces (includes cell culling when slice plane is active)
+                            if let Some(render_data) = vm.render_data() {
+                                render_pass.set_bind_group(
+                                    2,
+                                    engine.matcap_bind_group_for(structure.material()),
+                                    &[],
+                                );
+                                render_pass.set_bind_group(0, &render_data.bind_group, &[]);
+                                render_pass.set_index_buffer(
+                                    render_data.index_buffer.slice(..),
+                                    wgpu::IndexFormat::Uint32,
+                                );
+                                render_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
                             }
+                            // Note: No slice cap geometry needed - we use cell culling
+                            // which shows whole cells instead of cross-section caps
                         }
                     }
                 });
@@ -1315,35 +880,28 @@ impl App {
                         peel_pass.set_bind_group(3, peel.peel_bind_group(), &[]);
 
                         crate::with_context(|ctx| {
-                            for structure in ctx.registry.iter() {
-                                if !ctx.is_structure_visible(structure) {
-                                    continue;
-                                }
-                                if structure.type_name() == "SurfaceMesh" {
-                                    if let Some(mesh) =
-                                        structure.as_any().downcast_ref::<SurfaceMesh>()
-                                    {
-                                        if let Some(render_data) = mesh.render_data() {
-                                            peel_pass.set_bind_group(
-                                                2,
-                                                engine.matcap_bind_group_for(structure.material()),
-                                                &[],
-                                            );
-                                            peel_pass.set_bind_group(
-                                                0,
-                                                &render_data.bind_group,
-                                                &[],
-                                            );
-                                            peel_pass.set_index_buffer(
-                                                render_data.index_buffer.slice(..),
-                                                wgpu::IndexFormat::Uint32,
-                                            );
-                                            peel_pass.draw_indexed(
-                                                0..render_data.num_indices,
-                                                0,
-                                                0..1,
-                                            );
-                                        }
+                            for structure in render_scene::draw_sequence(ctx, &["SurfaceMesh"]) {
+                                let slice_plane_mask =
+                                    ctx.slice_plane_mask(structure.type_name(), structure.name());
+                                peel_pass.set_bind_group(
+                                    1,
+                                    engine.slice_plane_bind_group_for(slice_plane_mask),
+                                    &[],
+                                );
+                                if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>()
+                                {
+                                    if let Some(render_data) = mesh.render_data() {
+                                        peel_pass.set_bind_group(
+                                            2,
+                                            engine.matcap_bind_group_for(structure.material()),
+                                            &[],
+                                        );
+                                        peel_pass.set_bind_group(0, &render_data.bind_group, &[]);
+                                        peel_pass.set_index_buffer(
+                                            render_data.index_buffer.slice(..),
+                                            wgpu::IndexFormat::Uint32,
+                                        );
+                                        peel_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
                                     }
                                 }
                             }
@@ -1379,7 +937,8 @@ impl App {
             });
         }
 
-        // Apply tone mapping from HDR to surface (always runs, uses passthrough if disabled)
+        // Apply tone mapping from HDR to surface (always runs, uses passthrough if disabled),
+        // followed by depth of field when enabled
         engine.render_tone_mapping(&mut encoder, &view);
 
         // Render egui on top (directly to surface, after tone mapping)
@@ -1407,9 +966,7 @@ impl App {
         // Handle screenshot request from public API (screenshot() / screenshot_to_file())
         if let Some(request) = crate::take_screenshot_request() {
             let filename = request.filename.unwrap_or_else(|| {
-                let name = format!("screenshot_{:04}.png", self.screenshot_counter);
-                self.screenshot_counter += 1;
-                name
+                crate::screenshot::next_auto_screenshot_path(&mut self.screenshot_counter)
             });
             self.capture_screenshot(filename);
         }
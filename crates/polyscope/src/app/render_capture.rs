@@ -1,6 +1,6 @@
 //! Screenshot capture and headless rendering.
 
-use super::{App, GroundPlaneMode, render_scene};
+use super::{App, render_scene};
 
 impl App {
     /// Captures a screenshot by re-rendering to a dedicated texture.
@@ -20,6 +20,8 @@ impl App {
                 label: Some("screenshot encoder"),
             });
 
+        render_scene::dispatch_curve_network_tubes(&mut encoder, engine);
+
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("screenshot render pass"),
@@ -27,12 +29,9 @@ impl App {
                     view: &screenshot_view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: f64::from(self.background_color.x),
-                            g: f64::from(self.background_color.y),
-                            b: f64::from(self.background_color.z),
-                            a: 1.0,
-                        }),
+                        load: wgpu::LoadOp::Clear(super::render_init::screenshot_clear_color(
+                            false,
+                        )),
                         store: wgpu::StoreOp::Store,
                     },
                     depth_slice: None,
@@ -59,6 +58,13 @@ impl App {
 
             // Draw curve networks, camera views, and volume grids
             render_scene::draw_curve_networks_and_lines(&mut render_pass, engine);
+
+            // Draw camera view images
+            render_scene::draw_camera_view_images(&mut render_pass, engine);
+
+            // Draw curve network tubes and node spheres (tube mode)
+            render_scene::draw_curve_network_tubes(&mut render_pass, engine);
+            render_scene::draw_curve_network_nodes(&mut render_pass, engine);
         }
 
         // Render ground plane for screenshot
@@ -70,31 +76,23 @@ impl App {
                 ctx.length_scale,
             )
         });
-        let height_override = if self.ground_plane.height_is_relative {
-            None
+        let ground_plane = crate::with_context(|ctx| ctx.options.ground_plane.clone());
+        let height_override = Some(ground_plane.world_height(scene_min_y, length_scale));
+        let screenshot_gp_shadow_mode = ground_plane.shader_shadow_mode();
+        let screenshot_reflection_intensity = if ground_plane.reflection_pass_enabled() {
+            ground_plane.reflection_intensity
         } else {
-            Some(self.ground_plane.height)
+            0.0
         };
-        let screenshot_gp_shadow_mode = match self.ground_plane.mode {
-            GroundPlaneMode::None => 0u32,
-            GroundPlaneMode::ShadowOnly => 1u32,
-            GroundPlaneMode::Tile | GroundPlaneMode::TileReflection => 2u32,
-        };
-        let screenshot_reflection_intensity =
-            if self.ground_plane.mode == GroundPlaneMode::TileReflection {
-                self.ground_plane.reflection_intensity
-            } else {
-                0.0
-            };
         engine.render_ground_plane(
             &mut encoder,
             &screenshot_view,
-            self.ground_plane.mode != GroundPlaneMode::None,
+            ground_plane.is_visible(),
             scene_center,
             scene_min_y,
             length_scale,
             height_override,
-            self.ground_plane.shadow_darkness,
+            ground_plane.shadow_darkness,
             screenshot_gp_shadow_mode,
             screenshot_reflection_intensity,
         );
@@ -126,30 +124,99 @@ impl App {
     /// Renders a single frame in headless mode (no window, no egui).
     ///
     /// Initializes GPU resources for all structures, updates uniforms,
-    /// and renders the scene to the screenshot target texture.
+    /// and renders the scene to the screenshot target texture. The camera
+    /// starts from the last viewer frame if a window has been shown,
+    /// otherwise it is fitted to the scene; pending view requests apply on top.
     /// Call `capture_to_buffer()` after this to retrieve pixel data.
     pub(crate) fn render_frame_headless(&mut self) {
         let Some(engine) = &mut self.engine else {
             return;
         };
 
-        // Auto-fit camera to scene
-        self.camera_fitted = super::render_init::auto_fit_camera(engine, self.camera_fitted);
+        // Start from the last shown view, else fit the camera to the scene
+        if use_last_camera(engine) {
+            self.camera_fitted = true;
+        } else {
+            self.camera_fitted = super::render_init::auto_fit_camera(engine, self.camera_fitted);
+        }
+        // A single headless frame cannot animate, so framing always jumps
+        if super::render_init::apply_view_requests(engine, 0.0) {
+            self.camera_fitted = true;
+        }
+        crate::view::apply_camera_modes(&mut engine.camera, &mut self.camera_modes_generation);
+        if let Some(world_height) = self.ortho_world_height {
+            engine.camera.projection_mode = polyscope_render::ProjectionMode::Orthographic;
+            engine.camera.ortho_scale = world_height * 0.5;
+        }
+        super::render_init::sync_antialiasing(engine);
 
-        // Drain deferred material load queue
-        super::render_init::drain_material_queue(engine);
+        prepare_frame_headless(engine);
 
-        // Update camera and slice plane uniforms
-        super::render_init::update_uniforms(engine);
+        // Now render to screenshot target (reuses existing capture_screenshot rendering)
+        self.capture_screenshot_headless();
+    }
 
-        // Initialize GPU resources for all structures (shared function)
-        super::render_init::init_structure_gpu_resources(engine);
+    /// Renders the scene geometry from the engine's current camera and reads
+    /// back linear depth (see `RenderEngine::capture_linear_depth`).
+    ///
+    /// The camera is used as is: no auto-fit and no pending view requests.
+    /// The ground plane is not drawn.
+    pub(crate) fn render_depth_headless(&mut self) -> crate::Result<Vec<f32>> {
+        let engine = self
+            .engine
+            .as_mut()
+            .ok_or_else(|| crate::PolyscopeError::RenderError("Engine not initialized".into()))?;
 
-        // Update GPU buffers (headless: no pick uniforms)
-        super::render_init::update_gpu_buffers(engine, false);
+        prepare_frame_headless(engine);
 
-        // Now render to screenshot target (reuses existing capture_screenshot rendering)
-        self.capture_screenshot_headless();
+        let screenshot_view = engine.create_screenshot_target();
+        let mut encoder = engine
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("headless depth render encoder"),
+            });
+        draw_geometry_headless(
+            engine,
+            &mut encoder,
+            &screenshot_view,
+            wgpu::Color::TRANSPARENT,
+        );
+        engine.queue.submit(std::iter::once(encoder.finish()));
+
+        engine.capture_linear_depth().map_err(|e| {
+            crate::PolyscopeError::RenderError(format!("Failed to read back depth: {e}"))
+        })
+    }
+
+    /// Renders the pick pass in headless mode and resolves the structure
+    /// element at pixel `(x, y)`.
+    ///
+    /// Uses the camera of the last viewer frame if a window has been shown,
+    /// otherwise fits the camera to the scene like `render_frame_headless()`.
+    pub(crate) fn pick_headless(
+        &mut self,
+        x: f32,
+        y: f32,
+    ) -> crate::Result<Option<crate::PickResult>> {
+        let engine = self
+            .engine
+            .as_mut()
+            .ok_or_else(|| crate::PolyscopeError::RenderError("Engine not initialized".into()))?;
+
+        if !use_last_camera(engine) {
+            self.camera_fitted = super::render_init::auto_fit_camera(engine, self.camera_fitted);
+            crate::view::apply_camera_modes(&mut engine.camera, &mut self.camera_modes_generation);
+        }
+
+        engine.init_pick_buffers(engine.width, engine.height);
+        super::render_init::drain_material_queue(engine);
+        super::render_init::update_uniforms(engine);
+        super::render_init::init_structure_gpu_resources(engine);
+        super::render_pick::init_pick_resources(engine);
+        super::render_init::update_gpu_buffers(engine, true);
+        super::render_pick::render_pick_pass(engine);
+
+        Ok(self.resolve_pick_at(x, y))
     }
 
     /// Renders the scene to the screenshot target texture without saving to file.
@@ -167,97 +234,8 @@ impl App {
                 label: Some("headless render encoder"),
             });
 
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("headless render pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &screenshot_view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color {
-                            r: f64::from(self.background_color.x),
-                            g: f64::from(self.background_color.y),
-                            b: f64::from(self.background_color.z),
-                            a: 1.0,
-                        }),
-                        store: wgpu::StoreOp::Store,
-                    },
-                    depth_slice: None,
-                })],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                    view: engine.screenshot_depth_view(),
-                    depth_ops: Some(wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(1.0),
-                        store: wgpu::StoreOp::Store,
-                    }),
-                    stencil_ops: None,
-                }),
-                ..Default::default()
-            });
-
-            // Draw point clouds
-            render_scene::draw_point_clouds(&mut render_pass, engine);
-
-            // Draw vector quantities
-            render_scene::draw_vector_quantities(&mut render_pass, engine);
-
-            // Draw curve networks, camera views, and volume grids
-            render_scene::draw_curve_networks_and_lines(&mut render_pass, engine);
-        }
-
-        // Surface mesh / volume mesh pass (MRT: HDR + normal G-buffer)
-        // The mesh pipeline expects 2 color attachments, so we need a separate pass.
-        // Ensure normal texture exists before borrowing mesh_pipeline
-        if engine.mesh_pipeline.is_some() && engine.normal_view().is_none() {
-            let (w, h) = engine.dimensions();
-            engine.create_normal_texture_with_size(w, h);
-        }
-        if let Some(mesh_pipeline) = &engine.mesh_pipeline {
-            if let Some(normal_view) = engine.normal_view() {
-                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("headless mesh pass (MRT)"),
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &screenshot_view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load,
-                                store: wgpu::StoreOp::Store,
-                            },
-                            depth_slice: None,
-                        }),
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: normal_view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color {
-                                    r: 0.5,
-                                    g: 0.5,
-                                    b: 1.0,
-                                    a: 0.0,
-                                }),
-                                store: wgpu::StoreOp::Store,
-                            },
-                            depth_slice: None,
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: engine.screenshot_depth_view(),
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        }),
-                        stencil_ops: None,
-                    }),
-                    ..Default::default()
-                });
-
-                render_pass.set_pipeline(mesh_pipeline);
-                render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
-
-                render_scene::draw_meshes_simple(&mut render_pass, engine);
-            }
-        }
+        let clear_color = super::render_init::screenshot_clear_color(self.transparent_background);
+        draw_geometry_headless(engine, &mut encoder, &screenshot_view, clear_color);
 
         // Render ground plane
         let (scene_center, scene_min_y, length_scale) = crate::with_context(|ctx| {
@@ -268,30 +246,38 @@ impl App {
                 ctx.length_scale,
             )
         });
-        let height_override = if self.ground_plane.height_is_relative {
-            None
-        } else {
-            Some(self.ground_plane.height)
-        };
-        let gp_shadow_mode = match self.ground_plane.mode {
-            GroundPlaneMode::None => 0u32,
-            GroundPlaneMode::ShadowOnly => 1u32,
-            GroundPlaneMode::Tile | GroundPlaneMode::TileReflection => 2u32,
-        };
+        let ground_plane = crate::with_context(|ctx| ctx.options.ground_plane.clone());
+        let height_override = Some(ground_plane.world_height(scene_min_y, length_scale));
+        let gp_shadow_mode = ground_plane.shader_shadow_mode();
         engine.render_ground_plane(
             &mut encoder,
             &screenshot_view,
-            self.ground_plane.mode != GroundPlaneMode::None,
+            ground_plane.is_visible(),
             scene_center,
             scene_min_y,
             length_scale,
             height_override,
-            self.ground_plane.shadow_darkness,
+            ground_plane.shadow_darkness,
             gp_shadow_mode,
             0.0,
         );
 
-        // Apply tone mapping
+        // Ambient occlusion from the mesh pass's depth and normal buffers,
+        // skipped entirely when disabled
+        let (tone_mapping, ssao, dof) = crate::with_context(|ctx| {
+            (
+                ctx.options.tone_mapping.clone(),
+                ctx.options.ssao.clone(),
+                ctx.options.dof.clone(),
+            )
+        });
+        engine.update_tone_mapping(&tone_mapping, ssao.enabled);
+        engine.update_dof(&dof);
+        if ssao.enabled {
+            engine.render_ssao(&mut encoder, &ssao);
+        }
+
+        // Apply tone mapping, then depth of field when enabled
         engine.apply_screenshot_tone_mapping(&mut encoder);
 
         engine.queue.submit(std::iter::once(encoder.finish()));
@@ -309,4 +295,157 @@ impl App {
             crate::PolyscopeError::RenderError(format!("Failed to capture screenshot: {e}"))
         })
     }
+
+    /// Reads back the normal G-buffer of the rendered frame (see
+    /// `RenderEngine::read_normal_buffer`).
+    /// Must be called after `render_frame_headless()`.
+    pub(crate) fn capture_normals_to_buffer(&self) -> crate::Result<Vec<[f32; 4]>> {
+        let engine = self
+            .engine
+            .as_ref()
+            .ok_or_else(|| crate::PolyscopeError::RenderError("Engine not initialized".into()))?;
+
+        engine.read_normal_buffer().map_err(|e| {
+            crate::PolyscopeError::RenderError(format!("Failed to read back normals: {e}"))
+        })
+    }
+}
+
+/// Replaces the engine's camera with the camera of the last viewer frame,
+/// keeping the engine's aspect ratio. Returns false if no frame was shown yet.
+fn use_last_camera(engine: &mut polyscope_render::RenderEngine) -> bool {
+    let Some(camera) = crate::view::last_camera() else {
+        return false;
+    };
+    let aspect_ratio = engine.camera.aspect_ratio;
+    engine.camera = camera;
+    engine.camera.aspect_ratio = aspect_ratio;
+    true
+}
+
+/// Initializes and updates GPU data for all structures before a headless render.
+fn prepare_frame_headless(engine: &mut polyscope_render::RenderEngine) {
+    // Drain deferred material load queue
+    super::render_init::drain_material_queue(engine);
+    super::render_init::sync_color_maps(engine);
+
+    // Update camera and slice plane uniforms
+    super::render_init::update_uniforms(engine);
+
+    // Initialize GPU resources for all structures (shared function)
+    super::render_init::init_structure_gpu_resources(engine);
+
+    // Update GPU buffers (headless: no pick uniforms)
+    super::render_init::update_gpu_buffers(engine, false);
+}
+
+/// Draws all structures into `screenshot_view` and the depth buffer, which
+/// are cleared first. Ground plane and post-processing are not included.
+fn draw_geometry_headless(
+    engine: &mut polyscope_render::RenderEngine,
+    encoder: &mut wgpu::CommandEncoder,
+    screenshot_view: &wgpu::TextureView,
+    clear_color: wgpu::Color,
+) {
+    render_scene::dispatch_curve_network_tubes(encoder, engine);
+
+    {
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("headless render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: screenshot_view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(clear_color),
+                    store: wgpu::StoreOp::Store,
+                },
+                depth_slice: None,
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: engine.screenshot_depth_view(),
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            ..Default::default()
+        });
+
+        // Draw point clouds
+        render_scene::draw_point_clouds(&mut render_pass, engine);
+
+        // Draw vector quantities
+        render_scene::draw_vector_quantities(&mut render_pass, engine);
+
+        // Draw curve networks, camera views, and volume grids
+        render_scene::draw_curve_networks_and_lines(&mut render_pass, engine);
+
+        // Draw camera view images
+        render_scene::draw_camera_view_images(&mut render_pass, engine);
+
+        // Draw curve network tubes and node spheres (tube mode)
+        render_scene::draw_curve_network_tubes(&mut render_pass, engine);
+        render_scene::draw_curve_network_nodes(&mut render_pass, engine);
+    }
+
+    // Surface mesh / volume mesh pass (MRT: HDR + normal G-buffer)
+    // The mesh pipeline expects 2 color attachments, so we need a separate pass.
+    // Ensure normal texture exists before borrowing mesh_pipeline
+    if engine.mesh_pipeline.is_some() && engine.normal_view().is_none() {
+        // Must match the depth buffer, which is at SSAA resolution
+        let (w, h) = engine.dimensions();
+        let ssaa = engine.ssaa_factor();
+        engine.create_normal_texture_with_size(w * ssaa, h * ssaa);
+    }
+    if let Some(mesh_pipeline) = &engine.mesh_pipeline {
+        if let Some(normal_view) = engine.normal_view() {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("headless mesh pass (MRT)"),
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: screenshot_view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: wgpu::StoreOp::Store,
+                        },
+                        depth_slice: None,
+                    }),
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: normal_view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color {
+                                r: 0.5,
+                                g: 0.5,
+                                b: 1.0,
+                                a: 0.0,
+                            }),
+                            store: wgpu::StoreOp::Store,
+                        },
+                        depth_slice: None,
+                    }),
+                ],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: engine.screenshot_depth_view(),
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                ..Default::default()
+            });
+
+            render_pass.set_pipeline(mesh_pipeline);
+            render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
+
+            render_scene::draw_meshes_simple(&mut render_pass, engine);
+
+            // Volume grid isosurfaces and gridcubes share the MRT pass
+            render_scene::draw_volume_grid_isosurfaces(&mut render_pass, engine);
+            render_scene::draw_volume_grid_gridcubes(&mut render_pass, engine);
+        }
+    }
 }
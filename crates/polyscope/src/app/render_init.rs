@@ -1,8 +1,15 @@
 // Shared GPU initialization code for windowed and headless rendering
 
 use glam::Vec3;
+use polyscope_core::structure::Structure;
 use polyscope_core::{MaterialLoadRequest, slice_plane::SlicePlaneUniforms};
-use polyscope_render::RenderEngine;
+use polyscope_render::{
+    GridcubeRenderData, GridcubeUniforms, IsosurfaceRenderData, NavigationStyle, RenderEngine,
+    SimpleMeshUniforms,
+};
+use polyscope_structures::volume_grid::{
+    VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity, VolumeGridVizMode,
+};
 use polyscope_structures::{
     CameraView, CurveNetwork, PointCloud, SurfaceMesh, VolumeGrid, VolumeMesh,
 };
@@ -11,9 +18,9 @@ use polyscope_structures::{
 /// Returns the new `camera_fitted` value.
 pub fn auto_fit_camera(engine: &mut RenderEngine, camera_fitted: bool) -> bool {
     if !camera_fitted {
-        let (has_structures, bbox) = crate::with_context(|ctx| {
+        let (has_structures, bbox, planar) = crate::with_context(|ctx| {
             let has_structures = !ctx.registry.is_empty();
-            (has_structures, ctx.bounding_box)
+            (has_structures, ctx.bounding_box, ctx.planar_data_hint)
         });
 
         if has_structures {
@@ -21,6 +28,10 @@ pub fn auto_fit_camera(engine: &mut RenderEngine, camera_fitted: bool) -> bool {
             // Only fit if bounding box is valid (not default zeros or infinities)
             if min.x.is_finite() && max.x.is_finite() && (max - min).length() > 0.0 {
                 engine.camera.look_at_box(min, max);
+                // Only override the default style, never a user choice
+                if planar && engine.camera.navigation_style == NavigationStyle::Turntable {
+                    engine.camera.set_navigation_style(NavigationStyle::Planar);
+                }
                 return true;
             }
         }
@@ -28,7 +39,38 @@ pub fn auto_fit_camera(engine: &mut RenderEngine, camera_fitted: bool) -> bool {
     camera_fitted
 }
 
-/// Drain deferred material load queue and load materials into the engine.
+/// Applies pending camera requests (`reset_camera_to_home`,
+/// `load_camera_view`, then `look_at_*`). Returns true if any was applied.
+///
+/// With `smoothing > 0` a `look_at_*` request starts a camera flight of that
+/// many seconds instead of jumping.
+pub fn apply_view_requests(engine: &mut RenderEngine, smoothing: f32) -> bool {
+    let mut applied = false;
+    if crate::view::take_fit_scene_request() {
+        engine.camera.flight = None;
+        super::reset_view(engine);
+        applied = true;
+    }
+    if let Some(camera) = crate::view::take_camera_view_request() {
+        let aspect_ratio = engine.camera.aspect_ratio;
+        engine.camera = camera;
+        engine.camera.aspect_ratio = aspect_ratio;
+        applied = true;
+    }
+    if let Some((min, max)) = crate::view::take_look_at_request() {
+        engine.camera.flight = None;
+        if smoothing > 0.0 {
+            engine.camera.fly_to_box_from_front(min, max, smoothing);
+        } else {
+            engine.camera.look_at_box_from_front(min, max);
+        }
+        applied = true;
+    }
+    applied
+}
+
+/// Drain deferred material load queue and load materials into the engine,
+/// then mirror the engine's material names for `available_materials`.
 pub fn drain_material_queue(engine: &mut RenderEngine) {
     let pending_materials: Vec<MaterialLoadRequest> =
         crate::with_context_mut(|ctx| std::mem::take(&mut ctx.material_load_queue));
@@ -46,16 +88,70 @@ pub fn drain_material_queue(engine: &mut RenderEngine) {
                     eprintln!("Failed to load blendable material '{name}': {e}");
                 }
             }
+            MaterialLoadRequest::Image {
+                name,
+                width,
+                height,
+                rgba,
+            } => {
+                if let Err(e) = engine.load_matcap(&name, &rgba, width, height) {
+                    eprintln!("Failed to load material '{name}': {e}");
+                }
+            }
         }
     }
+
+    // Also picks up materials loaded from the UI on the previous frame
+    crate::materials::sync_engine_materials(&engine.materials);
 }
 
-/// Update camera and slice plane uniforms.
+/// Register custom color maps (from `register_color_map`) with the engine.
+pub fn sync_color_maps(engine: &mut RenderEngine) {
+    crate::color_maps::sync_custom_color_maps(&mut engine.color_maps);
+}
+
+/// Apply the SSAA factor from options (`set_antialiasing`) to the engine.
+/// Render textures are only reallocated when the factor changes.
+pub fn sync_antialiasing(engine: &mut RenderEngine) {
+    let factor = crate::with_context(|ctx| ctx.options.ssaa_factor);
+    if engine.ssaa_factor() != factor {
+        engine.set_ssaa_factor(factor);
+    }
+}
+
+/// Background color from options, for the on-screen render pass.
+pub fn background_color() -> Vec3 {
+    crate::with_context(|ctx| ctx.options.background_color.truncate())
+}
+
+/// Clear color for screenshots: the background color, with zero alpha if
+/// `transparent` or the `transparent_background` option is set.
+pub fn screenshot_clear_color(transparent: bool) -> wgpu::Color {
+    let (color, transparent_option) = crate::with_context(|ctx| {
+        (
+            ctx.options.background_color,
+            ctx.options.transparent_background,
+        )
+    });
+    wgpu::Color {
+        r: f64::from(color.x),
+        g: f64::from(color.y),
+        b: f64::from(color.z),
+        a: if transparent || transparent_option {
+            0.0
+        } else {
+            1.0
+        },
+    }
+}
+
+/// Update camera, slice plane and crop box uniforms.
 pub fn update_uniforms(engine: &mut RenderEngine) {
     engine.update_camera_uniforms();
 
     crate::with_context(|ctx| {
         engine.update_slice_plane_uniforms(ctx.slice_planes().map(SlicePlaneUniforms::from));
+        engine.update_crop_box_uniforms(ctx.crop_box.as_ref());
     });
 }
 
@@ -65,10 +161,10 @@ pub fn update_uniforms(engine: &mut RenderEngine) {
 /// - SurfaceMesh: init_gpu_resources + shadow resources + ALL vector/intrinsic/one-form quantity init
 /// - CurveNetwork: init_gpu_resources + tube resources + node resources
 /// - CameraView: init_render_data
-/// - VolumeGrid: init_render_data (base wireframe only, NOT quantity init)
-/// - VolumeMesh: init_render_data + slice plane culling
+/// - VolumeGrid: init_render_data + gridcube/isosurface quantity data
+/// - VolumeMesh: init_render_data + slice plane culling + cross-section cap
 ///
-/// Windowed-only extras (pick resources, VolumeGrid quantity init) are kept in render() inline.
+/// Windowed-only extras (pick resources) are kept in render() inline.
 pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
     crate::with_context_mut(|ctx| {
         // Collect slice plane data before the loop to avoid borrow conflicts
@@ -97,6 +193,7 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                                 &points,
                             );
                         }
+                        vq.upload_vectors(&engine.queue);
                     }
                 }
             }
@@ -109,6 +206,8 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                             engine.mesh_bind_group_layout(),
                             engine.camera_buffer(),
                         );
+                    } else if mesh.positions_dirty() {
+                        mesh.upload_vertex_positions(&engine.queue);
                     }
 
                     // Initialize shadow resources if render data exists but shadow doesn't
@@ -135,6 +234,7 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                                 &vertices,
                             );
                         }
+                        vq.upload_vectors(&engine.queue);
                     }
 
                     // Initialize face vector quantity render data if enabled
@@ -148,6 +248,7 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                                 &centroids,
                             );
                         }
+                        vq.upload_vectors(&engine.queue);
                     }
 
                     // Initialize vertex intrinsic vector quantity render data if enabled
@@ -239,6 +340,13 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                             ctx.length_scale,
                         );
                     }
+                    cv.update_image_render_data(
+                        &engine.device,
+                        &engine.queue,
+                        engine.camera_image_bind_group_layout(),
+                        engine.camera_buffer(),
+                        ctx.length_scale,
+                    );
                 }
             }
 
@@ -252,16 +360,17 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                             &engine.queue,
                         );
                     }
-                    // Note: VolumeGrid quantity init (gridcube/isosurface) is windowed-only
+                    init_volume_grid_quantities(engine, vg);
                 }
             }
 
             if structure.type_name() == "VolumeMesh" {
+                let name = structure.name().to_string();
                 if let Some(vm) = structure.as_any_mut().downcast_mut::<VolumeMesh>() {
                     let mut enabled_planes: Vec<(String, Vec3, Vec3)> = slice_planes
                         .iter()
-                        .filter(|p| p.is_enabled())
-                        .map(|p| (p.name().to_string(), p.origin(), p.normal()))
+                        .filter(|p| p.is_enabled() && p.applies_to("VolumeMesh", &name))
+                        .map(|p| (p.name().to_string(), p.origin(), p.kept_normal()))
                         .collect();
                     enabled_planes.sort_by(|a, b| a.0.cmp(&b.0));
                     let plane_params: Vec<(Vec3, Vec3)> = enabled_planes
@@ -289,10 +398,214 @@ pub fn init_structure_gpu_resources(engine: &mut RenderEngine) {
                             engine.camera_buffer(),
                         );
                     }
+
+                    // Cross-section cap for the first enabled plane
+                    if let Some((origin, normal)) = plane_params.first() {
+                        vm.update_slice_render_data(
+                            &engine.device,
+                            &engine.queue,
+                            engine.mesh_bind_group_layout(),
+                            engine.camera_buffer(),
+                            &engine.color_maps,
+                            *origin,
+                            *normal,
+                        );
+                    } else if vm.slice_render_data().is_some() {
+                        vm.clear_slice_render_data();
+                    }
                 }
             }
         }
     });
+
+    // Bounding boxes follow structure transforms, so they are refreshed every frame
+    let boxes = crate::with_context(polyscope_core::Context::bounding_box_wireframes);
+    engine.update_bounding_box_wireframes(&boxes);
+}
+
+/// Initializes gridcube and isosurface render data for the enabled scalar
+/// quantities of a volume grid and updates their uniforms.
+fn init_volume_grid_quantities(engine: &RenderEngine, vg: &mut VolumeGrid) {
+    let grid_spacing = vg.grid_spacing();
+    let cube_size_factor = vg.cube_size_factor();
+    let transform = vg.transform();
+    let node_dim = vg.node_dim();
+    let bound_min = vg.bound_min();
+    let bound_max = vg.bound_max();
+    // Cube visualization shows cell scalars as see-through voxels
+    let cell_transparency = if vg.cube_visualization() {
+        vg.cube_transparency()
+    } else {
+        0.0
+    };
+
+    for quantity in vg.quantities_mut() {
+        if !quantity.is_enabled() {
+            continue;
+        }
+
+        // Node scalar quantities: gridcube + isosurface
+        if let Some(nsq) = quantity
+            .as_any_mut()
+            .downcast_mut::<VolumeGridNodeScalarQuantity>()
+        {
+            match nsq.viz_mode() {
+                VolumeGridVizMode::Gridcube => {
+                    if nsq.gridcube_render_data().is_none() || nsq.gridcube_dirty() {
+                        // Generate node center positions
+                        let mut centers = Vec::new();
+                        let cell_dim_f = Vec3::new(
+                            (node_dim.x - 1).max(1) as f32,
+                            (node_dim.y - 1).max(1) as f32,
+                            (node_dim.z - 1).max(1) as f32,
+                        );
+                        for k in 0..node_dim.z {
+                            for j in 0..node_dim.y {
+                                for i in 0..node_dim.x {
+                                    let t = Vec3::new(i as f32, j as f32, k as f32) / cell_dim_f;
+                                    centers.push(bound_min + t * (bound_max - bound_min));
+                                }
+                            }
+                        }
+                        let half_size =
+                            grid_spacing.min_element() * 0.5 * cube_size_factor.max(0.5);
+
+                        // Sample colormap
+                        let colormap_colors: Vec<Vec3> =
+                            if let Some(cm) = engine.color_maps.get(nsq.color_map()) {
+                                cm.colors.clone()
+                            } else {
+                                vec![Vec3::ZERO, Vec3::ONE]
+                            };
+
+                        let data = GridcubeRenderData::new(
+                            &engine.device,
+                            &engine.queue,
+                            engine.gridcube_bind_group_layout(),
+                            engine.camera_buffer(),
+                            &centers,
+                            half_size,
+                            nsq.values(),
+                            &colormap_colors,
+                        );
+                        nsq.set_gridcube_render_data(data);
+                    }
+                }
+                VolumeGridVizMode::Isosurface => {
+                    if nsq.isosurface_render_data().is_none() || nsq.isosurface_dirty() {
+                        let mesh = nsq.extract_isosurface();
+                        if mesh.vertices.is_empty() {
+                            // Isovalue outside data range — clear old surface
+                            nsq.clear_isosurface_render_data();
+                        } else {
+                            let vertices = mesh.vertices.clone();
+                            let normals = mesh.normals.clone();
+                            let indices = mesh.indices.clone();
+                            let data = IsosurfaceRenderData::new(
+                                &engine.device,
+                                engine.simple_mesh_bind_group_layout(),
+                                engine.camera_buffer(),
+                                &vertices,
+                                &normals,
+                                &indices,
+                            );
+                            nsq.set_isosurface_render_data(data);
+                        }
+                    }
+                }
+            }
+
+            // Update uniforms every frame (model matrix may change)
+            if let Some(rd) = nsq.gridcube_render_data() {
+                let (data_min, data_max) = nsq.data_range();
+                let uniforms = GridcubeUniforms {
+                    model: transform.to_cols_array_2d(),
+                    cube_size_factor: cube_size_factor.max(0.5),
+                    data_min,
+                    data_max,
+                    transparency: 0.0,
+                    slice_planes_enabled: 0,
+                    ..Default::default()
+                };
+                rd.update_uniforms(&engine.queue, &uniforms);
+            }
+            if let Some(rd) = nsq.isosurface_render_data() {
+                let color = nsq.isosurface_color();
+                let uniforms = SimpleMeshUniforms {
+                    model: transform.to_cols_array_2d(),
+                    base_color: [color.x, color.y, color.z, 1.0],
+                    transparency: 0.0,
+                    slice_planes_enabled: 0,
+                    ..Default::default()
+                };
+                rd.update_uniforms(&engine.queue, &uniforms);
+            }
+        }
+
+        // Cell scalar quantities: gridcube only
+        if let Some(csq) = quantity
+            .as_any_mut()
+            .downcast_mut::<VolumeGridCellScalarQuantity>()
+        {
+            if csq.gridcube_render_data().is_none() || csq.gridcube_dirty() {
+                let cell_dim = node_dim.saturating_sub(glam::UVec3::ONE);
+                let cell_spacing = (bound_max - bound_min)
+                    / Vec3::new(
+                        cell_dim.x.max(1) as f32,
+                        cell_dim.y.max(1) as f32,
+                        cell_dim.z.max(1) as f32,
+                    );
+                let half_cell_spacing = cell_spacing * 0.5;
+
+                // Generate cell center positions
+                let mut centers = Vec::new();
+                for k in 0..cell_dim.z {
+                    for j in 0..cell_dim.y {
+                        for i in 0..cell_dim.x {
+                            let node_pos =
+                                bound_min + Vec3::new(i as f32, j as f32, k as f32) * cell_spacing;
+                            centers.push(node_pos + half_cell_spacing);
+                        }
+                    }
+                }
+                let half_size = cell_spacing.min_element() * 0.5 * cube_size_factor.max(0.5);
+
+                let colormap_colors: Vec<Vec3> =
+                    if let Some(cm) = engine.color_maps.get(csq.color_map()) {
+                        cm.colors.clone()
+                    } else {
+                        vec![Vec3::ZERO, Vec3::ONE]
+                    };
+
+                let data = GridcubeRenderData::new(
+                    &engine.device,
+                    &engine.queue,
+                    engine.gridcube_bind_group_layout(),
+                    engine.camera_buffer(),
+                    &centers,
+                    half_size,
+                    csq.values(),
+                    &colormap_colors,
+                );
+                csq.set_gridcube_render_data(data);
+            }
+
+            // Update uniforms every frame
+            if let Some(rd) = csq.gridcube_render_data() {
+                let (data_min, data_max) = csq.data_range();
+                let uniforms = GridcubeUniforms {
+                    model: transform.to_cols_array_2d(),
+                    cube_size_factor: cube_size_factor.max(0.5),
+                    data_min,
+                    data_max,
+                    transparency: cell_transparency,
+                    slice_planes_enabled: 0,
+                    ..Default::default()
+                };
+                rd.update_uniforms(&engine.queue, &uniforms);
+            }
+        }
+    }
 }
 
 /// Update GPU buffers for all structures.
@@ -303,7 +616,11 @@ pub fn update_gpu_buffers(engine: &RenderEngine, update_pick_uniforms: bool) {
         for structure in ctx.registry.iter() {
             if structure.type_name() == "PointCloud" {
                 if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
-                    pc.update_gpu_buffers(&engine.queue, &engine.color_maps);
+                    pc.update_gpu_buffers(
+                        &engine.queue,
+                        &engine.color_maps,
+                        ctx.options.no_data_color,
+                    );
                     if update_pick_uniforms {
                         pc.update_pick_uniforms(&engine.queue);
                     }
@@ -311,14 +628,19 @@ pub fn update_gpu_buffers(engine: &RenderEngine, update_pick_uniforms: bool) {
                     // Update vector quantity uniforms
                     let model = structure.transform();
                     if let Some(vq) = pc.active_vector_quantity() {
-                        vq.update_uniforms(&engine.queue, &model);
+                        vq.update_uniforms(&engine.queue, &model, structure.length_scale());
                     }
                 }
             }
 
             if structure.type_name() == "SurfaceMesh" {
                 if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
-                    mesh.update_gpu_buffers(&engine.queue, &engine.color_maps);
+                    mesh.update_gpu_buffers(
+                        &engine.queue,
+                        &engine.color_maps,
+                        ctx.options.backface_cull,
+                        ctx.options.no_data_color,
+                    );
                     if update_pick_uniforms {
                         mesh.update_pick_uniforms(&engine.queue);
                     }
@@ -326,12 +648,12 @@ pub fn update_gpu_buffers(engine: &RenderEngine, update_pick_uniforms: bool) {
                     // Update vertex vector quantity uniforms
                     let model = structure.transform();
                     if let Some(vq) = mesh.active_vertex_vector_quantity() {
-                        vq.update_uniforms(&engine.queue, &model);
+                        vq.update_uniforms(&engine.queue, &model, structure.length_scale());
                     }
 
                     // Update face vector quantity uniforms
                     if let Some(vq) = mesh.active_face_vector_quantity() {
-                        vq.update_uniforms(&engine.queue, &model);
+                        vq.update_uniforms(&engine.queue, &model, structure.length_scale());
                     }
 
                     // Update vertex intrinsic vector quantity uniforms
@@ -0,0 +1,258 @@
+//! GPU pick resources and the pick pass, shared by the viewer and headless picking.
+
+use super::{CurveNetwork, PointCloud, Structure, SurfaceMesh, VolumeGrid, VolumeMesh};
+use polyscope_core::structure::HasQuantities;
+use polyscope_render::RenderEngine;
+use polyscope_structures::volume_grid::{
+    VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity, VolumeGridVizMode,
+};
+
+/// Initializes pick resources for point clouds, surface meshes, curve networks
+/// and volume meshes that have render data but no pick bind group yet.
+///
+/// Volume grid pick resources are set up with their gridcube render data.
+pub(super) fn init_pick_resources(engine: &mut RenderEngine) {
+    crate::with_context_mut(|ctx| {
+        for structure in ctx.registry.iter_mut() {
+            // PointCloud: windowed-only pick resources
+            if structure.type_name() == "PointCloud" {
+                let structure_name = structure.name().to_string();
+                if let Some(pc) = structure.as_any_mut().downcast_mut::<PointCloud>() {
+                    // Initialize pick resources (after render data init by shared function)
+                    if pc.pick_bind_group().is_none() && pc.render_data().is_some() {
+                        let num_points = pc.points().len() as u32;
+                        let global_start =
+                            engine.assign_pick_range("PointCloud", &structure_name, num_points);
+                        pc.init_pick_resources(
+                            &engine.device,
+                            engine.point_pick_bind_group_layout(),
+                            engine.camera_buffer(),
+                            global_start,
+                        );
+                    }
+                }
+            }
+
+            // SurfaceMesh: windowed-only pick resources
+            if structure.type_name() == "SurfaceMesh" {
+                if let Some(mesh) = structure.as_any_mut().downcast_mut::<SurfaceMesh>() {
+                    // Initialize pick resources (after render data init by shared function)
+                    if mesh.pick_bind_group().is_none() && mesh.render_data().is_some() {
+                        let num_faces = mesh.num_faces() as u32;
+                        let global_start =
+                            engine.assign_pick_range("SurfaceMesh", mesh.name(), num_faces);
+                        mesh.init_pick_resources(
+                            &engine.device,
+                            engine.mesh_pick_bind_group_layout(),
+                            engine.camera_buffer(),
+                            global_start,
+                        );
+                    }
+                }
+            }
+
+            // CurveNetwork: windowed-only pick resources (edge and tube)
+            if structure.type_name() == "CurveNetwork" {
+                if let Some(cn) = structure.as_any_mut().downcast_mut::<CurveNetwork>() {
+                    // Initialize pick resources (after render data init by shared function)
+                    if cn.pick_bind_group().is_none() && cn.render_data().is_some() {
+                        // Initialize curve network pick pipeline if not done
+                        if !engine.has_curve_network_pick_pipeline() {
+                            engine.init_curve_network_pick_pipeline();
+                        }
+                        let num_edges = cn.num_edges() as u32;
+                        let global_start =
+                            engine.assign_pick_range("CurveNetwork", cn.name(), num_edges);
+                        cn.init_pick_resources(
+                            &engine.device,
+                            engine.pick_bind_group_layout(),
+                            engine.camera_buffer(),
+                            global_start,
+                        );
+                    }
+
+                    // Initialize tube pick resources (for tube render mode)
+                    // This provides a larger clickable area using ray-cylinder intersection
+                    if !cn.has_tube_pick_resources() && cn.render_data().is_some() {
+                        // Initialize tube pick pipeline if not done
+                        if !engine.has_curve_network_tube_pick_pipeline() {
+                            engine.init_curve_network_tube_pick_pipeline();
+                        }
+                        cn.init_tube_pick_resources(
+                            &engine.device,
+                            engine.curve_network_tube_pick_bind_group_layout(),
+                            engine.camera_buffer(),
+                        );
+                    }
+                }
+            }
+
+            // VolumeMesh: windowed-only pick resources
+            // (slice plane culling logic moved to shared function)
+            if structure.type_name() == "VolumeMesh" {
+                if let Some(vm) = structure.as_any_mut().downcast_mut::<VolumeMesh>() {
+                    // Initialize pick resources (after render data init by shared function)
+                    if vm.pick_bind_group().is_none() && vm.render_data().is_some() {
+                        if !engine.has_mesh_pick_pipeline() {
+                            engine.init_mesh_pick_pipeline();
+                        }
+                        let num_cells = vm.num_cells() as u32;
+                        let global_start =
+                            engine.assign_pick_range("VolumeMesh", vm.name(), num_cells);
+                        vm.init_pick_resources(
+                            &engine.device,
+                            engine.mesh_pick_bind_group_layout(),
+                            engine.camera_buffer(),
+                            global_start,
+                        );
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Renders all visible structures into the engine's pick buffer.
+pub(super) fn render_pick_pass(engine: &mut RenderEngine) {
+    let mut encoder = engine
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("pick pass encoder"),
+        });
+
+    if let Some(mut pick_pass) = engine.begin_pick_pass(&mut encoder) {
+        // Draw point clouds to pick buffer
+        pick_pass.set_pipeline(engine.point_pick_pipeline());
+
+        crate::with_context(|ctx| {
+            for structure in ctx.registry.iter() {
+                if !ctx.is_structure_visible(structure) {
+                    continue;
+                }
+                if structure.type_name() == "PointCloud" {
+                    if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
+                        if let (Some(pick_bind_group), Some(_)) =
+                            (pc.pick_bind_group(), pc.render_data())
+                        {
+                            pick_pass.set_bind_group(0, pick_bind_group, &[]);
+                            // 6 vertices per quad, one instance per displayed point
+                            pick_pass.draw(0..6, 0..pc.num_displayed_points());
+                        }
+                    }
+                }
+            }
+        });
+
+        // Draw curve networks to pick buffer
+        // Use tube picking (ray-cylinder) for all curve networks for better hit detection
+        crate::with_context(|ctx| {
+            for structure in ctx.registry.iter() {
+                if !ctx.is_structure_visible(structure) {
+                    continue;
+                }
+                if structure.type_name() == "CurveNetwork" {
+                    if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
+                        let Some(render_data) = cn.render_data() else {
+                            continue;
+                        };
+
+                        // Use tube picking when available - provides larger clickable area
+                        if engine.has_curve_network_tube_pick_pipeline() {
+                            if let (Some(tube_bind_group), Some(vertex_buffer)) = (
+                                cn.tube_pick_bind_group(),
+                                render_data.generated_vertex_buffer.as_ref(),
+                            ) {
+                                // Use tube-based picking (ray-cylinder intersection)
+                                pick_pass.set_pipeline(engine.curve_network_tube_pick_pipeline());
+                                pick_pass.set_bind_group(0, tube_bind_group, &[]);
+                                pick_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+                                // 36 vertices per edge (bounding box triangles)
+                                pick_pass.draw(0..render_data.num_edges * 36, 0..1);
+                            }
+                        } else if engine.has_curve_network_pick_pipeline() {
+                            // Fallback to line-based picking
+                            if let Some(pick_bind_group) = cn.pick_bind_group() {
+                                pick_pass.set_pipeline(engine.curve_network_pick_pipeline());
+                                pick_pass.set_bind_group(0, pick_bind_group, &[]);
+                                // 2 vertices per edge (LineList topology)
+                                pick_pass.draw(0..render_data.num_edges * 2, 0..1);
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        // Draw surface meshes and volume meshes to pick buffer
+        // (both use the same mesh pick pipeline with face/cell index mapping)
+        if engine.has_mesh_pick_pipeline() {
+            pick_pass.set_pipeline(engine.mesh_pick_pipeline());
+            crate::with_context(|ctx| {
+                for structure in ctx.registry.iter() {
+                    if !ctx.is_structure_visible(structure) {
+                        continue;
+                    }
+                    if structure.type_name() == "SurfaceMesh" {
+                        if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
+                            if let Some(pick_bind_group) = mesh.pick_bind_group() {
+                                pick_pass.set_bind_group(0, pick_bind_group, &[]);
+                                pick_pass.draw(0..mesh.num_triangulation_vertices(), 0..1);
+                            }
+                        }
+                    }
+                    if structure.type_name() == "VolumeMesh" {
+                        if let Some(vm) = structure.as_any().downcast_ref::<VolumeMesh>() {
+                            if let Some(pick_bind_group) = vm.pick_bind_group() {
+                                pick_pass.set_bind_group(0, pick_bind_group, &[]);
+                                pick_pass.draw(0..vm.num_render_vertices(), 0..1);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+
+        // --- VolumeGrid gridcube picking ---
+        if engine.has_gridcube_pick_pipeline() {
+            pick_pass.set_pipeline(engine.gridcube_pick_pipeline());
+            crate::with_context(|ctx| {
+                for structure in ctx.registry.iter() {
+                    if !ctx.is_structure_visible(structure) {
+                        continue;
+                    }
+                    if structure.type_name() == "VolumeGrid" {
+                        if let Some(vg) = structure.as_any().downcast_ref::<VolumeGrid>() {
+                            for quantity in vg.quantities() {
+                                if !quantity.is_enabled() {
+                                    continue;
+                                }
+                                if let Some(nsq) = quantity
+                                    .as_any()
+                                    .downcast_ref::<VolumeGridNodeScalarQuantity>()
+                                {
+                                    if nsq.viz_mode() == VolumeGridVizMode::Gridcube {
+                                        if let Some(pick_bg) = nsq.pick_bind_group() {
+                                            pick_pass.set_bind_group(0, pick_bg, &[]);
+                                            pick_pass.draw(0..nsq.pick_total_vertices(), 0..1);
+                                        }
+                                    }
+                                }
+                                if let Some(csq) = quantity
+                                    .as_any()
+                                    .downcast_ref::<VolumeGridCellScalarQuantity>()
+                                {
+                                    if let Some(pick_bg) = csq.pick_bind_group() {
+                                        pick_pass.set_bind_group(0, pick_bg, &[]);
+                                        pick_pass.draw(0..csq.pick_total_vertices(), 0..1);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    engine.queue.submit(std::iter::once(encoder.finish()));
+}
@@ -1,10 +1,25 @@
 use super::{CameraView, CurveNetwork, PointCloud, Structure, SurfaceMesh, VolumeGrid, VolumeMesh};
+use polyscope_core::Context;
 use polyscope_core::structure::HasQuantities;
 use polyscope_render::RenderEngine;
+use polyscope_structures::CurveRenderMode;
 use polyscope_structures::volume_grid::{
     VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity, VolumeGridVizMode,
 };
 
+/// Visible structures of the given types in the order a pass draws them.
+///
+/// Every per-structure loop of the scene passes walks this sequence, so the
+/// draw order set by users only applies among structures sharing a pass.
+pub(super) fn draw_sequence<'a>(
+    ctx: &'a Context,
+    type_names: &'a [&'a str],
+) -> impl Iterator<Item = &'a dyn Structure> + 'a {
+    ctx.registry.iter_in_draw_order().filter(move |structure| {
+        type_names.contains(&structure.type_name()) && ctx.is_structure_visible(*structure)
+    })
+}
+
 /// Draw point clouds to a wgpu render pass.
 pub(super) fn draw_point_clouds<'a>(
     render_pass: &mut wgpu::RenderPass<'a>,
@@ -14,25 +29,17 @@ pub(super) fn draw_point_clouds<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
-            if structure.type_name() == "PointCloud" {
-                if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
-                    if let Some(render_data) = pc.render_data() {
-                        render_pass.set_bind_group(0, &render_data.bind_group, &[]);
-                        render_pass.set_bind_group(
-                            2,
-                            engine.matcap_bind_group_for(pc.material()),
-                            &[],
-                        );
-                        // 6 vertices per quad, num_points instances
-                        render_pass.draw(0..6, 0..render_data.num_points);
-                    }
+        for structure in draw_sequence(ctx, &["PointCloud"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
+            if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
+                if let Some(render_data) = pc.render_data() {
+                    render_pass.set_bind_group(0, &render_data.bind_group, &[]);
+                    render_pass.set_bind_group(2, engine.matcap_bind_group_for(pc.material()), &[]);
+                    // 6 vertices per quad, one instance per displayed point
+                    render_pass.draw(0..6, 0..pc.num_displayed_points());
                 }
             }
         }
@@ -48,13 +55,11 @@ pub(super) fn draw_vector_quantities<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
+        for structure in draw_sequence(ctx, &["PointCloud", "SurfaceMesh"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
             render_pass.set_bind_group(2, engine.matcap_bind_group_for(structure.material()), &[]);
             if structure.type_name() == "PointCloud" {
                 if let Some(pc) = structure.as_any().downcast_ref::<PointCloud>() {
@@ -110,7 +115,8 @@ pub(super) fn draw_vector_quantities<'a>(
     });
 }
 
-/// Draw curve network edges (line mode), camera views, and volume grid wireframes to a wgpu render pass.
+/// Draw curve network edges (line mode), camera views, volume grid wireframes and structure
+/// bounding boxes to a wgpu render pass.
 pub(super) fn draw_curve_networks_and_lines<'a>(
     render_pass: &mut wgpu::RenderPass<'a>,
     engine: &'a RenderEngine,
@@ -119,18 +125,15 @@ pub(super) fn draw_curve_networks_and_lines<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
+        for structure in draw_sequence(ctx, &["CurveNetwork", "CameraView", "VolumeGrid"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
             render_pass.set_bind_group(2, engine.matcap_bind_group_for(structure.material()), &[]);
             if structure.type_name() == "CurveNetwork" {
                 if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
-                    // Only render in line mode (0)
-                    if cn.render_mode() == 0 {
+                    if cn.render_mode() == CurveRenderMode::Line {
                         if let Some(render_data) = cn.render_data() {
                             render_pass.set_bind_group(0, &render_data.bind_group, &[]);
                             // 2 vertices per edge (LineList topology)
@@ -159,6 +162,60 @@ pub(super) fn draw_curve_networks_and_lines<'a>(
             }
         }
     });
+
+    // Structure bounding box wireframes are never sliced
+    if let Some(render_data) = &engine.bbox_render_data {
+        render_pass.set_bind_group(0, &render_data.bind_group, &[]);
+        render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(u32::MAX), &[]);
+        render_pass.set_bind_group(2, engine.matcap_bind_group_for("clay"), &[]);
+        render_pass.draw(0..render_data.num_edges * 2, 0..1);
+    }
+}
+
+/// Draw camera view images (textured quads at the image frame) to a wgpu render pass.
+pub(super) fn draw_camera_view_images<'a>(
+    render_pass: &mut wgpu::RenderPass<'a>,
+    engine: &'a RenderEngine,
+) {
+    render_pass.set_pipeline(engine.camera_image_pipeline());
+
+    crate::with_context(|ctx| {
+        for structure in draw_sequence(ctx, &["CameraView"]) {
+            if let Some(cv) = structure.as_any().downcast_ref::<CameraView>() {
+                if let Some(render_data) = cv.image_render_data() {
+                    render_data.draw(render_pass);
+                }
+            }
+        }
+    });
+}
+
+/// Generate curve network tube geometry (one compute dispatch per network).
+/// Must run before `draw_curve_network_tubes` in the same frame.
+pub(super) fn dispatch_curve_network_tubes(
+    encoder: &mut wgpu::CommandEncoder,
+    engine: &RenderEngine,
+) {
+    let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+        label: Some("Curve Network Tube Compute Pass"),
+        timestamp_writes: None,
+    });
+
+    compute_pass.set_pipeline(engine.curve_network_tube_compute_pipeline());
+
+    crate::with_context(|ctx| {
+        for structure in draw_sequence(ctx, &["CurveNetwork"]) {
+            if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
+                if let Some(render_data) = cn.render_data() {
+                    if let Some(compute_bg) = &render_data.compute_bind_group {
+                        compute_pass.set_bind_group(0, compute_bg, &[]);
+                        let num_workgroups = render_data.num_edges.div_ceil(64);
+                        compute_pass.dispatch_workgroups(num_workgroups, 1, 1);
+                    }
+                }
+            }
+        }
+    });
 }
 
 /// Draw curve network tubes to a wgpu render pass.
@@ -170,32 +227,27 @@ pub(super) fn draw_curve_network_tubes<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
-            if structure.type_name() == "CurveNetwork" {
-                if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
-                    // Only render in tube mode (1)
-                    if cn.render_mode() == 1 {
-                        if let Some(render_data) = cn.render_data() {
-                            if let (Some(tube_bg), Some(gen_vb)) = (
-                                &render_data.tube_render_bind_group,
-                                &render_data.generated_vertex_buffer,
-                            ) {
-                                render_pass.set_bind_group(
-                                    2,
-                                    engine.matcap_bind_group_for(structure.material()),
-                                    &[],
-                                );
-                                render_pass.set_bind_group(0, tube_bg, &[]);
-                                render_pass.set_vertex_buffer(0, gen_vb.slice(..));
-                                // 36 vertices per edge (12 triangles for bounding box)
-                                render_pass.draw(0..render_data.num_edges * 36, 0..1);
-                            }
+        for structure in draw_sequence(ctx, &["CurveNetwork"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
+            if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
+                if cn.render_mode() == CurveRenderMode::Tube {
+                    if let Some(render_data) = cn.render_data() {
+                        if let (Some(tube_bg), Some(gen_vb)) = (
+                            &render_data.tube_render_bind_group,
+                            &render_data.generated_vertex_buffer,
+                        ) {
+                            render_pass.set_bind_group(
+                                2,
+                                engine.matcap_bind_group_for(structure.material()),
+                                &[],
+                            );
+                            render_pass.set_bind_group(0, tube_bg, &[]);
+                            render_pass.set_vertex_buffer(0, gen_vb.slice(..));
+                            // 36 vertices per edge (12 triangles for bounding box)
+                            render_pass.draw(0..render_data.num_edges * 36, 0..1);
                         }
                     }
                 }
@@ -213,28 +265,23 @@ pub(super) fn draw_curve_network_nodes<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
-            if structure.type_name() == "CurveNetwork" {
-                if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
-                    // Only render node spheres in tube mode (1)
-                    if cn.render_mode() == 1 {
-                        if let Some(render_data) = cn.render_data() {
-                            if let Some(node_bg) = &render_data.node_render_bind_group {
-                                render_pass.set_bind_group(
-                                    2,
-                                    engine.matcap_bind_group_for(structure.material()),
-                                    &[],
-                                );
-                                render_pass.set_bind_group(0, node_bg, &[]);
-                                // 6 vertices per quad, num_nodes instances
-                                render_pass.draw(0..6, 0..render_data.num_nodes);
-                            }
+        for structure in draw_sequence(ctx, &["CurveNetwork"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
+            if let Some(cn) = structure.as_any().downcast_ref::<CurveNetwork>() {
+                if cn.render_mode() == CurveRenderMode::Tube {
+                    if let Some(render_data) = cn.render_data() {
+                        if let Some(node_bg) = &render_data.node_render_bind_group {
+                            render_pass.set_bind_group(
+                                2,
+                                engine.matcap_bind_group_for(structure.material()),
+                                &[],
+                            );
+                            render_pass.set_bind_group(0, node_bg, &[]);
+                            // 6 vertices per quad, num_nodes instances
+                            render_pass.draw(0..6, 0..render_data.num_nodes);
                         }
                     }
                 }
@@ -252,13 +299,11 @@ pub(super) fn draw_meshes_simple<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) {
-                continue;
-            }
+        for structure in draw_sequence(ctx, &["SurfaceMesh", "VolumeMesh"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
             if structure.type_name() == "SurfaceMesh" {
                 if let Some(mesh) = structure.as_any().downcast_ref::<SurfaceMesh>() {
                     if let Some(render_data) = mesh.render_data() {
@@ -292,8 +337,22 @@ pub(super) fn draw_meshes_simple<'a>(
                         );
                         render_pass.draw_indexed(0..render_data.num_indices, 0, 0..1);
                     }
-                    // Note: No slice cap geometry needed - we use cell culling
-                    // which shows whole cells instead of cross-section caps
+                    // Cross-section cap, colored by the active quantity
+                    if let Some(slice_data) = vm.slice_render_data() {
+                        if !slice_data.is_empty() {
+                            render_pass.set_bind_group(
+                                2,
+                                engine.matcap_bind_group_for(structure.material()),
+                                &[],
+                            );
+                            render_pass.set_bind_group(0, slice_data.bind_group(), &[]);
+                            render_pass.set_index_buffer(
+                                slice_data.index_buffer().slice(..),
+                                wgpu::IndexFormat::Uint32,
+                            );
+                            render_pass.draw_indexed(0..slice_data.num_indices(), 0, 0..1);
+                        }
+                    }
                 }
             }
         }
@@ -309,13 +368,11 @@ pub(super) fn draw_volume_grid_isosurfaces<'a>(
         return;
     };
     render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
     crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) || structure.type_name() != "VolumeGrid" {
-                continue;
-            }
+        for structure in draw_sequence(ctx, &["VolumeGrid"]) {
+            let slice_plane_mask = ctx.slice_plane_mask(structure.type_name(), structure.name());
+            render_pass.set_bind_group(1, engine.slice_plane_bind_group_for(slice_plane_mask), &[]);
             if let Some(vg) = structure.as_any().downcast_ref::<VolumeGrid>() {
                 render_pass.set_bind_group(
                     2,
@@ -344,54 +401,114 @@ pub(super) fn draw_volume_grid_isosurfaces<'a>(
 }
 
 /// Draw volume grid gridcubes to a wgpu render pass.
+///
+/// Opaque cubes are drawn first so that the see-through voxel blocks of
+/// grids in cube visualization blend over them.
 pub(super) fn draw_volume_grid_gridcubes<'a>(
     render_pass: &mut wgpu::RenderPass<'a>,
     engine: &'a RenderEngine,
 ) {
-    let Some(pipeline) = &engine.gridcube_pipeline else {
+    let (Some(pipeline), Some(transparent_pipeline)) = (
+        &engine.gridcube_pipeline,
+        &engine.gridcube_transparent_pipeline,
+    ) else {
         return;
     };
-    render_pass.set_pipeline(pipeline);
-    render_pass.set_bind_group(1, &engine.slice_plane_bind_group, &[]);
 
-    crate::with_context(|ctx| {
-        for structure in ctx.registry.iter() {
-            if !ctx.is_structure_visible(structure) || structure.type_name() != "VolumeGrid" {
-                continue;
-            }
-            if let Some(vg) = structure.as_any().downcast_ref::<VolumeGrid>() {
+    for see_through in [false, true] {
+        render_pass.set_pipeline(if see_through {
+            transparent_pipeline
+        } else {
+            pipeline
+        });
+
+        crate::with_context(|ctx| {
+            for structure in draw_sequence(ctx, &["VolumeGrid"]) {
+                let slice_plane_mask =
+                    ctx.slice_plane_mask(structure.type_name(), structure.name());
                 render_pass.set_bind_group(
-                    2,
-                    engine.matcap_bind_group_for(structure.material()),
+                    1,
+                    engine.slice_plane_bind_group_for(slice_plane_mask),
                     &[],
                 );
-                for quantity in vg.quantities() {
-                    if !quantity.is_enabled() {
-                        continue;
-                    }
-                    if let Some(nsq) = quantity
-                        .as_any()
-                        .downcast_ref::<VolumeGridNodeScalarQuantity>()
-                    {
-                        if nsq.viz_mode() == VolumeGridVizMode::Gridcube {
-                            if let Some(rd) = nsq.gridcube_render_data() {
-                                render_pass.set_bind_group(0, &rd.bind_group, &[]);
-                                // 36 vertices per cube instance
-                                render_pass.draw(0..rd.num_instances * 36, 0..1);
+                if let Some(vg) = structure.as_any().downcast_ref::<VolumeGrid>() {
+                    render_pass.set_bind_group(
+                        2,
+                        engine.matcap_bind_group_for(structure.material()),
+                        &[],
+                    );
+                    let cells_see_through = vg.cube_visualization() && vg.cube_transparency() > 0.0;
+                    for quantity in vg.quantities() {
+                        if !quantity.is_enabled() {
+                            continue;
+                        }
+                        if let Some(nsq) = quantity
+                            .as_any()
+                            .downcast_ref::<VolumeGridNodeScalarQuantity>()
+                        {
+                            if !see_through && nsq.viz_mode() == VolumeGridVizMode::Gridcube {
+                                if let Some(rd) = nsq.gridcube_render_data() {
+                                    render_pass.set_bind_group(0, &rd.bind_group, &[]);
+                                    // 36 vertices per cube instance
+                                    render_pass.draw(0..rd.num_instances * 36, 0..1);
+                                }
                             }
                         }
-                    }
-                    if let Some(csq) = quantity
-                        .as_any()
-                        .downcast_ref::<VolumeGridCellScalarQuantity>()
-                    {
-                        if let Some(rd) = csq.gridcube_render_data() {
-                            render_pass.set_bind_group(0, &rd.bind_group, &[]);
-                            render_pass.draw(0..rd.num_instances * 36, 0..1);
+                        if let Some(csq) = quantity
+                            .as_any()
+                            .downcast_ref::<VolumeGridCellScalarQuantity>()
+                        {
+                            if cells_see_through == see_through {
+                                if let Some(rd) = csq.gridcube_render_data() {
+                                    render_pass.set_bind_group(0, &rd.bind_group, &[]);
+                                    render_pass.draw(0..rd.num_instances * 36, 0..1);
+                                }
+                            }
                         }
                     }
                 }
             }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    #[test]
+    fn test_draw_sequence_follows_draw_order() {
+        let mut ctx = Context::default();
+        for name in ["a", "b", "c", "hidden"] {
+            ctx.registry
+                .register(Box::new(PointCloud::new(name, vec![Vec3::ZERO])))
+                .unwrap();
         }
-    });
+        let mesh = SurfaceMesh::new(
+            "mesh",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![vec![0, 1, 2]],
+        );
+        ctx.registry.register(Box::new(mesh)).unwrap();
+        ctx.registry.set_draw_order("PointCloud", "a", 5);
+        ctx.registry.set_draw_order("PointCloud", "b", -1);
+        ctx.registry.set_draw_order("SurfaceMesh", "mesh", -10);
+        ctx.registry
+            .get_mut("PointCloud", "hidden")
+            .unwrap()
+            .set_enabled(false);
+
+        // Only visible point clouds, lowest order first, ties by name
+        let names: Vec<&str> = draw_sequence(&ctx, &["PointCloud"])
+            .map(Structure::name)
+            .collect();
+        assert_eq!(names, ["b", "c", "a"]);
+
+        // A pass drawing several types interleaves them by order
+        let names: Vec<&str> = draw_sequence(&ctx, &["PointCloud", "SurfaceMesh"])
+            .map(Structure::name)
+            .collect();
+        assert_eq!(names, ["mesh", "b", "c", "a"]);
+    }
 }
@@ -1,9 +1,7 @@
 //! egui UI integration: panels, gizmos, settings synchronization.
 
-use super::{
-    App, CameraView, CurveNetwork, GroundPlaneMode, PointCloud, SurfaceMesh, Vec3, VolumeGrid,
-    VolumeMesh,
-};
+use super::{App, CameraView, CurveNetwork, PointCloud, SurfaceMesh, Vec3, VolumeGrid, VolumeMesh};
+use polyscope_structures::{FloatingColorImage, FloatingScalarImage};
 
 /// Result of building the UI for one frame.
 pub(super) struct UiResult {
@@ -26,24 +24,19 @@ impl App {
         let mut egui_output = egui::FullOutput::default();
 
         // Build UI (declare mutable state before the loop so it persists across passes)
-        let mut bg_color = [
-            self.background_color.x,
-            self.background_color.y,
-            self.background_color.z,
-        ];
+        let initial_bg_color = super::render_init::background_color();
+        let mut bg_color = initial_bg_color.to_array();
 
         // Extract ground plane settings for UI
-        let mut gp_mode = match self.ground_plane.mode {
-            GroundPlaneMode::None => 0u32,
-            GroundPlaneMode::Tile => 1u32,
-            GroundPlaneMode::ShadowOnly => 2u32,
-            GroundPlaneMode::TileReflection => 3u32,
-        };
-        let mut gp_height = self.ground_plane.height;
-        let mut gp_height_is_relative = self.ground_plane.height_is_relative;
-        let mut gp_shadow_blur_iters = self.ground_plane.shadow_blur_iters;
-        let mut gp_shadow_darkness = self.ground_plane.shadow_darkness;
-        let mut gp_reflection_intensity = self.ground_plane.reflection_intensity;
+        let ground_plane = crate::with_context(|ctx| ctx.options.ground_plane.clone());
+        let mut gp_draw_tile = ground_plane.draw_tile;
+        let mut gp_draw_shadow = ground_plane.draw_shadow;
+        let mut gp_draw_reflection = ground_plane.draw_reflection;
+        let mut gp_height = ground_plane.height;
+        let mut gp_height_is_relative = ground_plane.height_is_relative;
+        let mut gp_shadow_blur_iters = ground_plane.shadow_blur_iters;
+        let mut gp_shadow_darkness = ground_plane.shadow_darkness;
+        let mut gp_reflection_intensity = ground_plane.reflection_intensity;
 
         // Sync camera settings from engine
         self.camera_settings = crate::camera_to_settings(&engine.camera);
@@ -58,6 +51,10 @@ impl App {
         let mut ssaa_changed = false;
         let mut fly_to_camera: Option<polyscope_structures::CameraParameters> = None;
 
+        // Hidden UI (e.g. for clean screenshots) skips panels, windows and
+        // gizmos; the scene and labels still render
+        let ui_visible = crate::is_ui_visible();
+
         for egui_pass in 0..max_egui_passes {
             if egui_pass == 0 {
                 egui.begin_frame(window);
@@ -72,7 +69,7 @@ impl App {
                 fly_to_camera = None;
             }
 
-            let panel_width = polyscope_ui::build_left_panel(&egui.context, |ui| {
+            let panel_width = polyscope_ui::build_left_panel(&egui.context, ui_visible, |ui| {
                 let view_action = polyscope_ui::build_controls_section(ui, &mut bg_color);
                 match view_action {
                     polyscope_ui::ViewAction::Screenshot => {
@@ -94,7 +91,17 @@ impl App {
                     scene_extents_changed = true;
                 }
 
-                // Appearance settings panel
+                // Appearance settings panel (options may have been changed from code)
+                polyscope_core::with_context(|ctx| {
+                    let settings = &mut self.appearance_settings;
+                    settings.transparency_mode = ctx.options.transparency_mode.into();
+                    settings.ssao_enabled = ctx.options.ssao.enabled;
+                    settings.ssao_radius = ctx.options.ssao.radius;
+                    settings.ssao_intensity = ctx.options.ssao.intensity;
+                    settings.ssao_bias = ctx.options.ssao.bias;
+                    settings.ssao_sample_count = ctx.options.ssao.sample_count;
+                    settings.ssaa_factor = ctx.options.ssaa_factor;
+                });
                 if polyscope_ui::build_appearance_section(ui, &mut self.appearance_settings) {
                     // Sync SSAO settings to global options
                     polyscope_core::with_context_mut(|ctx| {
@@ -104,6 +111,8 @@ impl App {
                         ctx.options.ssao.bias = self.appearance_settings.ssao_bias;
                         ctx.options.ssao.sample_count = self.appearance_settings.ssao_sample_count;
                         ctx.options.ssaa_factor = self.appearance_settings.ssaa_factor;
+                        ctx.options.transparency_mode =
+                            self.appearance_settings.transparency_mode.into();
                     });
 
                     // Mark SSAA as changed (will apply outside closure)
@@ -111,10 +120,25 @@ impl App {
                 }
 
                 // Tone mapping settings panel
-                polyscope_ui::panels::build_tone_mapping_section(
-                    ui,
-                    &mut self.tone_mapping_settings,
-                );
+                let mut tone_mapping_settings = polyscope_core::with_context(|ctx| {
+                    let config = &ctx.options.tone_mapping;
+                    polyscope_ui::ToneMappingSettings {
+                        operator: config.operator.into(),
+                        exposure: config.exposure,
+                        white_level: config.white_level,
+                        gamma: config.gamma,
+                    }
+                });
+                if polyscope_ui::panels::build_tone_mapping_section(ui, &mut tone_mapping_settings)
+                {
+                    polyscope_core::with_context_mut(|ctx| {
+                        let config = &mut ctx.options.tone_mapping;
+                        config.operator = tone_mapping_settings.operator.into();
+                        config.exposure = tone_mapping_settings.exposure;
+                        config.white_level = tone_mapping_settings.white_level;
+                        config.gamma = tone_mapping_settings.gamma;
+                    });
+                }
 
                 // Material loading section
                 let material_action =
@@ -192,7 +216,9 @@ impl App {
 
                 polyscope_ui::build_ground_plane_section(
                     ui,
-                    &mut gp_mode,
+                    &mut gp_draw_tile,
+                    &mut gp_draw_shadow,
+                    &mut gp_draw_reflection,
                     &mut gp_height,
                     &mut gp_height_is_relative,
                     &mut gp_shadow_blur_iters,
@@ -287,10 +313,14 @@ impl App {
                 );
             });
             // Update dynamic panel width (with small margin to account for resize handle)
-            self.left_panel_width = f64::from(panel_width) + 5.0;
+            self.left_panel_width = if ui_visible {
+                f64::from(panel_width) + 5.0
+            } else {
+                0.0
+            };
 
             // Show selection panel if we have a selection
-            if let Some(ref selection) = self.selection {
+            if let Some(selection) = self.selection.as_ref().filter(|_| ui_visible) {
                 if selection.hit {
                     polyscope_ui::build_selection_panel(&egui.context, selection, |ui| {
                         // Structure-specific pick UI (placeholder for now)
@@ -299,6 +329,43 @@ impl App {
                 }
             }
 
+            // Show enabled floating images as overlay windows
+            crate::with_context_mut(|ctx| {
+                for quantity in &mut ctx.floating_quantities {
+                    if !ui_visible || !quantity.is_enabled() {
+                        continue;
+                    }
+                    let any = quantity.as_any_mut();
+                    let texture = if let Some(img) = any.downcast_mut::<FloatingColorImage>() {
+                        img.egui_texture(&egui.context).cloned()
+                    } else if let Some(img) = any.downcast_mut::<FloatingScalarImage>() {
+                        engine
+                            .color_maps
+                            .get(img.colormap_name())
+                            .or_else(|| engine.color_maps.get("viridis"))
+                            .and_then(|colormap| img.egui_texture(&egui.context, colormap))
+                            .cloned()
+                    } else {
+                        None
+                    };
+                    if let Some(texture) = texture {
+                        if !polyscope_ui::build_floating_image_window(
+                            &egui.context,
+                            quantity.name(),
+                            &texture,
+                        ) {
+                            quantity.set_enabled(false);
+                        }
+                    }
+                }
+            });
+
+            // Draw text labels at their projected anchors
+            let view_proj = engine.camera.view_projection_matrix();
+            crate::with_context(|ctx| {
+                polyscope_ui::draw_labels(&egui.context, ctx.labels.values(), view_proj);
+            });
+
             // Common gizmo setup - check if pointer is over UI panel
             let panel_w = self.left_panel_width as f32;
             let pointer_over_ui = egui
@@ -316,7 +383,7 @@ impl App {
             );
 
             // Render transform gizmo if visible and a structure is selected
-            if self.gizmo_settings.visible && self.selection_info.has_selection {
+            if ui_visible && self.gizmo_settings.visible && self.selection_info.has_selection {
                 // Use centroid for gizmo position (so it appears at the center of the geometry)
                 // but keep the rotation and scale from the actual transform
                 let current_transform = polyscope_ui::TransformGizmo::compose_transform(
@@ -356,6 +423,11 @@ impl App {
                             let old_rotation_deg =
                                 glam::Vec3::from(self.selection_info.rotation_degrees);
                             let old_scale = glam::Vec3::from(self.selection_info.scale);
+
+                            // Link and snap the scale before placing the centroid
+                            let new_scale = crate::with_context(|ctx| {
+                                ctx.gizmo().constrain_scale(old_scale, new_scale)
+                            });
                             let world_centroid = glam::Vec3::from(self.selection_info.centroid);
 
                             // Compute local centroid (center of geometry in object space)
@@ -423,6 +495,7 @@ impl App {
                                                 pc.update_gpu_buffers(
                                                     &engine.queue,
                                                     &engine.color_maps,
+                                                    ctx.options.no_data_color,
                                                 );
                                             }
                                         } else if type_name == "SurfaceMesh" {
@@ -432,6 +505,8 @@ impl App {
                                                 mesh.update_gpu_buffers(
                                                     &engine.queue,
                                                     &engine.color_maps,
+                                                    ctx.options.backface_cull,
+                                                    ctx.options.no_data_color,
                                                 );
                                             }
                                         } else if type_name == "CurveNetwork" {
@@ -479,7 +554,8 @@ impl App {
                 }
             }
 
-            if self.gizmo_settings.visible && self.slice_plane_selection.has_selection {
+            if ui_visible && self.gizmo_settings.visible && self.slice_plane_selection.has_selection
+            {
                 let current_transform = polyscope_ui::TransformGizmo::compose_transform(
                     glam::Vec3::from(self.slice_plane_selection.origin),
                     glam::Vec3::from(self.slice_plane_selection.rotation_degrees),
@@ -536,21 +612,66 @@ impl App {
                     });
             }
 
-            // Update background color if changed
-            self.background_color = Vec3::new(bg_color[0], bg_color[1], bg_color[2]);
+            // Render crop box gizmo when its widget is enabled and no other
+            // gizmo is active
+            let crop_box_widget = crate::crop_box::crop_box_widget_transform().filter(|_| {
+                ui_visible
+                    && !self.selection_info.has_selection
+                    && !self.slice_plane_selection.has_selection
+            });
+            if let Some(center_transform) = crop_box_widget {
+                egui::Area::new(egui::Id::new("crop_box_gizmo_overlay"))
+                    .fixed_pos(egui::Pos2::ZERO)
+                    .interactable(false)
+                    .show(&egui.context, |ui| {
+                        ui.set_clip_rect(full_window_viewport);
+
+                        if pointer_over_ui {
+                            return;
+                        }
+
+                        if let Some(new_transform) = self.transform_gizmo.interact(
+                            ui,
+                            view_matrix,
+                            projection_matrix,
+                            center_transform,
+                            self.gizmo_settings.local_space,
+                            full_window_viewport,
+                        ) {
+                            // The box keeps its extents; only move and rotate it
+                            let (_scale, rotation, translation) =
+                                new_transform.to_scale_rotation_translation();
+                            crate::crop_box::apply_crop_box_gizmo_transform(
+                                glam::Mat4::from_rotation_translation(rotation, translation),
+                            );
+                        }
+                    });
+            }
+
+            // Update background color from UI (only on change, so a color set
+            // from code during this frame is not overwritten)
+            if Vec3::from_array(bg_color) != initial_bg_color {
+                crate::with_context_mut(|ctx| {
+                    ctx.options.background_color = Vec3::from_array(bg_color).extend(1.0);
+                });
+            }
 
-            // Update ground plane settings from UI
-            self.ground_plane.mode = match gp_mode {
-                0 => GroundPlaneMode::None,
-                1 => GroundPlaneMode::Tile,
-                2 => GroundPlaneMode::ShadowOnly,
-                _ => GroundPlaneMode::TileReflection,
+            // Update ground plane settings from UI (only on change, so settings
+            // made from code during this frame are not overwritten)
+            let ui_ground_plane = polyscope_core::GroundPlaneConfig {
+                draw_tile: gp_draw_tile,
+                draw_shadow: gp_draw_shadow,
+                draw_reflection: gp_draw_reflection,
+                height: gp_height,
+                height_is_relative: gp_height_is_relative,
+                shadow_blur_iters: gp_shadow_blur_iters,
+                shadow_darkness: gp_shadow_darkness,
+                reflection_intensity: gp_reflection_intensity,
+                ..ground_plane
             };
-            self.ground_plane.height = gp_height;
-            self.ground_plane.height_is_relative = gp_height_is_relative;
-            self.ground_plane.shadow_blur_iters = gp_shadow_blur_iters;
-            self.ground_plane.shadow_darkness = gp_shadow_darkness;
-            self.ground_plane.reflection_intensity = gp_reflection_intensity;
+            if ui_ground_plane != ground_plane {
+                crate::with_context_mut(|ctx| ctx.options.ground_plane = ui_ground_plane);
+            }
 
             // Apply camera settings if changed
             if camera_changed {
@@ -586,12 +707,7 @@ impl App {
 
             // Reset camera to home view (matching C++ Polyscope's resetCameraToHomeView)
             if reset_view_requested {
-                let bbox = crate::with_context(|ctx| ctx.bounding_box);
-                let (min, max) = bbox;
-                if min.x.is_finite() && max.x.is_finite() && (max - min).length() > 0.0 {
-                    engine.camera.look_at_box(min, max);
-                    engine.camera.fov = std::f32::consts::FRAC_PI_4; // Reset FOV to default 45°
-                }
+                super::reset_view(engine);
             }
 
             // Fly to a camera view with smooth animation (matching C++ Polyscope's startFlightTo)
@@ -0,0 +1,42 @@
+//! Bounding box wireframes for individual structures.
+//!
+//! Structures are addressed by the type names accepted by
+//! [`set_structure_enabled`](crate::set_structure_enabled), e.g. `"SurfaceMesh"`.
+
+use crate::{with_context, with_context_mut};
+
+/// Shows or hides a wireframe of a structure's world-space bounding box.
+///
+/// The box follows the structure's transform, including gizmo edits, and is
+/// hidden while the structure itself is hidden. Useful for debugging layout.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::ONE]);
+/// set_structure_bbox_visible("PointCloud", "pts", true);
+/// assert!(is_structure_bbox_visible("PointCloud", "pts"));
+/// ```
+pub fn set_structure_bbox_visible(type_name: &str, name: &str, visible: bool) {
+    with_context_mut(|ctx| {
+        let key = (type_name.to_string(), name.to_string());
+        if visible {
+            ctx.bbox_visible.insert(key);
+        } else {
+            ctx.bbox_visible.remove(&key);
+        }
+    });
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns whether a structure's bounding box wireframe is shown.
+#[must_use]
+pub fn is_structure_bbox_visible(type_name: &str, name: &str) -> bool {
+    with_context(|ctx| {
+        ctx.bbox_visible
+            .contains(&(type_name.to_string(), name.to_string()))
+    })
+}
@@ -0,0 +1,146 @@
+//! Scene callbacks: file drop, per-frame user callback and pick callback.
+
+use crate::{PickResult, with_context, with_context_mut};
+
+/// Sets a callback that is invoked when files are dropped onto the polyscope window.
+///
+/// The callback receives a slice of file paths that were dropped.
+///
+/// # Example
+/// ```no_run
+/// polyscope_rs::set_file_drop_callback(|paths| {
+///     for path in paths {
+///         println!("Dropped: {}", path.display());
+///     }
+/// });
+/// ```
+pub fn set_file_drop_callback(callback: impl FnMut(&[std::path::PathBuf]) + Send + Sync + 'static) {
+    with_context_mut(|ctx| {
+        ctx.file_drop_callback = Some(Box::new(callback));
+    });
+}
+
+/// Clears the file drop callback.
+pub fn clear_file_drop_callback() {
+    with_context_mut(|ctx| {
+        ctx.file_drop_callback = None;
+    });
+}
+
+/// Sets a callback that is invoked once per frame while `show()` is running.
+///
+/// This matches C++ Polyscope's `state::userCallback`. The callback runs after
+/// window events are processed and before the frame is rendered, outside of
+/// any lock on the global context, so it can freely use the `with_*` helpers,
+/// register or remove structures, and even replace or clear itself.
+///
+/// A panic inside the callback is caught and logged; the viewer keeps running
+/// and calls the callback again on the next frame.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+///
+/// let mut t = 0.0f32;
+/// set_user_callback(move || {
+///     t += 0.01;
+///     set_point_cloud_transform("pts", Mat4::from_rotation_y(t));
+/// });
+/// show();
+/// ```
+pub fn set_user_callback(callback: impl FnMut() + Send + 'static) {
+    let callback: Box<dyn FnMut() + Send> = Box::new(callback);
+    with_context_mut(|ctx| {
+        ctx.user_callback = Some(std::sync::Arc::new(std::sync::Mutex::new(callback)));
+    });
+}
+
+/// Clears the per-frame user callback.
+pub fn clear_user_callback() {
+    with_context_mut(|ctx| {
+        ctx.user_callback = None;
+    });
+}
+
+/// Runs the per-frame user callback, if any (for internal use by App).
+///
+/// The callback is cloned out of the context first, so it runs without the
+/// context lock held.
+pub(crate) fn invoke_user_callback() {
+    let Some(callback) = with_context(|ctx| ctx.user_callback.clone()) else {
+        return;
+    };
+    let mut callback = callback
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (*callback)()));
+    if let Err(panic) = result {
+        log::error!("User callback panicked: {}", panic_message(&*panic));
+    }
+}
+
+/// Sets a callback that is invoked whenever a left-click in the viewport
+/// resolves to a pick.
+///
+/// The callback receives the picked structure and element, or `None` when
+/// the background was clicked. Drags (camera moves, gizmo handles) and clicks
+/// on slice planes do not fire it.
+///
+/// The clicked structure is already selected (as by
+/// [`select_structure`](crate::select_structure)) when the callback runs, and
+/// it runs without the context lock held, so it may call
+/// [`select_structure`](crate::select_structure) or
+/// [`deselect_structure`](crate::deselect_structure) to override the selection.
+///
+/// It also receives the results of [`pick_at`](crate::pick_at) calls made
+/// while the viewer is running, one frame later.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// set_pick_callback(|pick| match pick {
+///     Some(p) => println!("{} #{}", p.structure_name, p.element_index),
+///     None => println!("background"),
+/// });
+/// show();
+/// ```
+pub fn set_pick_callback(callback: impl FnMut(Option<PickResult>) + Send + 'static) {
+    let callback: Box<dyn FnMut(Option<PickResult>) + Send> = Box::new(callback);
+    with_context_mut(|ctx| {
+        ctx.pick_callback = Some(std::sync::Arc::new(std::sync::Mutex::new(callback)));
+    });
+}
+
+/// Clears the pick callback.
+pub fn clear_pick_callback() {
+    with_context_mut(|ctx| {
+        ctx.pick_callback = None;
+    });
+}
+
+/// Runs the pick callback, if any (for internal use by App).
+pub(crate) fn invoke_pick_callback(pick: Option<PickResult>) {
+    let Some(callback) = with_context(|ctx| ctx.pick_callback.clone()) else {
+        return;
+    };
+    let mut callback = callback
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (*callback)(pick)));
+    if let Err(panic) = result {
+        log::error!("Pick callback panicked: {}", panic_message(&*panic));
+    }
+}
+
+pub(crate) fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|s| (*s).to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
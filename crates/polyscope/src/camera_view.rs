@@ -27,7 +27,10 @@
 //! }
 //! ```
 
-use crate::{CameraParameters, CameraView, Vec3, with_context_mut};
+use crate::{
+    CameraParameters, CameraView, OrStructureNotFound, PolyscopeError, Result, Vec3,
+    with_context_mut,
+};
 
 /// Registers a camera view with polyscope using camera parameters.
 pub fn register_camera_view(name: impl Into<String>, params: CameraParameters) -> CameraViewHandle {
@@ -81,6 +84,18 @@ impl CameraViewHandle {
         &self.name
     }
 
+    /// Shows or hides this camera view; a hidden camera view is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("CameraView", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this camera view is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("CameraView", &self.name)
+    }
+
     /// Sets the widget color.
     pub fn set_color(&self, color: Vec3) -> &Self {
         with_camera_view(&self.name, |cv| {
@@ -112,4 +127,60 @@ impl CameraViewHandle {
         });
         self
     }
+
+    /// Displays an image in the camera's image frame.
+    ///
+    /// `rgba` holds 8-bit sRGB RGBA pixels, row by row starting from the top
+    /// row. The image fills the frame drawn by the widget, so it follows the
+    /// field of view, aspect ratio and widget focal length. Replaces any
+    /// previous image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the camera view does not exist or `rgba` does not
+    /// have `width * height * 4` entries.
+    pub fn add_image(&self, width: u32, height: u32, rgba: Vec<u8>) -> Result<&Self> {
+        with_camera_view(&self.name, |cv| {
+            cv.add_image(width, height, rgba).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Removes the image added with [`Self::add_image`], if any.
+    pub fn remove_image(&self) -> &Self {
+        with_camera_view(&self.name, |cv| {
+            cv.remove_image();
+        });
+        self
+    }
+
+    /// Renders the current scene from this camera and returns its depth buffer.
+    ///
+    /// Values are linear eye-space depths in scene units (meters if the
+    /// scene is in meters): the distance from the camera along its look
+    /// direction, not along the pixel ray. Pixels where nothing was drawn are
+    /// `f32::INFINITY`. The `width * height` values are row-by-row from the
+    /// top-left.
+    ///
+    /// Uses the camera's position, orientation and vertical field of view;
+    /// pass a `width / height` matching its aspect ratio for the same
+    /// framing. Renders with a separate headless GPU context, like
+    /// [`crate::render_to_image`]. The ground plane is not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the camera view does not exist or the render
+    /// fails, e.g. when no GPU adapter is available.
+    pub fn render_depth(&self, width: u32, height: u32) -> Result<Vec<f32>> {
+        let params = with_camera_view_ref(&self.name, |cv| *cv.params())
+            .ok_or_else(|| PolyscopeError::StructureNotFound(self.name.clone()))?;
+        crate::headless::render_depth_from_camera(&params, width, height)
+    }
+
+    /// Returns true if this camera view has an image.
+    #[must_use]
+    pub fn has_image(&self) -> bool {
+        with_camera_view_ref(&self.name, CameraView::has_image).unwrap_or(false)
+    }
 }
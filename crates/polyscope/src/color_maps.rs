@@ -0,0 +1,63 @@
+//! Custom color maps.
+
+use std::sync::Mutex;
+
+use crate::{ColorMap, ColorMapRegistry, Result};
+
+/// Custom color maps registered from user code, applied to every render engine.
+static CUSTOM_COLOR_MAPS: Mutex<Vec<ColorMap>> = Mutex::new(Vec::new());
+
+/// Registers a custom color map built from control points.
+///
+/// Each control point is a position in `[0, 1]` and an RGB color; colors are
+/// linearly interpolated between sorted points. Once registered, the map can
+/// be used by any scalar quantity via its name. Registering an existing name
+/// replaces that map.
+///
+/// # Errors
+///
+/// Returns [`crate::PolyscopeError::InvalidColorMap`] if fewer than two points
+/// are given or a position is outside `[0, 1]`.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// register_color_map(
+///     "blue_white_red",
+///     &[(0.0, [0.0, 0.0, 1.0]), (0.5, [1.0, 1.0, 1.0]), (1.0, [1.0, 0.0, 0.0])],
+/// )
+/// .unwrap();
+/// ```
+pub fn register_color_map(name: &str, control_points: &[(f32, [f32; 3])]) -> Result<()> {
+    let color_map = ColorMap::from_control_points(name, control_points)?;
+    let mut maps = CUSTOM_COLOR_MAPS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    maps.retain(|m| m.name != color_map.name);
+    maps.push(color_map);
+    Ok(())
+}
+
+/// Adds custom color maps missing from (or changed in) `registry` (for internal use by App).
+pub(crate) fn sync_custom_color_maps(registry: &mut ColorMapRegistry) {
+    let maps = CUSTOM_COLOR_MAPS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for map in maps.iter() {
+        if registry
+            .get(&map.name)
+            .is_none_or(|m| m.colors != map.colors)
+        {
+            registry.register(map.clone());
+        }
+    }
+}
+
+/// Returns a registry with the built-in and custom color maps, for validating
+/// color map names outside the render loop.
+pub(crate) fn color_map_registry() -> ColorMapRegistry {
+    let mut registry = ColorMapRegistry::new();
+    sync_custom_color_maps(&mut registry);
+    registry
+}
@@ -0,0 +1,98 @@
+//! Crop box clipping.
+//!
+//! The crop box hides everything outside a box, independently of slice
+//! planes. It can be axis-aligned or oriented, and moved interactively with
+//! the transform gizmo.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polyscope_rs::*;
+//!
+//! fn main() -> Result<()> {
+//!     init()?;
+//!
+//!     register_point_cloud("points", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+//!
+//!     // Only show the part of the scene inside the unit cube
+//!     set_crop_box(Some((Vec3::ZERO, Vec3::ONE)));
+//!     set_crop_box_widget_enabled(true);
+//!
+//!     show();
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{CropBox, Mat4, Vec3, with_context, with_context_mut};
+
+/// Sets an axis-aligned crop box between two corners, or removes it with `None`.
+///
+/// Fragments of every structure outside the box are discarded. Setting a new
+/// box resets its orientation; use [`set_crop_box_transform`] to orient it.
+pub fn set_crop_box(bounds: Option<(Vec3, Vec3)>) {
+    with_context_mut(|ctx| {
+        ctx.crop_box = bounds.map(|(min, max)| {
+            let mut crop_box = CropBox::new(min, max);
+            if let Some(old) = &ctx.crop_box {
+                crop_box.set_draw_widget(old.draw_widget());
+            }
+            crop_box
+        });
+    });
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns the corners of the crop box in its own frame, if one is set.
+#[must_use]
+pub fn crop_box() -> Option<(Vec3, Vec3)> {
+    with_context(|ctx| ctx.crop_box.as_ref().map(CropBox::bounds))
+}
+
+/// Sets the rigid transform placing the crop box frame in the world.
+///
+/// Does nothing if no crop box is set.
+pub fn set_crop_box_transform(transform: Mat4) {
+    with_context_mut(|ctx| {
+        if let Some(crop_box) = &mut ctx.crop_box {
+            crop_box.set_transform(transform);
+        }
+    });
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns the transform placing the crop box frame in the world, if one is set.
+#[must_use]
+pub fn crop_box_transform() -> Option<Mat4> {
+    with_context(|ctx| ctx.crop_box.as_ref().map(CropBox::transform))
+}
+
+/// Sets whether the transform gizmo is shown to drag the crop box around.
+///
+/// Does nothing if no crop box is set.
+pub fn set_crop_box_widget_enabled(enabled: bool) {
+    with_context_mut(|ctx| {
+        if let Some(crop_box) = &mut ctx.crop_box {
+            crop_box.set_draw_widget(enabled);
+        }
+    });
+}
+
+/// Returns the world transform of the crop box center if its widget is shown.
+pub(crate) fn crop_box_widget_transform() -> Option<Mat4> {
+    with_context(|ctx| {
+        ctx.crop_box
+            .as_ref()
+            .filter(|crop_box| crop_box.draw_widget())
+            .map(CropBox::center_transform)
+    })
+}
+
+/// Moves the crop box so that its center has the given world transform.
+pub(crate) fn apply_crop_box_gizmo_transform(center_transform: Mat4) {
+    with_context_mut(|ctx| {
+        if let Some(crop_box) = &mut ctx.crop_box {
+            crop_box.set_center_transform(center_transform);
+        }
+    });
+    crate::redraw::notify_scene_changed();
+}
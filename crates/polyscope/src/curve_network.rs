@@ -27,7 +27,12 @@
 //! }
 //! ```
 
-use crate::{CurveNetwork, Vec3, with_context_mut};
+use polyscope_structures::curve_network::{CurveNodeRadiusQuantity, CurveNodeScalarQuantity};
+
+use crate::{
+    CurveNetwork, CurveRenderMode, HasQuantities, OrStructureNotFound, PolyscopeError, Quantity,
+    Result, Vec3, with_context_mut,
+};
 
 /// Registers a curve network with explicit edges.
 pub fn register_curve_network(
@@ -102,6 +107,57 @@ pub fn register_curve_network_segments(
     CurveNetworkHandle { name }
 }
 
+/// Registers a curve network with explicit edges together with an enabled
+/// node scalar quantity drawn through `colormap`.
+///
+/// Returns [`PolyscopeError::SizeMismatch`] without registering anything if
+/// `values` does not have one entry per node.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// let nodes = vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0)];
+/// register_curve_network_with_node_scalar(
+///     "path",
+///     nodes,
+///     vec![[0, 1], [1, 2]],
+///     "arc length",
+///     vec![0.0, 1.0, 2.0],
+///     "viridis",
+/// )
+/// .unwrap();
+/// ```
+pub fn register_curve_network_with_node_scalar(
+    name: impl Into<String>,
+    nodes: Vec<Vec3>,
+    edges: Vec<[u32; 2]>,
+    scalar_name: &str,
+    values: Vec<f32>,
+    colormap: &str,
+) -> Result<CurveNetworkHandle> {
+    if values.len() != nodes.len() {
+        return Err(PolyscopeError::SizeMismatch {
+            expected: nodes.len(),
+            actual: values.len(),
+        });
+    }
+
+    let handle = register_curve_network(name, nodes, edges);
+    handle.add_node_scalar_quantity(scalar_name, values)?;
+    with_curve_network(&handle.name, |cn| {
+        if let Some(q) = cn
+            .get_quantity_mut(scalar_name)
+            .and_then(|q| q.as_any_mut().downcast_mut::<CurveNodeScalarQuantity>())
+        {
+            q.set_colormap(colormap);
+            q.set_enabled(true);
+        }
+    });
+    Ok(handle)
+}
+
 impl_structure_accessors! {
     get_fn = get_curve_network,
     with_fn = with_curve_network,
@@ -125,6 +181,18 @@ impl CurveNetworkHandle {
         &self.name
     }
 
+    /// Shows or hides this curve network; a hidden curve network is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("CurveNetwork", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this curve network is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("CurveNetwork", &self.name)
+    }
+
     /// Sets the base color.
     pub fn set_color(&self, color: Vec3) -> &Self {
         with_curve_network(&self.name, |cn| {
@@ -141,6 +209,38 @@ impl CurveNetworkHandle {
         self
     }
 
+    /// Sets whether edges are drawn as tubes (default) or lines. Line mode
+    /// ignores the radius and is much cheaper for large networks.
+    pub fn set_render_mode(&self, mode: CurveRenderMode) -> &Self {
+        with_curve_network(&self.name, |cn| {
+            cn.set_render_mode(mode);
+        });
+        self
+    }
+
+    /// Returns the render mode, or the default if the network does not exist.
+    #[must_use]
+    pub fn render_mode(&self) -> CurveRenderMode {
+        with_curve_network_ref(&self.name, CurveNetwork::render_mode).unwrap_or_default()
+    }
+
+    /// Sets the node sphere radius used in tube mode, independently of the
+    /// edge radius. Defaults to the edge radius.
+    pub fn set_node_radius(&self, radius: f32, is_relative: bool) -> &Self {
+        with_curve_network(&self.name, |cn| {
+            cn.set_node_radius(radius, is_relative);
+        });
+        self
+    }
+
+    /// Sets the transparency (0.0 = opaque, 1.0 = fully transparent).
+    pub fn set_transparency(&self, transparency: f32) -> &Self {
+        with_curve_network(&self.name, |cn| {
+            cn.set_transparency(transparency);
+        });
+        self
+    }
+
     /// Sets the material.
     pub fn set_material(&self, material: &str) -> &Self {
         with_curve_network(&self.name, |cn| {
@@ -148,4 +248,62 @@ impl CurveNetworkHandle {
         });
         self
     }
+
+    /// Adds a node scalar quantity, colored through a colormap.
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have one
+    /// entry per node.
+    pub fn add_node_scalar_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_curve_network(&self.name, |cn| {
+            cn.add_node_scalar_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a radius quantity that sizes each node sphere by a scalar value.
+    ///
+    /// Node spheres are drawn in tube mode, independently of node colors. By
+    /// default values are relative: the largest value maps to the node
+    /// radius. Use [`Self::set_node_radius_quantity_relative`] to use the
+    /// values directly as world-space radii. Negative values are clamped to
+    /// zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the curve network does not exist or `values` does
+    /// not have one entry per node.
+    pub fn add_node_scalar_radius_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_curve_network(&self.name, |cn| {
+            cn.add_node_scalar_radius_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Sets whether a node radius quantity is relative to the node radius
+    /// (`true`) or gives absolute world-space radii (`false`).
+    pub fn set_node_radius_quantity_relative(&self, quantity: &str, is_relative: bool) -> &Self {
+        with_curve_network(&self.name, |cn| {
+            if let Some(q) = cn
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<CurveNodeRadiusQuantity>())
+            {
+                q.set_radius_is_relative(is_relative);
+            }
+        });
+        self
+    }
+
+    /// Adds an edge scalar quantity, colored through a colormap.
+    ///
+    /// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have one
+    /// entry per edge.
+    pub fn add_edge_scalar_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_curve_network(&self.name, |cn| {
+            cn.add_edge_scalar_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
 }
@@ -0,0 +1,45 @@
+//! Draw order of structures.
+//!
+//! Structures are addressed by the type names accepted by
+//! [`set_structure_enabled`](crate::set_structure_enabled), e.g. `"SurfaceMesh"`.
+
+use crate::{with_context, with_context_mut};
+
+/// Sets the draw order of a structure (default 0).
+///
+/// The renderer draws structure types in fixed passes (point clouds, vectors
+/// and curves first, then surface and volume meshes, then volume grids), so
+/// the order only decides which structure comes first among those drawn in
+/// the same pass, typically structures of the same type. Within a pass,
+/// lower orders are drawn first and equal orders are sorted by type and
+/// name. Give an overlay mesh a high order to blend it over other meshes in
+/// `Simple` transparency; it cannot move a point cloud after a mesh.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("annotations", vec![Vec3::ZERO, Vec3::ONE]);
+/// set_draw_order("PointCloud", "annotations", 10);
+/// assert_eq!(get_draw_order("PointCloud", "annotations"), Some(10));
+/// ```
+pub fn set_draw_order(type_name: &str, name: &str, order: i32) {
+    let found = with_context_mut(|ctx| ctx.registry.set_draw_order(type_name, name, order));
+    if found {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!("set_draw_order: no {type_name} named '{name}'");
+    }
+}
+
+/// Returns the draw order of a structure, or `None` if it does not exist.
+#[must_use]
+pub fn get_draw_order(type_name: &str, name: &str) -> Option<i32> {
+    with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .map(polyscope_core::Structure::draw_order)
+    })
+}
@@ -0,0 +1,82 @@
+//! Running the viewer inside an externally owned event loop.
+//!
+//! [`show()`](crate::show) creates its own window and blocks in its own winit
+//! event loop. Applications that already run a winit loop can instead hand
+//! polyscope a window with [`attach_window`] and drive it themselves:
+//!
+//! - forward every event for that window with [`process_window_event`]
+//!   (input, resizes, focus, ...);
+//! - call [`frame_tick`] to render a frame, typically on `RedrawRequested`,
+//!   then request the next redraw.
+//!
+//! The caller owns the event loop and the window, and decides when to exit.
+//! All calls must happen on the thread that attached the window. Use the winit
+//! version re-exported as `polyscope_rs::winit`.
+//!
+//! See `examples/embedded_loop_demo.rs`.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+use crate::Result;
+use crate::app::App;
+
+thread_local! {
+    /// The viewer driven by the caller's event loop, if a window is attached.
+    static EMBEDDED_APP: RefCell<Option<App>> = const { RefCell::new(None) };
+}
+
+/// Attaches polyscope to a window created by the caller's event loop.
+///
+/// Creates the render engine and UI for the window, replacing any previously
+/// attached window.
+///
+/// # Errors
+///
+/// Returns an error if the render engine cannot be created for the window.
+pub fn attach_window(window: Arc<Window>) -> Result<()> {
+    let mut app = App::new();
+    app.attach_window(window)?;
+    EMBEDDED_APP.with_borrow_mut(|embedded| *embedded = Some(app));
+    Ok(())
+}
+
+/// Detaches the window attached with [`attach_window`] and releases its GPU
+/// resources.
+pub fn detach_window() {
+    EMBEDDED_APP.with_borrow_mut(|embedded| *embedded = None);
+}
+
+/// Forwards a window event to the attached viewer.
+///
+/// Handles camera navigation, UI input, picking clicks and resizes.
+/// `RedrawRequested` does not render; call [`frame_tick`] for that. Does
+/// nothing if no window is attached.
+pub fn process_window_event(event: &WindowEvent) {
+    EMBEDDED_APP.with_borrow_mut(|embedded| {
+        if let Some(app) = embedded {
+            app.handle_window_event(event.clone());
+        }
+    });
+}
+
+/// Renders one frame to the attached window and presents it.
+///
+/// Runs the same per-frame work as the [`show()`](crate::show) loop: the
+/// user callback, camera and uniform updates, drawing all structures and the
+/// UI. Returns `false` if the viewer asked to close (e.g. Escape was pressed
+/// or a `CloseRequested` event was forwarded) or no window is attached.
+#[must_use]
+pub fn frame_tick() -> bool {
+    EMBEDDED_APP.with_borrow_mut(|embedded| {
+        let Some(app) = embedded else {
+            log::warn!("frame_tick: no window attached (see attach_window)");
+            return false;
+        };
+        app.run_frame();
+        !app.close_requested
+    })
+}
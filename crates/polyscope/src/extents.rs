@@ -0,0 +1,52 @@
+//! Scene length scale override.
+//!
+//! The length scale is normally the diagonal of the scene bounding box and
+//! sizes slice planes, the ground plane offset, camera clip planes and
+//! widgets. A single outlier can inflate it, so it can be fixed manually.
+
+use crate::{Context, with_context, with_context_mut};
+
+/// Fixes the scene length scale, overriding the automatic value.
+///
+/// Registering or removing structures no longer changes it until
+/// [`reset_length_scale`] is called. Logs a warning and does nothing unless
+/// `scale` is finite and positive.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// // One far-away outlier would otherwise set the scale to ~1000
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::ONE, Vec3::splat(1000.0)]);
+/// set_length_scale(2.0);
+/// assert_eq!(get_length_scale(), 2.0);
+/// ```
+pub fn set_length_scale(scale: f32) {
+    if !(scale.is_finite() && scale > 0.0) {
+        log::warn!("set_length_scale: scale must be finite and positive, got {scale}");
+        return;
+    }
+    with_context_mut(|ctx| ctx.set_length_scale(scale));
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns to the automatic length scale, recomputed from the registered
+/// structures.
+pub fn reset_length_scale() {
+    with_context_mut(Context::reset_length_scale);
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns the current scene length scale.
+#[must_use]
+pub fn get_length_scale() -> f32 {
+    with_context(|ctx| ctx.length_scale)
+}
+
+/// Returns whether the length scale was set with [`set_length_scale`].
+#[must_use]
+pub fn is_length_scale_manual() -> bool {
+    with_context(|ctx| ctx.length_scale_is_manual)
+}
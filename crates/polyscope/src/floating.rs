@@ -1,4 +1,93 @@
-use crate::{Vec3, with_context_mut};
+use polyscope_structures::floating::{FloatingColorImage, FloatingScalarImage, ImageOrigin};
+
+use crate::{PolyscopeError, Quantity, Result, Vec3, Vec4, with_context_mut};
+
+/// Adds a color image, displayed in an overlay window.
+///
+/// `colors` holds one RGBA color per pixel, row by row starting from the top
+/// row (see [`set_floating_image_origin`] for images stored bottom-up). A
+/// floating quantity with the same name is replaced.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::SizeMismatch`] if `colors` does not have
+/// `width * height` entries.
+pub fn add_color_image(
+    name: impl Into<String>,
+    width: u32,
+    height: u32,
+    colors: Vec<Vec4>,
+) -> Result<()> {
+    check_image_size(width, height, colors.len())?;
+    add_floating_quantity(Box::new(FloatingColorImage::from_rgba(
+        name, width, height, colors,
+    )));
+    Ok(())
+}
+
+/// Adds a scalar image, displayed in an overlay window through a color map.
+///
+/// The values are mapped over their min/max range with the `viridis` color
+/// map. Pixel order and replacement behave as in [`add_color_image`].
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::SizeMismatch`] if `values` does not have
+/// `width * height` entries.
+pub fn add_scalar_image(
+    name: impl Into<String>,
+    width: u32,
+    height: u32,
+    values: Vec<f32>,
+) -> Result<()> {
+    check_image_size(width, height, values.len())?;
+    add_floating_quantity(Box::new(FloatingScalarImage::new(
+        name, width, height, values,
+    )));
+    Ok(())
+}
+
+/// Sets whether the first row of a floating image is its top row
+/// ([`ImageOrigin::UpperLeft`], the default) or its bottom row
+/// ([`ImageOrigin::LowerLeft`]).
+pub fn set_floating_image_origin(name: &str, origin: ImageOrigin) {
+    with_context_mut(|ctx| {
+        let Some(quantity) = ctx
+            .floating_quantities
+            .iter_mut()
+            .find(|q| q.name() == name)
+        else {
+            return;
+        };
+        let any = quantity.as_any_mut();
+        if let Some(img) = any.downcast_mut::<FloatingColorImage>() {
+            img.set_origin(origin);
+        } else if let Some(img) = any.downcast_mut::<FloatingScalarImage>() {
+            img.set_origin(origin);
+        }
+    });
+}
+
+fn check_image_size(width: u32, height: u32, len: usize) -> Result<()> {
+    let expected = width as usize * height as usize;
+    if len == expected {
+        Ok(())
+    } else {
+        Err(PolyscopeError::SizeMismatch {
+            expected,
+            actual: len,
+        })
+    }
+}
+
+/// Adds a floating quantity, replacing one with the same name.
+fn add_floating_quantity(quantity: Box<dyn Quantity>) {
+    with_context_mut(|ctx| {
+        ctx.floating_quantities
+            .retain(|q| q.name() != quantity.name());
+        ctx.floating_quantities.push(quantity);
+    });
+}
 
 /// Registers a floating scalar image (not attached to any structure).
 pub fn register_floating_scalar_image(
@@ -7,7 +96,6 @@ pub fn register_floating_scalar_image(
     height: u32,
     values: Vec<f32>,
 ) {
-    use polyscope_structures::floating::FloatingScalarImage;
     let img = FloatingScalarImage::new(name, width, height, values);
     with_context_mut(|ctx| {
         ctx.floating_quantities.push(Box::new(img));
@@ -21,7 +109,6 @@ pub fn register_floating_color_image(
     height: u32,
     colors: Vec<Vec3>,
 ) {
-    use polyscope_structures::floating::FloatingColorImage;
     let img = FloatingColorImage::new(name, width, height, colors);
     with_context_mut(|ctx| {
         ctx.floating_quantities.push(Box::new(img));
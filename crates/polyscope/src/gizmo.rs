@@ -1,5 +1,10 @@
+use std::sync::Mutex;
+
 use crate::{GizmoMode, GizmoSpace, Mat4, with_context, with_context_mut};
 
+/// Selection last reported to the selection changed callback.
+static NOTIFIED_SELECTION: Mutex<Option<(String, String)>> = Mutex::new(None);
+
 /// Selects a structure for gizmo manipulation.
 ///
 /// Only one structure can be selected at a time. The gizmo will appear
@@ -8,6 +13,7 @@ pub fn select_structure(type_name: &str, name: &str) {
     with_context_mut(|ctx| {
         ctx.select_structure(type_name, name);
     });
+    notify_selection_changed();
 }
 
 /// Deselects the currently selected structure.
@@ -15,6 +21,76 @@ pub fn deselect_structure() {
     with_context_mut(|ctx| {
         ctx.deselect_structure();
     });
+    notify_selection_changed();
+}
+
+/// Sets a callback that is invoked whenever the selected structure changes.
+///
+/// The callback receives the new selection as `(type_name, name)`, or `None`
+/// after deselection. It fires once per change, whether the selection was
+/// made in the viewer (clicking a structure or the background, selecting a
+/// slice plane) or with [`select_structure`] / [`deselect_structure`];
+/// selecting the structure that is already selected does not fire it. It
+/// runs without the context lock held.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// set_selection_changed_callback(|selection| match selection {
+///     Some((type_name, name)) => println!("selected {type_name} '{name}'"),
+///     None => println!("deselected"),
+/// });
+/// show();
+/// ```
+pub fn set_selection_changed_callback(
+    callback: impl FnMut(Option<(String, String)>) + Send + 'static,
+) {
+    let callback: Box<dyn FnMut(_) + Send> = Box::new(callback);
+    with_context_mut(|ctx| {
+        ctx.selection_callback = Some(std::sync::Arc::new(Mutex::new(callback)));
+    });
+}
+
+/// Clears the selection changed callback.
+pub fn clear_selection_changed_callback() {
+    with_context_mut(|ctx| {
+        ctx.selection_callback = None;
+    });
+}
+
+/// Runs the selection changed callback if the selection differs from the
+/// one last reported (for internal use by App and the selection functions).
+pub(crate) fn notify_selection_changed() {
+    let (selection, callback) = with_context(|ctx| {
+        (
+            ctx.selected_structure.clone(),
+            ctx.selection_callback.clone(),
+        )
+    });
+    {
+        let mut notified = NOTIFIED_SELECTION
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if *notified == selection {
+            return;
+        }
+        notified.clone_from(&selection);
+    }
+    let Some(callback) = callback else {
+        return;
+    };
+    let mut callback = callback
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (*callback)(selection)));
+    if let Err(panic) = result {
+        log::error!(
+            "Selection changed callback panicked: {}",
+            crate::panic_message(&*panic)
+        );
+    }
 }
 
 /// Returns the currently selected structure, if any.
@@ -109,6 +185,7 @@ pub fn set_selected_transform(transform: Mat4) {
             }
         }
     });
+    crate::redraw::notify_scene_changed();
 }
 
 /// Gets the transform of the currently selected structure.
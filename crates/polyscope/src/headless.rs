@@ -4,19 +4,51 @@
 //! without opening a window. Useful for integration tests, batch processing,
 //! and automated screenshot generation.
 
-use crate::Result;
+use std::sync::Mutex;
+
 use crate::app::App;
+use crate::{CameraParameters, PickResult, ProjectionMode, Result, ScreenshotOptions};
 use pollster::FutureExt;
 use polyscope_core::state::with_context_mut;
 use polyscope_render::RenderEngine;
 
+/// Size used by [`capture_screenshot_rgba`] when no window has been shown yet.
+/// Matches the initial size of the viewer window.
+const DEFAULT_CAPTURE_SIZE: (u32, u32) = (1280, 720);
+
+/// Framebuffer size of the most recently shown window, if any.
+static FRAME_SIZE: Mutex<Option<(u32, u32)>> = Mutex::new(None);
+
+/// Pixels queued by [`pick_at`] while the viewer is running.
+static PENDING_PICKS: Mutex<Vec<(f32, f32)>> = Mutex::new(Vec::new());
+
+/// Records the current window framebuffer size for later captures.
+pub(crate) fn record_frame_size(width: u32, height: u32) {
+    if width == 0 || height == 0 {
+        return;
+    }
+    if let Ok(mut guard) = FRAME_SIZE.lock() {
+        *guard = Some((width, height));
+    }
+}
+
+/// Returns the size that [`capture_screenshot_rgba`] renders at.
+pub(crate) fn current_frame_size() -> (u32, u32) {
+    FRAME_SIZE
+        .lock()
+        .ok()
+        .and_then(|guard| *guard)
+        .unwrap_or(DEFAULT_CAPTURE_SIZE)
+}
+
 /// Renders the current scene to a file.
 ///
 /// Creates a headless GPU context, renders one frame of the current scene
 /// (all registered structures and quantities), and saves the result as
 /// a PNG or JPEG image.
 ///
-/// The camera is automatically fitted to the scene bounding box.
+/// The camera matches the last frame shown in the viewer. Without a window it
+/// is automatically fitted to the scene bounding box.
 ///
 /// # Example
 /// ```no_run
@@ -50,8 +82,243 @@ pub fn render_to_file(filename: &str, width: u32, height: u32) -> Result<()> {
 /// assert_eq!(pixels.len(), 800 * 600 * 4);
 /// ```
 pub fn render_to_image(width: u32, height: u32) -> Result<Vec<u8>> {
-    let mut app = App::new();
+    render_pixels(width, height, &ScreenshotOptions::default())
+}
+
+/// Renders the current scene headlessly and returns it as a PNG-encoded image.
+///
+/// Draws every registered structure once, including enabled quantities,
+/// materials, and slice planes, using the same per-structure draw code as the
+/// windowed viewer. The camera matches the last frame shown in the viewer, or
+/// is fitted to the scene bounding box (as on the first frame of `show()`) if
+/// no window has been shown.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// let png = render_headless(800, 600).unwrap();
+/// std::fs::write("output.png", png).unwrap();
+/// ```
+pub fn render_headless(width: u32, height: u32) -> Result<Vec<u8>> {
+    let pixels = render_to_image(width, height)?;
+    polyscope_render::encode_png(pixels, width, height)
+        .map_err(|e| crate::PolyscopeError::RenderError(format!("Failed to encode PNG: {e}")))
+}
+
+/// Captures the current scene into an in-memory RGBA buffer.
+///
+/// Performs a synchronous headless render at the current window size (or
+/// 1280x720 if no window has been shown) and returns `(width, height, pixels)`,
+/// where `pixels` holds tightly packed RGBA8 data, row-by-row from the top-left.
+/// Works without a window. Returns `None` if the render fails, e.g. when no
+/// GPU adapter is available.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// if let Some((width, height, pixels)) = capture_screenshot_rgba() {
+///     assert_eq!(pixels.len(), (width * height * 4) as usize);
+/// }
+/// ```
+#[must_use]
+pub fn capture_screenshot_rgba() -> Option<(u32, u32, Vec<u8>)> {
+    capture_screenshot_rgba_with_options(ScreenshotOptions::default())
+}
+
+/// Captures the current scene into an in-memory RGBA buffer with custom options.
+///
+/// Like [`capture_screenshot_rgba`], but with `transparent_background` set the
+/// background pixels have an alpha of zero, `size` overrides the frame size,
+/// and `ortho_world_height` renders an orthographic view at an exact scale.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// // 800 px tall covering 4 world units: 200 px per unit
+/// let options = ScreenshotOptions {
+///     size: Some((800, 800)),
+///     ortho_world_height: Some(4.0),
+///     ..Default::default()
+/// };
+/// let capture = capture_screenshot_rgba_with_options(options);
+/// ```
+#[must_use]
+pub fn capture_screenshot_rgba_with_options(
+    options: ScreenshotOptions,
+) -> Option<(u32, u32, Vec<u8>)> {
+    let (width, height) = options.size.unwrap_or_else(current_frame_size);
+    match render_pixels(width, height, &options) {
+        Ok(pixels) => Some((width, height, pixels)),
+        Err(e) => {
+            log::warn!("Failed to capture screenshot: {e}");
+            None
+        }
+    }
+}
+
+/// Captures the per-pixel surface normals of the current scene.
+///
+/// Renders one headless frame like [`capture_screenshot_rgba`] and returns
+/// `(width, height, normals)` with one `[x, y, z, w]` per pixel, row-by-row
+/// from the top-left. `xyz` is the unit normal in view space (+X right, +Y
+/// up, +Z toward the camera); `w` is the surface opacity, zero where no mesh
+/// was drawn. Only surface meshes, volume meshes and volume grid cubes write
+/// normals. Returns `None` if the render fails, e.g. when no GPU adapter is
+/// available.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_surface_mesh(
+///     "quad",
+///     vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0), Vec3::Y],
+///     vec![vec![0, 1, 2], vec![0, 2, 3]],
+/// );
+/// if let Some((width, height, normals)) = capture_normals() {
+///     let center = normals[(height / 2 * width + width / 2) as usize];
+///     println!("normal at center: {center:?}");
+/// }
+/// ```
+#[must_use]
+pub fn capture_normals() -> Option<(u32, u32, Vec<[f32; 4]>)> {
+    let (width, height) = current_frame_size();
+    let normals = headless_app(width, height).and_then(|mut app| {
+        app.render_frame_headless();
+        app.capture_normals_to_buffer()
+    });
+    match normals {
+        Ok(normals) => Some((width, height, normals)),
+        Err(e) => {
+            log::warn!("Failed to capture normals: {e}");
+            None
+        }
+    }
+}
 
+/// Picks the structure element at a pixel of the viewer.
+///
+/// Runs the GPU pick pass with a fresh headless engine and returns the
+/// structure and element under pixel `(x, y)`, measured from the top-left of
+/// the frame, or `None` for the background. Surface mesh picks also carry the
+/// barycentric weights within the hit triangle.
+///
+/// The frame has the size and camera of the last frame shown in the viewer.
+/// Before any window was shown it is 1280x720 with the camera fitted to the
+/// scene, as in [`render_to_image`]. Returns `None` if picking fails, e.g.
+/// when no GPU adapter is available.
+///
+/// While `show()` is running (e.g. when called from a user callback) the
+/// pick is deferred instead: this returns `None` and the result is passed to
+/// the callback set with [`set_pick_callback`](crate::set_pick_callback) once
+/// the next frame has been drawn. Deferred picks do not change the selection.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO]);
+/// if let Some(pick) = pick_at(640.0, 360.0) {
+///     println!("{} #{}", pick.structure_name, pick.element_index);
+/// }
+/// ```
+#[must_use]
+pub fn pick_at(x: f32, y: f32) -> Option<PickResult> {
+    if crate::redraw::window_open() {
+        PENDING_PICKS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push((x, y));
+        crate::request_redraw();
+        return None;
+    }
+    let (width, height) = current_frame_size();
+    match headless_app(width, height).and_then(|mut app| app.pick_headless(x, y)) {
+        Ok(pick) => pick,
+        Err(e) => {
+            log::warn!("Failed to pick: {e}");
+            None
+        }
+    }
+}
+
+/// Takes the picks deferred by [`pick_at`] while the viewer is running.
+pub(crate) fn take_pending_picks() -> Vec<(f32, f32)> {
+    std::mem::take(
+        &mut *PENDING_PICKS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    )
+}
+
+/// Renders one frame of the current scene with a fresh headless engine.
+pub(crate) fn render_pixels(
+    width: u32,
+    height: u32,
+    options: &ScreenshotOptions,
+) -> Result<Vec<u8>> {
+    if let Some(world_height) = options.ortho_world_height {
+        if !(world_height.is_finite() && world_height > 0.0) {
+            return Err(crate::PolyscopeError::RenderError(format!(
+                "ortho_world_height must be finite and positive, got {world_height}"
+            )));
+        }
+    }
+    let mut app = headless_app(width, height)?;
+    app.transparent_background = options.transparent_background;
+    app.ortho_world_height = options.ortho_world_height;
+
+    // Render one frame and capture
+    app.render_frame_headless();
+    app.capture_to_buffer()
+}
+
+/// Renders the scene from a camera pose and reads back linear depth
+/// (for internal use by `CameraViewHandle::render_depth`).
+///
+/// Uses the vertical field of view of `params`; the horizontal extent
+/// follows `width / height`.
+pub(crate) fn render_depth_from_camera(
+    params: &CameraParameters,
+    width: u32,
+    height: u32,
+) -> Result<Vec<f32>> {
+    let mut app = headless_app(width, height)?;
+    let (center, length_scale) = crate::with_context(|ctx| (ctx.center(), ctx.length_scale));
+    let length_scale = if length_scale > 0.0 {
+        length_scale
+    } else {
+        1.0
+    };
+
+    if let Some(engine) = app.engine.as_mut() {
+        let camera = &mut engine.camera;
+        camera.projection_mode = ProjectionMode::Perspective;
+        camera.position = params.position();
+        camera.target = params.position() + params.look_dir();
+        camera.up = params.camera_frame().1;
+        camera.fov = params.fov_vertical_degrees().to_radians();
+        // Clip planes cover the whole scene as seen from the camera
+        camera.near = length_scale * 0.001;
+        camera.far = (center - params.position()).length() + length_scale * 100.0;
+    }
+    app.render_depth_headless()
+}
+
+/// Creates an app with a fresh headless engine of the given size.
+fn headless_app(width: u32, height: u32) -> Result<App> {
+    let mut app = App::new();
     // Create headless render engine
     let engine = RenderEngine::new_headless(width, height)
         .block_on()
@@ -70,7 +337,5 @@ pub fn render_to_image(width: u32, height: u32) -> Result<Vec<u8>> {
         }
     });
 
-    // Render one frame and capture
-    app.render_frame_headless();
-    app.capture_to_buffer()
+    Ok(app)
 }
@@ -37,6 +37,38 @@ pub fn is_initialized() -> bool {
     polyscope_core::state::is_initialized()
 }
 
+/// Returns the number of frames the viewer has started since [`init()`].
+///
+/// Incremented once per frame, before the user callback runs, so it can be
+/// read from the callback to drive animations.
+#[must_use]
+pub fn frame_count() -> u64 {
+    crate::with_context(|ctx| ctx.frame_count)
+}
+
+/// Returns the time in seconds from [`init()`] to the start of the current
+/// frame.
+///
+/// The value is sampled once per frame, so every call within one user
+/// callback sees the same time.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// let pc = register_point_cloud("pts", vec![Vec3::ZERO]);
+/// set_user_callback(move || {
+///     pc.set_transparency(0.5 + 0.5 * elapsed_seconds().sin());
+/// });
+/// show();
+/// ```
+#[must_use]
+pub fn elapsed_seconds() -> f32 {
+    crate::with_context(|ctx| ctx.elapsed_seconds)
+}
+
 /// Shuts down polyscope and releases all resources.
 ///
 /// This clears all registered structures and resets the global state.
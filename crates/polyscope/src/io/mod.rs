@@ -0,0 +1,98 @@
+//! Geometry file import.
+//!
+//! Loaders parse common file formats into plain vertex/face data that can be
+//! passed straight to the registration functions.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polyscope_rs::*;
+//!
+//! fn main() -> Result<()> {
+//!     init()?;
+//!
+//!     load_ply("bunny.ply")?.register("bunny");
+//!
+//!     show();
+//!     Ok(())
+//! }
+//! ```
+
+mod obj;
+mod ply;
+mod stl;
+
+pub use obj::*;
+pub use ply::*;
+pub use stl::*;
+
+use crate::{Vec3, register_point_cloud, register_surface_mesh};
+
+/// Suggested quantity name for per-vertex colors found in a loaded file.
+pub const LOADED_COLOR_QUANTITY_NAME: &str = "vertex color";
+
+/// Suggested quantity name for texture coordinates found in a loaded file.
+pub const LOADED_UV_QUANTITY_NAME: &str = "uv";
+
+/// Geometry loaded from a file.
+///
+/// Files with face elements produce a [`LoadedGeometry::Mesh`], files with
+/// only vertices produce a [`LoadedGeometry::Points`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadedGeometry {
+    /// A polygon mesh. Faces may have any number (>= 3) of vertices.
+    Mesh {
+        /// Vertex positions.
+        vertices: Vec<Vec3>,
+        /// Polygon faces as lists of vertex indices.
+        faces: Vec<Vec<u32>>,
+        /// Per-vertex RGB colors in [0, 1], if the file provides them.
+        colors: Option<Vec<Vec3>>,
+    },
+    /// A point set without connectivity.
+    Points {
+        /// Point positions.
+        points: Vec<Vec3>,
+        /// Per-point RGB colors in [0, 1], if the file provides them.
+        colors: Option<Vec<Vec3>>,
+    },
+}
+
+impl LoadedGeometry {
+    /// Returns the per-vertex colors, if the file provided them.
+    #[must_use]
+    pub fn colors(&self) -> Option<&[Vec3]> {
+        match self {
+            Self::Mesh { colors, .. } | Self::Points { colors, .. } => colors.as_deref(),
+        }
+    }
+
+    /// Registers the geometry as a surface mesh or point cloud.
+    ///
+    /// If the file provided per-vertex colors, they are added as a color
+    /// quantity named [`LOADED_COLOR_QUANTITY_NAME`].
+    pub fn register(self, name: impl Into<String>) {
+        match self {
+            Self::Mesh {
+                vertices,
+                faces,
+                colors,
+            } => {
+                let mesh = register_surface_mesh(name, vertices, faces);
+                if let Some(colors) = colors {
+                    if let Err(e) =
+                        mesh.add_vertex_color_quantity(LOADED_COLOR_QUANTITY_NAME, colors)
+                    {
+                        log::warn!("Skipping loaded vertex colors: {e}");
+                    }
+                }
+            }
+            Self::Points { points, colors } => {
+                let pc = register_point_cloud(name, points);
+                if let Some(colors) = colors {
+                    pc.add_color_quantity(LOADED_COLOR_QUANTITY_NAME, colors);
+                }
+            }
+        }
+    }
+}
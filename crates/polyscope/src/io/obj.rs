@@ -0,0 +1,313 @@
+//! Wavefront OBJ import.
+//!
+//! Interprets `v`, `vt`, `vn` and `f` statements. Groups, materials,
+//! smoothing groups, lines and any other statements are ignored.
+
+use std::path::Path;
+
+use super::LOADED_UV_QUANTITY_NAME;
+use crate::{PolyscopeError, Result, SurfaceMeshHandle, Vec2, Vec3, register_surface_mesh};
+
+/// A polygon mesh loaded from an OBJ file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjMesh {
+    /// Vertex positions.
+    pub vertices: Vec<Vec3>,
+    /// Polygon faces as lists of vertex indices (n-gons are preserved).
+    pub faces: Vec<Vec<u32>>,
+    /// Per-vertex texture coordinates, if every face corner references one
+    /// and each vertex is always given the same coordinates.
+    pub uvs: Option<Vec<Vec2>>,
+    /// Per-corner texture coordinates in face order, if every face corner
+    /// references one. Unlike `uvs`, these keep texture seams.
+    pub corner_uvs: Option<Vec<Vec2>>,
+    /// Per-vertex normals, under the same conditions as `uvs`.
+    pub normals: Option<Vec<Vec3>>,
+}
+
+impl ObjMesh {
+    /// Registers the mesh as a surface mesh.
+    ///
+    /// Texture coordinates are added as a parameterization quantity named
+    /// [`LOADED_UV_QUANTITY_NAME`]: per-vertex if possible, otherwise per
+    /// corner. Per-vertex normals replace the computed vertex normals (see
+    /// [`SurfaceMesh::set_vertex_normals`](crate::SurfaceMesh::set_vertex_normals)).
+    pub fn register(self, name: impl Into<String>) -> SurfaceMeshHandle {
+        let mesh = register_surface_mesh(name, self.vertices, self.faces);
+        if let Some(normals) = self.normals {
+            if let Some(Err(e)) =
+                crate::with_surface_mesh(mesh.name(), |m| m.set_vertex_normals(normals))
+            {
+                log::warn!("Skipping loaded normals: {e}");
+            }
+        }
+        let added = match (self.uvs, self.corner_uvs) {
+            (Some(uvs), _) => {
+                mesh.add_vertex_parameterization_quantity(LOADED_UV_QUANTITY_NAME, uvs)
+            }
+            (None, Some(uvs)) => {
+                mesh.add_corner_parameterization_quantity(LOADED_UV_QUANTITY_NAME, uvs)
+            }
+            (None, None) => Ok(&mesh),
+        };
+        if let Err(e) = added {
+            log::warn!("Skipping loaded texture coordinates: {e}");
+        }
+        mesh
+    }
+}
+
+/// Loads an OBJ file.
+///
+/// Faces may reference `v`, `v/vt`, `v//vn` or `v/vt/vn`; only the position
+/// index defines the geometry. Negative indices count back from the most
+/// recent element, as in the OBJ specification.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, a face has fewer than three
+/// vertices, or an index is malformed or out of range.
+pub fn load_obj(path: impl AsRef<Path>) -> Result<ObjMesh> {
+    let bytes = std::fs::read(path)?;
+    parse_obj(&bytes)
+}
+
+/// Loads an OBJ file and registers it as a surface mesh.
+///
+/// Shorthand for [`load_obj`] followed by [`ObjMesh::register`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// fn main() -> Result<()> {
+///     init()?;
+///     register_obj("spot", "spot.obj")?;
+///     show();
+///     Ok(())
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be loaded (see [`load_obj`]).
+pub fn register_obj(name: impl Into<String>, path: impl AsRef<Path>) -> Result<SurfaceMeshHandle> {
+    Ok(load_obj(path)?.register(name))
+}
+
+/// Parses OBJ data from an in-memory buffer.
+///
+/// See [`load_obj`] for details.
+pub fn parse_obj(bytes: &[u8]) -> Result<ObjMesh> {
+    let text = std::str::from_utf8(bytes).map_err(|_| obj_error(0, "file is not valid UTF-8"))?;
+
+    let mut vertices = Vec::new();
+    let mut tex_coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut faces = Vec::new();
+    // Per face corner: (texture coordinate index, normal index)
+    let mut corners: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+
+    for (line_idx, line) in text.lines().enumerate() {
+        let line_no = line_idx + 1;
+        let line = line.split('#').next().unwrap_or_default();
+        let mut tokens = line.split_ascii_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+        let mut floats = |count: usize| -> Result<Vec<f32>> {
+            let values: Vec<f32> = tokens
+                .by_ref()
+                .take(count)
+                .map(str::parse)
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|_| obj_error(line_no, "invalid number"))?;
+            if values.len() < count {
+                return Err(obj_error(line_no, "too few coordinates"));
+            }
+            Ok(values)
+        };
+        match keyword {
+            "v" => {
+                let v = floats(3)?;
+                vertices.push(Vec3::new(v[0], v[1], v[2]));
+            }
+            "vt" => {
+                let t = floats(2)?;
+                tex_coords.push(Vec2::new(t[0], t[1]));
+            }
+            "vn" => {
+                let n = floats(3)?;
+                normals.push(Vec3::new(n[0], n[1], n[2]));
+            }
+            "f" => {
+                let mut face = Vec::new();
+                for corner in tokens {
+                    let mut parts = corner.split('/');
+                    let mut next_index = |len: usize| match parts.next() {
+                        None | Some("") => Ok(None),
+                        Some(s) => resolve_index(s, len)
+                            .map(Some)
+                            .ok_or_else(|| obj_error(line_no, "invalid or out-of-range index")),
+                    };
+                    let v = next_index(vertices.len())?
+                        .ok_or_else(|| obj_error(line_no, "face corner without a vertex"))?;
+                    let vt = next_index(tex_coords.len())?;
+                    let vn = next_index(normals.len())?;
+                    face.push(v as u32);
+                    corners.push((vt, vn));
+                }
+                if face.len() < 3 {
+                    return Err(obj_error(line_no, "face with fewer than 3 vertices"));
+                }
+                faces.push(face);
+            }
+            _ => {}
+        }
+    }
+
+    let corner_uvs: Option<Vec<Vec2>> = corners
+        .iter()
+        .map(|&(vt, _)| vt.map(|i| tex_coords[i]))
+        .collect();
+    let corner_normals: Option<Vec<Vec3>> = corners
+        .iter()
+        .map(|&(_, vn)| vn.map(|i| normals[i]))
+        .collect();
+    let uvs = corner_uvs
+        .as_deref()
+        .and_then(|c| per_vertex(vertices.len(), &faces, c));
+    let normals = corner_normals
+        .as_deref()
+        .and_then(|c| per_vertex(vertices.len(), &faces, c));
+
+    Ok(ObjMesh {
+        vertices,
+        faces,
+        uvs,
+        corner_uvs: corner_uvs.filter(|c| !c.is_empty()),
+        normals,
+    })
+}
+
+fn obj_error(line: usize, msg: &str) -> PolyscopeError {
+    if line == 0 {
+        PolyscopeError::FileLoadError(format!("OBJ: {msg}"))
+    } else {
+        PolyscopeError::FileLoadError(format!("OBJ line {line}: {msg}"))
+    }
+}
+
+/// Resolves a 1-based (or negative, relative) OBJ index against `len`
+/// elements seen so far.
+fn resolve_index(s: &str, len: usize) -> Option<usize> {
+    let index: i64 = s.parse().ok()?;
+    let resolved = if index < 0 {
+        i64::try_from(len).ok()? + index
+    } else {
+        index - 1
+    };
+    usize::try_from(resolved).ok().filter(|&i| i < len)
+}
+
+/// Collapses per-corner values to per-vertex values, or returns `None` if a
+/// vertex gets different values at different corners (a seam) or is not
+/// referenced by any face.
+fn per_vertex<T: Copy + PartialEq>(
+    num_vertices: usize,
+    faces: &[Vec<u32>],
+    corner_values: &[T],
+) -> Option<Vec<T>> {
+    if corner_values.is_empty() {
+        return None;
+    }
+    let mut values: Vec<Option<T>> = vec![None; num_vertices];
+    for (&v, &value) in faces.iter().flatten().zip(corner_values) {
+        match values[v as usize] {
+            Some(existing) if existing != value => return None,
+            _ => values[v as usize] = Some(value),
+        }
+    }
+    values.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXTURED_QUAD: &[u8] = b"# textured quad
+mtllib quad.mtl
+o quad
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+vn 0 0 1
+usemtl default
+s off
+f 1/1/1 2/2/1 3/3/1 4/4/1
+";
+
+    #[test]
+    fn test_textured_quad() {
+        let mesh = parse_obj(TEXTURED_QUAD).unwrap();
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.faces, vec![vec![0, 1, 2, 3]]);
+        let uvs = mesh.uvs.unwrap();
+        assert_eq!(uvs[2], Vec2::ONE);
+        assert_eq!(mesh.corner_uvs.unwrap().len(), 4);
+        assert_eq!(mesh.normals, Some(vec![Vec3::Z; 4]));
+    }
+
+    #[test]
+    fn test_negative_indices_and_missing_attributes() {
+        let data = b"v 0 0 0
+v 1 0 0
+v 0 1 0
+f -3 -2 -1
+v 1 1 0
+vn 0 0 1
+f 2//-1 4//1 3//1
+";
+        let mesh = parse_obj(data).unwrap();
+        assert_eq!(mesh.faces, vec![vec![0, 1, 2], vec![1, 3, 2]]);
+        assert_eq!(mesh.uvs, None);
+        assert_eq!(mesh.corner_uvs, None);
+        assert_eq!(mesh.normals, None);
+    }
+
+    #[test]
+    fn test_uv_seam_keeps_corner_uvs_only() {
+        let data = b"v 0 0 0
+v 1 0 0
+v 0 1 0
+v 1 1 0
+vt 0 0
+vt 1 0
+vt 0 1
+vt 0.5 0.5
+f 1/1 2/2 3/3
+f 2/4 4/2 3/3
+";
+        let mesh = parse_obj(data).unwrap();
+        assert_eq!(mesh.uvs, None);
+        let corner_uvs = mesh.corner_uvs.unwrap();
+        assert_eq!(corner_uvs.len(), 6);
+        assert_eq!(corner_uvs[3], Vec2::splat(0.5));
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(parse_obj(b"v 0 0\n").is_err());
+        assert!(parse_obj(b"v 0 0 x\n").is_err());
+        assert!(parse_obj(b"v 0 0 0\nv 1 0 0\nf 1 2\n").is_err());
+        assert!(parse_obj(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n").is_err());
+        assert!(parse_obj(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n").is_err());
+        assert!(parse_obj(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/2 2 3\n").is_err());
+    }
+}
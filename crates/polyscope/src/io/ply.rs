@@ -0,0 +1,592 @@
+//! PLY (Stanford polygon file format) import.
+//!
+//! Supports the `ascii` and `binary_little_endian` encodings. Only the
+//! `vertex` and `face` elements are interpreted; any other elements are
+//! parsed and skipped.
+
+use std::path::Path;
+
+use super::LoadedGeometry;
+use crate::{PolyscopeError, Result, Vec3};
+
+/// Loads a PLY file.
+///
+/// Returns [`LoadedGeometry::Mesh`] if the file contains a non-empty `face`
+/// element, otherwise [`LoadedGeometry::Points`]. Vertex coordinates may be
+/// stored as any PLY scalar type (`float`, `double`, integer types).
+/// Polygon faces are passed through unchanged; they are fan-triangulated
+/// when registered as a surface mesh.
+///
+/// Per-vertex `red`/`green`/`blue` properties are returned as colors in
+/// [0, 1]. Integer color channels are divided by 255.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, uses the
+/// `binary_big_endian` encoding, or is malformed.
+pub fn load_ply(path: impl AsRef<Path>) -> Result<LoadedGeometry> {
+    let bytes = std::fs::read(path)?;
+    parse_ply(&bytes)
+}
+
+/// Parses PLY data from an in-memory buffer.
+///
+/// See [`load_ply`] for details.
+pub fn parse_ply(bytes: &[u8]) -> Result<LoadedGeometry> {
+    let (header, body_start) = parse_header(bytes)?;
+    let body = &bytes[body_start..];
+    let mut reader = match header.format {
+        Format::Ascii => {
+            let text = std::str::from_utf8(body)
+                .map_err(|_| ply_error("ASCII body is not valid UTF-8"))?;
+            BodyReader::Ascii(text.split_ascii_whitespace())
+        }
+        Format::BinaryLittleEndian => BodyReader::Binary(body),
+    };
+
+    let mut vertices = Vec::new();
+    let mut colors: Vec<Vec3> = Vec::new();
+    let mut faces = Vec::new();
+    let mut has_colors = false;
+
+    for element in &header.elements {
+        match element.name.as_str() {
+            "vertex" => {
+                let pos = |name: &str| {
+                    element
+                        .scalar_index(name)
+                        .ok_or_else(|| ply_error(&format!("vertex element has no '{name}'")))
+                };
+                let (ix, iy, iz) = (pos("x")?, pos("y")?, pos("z")?);
+                let color_idx = match (
+                    element.scalar_index("red"),
+                    element.scalar_index("green"),
+                    element.scalar_index("blue"),
+                ) {
+                    (Some(r), Some(g), Some(b)) => Some([r, g, b]),
+                    _ => None,
+                };
+                has_colors = color_idx.is_some();
+
+                vertices.reserve(element.max_count(header.format, body.len()));
+                let mut values = vec![0.0; element.properties.len()];
+                for _ in 0..element.count {
+                    for (value, property) in values.iter_mut().zip(&element.properties) {
+                        *value = match property {
+                            Property::Scalar { ty, .. } => reader.read(*ty)?,
+                            Property::List {
+                                count_ty, item_ty, ..
+                            } => {
+                                reader.skip_list(*count_ty, *item_ty)?;
+                                0.0
+                            }
+                        };
+                    }
+                    vertices.push(Vec3::new(
+                        values[ix] as f32,
+                        values[iy] as f32,
+                        values[iz] as f32,
+                    ));
+                    if let Some(idx) = color_idx {
+                        let channel = |i: usize| {
+                            let v = values[idx[i]];
+                            if element.properties[idx[i]].is_float() {
+                                v as f32
+                            } else {
+                                (v / 255.0) as f32
+                            }
+                        };
+                        colors.push(Vec3::new(channel(0), channel(1), channel(2)));
+                    }
+                }
+            }
+            "face" => {
+                let list_idx = element
+                    .properties
+                    .iter()
+                    .position(|p| {
+                        matches!(p, Property::List { name, .. }
+                            if name == "vertex_indices" || name == "vertex_index")
+                    })
+                    .ok_or_else(|| ply_error("face element has no 'vertex_indices' list"))?;
+
+                faces.reserve(element.max_count(header.format, body.len()));
+                for _ in 0..element.count {
+                    for (i, property) in element.properties.iter().enumerate() {
+                        match property {
+                            Property::Scalar { ty, .. } => {
+                                reader.read(*ty)?;
+                            }
+                            Property::List {
+                                count_ty, item_ty, ..
+                            } if i == list_idx => {
+                                let n = reader.read(*count_ty)? as usize;
+                                let item_size = item_ty.encoded_size(header.format);
+                                let mut face = Vec::with_capacity(n.min(body.len() / item_size));
+                                for _ in 0..n {
+                                    let idx = reader.read(*item_ty)?;
+                                    if idx < 0.0 || idx > f64::from(u32::MAX) || idx.fract() != 0.0
+                                    {
+                                        return Err(ply_error(&format!(
+                                            "face {} has invalid vertex index {idx}",
+                                            faces.len()
+                                        )));
+                                    }
+                                    face.push(idx as u32);
+                                }
+                                faces.push(face);
+                            }
+                            Property::List {
+                                count_ty, item_ty, ..
+                            } => reader.skip_list(*count_ty, *item_ty)?,
+                        }
+                    }
+                }
+            }
+            _ => reader.skip_element(element)?,
+        }
+    }
+
+    let n_verts = vertices.len();
+    for (i, face) in faces.iter().enumerate() {
+        if face.len() < 3 {
+            return Err(ply_error(&format!(
+                "face {i} has {} vertices (minimum 3 required)",
+                face.len()
+            )));
+        }
+        if let Some(&idx) = face.iter().find(|&&idx| idx as usize >= n_verts) {
+            return Err(ply_error(&format!(
+                "face {i} references vertex {idx} but file only has {n_verts} vertices"
+            )));
+        }
+    }
+
+    let colors = has_colors.then_some(colors);
+    if faces.is_empty() {
+        Ok(LoadedGeometry::Points {
+            points: vertices,
+            colors,
+        })
+    } else {
+        Ok(LoadedGeometry::Mesh {
+            vertices,
+            faces,
+            colors,
+        })
+    }
+}
+
+fn ply_error(msg: &str) -> PolyscopeError {
+    PolyscopeError::FileLoadError(format!("PLY: {msg}"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+}
+
+/// PLY scalar data types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScalarType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl ScalarType {
+    fn parse(s: &str) -> Result<Self> {
+        Ok(match s {
+            "char" | "int8" => Self::I8,
+            "uchar" | "uint8" => Self::U8,
+            "short" | "int16" => Self::I16,
+            "ushort" | "uint16" => Self::U16,
+            "int" | "int32" => Self::I32,
+            "uint" | "uint32" => Self::U32,
+            "float" | "float32" => Self::F32,
+            "double" | "float64" => Self::F64,
+            _ => return Err(ply_error(&format!("unknown property type '{s}'"))),
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    /// Returns the fewest bytes a value takes in the body: its binary size,
+    /// or a digit and a separator in ASCII.
+    fn encoded_size(self, format: Format) -> usize {
+        match format {
+            Format::Ascii => 2,
+            Format::BinaryLittleEndian => self.size(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Property {
+    Scalar {
+        name: String,
+        ty: ScalarType,
+    },
+    List {
+        name: String,
+        count_ty: ScalarType,
+        item_ty: ScalarType,
+    },
+}
+
+impl Property {
+    fn is_float(&self) -> bool {
+        matches!(
+            self,
+            Self::Scalar {
+                ty: ScalarType::F32 | ScalarType::F64,
+                ..
+            }
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+impl Element {
+    fn scalar_index(&self, name: &str) -> Option<usize> {
+        self.properties
+            .iter()
+            .position(|p| matches!(p, Property::Scalar { name: n, .. } if n == name))
+    }
+
+    /// Returns the element count from the header, capped by how many elements
+    /// fit in a body of `body_len` bytes, so a corrupt count cannot force a
+    /// huge allocation.
+    fn max_count(&self, format: Format, body_len: usize) -> usize {
+        let min_size: usize = self
+            .properties
+            .iter()
+            .map(|p| match p {
+                Property::Scalar { ty, .. } => ty.encoded_size(format),
+                Property::List { count_ty, .. } => count_ty.encoded_size(format),
+            })
+            .sum();
+        self.count.min(body_len / min_size.max(1))
+    }
+}
+
+struct Header {
+    format: Format,
+    elements: Vec<Element>,
+}
+
+/// Parses the header, returning it along with the byte offset of the body.
+fn parse_header(bytes: &[u8]) -> Result<(Header, usize)> {
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    let mut offset = 0;
+    let mut first = true;
+
+    loop {
+        let rest = &bytes[offset..];
+        let line_len = rest
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or_else(|| ply_error("missing 'end_header'"))?;
+        let line = std::str::from_utf8(&rest[..line_len])
+            .map_err(|_| ply_error("header is not valid UTF-8"))?
+            .trim();
+        offset += line_len + 1;
+
+        if first {
+            if line != "ply" {
+                return Err(ply_error("missing 'ply' magic number"));
+            }
+            first = false;
+            continue;
+        }
+
+        let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+        match tokens.as_slice() {
+            ["end_header"] => break,
+            ["format", "ascii", _] => format = Some(Format::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(Format::BinaryLittleEndian),
+            ["format", other, _] => {
+                return Err(ply_error(&format!("unsupported format '{other}'")));
+            }
+            ["element", name, count] => elements.push(Element {
+                name: (*name).to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| ply_error(&format!("invalid element count '{count}'")))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count_ty, item_ty, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| ply_error("property before element"))?;
+                element.properties.push(Property::List {
+                    name: (*name).to_string(),
+                    count_ty: ScalarType::parse(count_ty)?,
+                    item_ty: ScalarType::parse(item_ty)?,
+                });
+            }
+            ["property", ty, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| ply_error("property before element"))?;
+                element.properties.push(Property::Scalar {
+                    name: (*name).to_string(),
+                    ty: ScalarType::parse(ty)?,
+                });
+            }
+            ["comment" | "obj_info", ..] | [] => {}
+            _ => return Err(ply_error(&format!("unrecognized header line '{line}'"))),
+        }
+    }
+
+    let format = format.ok_or_else(|| ply_error("missing 'format' line"))?;
+    Ok((Header { format, elements }, offset))
+}
+
+/// Sequential reader over the PLY body.
+enum BodyReader<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary(&'a [u8]),
+}
+
+impl BodyReader<'_> {
+    fn read(&mut self, ty: ScalarType) -> Result<f64> {
+        match self {
+            Self::Ascii(tokens) => {
+                let token = tokens
+                    .next()
+                    .ok_or_else(|| ply_error("unexpected end of data"))?;
+                token
+                    .parse::<f64>()
+                    .map_err(|_| ply_error(&format!("invalid number '{token}'")))
+            }
+            Self::Binary(data) => {
+                let size = ty.size();
+                if data.len() < size {
+                    return Err(ply_error("unexpected end of data"));
+                }
+                let (b, rest) = data.split_at(size);
+                *data = rest;
+                Ok(match ty {
+                    ScalarType::I8 => f64::from(i8::from_le_bytes([b[0]])),
+                    ScalarType::U8 => f64::from(b[0]),
+                    ScalarType::I16 => f64::from(i16::from_le_bytes([b[0], b[1]])),
+                    ScalarType::U16 => f64::from(u16::from_le_bytes([b[0], b[1]])),
+                    ScalarType::I32 => f64::from(i32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+                    ScalarType::U32 => f64::from(u32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+                    ScalarType::F32 => f64::from(f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+                    ScalarType::F64 => {
+                        f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+                    }
+                })
+            }
+        }
+    }
+
+    fn skip_list(&mut self, count_ty: ScalarType, item_ty: ScalarType) -> Result<()> {
+        let n = self.read(count_ty)? as usize;
+        for _ in 0..n {
+            self.read(item_ty)?;
+        }
+        Ok(())
+    }
+
+    fn skip_element(&mut self, element: &Element) -> Result<()> {
+        for _ in 0..element.count {
+            for property in &element.properties {
+                match property {
+                    Property::Scalar { ty, .. } => {
+                        self.read(*ty)?;
+                    }
+                    Property::List {
+                        count_ty, item_ty, ..
+                    } => self.skip_list(*count_ty, *item_ty)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_mesh_with_quad_and_colors() {
+        let data = b"ply
+format ascii 1.0
+comment test
+element vertex 4
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0 255 0 0
+1 0 0 0 255 0
+1 1 0 0 0 255
+0 1 0 255 255 255
+4 0 1 2 3
+";
+        let geom = parse_ply(data).unwrap();
+        let LoadedGeometry::Mesh {
+            vertices,
+            faces,
+            colors,
+        } = geom
+        else {
+            panic!("expected mesh");
+        };
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(vertices[2], Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(faces, vec![vec![0, 1, 2, 3]]);
+        let colors = colors.unwrap();
+        assert_eq!(colors[0], Vec3::X);
+        assert_eq!(colors[3], Vec3::ONE);
+    }
+
+    #[test]
+    fn test_ascii_points_without_faces() {
+        let data = b"ply
+format ascii 1.0
+element vertex 2
+property double x
+property double y
+property double z
+property float nx
+end_header
+0.5 1.5 2.5 0
+-1 -2 -3 0
+";
+        let geom = parse_ply(data).unwrap();
+        assert_eq!(
+            geom,
+            LoadedGeometry::Points {
+                points: vec![Vec3::new(0.5, 1.5, 2.5), Vec3::new(-1.0, -2.0, -3.0)],
+                colors: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_binary_little_endian_double_mesh() {
+        let mut data = b"ply
+format binary_little_endian 1.0
+element vertex 3
+property double x
+property double y
+property double z
+element face 1
+property list uchar uint vertex_indices
+property uchar flags
+end_header
+"
+        .to_vec();
+        for v in [[0.0f64, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]] {
+            for c in v {
+                data.extend_from_slice(&c.to_le_bytes());
+            }
+        }
+        data.push(3);
+        for i in [0u32, 1, 2] {
+            data.extend_from_slice(&i.to_le_bytes());
+        }
+        data.push(7);
+
+        let geom = parse_ply(&data).unwrap();
+        let LoadedGeometry::Mesh {
+            vertices, faces, ..
+        } = geom
+        else {
+            panic!("expected mesh");
+        };
+        assert_eq!(vertices, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        assert_eq!(faces, vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(parse_ply(b"not a ply\n").is_err());
+        assert!(parse_ply(b"ply\nformat binary_big_endian 1.0\nend_header\n").is_err());
+
+        // Face referencing a missing vertex
+        let data = b"ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+3 0 1 2
+";
+        assert!(parse_ply(data).is_err());
+
+        // Negative vertex index
+        let data = b"ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_indices
+end_header
+0 0 0
+1 0 0
+0 1 0
+3 0 1 -2
+";
+        assert!(parse_ply(data).is_err());
+
+        // Header count far beyond the body size
+        let data = b"ply
+format binary_little_endian 1.0
+element vertex 4000000000
+property float x
+property float y
+property float z
+end_header
+";
+        assert!(parse_ply(data).is_err());
+
+        // Truncated body
+        let data = b"ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+end_header
+0 0 0
+";
+        assert!(parse_ply(data).is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! STL (stereolithography) import.
+//!
+//! Supports both binary and ASCII STL. Facet normals are ignored; they are
+//! recomputed from the triangles when the mesh is registered.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::UVec3;
+
+use crate::{PolyscopeError, Result, Vec3};
+
+/// Size of the binary STL header (80-byte comment + `u32` triangle count).
+const BINARY_HEADER_LEN: usize = 84;
+
+/// Size of one binary STL triangle record (normal, 3 vertices, attribute).
+const BINARY_TRIANGLE_LEN: usize = 50;
+
+/// Welding tolerance, relative to the bounding box diagonal.
+const WELD_EPSILON: f32 = 1e-6;
+
+/// Loads an STL file as a triangle mesh.
+///
+/// The encoding is detected from the file size: data that is exactly as long
+/// as its binary triangle count implies is read as binary, anything starting
+/// with `solid` otherwise is read as ASCII.
+///
+/// STL stores three separate vertices per triangle. With `weld_vertices`,
+/// vertices closer than a tiny tolerance (relative to the mesh size) are
+/// merged so the result is a connected mesh; otherwise every triangle keeps
+/// its own three vertices.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// let (vertices, faces) = load_stl("part.stl", true).unwrap();
+/// register_surface_mesh("part", vertices, faces);
+/// show();
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is malformed.
+pub fn load_stl(path: impl AsRef<Path>, weld_vertices: bool) -> Result<(Vec<Vec3>, Vec<UVec3>)> {
+    let bytes = std::fs::read(path)?;
+    parse_stl(&bytes, weld_vertices)
+}
+
+/// Parses STL data from an in-memory buffer.
+///
+/// See [`load_stl`] for details.
+pub fn parse_stl(bytes: &[u8], weld_vertices: bool) -> Result<(Vec<Vec3>, Vec<UVec3>)> {
+    let triangles = if is_binary(bytes) {
+        parse_binary(bytes)?
+    } else if bytes.trim_ascii_start().starts_with(b"solid") {
+        parse_ascii(bytes)?
+    } else {
+        return Err(stl_error("neither a valid binary nor an ASCII STL file"));
+    };
+
+    if weld_vertices {
+        Ok(weld(&triangles))
+    } else {
+        let vertices = triangles.iter().flatten().copied().collect();
+        let faces = (0..triangles.len() as u32)
+            .map(|i| UVec3::new(3 * i, 3 * i + 1, 3 * i + 2))
+            .collect();
+        Ok((vertices, faces))
+    }
+}
+
+fn stl_error(msg: &str) -> PolyscopeError {
+    PolyscopeError::FileLoadError(format!("STL: {msg}"))
+}
+
+/// Returns true if the size matches the triangle count in a binary header.
+///
+/// ASCII files start with `solid`, but so do many binary headers, so the
+/// prefix alone is not a reliable test.
+fn is_binary(bytes: &[u8]) -> bool {
+    let Some(count) = bytes.get(80..BINARY_HEADER_LEN) else {
+        return false;
+    };
+    let count = u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as usize;
+    count
+        .checked_mul(BINARY_TRIANGLE_LEN)
+        .and_then(|len| len.checked_add(BINARY_HEADER_LEN))
+        == Some(bytes.len())
+}
+
+fn parse_binary(bytes: &[u8]) -> Result<Vec<[Vec3; 3]>> {
+    let read_vec3 = |record: &[u8], offset: usize| {
+        let f = |i: usize| {
+            let at = offset + 4 * i;
+            f32::from_le_bytes([record[at], record[at + 1], record[at + 2], record[at + 3]])
+        };
+        Vec3::new(f(0), f(1), f(2))
+    };
+    let triangles: Vec<[Vec3; 3]> = bytes[BINARY_HEADER_LEN..]
+        .chunks_exact(BINARY_TRIANGLE_LEN)
+        .map(|record| {
+            // Skip the 12-byte facet normal
+            [
+                read_vec3(record, 12),
+                read_vec3(record, 24),
+                read_vec3(record, 36),
+            ]
+        })
+        .collect();
+    if triangles.iter().flatten().any(|v| !v.is_finite()) {
+        return Err(stl_error("non-finite vertex coordinate"));
+    }
+    Ok(triangles)
+}
+
+fn parse_ascii(bytes: &[u8]) -> Result<Vec<[Vec3; 3]>> {
+    let text =
+        std::str::from_utf8(bytes).map_err(|_| stl_error("ASCII file is not valid UTF-8"))?;
+    let mut tokens = text.split_ascii_whitespace();
+    let mut triangles = Vec::new();
+    let mut polygon: Option<Vec<Vec3>> = None;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            "outer" => polygon = Some(Vec::new()),
+            "vertex" => {
+                let Some(polygon) = polygon.as_mut() else {
+                    return Err(stl_error("vertex outside of an outer loop"));
+                };
+                let mut coord = || -> Result<f32> {
+                    tokens
+                        .next()
+                        .and_then(|t| t.parse::<f32>().ok())
+                        .filter(|c| c.is_finite())
+                        .ok_or_else(|| stl_error("invalid vertex coordinate"))
+                };
+                polygon.push(Vec3::new(coord()?, coord()?, coord()?));
+            }
+            "endloop" => {
+                let polygon = polygon
+                    .take()
+                    .ok_or_else(|| stl_error("endloop without outer loop"))?;
+                if polygon.len() < 3 {
+                    return Err(stl_error("facet with fewer than 3 vertices"));
+                }
+                // Facets are almost always triangles; fan-triangulate the rest
+                for i in 1..polygon.len() - 1 {
+                    triangles.push([polygon[0], polygon[i], polygon[i + 1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+    if polygon.is_some() {
+        return Err(stl_error("unterminated outer loop"));
+    }
+    Ok(triangles)
+}
+
+/// Merges vertices closer than [`WELD_EPSILON`] times the bounding box
+/// diagonal and rebuilds the triangles against the merged vertex list.
+fn weld(triangles: &[[Vec3; 3]]) -> (Vec<Vec3>, Vec<UVec3>) {
+    let (min, max) = triangles.iter().flatten().fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), &v| (min.min(v), max.max(v)),
+    );
+    let tolerance = ((max - min).length() * WELD_EPSILON).max(f32::MIN_POSITIVE);
+
+    // Hash grid with cells as large as the tolerance: a match can only be
+    // in the same or a neighboring cell.
+    let cell_of = |v: Vec3| (v / tolerance).floor().as_ivec3();
+    let mut grid: HashMap<glam::IVec3, Vec<u32>> = HashMap::new();
+    let mut vertices: Vec<Vec3> = Vec::new();
+    let mut index_of = |v: Vec3| -> u32 {
+        let cell = cell_of(v);
+        for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let neighbor = cell + glam::IVec3::new(dx, dy, dz);
+                    if let Some(indices) = grid.get(&neighbor) {
+                        if let Some(&i) = indices
+                            .iter()
+                            .find(|&&i| vertices[i as usize].distance(v) <= tolerance)
+                        {
+                            return i;
+                        }
+                    }
+                }
+            }
+        }
+        let i = vertices.len() as u32;
+        vertices.push(v);
+        grid.entry(cell).or_default().push(i);
+        i
+    };
+
+    let faces = triangles
+        .iter()
+        .map(|[a, b, c]| UVec3::new(index_of(*a), index_of(*b), index_of(*c)))
+        .collect();
+    (vertices, faces)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The 12 triangles of a unit cube (two per face).
+    fn cube_triangles() -> Vec<[Vec3; 3]> {
+        let corner =
+            |i: u32| Vec3::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32);
+        let quads = [
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [2, 6, 7, 3],
+            [0, 4, 6, 2],
+            [1, 3, 7, 5],
+        ];
+        quads
+            .iter()
+            .flat_map(|q| {
+                [
+                    [corner(q[0]), corner(q[1]), corner(q[2])],
+                    [corner(q[0]), corner(q[2]), corner(q[3])],
+                ]
+            })
+            .collect()
+    }
+
+    fn binary_stl(triangles: &[[Vec3; 3]]) -> Vec<u8> {
+        // Header deliberately starts with "solid", as many exporters do
+        let mut data = b"solid exported as binary".to_vec();
+        data.resize(80, 0);
+        data.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+        for tri in triangles {
+            data.extend_from_slice(&[0; 12]);
+            for v in tri {
+                for c in v.to_array() {
+                    data.extend_from_slice(&c.to_le_bytes());
+                }
+            }
+            data.extend_from_slice(&[0; 2]);
+        }
+        data
+    }
+
+    fn ascii_stl(triangles: &[[Vec3; 3]]) -> String {
+        use std::fmt::Write;
+
+        let mut text = String::from("solid cube\n");
+        for tri in triangles {
+            text.push_str("  facet normal 0 0 0\n    outer loop\n");
+            for v in tri {
+                writeln!(text, "      vertex {} {} {}", v.x, v.y, v.z).unwrap();
+            }
+            text.push_str("    endloop\n  endfacet\n");
+        }
+        text.push_str("endsolid cube\n");
+        text
+    }
+
+    #[test]
+    fn test_binary_cube() {
+        let data = binary_stl(&cube_triangles());
+
+        let (vertices, faces) = parse_stl(&data, false).unwrap();
+        assert_eq!(faces.len(), 12);
+        assert_eq!(vertices.len(), 36);
+        assert_eq!(faces[1], UVec3::new(3, 4, 5));
+
+        let (vertices, faces) = parse_stl(&data, true).unwrap();
+        assert_eq!(faces.len(), 12);
+        assert_eq!(vertices.len(), 8);
+        for (face, tri) in faces.iter().zip(cube_triangles()) {
+            for (i, v) in face.to_array().into_iter().zip(tri) {
+                assert_eq!(vertices[i as usize], v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii_cube() {
+        let text = ascii_stl(&cube_triangles());
+
+        let (vertices, faces) = parse_stl(text.as_bytes(), false).unwrap();
+        assert_eq!(faces.len(), 12);
+        assert_eq!(vertices.len(), 36);
+
+        let (vertices, faces) = parse_stl(text.as_bytes(), true).unwrap();
+        assert_eq!(faces.len(), 12);
+        assert_eq!(vertices.len(), 8);
+    }
+
+    #[test]
+    fn test_weld_merges_nearby_vertices() {
+        let offset = Vec3::splat(1e-7);
+        let triangles = [
+            [Vec3::ZERO, Vec3::X, Vec3::Y],
+            [Vec3::X + offset, Vec3::ONE, Vec3::Y - offset],
+        ];
+        let (vertices, faces) = weld(&triangles);
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(faces[1], UVec3::new(1, 3, 2));
+    }
+
+    #[test]
+    fn test_invalid_files() {
+        assert!(parse_stl(b"", false).is_err());
+        assert!(parse_stl(b"not an stl file", false).is_err());
+        assert!(parse_stl(b"solid x\nouter loop\nvertex 0 0 0\nendloop\n", false).is_err());
+        assert!(parse_stl(b"solid x\nouter loop\nvertex 0 0 nan\n", false).is_err());
+        assert!(parse_stl(b"solid x\nouter loop\nvertex 0 0 0\n", false).is_err());
+
+        // Binary data truncated mid-record
+        let mut data = binary_stl(&cube_triangles());
+        data.truncate(data.len() - 10);
+        assert!(parse_stl(&data, false).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! Text labels anchored at world positions.
+//!
+//! Labels are drawn by the viewer as screen-facing text above the projection
+//! of their anchor, and are hidden while the anchor is behind the camera.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polyscope_rs::*;
+//!
+//! fn main() -> Result<()> {
+//!     init()?;
+//!
+//!     register_point_cloud("points", vec![Vec3::ZERO, Vec3::X]);
+//!     add_label("origin", Vec3::ZERO, "origin");
+//!     set_label_color("origin", Vec3::new(1.0, 0.8, 0.2));
+//!
+//!     show();
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{Label, Vec3, with_context, with_context_mut};
+
+/// Adds a text label at a world position, replacing any label with the
+/// same name.
+pub fn add_label(name: impl Into<String>, world_position: Vec3, text: impl Into<String>) {
+    let label = Label::new(text, world_position);
+    with_context_mut(|ctx| {
+        ctx.labels.insert(name.into(), label);
+    });
+    crate::redraw::notify_scene_changed();
+}
+
+/// Sets the text color of a label.
+pub fn set_label_color(name: &str, color: Vec3) {
+    let found = with_context_mut(|ctx| {
+        ctx.labels
+            .get_mut(name)
+            .map(|label| label.color = color)
+            .is_some()
+    });
+    if found {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!("set_label_color: no label named '{name}'");
+    }
+}
+
+/// Removes a label by name.
+pub fn remove_label(name: &str) {
+    if with_context_mut(|ctx| ctx.labels.remove(name)).is_some() {
+        crate::redraw::notify_scene_changed();
+    }
+}
+
+/// Removes all labels.
+pub fn remove_all_labels() {
+    with_context_mut(|ctx| ctx.labels.clear());
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns a copy of a label, or `None` if it does not exist.
+#[must_use]
+pub fn get_label(name: &str) -> Option<Label> {
+    with_context(|ctx| ctx.labels.get(name).cloned())
+}
@@ -0,0 +1,212 @@
+//! Materials: listing, assigning and loading custom matcaps.
+
+use std::sync::Mutex;
+
+use polyscope_core::state::MaterialLoadRequest;
+
+use crate::{MaterialRegistry, PolyscopeError, Result, with_context, with_context_mut};
+
+/// Names of the materials registered with the running render engine
+/// (built-in and custom), mirrored for use outside the render loop.
+static ENGINE_MATERIALS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Returns the names of all available materials.
+///
+/// Built-in materials come first, followed by custom materials (see
+/// [`load_static_material`], [`load_blendable_material`] and
+/// [`load_matcap_material`]). Custom materials are listed as soon as they are
+/// requested, so they can be assigned before the viewer loads them.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// assert!(available_materials().iter().any(|m| m == "clay"));
+/// ```
+#[must_use]
+pub fn available_materials() -> Vec<String> {
+    let mut names = ENGINE_MATERIALS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    if names.is_empty() {
+        names = MaterialRegistry::new()
+            .names()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+    }
+    // Queued materials can be assigned before the viewer loads them
+    let queued = with_context(|ctx| {
+        ctx.material_load_queue
+            .iter()
+            .map(|req| match req {
+                MaterialLoadRequest::Static { name, .. }
+                | MaterialLoadRequest::Blendable { name, .. }
+                | MaterialLoadRequest::Image { name, .. } => name.clone(),
+            })
+            .collect::<Vec<_>>()
+    });
+    let mut custom: Vec<String> = queued.into_iter().filter(|n| !names.contains(n)).collect();
+    custom.sort_unstable();
+    custom.dedup();
+    names.extend(custom);
+    names
+}
+
+/// Sets the material of a structure by name, e.g. `"wax"`.
+///
+/// Logs a warning and leaves the structure unchanged if the material is not
+/// one of [`available_materials`] or the structure does not exist.
+/// Structures without a material (e.g. volume grids) ignore it.
+pub fn set_structure_material(type_name: &str, name: &str, material: &str) {
+    if !available_materials().iter().any(|m| m == material) {
+        log::warn!("set_structure_material: unknown material '{material}'");
+        return;
+    }
+    let found = with_context_mut(|ctx| {
+        ctx.registry
+            .get_mut(type_name, name)
+            .map(|structure| structure.set_material(material))
+            .is_some()
+    });
+    if found {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!("set_structure_material: no {type_name} named '{name}'");
+    }
+}
+
+/// Returns the material of a structure, or `None` if it does not exist.
+#[must_use]
+pub fn get_structure_material(type_name: &str, name: &str) -> Option<String> {
+    with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .map(|structure| structure.material().to_string())
+    })
+}
+
+/// Mirrors the engine's material names for [`available_materials`] (for
+/// internal use by App).
+pub(crate) fn sync_engine_materials(registry: &MaterialRegistry) {
+    let mut loaded = ENGINE_MATERIALS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if loaded.len() != registry.len() {
+        *loaded = registry.names().into_iter().map(str::to_string).collect();
+    }
+}
+
+/// Loads a blendable (4-channel, RGB-tintable) matcap material from disk.
+///
+/// Takes a name and 4 image file paths for R, G, B, K matcap channels.
+/// The material becomes available in the UI material selector on the next frame.
+///
+/// Supports HDR, JPEG, PNG, EXR, and other image formats.
+///
+/// # Example
+/// ```no_run
+/// polyscope_rs::load_blendable_material("metal", [
+///     "assets/metal_r.hdr",
+///     "assets/metal_g.hdr",
+///     "assets/metal_b.hdr",
+///     "assets/metal_k.hdr",
+/// ]);
+/// ```
+pub fn load_blendable_material(name: &str, filenames: [&str; 4]) {
+    with_context_mut(|ctx| {
+        ctx.material_load_queue
+            .push(MaterialLoadRequest::Blendable {
+                name: name.to_string(),
+                filenames: [
+                    filenames[0].to_string(),
+                    filenames[1].to_string(),
+                    filenames[2].to_string(),
+                    filenames[3].to_string(),
+                ],
+            });
+    });
+}
+
+/// Loads a blendable material using a base path and extension.
+///
+/// Automatically expands to 4 filenames by appending `_r`, `_g`, `_b`, `_k`
+/// before the extension. For example:
+/// `load_blendable_material_ext("metal", "assets/metal", ".hdr")`
+/// loads `assets/metal_r.hdr`, `assets/metal_g.hdr`, `assets/metal_b.hdr`, `assets/metal_k.hdr`.
+pub fn load_blendable_material_ext(name: &str, base: &str, ext: &str) {
+    load_blendable_material(
+        name,
+        [
+            &format!("{base}_r{ext}"),
+            &format!("{base}_g{ext}"),
+            &format!("{base}_b{ext}"),
+            &format!("{base}_k{ext}"),
+        ],
+    );
+}
+
+/// Loads a static (single-texture, non-RGB-tintable) matcap material from disk.
+///
+/// The same texture is used for all 4 matcap channels. Static materials
+/// cannot be tinted with per-surface RGB colors.
+///
+/// Supports HDR, JPEG, PNG, EXR, and other image formats.
+///
+/// # Example
+/// ```no_run
+/// polyscope_rs::load_static_material("stone", "assets/stone.jpg");
+/// ```
+pub fn load_static_material(name: &str, filename: &str) {
+    with_context_mut(|ctx| {
+        ctx.material_load_queue.push(MaterialLoadRequest::Static {
+            name: name.to_string(),
+            path: filename.to_string(),
+        });
+    });
+}
+
+/// Loads a static matcap material from 8-bit RGBA pixels, row by row from
+/// the top. Alpha is ignored.
+///
+/// Like [`load_static_material`], but for images already in memory. The
+/// material is loaded by the viewer on the next frame and can be assigned to
+/// any structure by name right away.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::MaterialLoadError`] if `rgba` does not hold
+/// `width * height` pixels.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// // A flat orange matcap
+/// let rgba = [255, 128, 0, 255].repeat(4);
+/// load_matcap_material("orange", &rgba, 2, 2).unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X]);
+/// set_structure_material("PointCloud", "pts", "orange");
+/// ```
+pub fn load_matcap_material(name: &str, rgba: &[u8], width: u32, height: u32) -> Result<()> {
+    let expected = width as usize * height as usize * 4;
+    if expected == 0 || rgba.len() != expected {
+        return Err(PolyscopeError::MaterialLoadError(format!(
+            "expected {expected} bytes for a {width}x{height} RGBA image, got {}",
+            rgba.len()
+        )));
+    }
+    with_context_mut(|ctx| {
+        ctx.material_load_queue.push(MaterialLoadRequest::Image {
+            name: name.to_string(),
+            width,
+            height,
+            rgba: rgba.to_vec(),
+        });
+    });
+    Ok(())
+}
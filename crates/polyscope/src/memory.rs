@@ -0,0 +1,82 @@
+//! Per-structure size and GPU memory report.
+
+use crate::{
+    CameraView, CurveNetwork, HasQuantities, PointCloud, SurfaceMesh, VolumeGrid, VolumeMesh,
+    with_context,
+};
+
+/// Sizes of a registered structure, as listed by [`memory_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructureMemoryInfo {
+    /// Structure type name, e.g. `"SurfaceMesh"`.
+    pub type_name: String,
+    /// Structure name.
+    pub name: String,
+    /// Number of vertices: points, mesh vertices, curve or grid nodes.
+    pub num_vertices: usize,
+    /// Number of elements: points, faces, edges or cells.
+    pub num_elements: usize,
+    /// Number of quantities on the structure.
+    pub num_quantities: usize,
+    /// Estimated size of the GPU buffers of the structure and its
+    /// quantities, in bytes. Zero until the structure is first rendered.
+    pub gpu_buffer_bytes: u64,
+}
+
+/// Lists the size of every registered structure, sorted by type and name.
+///
+/// Useful to find out where GPU memory goes in large scenes.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X]);
+/// for info in memory_report() {
+///     println!("{} '{}': {} bytes", info.type_name, info.name, info.gpu_buffer_bytes);
+/// }
+/// ```
+#[must_use]
+pub fn memory_report() -> Vec<StructureMemoryInfo> {
+    with_context(|ctx| {
+        let mut report: Vec<StructureMemoryInfo> = ctx
+            .registry
+            .iter()
+            .map(|structure| {
+                let any = structure.as_any();
+                let (num_vertices, num_elements, num_quantities) =
+                    if let Some(pc) = any.downcast_ref::<PointCloud>() {
+                        (pc.num_points(), pc.num_points(), pc.num_quantities())
+                    } else if let Some(mesh) = any.downcast_ref::<SurfaceMesh>() {
+                        (mesh.num_vertices(), mesh.num_faces(), mesh.num_quantities())
+                    } else if let Some(cn) = any.downcast_ref::<CurveNetwork>() {
+                        (cn.num_nodes(), cn.num_edges(), cn.num_quantities())
+                    } else if let Some(vm) = any.downcast_ref::<VolumeMesh>() {
+                        (vm.num_vertices(), vm.num_cells(), vm.num_quantities())
+                    } else if let Some(grid) = any.downcast_ref::<VolumeGrid>() {
+                        (
+                            usize::try_from(grid.num_nodes()).unwrap_or(usize::MAX),
+                            usize::try_from(grid.num_cells()).unwrap_or(usize::MAX),
+                            grid.num_quantities(),
+                        )
+                    } else if let Some(view) = any.downcast_ref::<CameraView>() {
+                        (0, 0, view.num_quantities())
+                    } else {
+                        (0, 0, 0)
+                    };
+                StructureMemoryInfo {
+                    type_name: structure.type_name().to_string(),
+                    name: structure.name().to_string(),
+                    num_vertices,
+                    num_elements,
+                    num_quantities,
+                    gpu_buffer_bytes: structure.gpu_buffer_bytes(),
+                }
+            })
+            .collect();
+        report.sort_by(|a, b| (&a.type_name, &a.name).cmp(&(&b.type_name, &b.name)));
+        report
+    })
+}
@@ -0,0 +1,408 @@
+//! Global rendering options.
+
+use std::path::Path;
+
+use crate::{
+    DofConfig, GroundPlaneMode, Key, KeyAction, KeyBindings, Options, Result, SsaoConfig,
+    ToneMapOperator, TransparencyMode, Vec3, with_context, with_context_mut,
+};
+
+/// Sets the transparency rendering mode.
+///
+/// - [`TransparencyMode::Simple`] (default) blends transparent surfaces in
+///   draw order. It is fast but overlapping transparent surfaces may
+///   composite incorrectly.
+/// - [`TransparencyMode::Pretty`] uses depth peeling. It is slower (one
+///   extra pass per peel, see [`set_transparency_render_passes`]) but
+///   correct for stacked transparent layers.
+///
+/// Takes effect on the next frame and is reflected in the appearance panel.
+pub fn set_transparency_mode(mode: TransparencyMode) {
+    with_context_mut(|ctx| {
+        ctx.options.transparency_mode = mode;
+    });
+}
+
+/// Returns the current transparency rendering mode.
+#[must_use]
+pub fn get_transparency_mode() -> TransparencyMode {
+    with_context(|ctx| ctx.options.transparency_mode)
+}
+
+/// Sets the number of depth peeling passes used by [`TransparencyMode::Pretty`].
+///
+/// Each pass resolves one more layer of stacked transparent surfaces.
+/// Defaults to 4 and is clamped to at least 1.
+pub fn set_transparency_render_passes(passes: u32) {
+    with_context_mut(|ctx| {
+        ctx.options.transparency_render_passes = passes.max(1);
+    });
+}
+
+/// Returns the number of depth peeling passes.
+#[must_use]
+pub fn get_transparency_render_passes() -> u32 {
+    with_context(|ctx| ctx.options.transparency_render_passes)
+}
+
+/// Sets the global back-face culling override for surface meshes.
+///
+/// `Some(true)` culls back faces of every mesh, `Some(false)` disables culling
+/// everywhere, and `None` (default) falls back to each mesh's
+/// `BackfacePolicy`. Takes effect on the next frame.
+///
+/// Point clouds and curve networks are drawn as ray-cast impostors that have
+/// no back faces, so they are unaffected.
+pub fn set_backface_cull(cull: Option<bool>) {
+    with_context_mut(|ctx| {
+        ctx.options.backface_cull = cull;
+    });
+}
+
+/// Returns the global back-face culling override.
+#[must_use]
+pub fn get_backface_cull() -> Option<bool> {
+    with_context(|ctx| ctx.options.backface_cull)
+}
+
+/// Sets the ground plane mode.
+///
+/// This is a preset for [`set_ground_plane_draw_tile`],
+/// [`set_ground_plane_draw_shadow`] and [`set_ground_plane_draw_reflection`]:
+/// [`GroundPlaneMode::TileReflection`] also renders mirrored geometry, and
+/// every mode except [`GroundPlaneMode::None`] receives shadows. With `None`
+/// neither the reflection nor the shadow pass runs. Takes effect on the next
+/// frame and is reflected in the appearance panel.
+pub fn set_ground_plane_mode(mode: GroundPlaneMode) {
+    with_context_mut(|ctx| {
+        ctx.options.ground_plane.set_mode(mode);
+    });
+}
+
+/// Returns the ground plane mode closest to the current `draw_*` flags.
+#[must_use]
+pub fn get_ground_plane_mode() -> GroundPlaneMode {
+    with_context(|ctx| ctx.options.ground_plane.mode())
+}
+
+/// Shows or hides the tiled ground plane, independently of its shadow.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// // Decorative tile without the contact shadow
+/// set_ground_plane_draw_tile(true);
+/// set_ground_plane_draw_shadow(false);
+/// ```
+pub fn set_ground_plane_draw_tile(draw: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.ground_plane.draw_tile = draw;
+    });
+}
+
+/// Returns whether the tiled ground plane is drawn.
+#[must_use]
+pub fn get_ground_plane_draw_tile() -> bool {
+    with_context(|ctx| ctx.options.ground_plane.draw_tile)
+}
+
+/// Enables or disables ground plane shadows. When disabled the shadow map
+/// pass is skipped.
+pub fn set_ground_plane_draw_shadow(draw: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.ground_plane.draw_shadow = draw;
+    });
+}
+
+/// Returns whether ground plane shadows are drawn.
+#[must_use]
+pub fn get_ground_plane_draw_shadow() -> bool {
+    with_context(|ctx| ctx.options.ground_plane.draw_shadow)
+}
+
+/// Enables or disables ground plane reflections. Reflections are drawn in
+/// the tile, so the pass only runs while the tile is drawn too.
+pub fn set_ground_plane_draw_reflection(draw: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.ground_plane.draw_reflection = draw;
+    });
+}
+
+/// Returns whether ground plane reflections are enabled.
+#[must_use]
+pub fn get_ground_plane_draw_reflection() -> bool {
+    with_context(|ctx| ctx.options.ground_plane.draw_reflection)
+}
+
+/// Places the ground plane relative to the scene bounding box.
+///
+/// The plane sits `factor` scene length scales above the bottom of the
+/// bounding box (0 = just below the scene, negative values lower it), and
+/// follows the scene whenever its extents change. This also switches off a
+/// fixed ground plane height set from the UI.
+pub fn set_ground_plane_height_factor(factor: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.ground_plane.height_factor = factor;
+        ctx.options.ground_plane.height_is_relative = true;
+    });
+}
+
+/// Returns the ground plane height relative to the scene bounding box, in
+/// scene length scales.
+#[must_use]
+pub fn get_ground_plane_height_factor() -> f32 {
+    with_context(|ctx| ctx.options.ground_plane.height_factor)
+}
+
+/// Enables or disables screen-space ambient occlusion (SSAO).
+///
+/// SSAO darkens creases and contact regions of surface meshes. When disabled
+/// the SSAO pass is skipped entirely. Takes effect on the next frame and is
+/// reflected in the appearance panel.
+pub fn set_ssao_enabled(enabled: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.ssao.enabled = enabled;
+    });
+}
+
+/// Sets the SSAO sample radius, relative to the scene length scale.
+///
+/// Larger radii pick up broader occlusion. Clamped to be positive.
+pub fn set_ssao_radius(radius: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.ssao.radius = radius.max(f32::EPSILON);
+    });
+}
+
+/// Sets the SSAO strength (0 = no darkening). Clamped to be non-negative.
+pub fn set_ssao_intensity(intensity: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.ssao.intensity = intensity.max(0.0);
+    });
+}
+
+/// Sets the SSAO depth bias, which prevents flat surfaces from occluding
+/// themselves. Clamped to be non-negative.
+pub fn set_ssao_bias(bias: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.ssao.bias = bias.max(0.0);
+    });
+}
+
+/// Returns the current SSAO configuration.
+#[must_use]
+pub fn get_ssao_config() -> SsaoConfig {
+    with_context(|ctx| ctx.options.ssao.clone())
+}
+
+/// Sets the supersampling anti-aliasing (SSAA) factor: 1 (off), 2 or 4.
+///
+/// The scene is rendered at `factor` times the window resolution in each
+/// direction and downsampled, which applies to screenshots as well. Other
+/// values log a warning and are clamped to the nearest supported factor.
+/// Takes effect on the next frame and is reflected in the appearance panel.
+pub fn set_antialiasing(factor: u32) {
+    let clamped = match factor {
+        0 | 1 => 1,
+        2 | 3 => 2,
+        _ => 4,
+    };
+    if clamped != factor {
+        log::warn!(
+            "Unsupported antialiasing factor {factor} (expected 1, 2 or 4); using {clamped}"
+        );
+    }
+    with_context_mut(|ctx| {
+        ctx.options.ssaa_factor = clamped;
+    });
+}
+
+/// Returns the SSAA factor (1 = off).
+#[must_use]
+pub fn get_antialiasing() -> u32 {
+    with_context(|ctx| ctx.options.ssaa_factor)
+}
+
+/// Sets the operator that maps HDR scene colors to the display.
+///
+/// [`ToneMapOperator::Reinhard`] (default) gently compresses highlights,
+/// [`ToneMapOperator::Aces`] and [`ToneMapOperator::Filmic`] give a more
+/// contrasty filmic look, and [`ToneMapOperator::None`] only clamps and gamma
+/// encodes. Takes effect on the next frame and is reflected in the tone
+/// mapping panel.
+pub fn set_tone_map_operator(operator: ToneMapOperator) {
+    with_context_mut(|ctx| {
+        ctx.options.tone_mapping.operator = operator;
+    });
+}
+
+/// Returns the current tone mapping operator.
+#[must_use]
+pub fn get_tone_map_operator() -> ToneMapOperator {
+    with_context(|ctx| ctx.options.tone_mapping.operator)
+}
+
+/// Sets the exposure multiplier applied before tone mapping (default 1.1).
+/// Clamped to be positive.
+pub fn set_exposure(exposure: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.tone_mapping.exposure = exposure.max(f32::EPSILON);
+    });
+}
+
+/// Returns the exposure multiplier.
+#[must_use]
+pub fn get_exposure() -> f32 {
+    with_context(|ctx| ctx.options.tone_mapping.exposure)
+}
+
+/// Configures depth of field, applied to the tone-mapped image.
+///
+/// Pixels at `focal_distance` from the camera (in scene units) stay sharp;
+/// others are blurred by a disc that grows with their distance from that
+/// plane, up to `aperture` pixels in radius for the far background. Applies
+/// to the viewer and to screenshots. The focal distance is clamped to be
+/// positive and the aperture to be non-negative.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// set_dof(true, 3.0, 10.0);
+/// show();
+/// ```
+pub fn set_dof(enabled: bool, focal_distance: f32, aperture: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.dof = DofConfig {
+            enabled,
+            focal_distance: focal_distance.max(f32::EPSILON),
+            aperture: aperture.max(0.0),
+        };
+    });
+}
+
+/// Returns the current depth of field configuration.
+#[must_use]
+pub fn get_dof_config() -> DofConfig {
+    with_context(|ctx| ctx.options.dof.clone())
+}
+
+/// Sets the background color (default dark gray). It is the clear color of
+/// the main render pass and of screenshots, and is reflected in the controls
+/// panel.
+pub fn set_background_color(color: Vec3) {
+    with_context_mut(|ctx| {
+        ctx.options.background_color = color.extend(1.0);
+    });
+}
+
+/// Returns the background color.
+#[must_use]
+pub fn get_background_color() -> Vec3 {
+    with_context(|ctx| ctx.options.background_color.truncate())
+}
+
+/// Sets whether screenshots have a transparent background (alpha zero where
+/// nothing was drawn). The on-screen background stays opaque.
+pub fn set_transparent_background(transparent: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.transparent_background = transparent;
+    });
+}
+
+/// Returns whether screenshots have a transparent background.
+#[must_use]
+pub fn get_transparent_background() -> bool {
+    with_context(|ctx| ctx.options.transparent_background)
+}
+
+/// Binds a viewer action to a key, or unbinds it with `None`.
+///
+/// The defaults are `G`/`R`/`S` for the gizmo translate/rotate/scale modes,
+/// `F12` for a screenshot, `Home` to reset the view and `U` to toggle the UI
+/// panels. Shortcuts are ignored while a UI text field has keyboard focus,
+/// and the gizmo shortcuts are ignored in first-person navigation, where
+/// `WASD` moves the camera.
+pub fn set_key_binding(action: KeyAction, key: Option<Key>) {
+    with_context_mut(|ctx| {
+        ctx.options.key_bindings.set(action, key);
+    });
+}
+
+/// Returns the key bound to a viewer action.
+#[must_use]
+pub fn get_key_binding(action: KeyAction) -> Option<Key> {
+    with_context(|ctx| ctx.options.key_bindings.get(action))
+}
+
+/// Returns all keyboard shortcuts of the viewer.
+#[must_use]
+pub fn get_key_bindings() -> KeyBindings {
+    with_context(|ctx| ctx.options.key_bindings.clone())
+}
+
+/// Shows or hides the UI panels, e.g. for clean screenshots.
+///
+/// Hiding removes the left panel, the selection panel, floating image windows
+/// and transform gizmos; the scene itself still renders. The `U` key toggles
+/// this by default (see [`KeyAction::ToggleUi`]).
+pub fn set_ui_visible(visible: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.ui_visible = visible;
+    });
+}
+
+/// Returns whether the UI panels are drawn.
+#[must_use]
+pub fn is_ui_visible() -> bool {
+    with_context(|ctx| ctx.options.ui_visible)
+}
+
+/// Sets how long the camera takes to fly to a `look_at_structure()` /
+/// `look_at_bounding_box()` target, in seconds.
+///
+/// With 0 (the default) the camera jumps immediately. Larger values give a
+/// slower, smoothed transition over several frames. Headless renders always
+/// jump.
+pub fn set_camera_smoothing(seconds: f32) {
+    with_context_mut(|ctx| {
+        ctx.options.camera_smoothing = seconds.max(0.0);
+    });
+}
+
+/// Returns the camera flight duration for `look_at_*` requests, in seconds.
+#[must_use]
+pub fn get_camera_smoothing() -> f32 {
+    with_context(|ctx| ctx.options.camera_smoothing)
+}
+
+/// Saves all global options to a JSON file (see [`Options::save`]).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn save_options(path: impl AsRef<Path>) -> Result<()> {
+    with_context(|ctx| ctx.options.save(path))
+}
+
+/// Replaces all global options with those saved by [`save_options`].
+///
+/// Options missing from the file are reset to their defaults and unknown
+/// fields are ignored.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed; the current
+/// options are kept in that case.
+pub fn load_options(path: impl AsRef<Path>) -> Result<()> {
+    let options = Options::load(path)?;
+    with_context_mut(|ctx| ctx.options = options);
+    crate::redraw::notify_scene_changed();
+    Ok(())
+}
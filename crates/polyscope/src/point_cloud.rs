@@ -26,7 +26,13 @@
 //! }
 //! ```
 
-use crate::{PointCloud, Vec3, with_context_mut};
+use crate::{
+    HasQuantities, OrStructureNotFound, PointCloud, PointRenderMode, Result, Vec2, Vec3, Vec4,
+    with_context_mut,
+};
+use polyscope_structures::point_cloud::{
+    PointCloudRadiusQuantity, PointCloudScalarQuantity, PointCloudVectorQuantity,
+};
 
 /// Registers a point cloud with polyscope.
 ///
@@ -66,6 +72,37 @@ pub fn register_point_cloud(name: impl Into<String>, points: Vec<Vec3>) -> Point
     PointCloudHandle { name }
 }
 
+/// Registers a point cloud from 2D points.
+///
+/// Each point is placed in the XY plane (`z = 0`). Registering non-empty 2D
+/// data also marks the scene as planar, so the initial camera fit switches
+/// to planar navigation (unless a navigation style was already chosen).
+///
+/// # Panics
+///
+/// Panics if a structure with the same name already exists.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// let pc = register_point_cloud_2d("embedding", vec![Vec2::ZERO, Vec2::X, Vec2::Y]);
+/// pc.add_scalar_quantity("cluster", vec![0.0, 1.0, 1.0]);
+/// ```
+pub fn register_point_cloud_2d(name: impl Into<String>, points: Vec<Vec2>) -> PointCloudHandle {
+    let is_empty = points.is_empty();
+    let points = points.into_iter().map(|p| p.extend(0.0)).collect();
+    let handle = register_point_cloud(name, points);
+
+    if !is_empty {
+        with_context_mut(|ctx| ctx.planar_data_hint = true);
+    }
+
+    handle
+}
+
 impl_structure_accessors! {
     get_fn = get_point_cloud,
     with_fn = with_point_cloud,
@@ -103,6 +140,66 @@ impl PointCloudHandle {
         &self.name
     }
 
+    /// Shows or hides this point cloud; a hidden point cloud is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("PointCloud", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this point cloud is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("PointCloud", &self.name)
+    }
+
+    /// Sets the transparency (0.0 = opaque, 1.0 = fully transparent).
+    pub fn set_transparency(&self, transparency: f32) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            pc.set_transparency(transparency);
+        });
+        self
+    }
+
+    /// Sets how points are drawn: shaded sphere impostors (default), or
+    /// flat squares or discs.
+    ///
+    /// The flat modes skip the per-pixel ray-sphere intersection and
+    /// lighting, so they are cheaper when points cover many pixels; for
+    /// clouds with millions of small points the cost is dominated by the
+    /// vertex work and the difference shrinks.
+    pub fn set_point_render_mode(&self, mode: PointRenderMode) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            pc.set_point_render_mode(mode);
+        });
+        self
+    }
+
+    /// Returns how points are drawn, or the default if the point cloud does
+    /// not exist.
+    #[must_use]
+    pub fn point_render_mode(&self) -> PointRenderMode {
+        with_point_cloud_ref(&self.name, PointCloud::point_render_mode).unwrap_or_default()
+    }
+
+    /// Draws only a fraction (0..1) of the points, e.g. `0.1` draws every
+    /// tenth point.
+    ///
+    /// The subset is fixed, so it does not flicker between frames, and only
+    /// drawn points can be picked. Useful to keep huge clouds interactive.
+    pub fn set_display_fraction(&self, fraction: f32) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            pc.set_display_fraction(fraction);
+        });
+        self
+    }
+
+    /// Returns the fraction of points that are drawn, or 1.0 if the point
+    /// cloud does not exist.
+    #[must_use]
+    pub fn display_fraction(&self) -> f32 {
+        with_point_cloud_ref(&self.name, PointCloud::display_fraction).unwrap_or(1.0)
+    }
+
     /// Adds a scalar quantity to this point cloud.
     ///
     /// Scalar quantities assign a single value to each point, visualized
@@ -114,11 +211,77 @@ impl PointCloudHandle {
     /// * `name` - Name for this quantity (shown in UI)
     /// * `values` - One scalar value per point
     pub fn add_scalar_quantity(&self, name: &str, values: Vec<f32>) -> &Self {
-        with_context_mut(|ctx| {
-            if let Some(pc) = ctx.registry.get_mut("PointCloud", &self.name) {
-                if let Some(pc) = (pc as &mut dyn std::any::Any).downcast_mut::<PointCloud>() {
-                    pc.add_scalar_quantity(name, values);
-                }
+        with_point_cloud(&self.name, |pc| {
+            pc.add_scalar_quantity(name, values);
+        });
+        self
+    }
+
+    /// Adds a scalar quantity defined on a subset of the points.
+    ///
+    /// Points with `None` have no data and are drawn in
+    /// `Options::no_data_color` instead of the colormap.
+    pub fn add_scalar_quantity_masked(&self, name: &str, values: Vec<Option<f32>>) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            pc.add_scalar_quantity_masked(name, values);
+        });
+        self
+    }
+
+    /// Adds a scalar quantity that uses the given colormap.
+    ///
+    /// The colormap name is validated against the available colormaps
+    /// (e.g. `"viridis"`, `"coolwarm"`, `"blues"`). Unknown names fall back
+    /// to the default colormap and log a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name for this quantity (shown in UI)
+    /// * `values` - One scalar value per point
+    /// * `colormap` - Name of the colormap to use
+    pub fn add_scalar_quantity_with_colormap(
+        &self,
+        name: &str,
+        values: Vec<f32>,
+        colormap: &str,
+    ) -> &Self {
+        let color_maps = crate::color_maps::color_map_registry();
+        with_point_cloud(&self.name, |pc| {
+            pc.add_scalar_quantity(name, values);
+            if let Some(q) = pc
+                .get_quantity_mut(name)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudScalarQuantity>())
+            {
+                q.set_colormap_checked(colormap, &color_maps);
+            }
+        });
+        self
+    }
+
+    /// Sets a fixed colormap range on a scalar quantity.
+    ///
+    /// By default the range is fitted to the data min/max. A fixed range
+    /// keeps colors comparable between frames; values outside it are clamped.
+    pub fn set_scalar_map_range(&self, quantity: &str, min: f32, max: f32) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudScalarQuantity>())
+            {
+                q.set_map_range(min, max);
+            }
+        });
+        self
+    }
+
+    /// Resets a scalar quantity's colormap range to the data min/max.
+    pub fn reset_scalar_map_range(&self, quantity: &str) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudScalarQuantity>())
+            {
+                q.reset_map_range();
             }
         });
         self
@@ -135,11 +298,60 @@ impl PointCloudHandle {
     /// * `name` - Name for this quantity (shown in UI)
     /// * `vectors` - One 3D vector per point
     pub fn add_vector_quantity(&self, name: &str, vectors: Vec<Vec3>) -> &Self {
-        with_context_mut(|ctx| {
-            if let Some(pc) = ctx.registry.get_mut("PointCloud", &self.name) {
-                if let Some(pc) = (pc as &mut dyn std::any::Any).downcast_mut::<PointCloud>() {
-                    pc.add_vector_quantity(name, vectors);
-                }
+        with_point_cloud(&self.name, |pc| {
+            pc.add_vector_quantity(name, vectors);
+        });
+        self
+    }
+
+    /// Sets the arrow length scale of a vector quantity.
+    ///
+    /// If `relative`, the scale is multiplied by the point cloud's size.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyscope_rs::*;
+    ///
+    /// init().unwrap();
+    /// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X])
+    ///     .add_vector_quantity("dir", vec![Vec3::Y, Vec3::new(0.0, 5.0, 0.0)])
+    ///     .set_vector_normalize("dir", true)
+    ///     .set_vector_length_scale("dir", 0.1, true);
+    /// ```
+    pub fn set_vector_length_scale(&self, quantity: &str, scale: f32, relative: bool) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudVectorQuantity>())
+            {
+                q.set_length_scale(scale, relative);
+            }
+        });
+        self
+    }
+
+    /// Sets the arrow shaft radius of a vector quantity.
+    pub fn set_vector_radius(&self, quantity: &str, radius: f32) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudVectorQuantity>())
+            {
+                q.set_radius(radius);
+            }
+        });
+        self
+    }
+
+    /// Sets whether a vector quantity draws all arrows with the same length.
+    pub fn set_vector_normalize(&self, quantity: &str, normalize: bool) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudVectorQuantity>())
+            {
+                q.set_normalize(normalize);
             }
         });
         self
@@ -156,11 +368,58 @@ impl PointCloudHandle {
     /// * `name` - Name for this quantity (shown in UI)
     /// * `colors` - One RGB color (Vec3) per point
     pub fn add_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> &Self {
-        with_context_mut(|ctx| {
-            if let Some(pc) = ctx.registry.get_mut("PointCloud", &self.name) {
-                if let Some(pc) = (pc as &mut dyn std::any::Any).downcast_mut::<PointCloud>() {
-                    pc.add_color_quantity(name, colors);
-                }
+        with_point_cloud(&self.name, |pc| {
+            pc.add_color_quantity(name, colors);
+        });
+        self
+    }
+
+    /// Adds a color quantity with explicit per-point RGBA alpha values.
+    ///
+    /// Points with alpha below 1 are blended, even when the point cloud is
+    /// otherwise opaque; fully transparent points are not drawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the point cloud does not exist or `colors` does
+    /// not have one entry per point.
+    pub fn add_color_quantity_with_alpha(&self, name: &str, colors: Vec<Vec4>) -> Result<&Self> {
+        with_point_cloud(&self.name, |pc| {
+            pc.add_color_quantity_with_alpha(name, colors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a radius quantity that sizes each point by a scalar value.
+    ///
+    /// When enabled, each point is drawn with its own radius. By default
+    /// values are relative: the largest value maps to the point cloud's
+    /// point radius. Use [`Self::set_radius_quantity_relative`] to use the
+    /// values directly as world-space radii. Negative values are clamped
+    /// to zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the point cloud does not exist or `values` does
+    /// not have one entry per point.
+    pub fn add_scalar_radius_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_point_cloud(&self.name, |pc| {
+            pc.add_scalar_radius_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Sets whether a radius quantity is relative to the point radius
+    /// (`true`) or gives absolute world-space radii (`false`).
+    pub fn set_radius_quantity_relative(&self, quantity: &str, is_relative: bool) -> &Self {
+        with_point_cloud(&self.name, |pc| {
+            if let Some(q) = pc
+                .get_quantity_mut(quantity)
+                .and_then(|q| q.as_any_mut().downcast_mut::<PointCloudRadiusQuantity>())
+            {
+                q.set_radius_is_relative(is_relative);
             }
         });
         self
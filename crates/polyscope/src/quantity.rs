@@ -0,0 +1,286 @@
+//! Enabling, disabling and updating quantities by name.
+//!
+//! Structures and quantities are addressed by name, with the structure type
+//! names accepted by [`set_structure_enabled`](crate::set_structure_enabled),
+//! e.g. `"SurfaceMesh"`.
+
+use crate::{
+    CameraView, ColorSpace, CurveNetwork, HasQuantities, OrStructureNotFound, PointCloud,
+    PolyscopeError, Quantity, QuantityKind, Result, SurfaceMesh, VolumeGrid, VolumeMesh,
+    with_context, with_context_mut,
+};
+use polyscope_structures::quantity_update::{set_color_space, update_scalar_values};
+
+/// Enables or disables a quantity on a structure.
+///
+/// Logs a warning if the structure or the quantity does not exist. Other
+/// quantities are left as they are; see [`activate_quantity`] to also disable
+/// the quantities competing with it.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X])
+///     .add_scalar_quantity("height", vec![0.0, 1.0]);
+/// set_quantity_enabled("PointCloud", "pts", "height", true);
+/// assert!(is_quantity_enabled("PointCloud", "pts", "height"));
+/// ```
+pub fn set_quantity_enabled(
+    type_name: &str,
+    struct_name: &str,
+    quantity_name: &str,
+    enabled: bool,
+) {
+    let found = with_quantities_mut(type_name, struct_name, |structure| {
+        structure
+            .get_quantity_mut(quantity_name)
+            .map(|q| q.set_enabled(enabled))
+            .is_some()
+    });
+    if found == Some(true) {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!(
+            "set_quantity_enabled: no quantity '{quantity_name}' on {type_name} '{struct_name}'"
+        );
+    }
+}
+
+/// Enables a quantity and disables the other quantities of the same
+/// [`QuantityKind`] on its structure, so that it is the one displayed (e.g.
+/// the only enabled scalar quantity).
+///
+/// Logs a warning if the structure or the quantity does not exist.
+pub fn activate_quantity(type_name: &str, struct_name: &str, quantity_name: &str) {
+    let found = with_quantities_mut(type_name, struct_name, |structure| {
+        let Some(kind) = structure.get_quantity(quantity_name).map(Quantity::kind) else {
+            return false;
+        };
+        let siblings: Vec<String> = structure
+            .quantities()
+            .iter()
+            .filter(|q| q.kind() == kind && q.name() != quantity_name)
+            .map(|q| q.name().to_string())
+            .collect();
+        for sibling in siblings {
+            if let Some(q) = structure.get_quantity_mut(&sibling) {
+                q.set_enabled(false);
+            }
+        }
+        if let Some(q) = structure.get_quantity_mut(quantity_name) {
+            q.set_enabled(true);
+        }
+        true
+    });
+    if found == Some(true) {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!(
+            "activate_quantity: no quantity '{quantity_name}' on {type_name} '{struct_name}'"
+        );
+    }
+}
+
+/// Returns whether a quantity is enabled, or false if it does not exist.
+#[must_use]
+pub fn is_quantity_enabled(type_name: &str, struct_name: &str, quantity_name: &str) -> bool {
+    with_quantities(type_name, struct_name, |structure| {
+        structure
+            .get_quantity(quantity_name)
+            .map(Quantity::is_enabled)
+    })
+    .flatten()
+    .unwrap_or(false)
+}
+
+/// Returns the names of a structure's quantities, in insertion order.
+///
+/// Returns an empty list if the structure does not exist.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X])
+///     .add_scalar_quantity("height", vec![0.0, 1.0]);
+/// assert_eq!(get_quantity_names("PointCloud", "pts"), vec!["height"]);
+/// assert_eq!(
+///     get_quantity_kind("PointCloud", "pts", "height"),
+///     Some(QuantityKind::Scalar)
+/// );
+/// ```
+#[must_use]
+pub fn get_quantity_names(type_name: &str, struct_name: &str) -> Vec<String> {
+    with_quantities(type_name, struct_name, |structure| {
+        structure.quantity_names()
+    })
+    .unwrap_or_default()
+}
+
+/// Returns whether a structure has a quantity with the given name.
+#[must_use]
+pub fn has_quantity(type_name: &str, struct_name: &str, quantity_name: &str) -> bool {
+    with_quantities(type_name, struct_name, |structure| {
+        structure.has_quantity(quantity_name)
+    })
+    .unwrap_or(false)
+}
+
+/// Returns the kind of a quantity, or `None` if it does not exist.
+#[must_use]
+pub fn get_quantity_kind(
+    type_name: &str,
+    struct_name: &str,
+    quantity_name: &str,
+) -> Option<QuantityKind> {
+    with_quantities(type_name, struct_name, |structure| {
+        structure.get_quantity(quantity_name).map(Quantity::kind)
+    })
+    .flatten()
+}
+
+/// Replaces the values of a scalar quantity in place.
+///
+/// The quantity keeps its identity, enabled state and colormap, so this is
+/// suited to per-frame updates; the new colors are uploaded on the next
+/// frame. The colormap range follows the new values unless it was set
+/// explicitly, e.g. with `set_map_range`.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::StructureNotFound`] or
+/// [`PolyscopeError::QuantityNotFound`] if there is no such structure or
+/// scalar quantity, and [`PolyscopeError::SizeMismatch`] unless `values` has
+/// as many entries as the current values.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X])
+///     .add_scalar_quantity("height", vec![0.0, 1.0]);
+/// for frame in 0..10 {
+///     let t = frame as f32 * 0.1;
+///     update_scalar_quantity("PointCloud", "pts", "height", vec![t, 1.0 - t]).unwrap();
+/// }
+/// ```
+pub fn update_scalar_quantity(
+    type_name: &str,
+    struct_name: &str,
+    quantity_name: &str,
+    values: Vec<f32>,
+) -> Result<()> {
+    let result = with_quantities_mut(type_name, struct_name, |structure| {
+        structure
+            .get_quantity_mut(quantity_name)
+            .and_then(|q| update_scalar_values(q.as_mut(), values))
+            .unwrap_or_else(|| {
+                Err(PolyscopeError::QuantityNotFound(
+                    quantity_name.to_string(),
+                    struct_name.to_string(),
+                ))
+            })
+    })
+    .or_structure_not_found(struct_name);
+    if result.is_ok() {
+        crate::redraw::notify_scene_changed();
+    }
+    result
+}
+
+/// Sets how the colors of a color quantity are encoded.
+///
+/// Color quantities are sRGB by default and converted to linear on upload,
+/// since scenes are shaded in linear space. Use [`ColorSpace::Linear`] for
+/// colors that are already linear, e.g. computed from physical quantities.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::StructureNotFound`] or
+/// [`PolyscopeError::QuantityNotFound`] if there is no such structure or
+/// color quantity.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X])
+///     .add_color_quantity("radiance", vec![Vec3::splat(0.2), Vec3::ONE]);
+/// set_color_quantity_space("PointCloud", "pts", "radiance", ColorSpace::Linear).unwrap();
+/// ```
+pub fn set_color_quantity_space(
+    type_name: &str,
+    struct_name: &str,
+    quantity_name: &str,
+    color_space: ColorSpace,
+) -> Result<()> {
+    let result = with_quantities_mut(type_name, struct_name, |structure| {
+        let found = structure
+            .get_quantity_mut(quantity_name)
+            .is_some_and(|q| set_color_space(q.as_mut(), color_space));
+        if found {
+            Ok(())
+        } else {
+            Err(PolyscopeError::QuantityNotFound(
+                quantity_name.to_string(),
+                struct_name.to_string(),
+            ))
+        }
+    })
+    .or_structure_not_found(struct_name);
+    if result.is_ok() {
+        crate::redraw::notify_scene_changed();
+    }
+    result
+}
+
+/// Runs `f` on the quantities of a structure, if it exists.
+fn with_quantities<R>(
+    type_name: &str,
+    struct_name: &str,
+    f: impl FnOnce(&dyn HasQuantities) -> R,
+) -> Option<R> {
+    with_context(|ctx| {
+        let structure = ctx.registry.get(type_name, struct_name)?.as_any();
+        let structure: &dyn HasQuantities = match type_name {
+            "PointCloud" => structure.downcast_ref::<PointCloud>()?,
+            "SurfaceMesh" => structure.downcast_ref::<SurfaceMesh>()?,
+            "CurveNetwork" => structure.downcast_ref::<CurveNetwork>()?,
+            "VolumeMesh" => structure.downcast_ref::<VolumeMesh>()?,
+            "VolumeGrid" => structure.downcast_ref::<VolumeGrid>()?,
+            "CameraView" => structure.downcast_ref::<CameraView>()?,
+            _ => return None,
+        };
+        Some(f(structure))
+    })
+}
+
+/// Runs `f` on the quantities of a structure, if it exists.
+fn with_quantities_mut<R>(
+    type_name: &str,
+    struct_name: &str,
+    f: impl FnOnce(&mut dyn HasQuantities) -> R,
+) -> Option<R> {
+    with_context_mut(|ctx| {
+        let structure = ctx.registry.get_mut(type_name, struct_name)?.as_any_mut();
+        let structure: &mut dyn HasQuantities = match type_name {
+            "PointCloud" => structure.downcast_mut::<PointCloud>()?,
+            "SurfaceMesh" => structure.downcast_mut::<SurfaceMesh>()?,
+            "CurveNetwork" => structure.downcast_mut::<CurveNetwork>()?,
+            "VolumeMesh" => structure.downcast_mut::<VolumeMesh>()?,
+            "VolumeGrid" => structure.downcast_mut::<VolumeGrid>()?,
+            "CameraView" => structure.downcast_mut::<CameraView>()?,
+            _ => return None,
+        };
+        Some(f(structure))
+    })
+}
@@ -0,0 +1,86 @@
+//! On-demand redraws.
+//!
+//! By default the viewer renders continuously. With
+//! [`set_redraw_on_change(true)`](set_redraw_on_change) it only renders when
+//! something may have changed: on window input, after API calls that modify
+//! the scene (e.g. `set_*_transform`, enabling structures or quantities), and
+//! when [`request_redraw`] is called.
+
+use std::sync::{Arc, Mutex};
+
+use winit::window::Window;
+
+use crate::{with_context, with_context_mut};
+
+/// The window the viewer currently renders to, if any.
+static REDRAW_WINDOW: Mutex<Option<Arc<Window>>> = Mutex::new(None);
+
+/// Asks the viewer window to render a new frame.
+///
+/// Use this after changing data that the viewer cannot see change, e.g.
+/// from a background thread, when on-demand rendering is enabled with
+/// [`set_redraw_on_change`]. Does nothing if no window is open.
+///
+/// This may be called from any thread: it only signals the event loop,
+/// which renders the frame on its own thread. Scene changes made from
+/// another thread before the call are visible to that frame, since all
+/// state is shared through the global context lock.
+pub fn request_redraw() {
+    let window = REDRAW_WINDOW
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(window) = window.as_ref() {
+        window.request_redraw();
+    }
+}
+
+/// Sets whether the viewer renders only on demand.
+///
+/// When enabled, the window is redrawn on input, after scene changes made
+/// through the API and on [`request_redraw`], instead of every frame. This
+/// saves power for mostly static scenes. Note that the user callback then
+/// also only runs when a frame is drawn.
+pub fn set_redraw_on_change(enabled: bool) {
+    with_context_mut(|ctx| {
+        ctx.options.redraw_on_change = enabled;
+    });
+    request_redraw();
+}
+
+/// Returns whether the viewer renders only on demand.
+#[must_use]
+pub fn get_redraw_on_change() -> bool {
+    with_context(|ctx| ctx.options.redraw_on_change)
+}
+
+/// Requests a redraw after a scene change, if rendering on demand.
+pub(crate) fn notify_scene_changed() {
+    if get_redraw_on_change() {
+        request_redraw();
+    }
+}
+
+/// Returns whether a viewer window is open, i.e. `show()` is running.
+pub(crate) fn window_open() -> bool {
+    REDRAW_WINDOW
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Sets the window that [`request_redraw`] signals.
+pub(crate) fn set_redraw_window(window: Arc<Window>) {
+    *REDRAW_WINDOW
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(window);
+}
+
+/// Clears the window set with [`set_redraw_window`], if it is still `window`.
+pub(crate) fn clear_redraw_window(window: &Arc<Window>) {
+    let mut current = REDRAW_WINDOW
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if current.as_ref().is_some_and(|w| Arc::ptr_eq(w, window)) {
+        *current = None;
+    }
+}
@@ -1,6 +1,7 @@
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
-use crate::ScreenshotOptions;
+use crate::{ScreenshotOptions, with_context, with_context_mut};
 
 /// Global screenshot request storage.
 /// This allows `screenshot()` to be called from user code while `show()` is running.
@@ -17,8 +18,11 @@ pub struct ScreenshotRequest {
 
 /// Requests a screenshot with an auto-generated filename.
 ///
-/// The screenshot will be saved as `screenshot_NNNN.png` in the current directory,
-/// where NNNN is an auto-incrementing number.
+/// By default the screenshot is saved as `screenshot_NNNN.png` in the current
+/// directory, where NNNN is the first number not already used by a matching
+/// file. The directory, prefix and extension can be changed with
+/// [`set_screenshot_directory`], [`set_screenshot_prefix`] and
+/// [`set_screenshot_extension`].
 ///
 /// This function can be called while `show()` is running.
 /// The screenshot will be captured on the next frame.
@@ -94,3 +98,116 @@ pub(crate) fn take_screenshot_request() -> Option<ScreenshotRequest> {
         .ok()
         .and_then(|mut guard| guard.take())
 }
+
+/// Sets the directory for auto-named screenshots.
+///
+/// An empty path (the default) means the current directory. The directory is
+/// created when the next screenshot is saved.
+pub fn set_screenshot_directory(path: impl Into<PathBuf>) {
+    let path = path.into();
+    with_context_mut(|ctx| {
+        ctx.options.screenshot_dir = path;
+    });
+}
+
+/// Returns the directory for auto-named screenshots.
+#[must_use]
+pub fn get_screenshot_directory() -> PathBuf {
+    with_context(|ctx| ctx.options.screenshot_dir.clone())
+}
+
+/// Sets the filename prefix for auto-named screenshots (default `"screenshot"`).
+pub fn set_screenshot_prefix(prefix: impl Into<String>) {
+    let prefix = prefix.into();
+    with_context_mut(|ctx| {
+        ctx.options.screenshot_prefix = prefix;
+    });
+}
+
+/// Returns the filename prefix for auto-named screenshots.
+#[must_use]
+pub fn get_screenshot_prefix() -> String {
+    with_context(|ctx| ctx.options.screenshot_prefix.clone())
+}
+
+/// Sets the file extension for auto-named screenshots (default `"png"`).
+///
+/// A leading dot is ignored. Supported formats are PNG and JPEG.
+pub fn set_screenshot_extension(extension: impl Into<String>) {
+    let extension = extension.into();
+    let extension = extension.trim_start_matches('.').to_string();
+    with_context_mut(|ctx| {
+        ctx.options.screenshot_extension = extension;
+    });
+}
+
+/// Returns the file extension for auto-named screenshots.
+#[must_use]
+pub fn get_screenshot_extension() -> String {
+    with_context(|ctx| ctx.options.screenshot_extension.clone())
+}
+
+/// Returns the path for the next auto-named screenshot (for internal use by App).
+///
+/// Skips numbers already used by matching files in the target directory, so
+/// screenshots from earlier runs are not overwritten, and advances `counter`.
+pub(crate) fn next_auto_screenshot_path(counter: &mut u32) -> String {
+    let (dir, prefix, extension) = with_context(|ctx| {
+        (
+            ctx.options.screenshot_dir.clone(),
+            ctx.options.screenshot_prefix.clone(),
+            ctx.options.screenshot_extension.clone(),
+        )
+    });
+
+    let index = (*counter).max(first_unused_screenshot_index(&dir, &prefix, &extension));
+    *counter = index + 1;
+
+    numbered_image_path(&dir, &prefix, index, &extension)
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Returns `<dir>/<prefix>_NNNN.<extension>`, creating `dir` if needed.
+pub(crate) fn numbered_image_path(
+    dir: &Path,
+    prefix: &str,
+    index: u32,
+    extension: &str,
+) -> PathBuf {
+    if !dir.as_os_str().is_empty() {
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            log::warn!(
+                "Failed to create screenshot directory {}: {e}",
+                dir.display()
+            );
+        }
+    }
+
+    dir.join(format!("{prefix}_{index:04}.{extension}"))
+}
+
+/// Returns one past the highest `<prefix>_NNNN.<extension>` number in `dir`.
+fn first_unused_screenshot_index(dir: &Path, prefix: &str, extension: &str) -> u32 {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let number = name
+                .strip_prefix(prefix)?
+                .strip_prefix('_')?
+                .strip_suffix(extension)?
+                .strip_suffix('.')?;
+            number.parse::<u32>().ok()
+        })
+        .map(|n| n.saturating_add(1))
+        .max()
+        .unwrap_or(0)
+}
@@ -25,7 +25,9 @@
 //! }
 //! ```
 
-use crate::{Vec3, Vec4, with_context, with_context_mut};
+use crate::{
+    PolyscopeError, Result, SlicePlane, Vec3, Vec4, fit_plane, with_context, with_context_mut,
+};
 
 /// Adds a new slice plane to cut through geometry.
 ///
@@ -64,6 +66,44 @@ pub fn add_slice_plane_with_pose(
     SlicePlaneHandle { name }
 }
 
+/// Places a slice plane through a point with the given normal, e.g. a picked
+/// surface point and its normal.
+///
+/// The plane is created if it does not exist.
+pub fn fit_slice_plane_to_point(name: &str, world_point: Vec3, normal: Vec3) -> SlicePlaneHandle {
+    with_context_mut(|ctx| ctx.add_slice_plane(name).set_pose(world_point, normal));
+    crate::redraw::notify_scene_changed();
+    SlicePlaneHandle {
+        name: name.to_string(),
+    }
+}
+
+/// Places a slice plane on the best-fit plane of sampled points.
+///
+/// The plane goes through the centroid of the points, with the direction of
+/// least variance as its normal. The normal is oriented to keep the side an
+/// existing plane already keeps. The plane is created if it does not exist.
+///
+/// # Errors
+///
+/// Returns `PolyscopeError::InvalidGeometry` for fewer than three points or
+/// points that are all on one line.
+pub fn fit_slice_plane_to_points(name: &str, points: &[Vec3]) -> Result<SlicePlaneHandle> {
+    let (centroid, normal) = fit_plane(points).ok_or_else(|| {
+        PolyscopeError::InvalidGeometry(format!(
+            "cannot fit a plane to {} points; need three not on one line",
+            points.len()
+        ))
+    })?;
+    let previous = with_context(|ctx| ctx.get_slice_plane(name).map(SlicePlane::normal));
+    let normal = if previous.is_some_and(|n| n.dot(normal) < 0.0) {
+        -normal
+    } else {
+        normal
+    };
+    Ok(fit_slice_plane_to_point(name, centroid, normal))
+}
+
 /// Gets an existing slice plane by name.
 #[must_use]
 pub fn get_slice_plane(name: &str) -> Option<SlicePlaneHandle> {
@@ -103,6 +143,36 @@ pub fn get_all_slice_planes() -> Vec<String> {
     })
 }
 
+/// Sets whether a structure ignores all slice planes.
+///
+/// A flagged structure is drawn uncut regardless of which planes are active
+/// or which structures they target. Structures are addressed by the type names
+/// accepted by [`set_structure_enabled`](crate::set_structure_enabled).
+pub fn set_ignore_slice_planes(type_name: &str, name: &str, ignore: bool) {
+    let found = with_context_mut(|ctx| {
+        ctx.registry
+            .get_mut(type_name, name)
+            .map(|structure| structure.set_ignore_slice_planes(ignore))
+            .is_some()
+    });
+    if found {
+        crate::redraw::notify_scene_changed();
+    } else {
+        log::warn!("set_ignore_slice_planes: no {type_name} named '{name}'");
+    }
+}
+
+/// Returns whether a structure ignores all slice planes, or false if it does
+/// not exist.
+#[must_use]
+pub fn get_ignore_slice_planes(type_name: &str, name: &str) -> bool {
+    with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .is_some_and(polyscope_core::Structure::ignores_slice_planes)
+    })
+}
+
 /// Handle for a slice plane.
 #[derive(Clone)]
 pub struct SlicePlaneHandle {
@@ -183,6 +253,58 @@ impl SlicePlaneHandle {
         })
     }
 
+    /// Sets whether to flip which side of the plane is kept.
+    ///
+    /// The normal (and the widget arrow) keep pointing the same way.
+    pub fn set_inverted(&self, inverted: bool) -> &Self {
+        with_context_mut(|ctx| {
+            if let Some(plane) = ctx.get_slice_plane_mut(&self.name) {
+                plane.set_inverted(inverted);
+            }
+        });
+        self
+    }
+
+    /// Returns whether the kept side is flipped.
+    #[must_use]
+    pub fn is_inverted(&self) -> bool {
+        with_context(|ctx| {
+            ctx.get_slice_plane(&self.name)
+                .is_some_and(polyscope_core::SlicePlane::is_inverted)
+        })
+    }
+
+    /// Restricts the plane to the given (`type_name`, name) structures.
+    ///
+    /// `None` makes the plane cut all structures (the default).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polyscope_rs::*;
+    ///
+    /// init().unwrap();
+    /// add_slice_plane("cut")
+    ///     .set_active_structures(Some(vec![("SurfaceMesh".into(), "mesh A".into())]));
+    /// ```
+    pub fn set_active_structures(&self, structures: Option<Vec<(String, String)>>) -> &Self {
+        with_context_mut(|ctx| {
+            if let Some(plane) = ctx.get_slice_plane_mut(&self.name) {
+                plane.set_active_structures(structures);
+            }
+        });
+        self
+    }
+
+    /// Returns the structures the plane cuts, or `None` if it cuts all.
+    #[must_use]
+    pub fn active_structures(&self) -> Option<Vec<(String, String)>> {
+        with_context(|ctx| {
+            ctx.get_slice_plane(&self.name)
+                .and_then(|plane| plane.active_structures().map(<[_]>::to_vec))
+        })
+    }
+
     /// Sets whether to draw the plane visualization.
     pub fn set_draw_plane(&self, draw: bool) -> &Self {
         with_context_mut(|ctx| {
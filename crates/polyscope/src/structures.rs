@@ -0,0 +1,119 @@
+//! Structure registry queries.
+//!
+//! Structures are addressed by type name (e.g. `"PointCloud"`) and name.
+
+use crate::{Structure, Vec3, redraw, with_context, with_context_mut};
+
+/// Removes a structure by name.
+pub fn remove_structure(name: &str) {
+    with_context_mut(|ctx| {
+        // Try removing from each structure type
+        ctx.registry.remove("PointCloud", name);
+        ctx.registry.remove("SurfaceMesh", name);
+        ctx.registry.remove("CurveNetwork", name);
+        ctx.registry.remove("VolumeMesh", name);
+        ctx.registry.remove("VolumeGrid", name);
+        ctx.registry.remove("CameraView", name);
+        ctx.update_extents();
+    });
+}
+
+/// Enables or disables a single structure by type and name.
+///
+/// A disabled structure is skipped when drawing and picking. Structure
+/// groups can still hide an enabled structure. Logs a warning if no such
+/// structure exists.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X]);
+/// set_structure_enabled("PointCloud", "pts", false);
+/// assert!(!is_structure_enabled("PointCloud", "pts"));
+/// ```
+pub fn set_structure_enabled(type_name: &str, name: &str, enabled: bool) {
+    let found = with_context_mut(|ctx| {
+        ctx.registry
+            .get_mut(type_name, name)
+            .map(|structure| structure.set_enabled(enabled))
+            .is_some()
+    });
+    if found {
+        redraw::notify_scene_changed();
+    } else {
+        log::warn!("set_structure_enabled: no {type_name} named '{name}'");
+    }
+}
+
+/// Returns whether a structure is enabled, or false if it does not exist.
+#[must_use]
+pub fn is_structure_enabled(type_name: &str, name: &str) -> bool {
+    with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .is_some_and(Structure::is_enabled)
+    })
+}
+
+/// Returns `(type_name, name)` pairs for all registered structures.
+///
+/// The pairs are sorted by type name, then by name. Type names are the ones
+/// accepted by [`set_structure_enabled`], e.g. `"PointCloud"`.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X]);
+/// for (type_name, name) in get_all_structures() {
+///     set_structure_enabled(&type_name, &name, false);
+/// }
+/// ```
+#[must_use]
+pub fn get_all_structures() -> Vec<(String, String)> {
+    let mut structures: Vec<(String, String)> = with_context(|ctx| {
+        ctx.registry
+            .iter()
+            .map(|s| (s.type_name().to_string(), s.name().to_string()))
+            .collect()
+    });
+    structures.sort();
+    structures
+}
+
+/// Returns the number of registered structures.
+#[must_use]
+pub fn num_structures() -> usize {
+    with_context(|ctx| ctx.registry.len())
+}
+
+/// Returns a structure's axis-aligned bounding box in world space.
+///
+/// The structure's transform is applied. Returns `None` if the structure
+/// does not exist or has no spatial extent.
+#[must_use]
+pub fn get_structure_bounding_box(type_name: &str, name: &str) -> Option<(Vec3, Vec3)> {
+    with_context(|ctx| ctx.registry.get(type_name, name)?.bounding_box())
+}
+
+/// Returns the center of a structure's world-space bounding box.
+///
+/// See [`get_structure_bounding_box`].
+#[must_use]
+pub fn get_structure_centroid(type_name: &str, name: &str) -> Option<Vec3> {
+    get_structure_bounding_box(type_name, name).map(|(min, max)| (min + max) * 0.5)
+}
+
+/// Removes all structures.
+pub fn remove_all_structures() {
+    with_context_mut(|ctx| {
+        ctx.registry.clear();
+        ctx.planar_data_hint = false;
+        ctx.update_extents();
+    });
+}
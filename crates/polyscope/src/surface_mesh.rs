@@ -32,8 +32,16 @@
 //! }
 //! ```
 
-use crate::{SurfaceMesh, Vec2, Vec3, Vec4, with_context_mut};
+use crate::{
+    DVec3, HasQuantities, Mat4, OrStructureNotFound, ParamVizStyle, PickResult, PolyscopeError,
+    Result, SurfaceMesh, Vec2, Vec3, Vec4, set_surface_mesh_transform, with_context,
+    with_context_mut,
+};
 use glam::UVec3;
+use polyscope_structures::surface_mesh::{
+    MeshCornerParameterizationQuantity, MeshEdgeScalarQuantity, MeshFaceScalarQuantity,
+    MeshVertexParameterizationQuantity, MeshVertexScalarQuantity, bounding_box_center_f64,
+};
 
 /// Trait for face data that can be converted to the internal polygon format.
 ///
@@ -84,21 +92,8 @@ pub fn register_surface_mesh(
 ) -> SurfaceMeshHandle {
     let name = name.into();
     let faces = faces.into_face_list();
-    let n_verts = vertices.len();
-
-    // Validate faces
-    for (i, face) in faces.iter().enumerate() {
-        assert!(
-            face.len() >= 3,
-            "Face {i} has {} vertices (minimum 3 required)",
-            face.len()
-        );
-        for &idx in face {
-            assert!(
-                (idx as usize) < n_verts,
-                "Face {i} contains vertex index {idx} but mesh only has {n_verts} vertices"
-            );
-        }
+    if let Err(err) = validate_faces(&faces, vertices.len()) {
+        panic!("{err}");
     }
 
     let mesh = SurfaceMesh::new(name.clone(), vertices, faces);
@@ -113,6 +108,203 @@ pub fn register_surface_mesh(
     SurfaceMeshHandle { name }
 }
 
+/// Registers a polygonal surface mesh, validating the faces.
+///
+/// Faces may have any number of vertices (triangles, quads, n-gons); they are
+/// fan-triangulated for rendering. Unlike [`register_surface_mesh`], invalid
+/// input is reported as an error instead of a panic.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::InvalidGeometry`] if any face has fewer than 3
+/// vertices or contains an out-of-range vertex index, and
+/// [`PolyscopeError::StructureExists`] if the name is already taken.
+pub fn register_surface_mesh_polygonal(
+    name: impl Into<String>,
+    vertices: Vec<Vec3>,
+    faces: Vec<Vec<u32>>,
+) -> Result<SurfaceMeshHandle> {
+    let name = name.into();
+    validate_faces(&faces, vertices.len())?;
+
+    let mesh = SurfaceMesh::new(name.clone(), vertices, faces);
+
+    with_context_mut(|ctx| -> Result<()> {
+        ctx.registry.register(Box::new(mesh))?;
+        ctx.update_extents();
+        Ok(())
+    })?;
+
+    Ok(SurfaceMeshHandle { name })
+}
+
+/// Registers a surface mesh from double-precision vertex positions.
+///
+/// Use this for data with large coordinates (e.g. geospatial meshes), where
+/// `f32` positions would lose precision. Vertices are stored relative to the
+/// shared scene origin (see [`get_scene_origin`]), so the positions sent to
+/// the GPU stay small and all `f64` meshes keep their relative placement.
+/// The first call sets the origin to the center of its mesh and moves the
+/// structures and camera views that already exist by it, so they keep their
+/// position relative to the new mesh.
+///
+/// The scene is rendered relative to the origin: transforms, the camera and
+/// structures registered afterwards from `f32` positions use these scene
+/// coordinates. Use [`SurfaceMeshHandle::bounding_box_f64`] and
+/// [`PickResult::world_position_f64`] to recover true world coordinates.
+///
+/// # Panics
+///
+/// Panics if any face has fewer than 3 vertices or contains out-of-bounds
+/// vertex indices.
+pub fn register_surface_mesh_f64(
+    name: impl Into<String>,
+    vertices: Vec<[f64; 3]>,
+    faces: impl IntoFaceList,
+) -> SurfaceMeshHandle {
+    let name = name.into();
+    let faces = faces.into_face_list();
+    if let Err(err) = validate_faces(&faces, vertices.len()) {
+        panic!("{err}");
+    }
+
+    let origin = scene_origin_for(&vertices);
+    let mesh = SurfaceMesh::new_f64(name.clone(), &vertices, faces, origin);
+
+    with_context_mut(|ctx| {
+        ctx.registry
+            .register(Box::new(mesh))
+            .expect("failed to register surface mesh");
+        ctx.update_extents();
+    });
+
+    SurfaceMeshHandle { name }
+}
+
+/// Returns the world position that scene coordinates are relative to.
+///
+/// This is zero until the first [`register_surface_mesh_f64`] call sets it;
+/// [`remove_everything`](crate::remove_everything) resets it. Subtract it
+/// from large world coordinates to place `f32` data next to `f64` meshes.
+#[must_use]
+pub fn get_scene_origin() -> DVec3 {
+    with_context(|ctx| ctx.scene_origin.unwrap_or(DVec3::ZERO))
+}
+
+/// Returns the scene origin, first setting it to the center of `vertices`
+/// if none is set. Structures and camera views that already exist are then
+/// moved by it so they keep their position relative to the new origin.
+fn scene_origin_for(vertices: &[[f64; 3]]) -> DVec3 {
+    let (origin, newly_set) = with_context_mut(|ctx| {
+        if let Some(origin) = ctx.scene_origin {
+            return (origin, false);
+        }
+        let origin = bounding_box_center_f64(vertices);
+        ctx.scene_origin = Some(origin);
+        let shift = Mat4::from_translation(-origin.as_vec3());
+        for structure in ctx.registry.iter_mut() {
+            let transform = structure.transform();
+            structure.set_transform(shift * transform);
+        }
+        ctx.update_extents();
+        (origin, true)
+    });
+    if newly_set && origin != DVec3::ZERO {
+        crate::view::translate_view(-origin.as_vec3());
+    }
+    origin
+}
+
+/// Writes a registered surface mesh to a Wavefront OBJ file.
+///
+/// See [`SurfaceMesh::export_obj`] for what is written.
+///
+/// # Errors
+///
+/// Returns an error if the mesh does not exist or the file cannot be written.
+pub fn export_surface_mesh(name: &str, path: impl AsRef<std::path::Path>) -> Result<()> {
+    with_surface_mesh_ref(name, |mesh| mesh.export_obj(path)).or_structure_not_found(name)
+}
+
+/// Registers a simplified copy of a surface mesh under `new_name`.
+///
+/// See [`SurfaceMesh::decimate`] for how the mesh is simplified. The copy
+/// takes the transform of the source mesh; the source is left unchanged.
+///
+/// # Errors
+///
+/// Returns [`PolyscopeError::StructureNotFound`] if the source mesh does not
+/// exist, and [`PolyscopeError::StructureExists`] if `new_name` is taken.
+pub fn register_decimated(
+    source_name: &str,
+    new_name: impl Into<String>,
+    target_tris: usize,
+) -> Result<SurfaceMeshHandle> {
+    let name = new_name.into();
+    let (mesh, transform) = with_surface_mesh_ref(source_name, |mesh| {
+        let decimated = mesh.decimate(target_tris);
+        let faces = decimated
+            .triangulation()
+            .iter()
+            .map(|tri| tri.to_vec())
+            .collect();
+        (
+            SurfaceMesh::new(name.clone(), decimated.vertices().to_vec(), faces),
+            polyscope_core::Structure::transform(mesh),
+        )
+    })
+    .ok_or_else(|| PolyscopeError::StructureNotFound(source_name.to_string()))?;
+
+    with_context_mut(|ctx| -> Result<()> {
+        ctx.registry.register(Box::new(mesh))?;
+        ctx.update_extents();
+        Ok(())
+    })?;
+    set_surface_mesh_transform(&name, transform);
+
+    Ok(SurfaceMeshHandle { name })
+}
+
+/// Runs `f` on a vertex scalar quantity of the named mesh, if it exists.
+fn with_vertex_scalar(mesh: &str, quantity: &str, f: impl FnOnce(&mut MeshVertexScalarQuantity)) {
+    with_surface_mesh(mesh, |mesh| {
+        if let Some(sq) = mesh
+            .get_quantity_mut(quantity)
+            .and_then(|q| q.as_any_mut().downcast_mut::<MeshVertexScalarQuantity>())
+        {
+            f(sq);
+        }
+    });
+}
+
+/// Fills in the double-precision world position of a surface mesh pick.
+pub(crate) fn with_world_position_f64(mut pick: PickResult) -> PickResult {
+    if pick.structure_type == "SurfaceMesh" {
+        pick.world_position_f64 = with_surface_mesh_ref(&pick.structure_name, |mesh| {
+            mesh.to_world_f64(pick.world_position)
+        });
+    }
+    pick
+}
+
+/// Checks that every face has at least 3 vertices and only valid indices.
+fn validate_faces(faces: &[Vec<u32>], n_verts: usize) -> Result<()> {
+    for (i, face) in faces.iter().enumerate() {
+        if face.len() < 3 {
+            return Err(PolyscopeError::InvalidGeometry(format!(
+                "Face {i} has {} vertices (minimum 3 required)",
+                face.len()
+            )));
+        }
+        if let Some(&idx) = face.iter().find(|&&idx| idx as usize >= n_verts) {
+            return Err(PolyscopeError::InvalidGeometry(format!(
+                "Face {i} contains vertex index {idx} but mesh only has {n_verts} vertices"
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl_structure_accessors! {
     get_fn = get_surface_mesh,
     with_fn = with_surface_mesh,
@@ -153,6 +345,56 @@ impl SurfaceMeshHandle {
         &self.name
     }
 
+    /// Shows or hides this mesh; a hidden mesh is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("SurfaceMesh", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this mesh is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("SurfaceMesh", &self.name)
+    }
+
+    /// Returns the double-precision origin the mesh vertices are relative to.
+    ///
+    /// This is zero unless the mesh was registered with
+    /// [`register_surface_mesh_f64`].
+    #[must_use]
+    pub fn origin(&self) -> DVec3 {
+        with_surface_mesh_ref(&self.name, SurfaceMesh::origin).unwrap_or(DVec3::ZERO)
+    }
+
+    /// Returns the world-space bounding box in double precision.
+    #[must_use]
+    pub fn bounding_box_f64(&self) -> Option<(DVec3, DVec3)> {
+        with_surface_mesh_ref(&self.name, SurfaceMesh::bounding_box_f64).flatten()
+    }
+
+    /// Returns the world-space vertex centroid in double precision.
+    #[must_use]
+    pub fn centroid_f64(&self) -> Option<DVec3> {
+        with_surface_mesh_ref(&self.name, SurfaceMesh::centroid_f64).flatten()
+    }
+
+    // -- Orientation --
+
+    /// Reverses the winding of every face, turning the mesh inside out.
+    pub fn flip_orientation(&self) -> &Self {
+        with_surface_mesh(&self.name, SurfaceMesh::flip_orientation);
+        self
+    }
+
+    /// Makes the face winding consistent within each connected component,
+    /// with closed components facing outward. Useful for imported meshes that
+    /// render dark from inconsistent winding.
+    ///
+    /// Returns the number of reversed faces (0 if the mesh does not exist).
+    pub fn orient_consistently(&self) -> usize {
+        with_surface_mesh(&self.name, SurfaceMesh::orient_consistently).unwrap_or(0)
+    }
+
     // -- Appearance setters --
 
     /// Sets the surface color.
@@ -215,149 +457,504 @@ impl SurfaceMeshHandle {
     // -- Quantity methods --
 
     /// Adds a vertex scalar quantity.
-    pub fn add_vertex_scalar_quantity(&self, name: &str, values: Vec<f32>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `values` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_scalar_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_vertex_scalar_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a vertex scalar quantity defined on a subset of the vertices.
+    ///
+    /// Vertices with `None` have no data and are drawn in
+    /// `Options::no_data_color` instead of the colormap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `values` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_scalar_quantity_masked(
+        &self,
+        name: &str,
+        values: Vec<Option<f32>>,
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_scalar_quantity(name, values);
+            mesh.add_vertex_scalar_quantity_masked(name, values)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a face scalar quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `values` does not have one
+    /// entry per face.
+    pub fn add_face_scalar_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_face_scalar_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds an edge scalar quantity, with one value per edge in the order of
+    /// `SurfaceMesh::edges()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `values` does not have one
+    /// entry per edge.
+    pub fn add_edge_scalar_quantity(&self, name: &str, values: Vec<f32>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_edge_scalar_quantity(name, values).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a face scalar quantity holding the area of each face, kept up to
+    /// date when the vertices are updated.
+    pub fn add_face_area_quantity(&self, name: &str) -> &Self {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_face_area_quantity(name);
         });
         self
     }
 
-    /// Adds a face scalar quantity.
-    pub fn add_face_scalar_quantity(&self, name: &str, values: Vec<f32>) -> &Self {
+    /// Adds an edge scalar quantity holding the length of each edge, kept up
+    /// to date when the vertices are updated.
+    pub fn add_edge_length_quantity(&self, name: &str) -> &Self {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_scalar_quantity(name, values);
+            mesh.add_edge_length_quantity(name);
         });
         self
     }
 
-    /// Adds a vertex color quantity (RGB, alpha defaults to 1.0).
-    pub fn add_vertex_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> &Self {
+    /// Adds a vertex scalar quantity holding the approximate geodesic
+    /// distance from `source_vertex`, computed with the heat method.
+    ///
+    /// Vertices not connected to the source are clamped to the largest
+    /// reachable distance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `source_vertex` is not a
+    /// vertex index.
+    pub fn add_geodesic_distance_quantity(&self, name: &str, source_vertex: u32) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_color_quantity(name, colors);
+            mesh.add_geodesic_distance_quantity(name, source_vertex)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Sets a fixed colormap range on a vertex, face or edge scalar quantity.
+    ///
+    /// By default the range is fitted to the data min/max. A fixed range
+    /// keeps colors comparable between frames; values outside it are clamped.
+    pub fn set_scalar_map_range(&self, quantity: &str, min: f32, max: f32) -> &Self {
+        with_surface_mesh(&self.name, |mesh| {
+            if let Some(q) = mesh.get_quantity_mut(quantity) {
+                if let Some(sq) = q.as_any_mut().downcast_mut::<MeshVertexScalarQuantity>() {
+                    sq.set_map_range(min, max);
+                } else if let Some(sq) = q.as_any_mut().downcast_mut::<MeshFaceScalarQuantity>() {
+                    sq.set_map_range(min, max);
+                } else if let Some(sq) = q.as_any_mut().downcast_mut::<MeshEdgeScalarQuantity>() {
+                    sq.set_map_range(min, max);
+                }
+            }
+        });
+        self
+    }
+
+    /// Resets a vertex, face or edge scalar quantity's colormap range to the data min/max.
+    pub fn reset_scalar_map_range(&self, quantity: &str) -> &Self {
+        with_surface_mesh(&self.name, |mesh| {
+            if let Some(q) = mesh.get_quantity_mut(quantity) {
+                if let Some(sq) = q.as_any_mut().downcast_mut::<MeshVertexScalarQuantity>() {
+                    sq.reset_map_range();
+                } else if let Some(sq) = q.as_any_mut().downcast_mut::<MeshFaceScalarQuantity>() {
+                    sq.reset_map_range();
+                } else if let Some(sq) = q.as_any_mut().downcast_mut::<MeshEdgeScalarQuantity>() {
+                    sq.reset_map_range();
+                }
+            }
         });
         self
     }
 
+    /// Enables or disables isolines (contour lines) on a vertex scalar quantity.
+    pub fn set_isolines_enabled(&self, quantity: &str, enabled: bool) -> &Self {
+        with_vertex_scalar(&self.name, quantity, |sq| sq.set_isolines_enabled(enabled));
+        self
+    }
+
+    /// Sets the number of isolines across a vertex scalar quantity's colormap range.
+    pub fn set_isoline_count(&self, quantity: &str, count: u32) -> &Self {
+        with_vertex_scalar(&self.name, quantity, |sq| sq.set_isoline_count(count));
+        self
+    }
+
+    /// Sets the isoline width in pixels for a vertex scalar quantity.
+    pub fn set_isoline_width(&self, quantity: &str, width: f32) -> &Self {
+        with_vertex_scalar(&self.name, quantity, |sq| sq.set_isoline_width(width));
+        self
+    }
+
+    /// Returns whether isolines are enabled on a vertex scalar quantity.
+    #[must_use]
+    pub fn isolines_enabled(&self, quantity: &str) -> bool {
+        with_surface_mesh_ref(&self.name, |mesh| {
+            mesh.get_quantity(quantity)?
+                .as_any()
+                .downcast_ref::<MeshVertexScalarQuantity>()
+                .map(MeshVertexScalarQuantity::isolines_enabled)
+        })
+        .flatten()
+        .unwrap_or(false)
+    }
+
+    /// Adds a vertex color quantity (RGB, alpha defaults to 1.0).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `colors` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_vertex_color_quantity(name, colors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
     /// Adds a vertex color quantity with explicit per-vertex RGBA alpha values.
     ///
     /// Use this to specify per-vertex transparency. Requires Pretty (depth peeling)
     /// transparency mode to render correctly (set via appearance settings).
-    pub fn add_vertex_color_quantity_with_alpha(&self, name: &str, colors: Vec<Vec4>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `colors` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_color_quantity_with_alpha(
+        &self,
+        name: &str,
+        colors: Vec<Vec4>,
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_color_quantity_with_alpha(name, colors);
-        });
-        self
+            mesh.add_vertex_color_quantity_with_alpha(name, colors)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a face color quantity (RGB, alpha defaults to 1.0).
-    pub fn add_face_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `colors` does not have one
+    /// entry per face.
+    pub fn add_face_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_color_quantity(name, colors);
-        });
-        self
+            mesh.add_face_color_quantity(name, colors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a face color quantity with explicit per-face RGBA alpha values.
     ///
     /// Use this to specify per-face transparency. Requires Pretty (depth peeling)
     /// transparency mode to render correctly (set via appearance settings).
-    pub fn add_face_color_quantity_with_alpha(&self, name: &str, colors: Vec<Vec4>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `colors` does not have one
+    /// entry per face.
+    pub fn add_face_color_quantity_with_alpha(
+        &self,
+        name: &str,
+        colors: Vec<Vec4>,
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_color_quantity_with_alpha(name, colors);
-        });
-        self
+            mesh.add_face_color_quantity_with_alpha(name, colors)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a corner (per-face-vertex) color quantity.
+    ///
+    /// `colors` has one entry per face corner, faces in order, so faces can be
+    /// colored discontinuously across shared vertices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `colors` does not have one
+    /// entry per face corner.
+    pub fn add_corner_color_quantity(&self, name: &str, colors: Vec<Vec3>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_corner_color_quantity(name, colors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a vertex vector quantity (auto-scaled).
-    pub fn add_vertex_vector_quantity(&self, name: &str, vectors: Vec<Vec3>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_vector_quantity(&self, name: &str, vectors: Vec<Vec3>) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_vector_quantity(name, vectors);
-        });
-        self
+            mesh.add_vertex_vector_quantity(name, vectors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a face vector quantity (auto-scaled).
-    pub fn add_face_vector_quantity(&self, name: &str, vectors: Vec<Vec3>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` does not have one
+    /// entry per face.
+    pub fn add_face_vector_quantity(&self, name: &str, vectors: Vec<Vec3>) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_face_vector_quantity(name, vectors).map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a vertex parameterization (UV) quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `coords` does not have one
+    /// entry per vertex.
+    pub fn add_vertex_parameterization_quantity(
+        &self,
+        name: &str,
+        coords: Vec<Vec2>,
+    ) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_vertex_parameterization_quantity(name, coords)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Adds a corner parameterization (UV) quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `coords` does not have one
+    /// entry per face corner.
+    pub fn add_corner_parameterization_quantity(
+        &self,
+        name: &str,
+        coords: Vec<Vec2>,
+    ) -> Result<&Self> {
+        with_surface_mesh(&self.name, |mesh| {
+            mesh.add_corner_parameterization_quantity(name, coords)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
+    }
+
+    /// Sets the visualization style (checker, grid, local check or local
+    /// radial) of a vertex or corner parameterization quantity.
+    pub fn set_parameterization_style(&self, quantity: &str, style: ParamVizStyle) -> &Self {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_vector_quantity(name, vectors);
+            if let Some(q) = mesh.get_quantity_mut(quantity) {
+                if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshVertexParameterizationQuantity>()
+                {
+                    pq.set_style(style);
+                } else if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshCornerParameterizationQuantity>()
+                {
+                    pq.set_style(style);
+                }
+            }
         });
         self
     }
 
-    /// Adds a vertex parameterization (UV) quantity.
-    pub fn add_vertex_parameterization_quantity(&self, name: &str, coords: Vec<Vec2>) -> &Self {
+    /// Returns the visualization style of a vertex or corner parameterization
+    /// quantity, or `None` if there is no such quantity.
+    #[must_use]
+    pub fn parameterization_style(&self, quantity: &str) -> Option<ParamVizStyle> {
+        with_surface_mesh_ref(&self.name, |mesh| {
+            let q = mesh.get_quantity(quantity)?.as_any();
+            q.downcast_ref::<MeshVertexParameterizationQuantity>()
+                .map(MeshVertexParameterizationQuantity::style)
+                .or_else(|| {
+                    q.downcast_ref::<MeshCornerParameterizationQuantity>()
+                        .map(MeshCornerParameterizationQuantity::style)
+                })
+        })
+        .flatten()
+    }
+
+    /// Sets the checker (and grid) cell size of a vertex or corner
+    /// parameterization quantity, in UV units.
+    pub fn set_parameterization_checker_size(&self, quantity: &str, size: f32) -> &Self {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_parameterization_quantity(name, coords);
+            if let Some(q) = mesh.get_quantity_mut(quantity) {
+                if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshVertexParameterizationQuantity>()
+                {
+                    pq.set_checker_size(size);
+                } else if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshCornerParameterizationQuantity>()
+                {
+                    pq.set_checker_size(size);
+                }
+            }
         });
         self
     }
 
-    /// Adds a corner parameterization (UV) quantity.
-    pub fn add_corner_parameterization_quantity(&self, name: &str, coords: Vec<Vec2>) -> &Self {
+    /// Sets the two checker colors of a vertex or corner parameterization
+    /// quantity. The grid style draws lines in the second color.
+    pub fn set_parameterization_checker_colors(&self, quantity: &str, colors: [Vec3; 2]) -> &Self {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_corner_parameterization_quantity(name, coords);
+            if let Some(q) = mesh.get_quantity_mut(quantity) {
+                if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshVertexParameterizationQuantity>()
+                {
+                    pq.set_checker_colors(colors);
+                } else if let Some(pq) = q
+                    .as_any_mut()
+                    .downcast_mut::<MeshCornerParameterizationQuantity>()
+                {
+                    pq.set_checker_colors(colors);
+                }
+            }
         });
         self
     }
 
     /// Adds a vertex intrinsic vector quantity with explicit tangent basis (auto-scaled).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` or either basis
+    /// does not have one entry per vertex.
     pub fn add_vertex_intrinsic_vector_quantity(
         &self,
         name: &str,
         vectors: Vec<Vec2>,
         basis_x: Vec<Vec3>,
         basis_y: Vec<Vec3>,
-    ) -> &Self {
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_intrinsic_vector_quantity(name, vectors, basis_x, basis_y);
-        });
-        self
+            mesh.add_vertex_intrinsic_vector_quantity(name, vectors, basis_x, basis_y)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a vertex intrinsic vector quantity with auto-computed tangent basis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` does not have one
+    /// entry per vertex.
     pub fn add_vertex_intrinsic_vector_quantity_auto(
         &self,
         name: &str,
         vectors: Vec<Vec2>,
-    ) -> &Self {
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_vertex_intrinsic_vector_quantity_auto(name, vectors);
-        });
-        self
+            mesh.add_vertex_intrinsic_vector_quantity_auto(name, vectors)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a face intrinsic vector quantity with explicit tangent basis (auto-scaled).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` or either basis
+    /// does not have one entry per face.
     pub fn add_face_intrinsic_vector_quantity(
         &self,
         name: &str,
         vectors: Vec<Vec2>,
         basis_x: Vec<Vec3>,
         basis_y: Vec<Vec3>,
-    ) -> &Self {
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_intrinsic_vector_quantity(name, vectors, basis_x, basis_y);
-        });
-        self
+            mesh.add_face_intrinsic_vector_quantity(name, vectors, basis_x, basis_y)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a face intrinsic vector quantity with auto-computed tangent basis.
-    pub fn add_face_intrinsic_vector_quantity_auto(&self, name: &str, vectors: Vec<Vec2>) -> &Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `vectors` does not have one
+    /// entry per face.
+    pub fn add_face_intrinsic_vector_quantity_auto(
+        &self,
+        name: &str,
+        vectors: Vec<Vec2>,
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_face_intrinsic_vector_quantity_auto(name, vectors);
-        });
-        self
+            mesh.add_face_intrinsic_vector_quantity_auto(name, vectors)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 
     /// Adds a one-form quantity (edge-based differential form, auto-scaled).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mesh does not exist or `values` or `orientations`
+    /// does not have one entry per edge.
     pub fn add_one_form_quantity(
         &self,
         name: &str,
         values: Vec<f32>,
         orientations: Vec<bool>,
-    ) -> &Self {
+    ) -> Result<&Self> {
         with_surface_mesh(&self.name, |mesh| {
-            mesh.add_one_form_quantity(name, values, orientations);
-        });
-        self
+            mesh.add_one_form_quantity(name, values, orientations)
+                .map(|_| ())
+        })
+        .or_structure_not_found(&self.name)?;
+        Ok(self)
     }
 }
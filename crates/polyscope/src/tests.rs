@@ -0,0 +1,1464 @@
+use super::*;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+// Counter for unique test names to avoid race conditions
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+fn unique_name(prefix: &str) -> String {
+    let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+    format!("{prefix}_{n}")
+}
+
+fn setup() {
+    // Initialize context (only once)
+    // Use ok() to handle race conditions in parallel tests
+    let _ = init();
+}
+
+#[test]
+fn test_register_curve_network() {
+    setup();
+    let name = unique_name("test_cn");
+    let nodes = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+    ];
+    let edges = vec![[0, 1], [1, 2]];
+
+    let handle = register_curve_network(&name, nodes, edges);
+    assert_eq!(handle.name(), name);
+
+    // Verify it's retrievable
+    let found = get_curve_network(&name);
+    assert!(found.is_some());
+
+    // Verify non-existent returns None
+    let not_found = get_curve_network("nonexistent_xyz_123");
+    assert!(not_found.is_none());
+}
+
+#[test]
+fn test_register_curve_network_line() {
+    setup();
+    let name = unique_name("line");
+    let nodes = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(2.0, 0.0, 0.0),
+        Vec3::new(3.0, 0.0, 0.0),
+    ];
+
+    register_curve_network_line(&name, nodes);
+
+    let num_edges = with_curve_network_ref(&name, |cn| cn.num_edges());
+    assert_eq!(num_edges, Some(3)); // 0-1, 1-2, 2-3
+}
+
+#[test]
+fn test_register_curve_network_loop() {
+    setup();
+    let name = unique_name("loop");
+    let nodes = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+    ];
+
+    register_curve_network_loop(&name, nodes);
+
+    let num_edges = with_curve_network_ref(&name, |cn| cn.num_edges());
+    assert_eq!(num_edges, Some(3)); // 0-1, 1-2, 2-0
+}
+
+#[test]
+fn test_register_curve_network_segments() {
+    setup();
+    let name = unique_name("segs");
+    let nodes = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(2.0, 0.0, 0.0),
+        Vec3::new(3.0, 0.0, 0.0),
+    ];
+
+    register_curve_network_segments(&name, nodes);
+
+    let num_edges = with_curve_network_ref(&name, |cn| cn.num_edges());
+    assert_eq!(num_edges, Some(2)); // 0-1, 2-3
+}
+
+#[test]
+fn test_curve_network_handle_methods() {
+    setup();
+    let name = unique_name("handle_test");
+    let nodes = vec![Vec3::ZERO, Vec3::X];
+    let edges = vec![[0, 1]];
+
+    let handle = register_curve_network(&name, nodes, edges);
+
+    // Test chained setters
+    handle
+        .set_color(Vec3::new(1.0, 0.0, 0.0))
+        .set_radius(0.1, false)
+        .set_material("clay");
+
+    // Verify values were set
+    with_curve_network_ref(&name, |cn| {
+        assert_eq!(cn.color(), Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(cn.radius(), 0.1);
+        assert!(!cn.radius_is_relative());
+        assert_eq!(cn.material(), "clay");
+    });
+}
+
+#[test]
+fn test_surface_mesh_handle_methods() {
+    setup();
+    let name = unique_name("sm_handle_test");
+    let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE];
+    let faces = vec![[0u32, 1, 2], [1, 3, 2]];
+
+    let handle = register_surface_mesh(&name, vertices, faces);
+
+    // Test chained quantity methods
+    handle
+        .add_vertex_scalar_quantity("curvature", vec![0.0, 0.25, 0.5, 1.0])
+        .unwrap()
+        .add_vertex_color_quantity("vcolor", vec![Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE])
+        .unwrap()
+        .add_vertex_vector_quantity("vvec", vec![Vec3::Z; 4])
+        .unwrap()
+        .add_face_scalar_quantity("area", vec![0.5, 0.5])
+        .unwrap()
+        .add_face_color_quantity("region", vec![Vec3::X, Vec3::Y])
+        .unwrap()
+        .add_face_vector_quantity("fvec", vec![Vec3::Z; 2])
+        .unwrap();
+
+    // Verify quantities were added with the right sizes
+    with_surface_mesh_ref(&name, |mesh| {
+        assert_eq!(mesh.quantities().len(), 6);
+        let cases = [
+            ("curvature", 4, QuantityKind::Scalar),
+            ("vcolor", 4, QuantityKind::Color),
+            ("vvec", 4, QuantityKind::Vector),
+            ("area", 2, QuantityKind::Scalar),
+            ("region", 2, QuantityKind::Color),
+            ("fvec", 2, QuantityKind::Vector),
+        ];
+        for (q_name, size, kind) in cases {
+            let q = mesh.get_quantity(q_name).unwrap();
+            assert_eq!(q.data_size(), size, "data_size mismatch for {q_name}");
+            assert_eq!(q.kind(), kind, "kind mismatch for {q_name}");
+        }
+    });
+
+    // Adding to a removed mesh reports the missing structure
+    remove_structure(&name);
+    assert!(matches!(
+        handle.add_vertex_scalar_quantity("ignored", vec![0.0; 4]),
+        Err(PolyscopeError::StructureNotFound(_))
+    ));
+    assert!(get_surface_mesh(&name).is_none());
+}
+
+#[test]
+fn test_quantity_names_and_kinds() {
+    setup();
+    let name = unique_name("pc_quantity_names");
+    register_point_cloud(&name, vec![Vec3::ZERO, Vec3::X])
+        .add_scalar_quantity("height", vec![0.0, 1.0])
+        .add_vector_quantity("velocity", vec![Vec3::Y; 2]);
+
+    assert_eq!(
+        get_quantity_names("PointCloud", &name),
+        vec!["height", "velocity"]
+    );
+    assert!(has_quantity("PointCloud", &name, "velocity"));
+    assert!(!has_quantity("PointCloud", &name, "missing"));
+    assert_eq!(
+        get_quantity_kind("PointCloud", &name, "height"),
+        Some(QuantityKind::Scalar)
+    );
+    assert_eq!(
+        get_quantity_kind("PointCloud", &name, "velocity"),
+        Some(QuantityKind::Vector)
+    );
+    assert!(get_quantity_names("PointCloud", "no_such_cloud").is_empty());
+    remove_structure(&name);
+}
+
+#[test]
+fn test_point_cloud_scalar_colormap() {
+    use polyscope_structures::point_cloud::PointCloudScalarQuantity;
+
+    setup();
+    let name = unique_name("pc_colormap");
+    let pc = register_point_cloud(&name, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+
+    pc.add_scalar_quantity("plain", vec![0.0, 0.5, 1.0])
+        .add_scalar_quantity_with_colormap("viridis_q", vec![0.0, 0.5, 1.0], "viridis")
+        .add_scalar_quantity_with_colormap("coolwarm_q", vec![0.0, 0.5, 1.0], "coolwarm")
+        .add_scalar_quantity_with_colormap("bogus_q", vec![0.0, 0.5, 1.0], "no_such_map");
+
+    let colormap = |q_name: &str| {
+        with_point_cloud_ref(&name, |pc| {
+            pc.get_quantity(q_name)
+                .and_then(|q| q.as_any().downcast_ref::<PointCloudScalarQuantity>())
+                .map(|q| q.colormap_name().to_string())
+        })
+        .flatten()
+    };
+    assert_eq!(colormap("plain").as_deref(), Some("viridis"));
+    assert_eq!(colormap("viridis_q").as_deref(), Some("viridis"));
+    assert_eq!(colormap("coolwarm_q").as_deref(), Some("coolwarm"));
+    // Unknown names fall back to the default
+    assert_eq!(colormap("bogus_q").as_deref(), Some("viridis"));
+}
+
+#[test]
+fn test_scalar_map_range() {
+    use polyscope_structures::point_cloud::PointCloudScalarQuantity;
+    use polyscope_structures::surface_mesh::{MeshFaceScalarQuantity, MeshVertexScalarQuantity};
+
+    setup();
+    let pc_name = unique_name("pc_map_range");
+    let pc = register_point_cloud(&pc_name, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+    pc.add_scalar_quantity("s", vec![1.0, 2.0, 3.0])
+        .set_scalar_map_range("s", -1.0, 5.0);
+
+    let pc_range = || {
+        with_point_cloud_ref(&pc_name, |pc| {
+            let q = pc.get_quantity("s").unwrap();
+            let q = q
+                .as_any()
+                .downcast_ref::<PointCloudScalarQuantity>()
+                .unwrap();
+            (q.range_min(), q.range_max())
+        })
+    };
+    assert_eq!(pc_range(), Some((-1.0, 5.0)));
+    pc.reset_scalar_map_range("s");
+    assert_eq!(pc_range(), Some((1.0, 3.0)));
+
+    let mesh_name = unique_name("mesh_map_range");
+    let mesh = register_surface_mesh(
+        &mesh_name,
+        vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+        vec![[0u32, 1, 2]],
+    );
+    mesh.add_vertex_scalar_quantity("vs", vec![0.0, 1.0, 2.0])
+        .unwrap()
+        .add_face_scalar_quantity("fs", vec![4.0])
+        .unwrap()
+        .set_scalar_map_range("vs", 0.5, 1.5)
+        .set_scalar_map_range("fs", 0.0, 10.0);
+
+    with_surface_mesh_ref(&mesh_name, |m| {
+        let vs = m.get_quantity("vs").unwrap();
+        let vs = vs
+            .as_any()
+            .downcast_ref::<MeshVertexScalarQuantity>()
+            .unwrap();
+        assert_eq!((vs.range_min(), vs.range_max()), (0.5, 1.5));
+        let fs = m.get_quantity("fs").unwrap();
+        let fs = fs
+            .as_any()
+            .downcast_ref::<MeshFaceScalarQuantity>()
+            .unwrap();
+        assert_eq!((fs.range_min(), fs.range_max()), (0.0, 10.0));
+    });
+
+    mesh.reset_scalar_map_range("vs");
+    with_surface_mesh_ref(&mesh_name, |m| {
+        let vs = m.get_quantity("vs").unwrap();
+        let vs = vs
+            .as_any()
+            .downcast_ref::<MeshVertexScalarQuantity>()
+            .unwrap();
+        assert_eq!((vs.range_min(), vs.range_max()), (0.0, 2.0));
+    });
+}
+
+#[test]
+fn test_with_curve_network() {
+    setup();
+    let name = unique_name("with_test");
+    let nodes = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+    let edges = vec![[0, 1], [1, 2]];
+
+    register_curve_network(&name, nodes, edges);
+
+    // Test mutable access
+    let result = with_curve_network(&name, |cn| {
+        cn.set_color(Vec3::new(0.5, 0.5, 0.5));
+        cn.num_nodes()
+    });
+    assert_eq!(result, Some(3));
+
+    // Verify mutation persisted
+    let color = with_curve_network_ref(&name, |cn| cn.color());
+    assert_eq!(color, Some(Vec4::new(0.5, 0.5, 0.5, 1.0)));
+}
+
+#[test]
+fn test_create_group() {
+    setup();
+    let name = unique_name("test_group");
+    let handle = create_group(&name);
+    assert_eq!(handle.name(), name);
+    assert!(handle.is_enabled());
+}
+
+#[test]
+fn test_get_group() {
+    setup();
+    let name = unique_name("get_group");
+    create_group(&name);
+
+    let found = get_group(&name);
+    assert!(found.is_some());
+    assert_eq!(found.unwrap().name(), name);
+
+    let not_found = get_group("nonexistent_group_xyz");
+    assert!(not_found.is_none());
+}
+
+#[test]
+fn test_group_enable_disable() {
+    setup();
+    let name = unique_name("enable_group");
+    let handle = create_group(&name);
+
+    assert!(handle.is_enabled());
+    handle.set_enabled(false);
+    assert!(!handle.is_enabled());
+    handle.set_enabled(true);
+    assert!(handle.is_enabled());
+}
+
+#[test]
+fn test_group_add_structures() {
+    setup();
+    let group_name = unique_name("struct_group");
+    let pc_name = unique_name("pc_in_group");
+
+    // Create point cloud
+    register_point_cloud(&pc_name, vec![Vec3::ZERO, Vec3::X]);
+
+    // Create group and add point cloud
+    let handle = create_group(&group_name);
+    handle.add_point_cloud(&pc_name);
+
+    assert_eq!(handle.num_structures(), 1);
+}
+
+#[test]
+fn test_group_hierarchy() {
+    setup();
+    let parent_name = unique_name("parent_group");
+    let child_name = unique_name("child_group");
+
+    let parent = create_group(&parent_name);
+    let _child = create_group(&child_name);
+
+    parent.add_child_group(&child_name);
+
+    assert_eq!(parent.num_child_groups(), 1);
+}
+
+#[test]
+fn test_remove_group() {
+    setup();
+    let name = unique_name("remove_group");
+    create_group(&name);
+
+    assert!(get_group(&name).is_some());
+    remove_group(&name);
+    assert!(get_group(&name).is_none());
+}
+
+#[test]
+fn test_add_slice_plane() {
+    setup();
+    let name = unique_name("slice_plane");
+    let handle = add_slice_plane(&name);
+    assert_eq!(handle.name(), name);
+    assert!(handle.is_enabled());
+}
+
+#[test]
+fn test_slice_plane_pose() {
+    setup();
+    let name = unique_name("slice_pose");
+    let handle = add_slice_plane_with_pose(&name, Vec3::new(1.0, 2.0, 3.0), Vec3::X);
+
+    assert_eq!(handle.origin(), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(handle.normal(), Vec3::X);
+}
+
+#[test]
+fn test_slice_plane_setters() {
+    setup();
+    let name = unique_name("slice_setters");
+    let handle = add_slice_plane(&name);
+
+    handle
+        .set_origin(Vec3::new(1.0, 0.0, 0.0))
+        .set_normal(Vec3::Z)
+        .set_color(Vec3::new(1.0, 0.0, 0.0))
+        .set_transparency(0.5);
+
+    assert_eq!(handle.origin(), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(handle.normal(), Vec3::Z);
+    assert_eq!(handle.color(), Vec4::new(1.0, 0.0, 0.0, 1.0));
+    assert!((handle.transparency() - 0.5).abs() < 0.001);
+}
+
+#[test]
+fn test_slice_plane_enable_disable() {
+    setup();
+    let name = unique_name("slice_enable");
+    let handle = add_slice_plane(&name);
+
+    assert!(handle.is_enabled());
+    handle.set_enabled(false);
+    assert!(!handle.is_enabled());
+    handle.set_enabled(true);
+    assert!(handle.is_enabled());
+}
+
+#[test]
+fn test_remove_slice_plane() {
+    setup();
+    let name = unique_name("slice_remove");
+    add_slice_plane(&name);
+
+    assert!(get_slice_plane(&name).is_some());
+    remove_slice_plane(&name);
+    assert!(get_slice_plane(&name).is_none());
+}
+
+#[test]
+fn test_select_structure() {
+    setup();
+    let name = unique_name("select_pc");
+    register_point_cloud(&name, vec![Vec3::ZERO]);
+
+    assert!(!has_selection());
+
+    select_structure("PointCloud", &name);
+    assert!(has_selection());
+
+    let selected = get_selected_structure();
+    assert!(selected.is_some());
+    let (type_name, struct_name) = selected.unwrap();
+    assert_eq!(type_name, "PointCloud");
+    assert_eq!(struct_name, name);
+
+    deselect_structure();
+    assert!(!has_selection());
+}
+
+#[test]
+fn test_slice_plane_gizmo_selection() {
+    setup();
+    let name = unique_name("slice_gizmo");
+    add_slice_plane(&name);
+
+    // Initially no slice plane selected
+    let info = get_slice_plane_selection_info();
+    assert!(!info.has_selection);
+
+    // Select slice plane
+    select_slice_plane_for_gizmo(&name);
+    let info = get_slice_plane_selection_info();
+    assert!(info.has_selection);
+    assert_eq!(info.name, name);
+
+    // Deselect slice plane
+    deselect_slice_plane_gizmo();
+    let info = get_slice_plane_selection_info();
+    assert!(!info.has_selection);
+}
+
+#[test]
+fn test_slice_plane_structure_mutual_exclusion() {
+    setup();
+    let pc_name = unique_name("mutual_pc");
+    let plane_name = unique_name("mutual_plane");
+
+    register_point_cloud(&pc_name, vec![Vec3::ZERO]);
+    add_slice_plane(&plane_name);
+
+    // Select structure
+    select_structure("PointCloud", &pc_name);
+    assert!(has_selection());
+
+    // Select slice plane - should deselect structure
+    select_slice_plane_for_gizmo(&plane_name);
+    assert!(!has_selection()); // Structure should be deselected
+    let info = get_slice_plane_selection_info();
+    assert!(info.has_selection);
+
+    // Select structure again - should deselect slice plane
+    select_structure("PointCloud", &pc_name);
+    assert!(has_selection());
+    let info = get_slice_plane_selection_info();
+    assert!(!info.has_selection); // Slice plane should be deselected
+}
+
+#[test]
+fn test_structure_transform() {
+    setup();
+    let name = unique_name("transform_pc");
+    register_point_cloud(&name, vec![Vec3::ZERO, Vec3::X]);
+
+    // Default transform is identity
+    let transform = get_point_cloud_transform(&name);
+    assert!(transform.is_some());
+
+    // Set a translation transform
+    let new_transform = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    set_point_cloud_transform(&name, new_transform);
+
+    let transform = get_point_cloud_transform(&name).unwrap();
+    let translation = transform.w_axis.truncate();
+    assert!((translation - Vec3::new(1.0, 2.0, 3.0)).length() < 0.001);
+}
+
+#[test]
+fn test_get_slice_plane_settings() {
+    setup();
+    let name = unique_name("ui_slice_plane");
+
+    // Add a slice plane
+    add_slice_plane_with_pose(&name, Vec3::new(1.0, 2.0, 3.0), Vec3::X);
+
+    // Get settings
+    let settings = get_slice_plane_settings();
+    let found = settings.iter().find(|s| s.name == name);
+    assert!(found.is_some());
+
+    let s = found.unwrap();
+    assert_eq!(s.origin, [1.0, 2.0, 3.0]);
+    assert_eq!(s.normal, [1.0, 0.0, 0.0]);
+    assert!(s.enabled);
+}
+
+#[test]
+fn test_apply_slice_plane_settings() {
+    setup();
+    let name = unique_name("apply_slice_plane");
+
+    // Add a slice plane
+    add_slice_plane(&name);
+
+    // Create modified settings
+    let settings = polyscope_ui::SlicePlaneSettings {
+        name: name.clone(),
+        enabled: false,
+        origin: [5.0, 6.0, 7.0],
+        normal: [0.0, 0.0, 1.0],
+        inverted: true,
+        draw_plane: false,
+        draw_widget: true,
+        color: [1.0, 0.0, 0.0],
+        transparency: 0.8,
+        plane_size: 0.2,
+        is_selected: false,
+    };
+
+    // Apply settings
+    apply_slice_plane_settings(&settings);
+
+    // Verify
+    let handle = get_slice_plane(&name).unwrap();
+    assert!(!handle.is_enabled());
+    assert_eq!(handle.origin(), Vec3::new(5.0, 6.0, 7.0));
+    assert_eq!(handle.normal(), Vec3::Z);
+    assert!(handle.is_inverted());
+    assert!(!handle.draw_plane());
+    assert!(handle.draw_widget());
+    assert_eq!(handle.color(), Vec4::new(1.0, 0.0, 0.0, 1.0));
+    assert!((handle.transparency() - 0.8).abs() < 0.001);
+}
+
+#[test]
+fn test_handle_slice_plane_action_add() {
+    setup();
+    let name = unique_name("action_add_plane");
+    let mut settings = Vec::new();
+
+    handle_slice_plane_action(
+        polyscope_ui::SlicePlanesAction::Add(name.clone()),
+        &mut settings,
+    );
+
+    assert_eq!(settings.len(), 1);
+    assert_eq!(settings[0].name, name);
+    assert!(get_slice_plane(&name).is_some());
+}
+
+#[test]
+fn test_handle_slice_plane_action_remove() {
+    setup();
+    let name = unique_name("action_remove_plane");
+
+    // Add plane
+    add_slice_plane(&name);
+    let mut settings = vec![polyscope_ui::SlicePlaneSettings::with_name(&name)];
+
+    // Remove via action
+    handle_slice_plane_action(polyscope_ui::SlicePlanesAction::Remove(0), &mut settings);
+
+    assert!(settings.is_empty());
+    assert!(get_slice_plane(&name).is_none());
+}
+
+#[test]
+fn test_get_group_settings() {
+    setup();
+    let name = unique_name("ui_group");
+    let pc_name = unique_name("pc_in_ui_group");
+
+    // Create group and add a structure
+    let handle = create_group(&name);
+    register_point_cloud(&pc_name, vec![Vec3::ZERO]);
+    handle.add_point_cloud(&pc_name);
+
+    // Get settings
+    let settings = get_group_settings();
+    let found = settings.iter().find(|s| s.name == name);
+    assert!(found.is_some());
+
+    let s = found.unwrap();
+    assert!(s.enabled);
+    assert!(s.show_child_details);
+    assert_eq!(s.child_structures.len(), 1);
+    assert_eq!(s.child_structures[0], ("PointCloud".to_string(), pc_name));
+}
+
+#[test]
+fn test_apply_group_settings() {
+    setup();
+    let name = unique_name("apply_group");
+
+    // Create group
+    create_group(&name);
+
+    // Create modified settings
+    let settings = polyscope_ui::GroupSettings {
+        name: name.clone(),
+        enabled: false,
+        show_child_details: false,
+        parent_group: None,
+        child_structures: Vec::new(),
+        child_groups: Vec::new(),
+    };
+
+    // Apply settings
+    apply_group_settings(&settings);
+
+    // Verify
+    let handle = get_group(&name).unwrap();
+    assert!(!handle.is_enabled());
+}
+
+#[test]
+fn test_get_gizmo_settings() {
+    setup();
+
+    // Set known values
+    set_gizmo_space(GizmoSpace::Local);
+    set_gizmo_visible(false);
+    set_gizmo_snap_translate(0.5);
+    set_gizmo_snap_rotate(15.0);
+    set_gizmo_snap_scale(0.1);
+
+    let settings = get_gizmo_settings();
+    assert!(settings.local_space); // Local
+    assert!(!settings.visible);
+    assert!((settings.snap_translate - 0.5).abs() < 0.001);
+    assert!((settings.snap_rotate - 15.0).abs() < 0.001);
+    assert!((settings.snap_scale - 0.1).abs() < 0.001);
+}
+
+#[test]
+fn test_apply_gizmo_settings() {
+    setup();
+
+    let settings = polyscope_ui::GizmoSettings {
+        local_space: false, // World
+        visible: true,
+        snap_translate: 1.0,
+        snap_rotate: 45.0,
+        snap_scale: 0.25,
+    };
+
+    apply_gizmo_settings(&settings);
+
+    assert_eq!(get_gizmo_space(), GizmoSpace::World);
+    assert!(is_gizmo_visible());
+}
+
+#[test]
+fn test_get_selection_info_with_selection() {
+    setup();
+    let name = unique_name("gizmo_select_pc");
+
+    register_point_cloud(&name, vec![Vec3::ZERO]);
+    select_structure("PointCloud", &name);
+
+    let info = get_selection_info();
+    assert!(info.has_selection);
+    assert_eq!(info.type_name, "PointCloud");
+    assert_eq!(info.name, name);
+
+    deselect_structure();
+}
+
+#[test]
+fn test_apply_selection_transform() {
+    setup();
+    let name = unique_name("gizmo_transform_pc");
+
+    register_point_cloud(&name, vec![Vec3::ZERO]);
+    select_structure("PointCloud", &name);
+
+    let selection = polyscope_ui::SelectionInfo {
+        has_selection: true,
+        type_name: "PointCloud".to_string(),
+        name: name.clone(),
+        translation: [1.0, 2.0, 3.0],
+        rotation_degrees: [0.0, 0.0, 0.0],
+        scale: [1.0, 1.0, 1.0],
+        centroid: [1.0, 2.0, 3.0],
+    };
+
+    apply_selection_transform(&selection);
+
+    let transform = get_point_cloud_transform(&name).unwrap();
+    let translation = transform.w_axis.truncate();
+    assert!((translation - Vec3::new(1.0, 2.0, 3.0)).length() < 0.001);
+
+    deselect_structure();
+}
+
+#[test]
+fn test_apply_selection_transform_uniform_scale() {
+    setup();
+    let name = unique_name("gizmo_uniform_scale_pc");
+
+    register_point_cloud(&name, vec![Vec3::ZERO]);
+    select_structure("PointCloud", &name);
+    set_gizmo_mode(GizmoMode::UniformScale);
+
+    // Dragging the x handle scales all three axes
+    let selection = polyscope_ui::SelectionInfo {
+        has_selection: true,
+        type_name: "PointCloud".to_string(),
+        name: name.clone(),
+        translation: [0.0, 0.0, 0.0],
+        rotation_degrees: [0.0, 0.0, 0.0],
+        scale: [2.0, 1.0, 1.0],
+        centroid: [0.0, 0.0, 0.0],
+    };
+    apply_selection_transform(&selection);
+
+    set_gizmo_mode(GizmoMode::Translate);
+    let scale = Transform::from_matrix(get_point_cloud_transform(&name).unwrap()).scale;
+    assert!((scale - Vec3::splat(2.0)).length() < 0.001);
+
+    deselect_structure();
+}
+
+#[test]
+fn test_remove_all_groups() {
+    setup();
+    let g1 = unique_name("rag_group1");
+    let g2 = unique_name("rag_group2");
+    create_group(&g1);
+    create_group(&g2);
+
+    assert!(get_group(&g1).is_some());
+    assert!(get_group(&g2).is_some());
+
+    remove_all_groups();
+
+    assert!(get_group(&g1).is_none());
+    assert!(get_group(&g2).is_none());
+}
+
+#[test]
+fn test_remove_everything() {
+    setup();
+    let pc_name = unique_name("re_pc");
+    let group_name = unique_name("re_group");
+    let sp_name = unique_name("re_slice");
+
+    register_point_cloud(&pc_name, vec![Vec3::ZERO]);
+    create_group(&group_name);
+    add_slice_plane(&sp_name);
+
+    remove_everything();
+
+    assert!(get_point_cloud(&pc_name).is_none());
+    assert!(get_group(&group_name).is_none());
+    assert!(get_all_slice_planes().is_empty());
+}
+
+#[test]
+fn test_point_cloud_radius_quantity() {
+    use polyscope_structures::point_cloud::PointCloudRadiusQuantity;
+
+    setup();
+    let name = unique_name("pc_radius");
+    let pc = register_point_cloud(&name, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+    with_point_cloud(&name, |pc| pc.set_point_radius(0.1));
+
+    assert!(pc.add_scalar_radius_quantity("r", vec![1.0, 2.0]).is_err());
+    pc.add_scalar_radius_quantity("r", vec![-1.0, 2.0, 4.0])
+        .unwrap()
+        .set_radius_quantity_relative("r", false);
+
+    with_point_cloud_ref(&name, |pc| {
+        assert_eq!(pc.quantities().len(), 1);
+        let q = pc.get_quantity("r").unwrap();
+        let q = q
+            .as_any()
+            .downcast_ref::<PointCloudRadiusQuantity>()
+            .unwrap();
+        assert_eq!(q.values(), &[0.0, 2.0, 4.0]);
+        assert_eq!(q.compute_radii(pc.point_radius()), vec![0.0, 2.0, 4.0]);
+    });
+
+    pc.set_radius_quantity_relative("r", true);
+    with_point_cloud_ref(&name, |pc| {
+        let q = pc.get_quantity("r").unwrap();
+        let q = q
+            .as_any()
+            .downcast_ref::<PointCloudRadiusQuantity>()
+            .unwrap();
+        let radii = q.compute_radii(pc.point_radius());
+        assert!((radii[1] - 0.05).abs() < 1e-6);
+        assert!((radii[2] - 0.1).abs() < 1e-6);
+    });
+
+    remove_structure(&name);
+    assert!(pc.add_scalar_radius_quantity("r2", vec![0.0; 3]).is_err());
+}
+
+#[test]
+fn test_volume_grid_register_isosurface() {
+    setup();
+    let grid_name = unique_name("grid_iso");
+    let dim = glam::UVec3::splat(12);
+    let grid = register_volume_grid(&grid_name, dim, Vec3::splat(-1.0), Vec3::splat(1.0));
+    let mut values = Vec::new();
+    for k in 0..dim.z {
+        for j in 0..dim.y {
+            for i in 0..dim.x {
+                let p = Vec3::new(i as f32, j as f32, k as f32) / 11.0 * 2.0 - 1.0;
+                values.push(p.length() - 0.5);
+            }
+        }
+    }
+    grid.add_node_scalar_quantity("sdf", values);
+
+    let mesh_name = unique_name("grid_iso_mesh");
+    let mesh = grid.register_isosurface(&mesh_name, "sdf", 0.0).unwrap();
+    assert_eq!(mesh.name(), mesh_name);
+    let n_faces = with_surface_mesh_ref(&mesh_name, SurfaceMesh::num_faces).unwrap();
+    assert!(n_faces > 0);
+
+    // Out-of-range iso registers an empty mesh
+    let empty_name = unique_name("grid_iso_empty");
+    grid.register_isosurface(&empty_name, "sdf", 100.0).unwrap();
+    assert_eq!(
+        with_surface_mesh_ref(&empty_name, SurfaceMesh::num_faces),
+        Some(0)
+    );
+
+    assert!(grid.register_isosurface("unused", "missing", 0.0).is_none());
+    assert!(get_surface_mesh("unused").is_none());
+}
+
+#[test]
+fn test_transparency_options() {
+    setup();
+    set_transparency_mode(TransparencyMode::Pretty);
+    assert_eq!(get_transparency_mode(), TransparencyMode::Pretty);
+    assert_eq!(u32::from(TransparencyMode::Pretty), 2);
+    assert_eq!(TransparencyMode::from(0), TransparencyMode::Simple);
+
+    assert_eq!(get_transparency_render_passes(), 4);
+    set_transparency_render_passes(0);
+    assert_eq!(get_transparency_render_passes(), 1);
+    set_transparency_render_passes(4);
+
+    set_transparency_mode(TransparencyMode::Simple);
+}
+
+#[test]
+fn test_ground_plane_options() {
+    setup();
+    for mode in [
+        GroundPlaneMode::None,
+        GroundPlaneMode::ShadowOnly,
+        GroundPlaneMode::TileReflection,
+        GroundPlaneMode::Tile,
+    ] {
+        set_ground_plane_mode(mode);
+        assert_eq!(get_ground_plane_mode(), mode);
+        assert_eq!(with_context(|ctx| ctx.options.ground_plane.mode()), mode);
+    }
+
+    // Tile only: no shadow or reflection pass
+    set_ground_plane_mode(GroundPlaneMode::TileReflection);
+    set_ground_plane_draw_shadow(false);
+    set_ground_plane_draw_reflection(false);
+    assert!(get_ground_plane_draw_tile());
+    assert!(!get_ground_plane_draw_shadow());
+    let config = with_context(|ctx| ctx.options.ground_plane.clone());
+    assert!(config.is_visible());
+    assert!(!config.shadow_pass_enabled());
+    assert!(!config.reflection_pass_enabled());
+    assert_eq!(config.shader_shadow_mode(), 0);
+    set_ground_plane_mode(GroundPlaneMode::Tile);
+
+    set_ground_plane_height_factor(0.5);
+    assert!((get_ground_plane_height_factor() - 0.5).abs() < 1e-6);
+    let config = with_context(|ctx| ctx.options.ground_plane.clone());
+    assert!(config.height_is_relative);
+    // Half a length scale above the bottom of the bounding box
+    assert!((config.world_height(-1.0, 2.0) - (-1.0 + 2.0 * 0.499)).abs() < 1e-6);
+
+    set_ground_plane_height_factor(0.0);
+}
+
+#[test]
+fn test_ssao_options() {
+    setup();
+    set_ssao_enabled(true);
+    set_ssao_radius(0.25);
+    set_ssao_intensity(-1.0);
+    set_ssao_bias(0.05);
+    let config = get_ssao_config();
+    assert!(config.enabled);
+    assert!((config.radius - 0.25).abs() < 1e-6);
+    assert!(config.intensity.abs() < 1e-6);
+    assert!((config.bias - 0.05).abs() < 1e-6);
+
+    let defaults = SsaoConfig::default();
+    set_ssao_radius(defaults.radius);
+    set_ssao_intensity(defaults.intensity);
+    set_ssao_bias(defaults.bias);
+    set_ssao_enabled(false);
+    assert!(!get_ssao_config().enabled);
+}
+
+#[test]
+fn test_tone_mapping_options() {
+    setup();
+    assert_eq!(
+        ToneMappingConfig::default().operator,
+        ToneMapOperator::Reinhard
+    );
+    for operator in [
+        ToneMapOperator::None,
+        ToneMapOperator::Aces,
+        ToneMapOperator::Filmic,
+        ToneMapOperator::Reinhard,
+    ] {
+        set_tone_map_operator(operator);
+        assert_eq!(get_tone_map_operator(), operator);
+        assert_eq!(ToneMapOperator::from(u32::from(operator)), operator);
+    }
+
+    set_exposure(2.0);
+    assert!((get_exposure() - 2.0).abs() < 1e-6);
+    set_exposure(0.0);
+    assert!(get_exposure() > 0.0);
+    set_exposure(ToneMappingConfig::default().exposure);
+}
+
+#[test]
+fn test_backface_cull_option() {
+    setup();
+    assert_eq!(Options::default().backface_cull, None);
+    set_backface_cull(Some(true));
+    assert_eq!(get_backface_cull(), Some(true));
+    set_backface_cull(None);
+    assert_eq!(get_backface_cull(), None);
+}
+
+#[test]
+fn test_curve_network_scalar_quantities() {
+    setup();
+    let name = unique_name("cn_scalars");
+    let handle = register_curve_network_line(&name, vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+
+    handle
+        .add_node_scalar_quantity("height", vec![0.0, 0.5, 1.0])
+        .and_then(|h| h.add_edge_scalar_quantity("flow", vec![2.0, 3.0]))
+        .unwrap();
+    with_curve_network_ref(&name, |cn| {
+        assert!(cn.get_quantity("height").is_some());
+        assert!(cn.get_quantity("flow").is_some());
+    });
+
+    assert!(matches!(
+        handle.add_edge_scalar_quantity("bad", vec![1.0]),
+        Err(PolyscopeError::SizeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_look_at_structure() {
+    setup();
+    let name = unique_name("look_at_pc");
+    register_point_cloud(&name, vec![Vec3::ZERO, Vec3::ONE]);
+    set_point_cloud_transform(&name, Mat4::from_translation(Vec3::X));
+
+    look_at_structure("PointCloud", &name);
+    let (min, max) = view::take_look_at_request().unwrap();
+    assert_eq!(min, Vec3::X);
+    assert_eq!(max, Vec3::new(2.0, 1.0, 1.0));
+
+    // Degenerate boxes grow to the scene length scale
+    look_at_bounding_box(Vec3::Y, Vec3::Y);
+    let (min, max) = view::take_look_at_request().unwrap();
+    assert!((max - min).length() > 0.0);
+    assert_eq!((min + max) * 0.5, Vec3::Y);
+
+    look_at_structure("PointCloud", "does_not_exist");
+    assert!(view::take_look_at_request().is_none());
+}
+
+#[test]
+fn test_camera_view_save_load() {
+    setup();
+    let path = std::env::temp_dir().join(format!("{}.json", unique_name("camera_view")));
+    let camera = Camera {
+        position: Vec3::new(3.0, 2.0, 1.0),
+        projection_mode: ProjectionMode::Orthographic,
+        ..Camera::default()
+    };
+    std::fs::write(&path, camera.to_json()).unwrap();
+
+    load_camera_view(&path).unwrap();
+    // A pending view is what gets saved before the next frame
+    save_camera_view(&path).unwrap();
+    let restored = view::take_camera_view_request().unwrap();
+    assert_eq!(restored.position, camera.position);
+    assert_eq!(restored.projection_mode, ProjectionMode::Orthographic);
+    let saved = Camera::from_json(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(saved.position, camera.position);
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(load_camera_view(&path).is_err());
+}
+
+#[test]
+fn test_user_callback() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    setup();
+    let name = unique_name("callback_pc");
+    register_point_cloud(&name, vec![Vec3::ZERO]);
+    let calls = Arc::new(AtomicU32::new(0));
+    let counter = Arc::clone(&calls);
+    let cb_name = name.clone();
+    set_user_callback(move || {
+        // Mutating structures inside the callback must not deadlock
+        with_point_cloud(&cb_name, |pc| pc.set_point_radius(0.5));
+        assert_ne!(
+            counter.fetch_add(1, Ordering::SeqCst),
+            1,
+            "callback failure"
+        );
+    });
+
+    invoke_user_callback();
+    invoke_user_callback(); // panics, caught
+    invoke_user_callback();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        with_point_cloud_ref(&name, PointCloud::point_radius),
+        Some(0.5)
+    );
+
+    clear_user_callback();
+    invoke_user_callback();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_pick_callback() {
+    use std::sync::{Arc, Mutex};
+
+    setup();
+    let picks = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&picks);
+    set_pick_callback(move |pick| {
+        recorded
+            .lock()
+            .unwrap()
+            .push(pick.map(|p| (p.structure_name, p.element_index)));
+    });
+
+    invoke_pick_callback(Some(PickResult::new(
+        "PointCloud",
+        "pick_pc",
+        3,
+        Vec3::ZERO,
+        1.0,
+    )));
+    invoke_pick_callback(None);
+    clear_pick_callback();
+    invoke_pick_callback(None);
+
+    assert_eq!(
+        *picks.lock().unwrap(),
+        vec![Some(("pick_pc".to_string(), 3)), None]
+    );
+}
+
+#[test]
+fn test_register_surface_mesh_polygonal() {
+    setup();
+    let name = unique_name("poly_cube");
+    let vertices = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(1.0, 0.0, 1.0),
+        Vec3::new(1.0, 1.0, 1.0),
+        Vec3::new(0.0, 1.0, 1.0),
+    ];
+    let faces = vec![
+        vec![0, 3, 2, 1],
+        vec![4, 5, 6, 7],
+        vec![0, 1, 5, 4],
+        vec![2, 3, 7, 6],
+        vec![0, 4, 7, 3],
+        vec![1, 2, 6, 5],
+    ];
+
+    let handle = register_surface_mesh_polygonal(&name, vertices.clone(), faces.clone()).unwrap();
+    assert_eq!(handle.name(), name);
+    assert_eq!(
+        with_surface_mesh_ref(&name, SurfaceMesh::num_faces),
+        Some(6)
+    );
+    assert_eq!(
+        with_surface_mesh_ref(&name, SurfaceMesh::num_triangles),
+        Some(12)
+    );
+
+    // Duplicate names and invalid faces are errors, not panics
+    assert!(matches!(
+        register_surface_mesh_polygonal(&name, vertices.clone(), faces),
+        Err(PolyscopeError::StructureExists(_))
+    ));
+    let other = unique_name("poly_bad");
+    assert!(matches!(
+        register_surface_mesh_polygonal(&other, vertices.clone(), vec![vec![0, 1]]),
+        Err(PolyscopeError::InvalidGeometry(_))
+    ));
+    assert!(matches!(
+        register_surface_mesh_polygonal(&other, vertices, vec![vec![0, 1, 8]]),
+        Err(PolyscopeError::InvalidGeometry(_))
+    ));
+    assert!(get_surface_mesh(&other).is_none());
+}
+
+#[test]
+fn test_auto_screenshot_path() {
+    setup();
+    let dir = std::env::temp_dir().join(unique_name("polyscope_shots"));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("shot_0000.jpg"), b"").unwrap();
+    std::fs::write(dir.join("shot_0007.jpg"), b"").unwrap();
+    std::fs::write(dir.join("shot_0042.png"), b"").unwrap();
+
+    set_screenshot_directory(&dir);
+    set_screenshot_prefix("shot");
+    set_screenshot_extension(".jpg");
+    assert_eq!(get_screenshot_extension(), "jpg");
+
+    let mut counter = 0;
+    let path = screenshot::next_auto_screenshot_path(&mut counter);
+    assert_eq!(path, dir.join("shot_0008.jpg").to_string_lossy());
+    assert_eq!(counter, 9);
+
+    // Restore defaults for other tests
+    set_screenshot_directory("");
+    set_screenshot_prefix("screenshot");
+    set_screenshot_extension("png");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_register_color_map() {
+    setup();
+    register_color_map("test_black_white", &[(0.0, [0.0; 3]), (1.0, [1.0; 3])]).unwrap();
+    assert!(matches!(
+        register_color_map("test_bad", &[(0.0, [0.0; 3])]),
+        Err(PolyscopeError::InvalidColorMap(_))
+    ));
+
+    let registry = color_maps::color_map_registry();
+    let map = registry.get("test_black_white").unwrap();
+    assert!((map.sample(0.5) - Vec3::splat(0.5)).length() < 1e-5);
+    assert!(registry.get("test_bad").is_none());
+
+    // Scalar quantities accept the custom name
+    let name = unique_name("custom_cmap_pc");
+    register_point_cloud(&name, vec![Vec3::ZERO, Vec3::X]).add_scalar_quantity_with_colormap(
+        "s",
+        vec![0.0, 1.0],
+        "test_black_white",
+    );
+    let colormap = with_point_cloud_ref(&name, |pc| {
+        pc.get_quantity("s")
+            .and_then(|q| {
+                q.as_any()
+                    .downcast_ref::<polyscope_structures::point_cloud::PointCloudScalarQuantity>()
+            })
+            .map(|q| q.colormap_name().to_string())
+    })
+    .flatten();
+    assert_eq!(colormap.as_deref(), Some("test_black_white"));
+}
+
+#[test]
+fn test_surface_mesh_quantity_size_mismatch() {
+    setup();
+    let name = unique_name("sm_size_mismatch");
+    let mesh = register_surface_mesh(
+        &name,
+        vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+        vec![[0u32, 1, 2]],
+    );
+
+    assert!(matches!(
+        mesh.add_vertex_scalar_quantity("short", vec![0.0, 1.0]),
+        Err(PolyscopeError::SizeMismatch {
+            expected: 3,
+            actual: 2
+        })
+    ));
+    assert!(matches!(
+        mesh.add_face_color_quantity("long", vec![Vec3::X, Vec3::Y]),
+        Err(PolyscopeError::SizeMismatch {
+            expected: 1,
+            actual: 2
+        })
+    ));
+    with_surface_mesh_ref(&name, |m| assert!(m.quantities().is_empty()));
+}
+
+#[test]
+fn test_camera_mode_setters() {
+    setup();
+    set_projection_mode(ProjectionMode::Orthographic);
+    set_navigation_style(NavigationStyle::FirstPerson);
+    set_up_direction(AxisDirection::PosZ);
+    assert_eq!(get_projection_mode(), ProjectionMode::Orthographic);
+    assert_eq!(get_navigation_style(), NavigationStyle::FirstPerson);
+    assert_eq!(get_up_direction(), AxisDirection::PosZ);
+
+    // A fresh renderer picks up every mode set so far, once
+    let mut camera = Camera::default();
+    let mut generation = 0;
+    view::apply_camera_modes(&mut camera, &mut generation);
+    assert_eq!(camera.projection_mode, ProjectionMode::Orthographic);
+    assert_eq!(camera.navigation_style, NavigationStyle::FirstPerson);
+    assert_eq!(camera.up_direction, AxisDirection::PosZ);
+    assert_eq!(camera.up, Vec3::Z);
+
+    // Later UI changes are not overridden until a setter is called again
+    camera.projection_mode = ProjectionMode::Perspective;
+    view::apply_camera_modes(&mut camera, &mut generation);
+    assert_eq!(camera.projection_mode, ProjectionMode::Perspective);
+    set_projection_mode(ProjectionMode::Orthographic);
+    view::apply_camera_modes(&mut camera, &mut generation);
+    assert_eq!(camera.projection_mode, ProjectionMode::Orthographic);
+}
+
+#[test]
+fn test_set_structure_enabled() {
+    setup();
+    let shown = unique_name("enabled_pc_shown");
+    let hidden = unique_name("enabled_pc_hidden");
+    let shown_pc = register_point_cloud(&shown, vec![Vec3::ZERO, Vec3::X]);
+    register_point_cloud(&hidden, vec![Vec3::Y, Vec3::Z]);
+
+    set_structure_enabled("PointCloud", &hidden, false);
+    assert!(!is_structure_enabled("PointCloud", &hidden));
+    assert!(is_structure_enabled("PointCloud", &shown));
+    assert!(shown_pc.is_enabled());
+    with_context(|ctx| {
+        let s = ctx.registry.get("PointCloud", &hidden).unwrap();
+        assert!(!ctx.is_structure_visible(s));
+    });
+
+    shown_pc.set_enabled(false);
+    assert!(!is_structure_enabled("PointCloud", &shown));
+    shown_pc.set_enabled(true);
+    assert!(shown_pc.is_enabled());
+
+    // Unknown structures are reported as disabled
+    set_structure_enabled("PointCloud", "no_such_cloud", true);
+    assert!(!is_structure_enabled("PointCloud", "no_such_cloud"));
+}
+
+#[test]
+fn test_register_obj_with_uvs() {
+    use polyscope_structures::surface_mesh::MeshVertexParameterizationQuantity;
+
+    setup();
+    let name = unique_name("obj_quad");
+    let path = std::env::temp_dir().join(format!("{name}.obj"));
+    std::fs::write(
+        &path,
+        "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\n\
+         vt 0 0\nvt 1 0\nvt 1 1\nvt 0 1\n\
+         f 1/1 2/2 3/3 4/4\n",
+    )
+    .unwrap();
+    let handle = register_obj(&name, &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(handle.name(), name);
+
+    with_surface_mesh_ref(&name, |mesh| {
+        assert_eq!(mesh.num_faces(), 1);
+        let q = mesh.get_quantity(LOADED_UV_QUANTITY_NAME).unwrap();
+        assert_eq!(q.kind(), QuantityKind::Parameterization);
+        let q = q
+            .as_any()
+            .downcast_ref::<MeshVertexParameterizationQuantity>()
+            .unwrap();
+        assert_eq!(q.coords()[2], Vec2::ONE);
+    });
+    assert!(register_obj(unique_name("obj_missing"), "/no/such/file.obj").is_err());
+}
+
+#[test]
+fn test_parameterization_style_setters() {
+    use polyscope_structures::surface_mesh::MeshCornerParameterizationQuantity;
+
+    setup();
+    let name = unique_name("param_style");
+    let mesh = register_surface_mesh(
+        &name,
+        vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+        vec![[0u32, 1, 2]],
+    );
+    mesh.add_vertex_parameterization_quantity("vuv", vec![Vec2::ZERO, Vec2::X, Vec2::Y])
+        .unwrap()
+        .add_corner_parameterization_quantity("cuv", vec![Vec2::ZERO, Vec2::X, Vec2::Y])
+        .unwrap();
+
+    for style in [
+        ParamVizStyle::Checker,
+        ParamVizStyle::Grid,
+        ParamVizStyle::LocalCheck,
+        ParamVizStyle::LocalRad,
+    ] {
+        mesh.set_parameterization_style("vuv", style)
+            .set_parameterization_style("cuv", style);
+        assert_eq!(mesh.parameterization_style("vuv"), Some(style));
+        assert_eq!(mesh.parameterization_style("cuv"), Some(style));
+    }
+    assert_eq!(mesh.parameterization_style("missing"), None);
+
+    let colors = [Vec3::ONE, Vec3::ZERO];
+    mesh.set_parameterization_checker_size("cuv", 0.25)
+        .set_parameterization_checker_colors("cuv", colors);
+    with_surface_mesh_ref(&name, |m| {
+        let q = m
+            .get_quantity("cuv")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<MeshCornerParameterizationQuantity>()
+            .unwrap();
+        assert_eq!(q.checker_size(), 0.25);
+        assert_eq!(q.checker_colors(), colors);
+    });
+}
+
+#[test]
+fn test_get_all_structures() {
+    setup();
+    let pc = unique_name("list_pc");
+    let mesh = unique_name("list_mesh");
+    register_point_cloud(&pc, vec![Vec3::ZERO, Vec3::X]);
+    register_surface_mesh(
+        &mesh,
+        vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+        vec![glam::UVec3::new(0, 1, 2)],
+    );
+
+    let structures = get_all_structures();
+    assert!(structures.contains(&("PointCloud".to_string(), pc.clone())));
+    assert!(structures.contains(&("SurfaceMesh".to_string(), mesh.clone())));
+    assert!(structures.windows(2).all(|w| w[0] <= w[1]));
+    assert!(num_structures() >= 2);
+
+    remove_structure(&pc);
+    remove_structure(&mesh);
+    let structures = get_all_structures();
+    assert!(
+        !structures
+            .iter()
+            .any(|(_, name)| *name == pc || *name == mesh)
+    );
+}
+
+#[test]
+fn test_register_point_cloud_2d() {
+    setup();
+    let name = unique_name("pc_2d");
+    register_point_cloud_2d(&name, vec![Vec2::new(1.0, 2.0), Vec2::new(-3.0, 4.0)]);
+
+    let points = with_point_cloud_ref(&name, |pc| pc.points().to_vec()).unwrap();
+    assert_eq!(
+        points,
+        vec![Vec3::new(1.0, 2.0, 0.0), Vec3::new(-3.0, 4.0, 0.0)]
+    );
+    assert!(with_context(|ctx| ctx.planar_data_hint));
+
+    // Empty input registers an empty cloud that contributes no extents
+    let empty = unique_name("pc_2d_empty");
+    register_point_cloud_2d(&empty, Vec::new());
+    assert_eq!(
+        with_point_cloud_ref(&empty, Structure::bounding_box),
+        Some(None)
+    );
+}
+
+#[test]
+fn test_degenerate_bounding_box() {
+    setup();
+    // Clear all structures so only our degenerate point cloud contributes
+    remove_all_structures();
+    let name = unique_name("degen_bbox");
+    // Register a point cloud where all points are at the same location
+    register_point_cloud(&name, vec![Vec3::ONE, Vec3::ONE, Vec3::ONE]);
+
+    let (bb_min, bb_max) = with_context(|ctx| ctx.bounding_box);
+    // Bounding box should be perturbed so min != max
+    assert!(
+        bb_max.x > bb_min.x,
+        "degenerate bbox not perturbed: min={bb_min}, max={bb_max}"
+    );
+    assert!(bb_max.y > bb_min.y);
+    assert!(bb_max.z > bb_min.z);
+}
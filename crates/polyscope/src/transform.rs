@@ -1,25 +1,54 @@
-use crate::{Mat4, with_context, with_context_mut};
+use crate::{Mat4, Transform, Vec3, with_context, with_context_mut};
 
-/// Generates `set_<name>_transform` and `get_<name>_transform` functions for a structure type.
+/// Sets the transform of any registered structure by type and name.
+///
+/// Type names are the ones returned by [`get_all_structures`](crate::get_all_structures),
+/// e.g. `"VolumeGrid"` or `"CameraView"`. Does nothing if no such structure
+/// exists.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_volume_grid_uniform("grid", 4, Vec3::ZERO, Vec3::ONE);
+/// set_transform("VolumeGrid", "grid", Mat4::from_translation(Vec3::X));
+/// assert_eq!(get_transform("VolumeGrid", "grid"), Some(Mat4::from_translation(Vec3::X)));
+/// ```
+pub fn set_transform(type_name: &str, name: &str, transform: Mat4) {
+    with_context_mut(|ctx| {
+        if let Some(s) = ctx.registry.get_mut(type_name, name) {
+            s.set_transform(transform);
+        }
+    });
+    crate::redraw::notify_scene_changed();
+}
+
+/// Gets the transform of any registered structure by type and name, or
+/// `None` if no such structure exists.
+#[must_use]
+pub fn get_transform(type_name: &str, name: &str) -> Option<Mat4> {
+    with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .map(polyscope_core::Structure::transform)
+    })
+}
+
+/// Generates `set_<name>_transform` and `get_<name>_transform` shims over
+/// [`set_transform`] and [`get_transform`] for a structure type.
 macro_rules! impl_transform_accessors {
     ($set_fn:ident, $get_fn:ident, $type_name:expr, $doc_name:expr) => {
         #[doc = concat!("Sets the transform of a ", $doc_name, " by name.")]
         pub fn $set_fn(name: &str, transform: Mat4) {
-            with_context_mut(|ctx| {
-                if let Some(s) = ctx.registry.get_mut($type_name, name) {
-                    s.set_transform(transform);
-                }
-            });
+            set_transform($type_name, name, transform);
         }
 
         #[doc = concat!("Gets the transform of a ", $doc_name, " by name.")]
         #[must_use]
         pub fn $get_fn(name: &str) -> Option<Mat4> {
-            with_context(|ctx| {
-                ctx.registry
-                    .get($type_name, name)
-                    .map(polyscope_core::Structure::transform)
-            })
+            get_transform($type_name, name)
         }
     };
 }
@@ -48,3 +77,73 @@ impl_transform_accessors!(
     "VolumeMesh",
     "volume mesh"
 );
+
+/// Builds a transform matrix from a translation, rotation and scale.
+///
+/// The rotation is given as XYZ Euler angles in degrees, as shown in the
+/// gizmo panel. The matrix scales first, then rotates, then translates. Use
+/// it with [`set_selected_transform`](crate::set_selected_transform) or the
+/// `set_*_transform` functions.
+///
+/// # Example
+///
+/// ```
+/// use polyscope_rs::*;
+///
+/// let m = make_transform(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 90.0, 0.0), Vec3::ONE);
+/// let (translation, _, scale) = decompose_transform(m);
+/// assert!((translation - Vec3::X).length() < 1e-5);
+/// assert!((scale - Vec3::ONE).length() < 1e-5);
+/// ```
+#[must_use]
+pub fn make_transform(translation: Vec3, euler_degrees: Vec3, scale: Vec3) -> Mat4 {
+    let mut transform = Transform {
+        translation,
+        scale,
+        ..Transform::identity()
+    };
+    transform.set_euler_angles_degrees(euler_degrees);
+    transform.to_matrix()
+}
+
+/// Splits a transform matrix into `(translation, euler_degrees, scale)`.
+///
+/// Inverse of [`make_transform`] for matrices without shear. The Euler
+/// angles are not unique, so they may differ from the ones the matrix was
+/// built from while describing the same rotation.
+#[must_use]
+pub fn decompose_transform(matrix: Mat4) -> (Vec3, Vec3, Vec3) {
+    let transform = Transform::from_matrix(matrix);
+    (
+        transform.translation,
+        transform.euler_angles_degrees(),
+        transform.scale,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_round_trip() {
+        let translation = Vec3::new(1.5, -2.0, 0.25);
+        let euler = Vec3::new(35.0, -70.0, 120.0);
+        let scale = Vec3::new(0.5, 2.0, 3.0);
+        let matrix = make_transform(translation, euler, scale);
+
+        let (t, e, s) = decompose_transform(matrix);
+        assert!((t - translation).length() < 1e-5);
+        assert!((e - euler).length() < 1e-3, "euler angles {e} != {euler}");
+        assert!((s - scale).length() < 1e-4);
+        assert!(make_transform(t, e, s).abs_diff_eq(matrix, 1e-4));
+    }
+
+    #[test]
+    fn test_make_transform_order() {
+        // Scale, then rotate 90 degrees about Z, then translate
+        let matrix = make_transform(Vec3::Z, Vec3::new(0.0, 0.0, 90.0), Vec3::splat(2.0));
+        let p = matrix.transform_point3(Vec3::X);
+        assert!((p - Vec3::new(0.0, 2.0, 1.0)).length() < 1e-5);
+    }
+}
@@ -0,0 +1,115 @@
+//! Turntable animation rendering.
+//!
+//! Renders a series of headless screenshots while orbiting the camera around
+//! the scene, for turning a scene into an animation.
+
+use std::f32::consts::TAU;
+use std::path::{Path, PathBuf};
+
+use crate::headless::{current_frame_size, render_pixels};
+use crate::screenshot::numbered_image_path;
+use crate::view::{apply_camera_modes, last_camera, replace_camera_view_request};
+use crate::{
+    Camera, Context, PolyscopeError, Result, ScreenshotOptions, Vec3, get_screenshot_extension,
+    get_up_direction, with_context,
+};
+
+/// Renders `frames` images while orbiting the camera 360° around the scene.
+///
+/// The orbit starts at the current camera (the last shown or loaded view,
+/// otherwise the view fitted to the scene), rotates about the scene center
+/// around the up direction (see [`set_up_direction`](crate::set_up_direction)),
+/// and writes `frame_0000.png`, `frame_0001.png`, ... into `output_dir`
+/// using the screenshot extension. Each frame is rendered headlessly at the
+/// size used by [`capture_screenshot_rgba`](crate::capture_screenshot_rgba)
+/// unless `options.size` is set.
+///
+/// The camera is left as it was before the call. Returns the written paths.
+///
+/// # Errors
+///
+/// Returns an error if a frame fails to render or save.
+///
+/// # Example
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// render_turntable(36, "turntable", ScreenshotOptions::default()).unwrap();
+/// ```
+pub fn render_turntable(
+    frames: u32,
+    output_dir: impl AsRef<Path>,
+    options: ScreenshotOptions,
+) -> Result<Vec<PathBuf>> {
+    let (width, height) = options.size.unwrap_or_else(current_frame_size);
+    let original = replace_camera_view_request(None);
+    let base = original
+        .clone()
+        .or_else(last_camera)
+        .unwrap_or_else(|| fitted_camera(width, height));
+    let center = with_context(Context::center);
+    let axis = get_up_direction().to_vec3();
+    let extension = get_screenshot_extension();
+
+    let result = (0..frames)
+        .map(|i| {
+            let camera = orbit_camera(&base, center, axis, i, frames);
+            replace_camera_view_request(Some(camera));
+            let data = render_pixels(width, height, &options)?;
+            let path = numbered_image_path(output_dir.as_ref(), "frame", i, &extension);
+            polyscope_render::save_image(&path.to_string_lossy(), &data, width, height)
+                .map_err(|e| PolyscopeError::RenderError(format!("Failed to save image: {e}")))?;
+            Ok(path)
+        })
+        .collect();
+
+    replace_camera_view_request(original);
+    result
+}
+
+/// Returns the camera a fresh headless render would start from.
+fn fitted_camera(width: u32, height: u32) -> Camera {
+    #[allow(clippy::cast_precision_loss)]
+    let mut camera = Camera::new(width as f32 / height as f32);
+    let (min, max) = with_context(|ctx| ctx.bounding_box);
+    if min.x.is_finite() && max.x.is_finite() && (max - min).length() > 0.0 {
+        camera.look_at_box(min, max);
+    }
+    apply_camera_modes(&mut camera, &mut 0);
+    camera
+}
+
+/// Returns `base` rotated about `axis` through `center` by `index / frames`
+/// of a full turn.
+fn orbit_camera(base: &Camera, center: Vec3, axis: Vec3, index: u32, frames: u32) -> Camera {
+    #[allow(clippy::cast_precision_loss)]
+    let angle = TAU * index as f32 / frames as f32;
+    let rotation = glam::Quat::from_axis_angle(axis, angle);
+    let mut camera = base.clone();
+    camera.position = center + rotation * (base.position - center);
+    camera.target = center + rotation * (base.target - center);
+    camera.up = rotation * base.up;
+    camera
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orbit_camera_keeps_distance_and_turns_fully() {
+        let mut base = Camera::new(1.0);
+        base.position = Vec3::new(0.0, 1.0, 5.0);
+        base.target = Vec3::ZERO;
+        let center = Vec3::new(0.0, 0.0, 1.0);
+
+        let quarter = orbit_camera(&base, center, Vec3::Y, 1, 4);
+        assert!((quarter.position - Vec3::new(4.0, 1.0, 1.0)).length() < 1e-5);
+        assert!((quarter.target - Vec3::new(-1.0, 0.0, 1.0)).length() < 1e-5);
+
+        let full = orbit_camera(&base, center, Vec3::Y, 4, 4);
+        assert!((full.position - base.position).length() < 1e-5);
+    }
+}
@@ -74,6 +74,7 @@ pub fn get_slice_plane_settings() -> Vec<polyscope_ui::SlicePlaneSettings> {
                 enabled: plane.is_enabled(),
                 origin: plane.origin().to_array(),
                 normal: plane.normal().to_array(),
+                inverted: plane.is_inverted(),
                 draw_plane: plane.draw_plane(),
                 draw_widget: plane.draw_widget(),
                 color: plane.color().truncate().to_array(),
@@ -92,6 +93,7 @@ pub fn apply_slice_plane_settings(settings: &polyscope_ui::SlicePlaneSettings) {
             plane.set_enabled(settings.enabled);
             plane.set_origin(Vec3::from_array(settings.origin));
             plane.set_normal(Vec3::from_array(settings.normal));
+            plane.set_inverted(settings.inverted);
             plane.set_draw_plane(settings.draw_plane);
             plane.set_draw_widget(settings.draw_widget);
             plane.set_color(Vec3::from_array(settings.color));
@@ -119,6 +121,7 @@ pub fn handle_slice_plane_action(
                         enabled: plane.is_enabled(),
                         origin: plane.origin().to_array(),
                         normal: plane.normal().to_array(),
+                        inverted: plane.is_inverted(),
                         draw_plane: plane.draw_plane(),
                         draw_widget: plane.draw_widget(),
                         color: plane.color().truncate().to_array(),
@@ -185,6 +188,8 @@ pub fn select_slice_plane_for_gizmo(name: &str) {
     with_context_mut(|ctx| {
         ctx.select_slice_plane(name);
     });
+    // Selecting a slice plane deselects the structure
+    crate::gizmo::notify_selection_changed();
 }
 
 /// Deselects the current slice plane from gizmo.
@@ -339,6 +344,9 @@ pub fn get_selection_info() -> polyscope_ui::SelectionInfo {
 }
 
 /// Applies transform from selection info to the selected structure.
+///
+/// The scale is linked in [`GizmoMode::UniformScale`](crate::GizmoMode::UniformScale)
+/// mode and snapped to the gizmo's scale snap value.
 pub fn apply_selection_transform(selection: &polyscope_ui::SelectionInfo) {
     if !selection.has_selection {
         return;
@@ -353,12 +361,18 @@ pub fn apply_selection_transform(selection: &polyscope_ui::SelectionInfo) {
     );
     let scale = Vec3::from_array(selection.scale);
 
-    let transform = Mat4::from_scale_rotation_translation(scale, rotation, translation);
-
     with_context_mut(|ctx| {
         if let Some((type_name, name)) = ctx.selected_structure.clone() {
+            let gizmo = ctx.gizmo().clone();
             if let Some(structure) = ctx.registry.get_mut(&type_name, &name) {
-                structure.set_transform(transform);
+                // Link and snap the scale according to the gizmo settings
+                let previous = Transform::from_matrix(structure.transform()).scale;
+                let scale = gizmo.constrain_scale(previous, scale);
+                structure.set_transform(Mat4::from_scale_rotation_translation(
+                    scale,
+                    rotation,
+                    translation,
+                ));
             }
         }
     });
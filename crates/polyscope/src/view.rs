@@ -0,0 +1,497 @@
+//! Camera framing and view persistence API.
+//!
+//! Lets user code point the camera at a structure or an arbitrary box, save
+//! or restore camera views as JSON, and pick the navigation style, projection,
+//! up direction, field of view and clip planes, and set a home view to reset
+//! to. Requests are queued and applied by the viewer on its next frame (or by
+//! the next headless render).
+
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{AxisDirection, Camera, NavigationStyle, ProjectionMode, Result, Vec3};
+
+/// Pending camera framing request, as a world-space `(min, max)` box.
+static LOOK_AT_REQUEST: Mutex<Option<(Vec3, Vec3)>> = Mutex::new(None);
+
+/// Pending camera view to restore.
+static CAMERA_VIEW_REQUEST: Mutex<Option<Camera>> = Mutex::new(None);
+
+/// Camera of the most recently rendered frame.
+static CURRENT_CAMERA: Mutex<Option<Camera>> = Mutex::new(None);
+
+/// Last cursor position over the viewer window, in physical pixels.
+static CURSOR_POSITION: Mutex<Option<(f32, f32)>> = Mutex::new(None);
+
+/// Camera stored by `set_home_view`.
+static HOME_CAMERA: Mutex<Option<Camera>> = Mutex::new(None);
+
+/// Pending request to fit the camera to the whole scene.
+static FIT_SCENE_REQUEST: AtomicBool = AtomicBool::new(false);
+
+/// Camera modes chosen from user code.
+struct CameraModes {
+    navigation_style: Option<NavigationStyle>,
+    projection_mode: Option<ProjectionMode>,
+    up_direction: Option<AxisDirection>,
+    /// Vertical field of view in degrees.
+    fov_degrees: Option<f32>,
+    /// `(near, far)` clip plane distances.
+    clip_planes: Option<(f32, f32)>,
+    /// Bumped on every change so each renderer applies a change once.
+    generation: u64,
+}
+
+static CAMERA_MODES: Mutex<CameraModes> = Mutex::new(CameraModes {
+    navigation_style: None,
+    projection_mode: None,
+    up_direction: None,
+    fov_degrees: None,
+    clip_planes: None,
+    generation: 0,
+});
+
+/// Frames the camera on a registered structure.
+///
+/// Uses the structure's world-space bounding box (including its transform)
+/// and the same fitting as the initial view, along the current up and front
+/// directions. Logs a warning if the structure does not exist or has no
+/// spatial extent.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// register_point_cloud("pts", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+/// look_at_structure("PointCloud", "pts");
+/// show();
+/// ```
+pub fn look_at_structure(type_name: &str, name: &str) {
+    let bbox = crate::with_context(|ctx| {
+        ctx.registry
+            .get(type_name, name)
+            .map(polyscope_core::Structure::bounding_box)
+    });
+    match bbox {
+        Some(Some((min, max))) => look_at_bounding_box(min, max),
+        Some(None) => log::warn!("look_at_structure: {type_name} '{name}' has no extent"),
+        None => log::warn!("look_at_structure: no {type_name} named '{name}'"),
+    }
+}
+
+/// Frames the camera on a world-space axis-aligned bounding box.
+///
+/// A zero-size box (e.g. a single point) is expanded to the scene length
+/// scale so the camera does not collapse onto it.
+pub fn look_at_bounding_box(min: Vec3, max: Vec3) {
+    if !min.is_finite() || !max.is_finite() {
+        return;
+    }
+    let (min, max) = (min.min(max), min.max(max));
+    let (min, max) = if (max - min).length() > 0.0 {
+        (min, max)
+    } else {
+        let half = Vec3::splat(crate::with_context(|ctx| ctx.length_scale) * 0.5);
+        let center = (min + max) * 0.5;
+        (center - half, center + half)
+    };
+    if let Ok(mut guard) = LOOK_AT_REQUEST.lock() {
+        *guard = Some((min, max));
+    }
+}
+
+/// Takes and returns a pending framing request (for internal use by App).
+pub(crate) fn take_look_at_request() -> Option<(Vec3, Vec3)> {
+    LOOK_AT_REQUEST
+        .lock()
+        .ok()
+        .and_then(|mut guard| guard.take())
+}
+
+/// Saves the current camera view to a JSON file.
+///
+/// Stores position, target, up/front directions, field of view, clip planes,
+/// projection mode, navigation style, and orthographic scale (see
+/// [`Camera::to_json`]). If no frame has been rendered yet, a pending
+/// [`load_camera_view`] or else the default camera is saved.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// // ... register structures, adjust the view in show() ...
+/// save_camera_view("figure_view.json").unwrap();
+/// ```
+pub fn save_camera_view(path: impl AsRef<Path>) -> Result<()> {
+    std::fs::write(path, current_view().to_json())?;
+    Ok(())
+}
+
+/// Returns the pending camera view, else the last rendered camera, else the
+/// default camera.
+fn current_view() -> Camera {
+    let pending = CAMERA_VIEW_REQUEST
+        .lock()
+        .ok()
+        .and_then(|guard| guard.clone());
+    pending.or_else(last_camera).unwrap_or_default()
+}
+
+/// Loads a camera view saved by [`save_camera_view`].
+///
+/// The view is applied on the next frame, replacing the automatic initial
+/// fit. The viewport's aspect ratio is kept.
+pub fn load_camera_view(path: impl AsRef<Path>) -> Result<()> {
+    let camera = Camera::from_json(&std::fs::read_to_string(path)?)?;
+    if let Ok(mut guard) = CAMERA_VIEW_REQUEST.lock() {
+        *guard = Some(camera);
+    }
+    Ok(())
+}
+
+/// Stores the current camera as the home view.
+///
+/// [`reset_camera_to_home`], the reset view button and the reset view
+/// shortcut (`Home` by default) then return to it instead of fitting the
+/// camera to the scene. Captures the same camera as [`save_camera_view`].
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// load_camera_view("figure_view.json").unwrap();
+/// set_home_view();
+/// // ... later, after the user has moved the camera ...
+/// reset_camera_to_home();
+/// ```
+pub fn set_home_view() {
+    let camera = current_view();
+    if let Ok(mut guard) = HOME_CAMERA.lock() {
+        *guard = Some(camera);
+    }
+}
+
+/// Forgets the home view, so resetting fits the camera to the scene again.
+pub fn clear_home_view() {
+    if let Ok(mut guard) = HOME_CAMERA.lock() {
+        *guard = None;
+    }
+}
+
+/// Resets the camera to the home view on the next frame.
+///
+/// Restores the view stored by [`set_home_view`], keeping the viewport's
+/// aspect ratio, or otherwise frames the whole scene like the initial
+/// automatic fit. Replaces any pending [`load_camera_view`] or `look_at_*`
+/// request.
+pub fn reset_camera_to_home() {
+    let home = home_camera();
+    if let Ok(mut guard) = LOOK_AT_REQUEST.lock() {
+        *guard = None;
+    }
+    FIT_SCENE_REQUEST.store(home.is_none(), Ordering::Relaxed);
+    if let Ok(mut guard) = CAMERA_VIEW_REQUEST.lock() {
+        *guard = home;
+    }
+    crate::redraw::notify_scene_changed();
+}
+
+/// Returns the home view, if one is set (for internal use by App).
+pub(crate) fn home_camera() -> Option<Camera> {
+    HOME_CAMERA.lock().ok().and_then(|guard| guard.clone())
+}
+
+/// Takes a pending request to fit the camera to the scene (for internal use
+/// by App).
+pub(crate) fn take_fit_scene_request() -> bool {
+    FIT_SCENE_REQUEST.swap(false, Ordering::Relaxed)
+}
+
+/// Takes and returns a pending camera view (for internal use by App).
+pub(crate) fn take_camera_view_request() -> Option<Camera> {
+    CAMERA_VIEW_REQUEST
+        .lock()
+        .ok()
+        .and_then(|mut guard| guard.take())
+}
+
+/// Replaces the pending camera view and returns the previous one (for
+/// internal use by `render_turntable`).
+pub(crate) fn replace_camera_view_request(camera: Option<Camera>) -> Option<Camera> {
+    CAMERA_VIEW_REQUEST
+        .lock()
+        .ok()
+        .and_then(|mut guard| std::mem::replace(&mut *guard, camera))
+}
+
+/// Moves the current, pending and home views by `offset` when the scene
+/// origin is set (for internal use by `register_surface_mesh_f64`).
+///
+/// The viewer's camera is moved through a pending view request, so it keeps
+/// showing the same content.
+pub(crate) fn translate_view(offset: Vec3) {
+    let shift = |camera: &mut Camera| {
+        camera.position += offset;
+        camera.target += offset;
+        camera.flight = None;
+    };
+    if let Ok(mut guard) = HOME_CAMERA.lock() {
+        if let Some(camera) = guard.as_mut() {
+            shift(camera);
+        }
+    }
+    if let Ok(mut guard) = LOOK_AT_REQUEST.lock() {
+        if let Some((min, max)) = guard.as_mut() {
+            *min += offset;
+            *max += offset;
+        }
+    }
+    let current = last_camera().map(|mut camera| {
+        shift(&mut camera);
+        record_camera(&camera);
+        camera
+    });
+    if let Ok(mut guard) = CAMERA_VIEW_REQUEST.lock() {
+        match guard.as_mut() {
+            Some(pending) => shift(pending),
+            None => *guard = current,
+        }
+    }
+}
+
+/// Records the camera of the frame being rendered (for internal use by App).
+pub(crate) fn record_camera(camera: &Camera) {
+    if let Ok(mut guard) = CURRENT_CAMERA.lock() {
+        *guard = Some(camera.clone());
+    }
+}
+
+/// Returns the camera of the most recently rendered viewer frame, if any.
+pub(crate) fn last_camera() -> Option<Camera> {
+    CURRENT_CAMERA.lock().ok().and_then(|guard| guard.clone())
+}
+
+/// Records the cursor position over the window, or `None` when it leaves
+/// (for internal use by App).
+pub(crate) fn record_cursor_position(position: Option<(f32, f32)>) {
+    if let Ok(mut guard) = CURSOR_POSITION.lock() {
+        *guard = position;
+    }
+}
+
+/// Returns the world-space ray `(origin, direction)` under the mouse cursor.
+///
+/// Uses the camera of the last rendered frame, so it is meant for user
+/// callbacks, e.g. to build measurement tools on top of custom picking.
+/// Returns `None` before the first frame or while the cursor is outside the
+/// window. For an arbitrary pixel use [`Camera::screen_ray`], whose pixel
+/// coordinates start at the top-left corner of the viewport.
+#[must_use]
+pub fn cursor_ray() -> Option<(Vec3, Vec3)> {
+    let (x, y) = CURSOR_POSITION.lock().ok().and_then(|guard| *guard)?;
+    let camera = last_camera()?;
+    Some(camera.screen_ray(x, y, crate::headless::current_frame_size()))
+}
+
+/// Records a camera mode change and mirrors it into the last rendered camera,
+/// so getters see it before the next frame.
+fn update_camera_modes(update: impl Fn(&mut CameraModes), apply: impl Fn(&mut Camera)) {
+    if let Ok(mut modes) = CAMERA_MODES.lock() {
+        update(&mut modes);
+        modes.generation += 1;
+    }
+    if let Ok(mut guard) = CURRENT_CAMERA.lock() {
+        if let Some(camera) = guard.as_mut() {
+            apply(camera);
+        }
+    }
+}
+
+/// Sets the camera navigation style (turntable, free, planar, ...).
+///
+/// Applied on the next frame and kept until changed again, here or in the
+/// camera settings panel. Also used by later headless renders.
+pub fn set_navigation_style(style: NavigationStyle) {
+    update_camera_modes(
+        |modes| modes.navigation_style = Some(style),
+        |camera| camera.set_navigation_style(style),
+    );
+}
+
+/// Sets the camera projection mode (perspective or orthographic).
+///
+/// Applied on the next frame and kept until changed again, here or in the
+/// camera settings panel. Also used by later headless renders.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// set_projection_mode(ProjectionMode::Orthographic);
+/// assert_eq!(get_projection_mode(), ProjectionMode::Orthographic);
+/// ```
+pub fn set_projection_mode(mode: ProjectionMode) {
+    update_camera_modes(
+        |modes| modes.projection_mode = Some(mode),
+        |camera| camera.set_projection_mode(mode),
+    );
+}
+
+/// Sets the camera up direction; the front direction follows it.
+///
+/// Applied on the next frame and kept until changed again, here or in the
+/// camera settings panel. Also used by later headless renders.
+pub fn set_up_direction(direction: AxisDirection) {
+    update_camera_modes(
+        |modes| modes.up_direction = Some(direction),
+        |camera| camera.set_up_direction(direction),
+    );
+}
+
+/// Smallest vertical field of view accepted by [`set_fov_degrees`].
+pub const MIN_FOV_DEGREES: f32 = 5.0;
+
+/// Largest vertical field of view accepted by [`set_fov_degrees`].
+pub const MAX_FOV_DEGREES: f32 = 120.0;
+
+/// Sets the vertical field of view of the perspective camera, in degrees.
+///
+/// Clamped to [`MIN_FOV_DEGREES`]..=[`MAX_FOV_DEGREES`]; non-finite values
+/// are ignored with a warning. Applied on the next frame and kept until
+/// changed again, here or in the camera settings panel.
+///
+/// # Example
+///
+/// ```no_run
+/// use polyscope_rs::*;
+///
+/// init().unwrap();
+/// set_fov_degrees(30.0);
+/// assert_eq!(get_fov_degrees(), 30.0);
+/// ```
+pub fn set_fov_degrees(degrees: f32) {
+    if !degrees.is_finite() {
+        log::warn!("set_fov_degrees: field of view must be finite, got {degrees}");
+        return;
+    }
+    let degrees = degrees.clamp(MIN_FOV_DEGREES, MAX_FOV_DEGREES);
+    update_camera_modes(
+        |modes| modes.fov_degrees = Some(degrees),
+        |camera| camera.fov = degrees.to_radians(),
+    );
+}
+
+/// Returns the vertical field of view in degrees.
+#[must_use]
+pub fn get_fov_degrees() -> f32 {
+    current_camera_mode(Camera::fov_degrees, |modes| modes.fov_degrees)
+}
+
+/// Sets the near and far clip plane distances.
+///
+/// Logs a warning and does nothing unless `0 < near < far` and both are
+/// finite. Applied on the next frame; framing the camera (e.g. with
+/// [`look_at_structure`]) recomputes them from the framed box.
+pub fn set_clip_planes(near: f32, far: f32) {
+    if !(near.is_finite() && far.is_finite() && near > 0.0 && near < far) {
+        log::warn!("set_clip_planes: need finite 0 < near < far, got near {near}, far {far}");
+        return;
+    }
+    update_camera_modes(
+        |modes| modes.clip_planes = Some((near, far)),
+        |camera| {
+            camera.near = near;
+            camera.far = far;
+        },
+    );
+}
+
+/// Returns the `(near, far)` clip plane distances.
+#[must_use]
+pub fn get_clip_planes() -> (f32, f32) {
+    current_camera_mode(
+        |camera| (camera.near, camera.far),
+        |modes| modes.clip_planes,
+    )
+}
+
+/// Reads a camera mode: the last rendered camera, else the requested mode,
+/// else the default camera.
+fn current_camera_mode<T>(
+    from_camera: impl Fn(&Camera) -> T,
+    from_modes: impl Fn(&CameraModes) -> Option<T>,
+) -> T {
+    if let Some(value) = CURRENT_CAMERA
+        .lock()
+        .ok()
+        .and_then(|guard| guard.as_ref().map(&from_camera))
+    {
+        return value;
+    }
+    CAMERA_MODES
+        .lock()
+        .ok()
+        .and_then(|modes| from_modes(&modes))
+        .unwrap_or_else(|| from_camera(&Camera::default()))
+}
+
+/// Returns the current camera navigation style.
+#[must_use]
+pub fn get_navigation_style() -> NavigationStyle {
+    current_camera_mode(
+        |camera| camera.navigation_style,
+        |modes| modes.navigation_style,
+    )
+}
+
+/// Returns the current camera projection mode.
+#[must_use]
+pub fn get_projection_mode() -> ProjectionMode {
+    current_camera_mode(
+        |camera| camera.projection_mode,
+        |modes| modes.projection_mode,
+    )
+}
+
+/// Returns the current camera up direction.
+#[must_use]
+pub fn get_up_direction() -> AxisDirection {
+    current_camera_mode(|camera| camera.up_direction, |modes| modes.up_direction)
+}
+
+/// Applies camera modes changed since `applied_generation` (for internal use
+/// by App). A fresh renderer passes 0 and so picks up every mode set so far.
+pub(crate) fn apply_camera_modes(camera: &mut Camera, applied_generation: &mut u64) {
+    let Ok(modes) = CAMERA_MODES.lock() else {
+        return;
+    };
+    if modes.generation == *applied_generation {
+        return;
+    }
+    *applied_generation = modes.generation;
+    if let Some(style) = modes.navigation_style {
+        camera.set_navigation_style(style);
+    }
+    if let Some(mode) = modes.projection_mode {
+        camera.set_projection_mode(mode);
+    }
+    if let Some(direction) = modes.up_direction {
+        camera.set_up_direction(direction);
+    }
+    if let Some(degrees) = modes.fov_degrees {
+        camera.fov = degrees.to_radians();
+    }
+    if let Some((near, far)) = modes.clip_planes {
+        camera.near = near;
+        camera.far = far;
+    }
+}
@@ -38,8 +38,11 @@
 //! }
 //! ```
 
-use crate::{Vec3, VolumeGrid, with_context_mut};
-use polyscope_core::structure::HasQuantities;
+use crate::{
+    SurfaceMeshHandle, Vec3, VolumeGrid, register_surface_mesh, set_surface_mesh_transform,
+    with_context_mut,
+};
+use polyscope_core::structure::{HasQuantities, Structure};
 use polyscope_structures::volume_grid::{
     VolumeGridCellScalarQuantity, VolumeGridNodeScalarQuantity, VolumeGridVizMode,
 };
@@ -97,6 +100,18 @@ impl VolumeGridHandle {
         &self.name
     }
 
+    /// Shows or hides this volume grid; a hidden volume grid is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("VolumeGrid", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this volume grid is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("VolumeGrid", &self.name)
+    }
+
     /// Sets the edge color.
     pub fn set_edge_color(&self, color: Vec3) -> &Self {
         with_volume_grid(&self.name, |vg| {
@@ -137,6 +152,24 @@ impl VolumeGridHandle {
         self
     }
 
+    /// Shows the enabled cell scalar quantity as a semi-transparent voxel
+    /// block, so interior cells show through.
+    pub fn set_cube_visualization(&self, enabled: bool) -> &Self {
+        with_volume_grid(&self.name, |vg| {
+            vg.set_cube_visualization(enabled);
+        });
+        self
+    }
+
+    /// Sets the cube transparency used by the cube visualization
+    /// (0 = opaque, 1 = invisible).
+    pub fn set_cube_transparency(&self, transparency: f32) -> &Self {
+        with_volume_grid(&self.name, |vg| {
+            vg.set_cube_transparency(transparency);
+        });
+        self
+    }
+
     /// Enables a quantity by name.
     pub fn set_quantity_enabled(&self, quantity_name: &str, enabled: bool) -> &Self {
         with_volume_grid(&self.name, |vg| {
@@ -192,6 +225,28 @@ impl VolumeGridHandle {
         self
     }
 
+    /// Extracts an isosurface from a node scalar quantity and registers it
+    /// as a new surface mesh.
+    ///
+    /// The new mesh inherits the grid's transform. Returns `None` if the grid
+    /// or the node scalar quantity does not exist. If `iso` lies outside the
+    /// data range, an empty mesh is registered.
+    pub fn register_isosurface(
+        &self,
+        new_name: &str,
+        quantity_name: &str,
+        iso: f32,
+    ) -> Option<SurfaceMeshHandle> {
+        let (vertices, triangles, transform) = with_volume_grid_ref(&self.name, |vg| {
+            vg.extract_isosurface(quantity_name, iso)
+                .map(|(v, t)| (v, t, vg.transform()))
+        })??;
+
+        let mesh = register_surface_mesh(new_name, vertices, triangles);
+        set_surface_mesh_transform(new_name, transform);
+        Some(mesh)
+    }
+
     /// Sets the color map for a quantity (node or cell scalar).
     pub fn set_color_map(&self, quantity_name: &str, color_map: &str) -> &Self {
         with_volume_grid(&self.name, |vg| {
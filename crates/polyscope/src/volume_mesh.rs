@@ -112,6 +112,18 @@ impl VolumeMeshHandle {
         &self.name
     }
 
+    /// Shows or hides this volume mesh; a hidden volume mesh is neither drawn nor pickable.
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        crate::set_structure_enabled("VolumeMesh", &self.name, enabled);
+        self
+    }
+
+    /// Returns whether this volume mesh is enabled (false once it is removed).
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        crate::is_structure_enabled("VolumeMesh", &self.name)
+    }
+
     /// Sets the base color.
     pub fn set_color(&self, color: Vec3) -> &Self {
         with_volume_mesh(&self.name, |vm| {
@@ -144,6 +156,21 @@ impl VolumeMeshHandle {
         self
     }
 
+    /// Sets whether the faces of all cells are rendered, not just the
+    /// boundary faces (default: false).
+    pub fn set_show_interior(&self, show: bool) -> &Self {
+        with_volume_mesh(&self.name, |vm| {
+            vm.set_show_interior(show);
+        });
+        self
+    }
+
+    /// Returns whether the faces of all cells are rendered.
+    #[must_use]
+    pub fn show_interior(&self) -> bool {
+        with_volume_mesh_ref(&self.name, VolumeMesh::show_interior).unwrap_or(false)
+    }
+
     /// Adds a vertex scalar quantity.
     pub fn add_vertex_scalar_quantity(&self, name: impl Into<String>, values: Vec<f32>) -> &Self {
         let name = name.into();
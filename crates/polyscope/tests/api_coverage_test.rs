@@ -130,6 +130,31 @@ fn api_coverage_tests() {
         assert!(get_slice_plane("to_remove").is_none());
     }
 
+    // --- Test: Slice plane restricted to one structure ---
+    {
+        remove_all_slice_planes();
+        remove_all_structures();
+
+        let verts = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        register_surface_mesh("sp_mesh_a", verts.clone(), vec![[0u32, 1, 2]]);
+        register_surface_mesh("sp_mesh_b", verts, vec![[0u32, 1, 2]]);
+
+        let plane = add_slice_plane("sp_only_a");
+        assert!(plane.active_structures().is_none());
+        let mask = |name: &str| with_context(|ctx| ctx.slice_plane_mask("SurfaceMesh", name));
+        assert_eq!(mask("sp_mesh_a"), 0);
+        assert_eq!(mask("sp_mesh_b"), 0);
+
+        let only_a = vec![("SurfaceMesh".to_string(), "sp_mesh_a".to_string())];
+        plane.set_active_structures(Some(only_a.clone()));
+        assert_eq!(plane.active_structures(), Some(only_a));
+        assert_eq!(mask("sp_mesh_a"), 0);
+        assert_eq!(mask("sp_mesh_b"), 1);
+
+        plane.set_active_structures(None);
+        assert_eq!(mask("sp_mesh_b"), 0);
+    }
+
     // --- Test: Multiple slice planes ---
     {
         remove_all_slice_planes();
@@ -310,6 +335,66 @@ fn api_coverage_tests() {
         assert!((retrieved - combined).abs_diff_eq(Mat4::ZERO, 0.001));
     }
 
+    // --- Test: Generic transform on a volume grid ---
+    {
+        remove_all_structures();
+
+        register_volume_grid_uniform("transform_vg", 4, Vec3::ZERO, Vec3::ONE);
+        assert_eq!(
+            get_transform("VolumeGrid", "transform_vg"),
+            Some(Mat4::IDENTITY)
+        );
+
+        let translation = Mat4::from_translation(Vec3::new(0.0, 5.0, 0.0));
+        set_transform("VolumeGrid", "transform_vg", translation);
+
+        let retrieved = get_transform("VolumeGrid", "transform_vg").unwrap();
+        assert!((retrieved - translation).abs_diff_eq(Mat4::ZERO, 0.001));
+        with_volume_grid_ref("transform_vg", |vg| {
+            assert!((vg.transform() - translation).abs_diff_eq(Mat4::ZERO, 0.001));
+        });
+        assert!(get_transform("VolumeGrid", "missing").is_none());
+    }
+
+    // --- Test: World-space bounding box and centroid ---
+    {
+        remove_all_structures();
+
+        let points = vec![
+            Vec3::ZERO,
+            Vec3::new(1.0, 2.0, 0.5),
+            Vec3::new(-1.0, 0.0, 3.0),
+        ];
+        register_point_cloud("bbox_pc", points.clone());
+        let offset = Vec3::new(5.0, -2.0, 1.0);
+        set_point_cloud_transform("bbox_pc", Mat4::from_translation(offset));
+
+        let (min, max) = get_structure_bounding_box("PointCloud", "bbox_pc").unwrap();
+        for p in &points {
+            let world = *p + offset;
+            assert!(world.cmpge(min - 1e-5).all() && world.cmple(max + 1e-5).all());
+        }
+        assert!(min.abs_diff_eq(Vec3::new(-1.0, 0.0, 0.0) + offset, 1e-5));
+        assert!(max.abs_diff_eq(Vec3::new(1.0, 2.0, 3.0) + offset, 1e-5));
+        let centroid = get_structure_centroid("PointCloud", "bbox_pc").unwrap();
+        assert!(centroid.abs_diff_eq(Vec3::new(0.0, 1.0, 1.5) + offset, 1e-5));
+
+        let verts = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+        register_surface_mesh("bbox_mesh", verts.clone(), vec![[0u32, 1, 2], [0, 1, 3]]);
+        set_surface_mesh_transform("bbox_mesh", Mat4::from_translation(-offset));
+
+        let (min, max) = get_structure_bounding_box("SurfaceMesh", "bbox_mesh").unwrap();
+        for v in &verts {
+            let world = *v - offset;
+            assert!(world.cmpge(min - 1e-5).all() && world.cmple(max + 1e-5).all());
+        }
+        let centroid = get_structure_centroid("SurfaceMesh", "bbox_mesh").unwrap();
+        assert!(centroid.abs_diff_eq(Vec3::splat(0.5) - offset, 1e-5));
+
+        assert!(get_structure_bounding_box("PointCloud", "nonexistent").is_none());
+        assert!(get_structure_centroid("SurfaceMesh", "bbox_pc").is_none());
+    }
+
     // --- Test: Nonexistent structure transform ---
     {
         assert!(get_point_cloud_transform("nonexistent").is_none());
@@ -367,7 +452,8 @@ fn api_coverage_tests() {
                 Vec2::new(0.0, 1.0),
                 Vec2::new(0.5, 0.5),
             ],
-        );
+        )
+        .unwrap();
 
         // Explicit basis
         let basis_x = vec![Vec3::X, Vec3::X, Vec3::X];
@@ -381,10 +467,12 @@ fn api_coverage_tests() {
             ],
             basis_x,
             basis_y,
-        );
+        )
+        .unwrap();
 
         // Face intrinsic
-        mesh.add_face_intrinsic_vector_quantity_auto("face_intrinsic", vec![Vec2::new(1.0, 0.0)]);
+        mesh.add_face_intrinsic_vector_quantity_auto("face_intrinsic", vec![Vec2::new(1.0, 0.0)])
+            .unwrap();
     }
 
     // --- Test: Surface mesh one-form quantity ---
@@ -402,7 +490,8 @@ fn api_coverage_tests() {
         );
 
         // Triangle has 3 edges
-        mesh.add_one_form_quantity("one_form", vec![1.0, -0.5, 0.5], vec![true, true, false]);
+        mesh.add_one_form_quantity("one_form", vec![1.0, -0.5, 0.5], vec![true, true, false])
+            .unwrap();
     }
 
     // --- Test: Surface mesh RGBA color quantities ---
@@ -423,10 +512,84 @@ fn api_coverage_tests() {
                 Vec4::new(0.0, 1.0, 0.0, 0.7),
                 Vec4::new(0.0, 0.0, 1.0, 0.9),
             ],
-        );
+        )
+        .unwrap();
 
         // Face RGBA
-        mesh.add_face_color_quantity_with_alpha("face_rgba", vec![Vec4::new(1.0, 1.0, 0.0, 0.5)]);
+        mesh.add_face_color_quantity_with_alpha("face_rgba", vec![Vec4::new(1.0, 1.0, 0.0, 0.5)])
+            .unwrap();
+
+        // Corner colors (one per face corner)
+        assert!(
+            mesh.add_corner_color_quantity("corner", vec![Vec3::ONE])
+                .is_err()
+        );
+        mesh.add_corner_color_quantity("corner", vec![Vec3::X, Vec3::Y, Vec3::Z])
+            .unwrap();
+    }
+
+    // --- Test: Quantity enable and activate by name ---
+    {
+        remove_all_structures();
+
+        let mesh = register_surface_mesh(
+            "toggle_mesh",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![[0u32, 1, 2]],
+        );
+        mesh.add_vertex_scalar_quantity("a", vec![0.0, 1.0, 2.0])
+            .unwrap();
+        mesh.add_vertex_scalar_quantity("b", vec![2.0, 1.0, 0.0])
+            .unwrap();
+
+        set_quantity_enabled("SurfaceMesh", "toggle_mesh", "a", true);
+        assert!(is_quantity_enabled("SurfaceMesh", "toggle_mesh", "a"));
+        assert!(!is_quantity_enabled("SurfaceMesh", "toggle_mesh", "b"));
+
+        activate_quantity("SurfaceMesh", "toggle_mesh", "b");
+        assert!(!is_quantity_enabled("SurfaceMesh", "toggle_mesh", "a"));
+        assert!(is_quantity_enabled("SurfaceMesh", "toggle_mesh", "b"));
+        with_surface_mesh("toggle_mesh", |m| {
+            assert_eq!(m.active_vertex_scalar_quantity().unwrap().name(), "b");
+        });
+
+        // Missing structures and quantities are ignored
+        set_quantity_enabled("SurfaceMesh", "toggle_mesh", "missing", true);
+        activate_quantity("PointCloud", "toggle_mesh", "a");
+        assert!(!is_quantity_enabled("SurfaceMesh", "missing", "a"));
+    }
+
+    // --- Test: Listing and assigning materials ---
+    {
+        remove_all_structures();
+
+        let materials = available_materials();
+        assert!(materials.iter().any(|m| m == "clay"));
+        assert!(materials.iter().any(|m| m == "wax"));
+
+        register_point_cloud("material_pc", vec![Vec3::ZERO, Vec3::X]);
+        set_structure_material("PointCloud", "material_pc", "wax");
+        assert_eq!(
+            get_structure_material("PointCloud", "material_pc").as_deref(),
+            Some("wax")
+        );
+
+        // Unknown materials are rejected
+        set_structure_material("PointCloud", "material_pc", "not_a_material");
+        assert_eq!(
+            get_structure_material("PointCloud", "material_pc").as_deref(),
+            Some("wax")
+        );
+
+        // Custom matcaps are listed and assignable before the viewer loads them
+        load_matcap_material("solid_green", &[0, 255, 0, 255].repeat(4), 2, 2).unwrap();
+        assert!(available_materials().iter().any(|m| m == "solid_green"));
+        set_structure_material("PointCloud", "material_pc", "solid_green");
+        assert_eq!(
+            get_structure_material("PointCloud", "material_pc").as_deref(),
+            Some("solid_green")
+        );
+        assert!(load_matcap_material("bad_size", &[0; 12], 2, 2).is_err());
     }
 
     // --- Test: Surface mesh parameterization ---
@@ -443,19 +606,69 @@ fn api_coverage_tests() {
         mesh.add_vertex_parameterization_quantity(
             "vertex_uv",
             vec![Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)],
-        );
+        )
+        .unwrap();
 
         // Corner parameterization (3 corners for 1 triangle)
         mesh.add_corner_parameterization_quantity(
             "corner_uv",
             vec![Vec2::ZERO, Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)],
-        );
+        )
+        .unwrap();
     }
 
     // ========================================================================
     // CURVE NETWORK HANDLE METHOD TESTS
     // ========================================================================
 
+    // --- Test: Point cloud vector quantity scaling ---
+    {
+        remove_all_structures();
+
+        register_point_cloud("pc_vec_test", vec![Vec3::ZERO, Vec3::X])
+            .add_vector_quantity("dir", vec![Vec3::Y, Vec3::new(0.0, 4.0, 0.0)])
+            .set_vector_length_scale("dir", 0.1, true)
+            .set_vector_radius("dir", 0.01)
+            .set_vector_normalize("dir", true);
+
+        with_point_cloud_ref("pc_vec_test", |pc| {
+            let vq = pc
+                .get_quantity("dir")
+                .and_then(|q| {
+                    q.as_any()
+                        .downcast_ref::<polyscope_structures::point_cloud::PointCloudVectorQuantity>()
+                })
+                .unwrap();
+            assert!((vq.length_scale() - 0.1).abs() < 1e-6);
+            assert!(vq.length_is_relative());
+            assert!((vq.radius() - 0.01).abs() < 1e-6);
+            assert!(vq.is_normalized());
+        });
+    }
+
+    // --- Test: Point cloud render mode ---
+    {
+        let pc = register_point_cloud("pc_mode_test", vec![Vec3::ZERO, Vec3::X]);
+        assert_eq!(pc.point_render_mode(), PointRenderMode::Sphere);
+        pc.set_point_render_mode(PointRenderMode::Circle);
+        assert_eq!(pc.point_render_mode(), PointRenderMode::Circle);
+        with_point_cloud_ref("pc_mode_test", |pc| {
+            assert_eq!(pc.uniforms().render_mode, 2);
+        });
+    }
+
+    // --- Test: Point cloud display fraction ---
+    {
+        let points = (0..1000u16).map(|i| Vec3::splat(f32::from(i))).collect();
+        let pc = register_point_cloud("pc_fraction_test", points);
+        pc.set_display_fraction(0.1);
+        assert!((pc.display_fraction() - 0.1).abs() < 1e-6);
+        with_point_cloud_ref("pc_fraction_test", |pc| {
+            assert_eq!(pc.num_displayed_points(), 100);
+            assert_eq!(pc.num_points(), 1000);
+        });
+    }
+
     // --- Test: Curve network handle methods ---
     {
         remove_all_structures();
@@ -468,6 +681,11 @@ fn api_coverage_tests() {
         cn.set_color(Vec3::new(1.0, 0.0, 0.0))
             .set_radius(0.05, true)
             .set_material("clay");
+
+        // Render mode (tubes by default)
+        assert_eq!(cn.render_mode(), CurveRenderMode::Tube);
+        cn.set_render_mode(CurveRenderMode::Line);
+        assert_eq!(cn.render_mode(), CurveRenderMode::Line);
     }
 
     // --- Test: Curve network quantities via with_curve_network ---
@@ -480,7 +698,8 @@ fn api_coverage_tests() {
 
         // Node quantities
         with_curve_network("cn_quant_test", |cn| {
-            cn.add_node_scalar_quantity("node_scalar", vec![0.0, 0.33, 0.66, 1.0]);
+            cn.add_node_scalar_quantity("node_scalar", vec![0.0, 0.33, 0.66, 1.0])
+                .unwrap();
             cn.add_node_vector_quantity("node_vec", vec![Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE]);
             cn.add_node_color_quantity(
                 "node_color",
@@ -495,7 +714,8 @@ fn api_coverage_tests() {
 
         // Edge quantities (3 edges)
         with_curve_network("cn_quant_test", |cn| {
-            cn.add_edge_scalar_quantity("edge_scalar", vec![0.25, 0.5, 0.75]);
+            cn.add_edge_scalar_quantity("edge_scalar", vec![0.25, 0.5, 0.75])
+                .unwrap();
             cn.add_edge_vector_quantity("edge_vec", vec![Vec3::X, Vec3::Y, Vec3::Z]);
             cn.add_edge_color_quantity(
                 "edge_color",
@@ -568,6 +788,748 @@ fn api_coverage_tests() {
         vm.add_cell_color_quantity("cc", vec![Vec3::new(0.5, 0.5, 0.5)]);
     }
 
+    // --- Test: Volume mesh interior faces ---
+    {
+        let vm = get_volume_mesh("vm_quant_test").unwrap();
+        assert!(!vm.show_interior());
+        vm.set_show_interior(true);
+        assert!(vm.show_interior());
+        vm.set_show_interior(false);
+    }
+
+    // ========================================================================
+    // FLOATING IMAGE TESTS
+    // ========================================================================
+
+    // --- Test: Color and scalar image registration ---
+    {
+        remove_all_floating_quantities();
+
+        let colors = vec![Vec4::new(1.0, 0.0, 0.0, 1.0); 6];
+        assert!(add_color_image("color_img", 3, 3, colors.clone()).is_err());
+        add_color_image("color_img", 3, 2, colors).unwrap();
+        assert!(add_scalar_image("scalar_img", 2, 2, vec![0.0; 3]).is_err());
+        add_scalar_image("scalar_img", 2, 2, vec![0.0, 1.0, 2.0, 3.0]).unwrap();
+        set_floating_image_origin("scalar_img", ImageOrigin::LowerLeft);
+        // Re-adding replaces the existing image
+        add_color_image("color_img", 1, 1, vec![Vec4::ONE]).unwrap();
+
+        with_context(|ctx| {
+            let names: Vec<&str> = ctx.floating_quantities.iter().map(|q| q.name()).collect();
+            assert_eq!(names, ["scalar_img", "color_img"]);
+            let scalar = ctx.floating_quantities[0]
+                .as_any()
+                .downcast_ref::<FloatingScalarImage>()
+                .unwrap();
+            assert_eq!(scalar.origin(), ImageOrigin::LowerLeft);
+            assert!((scalar.pixel(0, 0) - 2.0).abs() < 1e-6);
+            let color = ctx.floating_quantities[1]
+                .as_any()
+                .downcast_ref::<FloatingColorImage>()
+                .unwrap();
+            assert_eq!((color.width(), color.height()), (1, 1));
+        });
+
+        remove_all_floating_quantities();
+    }
+
+    // ========================================================================
+    // OPTIONS TESTS
+    // ========================================================================
+
+    // --- Test: Antialiasing factor is clamped to 1, 2 or 4 ---
+    {
+        for (requested, expected) in [(0, 1), (1, 1), (2, 2), (3, 2), (4, 4), (8, 4)] {
+            set_antialiasing(requested);
+            assert_eq!(get_antialiasing(), expected);
+        }
+        set_antialiasing(1);
+    }
+
+    // --- Test: Frame counters before the viewer runs ---
+    {
+        assert_eq!(frame_count(), 0);
+        assert!(elapsed_seconds().abs() < f32::EPSILON);
+    }
+
+    // --- Test: Background color and transparency options ---
+    {
+        set_background_color(Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(get_background_color(), Vec3::new(1.0, 0.0, 0.0));
+        set_transparent_background(true);
+        assert!(get_transparent_background());
+        set_transparent_background(false);
+        set_background_color(Vec3::splat(0.1));
+    }
+
+    // --- Test: Keyboard shortcut bindings ---
+    {
+        assert_eq!(
+            get_key_binding(KeyAction::GizmoRotate),
+            Some(Key::Char('R'))
+        );
+        set_key_binding(KeyAction::GizmoRotate, Some(Key::Char('e')));
+        assert_eq!(
+            get_key_bindings().action_for(Key::Char('E')),
+            Some(KeyAction::GizmoRotate)
+        );
+        set_key_binding(KeyAction::GizmoRotate, Some(Key::Char('R')));
+    }
+
+    // --- Test: Hiding the UI panels ---
+    {
+        assert!(is_ui_visible());
+        assert_eq!(
+            get_key_bindings().action_for(Key::Char('u')),
+            Some(KeyAction::ToggleUi)
+        );
+        set_ui_visible(false);
+        assert!(!is_ui_visible());
+        set_ui_visible(true);
+        assert!(is_ui_visible());
+    }
+
+    // --- Test: On-demand redraws without a window ---
+    {
+        assert!(!get_redraw_on_change());
+        set_redraw_on_change(true);
+        assert!(get_redraw_on_change());
+        // No window is open: requesting redraws is a no-op
+        request_redraw();
+        set_point_cloud_transform("nonexistent", Mat4::IDENTITY);
+        set_redraw_on_change(false);
+    }
+
+    // --- Test: Double-precision surface mesh vertices ---
+    {
+        let vertices = vec![
+            [5.0e6, 5.0e6, 100.0],
+            [5.0e6 + 0.5, 5.0e6, 100.0],
+            [5.0e6, 5.0e6 + 0.5, 100.25],
+        ];
+        register_point_cloud("geo_points", vec![Vec3::ZERO]);
+        assert_eq!(get_scene_origin(), DVec3::ZERO);
+        let mesh = register_surface_mesh_f64("geo_mesh", vertices, vec![[0u32, 1, 2]]);
+        let origin = DVec3::new(5.0e6 + 0.25, 5.0e6 + 0.25, 100.125);
+        assert!((mesh.origin() - origin).length() < 1e-9);
+        assert_eq!(get_scene_origin(), mesh.origin());
+        let (min, max) = mesh.bounding_box_f64().unwrap();
+        assert!((min - DVec3::new(5.0e6, 5.0e6, 100.0)).length() < 1e-6);
+        assert!((max - DVec3::new(5.0e6 + 0.5, 5.0e6 + 0.5, 100.25)).length() < 1e-6);
+
+        // Existing structures move into the frame of the new origin
+        let shifted = get_transform("PointCloud", "geo_points").unwrap();
+        assert_eq!(shifted, Mat4::from_translation(-origin.as_vec3()));
+
+        // A second mesh shares the origin and keeps its offset from the first
+        let second = register_surface_mesh_f64(
+            "geo_mesh_2",
+            vec![
+                [5.0e6 + 100.0, 5.0e6, 100.0],
+                [5.0e6 + 101.0, 5.0e6, 100.0],
+                [5.0e6 + 100.0, 5.0e6 + 1.0, 100.0],
+            ],
+            vec![[0u32, 1, 2]],
+        );
+        assert_eq!(second.origin(), mesh.origin());
+        let (second_min, _) = second.bounding_box_f64().unwrap();
+        assert!((second_min - min - DVec3::new(100.0, 0.0, 0.0)).length() < 1e-6);
+        remove_structure("geo_mesh");
+        remove_structure("geo_mesh_2");
+        remove_structure("geo_points");
+        with_context_mut(|ctx| ctx.scene_origin = None);
+    }
+
+    // --- Test: Isolines on a mesh vertex scalar quantity ---
+    {
+        let mesh = register_surface_mesh(
+            "isoline_mesh",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![[0u32, 1, 2]],
+        );
+        mesh.add_vertex_scalar_quantity("height", vec![0.0, 0.5, 1.0])
+            .unwrap();
+        assert!(!mesh.isolines_enabled("height"));
+        mesh.set_isolines_enabled("height", true)
+            .set_isoline_count("height", 8)
+            .set_isoline_width("height", 1.5);
+        assert!(mesh.isolines_enabled("height"));
+        mesh.set_isolines_enabled("height", false);
+        assert!(!mesh.isolines_enabled("height"));
+        remove_structure("isoline_mesh");
+    }
+
+    // --- Test: Structure bounding box wireframes ---
+    {
+        register_point_cloud("bbox_points", vec![Vec3::ZERO, Vec3::ONE]);
+        assert!(!is_structure_bbox_visible("PointCloud", "bbox_points"));
+        set_structure_bbox_visible("PointCloud", "bbox_points", true);
+        assert!(is_structure_bbox_visible("PointCloud", "bbox_points"));
+
+        // The box follows the structure transform
+        set_point_cloud_transform("bbox_points", Mat4::from_translation(Vec3::X * 2.0));
+        let boxes = with_context(Context::bounding_box_wireframes);
+        assert_eq!(boxes.len(), 1);
+        assert!((boxes[0].0 - Vec3::new(2.0, 0.0, 0.0)).length() < 1e-5);
+        assert!((boxes[0].1 - Vec3::new(3.0, 1.0, 1.0)).length() < 1e-5);
+
+        // Hidden structures draw no box
+        set_structure_enabled("PointCloud", "bbox_points", false);
+        assert!(with_context(Context::bounding_box_wireframes).is_empty());
+
+        set_structure_bbox_visible("PointCloud", "bbox_points", false);
+        assert!(!is_structure_bbox_visible("PointCloud", "bbox_points"));
+        remove_structure("bbox_points");
+    }
+
+    // --- Test: Camera smoothing option ---
+    {
+        assert_eq!(get_camera_smoothing(), 0.0);
+        set_camera_smoothing(0.5);
+        assert_eq!(get_camera_smoothing(), 0.5);
+        set_camera_smoothing(-1.0);
+        assert_eq!(get_camera_smoothing(), 0.0);
+    }
+
+    // --- Test: Structure ignoring slice planes ---
+    {
+        register_surface_mesh(
+            "ignore_planes_mesh",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![[0u32, 1, 2]],
+        );
+        add_slice_plane("ignore_planes_plane");
+        let mask = || with_context(|ctx| ctx.slice_plane_mask("SurfaceMesh", "ignore_planes_mesh"));
+        assert!(!get_ignore_slice_planes(
+            "SurfaceMesh",
+            "ignore_planes_mesh"
+        ));
+        assert_eq!(mask(), 0);
+
+        set_ignore_slice_planes("SurfaceMesh", "ignore_planes_mesh", true);
+        assert!(get_ignore_slice_planes("SurfaceMesh", "ignore_planes_mesh"));
+        assert_eq!(mask(), u32::MAX);
+
+        set_ignore_slice_planes("SurfaceMesh", "ignore_planes_mesh", false);
+        assert_eq!(mask(), 0);
+        remove_slice_plane("ignore_planes_plane");
+        remove_structure("ignore_planes_mesh");
+    }
+
+    // --- Test: Cursor ray without a window ---
+    {
+        assert!(cursor_ray().is_none());
+        let camera = Camera::new(1.0);
+        let (_, dir) = camera.screen_ray(50.0, 50.0, (100, 100));
+        assert!((dir - camera.forward()).length() < 1e-4);
+    }
+
+    // --- Test: Structure draw order ---
+    {
+        register_point_cloud("order_a", vec![Vec3::ZERO]);
+        register_point_cloud("order_b", vec![Vec3::ZERO]);
+        register_point_cloud("order_c", vec![Vec3::ZERO]);
+        assert_eq!(get_draw_order("PointCloud", "order_a"), Some(0));
+        set_draw_order("PointCloud", "order_a", 5);
+        set_draw_order("PointCloud", "order_b", -1);
+        set_draw_order("PointCloud", "order_c", 2);
+        assert_eq!(get_draw_order("PointCloud", "order_a"), Some(5));
+        assert_eq!(get_draw_order("PointCloud", "missing"), None);
+
+        let sequence: Vec<String> = with_context(|ctx| {
+            ctx.registry
+                .iter_in_draw_order()
+                .map(|s| s.name().to_string())
+                .filter(|name| name.starts_with("order_"))
+                .collect()
+        });
+        assert_eq!(sequence, ["order_b", "order_c", "order_a"]);
+        remove_structure("order_a");
+        remove_structure("order_b");
+        remove_structure("order_c");
+    }
+
+    // --- Test: Surface mesh OBJ export round trip ---
+    {
+        let vertices: Vec<Vec3> = (0..8u8)
+            .map(|i| {
+                let bit = |b: u8| f32::from((i >> b) & 1);
+                Vec3::new(bit(0), bit(1), bit(2))
+            })
+            .collect();
+        let faces = vec![
+            vec![0u32, 2, 3, 1],
+            vec![4, 5, 7, 6],
+            vec![0, 1, 5, 4],
+            vec![2, 6, 7, 3],
+            vec![0, 4, 6, 2],
+            vec![1, 3, 7, 5],
+        ];
+        register_surface_mesh("export_cube", vertices, faces.clone());
+        let path = std::env::temp_dir().join("polyscope_export_cube.obj");
+        export_surface_mesh("export_cube", &path).unwrap();
+        let loaded = load_obj(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Faces keep their quads rather than the render triangulation
+        assert_eq!(loaded.vertices.len(), 8);
+        assert_eq!(loaded.faces, faces);
+        assert_eq!(loaded.normals.map(|n| n.len()), Some(8));
+        assert!(export_surface_mesh("no_such_mesh", &path).is_err());
+        remove_structure("export_cube");
+    }
+
+    // --- Test: Depth of field options ---
+    {
+        assert!(!get_dof_config().enabled);
+        set_dof(true, 4.0, 12.0);
+        let dof = get_dof_config();
+        assert!(dof.enabled);
+        assert_eq!(dof.focal_distance, 4.0);
+        assert_eq!(dof.aperture, 12.0);
+        set_dof(false, -1.0, -2.0);
+        let dof = get_dof_config();
+        assert!(!dof.enabled);
+        assert!(dof.focal_distance > 0.0);
+        assert_eq!(dof.aperture, 0.0);
+    }
+
+    // --- Test: Text labels ---
+    {
+        add_label("apex", Vec3::new(0.0, 2.0, 0.0), "apex");
+        let label = get_label("apex").expect("label not stored");
+        assert_eq!(label.text, "apex");
+        assert_eq!(label.position, Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(label.color, Vec3::ONE);
+        set_label_color("apex", Vec3::X);
+        assert_eq!(get_label("apex").unwrap().color, Vec3::X);
+        remove_label("apex");
+        assert!(get_label("apex").is_none());
+    }
+
+    // --- Test: Options save/load round trip ---
+    {
+        let original = with_context(|ctx| ctx.options.clone());
+        set_antialiasing(2);
+        set_camera_smoothing(0.5);
+        set_transparency_mode(TransparencyMode::Pretty);
+        set_screenshot_prefix("saved");
+        let saved = with_context(|ctx| ctx.options.clone());
+
+        let path = std::env::temp_dir().join("polyscope_options.json");
+        save_options(&path).unwrap();
+        with_context_mut(|ctx| ctx.options = original.clone());
+        load_options(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(with_context(|ctx| ctx.options.clone()), saved);
+        assert!(load_options("no_such_options.json").is_err());
+        with_context_mut(|ctx| ctx.options = original);
+    }
+
+    // --- Test: Curve network with node scalar in one call ---
+    {
+        let nodes = vec![Vec3::ZERO, Vec3::X, Vec3::new(1.0, 1.0, 0.0)];
+        let edges = vec![[0, 1], [1, 2]];
+        let handle = register_curve_network_with_node_scalar(
+            "cn_scalar",
+            nodes.clone(),
+            edges.clone(),
+            "arc",
+            vec![0.0, 1.0, 2.0],
+            "blues",
+        )
+        .unwrap();
+        assert_eq!(handle.name(), "cn_scalar");
+        assert!(is_quantity_enabled("CurveNetwork", "cn_scalar", "arc"));
+        let active = with_curve_network_ref("cn_scalar", |cn| {
+            cn.active_node_scalar_quantity()
+                .map(|q| (q.name().to_string(), q.colormap_name().to_string()))
+        })
+        .flatten();
+        assert_eq!(active, Some(("arc".to_string(), "blues".to_string())));
+
+        let mismatch = register_curve_network_with_node_scalar(
+            "cn_scalar_bad",
+            nodes,
+            edges,
+            "arc",
+            vec![0.0, 1.0],
+            "viridis",
+        );
+        assert!(mismatch.is_err());
+        assert!(get_curve_network("cn_scalar_bad").is_none());
+    }
+
+    // --- Test: Manual length scale override ---
+    {
+        set_length_scale(3.0);
+        assert!(is_length_scale_manual());
+        register_point_cloud("ls_outlier", vec![Vec3::ZERO, Vec3::splat(1000.0)]);
+        assert!((get_length_scale() - 3.0).abs() < f32::EPSILON);
+        set_length_scale(-1.0);
+        assert!((get_length_scale() - 3.0).abs() < f32::EPSILON);
+
+        reset_length_scale();
+        assert!(!is_length_scale_manual());
+        assert!(get_length_scale() > 1000.0);
+        remove_structure("ls_outlier");
+    }
+
+    // --- Test: Surface mesh orientation repair ---
+    {
+        // Tetrahedron with one inverted face
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z];
+        let faces = vec![[0u32, 2, 1], [0, 1, 3], [1, 2, 3], [0, 2, 3]];
+        let mesh = register_surface_mesh("orient_tet", vertices, faces);
+        let first_face = || with_surface_mesh_ref("orient_tet", |m| m.faces()[0].clone());
+
+        mesh.flip_orientation();
+        assert_eq!(first_face(), Some(vec![0, 1, 2]));
+        assert_eq!(mesh.orient_consistently(), 3);
+        assert_eq!(first_face(), Some(vec![0, 2, 1]));
+        assert_eq!(mesh.orient_consistently(), 0);
+        remove_structure("orient_tet");
+    }
+
+    // --- Test: Field of view and clip planes ---
+    {
+        set_fov_degrees(60.0);
+        assert!((get_fov_degrees() - 60.0).abs() < 1e-4);
+        set_fov_degrees(500.0);
+        assert!((get_fov_degrees() - MAX_FOV_DEGREES).abs() < 1e-4);
+        set_fov_degrees(45.0);
+
+        set_clip_planes(0.05, 50.0);
+        assert_eq!(get_clip_planes(), (0.05, 50.0));
+        // Rejected: near >= far, and non-positive near
+        set_clip_planes(10.0, 1.0);
+        set_clip_planes(0.0, 1.0);
+        assert_eq!(get_clip_planes(), (0.05, 50.0));
+    }
+
+    // --- Test: Incremental scalar quantity update ---
+    {
+        register_point_cloud("update_values_pc", vec![Vec3::ZERO, Vec3::X, Vec3::Y])
+            .add_scalar_quantity("s", vec![0.0, 0.5, 1.0]);
+        set_quantity_enabled("PointCloud", "update_values_pc", "s", true);
+        with_point_cloud("update_values_pc", |pc| {
+            pc.get_quantity_mut("s")
+                .unwrap()
+                .as_any_mut()
+                .downcast_mut::<polyscope_structures::point_cloud::PointCloudScalarQuantity>()
+                .unwrap()
+                .set_colormap("reds");
+        });
+        let reds = ColorMapRegistry::new().get("reds").unwrap().clone();
+        let colors_before = with_point_cloud_ref("update_values_pc", |pc| {
+            pc.active_scalar_quantity().unwrap().compute_colors(&reds)
+        })
+        .unwrap();
+
+        update_scalar_quantity("PointCloud", "update_values_pc", "s", vec![1.0, 0.5, 0.0]).unwrap();
+        with_point_cloud_ref("update_values_pc", |pc| {
+            let sq = pc.active_scalar_quantity().unwrap();
+            assert_eq!(sq.values(), &[1.0, 0.5, 0.0]);
+            assert_eq!(sq.colormap_name(), "reds");
+            assert!(sq.range_min().abs() < 1e-6 && (sq.range_max() - 1.0).abs() < 1e-6);
+            assert_ne!(sq.compute_colors(&reds), colors_before);
+        });
+
+        assert!(matches!(
+            update_scalar_quantity("PointCloud", "update_values_pc", "s", vec![0.0]),
+            Err(PolyscopeError::SizeMismatch { .. })
+        ));
+        assert!(
+            update_scalar_quantity("PointCloud", "update_values_pc", "missing", vec![]).is_err()
+        );
+    }
+
+    // --- Test: Home view ---
+    {
+        let path = std::env::temp_dir().join("polyscope_home_view.json");
+        let load_position = |position: Vec3| {
+            let camera = Camera {
+                position,
+                ..Camera::default()
+            };
+            std::fs::write(&path, camera.to_json()).unwrap();
+            load_camera_view(&path).unwrap();
+        };
+        let saved_position = || {
+            save_camera_view(&path).unwrap();
+            Camera::from_json(&std::fs::read_to_string(&path).unwrap())
+                .unwrap()
+                .position
+        };
+
+        let home = Vec3::new(3.0, 2.0, 1.0);
+        load_position(home);
+        set_home_view();
+        // Move the camera away, then reset
+        load_position(Vec3::new(-5.0, 0.0, 0.0));
+        assert_ne!(saved_position(), home);
+        reset_camera_to_home();
+        assert_eq!(saved_position(), home);
+
+        clear_home_view();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // --- Test: Geodesic distance quantity ---
+    {
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+        ];
+        let faces = vec![vec![0, 1, 4, 3], vec![1, 2, 5, 4]];
+        let mesh = register_surface_mesh("geodesic_mesh", vertices, faces);
+        mesh.add_geodesic_distance_quantity("dist", 0).unwrap();
+        assert!(mesh.add_geodesic_distance_quantity("bad", 6).is_err());
+        with_surface_mesh_ref("geodesic_mesh", |m| {
+            let dist = m
+                .get_quantity("dist")
+                .unwrap()
+                .as_any()
+                .downcast_ref::<polyscope_structures::surface_mesh::MeshVertexScalarQuantity>()
+                .unwrap();
+            assert!(dist.values()[0].abs() < 1e-6);
+            assert!((dist.values()[5] - 5.0_f32.sqrt()).abs() < 0.3);
+        });
+        remove_structure("geodesic_mesh");
+    }
+
+    // --- Test: Color quantity color space ---
+    {
+        register_point_cloud("color_space_pc", vec![Vec3::ZERO])
+            .add_color_quantity("gray", vec![Vec3::splat(0.5)]);
+        let uploaded_gray = || {
+            with_point_cloud_ref("color_space_pc", |pc| {
+                let q = pc.get_quantity("gray").unwrap().as_any();
+                let cq = q
+                    .downcast_ref::<polyscope_structures::point_cloud::PointCloudColorQuantity>()
+                    .unwrap();
+                cq.linear_colors()[0].x
+            })
+            .unwrap()
+        };
+        // sRGB input is linearized on upload
+        assert!((uploaded_gray() - 0.214).abs() < 1e-3);
+        set_color_quantity_space("PointCloud", "color_space_pc", "gray", ColorSpace::Linear)
+            .unwrap();
+        assert!((uploaded_gray() - 0.5).abs() < 1e-6);
+
+        assert!(
+            set_color_quantity_space("PointCloud", "color_space_pc", "nope", ColorSpace::Srgb)
+                .is_err()
+        );
+        assert!(set_color_quantity_space("PointCloud", "nope", "gray", ColorSpace::Srgb).is_err());
+        remove_structure("color_space_pc");
+    }
+
+    // --- Test: Selection changed callback ---
+    {
+        use std::sync::{Arc, Mutex};
+        type Selection = Option<(String, String)>;
+
+        register_point_cloud("selection_cb_a", vec![Vec3::ZERO]);
+        register_point_cloud("selection_cb_b", vec![Vec3::X]);
+        deselect_structure();
+        let seen: Arc<Mutex<Vec<Selection>>> = Arc::default();
+        let sink = Arc::clone(&seen);
+        set_selection_changed_callback(move |selection| sink.lock().unwrap().push(selection));
+
+        select_structure("PointCloud", "selection_cb_a");
+        // Reselecting the same structure is not a change
+        select_structure("PointCloud", "selection_cb_a");
+        select_structure("PointCloud", "selection_cb_b");
+        deselect_structure();
+        deselect_structure();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 3);
+        assert_eq!(
+            seen[0],
+            Some(("PointCloud".to_string(), "selection_cb_a".to_string()))
+        );
+        assert_eq!(
+            seen[1],
+            Some(("PointCloud".to_string(), "selection_cb_b".to_string()))
+        );
+        assert_eq!(seen[2], None);
+
+        clear_selection_changed_callback();
+        remove_structure("selection_cb_a");
+        remove_structure("selection_cb_b");
+    }
+
+    // --- Test: Mesh decimation ---
+    {
+        let size = 8u32;
+        let row = size + 1;
+        let vertices = (0..=8u8)
+            .flat_map(|y| (0..=8u8).map(move |x| Vec3::new(f32::from(x), f32::from(y), 0.0)))
+            .collect();
+        let mut faces = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let corner = y * row + x;
+                faces.push([corner, corner + 1, corner + row + 1]);
+                faces.push([corner, corner + row + 1, corner + row]);
+            }
+        }
+        register_surface_mesh("decimate_src", vertices, faces);
+        let transform = Mat4::from_translation(Vec3::new(0.0, 0.0, 2.0));
+        set_surface_mesh_transform("decimate_src", transform);
+
+        register_decimated("decimate_src", "decimate_dst", 16).unwrap();
+        let faces = with_surface_mesh_ref("decimate_dst", SurfaceMesh::num_faces).unwrap();
+        assert!(faces < 128);
+        assert_eq!(get_surface_mesh_transform("decimate_dst"), Some(transform));
+        assert_eq!(
+            with_surface_mesh_ref("decimate_src", SurfaceMesh::num_faces),
+            Some(128)
+        );
+
+        assert!(register_decimated("decimate_src", "decimate_dst", 16).is_err());
+        assert!(register_decimated("nope", "decimate_other", 16).is_err());
+        remove_structure("decimate_src");
+        remove_structure("decimate_dst");
+    }
+
+    // --- Test: Memory report ---
+    {
+        let before = memory_report().len();
+        register_point_cloud("memory_pc", vec![Vec3::ZERO; 7])
+            .add_scalar_quantity("s", vec![0.0; 7]);
+        register_surface_mesh(
+            "memory_mesh",
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE],
+            vec![[0u32, 1, 2], [1, 3, 2]],
+        );
+        register_curve_network_line("memory_cn", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+
+        let report = memory_report();
+        assert_eq!(report.len(), before + 3);
+        let info = |name: &str| report.iter().find(|i| i.name == name).unwrap().clone();
+
+        let pc = info("memory_pc");
+        assert_eq!(pc.type_name, "PointCloud");
+        assert_eq!(
+            (pc.num_vertices, pc.num_elements, pc.num_quantities),
+            (7, 7, 1)
+        );
+        let mesh = info("memory_mesh");
+        assert_eq!(mesh.type_name, "SurfaceMesh");
+        assert_eq!(
+            (mesh.num_vertices, mesh.num_elements, mesh.num_quantities),
+            (4, 2, 0)
+        );
+        let cn = info("memory_cn");
+        assert_eq!((cn.num_vertices, cn.num_elements), (3, 2));
+        // Nothing is uploaded before the first frame
+        assert_eq!(mesh.gpu_buffer_bytes, 0);
+
+        remove_structure("memory_pc");
+        remove_structure("memory_mesh");
+        remove_structure("memory_cn");
+    }
+
+    // --- Test: Curve network node radius quantity ---
+    {
+        let cn = register_curve_network_line("node_radius_cn", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        assert!(
+            cn.add_node_scalar_radius_quantity("bad", vec![1.0])
+                .is_err()
+        );
+        cn.add_node_scalar_radius_quantity("degree", vec![1.0, 2.0, 1.0])
+            .unwrap()
+            .set_node_radius_quantity_relative("degree", false);
+        set_quantity_enabled("CurveNetwork", "node_radius_cn", "degree", true);
+        with_curve_network_ref("node_radius_cn", |cn| {
+            let q = cn.active_node_radius_quantity().unwrap();
+            assert!(!q.radius_is_relative());
+            assert_eq!(q.compute_radii(cn.node_radius()), vec![1.0, 2.0, 1.0]);
+        });
+        remove_structure("node_radius_cn");
+    }
+
+    // --- Test: Crop box ---
+    {
+        assert!(crop_box().is_none());
+        set_crop_box(Some((Vec3::ONE, -Vec3::ONE)));
+        assert_eq!(crop_box(), Some((-Vec3::ONE, Vec3::ONE)));
+        assert_eq!(crop_box_transform(), Some(Mat4::IDENTITY));
+        let rotation = Mat4::from_rotation_z(0.5);
+        set_crop_box_transform(rotation);
+        set_crop_box_widget_enabled(true);
+        with_context(|ctx| {
+            let crop_box = ctx.crop_box.as_ref().unwrap();
+            assert!(crop_box.draw_widget());
+            assert_eq!(crop_box.transform(), rotation);
+        });
+        set_crop_box(None);
+        assert!(crop_box().is_none());
+        assert!(crop_box_transform().is_none());
+    }
+
+    // --- Test: Masked scalar quantities ---
+    {
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE];
+        let mesh = register_surface_mesh(
+            "masked_mesh",
+            vertices.clone(),
+            vec![[0u32, 1, 2], [2, 1, 3]],
+        );
+        mesh.add_vertex_scalar_quantity_masked("measured", vec![Some(1.0), None, Some(2.0), None])
+            .unwrap();
+        assert!(
+            mesh.add_vertex_scalar_quantity_masked("bad", vec![None])
+                .is_err()
+        );
+        let pc = register_point_cloud("masked_pc", vertices);
+        pc.add_scalar_quantity_masked("measured", vec![None, Some(1.0), None, Some(3.0)]);
+        with_point_cloud_ref("masked_pc", |pc| {
+            let q = pc.get_quantity("measured").unwrap().as_any();
+            let sq = q
+                .downcast_ref::<polyscope_structures::point_cloud::PointCloudScalarQuantity>()
+                .unwrap();
+            assert!(sq.has_missing_values());
+            assert_eq!((sq.range_min(), sq.range_max()), (1.0, 3.0));
+        });
+        with_context(|ctx| assert_eq!(ctx.options.no_data_color, Vec4::new(0.5, 0.5, 0.5, 1.0)));
+        remove_structure("masked_mesh");
+        remove_structure("masked_pc");
+    }
+
+    // --- Test: Fit slice plane to points ---
+    {
+        let plane = fit_slice_plane_to_point("fit_plane", Vec3::ONE, Vec3::Z * 2.0);
+        assert_eq!(plane.origin(), Vec3::ONE);
+        assert_eq!(plane.normal(), Vec3::Z);
+
+        // Coplanar points on z = x + 1, whose normal is along (-1, 0, 1)
+        let points: Vec<Vec3> = [(0.0, 0.0), (1.0, 0.0), (0.0, 2.0), (2.0, 3.0), (-1.0, 1.0)]
+            .into_iter()
+            .map(|(x, y)| Vec3::new(x, y, x + 1.0))
+            .collect();
+        let plane = fit_slice_plane_to_points("fit_plane", &points).unwrap();
+        let expected = Vec3::new(-1.0, 0.0, 1.0).normalize();
+        // Oriented to match the previous +Z normal
+        assert!(
+            plane.normal().abs_diff_eq(expected, 1e-5),
+            "{}",
+            plane.normal()
+        );
+        assert!((plane.origin() - Vec3::new(0.4, 1.2, 1.4)).length() < 1e-5);
+
+        assert!(fit_slice_plane_to_points("fit_plane", &[Vec3::ZERO, Vec3::X]).is_err());
+        remove_slice_plane("fit_plane");
+    }
+
     // ========================================================================
     // CLEANUP
     // ========================================================================
@@ -135,3 +135,51 @@ fn test_show_window() {
 
     shutdown();
 }
+
+/// Compares per-frame vertex updates of a 100k-vertex mesh with
+/// `update_vertices` (full rebuild) and `update_vertex_positions_only`.
+///
+/// Measures the CPU-side work only; `update_vertices` additionally drops the
+/// GPU buffers, which are then recreated on the next frame.
+/// Run with `cargo test --release test_update_vertex_positions_timing -- --ignored`.
+#[test]
+#[ignore = "timing comparison, run in release mode"]
+fn test_update_vertex_positions_timing() {
+    use std::time::{Duration, Instant};
+
+    const SIZE: u16 = 316; // 317 x 317 = 100,489 vertices
+    const FRAMES: u16 = 10;
+
+    let row = SIZE + 1;
+    let grid = |t: f32| -> Vec<Vec3> {
+        (0..row)
+            .flat_map(|y| (0..row).map(move |x| (f32::from(x), f32::from(y))))
+            .map(|(x, y)| Vec3::new(x, y, (x * 0.1 + t).sin()))
+            .collect()
+    };
+    let row = u32::from(row);
+    let mut faces = Vec::new();
+    for y in 0..u32::from(SIZE) {
+        for x in 0..u32::from(SIZE) {
+            let corner = y * row + x;
+            faces.push(vec![corner, corner + 1, corner + row + 1, corner + row]);
+        }
+    }
+
+    let time_updates = |update: fn(&mut SurfaceMesh, Vec<Vec3>)| -> Duration {
+        let mut mesh = SurfaceMesh::new("timing", grid(0.0), faces.clone());
+        let frames: Vec<Vec<Vec3>> = (1..=FRAMES).map(|f| grid(f32::from(f) * 0.1)).collect();
+        let start = Instant::now();
+        for vertices in frames {
+            update(&mut mesh, vertices);
+        }
+        start.elapsed() / u32::from(FRAMES)
+    };
+
+    let full = time_updates(SurfaceMesh::update_vertices);
+    let positions_only = time_updates(SurfaceMesh::update_vertex_positions_only);
+    assert!(
+        positions_only < full,
+        "positions-only update ({positions_only:?}) should beat a full rebuild ({full:?})"
+    );
+}
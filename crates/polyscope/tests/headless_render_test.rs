@@ -249,8 +249,10 @@ fn headless_render_tests() {
         ];
         let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![0, 1, 3], vec![1, 2, 3], vec![0, 2, 3]];
         let mesh = register_surface_mesh("mesh_sq", vertices, faces);
-        mesh.add_vertex_scalar_quantity("vscalar", vec![0.0, 0.5, 1.0, 0.75]);
-        mesh.add_face_scalar_quantity("fscalar", vec![0.1, 0.4, 0.7, 1.0]);
+        mesh.add_vertex_scalar_quantity("vscalar", vec![0.0, 0.5, 1.0, 0.75])
+            .unwrap();
+        mesh.add_face_scalar_quantity("fscalar", vec![0.1, 0.4, 0.7, 1.0])
+            .unwrap();
         mesh.add_vertex_color_quantity(
             "vcolor",
             vec![
@@ -259,7 +261,8 @@ fn headless_render_tests() {
                 Vec3::new(0.0, 0.0, 1.0),
                 Vec3::new(1.0, 1.0, 0.0),
             ],
-        );
+        )
+        .unwrap();
         mesh.add_face_color_quantity(
             "fcolor",
             vec![
@@ -268,7 +271,8 @@ fn headless_render_tests() {
                 Vec3::new(0.0, 0.0, 1.0),
                 Vec3::new(1.0, 1.0, 0.0),
             ],
-        );
+        )
+        .unwrap();
 
         let pixels = render_to_image(400, 300).expect("mesh scalar+color render failed");
         assert!(
@@ -291,8 +295,10 @@ fn headless_render_tests() {
         mesh.add_vertex_vector_quantity(
             "vvec",
             vec![Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE.normalize()],
-        );
-        mesh.add_face_vector_quantity("fvec", vec![Vec3::Z, Vec3::Z, Vec3::Y, Vec3::X]);
+        )
+        .unwrap();
+        mesh.add_face_vector_quantity("fvec", vec![Vec3::Z, Vec3::Z, Vec3::Y, Vec3::X])
+            .unwrap();
         mesh.add_vertex_parameterization_quantity(
             "vparam",
             vec![
@@ -301,7 +307,8 @@ fn headless_render_tests() {
                 Vec2::new(0.5, 1.0),
                 Vec2::new(0.5, 0.5),
             ],
-        );
+        )
+        .unwrap();
 
         let pixels = render_to_image(400, 300).expect("mesh vector+param render failed");
         assert!(
@@ -458,7 +465,7 @@ fn headless_render_tests() {
             vec![3, 2, 4],    // triangle
         ];
         let mesh = register_surface_mesh("poly_mesh", vertices, faces);
-        mesh.add_face_scalar_quantity("fs", vec![0.3, 0.8]);
+        mesh.add_face_scalar_quantity("fs", vec![0.3, 0.8]).unwrap();
 
         let pixels = render_to_image(400, 300).expect("polygon mesh render failed");
         assert!(
@@ -492,7 +499,8 @@ fn headless_render_tests() {
                 Vec3::new(0.0, 1.0, 0.0),
                 Vec3::new(0.0, 0.0, 1.0),
             ],
-        );
+        )
+        .unwrap();
 
         // Curve network
         register_curve_network(
@@ -522,6 +530,765 @@ fn headless_render_tests() {
         );
     }
 
+    // --- Test 19: In-memory RGBA capture of a cube ---
+    {
+        remove_all_structures();
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+            Vec3::new(0.0, 1.0, 1.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![
+            vec![0, 3, 2, 1],
+            vec![4, 5, 6, 7],
+            vec![0, 1, 5, 4],
+            vec![2, 3, 7, 6],
+            vec![0, 4, 7, 3],
+            vec![1, 2, 6, 5],
+        ];
+        register_surface_mesh("test_cube", vertices, faces);
+
+        let (width, height, pixels) = capture_screenshot_rgba().expect("in-memory capture failed");
+        assert!(!pixels.is_empty());
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+        assert!(
+            has_nontrivial_content(&pixels, width, height),
+            "cube capture should produce non-trivial output"
+        );
+
+        let (width, height, pixels) = capture_screenshot_rgba_with_options(ScreenshotOptions {
+            transparent_background: true,
+            ..Default::default()
+        })
+        .expect("transparent in-memory capture failed");
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+        assert!(
+            pixels.chunks(4).any(|px| px[3] == 0),
+            "transparent capture should contain background pixels with zero alpha"
+        );
+    }
+
+    // --- Test 20: PNG-encoded headless render ---
+    {
+        remove_all_structures();
+        let empty_png = render_headless(200, 150).expect("empty PNG render failed");
+        assert!(
+            empty_png.starts_with(b"\x89PNG"),
+            "output should be PNG-encoded"
+        );
+
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.5, 1.0, 0.0),
+            Vec3::new(0.5, 0.5, 1.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![0, 1, 3], vec![1, 2, 3], vec![0, 2, 3]];
+        register_surface_mesh("png_mesh", vertices, faces);
+
+        let mesh_png = render_headless(200, 150).expect("mesh PNG render failed");
+        assert!(
+            mesh_png.starts_with(b"\x89PNG"),
+            "output should be PNG-encoded"
+        );
+        assert_ne!(
+            empty_png, mesh_png,
+            "mesh render should differ from empty scene"
+        );
+    }
+
+    // --- Test 21: Volume mesh slice cap colored by a vertex scalar ---
+    {
+        remove_all_structures();
+        let vertices = vec![
+            Vec3::new(-1.0, -1.0, -1.0),
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(0.0, 1.0, -1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+        register_tet_mesh("slice_tet", vertices, vec![[0, 1, 2, 3]])
+            .add_vertex_scalar_quantity("z", vec![-1.0, -1.0, -1.0, 1.0]);
+        with_volume_mesh("slice_tet", |vm| {
+            vm.get_quantity_mut("z").unwrap().set_enabled(true);
+        });
+        // The tet's centroid is behind the plane, so only the cap at z = 0 remains
+        add_slice_plane_with_pose("slice_cap_plane", Vec3::ZERO, Vec3::Z).set_draw_plane(false);
+
+        let (width, height) = (200, 150);
+        let pixels = render_to_image(width, height).expect("volume mesh slice render failed");
+        let center = ((height / 2 * width + width / 2) * 4) as usize;
+        let [r, g, b, _] = [
+            pixels[center],
+            pixels[center + 1],
+            pixels[center + 2],
+            pixels[center + 3],
+        ];
+        // viridis(0.5) is teal: green dominates red
+        assert!(
+            u32::from(g) > u32::from(r) + 30 && u32::from(b) > u32::from(r),
+            "slice cap should show the mapped scalar, got ({r}, {g}, {b})"
+        );
+        remove_all_slice_planes();
+    }
+
+    // --- Test 22: SSAO darkens the creases of an inside corner ---
+    {
+        remove_all_structures();
+        // Floor and two walls meeting at the origin
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 1.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![vec![0, 3, 2, 1], vec![0, 1, 5, 4], vec![0, 4, 6, 3]];
+        register_surface_mesh("ssao_corner", vertices, faces);
+        set_ground_plane_mode(GroundPlaneMode::None);
+
+        let (width, height) = (200, 150);
+        set_ssao_enabled(false);
+        let plain = render_to_image(width, height).expect("render without SSAO failed");
+        set_ssao_enabled(true);
+        let occluded = render_to_image(width, height).expect("render with SSAO failed");
+        set_ssao_enabled(false);
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+
+        assert!(has_nontrivial_content(&plain, width, height));
+        assert_ne!(
+            plain, occluded,
+            "SSAO should change the shading of the corner"
+        );
+    }
+
+    // --- Test 23: Tone mapping operator changes highlights ---
+    {
+        remove_all_structures();
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        register_surface_mesh("bright_quad", vertices, vec![vec![0, 1, 2, 3]])
+            .set_surface_color(Vec3::new(1.0, 0.9, 0.6));
+        set_exposure(3.0);
+
+        let (width, height) = (200, 150);
+        set_tone_map_operator(ToneMapOperator::None);
+        let clamped = render_to_image(width, height).expect("render without tone mapping failed");
+        set_tone_map_operator(ToneMapOperator::Aces);
+        let aces = render_to_image(width, height).expect("ACES render failed");
+        set_tone_map_operator(ToneMapOperator::Reinhard);
+        set_exposure(ToneMappingConfig::default().exposure);
+
+        // The over-exposed quad covers the center of the image
+        let center = ((height / 2 * width + width / 2) * 4) as usize;
+        assert_ne!(
+            clamped[center..center + 3],
+            aces[center..center + 3],
+            "ACES should compress the highlight differently than plain clamping"
+        );
+    }
+
+    // --- Test 24: Camera view image on the image plane ---
+    {
+        remove_all_structures();
+        // Image frame at z = 0, facing the default viewer on +Z
+        let cam = register_camera_view_look_at(
+            "imaged_cam",
+            Vec3::new(0.0, 0.0, 5.0),
+            Vec3::ZERO,
+            Vec3::Y,
+            60.0,
+            1.0,
+        );
+        cam.set_widget_focal_length(5.0, false);
+        // Frames the scene around the image
+        register_point_cloud(
+            "image_extent",
+            vec![Vec3::new(-1.0, -1.0, 0.0), Vec3::new(1.0, 1.0, 0.0)],
+        );
+        set_ground_plane_mode(GroundPlaneMode::None);
+
+        // 3x3 checkerboard: red center cell, blue neighbors
+        let mut rgba = Vec::new();
+        for row in 0..3 {
+            for col in 0..3 {
+                let red = (row + col) % 2 == 0;
+                rgba.extend_from_slice(if red {
+                    &[255, 0, 0, 255]
+                } else {
+                    &[0, 0, 255, 255]
+                });
+            }
+        }
+        assert!(cam.add_image(3, 2, rgba.clone()).is_err());
+        cam.add_image(3, 3, rgba).expect("valid image rejected");
+        assert!(cam.has_image());
+
+        let (width, height) = (200, 150);
+        let pixels = render_to_image(width, height).expect("camera image render failed");
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+
+        // Slightly right of center: inside the center cell, clear of the
+        // frustum lines that meet at the projected camera origin
+        let at = ((height / 2 * width + width / 2 + 8) * 4) as usize;
+        let px = &pixels[at..at + 4];
+        assert!(
+            px[0] > 100 && px[0] > 2 * px[2].max(1),
+            "expected the red center cell of the image, got {px:?}"
+        );
+    }
+
+    // --- Test 25: Depth rendered from a camera view ---
+    {
+        remove_all_structures();
+        let vertices = vec![
+            Vec3::new(-10.0, -10.0, 0.0),
+            Vec3::new(10.0, -10.0, 0.0),
+            Vec3::new(10.0, 10.0, 0.0),
+            Vec3::new(-10.0, 10.0, 0.0),
+        ];
+        register_surface_mesh("depth_plane", vertices, vec![vec![0, 1, 2, 3]]);
+        let cam = register_camera_view_look_at(
+            "depth_cam",
+            Vec3::new(0.0, 0.0, 5.0),
+            Vec3::ZERO,
+            Vec3::Y,
+            60.0,
+            4.0 / 3.0,
+        );
+
+        let (width, height) = (120, 90);
+        let depth = cam
+            .render_depth(width, height)
+            .expect("depth render failed");
+        assert_eq!(depth.len(), (width * height) as usize);
+
+        // Eye-space depth of a plane facing the camera is the same at the
+        // center and off-center
+        let center = depth[(height / 2 * width + width / 2) as usize];
+        let off_center = depth[(height / 4 * width + width / 4) as usize];
+        assert!((center - 5.0).abs() < 1e-2, "center depth {center}");
+        assert!(
+            (off_center - 5.0).abs() < 1e-2,
+            "off-center depth {off_center}"
+        );
+    }
+
+    // --- Test 26: Supersampling anti-aliasing ---
+    {
+        // The engine keeps its SSAA factor across resizes
+        let mut engine = pollster::block_on(RenderEngine::new_headless(64, 48))
+            .expect("headless engine creation failed");
+        engine.set_ssaa_factor(2);
+        engine.resize(80, 60);
+        assert_eq!(engine.ssaa_factor(), 2);
+        assert_eq!(engine.dimensions(), (80, 60));
+
+        // Screenshots are downsampled to the requested size
+        remove_all_structures();
+        register_point_cloud("ssaa_points", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        set_antialiasing(4);
+        let pixels = render_to_image(120, 90).expect("SSAA render failed");
+        assert!(has_nontrivial_content(&pixels, 120, 90));
+        set_antialiasing(1);
+    }
+
+    // --- Test 27: Background color and transparent background ---
+    {
+        remove_all_structures();
+        set_background_color(Vec3::new(1.0, 0.0, 0.0));
+        let pixels = render_to_image(64, 48).expect("background render failed");
+        let corner = &pixels[0..4];
+        assert!(
+            corner[0] > 200 && corner[1] < 30 && corner[2] < 30 && corner[3] == 255,
+            "corner pixel should be opaque red, got {corner:?}"
+        );
+
+        set_transparent_background(true);
+        let pixels = render_to_image(64, 48).expect("transparent background render failed");
+        assert_eq!(pixels[3], 0, "corner pixel should be transparent");
+
+        set_transparent_background(false);
+        set_background_color(Vec3::splat(0.1));
+    }
+
+    // --- Test 28: Custom matcap material from RGBA pixels ---
+    {
+        remove_all_structures();
+        load_matcap_material("solid_red", &[255, 0, 0, 255].repeat(4), 2, 2)
+            .expect("valid matcap image");
+        register_surface_mesh(
+            "matcap_mesh",
+            vec![
+                Vec3::new(-1.0, -1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+                Vec3::Y,
+            ],
+            vec![vec![0u32, 1, 2]],
+        );
+        set_structure_material("SurfaceMesh", "matcap_mesh", "solid_red");
+        render_to_image(64, 48).expect("custom matcap render failed");
+        assert!(available_materials().iter().any(|m| m == "solid_red"));
+        assert_eq!(
+            get_structure_material("SurfaceMesh", "matcap_mesh").as_deref(),
+            Some("solid_red")
+        );
+    }
+
+    // --- Test 29: Picking at a pixel ---
+    {
+        remove_all_structures();
+        register_surface_mesh(
+            "pick_mesh",
+            vec![
+                Vec3::new(-1.0, -1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(-1.0, 1.0, 0.0),
+            ],
+            vec![[0u32, 1, 2], [0, 2, 3]],
+        );
+
+        // The camera is fitted to the mesh, which covers the frame center
+        let pick = pick_at(640.0, 360.0).expect("center pixel should hit the mesh");
+        assert_eq!(pick.structure_type, "SurfaceMesh");
+        assert_eq!(pick.structure_name, "pick_mesh");
+        assert!(pick.element_index < 2);
+        assert!(pick.world_position.z.abs() < 1e-3);
+        assert!(pick.barycentric.is_some());
+
+        // Outside the frame nothing is picked
+        assert!(pick_at(-1.0, 360.0).is_none());
+    }
+
+    // --- Test 30: Isolines on a vertex scalar ramp ---
+    {
+        remove_all_structures();
+        let mesh = register_surface_mesh(
+            "ramp_mesh",
+            vec![
+                Vec3::new(-1.0, -1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(-1.0, 1.0, 0.0),
+            ],
+            vec![[0u32, 1, 2], [0, 2, 3]],
+        );
+        mesh.add_vertex_scalar_quantity("ramp", vec![-1.0, 1.0, 1.0, -1.0])
+            .expect("valid scalar");
+        set_quantity_enabled("SurfaceMesh", "ramp_mesh", "ramp", true);
+
+        let (width, height) = (128, 96);
+        let row_luminance = |pixels: &[u8]| -> Vec<u32> {
+            let row = (height / 2 * width) as usize * 4;
+            pixels[row..row + width as usize * 4]
+                .chunks(4)
+                .map(|p| u32::from(p[0]) + u32::from(p[1]) + u32::from(p[2]))
+                .collect()
+        };
+        let plain = row_luminance(&render_to_image(width, height).expect("ramp render failed"));
+
+        mesh.set_isolines_enabled("ramp", true)
+            .set_isoline_count("ramp", 4)
+            .set_isoline_width("ramp", 2.0);
+        assert!(mesh.isolines_enabled("ramp"));
+        let banded = row_luminance(&render_to_image(width, height).expect("isoline render failed"));
+
+        // Isolines darken a few narrow bands across the ramp, not the whole row
+        let darkened: Vec<usize> = (0..width as usize)
+            .filter(|&x| banded[x] * 10 < plain[x] * 7)
+            .collect();
+        let bands = darkened.windows(2).filter(|w| w[1] != w[0] + 1).count() + 1;
+        assert!(!darkened.is_empty(), "isolines should darken some pixels");
+        assert!(bands >= 3, "expected distinct isoline bands, got {bands}");
+        assert!(darkened.len() < width as usize / 2);
+    }
+
+    // --- Test 31: Structure bounding box wireframes ---
+    {
+        let mut engine = pollster::block_on(RenderEngine::new_headless(64, 48))
+            .expect("headless engine creation failed");
+        let boxes = [
+            (Vec3::ZERO, Vec3::ONE),
+            (Vec3::splat(-2.0), Vec3::splat(-1.0)),
+        ];
+        engine.update_bounding_box_wireframes(&boxes);
+        assert_eq!(engine.bounding_box_wireframe_edge_count(), 24);
+        engine.update_bounding_box_wireframes(&boxes[..1]);
+        assert_eq!(engine.bounding_box_wireframe_edge_count(), 12);
+        engine.update_bounding_box_wireframes(&[]);
+        assert_eq!(engine.bounding_box_wireframe_edge_count(), 0);
+
+        remove_all_structures();
+        register_point_cloud("bbox_cloud", vec![Vec3::ZERO, Vec3::ONE]);
+        set_structure_bbox_visible("PointCloud", "bbox_cloud", true);
+        let pixels = render_to_image(64, 48).expect("bbox render failed");
+        assert!(has_nontrivial_content(&pixels, 64, 48));
+    }
+
+    // --- Test 32: Structure ignoring slice planes renders uncut ---
+    {
+        remove_all_structures();
+        register_surface_mesh(
+            "uncut_mesh",
+            vec![
+                Vec3::new(-1.0, -1.0, 0.0),
+                Vec3::new(1.0, -1.0, 0.0),
+                Vec3::new(1.0, 1.0, 0.0),
+                Vec3::new(-1.0, 1.0, 0.0),
+            ],
+            vec![[0u32, 1, 2], [0, 2, 3]],
+        );
+        let (width, height) = (96, 72);
+        let uncut = render_to_image(width, height).expect("uncut render failed");
+
+        // The whole mesh lies behind this plane
+        add_slice_plane_with_pose("ignore_plane", Vec3::Z, Vec3::Z).set_draw_plane(false);
+        let cut = render_to_image(width, height).expect("cut render failed");
+        assert_ne!(cut, uncut, "the plane should cut the mesh away");
+
+        set_ignore_slice_planes("SurfaceMesh", "uncut_mesh", true);
+        assert!(get_ignore_slice_planes("SurfaceMesh", "uncut_mesh"));
+        let flagged = render_to_image(width, height).expect("flagged render failed");
+        assert_eq!(flagged, uncut, "a flagged mesh should render uncut");
+        remove_all_slice_planes();
+    }
+
+    // --- Test 33: Depth of field blurs far geometry, keeps the focal plane sharp ---
+    {
+        remove_all_structures();
+        set_ground_plane_mode(GroundPlaneMode::None);
+        let quad = |x0: f32, x1: f32, half_height: f32, z: f32| {
+            vec![
+                Vec3::new(x0, -half_height, z),
+                Vec3::new(x1, -half_height, z),
+                Vec3::new(x1, half_height, z),
+                Vec3::new(x0, half_height, z),
+            ]
+        };
+        // In-focus quad on the left at depth 5, far quad on the right at depth 15
+        register_surface_mesh(
+            "dof_near",
+            quad(-1.5, -0.2, 1.0, 0.0),
+            vec![vec![0u32, 1, 2, 3]],
+        );
+        register_surface_mesh(
+            "dof_far",
+            quad(1.0, 8.0, 5.0, -10.0),
+            vec![vec![0u32, 1, 2, 3]],
+        );
+
+        let camera_path = std::env::temp_dir().join("polyscope_dof_camera.json");
+        let camera = Camera {
+            position: Vec3::new(0.0, 0.0, 5.0),
+            target: Vec3::ZERO,
+            ..Camera::default()
+        };
+        std::fs::write(&camera_path, camera.to_json()).unwrap();
+        let (width, height) = (160, 120);
+        let render = || {
+            load_camera_view(&camera_path).unwrap();
+            render_to_image(width, height).expect("depth of field render failed")
+        };
+
+        let sharp = render();
+        set_dof(true, 5.0, 8.0);
+        assert!(get_dof_config().enabled);
+        let blurred = render();
+        set_dof(false, 5.0, 8.0);
+        std::fs::remove_file(&camera_path).unwrap();
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+
+        let differs = |x0: u32, x1: u32, y0: u32, y1: u32| {
+            (y0..y1)
+                .flat_map(|y| (x0..x1).map(move |x| ((y * width + x) * 4) as usize))
+                .any(|i| sharp[i..i + 4] != blurred[i..i + 4])
+        };
+        // The near quad spans roughly x in [37, 75], y in [31, 89]
+        assert!(
+            !differs(42, 70, 36, 84),
+            "the focal plane should stay sharp"
+        );
+        // The far quad's edges are blurred into the background
+        assert!(
+            differs(80, width, 0, height),
+            "far geometry should be blurred"
+        );
+    }
+
+    // --- Test 34: Turntable writes one distinct frame per orbit step ---
+    {
+        remove_all_structures();
+        register_surface_mesh(
+            "turntable_mesh",
+            vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(0.0, 1.0, 0.5),
+            ],
+            vec![vec![0u32, 1, 2]],
+        );
+
+        let dir = std::env::temp_dir().join("polyscope_turntable_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths =
+            render_turntable(4, &dir, ScreenshotOptions::default()).expect("turntable failed");
+        assert_eq!(paths.len(), 4);
+        let frames: Vec<Vec<u8>> = paths
+            .iter()
+            .map(|path| std::fs::read(path).expect("missing turntable frame"))
+            .collect();
+        assert!(dir.join("frame_0000.png").exists());
+        assert!(dir.join("frame_0003.png").exists());
+        for (i, a) in frames.iter().enumerate() {
+            for b in &frames[i + 1..] {
+                assert_ne!(a, b, "turntable frames should differ");
+            }
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // --- Test 35: Volume grid cube visualization colors cells by the color map ---
+    {
+        remove_all_structures();
+        set_ground_plane_mode(GroundPlaneMode::None);
+        // 3x3x3 cells with a gradient along x: the center column maps to t = 0.5
+        let vg = register_volume_grid(
+            "voxels",
+            glam::UVec3::splat(4),
+            Vec3::splat(-1.5),
+            Vec3::splat(1.5),
+        );
+        let values: Vec<f32> = (0..27u8).map(|c| f32::from(c % 3)).collect();
+        vg.add_cell_scalar_quantity("x", values);
+        vg.set_quantity_enabled("x", true);
+        vg.set_color_map("x", "viridis");
+        vg.set_cube_visualization(true);
+
+        let (width, height) = (200, 150);
+        let center = ((height / 2 * width + width / 2) * 4) as usize;
+        vg.set_cube_transparency(0.0);
+        let opaque = render_to_image(width, height).expect("voxel render failed");
+        vg.set_cube_transparency(0.5);
+        let see_through = render_to_image(width, height).expect("voxel render failed");
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+
+        // Compare hues: lighting and tone mapping change brightness, not the
+        // closest color map sample
+        let direction = |c: Vec3| c / c.length().max(1e-6);
+        let px = &opaque[center..center + 3];
+        let rendered = direction(Vec3::new(
+            f32::from(px[0]),
+            f32::from(px[1]),
+            f32::from(px[2]),
+        ));
+        let registry = ColorMapRegistry::new();
+        let viridis = registry.get("viridis").unwrap();
+        let closest = [0.0, 0.5, 1.0]
+            .into_iter()
+            .min_by(|&a, &b| {
+                let da = (direction(viridis.sample(a)) - rendered).length();
+                let db = (direction(viridis.sample(b)) - rendered).length();
+                da.total_cmp(&db)
+            })
+            .unwrap();
+        assert!(
+            (closest - 0.5).abs() < 1e-6,
+            "center voxel should show viridis(0.5), got {px:?}"
+        );
+        assert_ne!(
+            &opaque[center..center + 4],
+            &see_through[center..center + 4],
+            "transparent cubes should let the interior show through"
+        );
+    }
+
+    // --- Test 36: Normal G-buffer readback of a quad facing the camera ---
+    {
+        remove_all_structures();
+        // The fitted camera looks down -Z, so this counter-clockwise quad faces it
+        let vertices = vec![
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![0, 2, 3]];
+        register_surface_mesh("normal_quad", vertices, faces);
+
+        let (width, height, normals) = capture_normals().expect("normal capture failed");
+        assert_eq!(normals.len(), (width * height) as usize);
+        let center = normals[(height / 2 * width + width / 2) as usize];
+        assert!(
+            center[2] > 0.99 && center[0].abs() < 0.01 && center[1].abs() < 0.01,
+            "quad normal should point toward +Z in view space, got {center:?}"
+        );
+        assert!(
+            center[3] > 0.99,
+            "quad should be fully covered, got {center:?}"
+        );
+        assert!(normals[0][3].abs() < 1e-3, "corner should be background");
+    }
+
+    // --- Test 37: Orthographic capture at an exact pixel-to-world scale ---
+    {
+        remove_all_structures();
+        set_ground_plane_mode(GroundPlaneMode::None);
+        let vertices = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![0, 2, 3]];
+        register_surface_mesh("unit_square", vertices, faces);
+
+        // 200 px covering 4 world units: the unit square spans 50 px
+        let (width, height, pixels) = capture_screenshot_rgba_with_options(ScreenshotOptions {
+            transparent_background: true,
+            size: Some((200, 200)),
+            ortho_world_height: Some(4.0),
+        })
+        .expect("orthographic capture failed");
+        assert_eq!((width, height), (200, 200));
+
+        let covered = |x: u32, y: u32| pixels[((y * width + x) * 4 + 3) as usize] > 127;
+        let columns = (0..width)
+            .filter(|&x| (0..height).any(|y| covered(x, y)))
+            .count();
+        let rows = (0..height)
+            .filter(|&y| (0..width).any(|x| covered(x, y)))
+            .count();
+        assert!(
+            columns.abs_diff(50) <= 1 && rows.abs_diff(50) <= 1,
+            "unit square should span 50x50 px, got {columns}x{rows}"
+        );
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+    }
+
+    // --- Test 38: Memory report counts GPU buffers after a render ---
+    {
+        remove_all_structures();
+        let vertices = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+        register_surface_mesh("memory_tri", vertices, vec![[0u32, 1, 2]]);
+        render_to_image(64, 64).expect("render failed");
+
+        let report = memory_report();
+        assert_eq!(report.len(), 1);
+        assert_eq!((report[0].num_vertices, report[0].num_elements), (3, 1));
+        // At least the three positions, as vec4s
+        assert!(
+            report[0].gpu_buffer_bytes >= 3 * 16,
+            "expected uploaded buffers, got {} bytes",
+            report[0].gpu_buffer_bytes
+        );
+    }
+
+    // --- Test 39: Node radius quantity fills the node radius buffer ---
+    {
+        remove_all_structures();
+        let cn = register_curve_network_line("radius_nodes", vec![Vec3::ZERO, Vec3::X, Vec3::Y]);
+        cn.add_node_scalar_radius_quantity("degree", vec![1.0, 2.0, 1.0])
+            .unwrap();
+        set_quantity_enabled("CurveNetwork", "radius_nodes", "degree", true);
+        render_to_image(64, 64).expect("render failed");
+
+        let buffer_size = with_curve_network_ref("radius_nodes", |cn| {
+            cn.render_data()
+                .and_then(|rd| rd.node_radius_buffer.as_ref())
+                .map(wgpu::Buffer::size)
+        })
+        .flatten();
+        assert_eq!(buffer_size, Some(3 * 4));
+    }
+
+    // --- Test 40: Crop box hides geometry outside of it ---
+    {
+        remove_all_structures();
+        set_ground_plane_mode(GroundPlaneMode::None);
+        let vertices = vec![
+            Vec3::new(-1.0, -0.5, 0.0),
+            Vec3::new(1.0, -0.5, 0.0),
+            Vec3::new(1.0, 0.5, 0.0),
+            Vec3::new(-1.0, 0.5, 0.0),
+        ];
+        let faces: Vec<Vec<u32>> = vec![vec![0, 1, 2], vec![0, 2, 3]];
+        register_surface_mesh("crop_strip", vertices, faces);
+
+        // 200 px covering 4 world units: the strip spans 100 px, and the half
+        // left of x = 0 inside the box spans 50 px
+        let covered_columns = || {
+            let (width, height, pixels) = capture_screenshot_rgba_with_options(ScreenshotOptions {
+                transparent_background: true,
+                size: Some((200, 200)),
+                ortho_world_height: Some(4.0),
+            })
+            .expect("orthographic capture failed");
+            (0..width)
+                .filter(|&x| (0..height).any(|y| pixels[((y * width + x) * 4 + 3) as usize] > 127))
+                .count()
+        };
+        let full = covered_columns();
+        set_crop_box(Some((Vec3::splat(-2.0), Vec3::new(0.0, 2.0, 2.0))));
+        let cropped = covered_columns();
+        set_crop_box(None);
+        assert!(
+            full.abs_diff(100) <= 1 && cropped.abs_diff(50) <= 1,
+            "strip should span 100 px, and 50 px when cropped, got {full} and {cropped}"
+        );
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+    }
+
+    // --- Test 41: Updated scalar values reach the GPU buffers ---
+    {
+        remove_all_structures();
+        set_ground_plane_mode(GroundPlaneMode::None);
+        let vertices = vec![
+            Vec3::new(-1.0, -1.0, 0.0),
+            Vec3::new(1.0, -1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(-1.0, 1.0, 0.0),
+        ];
+        register_surface_mesh("update_values_mesh", vertices, vec![vec![0, 1, 2, 3]])
+            .add_vertex_scalar_quantity("s", vec![0.0, 0.0, 1.0, 1.0])
+            .unwrap();
+        set_quantity_enabled("SurfaceMesh", "update_values_mesh", "s", true);
+
+        let capture = || {
+            capture_screenshot_rgba_with_options(ScreenshotOptions {
+                transparent_background: true,
+                size: Some((64, 64)),
+                ortho_world_height: Some(3.0),
+            })
+            .expect("scalar capture failed")
+            .2
+        };
+        let before = capture();
+        update_scalar_quantity(
+            "SurfaceMesh",
+            "update_values_mesh",
+            "s",
+            vec![1.0, 1.0, 0.0, 0.0],
+        )
+        .unwrap();
+        let after = capture();
+        assert_ne!(
+            before, after,
+            "updated values should change the drawn colors"
+        );
+        set_ground_plane_mode(GroundPlaneMode::Tile);
+    }
+
     // Clean up
     remove_all_structures();
 }
@@ -55,7 +55,8 @@ fn main() {
 
         // Add node scalar quantity (height along helix)
         let node_heights: Vec<f32> = nodes.iter().map(|n| n.y).collect();
-        c.add_node_scalar_quantity("height", node_heights);
+        c.add_node_scalar_quantity("height", node_heights)
+            .expect("one height per node");
     });
 
     // Create a circle as a loop
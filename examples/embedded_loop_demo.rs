@@ -0,0 +1,88 @@
+//! Embedding the viewer in an application-owned winit event loop.
+//!
+//! Instead of calling `show()`, the application creates the window itself,
+//! attaches polyscope to it, forwards window events and renders with
+//! `frame_tick()` whenever the window needs redrawing.
+//!
+//! Run with: cargo run --example `embedded_loop_demo`
+
+use std::sync::Arc;
+
+use glam::Vec3;
+use polyscope_rs::winit::{
+    application::ApplicationHandler,
+    dpi::LogicalSize,
+    event::WindowEvent,
+    event_loop::{ActiveEventLoop, EventLoop},
+    window::{Window, WindowId},
+};
+
+/// The host application: owns the window and decides when to exit.
+#[derive(Default)]
+struct Host {
+    window: Option<Arc<Window>>,
+}
+
+impl ApplicationHandler for Host {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        if self.window.is_some() {
+            return;
+        }
+        let attributes = Window::default_attributes()
+            .with_title("Host application")
+            .with_inner_size(LogicalSize::new(1024, 768));
+        let window = Arc::new(
+            event_loop
+                .create_window(attributes)
+                .expect("failed to create window"),
+        );
+        polyscope_rs::attach_window(window.clone()).expect("failed to attach polyscope");
+        window.request_redraw();
+        self.window = Some(window);
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        // Polyscope needs input, resize and focus events for navigation and UI
+        polyscope_rs::process_window_event(&event);
+
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::RedrawRequested => {
+                // Host-side per-frame work would go here
+                if !polyscope_rs::frame_tick() {
+                    event_loop.exit();
+                }
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    env_logger::init();
+    polyscope_rs::init().expect("Failed to initialize polyscope");
+
+    let helix: Vec<Vec3> = (0..200_u8)
+        .map(|i| {
+            let t = f32::from(i) * 0.1;
+            Vec3::new(t.cos(), t * 0.1, t.sin())
+        })
+        .collect();
+    let curve = polyscope_rs::register_curve_network_line("helix", helix);
+
+    // Animate from the user callback, which frame_tick runs every frame
+    polyscope_rs::set_user_callback(move || {
+        let t = polyscope_rs::elapsed_seconds();
+        curve.set_color(Vec3::new(0.5 + 0.5 * t.sin(), 0.4, 0.5 + 0.5 * t.cos()));
+    });
+
+    let event_loop = EventLoop::new().expect("failed to create event loop");
+    event_loop
+        .run_app(&mut Host::default())
+        .expect("event loop error");
+
+    polyscope_rs::detach_window();
+}
@@ -283,7 +283,9 @@ fn scene_surface_mesh() {
         .collect();
 
     let handle = polyscope_rs::register_surface_mesh("spot", verts, faces);
-    handle.add_vertex_color_quantity("height_color", colors);
+    handle
+        .add_vertex_color_quantity("height_color", colors)
+        .expect("one color per vertex");
 }
 
 /// Screenshot 4: Curve network
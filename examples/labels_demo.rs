@@ -0,0 +1,37 @@
+//! Text Labels Demo
+//!
+//! Labels the corners of a cube with their coordinates and names its center.
+//! Labels stay facing the camera while orbiting and disappear when their
+//! anchor moves behind the camera.
+//!
+//! Run with: cargo run --example `labels_demo`
+
+use glam::Vec3;
+
+fn main() -> polyscope_rs::Result<()> {
+    polyscope_rs::init()?;
+
+    let mut corners = Vec::new();
+    for x in [-1.0, 1.0] {
+        for y in [-1.0, 1.0] {
+            for z in [-1.0, 1.0] {
+                corners.push(Vec3::new(x, y, z));
+            }
+        }
+    }
+    polyscope_rs::register_point_cloud("corners", corners.clone());
+
+    for (i, corner) in corners.iter().enumerate() {
+        polyscope_rs::add_label(
+            format!("corner {i}"),
+            *corner,
+            format!("({}, {}, {})", corner.x, corner.y, corner.z),
+        );
+    }
+
+    polyscope_rs::add_label("center", Vec3::ZERO, "center");
+    polyscope_rs::set_label_color("center", Vec3::new(1.0, 0.8, 0.2));
+
+    polyscope_rs::show();
+    Ok(())
+}
@@ -142,6 +142,15 @@ fn main() {
         .collect();
     sphere.add_color_quantity("shell_color", shell_colors);
 
+    // Radius quantity: points shrink toward the center (relative to point radius)
+    let shell_radii: Vec<f32> = sphere_points
+        .iter()
+        .map(|p| (*p - sphere_center).length())
+        .collect();
+    sphere
+        .add_scalar_radius_quantity("shell_radius", shell_radii)
+        .expect("one radius per point");
+
     // === Point Cloud 3: Grid sampling for visualization ===
     let grid_origin = Vec3::new(-3.0, 0.0, 0.0);
     let mut grid_points = Vec::new();
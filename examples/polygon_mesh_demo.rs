@@ -158,7 +158,8 @@ fn main() {
         .map(|i| i as f32 / faces.len() as f32)
         .collect();
     polyscope_rs::with_surface_mesh("quad_cube", |mesh| {
-        mesh.add_face_scalar_quantity("face_id", face_scalars);
+        mesh.add_face_scalar_quantity("face_id", face_scalars)
+            .expect("one id per face");
     });
 
     // 2. Hexagonal prism — top and bottom are hexagons, sides are quads
@@ -173,7 +174,8 @@ fn main() {
         .map(|i| i as f32 / faces.len() as f32)
         .collect();
     polyscope_rs::with_surface_mesh("hex_prism", |mesh| {
-        mesh.add_face_scalar_quantity("face_id", face_scalars);
+        mesh.add_face_scalar_quantity("face_id", face_scalars)
+            .expect("one id per face");
     });
 
     // 3. Octagonal prism — top and bottom are octagons
@@ -188,7 +190,8 @@ fn main() {
         .map(|i| i as f32 / faces.len() as f32)
         .collect();
     polyscope_rs::with_surface_mesh("oct_prism", |mesh| {
-        mesh.add_face_scalar_quantity("face_id", face_scalars);
+        mesh.add_face_scalar_quantity("face_id", face_scalars)
+            .expect("one id per face");
     });
 
     // 4. Truncated pyramid — pentagons + quads mixed
@@ -203,7 +206,8 @@ fn main() {
         .map(|i| i as f32 / faces.len() as f32)
         .collect();
     polyscope_rs::with_surface_mesh("truncated_pyramid", |mesh| {
-        mesh.add_face_scalar_quantity("face_id", face_scalars);
+        mesh.add_face_scalar_quantity("face_id", face_scalars)
+            .expect("one id per face");
     });
 
     println!("Polygon Mesh Demo");
@@ -95,7 +95,8 @@ fn main() {
     polyscope_rs::with_surface_mesh("bunny", |mesh| {
         // Add vertex height scalar quantity (Y coordinate)
         let vertex_heights: Vec<f32> = vertices.iter().map(|v| v.y).collect();
-        mesh.add_vertex_scalar_quantity("height", vertex_heights);
+        mesh.add_vertex_scalar_quantity("height", vertex_heights)
+            .expect("one height per vertex");
 
         // Add vertex colors based on position
         let y_min = vertices.iter().map(|v| v.y).fold(f32::INFINITY, f32::min);
@@ -110,27 +111,32 @@ fn main() {
                 Vec3::new(t, 0.5, 1.0 - t)
             })
             .collect();
-        mesh.add_vertex_color_quantity("height_color", vertex_colors);
+        mesh.add_vertex_color_quantity("height_color", vertex_colors)
+            .expect("one color per vertex");
 
         // Add vertex vector quantity (vertex normals)
-        mesh.add_vertex_vector_quantity("vertex normals", vertex_normals.clone());
+        mesh.add_vertex_vector_quantity("vertex normals", vertex_normals.clone())
+            .expect("one normal per vertex");
 
         // Add face vector quantity (face normals)
-        mesh.add_face_vector_quantity("face normals", face_normals.clone());
+        mesh.add_face_vector_quantity("face normals", face_normals.clone())
+            .expect("one normal per face");
 
         // Add parameterization quantity (UV from projection)
         let uv_coords: Vec<Vec2> = vertices
             .iter()
             .map(|v| Vec2::new(v.x * 5.0, v.z * 5.0))
             .collect();
-        mesh.add_vertex_parameterization_quantity("uv_projection", uv_coords);
+        mesh.add_vertex_parameterization_quantity("uv_projection", uv_coords)
+            .expect("one UV per vertex");
 
         // Add intrinsic vector quantity (tangent field, auto basis)
         let tangent_vecs: Vec<Vec2> = vertices
             .iter()
             .map(|v| Vec2::new(v.y.sin(), v.x.cos()).normalize_or_zero() * 0.02)
             .collect();
-        mesh.add_vertex_intrinsic_vector_quantity_auto("tangent_field", tangent_vecs);
+        mesh.add_vertex_intrinsic_vector_quantity_auto("tangent_field", tangent_vecs)
+            .expect("one tangent per vertex");
 
         // Add one-form quantity (edge-based flow values)
         let num_edges = mesh.edges().len();
@@ -138,12 +144,25 @@ fn main() {
             .map(|i| (i as f32 * 0.1).sin() * 0.02)
             .collect();
         let edge_orientations: Vec<bool> = (0..num_edges).map(|i| i % 2 == 0).collect();
-        mesh.add_one_form_quantity("edge_flow", edge_values, edge_orientations);
+        mesh.add_one_form_quantity("edge_flow", edge_values, edge_orientations)
+            .expect("one flow value per edge");
 
         // Set a nice surface color
         mesh.set_surface_color(Vec3::new(0.8, 0.6, 0.4));
     });
 
+    // Move vertices in place (e.g. per animation frame). Connectivity is
+    // unchanged, so the triangulation and edges are reused and only the
+    // GPU position/normal buffers are rewritten.
+    let inflated: Vec<Vec3> = vertices
+        .iter()
+        .zip(&vertex_normals)
+        .map(|(v, n)| *v + *n * 0.001)
+        .collect();
+    polyscope_rs::with_surface_mesh("bunny", |mesh| {
+        mesh.update_vertex_positions_only(inflated);
+    });
+
     println!("Surface mesh demo running...");
     println!("Displaying the Stanford Bunny with quantities:");
     println!("  - height: vertex scalar (Y coordinate)");